@@ -1,3 +1,5 @@
+use std::collections::HashMap;
+
 use serde::{Deserialize, Serialize};
 
 /// Configuration for including files that override gitignore rules.
@@ -14,6 +16,7 @@ use serde::{Deserialize, Serialize};
 ///     patterns: vec![".env*".to_string(), "*.local.json".to_string()],
 ///     enabled: true,
 ///     max_file_size: Some("10MB".to_string()),
+///     ..Default::default()
 /// };
 /// ```
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -32,12 +35,23 @@ pub struct IncludeConfig {
 
     /// Optional maximum file size limit (e.g., "10MB", "1GB").
     /// Files exceeding this limit will be skipped with a warning.
+    /// Only applies to files that end up byte-copied; reflinked and
+    /// hardlinked files share blocks with the source.
     #[serde(default)]
     pub max_file_size: Option<String>,
+
+    /// How matching files are copied. Defaults to `reflink`.
+    #[serde(default)]
+    pub strategy: Option<CopyStrategy>,
+
+    /// Per-pattern strategy overrides, keyed by an entry of `patterns`.
+    #[serde(default, skip_serializing_if = "HashMap::is_empty")]
+    pub strategies: HashMap<String, CopyStrategy>,
 }
 
 impl IncludeConfig {
-    /// Validate that all patterns are valid glob patterns.
+    /// Validate that all patterns are valid glob patterns and that every
+    /// strategy override names a configured pattern.
     ///
     /// Returns an error if any pattern is invalid.
     pub fn validate(&self) -> Result<(), String> {
@@ -45,8 +59,47 @@ impl IncludeConfig {
             glob::Pattern::new(pattern)
                 .map_err(|e| format!("Invalid pattern '{}': {}", pattern, e))?;
         }
+        for pattern in self.strategies.keys() {
+            if !self.patterns.contains(pattern) {
+                return Err(format!(
+                    "Strategy set for '{}', which is not in include patterns",
+                    pattern
+                ));
+            }
+        }
         Ok(())
     }
+
+    /// Default strategy for files whose pattern has no override.
+    pub fn strategy(&self) -> CopyStrategy {
+        self.strategy.unwrap_or_default()
+    }
+
+    /// Strategy for files matched by `pattern`.
+    pub fn strategy_for(&self, pattern: &str) -> CopyStrategy {
+        self.strategies
+            .get(pattern)
+            .copied()
+            .unwrap_or_else(|| self.strategy())
+    }
+}
+
+/// How an included file is materialized in the new worktree.
+///
+/// Link strategies fall back to a byte copy when the filesystem can't
+/// share the file (no reflink support, source on another device).
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum CopyStrategy {
+    /// Plain byte copy.
+    Copy,
+    /// Copy-on-write clone (Btrfs, XFS, APFS). Behaves like a copy: edits in
+    /// the worktree never touch the source.
+    #[default]
+    Reflink,
+    /// Hard link to the source file. Cheapest, but both paths share one
+    /// inode — only for files nobody edits in place (model weights, fixtures).
+    Hardlink,
 }
 
 /// A compiled glob pattern rule for matching files.
@@ -94,6 +147,7 @@ mod tests {
             patterns: vec!["[bad-glob".to_string()],
             enabled: true,
             max_file_size: None,
+            ..Default::default()
         };
         assert!(config.validate().is_err());
     }
@@ -104,6 +158,7 @@ mod tests {
             patterns: vec![".env*".to_string(), "**/*.local.json".to_string()],
             enabled: true,
             max_file_size: None,
+            ..Default::default()
         };
         assert!(config.validate().is_ok());
     }
@@ -114,10 +169,40 @@ mod tests {
             patterns: vec![],
             enabled: true,
             max_file_size: None,
+            ..Default::default()
         };
         assert!(config.validate().is_ok());
     }
 
+    #[test]
+    fn test_validate_rejects_strategy_for_unknown_pattern() {
+        let mut config = IncludeConfig::default();
+        config
+            .strategies
+            .insert("models/**".to_string(), CopyStrategy::Hardlink);
+        assert!(config.validate().is_err());
+
+        config.patterns.push("models/**".to_string());
+        assert!(config.validate().is_ok());
+    }
+
+    #[test]
+    fn test_strategy_for_uses_override_then_default() {
+        let config: IncludeConfig = toml::from_str(
+            r#"
+            patterns = ["models/**", ".env*"]
+            strategy = "copy"
+
+            [strategies]
+            "models/**" = "hardlink"
+            "#,
+        )
+        .unwrap();
+        assert_eq!(config.strategy_for("models/**"), CopyStrategy::Hardlink);
+        assert_eq!(config.strategy_for(".env*"), CopyStrategy::Copy);
+        assert_eq!(IncludeConfig::default().strategy(), CopyStrategy::Reflink);
+    }
+
     #[test]
     fn test_pattern_rule_new_valid() {
         let rule = PatternRule::new(".env*".to_string()).unwrap();
@@ -158,6 +243,8 @@ impl Default for IncludeConfig {
             patterns: default_include_patterns(),
             enabled: true,
             max_file_size: None,
+            strategy: None,
+            strategies: HashMap::new(),
         }
     }
 }
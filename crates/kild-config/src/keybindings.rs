@@ -46,6 +46,11 @@ pub struct TerminalKeybindings {
     /// Default: `"cmd+v"`
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub paste: Option<String>,
+
+    /// Open the terminal search bar (scrollback included).
+    /// Default: `"cmd+f"`
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub search: Option<String>,
 }
 
 impl TerminalKeybindings {
@@ -64,6 +69,11 @@ impl TerminalKeybindings {
         self.paste.as_deref().unwrap_or("cmd+v")
     }
 
+    /// Returns the search binding, defaulting to `"cmd+f"`.
+    pub fn search(&self) -> &str {
+        self.search.as_deref().unwrap_or("cmd+f")
+    }
+
     /// Merge two terminal keybinding configs. Override takes precedence for set fields.
     pub fn merge(base: &Self, override_config: &Self) -> Self {
         Self {
@@ -73,6 +83,7 @@ impl TerminalKeybindings {
                 .or(base.focus_escape.clone()),
             copy: override_config.copy.clone().or(base.copy.clone()),
             paste: override_config.paste.clone().or(base.paste.clone()),
+            search: override_config.search.clone().or(base.search.clone()),
         }
     }
 }
@@ -111,6 +122,22 @@ pub struct NavigationKeybindings {
     /// Default: `"cmd+shift+["`
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub prev_workspace: Option<String>,
+
+    /// Open the command palette.
+    /// Default: `"cmd+p"` (`cmd+k` is taken by `prev_kild`)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub command_palette: Option<String>,
+
+    /// Cycle the active workspace through named layouts (or the presets
+    /// when none are saved).
+    /// Default: `"cmd+shift+l"`
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub next_layout: Option<String>,
+
+    /// Show or hide the activity feed.
+    /// Default: `"cmd+shift+a"`
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub activity_feed: Option<String>,
 }
 
 impl NavigationKeybindings {
@@ -144,6 +171,21 @@ impl NavigationKeybindings {
         self.prev_workspace.as_deref().unwrap_or("cmd+shift+[")
     }
 
+    /// Returns the command_palette binding, defaulting to `"cmd+p"`.
+    pub fn command_palette(&self) -> &str {
+        self.command_palette.as_deref().unwrap_or("cmd+p")
+    }
+
+    /// Returns the next_layout binding, defaulting to `"cmd+shift+l"`.
+    pub fn next_layout(&self) -> &str {
+        self.next_layout.as_deref().unwrap_or("cmd+shift+l")
+    }
+
+    /// Returns the activity_feed binding, defaulting to `"cmd+shift+a"`.
+    pub fn activity_feed(&self) -> &str {
+        self.activity_feed.as_deref().unwrap_or("cmd+shift+a")
+    }
+
     /// Merge two navigation keybinding configs. Override takes precedence for set fields.
     pub fn merge(base: &Self, override_config: &Self) -> Self {
         Self {
@@ -165,6 +207,18 @@ impl NavigationKeybindings {
                 .prev_workspace
                 .clone()
                 .or(base.prev_workspace.clone()),
+            command_palette: override_config
+                .command_palette
+                .clone()
+                .or(base.command_palette.clone()),
+            next_layout: override_config
+                .next_layout
+                .clone()
+                .or(base.next_layout.clone()),
+            activity_feed: override_config
+                .activity_feed
+                .clone()
+                .or(base.activity_feed.clone()),
         }
     }
 }
@@ -256,12 +310,16 @@ mod tests {
         assert_eq!(parsed.terminal.focus_escape(), "ctrl+escape");
         assert_eq!(parsed.terminal.copy(), "cmd+c");
         assert_eq!(parsed.terminal.paste(), "cmd+v");
+        assert_eq!(parsed.terminal.search(), "cmd+f");
         assert_eq!(parsed.navigation.next_kild(), "cmd+j");
         assert_eq!(parsed.navigation.prev_kild(), "cmd+k");
         assert_eq!(parsed.navigation.jump_modifier(), "ctrl");
         assert_eq!(parsed.navigation.toggle_view(), "cmd+d");
         assert_eq!(parsed.navigation.next_workspace(), "cmd+shift+]");
         assert_eq!(parsed.navigation.prev_workspace(), "cmd+shift+[");
+        assert_eq!(parsed.navigation.command_palette(), "cmd+p");
+        assert_eq!(parsed.navigation.next_layout(), "cmd+shift+l");
+        assert_eq!(parsed.navigation.activity_feed(), "cmd+shift+a");
     }
 
     #[test]
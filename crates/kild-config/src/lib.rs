@@ -17,12 +17,16 @@ pub mod types;
 
 // Public API re-exports
 pub use errors::ConfigError;
-pub use include_config::{CopyOptions, IncludeConfig, PatternRule, default_include_patterns};
+pub use include_config::{
+    CopyOptions, CopyStrategy, IncludeConfig, PatternRule, default_include_patterns,
+};
 pub use keybindings::{Keybindings, NavigationKeybindings, TerminalKeybindings};
-pub use loading::{get_agent_command, load_hierarchy, merge_configs};
+pub use loading::{get_agent_command, load_hierarchy, load_hierarchy_for, merge_configs};
 pub use types::{
-    AgentConfig, AgentSettings, Config, DaemonRuntimeConfig, EditorConfig, GitConfig, HealthConfig,
-    KildConfig, TerminalConfig, UiConfig,
+    AgentConfig, AgentSettings, ApprovalsConfig, CacheLinkMethod, CheckpointsConfig,
+    CommandChannel, Config, DaemonHost, DaemonRuntimeConfig, EditorConfig, GitConfig, HealthConfig,
+    KildConfig, ModelPrice, NotifyConfig, NotifyEvent, PushChannel, PushService, SharedCacheConfig,
+    TerminalConfig, UiConfig, UsageConfig, WebhookChannel, WorktreeConfig,
 };
 pub use validation::{VALID_TERMINALS, validate_config};
 
@@ -43,6 +47,15 @@ impl KildConfig {
         loading::load_hierarchy()
     }
 
+    /// Load configuration with the project config taken from `project_root`.
+    ///
+    /// See [`loading::load_hierarchy_for`] for details.
+    pub fn load_hierarchy_for(
+        project_root: &std::path::Path,
+    ) -> Result<Self, Box<dyn std::error::Error>> {
+        loading::load_hierarchy_for(project_root)
+    }
+
     /// Validate the configuration.
     ///
     /// See [`validation::validate_config`] for details.
@@ -14,7 +14,8 @@
 use crate::agent_data;
 use crate::include_config::IncludeConfig;
 use crate::types::{
-    AgentConfig, DaemonRuntimeConfig, GitConfig, HealthConfig, KildConfig, TerminalConfig, UiConfig,
+    AgentConfig, ApprovalsConfig, CheckpointsConfig, DaemonRuntimeConfig, GitConfig, HealthConfig,
+    KildConfig, NotifyConfig, TerminalConfig, UiConfig, UsageConfig, WorktreeConfig,
 };
 use crate::validation::validate_config;
 use std::fs;
@@ -41,6 +42,14 @@ fn is_file_not_found(e: &(dyn std::error::Error + 'static)) -> bool {
 ///
 /// Returns an error if validation fails. Missing config files are not errors.
 pub fn load_hierarchy() -> Result<KildConfig, Box<dyn std::error::Error>> {
+    load_hierarchy_for(&std::env::current_dir()?)
+}
+
+/// Load configuration with the project config taken from `project_root`.
+///
+/// Same as [`load_hierarchy`] but for callers whose working directory is not
+/// the project, such as the daemon handling a hook from a worktree.
+pub fn load_hierarchy_for(project_root: &Path) -> Result<KildConfig, Box<dyn std::error::Error>> {
     let mut config = KildConfig::default();
 
     // Load user config (file not found is expected, parse errors fail)
@@ -51,7 +60,7 @@ pub fn load_hierarchy() -> Result<KildConfig, Box<dyn std::error::Error>> {
     }
 
     // Load project config (file not found is expected, parse errors fail)
-    match load_project_config() {
+    match load_project_config(project_root) {
         Ok(project_config) => config = merge_configs(config, project_config),
         Err(e) if !is_file_not_found(e.as_ref()) => return Err(e),
         Err(_) => {} // File not found - continue with merged config
@@ -69,10 +78,9 @@ fn load_user_config() -> Result<KildConfig, Box<dyn std::error::Error>> {
     load_config_file(&paths.user_config())
 }
 
-/// Load the project configuration from ./.kild/config.toml.
-fn load_project_config() -> Result<KildConfig, Box<dyn std::error::Error>> {
-    let project_root = std::env::current_dir()?;
-    load_config_file(&kild_paths::KildPaths::project_config(&project_root))
+/// Load the project configuration from `<project_root>/.kild/config.toml`.
+fn load_project_config(project_root: &Path) -> Result<KildConfig, Box<dyn std::error::Error>> {
+    load_config_file(&kild_paths::KildPaths::project_config(project_root))
 }
 
 /// Load a configuration file from the given path.
@@ -87,7 +95,8 @@ fn load_config_file(path: &Path) -> Result<KildConfig, Box<dyn std::error::Error
 /// Merge include pattern configurations.
 ///
 /// When both configs have patterns, combines and deduplicates them.
-/// Override config wins for enabled and max_file_size settings.
+/// Override config wins for enabled, max_file_size and strategy settings;
+/// per-pattern strategies are merged with override entries winning.
 fn merge_include_patterns(
     base: Option<IncludeConfig>,
     override_config: Option<IncludeConfig>,
@@ -96,6 +105,8 @@ fn merge_include_patterns(
         (Some(base_config), Some(override_cfg)) => {
             // Both configs present - merge patterns and use override settings
             let mut merged_patterns = base_config.patterns;
+            let mut strategies = base_config.strategies;
+            strategies.extend(override_cfg.strategies);
             for pattern in override_cfg.patterns {
                 if !merged_patterns.contains(&pattern) {
                     merged_patterns.push(pattern);
@@ -105,6 +116,8 @@ fn merge_include_patterns(
                 patterns: merged_patterns,
                 enabled: override_cfg.enabled,
                 max_file_size: override_cfg.max_file_size.or(base_config.max_file_size),
+                strategy: override_cfg.strategy.or(base_config.strategy),
+                strategies,
             })
         }
         (None, Some(override_cfg)) => Some(override_cfg),
@@ -170,10 +183,16 @@ pub fn merge_configs(base: KildConfig, override_config: KildConfig) -> KildConfi
                 .fetch_before_create
                 .or(base.git.fetch_before_create),
             forge: override_config.git.forge.or(base.git.forge),
+            sparse_paths: override_config.git.sparse_paths.or(base.git.sparse_paths),
         },
+        worktree: WorktreeConfig::merge(&base.worktree, &override_config.worktree),
         editor: base.editor.merge(override_config.editor),
         daemon: DaemonRuntimeConfig::merge(&base.daemon, &override_config.daemon),
         ui: UiConfig::merge(&base.ui, &override_config.ui),
+        usage: UsageConfig::merge(&base.usage, &override_config.usage),
+        approvals: ApprovalsConfig::merge(&base.approvals, &override_config.approvals),
+        notify: NotifyConfig::merge(&base.notify, &override_config.notify),
+        checkpoints: CheckpointsConfig::merge(&base.checkpoints, &override_config.checkpoints),
     }
 }
 
@@ -243,6 +262,7 @@ fn build_command(base: &str, flags: Option<&str>) -> String {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::include_config::CopyStrategy;
     use crate::types::AgentSettings;
     use std::env;
     use std::fs;
@@ -591,6 +611,40 @@ patterns = []
         assert!(include.patterns.contains(&".env*".to_string())); // But patterns still merged
     }
 
+    #[test]
+    fn test_include_patterns_merge_strategies() {
+        let user_config: KildConfig = toml::from_str(
+            r#"
+[include_patterns]
+patterns = ["models/**", "fixtures/**"]
+strategy = "copy"
+
+[include_patterns.strategies]
+"models/**" = "hardlink"
+"fixtures/**" = "hardlink"
+"#,
+        )
+        .unwrap();
+
+        let project_config: KildConfig = toml::from_str(
+            r#"
+[include_patterns]
+patterns = []
+
+[include_patterns.strategies]
+"fixtures/**" = "reflink"
+"#,
+        )
+        .unwrap();
+
+        let merged = merge_configs(user_config, project_config);
+        let include = merged.include_patterns.unwrap();
+
+        assert_eq!(include.strategy(), CopyStrategy::Copy); // Unset in project, user kept
+        assert_eq!(include.strategy_for("models/**"), CopyStrategy::Hardlink);
+        assert_eq!(include.strategy_for("fixtures/**"), CopyStrategy::Reflink);
+    }
+
     #[test]
     fn test_include_patterns_default_has_patterns() {
         let config = KildConfig::default();
@@ -26,6 +26,25 @@
 //! [health]
 //! idle_threshold_minutes = 10
 //! history_enabled = true
+//!
+//! [approvals]
+//! allow = ["Bash(cargo test*)", "Read"]
+//! deny = ["Bash(git push*)"]
+//!
+//! [notify]
+//! events = ["waiting", "error"]
+//!
+//! [[notify.webhook]]
+//! url = "https://hooks.example.com/kild"
+//! events = ["done", "crashed"]
+//!
+//! [checkpoints]
+//! before_inject = true
+//! on_stop = true
+//!
+//! [usage.prices.claude-sonnet-4]
+//! input_per_mtok = 3.0
+//! output_per_mtok = 15.0
 //! ```
 
 use crate::include_config::IncludeConfig;
@@ -97,6 +116,10 @@ pub struct KildConfig {
     #[serde(default)]
     pub git: GitConfig,
 
+    /// Worktree setup (shared build caches)
+    #[serde(default)]
+    pub worktree: WorktreeConfig,
+
     /// Editor configuration for `kild code`
     #[serde(default)]
     pub editor: EditorConfig,
@@ -105,9 +128,25 @@ pub struct KildConfig {
     #[serde(default)]
     pub daemon: DaemonRuntimeConfig,
 
-    /// UI configuration (keybindings, navigation).
+    /// UI configuration (theme, fonts).
     #[serde(default)]
     pub ui: UiConfig,
+
+    /// Token usage accounting (price table for cost estimates).
+    #[serde(default)]
+    pub usage: UsageConfig,
+
+    /// Agent permission broker rules.
+    #[serde(default)]
+    pub approvals: ApprovalsConfig,
+
+    /// Notification channels and event filters.
+    #[serde(default)]
+    pub notify: NotifyConfig,
+
+    /// Automatic worktree checkpoints.
+    #[serde(default)]
+    pub checkpoints: CheckpointsConfig,
 }
 
 impl Default for KildConfig {
@@ -119,25 +158,88 @@ impl Default for KildConfig {
             include_patterns: default_include_patterns_option(),
             health: HealthConfig::default(),
             git: GitConfig::default(),
+            worktree: WorktreeConfig::default(),
             editor: <EditorConfig as Default>::default(),
             daemon: DaemonRuntimeConfig::default(),
             ui: UiConfig::default(),
+            usage: UsageConfig::default(),
+            approvals: ApprovalsConfig::default(),
+            notify: NotifyConfig::default(),
+            checkpoints: CheckpointsConfig::default(),
         }
     }
 }
 
 /// UI configuration for the KILD native GUI.
 ///
-/// Keyboard shortcuts have moved to `keybindings.toml` (see `Keybindings`).
-/// This section is kept for forward extension.
+/// Keyboard shortcuts live in `keybindings.toml` (see `Keybindings`).
+/// Changes to this section are picked up by a running kild-ui.
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
 #[serde(default)]
-pub struct UiConfig {}
+pub struct UiConfig {
+    /// Color theme: `"tallinn-night"` (built-in dark), `"tallinn-day"`
+    /// (built-in light), or the name of a file in `~/.kild/themes/`
+    /// without its `.toml` extension.
+    /// Default: `"tallinn-night"`
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub theme: Option<String>,
+
+    /// Font family for UI text. Default: `"Inter"`
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub font_family: Option<String>,
+
+    /// Base font size in pixels for UI controls. Default: 13
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub font_size: Option<f32>,
+
+    /// Font family for terminals and other monospace text.
+    /// Default: `"JetBrains Mono"`
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub mono_font_family: Option<String>,
+
+    /// Terminal font size in pixels. Default: 13
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub mono_font_size: Option<f32>,
+}
 
 impl UiConfig {
+    /// Smallest and largest accepted font sizes, in pixels.
+    pub const FONT_SIZE_RANGE: std::ops::RangeInclusive<f32> = 6.0..=48.0;
+
     /// Merge two UI configs. Override takes precedence for set fields.
-    pub fn merge(_base: &Self, _override_config: &Self) -> Self {
-        Self {}
+    pub fn merge(base: &Self, override_config: &Self) -> Self {
+        Self {
+            theme: override_config.theme.clone().or(base.theme.clone()),
+            font_family: override_config
+                .font_family
+                .clone()
+                .or(base.font_family.clone()),
+            font_size: override_config.font_size.or(base.font_size),
+            mono_font_family: override_config
+                .mono_font_family
+                .clone()
+                .or(base.mono_font_family.clone()),
+            mono_font_size: override_config.mono_font_size.or(base.mono_font_size),
+        }
+    }
+
+    /// Check that font sizes are within [`Self::FONT_SIZE_RANGE`].
+    pub fn validate(&self) -> Result<(), String> {
+        for (key, size) in [
+            ("font_size", self.font_size),
+            ("mono_font_size", self.mono_font_size),
+        ] {
+            if let Some(size) = size
+                && !Self::FONT_SIZE_RANGE.contains(&size)
+            {
+                return Err(format!(
+                    "ui.{key} = {size} is out of range ({}-{})",
+                    Self::FONT_SIZE_RANGE.start(),
+                    Self::FONT_SIZE_RANGE.end()
+                ));
+            }
+        }
+        Ok(())
     }
 }
 
@@ -184,6 +286,23 @@ pub struct DaemonRuntimeConfig {
     ///   openssl x509 -in ~/.kild/certs/daemon.crt -fingerprint -sha256 -noout
     /// Format: "sha256:<lowercase hex>" — 64 hex chars after "sha256:"
     pub remote_cert_fingerprint: Option<String>,
+
+    /// Extra daemons the UI shows alongside the default one, added with
+    /// `[[daemon.hosts]]` tables. Does not affect where the CLI connects.
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub hosts: Vec<DaemonHost>,
+}
+
+/// Remote daemon endpoint listed under `[[daemon.hosts]]`.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct DaemonHost {
+    /// Display name, unique across hosts (e.g. "build-box").
+    pub name: String,
+    /// TLS address of the daemon, "host:port".
+    pub address: String,
+    /// SHA-256 fingerprint of the daemon's TLS certificate, same format as
+    /// `remote_cert_fingerprint`.
+    pub cert_fingerprint: String,
 }
 
 impl DaemonRuntimeConfig {
@@ -217,6 +336,39 @@ impl DaemonRuntimeConfig {
         Ok(())
     }
 
+    /// Validate the `[[daemon.hosts]]` entries: names must be present,
+    /// unique and free of '/', addresses must carry a port and fingerprints must be
+    /// `sha256:` followed by 64 hex chars.
+    pub fn validate_hosts(&self) -> Result<(), String> {
+        let mut names = std::collections::HashSet::new();
+        for host in &self.hosts {
+            if host.name.trim().is_empty() || host.name.contains('/') {
+                return Err(format!(
+                    "daemon host '{}' needs a name without '/'",
+                    host.address
+                ));
+            }
+            if !names.insert(host.name.as_str()) {
+                return Err(format!("daemon host '{}' is listed twice", host.name));
+            }
+            let port = host.address.rsplit_once(':').map(|(_, p)| p);
+            if port.is_none_or(|p| p.parse::<u16>().is_err()) {
+                return Err(format!(
+                    "daemon host '{}': address '{}' must be host:port",
+                    host.name, host.address
+                ));
+            }
+            let hex = host.cert_fingerprint.strip_prefix("sha256:");
+            if hex.is_none_or(|h| h.len() != 64 || !h.chars().all(|c| c.is_ascii_hexdigit())) {
+                return Err(format!(
+                    "daemon host '{}': cert_fingerprint must be sha256:<64 hex chars>",
+                    host.name
+                ));
+            }
+        }
+        Ok(())
+    }
+
     /// Merge two daemon runtime configs. Override takes precedence for set fields.
     pub fn merge(base: &Self, override_config: &Self) -> Self {
         Self {
@@ -233,6 +385,14 @@ impl DaemonRuntimeConfig {
                 .remote_cert_fingerprint
                 .clone()
                 .or(base.remote_cert_fingerprint.clone()),
+            // Hosts are combined; an override host replaces a base host of the same name
+            hosts: base
+                .hosts
+                .iter()
+                .filter(|h| !override_config.hosts.iter().any(|o| o.name == h.name))
+                .chain(&override_config.hosts)
+                .cloned()
+                .collect(),
         }
     }
 }
@@ -265,6 +425,12 @@ pub struct GitConfig {
     /// When None, detect_forge() inspects the git remote URL.
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub forge: Option<ForgeType>,
+
+    /// Directories to check out in new worktrees (cone-mode sparse checkout).
+    /// `--sparse` on `kild create` replaces this list; `--no-sparse` ignores it.
+    /// Default: full checkout
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub sparse_paths: Option<Vec<String>>,
 }
 
 impl GitConfig {
@@ -287,6 +453,94 @@ impl GitConfig {
     pub fn forge(&self) -> Option<ForgeType> {
         self.forge
     }
+
+    /// Returns the preset sparse-checkout directories, empty for a full checkout.
+    pub fn sparse_paths(&self) -> &[String] {
+        self.sparse_paths.as_deref().unwrap_or_default()
+    }
+}
+
+/// Worktree setup configuration (`[worktree]`).
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct WorktreeConfig {
+    /// Share build output with the main checkout (`[worktree.shared_cache]`).
+    #[serde(default)]
+    pub shared_cache: SharedCacheConfig,
+}
+
+impl WorktreeConfig {
+    pub fn merge(base: &Self, override_config: &Self) -> Self {
+        Self {
+            shared_cache: SharedCacheConfig::merge(
+                &base.shared_cache,
+                &override_config.shared_cache,
+            ),
+        }
+    }
+}
+
+/// How seeded files share disk with the main checkout.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum CacheLinkMethod {
+    /// Reflink where the filesystem supports it; elsewhere seeding is
+    /// skipped, since a full copy costs as much disk as a cold build.
+    #[default]
+    Auto,
+    /// Copy-on-write clones only (btrfs, XFS, APFS); seeding elsewhere fails
+    /// with a warning.
+    Reflink,
+    /// Hardlinks only, never chosen automatically. Files rewritten in place
+    /// would change in both places.
+    Hardlink,
+}
+
+/// Opt-in shared build caches for new kilds.
+///
+/// When enabled, `kild create` seeds the kild's `target/` from the main
+/// checkout's and clones `node_modules` from a shared, lockfile-keyed
+/// snapshot when the kild's lockfile matches the main checkout's.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct SharedCacheConfig {
+    /// Default: false.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub enabled: Option<bool>,
+    /// Seed `target/` from the main checkout. Default: true.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub cargo_target: Option<bool>,
+    /// Clone `node_modules` when lockfiles match. Default: true.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub node_modules: Option<bool>,
+    /// Default: auto.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub method: Option<CacheLinkMethod>,
+}
+
+impl SharedCacheConfig {
+    pub fn merge(base: &Self, override_config: &Self) -> Self {
+        Self {
+            enabled: override_config.enabled.or(base.enabled),
+            cargo_target: override_config.cargo_target.or(base.cargo_target),
+            node_modules: override_config.node_modules.or(base.node_modules),
+            method: override_config.method.or(base.method),
+        }
+    }
+
+    pub fn enabled(&self) -> bool {
+        self.enabled.unwrap_or(false)
+    }
+
+    pub fn cargo_target(&self) -> bool {
+        self.enabled() && self.cargo_target.unwrap_or(true)
+    }
+
+    pub fn node_modules(&self) -> bool {
+        self.enabled() && self.node_modules.unwrap_or(true)
+    }
+
+    pub fn method(&self) -> CacheLinkMethod {
+        self.method.unwrap_or_default()
+    }
 }
 
 /// Editor configuration for `kild code`.
@@ -399,6 +653,261 @@ pub struct HealthConfig {
     pub history_retention_days: Option<u64>,
 }
 
+/// Agent permission broker configuration.
+///
+/// Rules use `Tool` or `Tool(pattern)` syntax, where `pattern` is matched
+/// against the tool's subject (the Bash command, file path, or URL) with `*`
+/// as a wildcard. Deny rules win over allow rules. A patterned `Bash` allow
+/// rule never approves a command that chains, pipes, substitutes or
+/// redirects. Requests matching neither are queued for `kild approvals` /
+/// the UI.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct ApprovalsConfig {
+    /// Rules that are approved without asking.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub allow: Vec<String>,
+    /// Rules that are denied without asking.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub deny: Vec<String>,
+    /// Seconds a queued request waits for a decision before falling back to
+    /// the agent's own prompt. Default: 120.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub timeout_secs: Option<u64>,
+}
+
+impl ApprovalsConfig {
+    /// Merge two approval configs. Rule lists are combined; override wins for timeout.
+    pub fn merge(base: &Self, override_config: &Self) -> Self {
+        let combine = |a: &[String], b: &[String]| {
+            let mut rules = a.to_vec();
+            for rule in b {
+                if !rules.contains(rule) {
+                    rules.push(rule.clone());
+                }
+            }
+            rules
+        };
+        Self {
+            allow: combine(&base.allow, &override_config.allow),
+            deny: combine(&base.deny, &override_config.deny),
+            timeout_secs: override_config.timeout_secs.or(base.timeout_secs),
+        }
+    }
+
+    pub fn timeout_secs(&self) -> u64 {
+        self.timeout_secs.unwrap_or(120)
+    }
+}
+
+/// Automatic checkpoint configuration.
+///
+/// Manual checkpoints (`kild checkpoint`) are always available; these settings
+/// control the ones kild records on its own.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct CheckpointsConfig {
+    /// Checkpoint before each `kild inject`. Default: false.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub before_inject: Option<bool>,
+    /// Checkpoint when the agent's `Stop` hook fires. Default: false.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub on_stop: Option<bool>,
+    /// Automatic checkpoints kept per kild; older ones are pruned. Default: 20.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub keep: Option<usize>,
+}
+
+impl CheckpointsConfig {
+    /// Merge two checkpoint configs. Override takes precedence for set fields.
+    pub fn merge(base: &Self, override_config: &Self) -> Self {
+        Self {
+            before_inject: override_config.before_inject.or(base.before_inject),
+            on_stop: override_config.on_stop.or(base.on_stop),
+            keep: override_config.keep.or(base.keep),
+        }
+    }
+
+    pub fn before_inject(&self) -> bool {
+        self.before_inject.unwrap_or(false)
+    }
+
+    pub fn on_stop(&self) -> bool {
+        self.on_stop.unwrap_or(false)
+    }
+
+    pub fn keep(&self) -> usize {
+        self.keep.unwrap_or(20)
+    }
+}
+
+/// Session events that can trigger a notification.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum NotifyEvent {
+    /// The agent is blocked on user input.
+    Waiting,
+    /// The agent finished its turn (or reported `done`).
+    Done,
+    /// The agent reported an error.
+    Error,
+    /// The agent process exited while the kild was still active.
+    Crashed,
+}
+
+impl std::fmt::Display for NotifyEvent {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            NotifyEvent::Waiting => write!(f, "waiting"),
+            NotifyEvent::Done => write!(f, "done"),
+            NotifyEvent::Error => write!(f, "error"),
+            NotifyEvent::Crashed => write!(f, "crashed"),
+        }
+    }
+}
+
+/// Notification configuration (`[notify]`).
+///
+/// Desktop notifications are on by default. Webhook, push (ntfy/Gotify) and
+/// command channels are added with `[[notify.webhook]]`, `[[notify.push]]`
+/// and `[[notify.command]]` tables; any number of each can be combined. Each
+/// channel notifies for the top-level `events` unless it sets its own.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct NotifyConfig {
+    /// Send desktop notifications (notify-send/osascript). Default: true.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub desktop: Option<bool>,
+    /// Events every channel notifies for. Default: waiting, error.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub events: Option<Vec<NotifyEvent>>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub webhook: Vec<WebhookChannel>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub push: Vec<PushChannel>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub command: Vec<CommandChannel>,
+}
+
+impl NotifyConfig {
+    /// Merge two notify configs. Channels are combined; override wins for
+    /// `desktop` and `events`.
+    pub fn merge(base: &Self, override_config: &Self) -> Self {
+        Self {
+            desktop: override_config.desktop.or(base.desktop),
+            events: override_config
+                .events
+                .clone()
+                .or_else(|| base.events.clone()),
+            webhook: [base.webhook.as_slice(), &override_config.webhook].concat(),
+            push: [base.push.as_slice(), &override_config.push].concat(),
+            command: [base.command.as_slice(), &override_config.command].concat(),
+        }
+    }
+
+    pub fn desktop(&self) -> bool {
+        self.desktop.unwrap_or(true)
+    }
+
+    /// Events channels without their own filter notify for.
+    pub fn events(&self) -> &[NotifyEvent] {
+        self.events
+            .as_deref()
+            .unwrap_or(&[NotifyEvent::Waiting, NotifyEvent::Error])
+    }
+
+    /// Whether a channel with the given filter should fire for `event`.
+    pub fn wants(&self, channel_events: Option<&[NotifyEvent]>, event: NotifyEvent) -> bool {
+        channel_events.unwrap_or(self.events()).contains(&event)
+    }
+}
+
+/// Generic HTTP webhook: POSTs a JSON payload with the event, branch,
+/// project, status and message.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct WebhookChannel {
+    pub url: String,
+    /// Extra request headers (e.g. `Authorization`).
+    #[serde(default, skip_serializing_if = "HashMap::is_empty")]
+    pub headers: HashMap<String, String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub events: Option<Vec<NotifyEvent>>,
+}
+
+/// Push service protocol for [`PushChannel`].
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum PushService {
+    /// ntfy: `url` is the full topic URL (`https://ntfy.sh/<topic>`).
+    #[default]
+    Ntfy,
+    /// Gotify: `url` is the server root; `token` is an application token.
+    Gotify,
+}
+
+/// Phone push via an ntfy- or Gotify-compatible server.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PushChannel {
+    pub url: String,
+    #[serde(default)]
+    pub service: PushService,
+    /// Access token (ntfy bearer token or Gotify app token).
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub token: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub events: Option<Vec<NotifyEvent>>,
+}
+
+/// Arbitrary command run through `sh -c` with the JSON payload on stdin and
+/// `KILD_NOTIFY_*` environment variables set.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CommandChannel {
+    pub command: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub events: Option<Vec<NotifyEvent>>,
+}
+
+/// Token usage accounting configuration.
+///
+/// Holds the price table used to turn token counts parsed from agent
+/// transcripts into cost estimates. Keys are model names or model name
+/// prefixes (e.g. `claude-sonnet-4` matches `claude-sonnet-4-20250514`);
+/// the longest matching key wins. Models without a matching entry report
+/// token counts but no cost.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct UsageConfig {
+    #[serde(default, skip_serializing_if = "HashMap::is_empty")]
+    pub prices: HashMap<String, ModelPrice>,
+}
+
+impl UsageConfig {
+    /// Merge two usage configs. Override entries replace base entries per model key.
+    pub fn merge(base: &Self, override_config: &Self) -> Self {
+        let mut prices = base.prices.clone();
+        prices.extend(override_config.prices.clone());
+        Self { prices }
+    }
+
+    /// Look up the price for a model, matching the longest configured prefix.
+    pub fn price_for(&self, model: &str) -> Option<&ModelPrice> {
+        self.prices
+            .iter()
+            .filter(|(key, _)| model.starts_with(key.as_str()))
+            .max_by_key(|(key, _)| key.len())
+            .map(|(_, price)| price)
+    }
+}
+
+/// USD price per million tokens for one model.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ModelPrice {
+    pub input_per_mtok: f64,
+    pub output_per_mtok: f64,
+    /// Price for prompt-cache reads. Default: same as input.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub cache_read_per_mtok: Option<f64>,
+    /// Price for prompt-cache writes. Default: same as input.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub cache_write_per_mtok: Option<f64>,
+}
+
 /// Global agent configuration.
 ///
 /// Defines the default agent and global settings that apply to all agents
@@ -425,7 +934,7 @@ pub struct AgentConfig {
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
 pub struct TerminalConfig {
     /// Preferred terminal emulator.
-    /// Options: iterm2, iterm, terminal, ghostty, native.
+    /// Options: iterm2, iterm, terminal, ghostty, alacritty, wezterm, kitty, tmux, native.
     #[serde(default)]
     pub preferred: Option<String>,
 }
@@ -540,6 +1049,22 @@ base_branch = "develop"
         assert!(config.git.fetch_before_create()); // default via accessor
     }
 
+    #[test]
+    fn test_git_config_sparse_paths() {
+        let config: KildConfig = toml::from_str(
+            r#"
+[git]
+sparse_paths = ["services/billing", "libs/shared"]
+"#,
+        )
+        .unwrap();
+        assert_eq!(
+            config.git.sparse_paths(),
+            ["services/billing", "libs/shared"]
+        );
+        assert!(KildConfig::default().git.sparse_paths().is_empty());
+    }
+
     #[test]
     fn test_editor_config_serialization() {
         let config = <EditorConfig as Default>::default();
@@ -671,25 +1196,368 @@ remote_cert_fingerprint = "sha256:abc123"
         );
     }
 
+    #[test]
+    fn test_daemon_hosts_from_toml_and_merge() {
+        let fp = format!("sha256:{}", "ab".repeat(32));
+        let base: KildConfig = toml::from_str(&format!(
+            r#"
+[[daemon.hosts]]
+name = "build-box"
+address = "build-box:7432"
+cert_fingerprint = "{fp}"
+
+[[daemon.hosts]]
+name = "gpu"
+address = "gpu:7432"
+cert_fingerprint = "{fp}"
+"#
+        ))
+        .unwrap();
+        assert_eq!(base.daemon.hosts.len(), 2);
+        assert!(base.daemon.validate_hosts().is_ok());
+
+        let override_config = DaemonRuntimeConfig {
+            hosts: vec![DaemonHost {
+                name: "gpu".to_string(),
+                address: "gpu-2:7432".to_string(),
+                cert_fingerprint: fp.clone(),
+            }],
+            ..Default::default()
+        };
+        let merged = DaemonRuntimeConfig::merge(&base.daemon, &override_config);
+        let addresses: Vec<_> = merged.hosts.iter().map(|h| h.address.as_str()).collect();
+        assert_eq!(addresses, vec!["build-box:7432", "gpu-2:7432"]);
+    }
+
+    #[test]
+    fn test_daemon_hosts_validation() {
+        let host = |name: &str, address: &str, fp: &str| DaemonHost {
+            name: name.to_string(),
+            address: address.to_string(),
+            cert_fingerprint: fp.to_string(),
+        };
+        let fp = format!("sha256:{}", "0f".repeat(32));
+        let config = |hosts| DaemonRuntimeConfig {
+            hosts,
+            ..Default::default()
+        };
+
+        assert!(
+            config(vec![host("a", "a:7432", &fp)])
+                .validate_hosts()
+                .is_ok()
+        );
+        assert!(
+            config(vec![host("", "a:7432", &fp)])
+                .validate_hosts()
+                .is_err()
+        );
+        assert!(
+            config(vec![host("a", "a:7432", &fp), host("a", "b:7432", &fp)])
+                .validate_hosts()
+                .is_err()
+        );
+        assert!(config(vec![host("a", "a", &fp)]).validate_hosts().is_err());
+        assert!(
+            config(vec![host("a", "a:7432", "sha256:abc")])
+                .validate_hosts()
+                .is_err()
+        );
+    }
+
     // --- UiConfig tests ---
 
     #[test]
     fn test_ui_config_serialization_round_trips() {
-        let config = UiConfig::default();
+        let config = UiConfig {
+            theme: Some("tallinn-day".to_string()),
+            mono_font_size: Some(15.0),
+            ..Default::default()
+        };
         let toml_str = toml::to_string(&config).unwrap();
-        let _parsed: UiConfig = toml::from_str(&toml_str).unwrap();
+        let parsed: UiConfig = toml::from_str(&toml_str).unwrap();
+        assert_eq!(parsed.theme.as_deref(), Some("tallinn-day"));
+        assert_eq!(parsed.mono_font_size, Some(15.0));
+        assert!(parsed.font_family.is_none());
     }
 
     #[test]
-    fn test_ui_config_merge_returns_self() {
-        let base = UiConfig::default();
-        let override_config = UiConfig::default();
-        let _merged = UiConfig::merge(&base, &override_config);
+    fn test_ui_config_merge_override_wins_for_set_fields() {
+        let base = UiConfig {
+            theme: Some("solarized".to_string()),
+            font_size: Some(12.0),
+            ..Default::default()
+        };
+        let override_config = UiConfig {
+            theme: Some("tallinn-day".to_string()),
+            ..Default::default()
+        };
+        let merged = UiConfig::merge(&base, &override_config);
+        assert_eq!(merged.theme.as_deref(), Some("tallinn-day"));
+        assert_eq!(merged.font_size, Some(12.0));
     }
 
     #[test]
-    fn test_ui_config_from_empty_toml() {
+    fn test_ui_config_validate_font_sizes() {
+        assert!(UiConfig::default().validate().is_ok());
+        let config = UiConfig {
+            mono_font_size: Some(2.0),
+            ..Default::default()
+        };
+        assert!(config.validate().unwrap_err().contains("mono_font_size"));
+    }
+
+    #[test]
+    fn test_ui_config_from_toml() {
         let config: KildConfig = toml::from_str("").unwrap();
-        let _ = config.ui; // UiConfig is empty but must deserialize
+        assert!(config.ui.theme.is_none());
+
+        let config: KildConfig = toml::from_str(
+            r#"
+[ui]
+theme = "tallinn-day"
+mono_font_family = "Iosevka"
+"#,
+        )
+        .unwrap();
+        assert_eq!(config.ui.theme.as_deref(), Some("tallinn-day"));
+        assert_eq!(config.ui.mono_font_family.as_deref(), Some("Iosevka"));
+    }
+
+    // --- UsageConfig tests ---
+
+    fn price(input: f64, output: f64) -> ModelPrice {
+        ModelPrice {
+            input_per_mtok: input,
+            output_per_mtok: output,
+            cache_read_per_mtok: None,
+            cache_write_per_mtok: None,
+        }
+    }
+
+    #[test]
+    fn test_usage_config_from_toml() {
+        let config: KildConfig = toml::from_str(
+            r#"
+[usage.prices.claude-sonnet-4]
+input_per_mtok = 3.0
+output_per_mtok = 15.0
+cache_read_per_mtok = 0.3
+"#,
+        )
+        .unwrap();
+        let price = config.usage.prices.get("claude-sonnet-4").unwrap();
+        assert_eq!(price.input_per_mtok, 3.0);
+        assert_eq!(price.cache_read_per_mtok, Some(0.3));
+        assert_eq!(price.cache_write_per_mtok, None);
+    }
+
+    #[test]
+    fn test_usage_config_price_for_longest_prefix() {
+        let mut prices = HashMap::new();
+        prices.insert("claude".to_string(), price(1.0, 1.0));
+        prices.insert("claude-opus-4".to_string(), price(15.0, 75.0));
+        let config = UsageConfig { prices };
+
+        assert_eq!(
+            config
+                .price_for("claude-opus-4-20250514")
+                .unwrap()
+                .input_per_mtok,
+            15.0
+        );
+        assert_eq!(
+            config.price_for("claude-haiku").unwrap().input_per_mtok,
+            1.0
+        );
+        assert!(config.price_for("gpt-5").is_none());
+    }
+
+    #[test]
+    fn test_usage_config_merge_overrides_per_key() {
+        let mut base_prices = HashMap::new();
+        base_prices.insert("a".to_string(), price(1.0, 1.0));
+        base_prices.insert("b".to_string(), price(2.0, 2.0));
+        let mut override_prices = HashMap::new();
+        override_prices.insert("b".to_string(), price(9.0, 9.0));
+
+        let merged = UsageConfig::merge(
+            &UsageConfig {
+                prices: base_prices,
+            },
+            &UsageConfig {
+                prices: override_prices,
+            },
+        );
+        assert_eq!(merged.prices["a"].input_per_mtok, 1.0);
+        assert_eq!(merged.prices["b"].input_per_mtok, 9.0);
+    }
+
+    // --- CheckpointsConfig tests ---
+
+    #[test]
+    fn test_checkpoints_config_defaults_and_merge() {
+        let config: KildConfig = toml::from_str("[checkpoints]\non_stop = true\n").unwrap();
+        assert!(config.checkpoints.on_stop());
+        assert!(!config.checkpoints.before_inject());
+        assert_eq!(config.checkpoints.keep(), 20);
+
+        let override_config = CheckpointsConfig {
+            on_stop: Some(false),
+            keep: Some(5),
+            ..Default::default()
+        };
+        let merged = CheckpointsConfig::merge(&config.checkpoints, &override_config);
+        assert!(!merged.on_stop());
+        assert_eq!(merged.keep(), 5);
+    }
+
+    // --- ApprovalsConfig tests ---
+
+    #[test]
+    fn test_approvals_config_from_toml() {
+        let config: KildConfig = toml::from_str(
+            r#"
+[approvals]
+allow = ["Bash(cargo test*)"]
+deny = ["Bash(git push*)"]
+"#,
+        )
+        .unwrap();
+        assert_eq!(config.approvals.allow, vec!["Bash(cargo test*)"]);
+        assert_eq!(config.approvals.deny, vec!["Bash(git push*)"]);
+        assert_eq!(config.approvals.timeout_secs(), 120);
+    }
+
+    #[test]
+    fn test_approvals_config_merge_combines_rules() {
+        let base = ApprovalsConfig {
+            allow: vec!["Read".to_string(), "Bash(ls*)".to_string()],
+            deny: vec![],
+            timeout_secs: Some(30),
+        };
+        let override_config = ApprovalsConfig {
+            allow: vec!["Bash(ls*)".to_string(), "Bash(cargo test*)".to_string()],
+            deny: vec!["Bash(rm *)".to_string()],
+            timeout_secs: None,
+        };
+        let merged = ApprovalsConfig::merge(&base, &override_config);
+        assert_eq!(merged.allow, vec!["Read", "Bash(ls*)", "Bash(cargo test*)"]);
+        assert_eq!(merged.deny, vec!["Bash(rm *)"]);
+        assert_eq!(merged.timeout_secs(), 30);
+    }
+
+    // --- SharedCacheConfig tests ---
+
+    #[test]
+    fn test_shared_cache_config_from_toml() {
+        let config: KildConfig = toml::from_str(
+            r#"
+[worktree.shared_cache]
+enabled = true
+node_modules = false
+method = "hardlink"
+"#,
+        )
+        .unwrap();
+        let cache = &config.worktree.shared_cache;
+        assert!(cache.enabled());
+        assert!(cache.cargo_target());
+        assert!(!cache.node_modules());
+        assert_eq!(cache.method(), CacheLinkMethod::Hardlink);
+    }
+
+    #[test]
+    fn test_shared_cache_config_off_by_default() {
+        let cache = KildConfig::default().worktree.shared_cache;
+        assert!(!cache.enabled());
+        assert!(!cache.cargo_target());
+        assert!(!cache.node_modules());
+        assert_eq!(cache.method(), CacheLinkMethod::Auto);
+    }
+
+    #[test]
+    fn test_shared_cache_config_merge() {
+        let base = SharedCacheConfig {
+            enabled: Some(true),
+            method: Some(CacheLinkMethod::Reflink),
+            ..Default::default()
+        };
+        let project = SharedCacheConfig {
+            cargo_target: Some(false),
+            ..Default::default()
+        };
+        let merged = SharedCacheConfig::merge(&base, &project);
+        assert!(merged.enabled());
+        assert!(!merged.cargo_target());
+        assert_eq!(merged.method(), CacheLinkMethod::Reflink);
+    }
+
+    // --- NotifyConfig tests ---
+
+    #[test]
+    fn test_notify_config_defaults() {
+        let config = NotifyConfig::default();
+        assert!(config.desktop());
+        assert_eq!(config.events(), &[NotifyEvent::Waiting, NotifyEvent::Error]);
+        assert!(config.wants(None, NotifyEvent::Waiting));
+        assert!(!config.wants(None, NotifyEvent::Done));
+    }
+
+    #[test]
+    fn test_notify_config_from_toml() {
+        let config: KildConfig = toml::from_str(
+            r#"
+[notify]
+desktop = false
+events = ["waiting", "crashed"]
+
+[[notify.webhook]]
+url = "https://hooks.example.com/kild"
+headers = { Authorization = "Bearer abc" }
+events = ["done"]
+
+[[notify.push]]
+url = "https://ntfy.sh/my-kilds"
+
+[[notify.push]]
+url = "https://gotify.example.com"
+service = "gotify"
+token = "app-token"
+
+[[notify.command]]
+command = "logger kild"
+"#,
+        )
+        .unwrap();
+        let notify = &config.notify;
+        assert!(!notify.desktop());
+        assert_eq!(notify.webhook.len(), 1);
+        assert_eq!(notify.webhook[0].headers["Authorization"], "Bearer abc");
+        assert!(notify.wants(notify.webhook[0].events.as_deref(), NotifyEvent::Done));
+        assert!(!notify.wants(notify.webhook[0].events.as_deref(), NotifyEvent::Waiting));
+        assert_eq!(notify.push[0].service, PushService::Ntfy);
+        assert_eq!(notify.push[1].service, PushService::Gotify);
+        assert_eq!(notify.push[1].token.as_deref(), Some("app-token"));
+        assert!(notify.wants(notify.command[0].events.as_deref(), NotifyEvent::Crashed));
+    }
+
+    #[test]
+    fn test_notify_config_rejects_unknown_event() {
+        let result: Result<KildConfig, _> = toml::from_str("[notify]\nevents = [\"finished\"]\n");
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn test_notify_config_merge_combines_channels() {
+        let base: NotifyConfig =
+            toml::from_str("events = [\"done\"]\n[[webhook]]\nurl = \"http://a\"\n").unwrap();
+        let override_config: NotifyConfig =
+            toml::from_str("desktop = false\n[[webhook]]\nurl = \"http://b\"\n").unwrap();
+        let merged = NotifyConfig::merge(&base, &override_config);
+        assert!(!merged.desktop());
+        assert_eq!(merged.events(), &[NotifyEvent::Done]);
+        let urls: Vec<&str> = merged.webhook.iter().map(|w| w.url.as_str()).collect();
+        assert_eq!(urls, vec!["http://a", "http://b"]);
     }
 }
@@ -8,7 +8,17 @@ use crate::errors::ConfigError;
 use crate::types::KildConfig;
 
 /// Valid terminal emulator names.
-pub const VALID_TERMINALS: [&str; 5] = ["iterm2", "iterm", "terminal", "ghostty", "native"];
+pub const VALID_TERMINALS: [&str; 9] = [
+    "iterm2",
+    "iterm",
+    "terminal",
+    "ghostty",
+    "alacritty",
+    "wezterm",
+    "kitty",
+    "tmux",
+    "native",
+];
 
 /// Validate a KildConfig, returning an error if any values are invalid.
 ///
@@ -17,6 +27,7 @@ pub const VALID_TERMINALS: [&str; 5] = ["iterm2", "iterm", "terminal", "ghostty"
 /// - Agent name must be a known agent
 /// - Terminal preference, if set, should be a valid terminal name (warning only)
 /// - Include patterns, if configured, must be valid
+/// - `[[daemon.hosts]]` entries must have unique names, ports and fingerprints
 ///
 /// # Errors
 ///
@@ -53,6 +64,16 @@ pub fn validate_config(config: &KildConfig) -> Result<(), ConfigError> {
         });
     }
 
+    config
+        .daemon
+        .validate_hosts()
+        .map_err(|message| ConfigError::InvalidConfiguration { message })?;
+
+    config
+        .ui
+        .validate()
+        .map_err(|message| ConfigError::InvalidConfiguration { message })?;
+
     Ok(())
 }
 
@@ -104,6 +125,9 @@ mod tests {
         assert!(VALID_TERMINALS.contains(&"iterm"));
         assert!(VALID_TERMINALS.contains(&"terminal"));
         assert!(VALID_TERMINALS.contains(&"ghostty"));
+        assert!(VALID_TERMINALS.contains(&"wezterm"));
+        assert!(VALID_TERMINALS.contains(&"kitty"));
+        assert!(VALID_TERMINALS.contains(&"tmux"));
         assert!(VALID_TERMINALS.contains(&"native"));
         assert!(!VALID_TERMINALS.contains(&"invalid"));
     }
@@ -137,6 +161,7 @@ mod tests {
             patterns: vec!["[invalid-glob".to_string()],
             enabled: true,
             max_file_size: None,
+            ..Default::default()
         });
         let result = validate_config(&config);
         assert!(result.is_err());
@@ -154,6 +179,7 @@ mod tests {
             patterns: vec![".env*".to_string(), "*.local.json".to_string()],
             enabled: true,
             max_file_size: None,
+            ..Default::default()
         });
         assert!(validate_config(&config).is_ok());
     }
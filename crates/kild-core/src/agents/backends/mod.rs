@@ -45,6 +45,8 @@ macro_rules! define_agent_backend {
         binary: $binary:expr,
         command: $cmd:expr,
         process_patterns: [$($pat:expr),+ $(,)?],
+        prompt_patterns: [$($prompt:expr),* $(,)?],
+        busy_patterns: [$($busy:expr),* $(,)?],
         yolo_flags: $yolo:expr
     ) => {
         pub struct $struct_name;
@@ -70,6 +72,14 @@ macro_rules! define_agent_backend {
                 vec![$($pat.to_string()),+]
             }
 
+            fn prompt_patterns(&self) -> &'static [&'static str] {
+                &[$($prompt),*]
+            }
+
+            fn busy_patterns(&self) -> &'static [&'static str] {
+                &[$($busy),*]
+            }
+
             fn yolo_flags(&self) -> Option<&'static str> {
                 Some($yolo)
             }
@@ -98,7 +108,9 @@ macro_rules! define_agent_backend {
         display_name: $display:expr,
         binary: $binary:expr,
         command: $cmd:expr,
-        process_patterns: [$($pat:expr),+ $(,)?]
+        process_patterns: [$($pat:expr),+ $(,)?],
+        prompt_patterns: [$($prompt:expr),* $(,)?],
+        busy_patterns: [$($busy:expr),* $(,)?]
     ) => {
         pub struct $struct_name;
 
@@ -122,6 +134,14 @@ macro_rules! define_agent_backend {
             fn process_patterns(&self) -> Vec<String> {
                 vec![$($pat.to_string()),+]
             }
+
+            fn prompt_patterns(&self) -> &'static [&'static str] {
+                &[$($prompt),*]
+            }
+
+            fn busy_patterns(&self) -> &'static [&'static str] {
+                &[$($busy),*]
+            }
         }
 
         #[cfg(test)]
@@ -149,6 +169,8 @@ mod amp {
         binary: "amp",
         command: "amp",
         process_patterns: ["amp"],
+        prompt_patterns: [],
+        busy_patterns: [],
         yolo_flags: "--dangerously-allow-all"
     );
 }
@@ -161,6 +183,8 @@ mod claude {
         binary: "claude",
         command: "claude",
         process_patterns: ["claude", "claude-code"],
+        prompt_patterns: ["? for shortcuts"],
+        busy_patterns: ["esc to interrupt"],
         yolo_flags: "--dangerously-skip-permissions"
     );
 }
@@ -173,6 +197,8 @@ mod codex {
         binary: "codex",
         command: "codex",
         process_patterns: ["codex"],
+        prompt_patterns: ["⏎ send", "? for shortcuts"],
+        busy_patterns: ["esc to interrupt"],
         yolo_flags: "--yolo"
     );
 }
@@ -185,6 +211,8 @@ mod gemini {
         binary: "gemini",
         command: "gemini",
         process_patterns: ["gemini", "gemini-cli"],
+        prompt_patterns: ["type your message"],
+        busy_patterns: ["esc to cancel"],
         yolo_flags: "--yolo --approval-mode yolo"
     );
 }
@@ -197,6 +225,8 @@ mod kiro {
         binary: "kiro-cli",
         command: "kiro-cli chat",
         process_patterns: ["kiro-cli", "kiro"],
+        prompt_patterns: [],
+        busy_patterns: [],
         yolo_flags: "--trust-all-tools"
     );
 }
@@ -208,7 +238,9 @@ mod opencode {
         display_name: "OpenCode",
         binary: "opencode",
         command: "opencode",
-        process_patterns: ["opencode"]
+        process_patterns: ["opencode"],
+        prompt_patterns: ["enter send"],
+        busy_patterns: ["esc interrupt"]
     );
 }
 
@@ -40,7 +40,7 @@ pub use errors::AgentError;
 pub use registry::{
     default_agent_name, default_agent_type, get_agent, get_agent_by_type, get_all_process_patterns,
     get_default_command, get_process_patterns, get_yolo_flags, is_agent_available, is_claude_agent,
-    is_valid_agent, supported_agents_string, valid_agent_names,
+    is_valid_agent, supported_agents_string, valid_agent_names, with_yolo_flags,
 };
 pub use traits::AgentBackend;
 pub use types::AgentType;
@@ -95,6 +95,18 @@ pub fn get_yolo_flags(name: &str) -> Option<&'static str> {
     get_agent(name).and_then(|backend| backend.yolo_flags())
 }
 
+/// Agent flags for `--yolo`: the agent's yolo flags followed by `flags`.
+///
+/// Returns `None` when the agent has no yolo mode; callers then keep
+/// `flags` as they are and warn.
+pub fn with_yolo_flags(name: &str, flags: Option<&str>) -> Option<String> {
+    let yolo = get_yolo_flags(name)?;
+    Some(match flags {
+        Some(existing) => format!("{} {}", yolo, existing),
+        None => yolo.to_string(),
+    })
+}
+
 /// Check if the agent is Claude Code (case-insensitive).
 ///
 /// Used to determine whether the Claude Code inbox fast-path should be used
@@ -238,6 +250,16 @@ mod tests {
         assert_eq!(get_yolo_flags("unknown"), None);
     }
 
+    #[test]
+    fn test_with_yolo_flags() {
+        assert_eq!(with_yolo_flags("codex", None).as_deref(), Some("--yolo"));
+        assert_eq!(
+            with_yolo_flags("claude", Some("--model opus")).as_deref(),
+            Some("--dangerously-skip-permissions --model opus")
+        );
+        assert_eq!(with_yolo_flags("opencode", Some("--x")), None);
+    }
+
     /// Test the yolo flag merging logic used by CLI create command.
     /// Yolo flags should be prepended to existing user flags.
     #[test]
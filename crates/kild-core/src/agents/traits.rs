@@ -24,6 +24,21 @@ pub trait AgentBackend: Send + Sync {
     /// as process name.
     fn process_patterns(&self) -> Vec<String>;
 
+    /// Screen text shown while the agent sits idle at its input prompt.
+    ///
+    /// Matched case-insensitively against the tail of the PTY scrollback by
+    /// PTY message delivery, which waits for one of these before typing.
+    /// Empty when the agent's prompt can't be recognised reliably.
+    fn prompt_patterns(&self) -> &'static [&'static str] {
+        &[]
+    }
+
+    /// Screen text shown while the agent is processing a turn
+    /// (e.g. "esc to interrupt"). Used to confirm a delivered message was picked up.
+    fn busy_patterns(&self) -> &'static [&'static str] {
+        &[]
+    }
+
     /// Returns the CLI flags for "yolo mode" (full autonomy, skip all permission prompts).
     /// Returns `None` if the agent doesn't support autonomous mode.
     fn yolo_flags(&self) -> Option<&'static str> {
@@ -67,5 +82,7 @@ mod tests {
         assert!(backend.is_available());
         assert_eq!(backend.default_command(), "mock-cli");
         assert_eq!(backend.yolo_flags(), None);
+        assert!(backend.prompt_patterns().is_empty());
+        assert!(backend.busy_patterns().is_empty());
     }
 }
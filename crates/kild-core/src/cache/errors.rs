@@ -0,0 +1,61 @@
+use crate::errors::KildError;
+
+#[derive(Debug, thiserror::Error)]
+pub enum CacheError {
+    #[error("Git operation failed: {source}")]
+    GitError {
+        #[from]
+        source: crate::git::errors::GitError,
+    },
+
+    #[error("Session operation failed: {source}")]
+    SessionError {
+        #[from]
+        source: crate::sessions::errors::SessionError,
+    },
+
+    #[error("IO error in shared cache: {source}")]
+    IoError {
+        #[from]
+        source: std::io::Error,
+    },
+}
+
+impl KildError for CacheError {
+    fn error_code(&self) -> &'static str {
+        match self {
+            CacheError::GitError { .. } => "CACHE_GIT_ERROR",
+            CacheError::SessionError { .. } => "CACHE_SESSION_ERROR",
+            CacheError::IoError { .. } => "CACHE_IO_ERROR",
+        }
+    }
+
+    fn is_user_error(&self) -> bool {
+        matches!(
+            self,
+            CacheError::GitError {
+                source: crate::git::errors::GitError::NotInRepository
+            }
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_cache_error_codes() {
+        let error = CacheError::GitError {
+            source: crate::git::errors::GitError::NotInRepository,
+        };
+        assert_eq!(error.error_code(), "CACHE_GIT_ERROR");
+        assert!(error.is_user_error());
+
+        let error = CacheError::IoError {
+            source: std::io::Error::other("disk full"),
+        };
+        assert_eq!(error.error_code(), "CACHE_IO_ERROR");
+        assert!(!error.is_user_error());
+    }
+}
@@ -0,0 +1,632 @@
+use std::collections::HashSet;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use kild_config::{CacheLinkMethod, Config, SharedCacheConfig};
+use tracing::{debug, info, warn};
+
+use crate::cache::{errors::CacheError, operations, types::*};
+use crate::git;
+use crate::git::types::GitProjectState;
+use crate::sessions::persistence;
+
+/// Set up shared caches for a freshly created worktree.
+///
+/// Best-effort: every failure is logged and reported in `skipped`, never
+/// returned — a kild without shared caches still works, it just builds from
+/// scratch.
+pub fn setup_shared_cache(
+    project: &GitProjectState,
+    worktree: &Path,
+    config: &SharedCacheConfig,
+) -> CacheSetup {
+    if !config.enabled() {
+        return CacheSetup::default();
+    }
+
+    info!(
+        event = "core.cache.setup_started",
+        project_id = project.id,
+        worktree = %worktree.display(),
+        method = ?config.method()
+    );
+
+    let mut setup = CacheSetup::default();
+    if config.cargo_target() {
+        setup.target = seed_cargo_target(&project.path, worktree, config.method());
+    }
+    if config.node_modules() {
+        let cache_dir = Config::new().paths().project_cache_dir(&project.id);
+        link_node_modules(
+            &project.path,
+            worktree,
+            &cache_dir,
+            config.method(),
+            &mut setup,
+        );
+    }
+
+    info!(
+        event = "core.cache.setup_completed",
+        project_id = project.id,
+        target_files = setup.target.map_or(0, |t| t.files),
+        linked = setup.linked.len(),
+        skipped = setup.skipped.len()
+    );
+    setup
+}
+
+/// Seed `worktree/target` from the main checkout's `target/`.
+///
+/// `incremental/` is left out: it is large, tied to the old source tree and
+/// rebuilt cheaply. Returns `None` when there is nothing to seed, the
+/// filesystem can't reflink under `auto`, or seeding failed (the partial
+/// clone is removed).
+fn seed_cargo_target(
+    main_checkout: &Path,
+    worktree: &Path,
+    method: CacheLinkMethod,
+) -> Option<CloneStats> {
+    let source = main_checkout.join("target");
+    let destination = worktree.join("target");
+    if !source.is_dir() || !worktree.join("Cargo.toml").is_file() || destination.exists() {
+        debug!(
+            event = "core.cache.target_seed_skipped",
+            source = %source.display()
+        );
+        return None;
+    }
+
+    match operations::clone_tree(&source, &destination, method, &["incremental"]) {
+        Ok(stats) => {
+            info!(
+                event = "core.cache.target_seed_completed",
+                files = stats.files,
+                bytes = stats.bytes,
+                method = ?stats.method
+            );
+            Some(stats)
+        }
+        Err(e)
+            if e.kind() == std::io::ErrorKind::Unsupported && method == CacheLinkMethod::Auto =>
+        {
+            info!(
+                event = "core.cache.target_seed_skipped",
+                source = %source.display(),
+                reason = "filesystem lacks reflink support"
+            );
+            let _ = fs::remove_dir_all(&destination);
+            None
+        }
+        Err(e) => {
+            warn!(
+                event = "core.cache.target_seed_failed",
+                source = %source.display(),
+                error = %e
+            );
+            let _ = fs::remove_dir_all(&destination);
+            None
+        }
+    }
+}
+
+/// Clone each installed `node_modules` of the main checkout into the worktree,
+/// through a lockfile-keyed snapshot, when the worktree's lockfile matches.
+fn link_node_modules(
+    main_checkout: &Path,
+    worktree: &Path,
+    cache_dir: &Path,
+    method: CacheLinkMethod,
+    setup: &mut CacheSetup,
+) {
+    for dir in operations::find_installed_packages(main_checkout) {
+        match link_package(main_checkout, worktree, cache_dir, &dir, method) {
+            Ok(()) => setup.linked.push(dir),
+            Err(reason) => {
+                debug!(
+                    event = "core.cache.node_modules_skipped",
+                    dir = %dir.display(),
+                    reason = %reason
+                );
+                setup.skipped.push((dir, reason));
+            }
+        }
+    }
+
+    if !setup.linked.is_empty()
+        && let Err(e) = operations::exclude_links(worktree, &setup.linked)
+    {
+        warn!(
+            event = "core.cache.git_exclude_failed",
+            worktree = %worktree.display(),
+            error = %e
+        );
+    }
+}
+
+fn link_package(
+    main_checkout: &Path,
+    worktree: &Path,
+    cache_dir: &Path,
+    dir: &Path,
+    method: CacheLinkMethod,
+) -> Result<(), String> {
+    let main_dir = main_checkout.join(dir);
+    let kild_dir = worktree.join(dir);
+    let main_lock = operations::find_lockfile(&main_dir).ok_or("no lockfile")?;
+    let kild_lock = kild_dir.join(main_lock.file_name().unwrap_or_default());
+    if !kild_lock.is_file() {
+        return Err("lockfile not in worktree".to_string());
+    }
+
+    let hash = operations::lockfile_hash(&main_lock).map_err(|e| e.to_string())?;
+    if operations::lockfile_hash(&kild_lock).map_err(|e| e.to_string())? != hash {
+        return Err("lockfile differs from main checkout".to_string());
+    }
+    if !operations::node_modules_is_current(&main_dir, &main_lock) {
+        return Err("main checkout's node_modules is older than its lockfile".to_string());
+    }
+    let clone = kild_dir.join("node_modules");
+    if fs::symlink_metadata(&clone).is_ok() {
+        return Err("node_modules already present".to_string());
+    }
+
+    let reason = |e: std::io::Error| match e.kind() {
+        std::io::ErrorKind::Unsupported => "filesystem lacks reflink support".to_string(),
+        _ => e.to_string(),
+    };
+    let snapshot = ensure_snapshot(
+        cache_dir,
+        dir,
+        &hash,
+        &main_dir.join("node_modules"),
+        method,
+    )
+    .map_err(|e| format!("snapshot failed: {}", reason(e)))?;
+
+    // Each kild gets its own clone rather than a link to the snapshot, so an
+    // install in one kild can't rewrite the modules of every other
+    operations::clone_tree(&snapshot, &clone, method, &[])
+        .and_then(|_| {
+            fs::write(
+                clone.join(operations::SNAPSHOT_MARKER),
+                snapshot.to_string_lossy().as_bytes(),
+            )
+        })
+        .map_err(|e| {
+            let _ = fs::remove_dir_all(&clone);
+            format!("clone failed: {}", reason(e))
+        })
+}
+
+/// Path of the `node_modules` snapshot for a package dir and lockfile hash:
+/// `<cache>/node_modules/<slot>/<hash>/node_modules`.
+fn snapshot_path(cache_dir: &Path, dir: &Path, hash: &str) -> PathBuf {
+    cache_dir
+        .join("node_modules")
+        .join(operations::snapshot_slot(dir))
+        .join(hash)
+        .join("node_modules")
+}
+
+/// Create the snapshot if it doesn't exist yet and return its path.
+///
+/// The clone goes to a temporary directory renamed into place, so
+/// concurrent `kild create`s never clone a half-written snapshot.
+fn ensure_snapshot(
+    cache_dir: &Path,
+    dir: &Path,
+    hash: &str,
+    source: &Path,
+    method: CacheLinkMethod,
+) -> std::io::Result<PathBuf> {
+    let snapshot = snapshot_path(cache_dir, dir, hash);
+    if snapshot.is_dir() {
+        return Ok(snapshot);
+    }
+
+    let entry = snapshot.parent().unwrap_or(cache_dir);
+    let slot = entry.parent().unwrap_or(cache_dir);
+    fs::create_dir_all(slot)?;
+    let staging = slot.join(format!("{}.tmp-{}", hash, std::process::id()));
+    let _ = fs::remove_dir_all(&staging);
+    fs::create_dir(&staging)?;
+
+    let result = operations::clone_tree(source, &staging.join("node_modules"), method, &[])
+        .and_then(|stats| {
+            fs::write(staging.join("source"), dir.to_string_lossy().as_bytes())?;
+            info!(
+                event = "core.cache.snapshot_created",
+                dir = %dir.display(),
+                hash = hash,
+                files = stats.files,
+                method = ?stats.method
+            );
+            fs::rename(&staging, entry)
+        });
+    if let Err(e) = result {
+        let _ = fs::remove_dir_all(&staging);
+        // Lost a race with another create — theirs is just as good
+        if !snapshot.is_dir() {
+            return Err(e);
+        }
+    }
+    Ok(snapshot)
+}
+
+/// A snapshot clone found in a kild worktree, with its marker file.
+struct FoundLink {
+    link: SharedLink,
+    marker: PathBuf,
+    target: PathBuf,
+}
+
+fn collect_links(cache_dir: &Path, kilds: &[(String, PathBuf)]) -> Vec<FoundLink> {
+    let mut found = Vec::new();
+    for (branch, worktree) in kilds {
+        for (dir, target) in operations::find_snapshot_clones(worktree) {
+            if !target.starts_with(cache_dir) {
+                continue;
+            }
+            let lockfile_hash = target
+                .parent()
+                .and_then(Path::file_name)
+                .map(|n| n.to_string_lossy().to_string())
+                .unwrap_or_default();
+            let current = operations::find_lockfile(&worktree.join(&dir))
+                .and_then(|lock| operations::lockfile_hash(&lock).ok());
+            let state = if current.as_deref() == Some(lockfile_hash.as_str()) {
+                LinkState::Shared
+            } else {
+                LinkState::Diverged
+            };
+            found.push(FoundLink {
+                marker: worktree
+                    .join(&dir)
+                    .join("node_modules")
+                    .join(operations::SNAPSHOT_MARKER),
+                link: SharedLink {
+                    branch: branch.clone(),
+                    dir,
+                    lockfile_hash,
+                    state,
+                },
+                target,
+            });
+        }
+    }
+    found
+}
+
+/// Snapshots in the cache: (entry dir holding `node_modules`, package dir, hash).
+fn list_snapshots(cache_dir: &Path) -> Vec<(PathBuf, PathBuf, String)> {
+    let mut snapshots = Vec::new();
+    let Ok(slots) = fs::read_dir(cache_dir.join("node_modules")) else {
+        return snapshots;
+    };
+    for slot in slots.flatten() {
+        let Ok(entries) = fs::read_dir(slot.path()) else {
+            continue;
+        };
+        for entry in entries.flatten() {
+            let hash = entry.file_name().to_string_lossy().to_string();
+            // In-flight snapshots of a concurrent create
+            if hash.contains(".tmp-") || !entry.path().join("node_modules").is_dir() {
+                continue;
+            }
+            let dir = fs::read_to_string(entry.path().join("source"))
+                .map(PathBuf::from)
+                .unwrap_or_else(|_| PathBuf::from(slot.file_name()));
+            snapshots.push((entry.path(), dir, hash));
+        }
+    }
+    snapshots.sort();
+    snapshots
+}
+
+fn status_in(project: &str, cache_dir: &Path, kilds: &[(String, PathBuf)]) -> CacheStatus {
+    let links = collect_links(cache_dir, kilds);
+    let snapshots: Vec<Snapshot> = list_snapshots(cache_dir)
+        .into_iter()
+        .map(|(entry, dir, lockfile_hash)| {
+            let path = entry.join("node_modules");
+            Snapshot {
+                used_by: links
+                    .iter()
+                    .filter(|l| l.target == path)
+                    .map(|l| l.link.branch.clone())
+                    .collect(),
+                bytes: crate::cleanup::measure_disk_usage(&path).total_bytes,
+                dir,
+                lockfile_hash,
+                path,
+            }
+        })
+        .collect();
+
+    CacheStatus {
+        project: project.to_string(),
+        cache_dir: cache_dir.to_path_buf(),
+        total_bytes: snapshots.iter().map(|s| s.bytes).sum(),
+        snapshots,
+        links: links.into_iter().map(|l| l.link).collect(),
+    }
+}
+
+fn prune_in(cache_dir: &Path, kilds: &[(String, PathBuf)]) -> PruneReport {
+    let mut report = PruneReport::default();
+    let mut in_use = HashSet::new();
+
+    for found in collect_links(cache_dir, kilds) {
+        if found.link.state == LinkState::Shared {
+            in_use.insert(found.target);
+            continue;
+        }
+        // The kild keeps its modules; it just stops counting as a user
+        match fs::remove_file(&found.marker) {
+            Ok(()) => {
+                info!(
+                    event = "core.cache.diverged_link_removed",
+                    branch = %found.link.branch,
+                    path = %found.marker.display()
+                );
+                report.unlinked.push(found.link);
+            }
+            Err(e) => {
+                warn!(
+                    event = "core.cache.diverged_link_remove_failed",
+                    path = %found.marker.display(),
+                    error = %e
+                );
+                in_use.insert(found.target);
+            }
+        }
+    }
+
+    for (entry, _, _) in list_snapshots(cache_dir) {
+        if in_use.contains(&entry.join("node_modules")) {
+            continue;
+        }
+        let bytes = crate::cleanup::measure_disk_usage(&entry).total_bytes;
+        match fs::remove_dir_all(&entry) {
+            Ok(()) => {
+                info!(
+                    event = "core.cache.snapshot_removed",
+                    path = %entry.display(),
+                    bytes = bytes
+                );
+                report.freed_bytes += bytes;
+                report.removed_snapshots.push(entry);
+            }
+            Err(e) => warn!(
+                event = "core.cache.snapshot_remove_failed",
+                path = %entry.display(),
+                error = %e
+            ),
+        }
+    }
+    report
+}
+
+/// Worktrees of the current project's kilds: (branch, worktree path).
+fn project_kilds() -> Result<(GitProjectState, Vec<(String, PathBuf)>), CacheError> {
+    let project = git::detect_project()?;
+    let (sessions, _) = persistence::load_sessions_from_files(&Config::new().sessions_dir())?;
+    let kilds = sessions
+        .into_iter()
+        .filter(|s| *s.project_id == *project.id && !s.use_main_worktree)
+        .map(|s| (s.branch.to_string(), s.worktree_path))
+        .collect();
+    Ok((project, kilds))
+}
+
+/// Shared cache snapshots and links of the current project.
+pub fn cache_status() -> Result<CacheStatus, CacheError> {
+    let (project, kilds) = project_kilds()?;
+    let cache_dir = Config::new().paths().project_cache_dir(&project.id);
+    Ok(status_in(&project.name, &cache_dir, &kilds))
+}
+
+/// Stop tracking diverged `node_modules` clones and delete snapshots no kild
+/// matches.
+pub fn prune_cache() -> Result<PruneReport, CacheError> {
+    let (project, kilds) = project_kilds()?;
+    let cache_dir = Config::new().paths().project_cache_dir(&project.id);
+    info!(event = "core.cache.prune_started", project_id = project.id);
+    let report = prune_in(&cache_dir, &kilds);
+    info!(
+        event = "core.cache.prune_completed",
+        unlinked = report.unlinked.len(),
+        removed_snapshots = report.removed_snapshots.len(),
+        freed_bytes = report.freed_bytes
+    );
+    Ok(report)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+
+    /// Main checkout with an installed root package, plus an empty worktree.
+    struct Fixture {
+        _dir: TempDir,
+        main: PathBuf,
+        worktree: PathBuf,
+        cache: PathBuf,
+    }
+
+    impl Fixture {
+        fn new() -> Self {
+            let dir = TempDir::new().unwrap();
+            let main = dir.path().join("main");
+            let worktree = dir.path().join("kild");
+            let cache = dir.path().join("cache");
+            fs::create_dir_all(main.join("node_modules/left-pad")).unwrap();
+            fs::write(main.join("node_modules/left-pad/index.js"), "pad").unwrap();
+            fs::write(main.join("package-lock.json"), "v1").unwrap();
+            fs::write(main.join("node_modules/.package-lock.json"), "v1").unwrap();
+            fs::create_dir_all(&worktree).unwrap();
+            fs::write(worktree.join("package-lock.json"), "v1").unwrap();
+            Self {
+                _dir: dir,
+                main,
+                worktree,
+                cache,
+            }
+        }
+    }
+
+    #[test]
+    fn test_link_node_modules_clones_snapshot_when_lockfiles_match() {
+        let fx = Fixture::new();
+        let mut setup = CacheSetup::default();
+        link_node_modules(
+            &fx.main,
+            &fx.worktree,
+            &fx.cache,
+            CacheLinkMethod::Hardlink,
+            &mut setup,
+        );
+
+        assert_eq!(setup.linked, vec![PathBuf::new()]);
+        let modules = fx.worktree.join("node_modules");
+        assert!(fs::symlink_metadata(&modules).unwrap().is_dir());
+        let snapshot = fs::read_to_string(modules.join(operations::SNAPSHOT_MARKER)).unwrap();
+        let snapshot = PathBuf::from(snapshot);
+        assert!(snapshot.starts_with(fx.cache.join("node_modules/_root")));
+        assert_eq!(
+            fs::read_to_string(modules.join("left-pad/index.js")).unwrap(),
+            "pad"
+        );
+
+        // Installs in the kild never reach the snapshot other kilds clone
+        fs::create_dir(modules.join("is-odd")).unwrap();
+        fs::remove_dir_all(modules.join("left-pad")).unwrap();
+        assert!(!snapshot.join("is-odd").exists());
+        assert!(snapshot.join("left-pad/index.js").is_file());
+        assert!(!snapshot.join(operations::SNAPSHOT_MARKER).exists());
+    }
+
+    #[test]
+    fn test_link_node_modules_skips_diverged_lockfile() {
+        let fx = Fixture::new();
+        fs::write(fx.worktree.join("package-lock.json"), "v2").unwrap();
+        let mut setup = CacheSetup::default();
+        link_node_modules(
+            &fx.main,
+            &fx.worktree,
+            &fx.cache,
+            CacheLinkMethod::Hardlink,
+            &mut setup,
+        );
+
+        assert!(setup.linked.is_empty());
+        assert_eq!(
+            setup.skipped,
+            vec![(
+                PathBuf::new(),
+                "lockfile differs from main checkout".to_string()
+            )]
+        );
+        assert!(!fx.worktree.join("node_modules").exists());
+    }
+
+    #[test]
+    fn test_status_and_prune_handle_divergence() {
+        let fx = Fixture::new();
+        let mut setup = CacheSetup::default();
+        link_node_modules(
+            &fx.main,
+            &fx.worktree,
+            &fx.cache,
+            CacheLinkMethod::Hardlink,
+            &mut setup,
+        );
+        let kilds = vec![("feat".to_string(), fx.worktree.clone())];
+
+        let status = status_in("app", &fx.cache, &kilds);
+        assert_eq!(status.snapshots.len(), 1);
+        assert_eq!(status.snapshots[0].used_by, vec!["feat".to_string()]);
+        assert_eq!(status.links[0].state, LinkState::Shared);
+
+        // Nothing to prune while the kild still matches
+        assert_eq!(prune_in(&fx.cache, &kilds), PruneReport::default());
+
+        // The kild bumps a dependency: its link no longer matches
+        fs::write(fx.worktree.join("package-lock.json"), "v2").unwrap();
+        let status = status_in("app", &fx.cache, &kilds);
+        assert_eq!(status.links[0].state, LinkState::Diverged);
+
+        let report = prune_in(&fx.cache, &kilds);
+        assert_eq!(report.unlinked.len(), 1);
+        assert_eq!(report.removed_snapshots.len(), 1);
+        // The kild keeps its own modules
+        assert!(fx.worktree.join("node_modules/left-pad/index.js").is_file());
+        let status = status_in("app", &fx.cache, &kilds);
+        assert!(status.snapshots.is_empty());
+        assert!(status.links.is_empty());
+    }
+
+    #[test]
+    fn test_seed_cargo_target_requires_cargo_project() {
+        let fx = Fixture::new();
+        fs::create_dir_all(fx.main.join("target/debug")).unwrap();
+        fs::write(fx.main.join("target/debug/app"), "bin").unwrap();
+
+        assert_eq!(
+            seed_cargo_target(&fx.main, &fx.worktree, CacheLinkMethod::Hardlink),
+            None
+        );
+
+        fs::write(fx.worktree.join("Cargo.toml"), "[package]").unwrap();
+        let stats = seed_cargo_target(&fx.main, &fx.worktree, CacheLinkMethod::Hardlink).unwrap();
+        assert_eq!(stats.files, 1);
+        assert!(fx.worktree.join("target/debug/app").is_file());
+
+        // Never clobbers an existing target/
+        assert_eq!(
+            seed_cargo_target(&fx.main, &fx.worktree, CacheLinkMethod::Hardlink),
+            None
+        );
+    }
+
+    #[test]
+    fn test_seed_cargo_target_auto_never_shares_writes() {
+        let fx = Fixture::new();
+        fs::write(fx.worktree.join("Cargo.toml"), "[package]").unwrap();
+        fs::create_dir_all(fx.main.join("target/debug")).unwrap();
+        let source = fx.main.join("target/debug/app");
+        fs::write(&source, "bin").unwrap();
+
+        // Without reflinks, auto skips rather than byte-copying target/
+        let Some(stats) = seed_cargo_target(&fx.main, &fx.worktree, CacheLinkMethod::Auto) else {
+            assert!(!fx.worktree.join("target").exists());
+            return;
+        };
+        assert_eq!(stats.method, Some(LinkKind::Reflink));
+        let seeded = fx.worktree.join("target/debug/app");
+        assert_eq!(
+            fs::metadata(&seeded).unwrap().modified().unwrap(),
+            fs::metadata(&source).unwrap().modified().unwrap()
+        );
+
+        // A build rewriting the seeded file in place leaves the main checkout alone
+        fs::write(&seeded, "rebuilt").unwrap();
+        assert_eq!(fs::read_to_string(&source).unwrap(), "bin");
+    }
+
+    #[test]
+    fn test_setup_shared_cache_disabled_is_noop() {
+        let fx = Fixture::new();
+        let project = GitProjectState {
+            id: "p".to_string(),
+            name: "app".to_string(),
+            path: fx.main.clone(),
+            remote_url: None,
+        };
+        let setup = setup_shared_cache(&project, &fx.worktree, &SharedCacheConfig::default());
+        assert_eq!(setup, CacheSetup::default());
+        assert!(!fx.worktree.join("node_modules").exists());
+    }
+}
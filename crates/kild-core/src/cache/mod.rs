@@ -0,0 +1,20 @@
+//! Shared build caches for kild worktrees (`[worktree.shared_cache]`).
+//!
+//! At creation time a kild's `target/` is seeded from the main checkout with
+//! reflinks or hardlinks, and each `node_modules` whose lockfile matches the
+//! main checkout's is cloned from a snapshot in `~/.kild/cache/<project>/`.
+//! Every kild gets its own clone, so installs never leak between kilds.
+//! Snapshots are keyed by lockfile hash, so a kild whose lockfile later
+//! diverges is reported by `kild cache status`, and `kild cache prune`
+//! deletes snapshots no kild matches.
+
+pub mod errors;
+pub mod handler;
+mod operations;
+pub mod types;
+
+pub use errors::CacheError;
+pub use handler::{cache_status, prune_cache, setup_shared_cache};
+pub use types::{
+    CacheSetup, CacheStatus, CloneStats, LinkState, PruneReport, SharedLink, Snapshot,
+};
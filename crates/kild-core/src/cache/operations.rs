@@ -0,0 +1,391 @@
+//! Filesystem operations behind the shared cache: cloning trees with
+//! reflinks or hardlinks, finding installed `node_modules` and their lockfiles,
+//! and keeping cloned `node_modules` out of `git status`.
+
+use std::fs;
+use std::io::{self, Write};
+use std::path::{Path, PathBuf};
+use std::time::SystemTime;
+
+use kild_config::CacheLinkMethod;
+use sha2::{Digest, Sha256};
+use tracing::debug;
+
+use crate::cache::types::{CloneStats, LinkKind};
+use crate::files::operations::reflink_file;
+
+/// Lockfiles that pin a `node_modules` install, in lookup order.
+pub(super) const LOCKFILES: &[&str] = &[
+    "package-lock.json",
+    "npm-shrinkwrap.json",
+    "pnpm-lock.yaml",
+    "yarn.lock",
+    "bun.lock",
+    "bun.lockb",
+];
+
+/// Files package managers rewrite on every install.
+const INSTALL_MARKERS: &[&str] = &[
+    ".package-lock.json",
+    ".yarn-integrity",
+    ".yarn-state.yml",
+    ".modules.yaml",
+];
+
+/// File inside a kild's cloned `node_modules` naming the snapshot it was
+/// cloned from.
+pub(super) const SNAPSHOT_MARKER: &str = ".kild-snapshot";
+
+/// How deep below the checkout root to look for JS packages.
+const MAX_DEPTH: usize = 3;
+
+/// Lockfile in `dir`, if any.
+pub(super) fn find_lockfile(dir: &Path) -> Option<PathBuf> {
+    LOCKFILES
+        .iter()
+        .map(|name| dir.join(name))
+        .find(|path| path.is_file())
+}
+
+/// Short SHA-256 of a lockfile's contents.
+pub(super) fn lockfile_hash(path: &Path) -> io::Result<String> {
+    let digest = Sha256::digest(fs::read(path)?);
+    Ok(hex::encode(&digest[..8]))
+}
+
+/// Whether `dir/node_modules` was installed after `lockfile` last changed.
+pub(super) fn node_modules_is_current(dir: &Path, lockfile: &Path) -> bool {
+    let mtime = |p: &Path| fs::metadata(p).and_then(|m| m.modified()).ok();
+    let Some(lock_time) = mtime(lockfile) else {
+        return false;
+    };
+    let node_modules = dir.join("node_modules");
+    let installed = std::iter::once(node_modules.clone())
+        .chain(INSTALL_MARKERS.iter().map(|m| node_modules.join(m)))
+        .filter_map(|p| mtime(&p))
+        .max()
+        .unwrap_or(SystemTime::UNIX_EPOCH);
+    installed >= lock_time
+}
+
+/// Cache directory name for a package directory (`_root` for the checkout root).
+pub(super) fn snapshot_slot(dir: &Path) -> String {
+    if dir.as_os_str().is_empty() {
+        "_root".to_string()
+    } else {
+        dir.to_string_lossy().replace(['/', '\\'], "_")
+    }
+}
+
+/// Visit directories below `root` (relative paths, `""` for the root),
+/// skipping hidden, `node_modules` and `target` directories.
+fn walk_package_dirs(root: &Path, rel: &Path, depth: usize, visit: &mut impl FnMut(&Path)) {
+    visit(rel);
+    if depth == MAX_DEPTH {
+        return;
+    }
+    let Ok(entries) = fs::read_dir(root.join(rel)) else {
+        return;
+    };
+    for entry in entries.flatten() {
+        let name = entry.file_name();
+        let name_str = name.to_string_lossy();
+        let is_dir = entry.file_type().is_ok_and(|t| t.is_dir());
+        if !is_dir || name_str.starts_with('.') || name == "node_modules" || name == "target" {
+            continue;
+        }
+        walk_package_dirs(root, &rel.join(&name), depth + 1, visit);
+    }
+}
+
+/// Package directories under `root` with a lockfile and an installed
+/// (real, not symlinked) `node_modules`.
+pub(super) fn find_installed_packages(root: &Path) -> Vec<PathBuf> {
+    let mut found = Vec::new();
+    walk_package_dirs(root, Path::new(""), 0, &mut |rel| {
+        let dir = root.join(rel);
+        let installed = fs::symlink_metadata(dir.join("node_modules")).is_ok_and(|m| m.is_dir());
+        if installed && find_lockfile(&dir).is_some() {
+            found.push(rel.to_path_buf());
+        }
+    });
+    found
+}
+
+/// `node_modules` cloned from a snapshot under `root`: (package dir relative
+/// to root, snapshot path).
+pub(super) fn find_snapshot_clones(root: &Path) -> Vec<(PathBuf, PathBuf)> {
+    let mut found = Vec::new();
+    walk_package_dirs(root, Path::new(""), 0, &mut |rel| {
+        let marker = root.join(rel).join("node_modules").join(SNAPSHOT_MARKER);
+        if let Ok(snapshot) = fs::read_to_string(&marker) {
+            found.push((rel.to_path_buf(), PathBuf::from(snapshot)));
+        }
+    });
+    found
+}
+
+/// Clone the tree at `source` into a new directory `destination`, sharing
+/// file data via reflinks or hardlinks per `method`. Directories named in
+/// `skip` are left out; symlinks are recreated as-is.
+///
+/// Files are never byte-copied: a full copy costs as much disk as building
+/// from scratch. Without reflink support this fails with
+/// [`io::ErrorKind::Unsupported`] on the first file, before anything large
+/// was written.
+///
+/// Modification times are preserved so mtime-based freshness checks (cargo
+/// fingerprints) treat cloned artifacts as older than freshly checked-out
+/// sources rather than as just-built.
+pub(super) fn clone_tree(
+    source: &Path,
+    destination: &Path,
+    method: CacheLinkMethod,
+    skip: &[&str],
+) -> io::Result<CloneStats> {
+    let mut stats = CloneStats::default();
+    clone_dir(source, destination, method, skip, &mut stats)?;
+    Ok(stats)
+}
+
+fn clone_dir(
+    source: &Path,
+    destination: &Path,
+    method: CacheLinkMethod,
+    skip: &[&str],
+    stats: &mut CloneStats,
+) -> io::Result<()> {
+    fs::create_dir(destination)?;
+    for entry in fs::read_dir(source)? {
+        let entry = entry?;
+        let src = entry.path();
+        let dst = destination.join(entry.file_name());
+        let meta = fs::symlink_metadata(&src)?;
+        let file_type = meta.file_type();
+
+        if file_type.is_symlink() {
+            std::os::unix::fs::symlink(fs::read_link(&src)?, &dst)?;
+        } else if file_type.is_dir() {
+            if skip.iter().any(|s| entry.file_name() == *s) {
+                continue;
+            }
+            clone_dir(&src, &dst, method, skip, stats)?;
+        } else {
+            stats.method = Some(link_file(&src, &dst, method)?);
+            stats.files += 1;
+            stats.bytes += meta.len();
+        }
+    }
+    Ok(())
+}
+
+fn link_file(src: &Path, dst: &Path, method: CacheLinkMethod) -> io::Result<LinkKind> {
+    // Build tools rewrite files in place, so a hardlink would let a kild's
+    // build change the main checkout's. Only an explicit `hardlink` opts in.
+    if method == CacheLinkMethod::Hardlink {
+        fs::hard_link(src, dst)?;
+        return Ok(LinkKind::Hardlink);
+    }
+    reflink_file(src, dst).map_err(|e| {
+        debug!(
+            event = "core.cache.reflink_unsupported",
+            path = %src.display(),
+            error = %e
+        );
+        io::Error::new(io::ErrorKind::Unsupported, format!("reflink failed: {}", e))
+    })?;
+    Ok(LinkKind::Reflink)
+}
+
+/// Keep cloned `node_modules` in `dirs` out of `git status`.
+///
+/// Not every project ignores `node_modules`, and an untracked entry would
+/// make `kild destroy` think the kild has uncommitted work. Anchored paths are appended to the repository's shared
+/// `info/exclude`, which is harmless for other checkouts.
+pub(super) fn exclude_links(worktree: &Path, dirs: &[PathBuf]) -> io::Result<()> {
+    let repo = git2::Repository::open(worktree).map_err(io::Error::other)?;
+    // Linked worktrees point at the shared git dir via their `commondir` file
+    let git_dir = repo.path();
+    let common_dir = fs::read_to_string(git_dir.join("commondir"))
+        .map(|rel| git_dir.join(rel.trim()))
+        .unwrap_or_else(|_| git_dir.to_path_buf());
+    let exclude = common_dir.join("info").join("exclude");
+    let existing = fs::read_to_string(&exclude).unwrap_or_default();
+
+    let additions: Vec<String> = dirs
+        .iter()
+        .map(|dir| format!("/{}", dir.join("node_modules").display()))
+        .filter(|line| !existing.lines().any(|l| l == line))
+        .collect();
+    if additions.is_empty() {
+        return Ok(());
+    }
+
+    if let Some(parent) = exclude.parent() {
+        fs::create_dir_all(parent)?;
+    }
+    let mut file = fs::OpenOptions::new()
+        .create(true)
+        .append(true)
+        .open(&exclude)?;
+    if !existing.is_empty() && !existing.ends_with('\n') {
+        writeln!(file)?;
+    }
+    for line in additions {
+        writeln!(file, "{}", line)?;
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+
+    #[test]
+    fn test_find_installed_packages_skips_links_and_nested_modules() {
+        let dir = TempDir::new().unwrap();
+        let root = dir.path();
+        fs::create_dir_all(root.join("node_modules/dep/node_modules")).unwrap();
+        fs::write(root.join("package-lock.json"), "{}").unwrap();
+        fs::create_dir_all(root.join("web/node_modules")).unwrap();
+        fs::write(root.join("web/yarn.lock"), "").unwrap();
+        // No lockfile: not a package we can key
+        fs::create_dir_all(root.join("docs/node_modules")).unwrap();
+        // Symlinked node_modules is already shared
+        fs::create_dir_all(root.join("app")).unwrap();
+        fs::write(root.join("app/package-lock.json"), "{}").unwrap();
+        std::os::unix::fs::symlink(root.join("node_modules"), root.join("app/node_modules"))
+            .unwrap();
+
+        let mut found = find_installed_packages(root);
+        found.sort();
+        assert_eq!(found, vec![PathBuf::new(), PathBuf::from("web")]);
+
+        fs::write(
+            root.join("web/node_modules").join(SNAPSHOT_MARKER),
+            "/cache/x",
+        )
+        .unwrap();
+        assert_eq!(
+            find_snapshot_clones(root),
+            vec![(PathBuf::from("web"), PathBuf::from("/cache/x"))]
+        );
+    }
+
+    #[test]
+    fn test_lockfile_hash_tracks_content() {
+        let dir = TempDir::new().unwrap();
+        let lock = dir.path().join("yarn.lock");
+        fs::write(&lock, "a@1").unwrap();
+        let first = lockfile_hash(&lock).unwrap();
+        assert_eq!(first.len(), 16);
+        assert_eq!(lockfile_hash(&lock).unwrap(), first);
+        fs::write(&lock, "a@2").unwrap();
+        assert_ne!(lockfile_hash(&lock).unwrap(), first);
+    }
+
+    #[test]
+    fn test_node_modules_is_current_compares_install_markers() {
+        let dir = TempDir::new().unwrap();
+        let root = dir.path();
+        let lock = root.join("package-lock.json");
+        fs::create_dir_all(root.join("node_modules")).unwrap();
+        let marker = root.join("node_modules/.package-lock.json");
+        fs::write(&marker, "{}").unwrap();
+        fs::write(&lock, "{}").unwrap();
+
+        let set_mtime = |p: &Path, secs: u64| {
+            let time = SystemTime::UNIX_EPOCH + std::time::Duration::from_secs(secs);
+            fs::File::options()
+                .write(true)
+                .open(p)
+                .unwrap()
+                .set_modified(time)
+                .unwrap();
+        };
+        let set_dir_mtime = |p: &Path, secs: u64| {
+            let time = SystemTime::UNIX_EPOCH + std::time::Duration::from_secs(secs);
+            fs::File::open(p).unwrap().set_modified(time).unwrap();
+        };
+
+        set_dir_mtime(&root.join("node_modules"), 100);
+        set_mtime(&marker, 300);
+        set_mtime(&lock, 200);
+        assert!(node_modules_is_current(root, &lock));
+
+        set_mtime(&marker, 150);
+        assert!(!node_modules_is_current(root, &lock));
+    }
+
+    #[test]
+    fn test_snapshot_slot() {
+        assert_eq!(snapshot_slot(Path::new("")), "_root");
+        assert_eq!(snapshot_slot(Path::new("apps/web")), "apps_web");
+    }
+
+    #[test]
+    fn test_clone_tree_hardlinks_and_skips() {
+        use std::os::unix::fs::MetadataExt;
+
+        let dir = TempDir::new().unwrap();
+        let src = dir.path().join("target");
+        fs::create_dir_all(src.join("debug/deps")).unwrap();
+        fs::create_dir_all(src.join("debug/incremental/app-1")).unwrap();
+        fs::write(src.join("debug/deps/libfoo.rlib"), b"rlib").unwrap();
+        fs::write(src.join("debug/incremental/app-1/query"), b"x").unwrap();
+        std::os::unix::fs::symlink("deps/libfoo.rlib", src.join("debug/libfoo.rlib")).unwrap();
+
+        let dst = dir.path().join("clone");
+        let stats = clone_tree(&src, &dst, CacheLinkMethod::Hardlink, &["incremental"]).unwrap();
+
+        assert_eq!(stats.files, 1);
+        assert_eq!(stats.bytes, 4);
+        assert_eq!(stats.method, Some(LinkKind::Hardlink));
+        let ino = |p: &Path| fs::metadata(p).unwrap().ino();
+        assert_eq!(
+            ino(&dst.join("debug/deps/libfoo.rlib")),
+            ino(&src.join("debug/deps/libfoo.rlib"))
+        );
+        assert!(!dst.join("debug/incremental").exists());
+        assert_eq!(
+            fs::read_link(dst.join("debug/libfoo.rlib")).unwrap(),
+            PathBuf::from("deps/libfoo.rlib")
+        );
+    }
+
+    #[test]
+    fn test_exclude_links_appends_once() {
+        let dir = TempDir::new().unwrap();
+        git2::Repository::init(dir.path()).unwrap();
+
+        let dirs = vec![PathBuf::new(), PathBuf::from("web")];
+        exclude_links(dir.path(), &dirs).unwrap();
+        exclude_links(dir.path(), &dirs).unwrap();
+
+        let exclude = fs::read_to_string(dir.path().join(".git/info/exclude")).unwrap();
+        let count = |line: &str| exclude.lines().filter(|l| *l == line).count();
+        assert_eq!(count("/node_modules"), 1);
+        assert_eq!(count("/web/node_modules"), 1);
+    }
+
+    #[test]
+    fn test_exclude_links_from_linked_worktree_uses_common_dir() {
+        use crate::git::test_support::{
+            create_branch, create_worktree_for_branch, init_repo_with_commit,
+        };
+
+        let dir = TempDir::new().unwrap();
+        let main = dir.path().join("main");
+        let worktree = dir.path().join("kild");
+        fs::create_dir_all(&main).unwrap();
+        init_repo_with_commit(&main).unwrap();
+        create_branch(&main, "kild/feat").unwrap();
+        create_worktree_for_branch(&main, "kild-feat", &worktree, "kild/feat").unwrap();
+
+        exclude_links(&worktree, &[PathBuf::new()]).unwrap();
+
+        let exclude = fs::read_to_string(main.join(".git/info/exclude")).unwrap();
+        assert!(exclude.lines().any(|l| l == "/node_modules"));
+    }
+}
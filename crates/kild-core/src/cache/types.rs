@@ -0,0 +1,95 @@
+use std::path::PathBuf;
+
+use serde::Serialize;
+
+/// How cloned files ended up sharing disk with their source.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum LinkKind {
+    Reflink,
+    Hardlink,
+}
+
+impl std::fmt::Display for LinkKind {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            LinkKind::Reflink => write!(f, "reflink"),
+            LinkKind::Hardlink => write!(f, "hardlink"),
+        }
+    }
+}
+
+/// Result of cloning a directory tree.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize)]
+pub struct CloneStats {
+    pub files: u64,
+    pub bytes: u64,
+    /// `None` when the tree held no files.
+    pub method: Option<LinkKind>,
+}
+
+/// What shared-cache setup did for a new kild.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct CacheSetup {
+    /// Seeded `target/`, if any.
+    pub target: Option<CloneStats>,
+    /// Directories (relative to the worktree) whose `node_modules` was cloned
+    /// from a shared snapshot.
+    pub linked: Vec<PathBuf>,
+    /// Directories skipped, with the reason.
+    pub skipped: Vec<(PathBuf, String)>,
+}
+
+/// A lockfile-keyed `node_modules` snapshot in the project cache.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct Snapshot {
+    /// Directory (relative to the checkout) the snapshot was taken from.
+    pub dir: PathBuf,
+    /// Short hash of the lockfile the snapshot was installed from.
+    pub lockfile_hash: String,
+    pub path: PathBuf,
+    pub bytes: u64,
+    /// Branches whose worktree was cloned from this snapshot.
+    pub used_by: Vec<String>,
+}
+
+/// Whether a kild's lockfile still matches the snapshot it was cloned from.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum LinkState {
+    Shared,
+    /// The lockfile changed (or is gone) since cloning — the snapshot no
+    /// longer matches what the kild declares.
+    Diverged,
+}
+
+/// A kild `node_modules` cloned from a snapshot in the project cache.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct SharedLink {
+    pub branch: String,
+    /// Directory (relative to the worktree) holding the cloned `node_modules`.
+    pub dir: PathBuf,
+    pub lockfile_hash: String,
+    pub state: LinkState,
+}
+
+/// Shared cache state of the current project (`kild cache status`).
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct CacheStatus {
+    pub project: String,
+    pub cache_dir: PathBuf,
+    pub snapshots: Vec<Snapshot>,
+    pub links: Vec<SharedLink>,
+    pub total_bytes: u64,
+}
+
+/// What `kild cache prune` removed.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize)]
+pub struct PruneReport {
+    /// Diverged clones no longer counted as snapshot users. The kilds keep
+    /// their `node_modules`.
+    pub unlinked: Vec<SharedLink>,
+    /// Snapshots no kild matches any more.
+    pub removed_snapshots: Vec<PathBuf>,
+    pub freed_bytes: u64,
+}
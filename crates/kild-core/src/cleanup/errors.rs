@@ -38,6 +38,9 @@ pub enum CleanupError {
         source: std::io::Error,
     },
 
+    #[error("Cannot check PR status: {message}")]
+    ForgeUnavailable { message: String },
+
     #[error("Cleanup strategy '{strategy}' failed: {source}")]
     StrategyFailed {
         strategy: String,
@@ -58,6 +61,7 @@ impl KildError for CleanupError {
             CleanupError::GitError { .. } => "CLEANUP_GIT_ERROR",
             CleanupError::SessionError { .. } => "CLEANUP_SESSION_ERROR",
             CleanupError::IoError { .. } => "CLEANUP_IO_ERROR",
+            CleanupError::ForgeUnavailable { .. } => "CLEANUP_FORGE_UNAVAILABLE",
             CleanupError::StrategyFailed { .. } => "CLEANUP_STRATEGY_FAILED",
         }
     }
@@ -68,6 +72,7 @@ impl KildError for CleanupError {
             CleanupError::NotInRepository
                 | CleanupError::NoOrphanedResources
                 | CleanupError::PermissionDenied { .. }
+                | CleanupError::ForgeUnavailable { .. }
         )
     }
 }
@@ -97,6 +102,19 @@ mod tests {
         assert!(!error.is_user_error());
     }
 
+    #[test]
+    fn test_forge_unavailable_error() {
+        let error = CleanupError::ForgeUnavailable {
+            message: "no forge CLI found".to_string(),
+        };
+        assert_eq!(
+            error.to_string(),
+            "Cannot check PR status: no forge CLI found"
+        );
+        assert_eq!(error.error_code(), "CLEANUP_FORGE_UNAVAILABLE");
+        assert!(error.is_user_error());
+    }
+
     #[test]
     fn test_permission_denied_error() {
         let error = CleanupError::PermissionDenied {
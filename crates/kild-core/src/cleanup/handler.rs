@@ -307,6 +307,65 @@ pub fn scan_for_orphans_with_strategy(
                 summary.add_branch(branch);
             }
         }
+        CleanupStrategy::Merged => {
+            let project = git::detect_project().map_err(|e| {
+                error!(event = "core.cleanup.strategy_failed", strategy = "Merged", error = %e);
+                CleanupError::GitError { source: e }
+            })?;
+
+            // Without a forge every PR would look unmerged — refuse instead
+            let backend = crate::forge::get_forge_backend(&project.path, load_forge_override())
+                .ok_or_else(|| {
+                    error!(
+                        event = "core.cleanup.strategy_failed",
+                        strategy = "Merged",
+                        reason = "no_forge_backend"
+                    );
+                    CleanupError::ForgeUnavailable {
+                        message: "no supported forge detected for this repository, \
+                                  or its CLI is not installed"
+                            .to_string(),
+                    }
+                })?;
+
+            let sessions = operations::detect_merged_sessions(
+                &config.sessions_dir(),
+                &project.id,
+                |worktree_path, kild_branch| {
+                    // Branch may already be gone locally; ask from the project root
+                    let dir = if worktree_path.exists() {
+                        worktree_path
+                    } else {
+                        project.path.as_path()
+                    };
+                    backend.is_pr_merged(dir, kild_branch).unwrap_or_else(|e| {
+                        warn!(
+                            event = "core.cleanup.pr_merge_check_failed",
+                            branch = kild_branch,
+                            error = %e
+                        );
+                        false
+                    })
+                },
+            )
+            .map_err(|e| {
+                error!(event = "core.cleanup.strategy_failed", strategy = "Merged", error = %e);
+                CleanupError::StrategyFailed {
+                    strategy: "Merged".to_string(),
+                    source: Box::new(e),
+                }
+            })?;
+
+            info!(
+                event = "core.cleanup.merged_scan_completed",
+                merged_count = sessions.len(),
+                project = project.name
+            );
+
+            for session_id in sessions {
+                summary.add_session(session_id);
+            }
+        }
     }
 
     info!(
@@ -317,6 +376,20 @@ pub fn scan_for_orphans_with_strategy(
     Ok(summary)
 }
 
+/// Forge override from config, falling back to auto-detection on load errors.
+pub(super) fn load_forge_override() -> Option<crate::forge::ForgeType> {
+    kild_config::KildConfig::load_hierarchy()
+        .inspect_err(|e| {
+            warn!(
+                event = "core.cleanup.config_load_failed",
+                error = %e,
+                "Could not load config for forge override — falling back to auto-detection"
+            );
+        })
+        .ok()
+        .and_then(|c| c.git.forge())
+}
+
 fn cleanup_orphaned_branches(branches: &[String]) -> Result<Vec<String>, CleanupError> {
     // Early return for empty list - no Git access needed
     if branches.is_empty() {
@@ -522,7 +595,7 @@ fn cleanup_orphaned_worktrees(
 ///
 /// Returns `(worktree_path, use_main_worktree, branch)` or `None` if the
 /// session file can't be read or parsed.
-fn load_session_for_cleanup(
+pub(super) fn load_session_for_cleanup(
     sessions_dir: &Path,
     session_id: &str,
 ) -> Option<(PathBuf, bool, String)> {
@@ -1,6 +1,7 @@
 pub mod errors;
 pub mod handler;
 mod operations;
+pub mod plan;
 pub mod types;
 
 // Public API exports
@@ -9,4 +10,8 @@ pub use handler::{
     cleanup_all, cleanup_all_with_strategy, cleanup_orphaned_resources, scan_for_orphans,
     scan_for_orphans_with_strategy,
 };
-pub use types::{CleanupStrategy, CleanupSummary, OrphanedResource, ResourceType};
+pub use plan::{measure_disk_usage, plan_cleanup, plan_max_disk};
+pub use types::{
+    CleanupPlan, CleanupStrategy, CleanupSummary, DiskUsage, OrphanedResource, PlanAction,
+    PlanItem, ResourceType,
+};
@@ -5,6 +5,7 @@
 //! - detect_sessions_older_than: Stopped sessions older than N days
 //! - detect_orphaned_branches: Git branches without corresponding sessions
 //! - detect_orphaned_worktrees: Worktrees without corresponding sessions
+//! - detect_merged_sessions: Stopped sessions whose PR is merged upstream
 
 use crate::cleanup::errors::CleanupError;
 use crate::git;
@@ -407,6 +408,48 @@ pub fn detect_sessions_older_than(
     Ok(old_sessions)
 }
 
+/// Detect stopped sessions of a project whose kild branch has a merged PR.
+///
+/// Active sessions are never returned — an agent may still be pushing to a
+/// merged branch. Main-worktree sessions are skipped because their branch is
+/// the project's own checkout. `is_merged` receives the session's worktree
+/// path and kild branch name and is only called for candidates.
+pub fn detect_merged_sessions(
+    sessions_dir: &Path,
+    project_id: &str,
+    is_merged: impl Fn(&Path, &str) -> bool,
+) -> Result<Vec<String>, CleanupError> {
+    if !sessions_dir.exists() {
+        return Ok(Vec::new());
+    }
+
+    let (sessions, _) = crate::sessions::persistence::load_sessions_from_files(sessions_dir)?;
+
+    let merged = sessions
+        .into_iter()
+        .filter(|s| {
+            &*s.project_id == project_id
+                && s.status == crate::sessions::types::SessionStatus::Stopped
+                && !s.use_main_worktree
+        })
+        .filter(|s| {
+            let kild_branch = git::kild_branch_name(&s.branch);
+            let merged = is_merged(&s.worktree_path, &kild_branch);
+            if merged {
+                info!(
+                    event = "core.cleanup.session_pr_merged",
+                    session_id = %s.id,
+                    branch = %kild_branch,
+                );
+            }
+            merged
+        })
+        .map(|s| s.id.to_string())
+        .collect();
+
+    Ok(merged)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -431,6 +474,35 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_detect_merged_sessions_only_stopped_in_project() {
+        use crate::sessions::persistence::save_session_to_file;
+        use crate::sessions::types::{Session, SessionStatus};
+
+        let temp_dir = TempDir::new().unwrap();
+        let sessions_dir = temp_dir.path();
+
+        let mut stopped = Session::new_for_test("merged-stopped", temp_dir.path().join("a"));
+        stopped.status = SessionStatus::Stopped;
+        let project_id = stopped.project_id.to_string();
+        let mut active = Session::new_for_test("merged-active", temp_dir.path().join("b"));
+        active.status = SessionStatus::Active;
+        let mut unmerged = Session::new_for_test("open-pr", temp_dir.path().join("c"));
+        unmerged.status = SessionStatus::Stopped;
+        for s in [&stopped, &active, &unmerged] {
+            save_session_to_file(s, sessions_dir).unwrap();
+        }
+
+        let merged = detect_merged_sessions(sessions_dir, &project_id, |_, branch| {
+            branch.contains("merged")
+        })
+        .unwrap();
+        assert_eq!(merged, vec![stopped.id.to_string()]);
+
+        let none = detect_merged_sessions(sessions_dir, "other-project", |_, _| true).unwrap();
+        assert!(none.is_empty());
+    }
+
     #[test]
     fn test_detect_stale_sessions_empty_dir() {
         let temp_dir = TempDir::new().unwrap();
@@ -0,0 +1,396 @@
+//! Cleanup plans: what `kild cleanup` would remove and why, without touching
+//! anything.
+//!
+//! A plan is built from the same scan the real cleanup uses and enriched with
+//! per-worktree disk usage, the branch's last commit time and, when a forge
+//! CLI is available, whether its PR is merged upstream.
+
+use std::path::Path;
+
+use kild_config::Config;
+use kild_protocol::AgentStatus;
+use tracing::{debug, info, warn};
+
+use crate::cleanup::{errors::CleanupError, handler, types::*};
+use crate::forge::ForgeBackend;
+use crate::git;
+use crate::sessions::persistence;
+use crate::sessions::types::{Session, SessionStatus};
+
+/// Build the plan for a cleanup strategy.
+pub fn plan_cleanup(strategy: CleanupStrategy) -> Result<CleanupPlan, CleanupError> {
+    info!(event = "core.cleanup.plan_started", strategy = %strategy);
+
+    let summary = handler::scan_for_orphans_with_strategy(strategy.clone())?;
+    let config = Config::new();
+    let repo_dir = std::env::current_dir().map_err(|e| CleanupError::IoError { source: e })?;
+
+    // Merged strategy already asked the forge; don't ask twice per item
+    let forge = if strategy == CleanupStrategy::Merged {
+        None
+    } else {
+        crate::forge::get_forge_backend(&repo_dir, handler::load_forge_override())
+    };
+    let merged_status = |dir: &Path, branch: &str| -> Option<bool> {
+        if strategy == CleanupStrategy::Merged {
+            return Some(true);
+        }
+        check_merged(forge?, dir, branch)
+    };
+
+    let mut items = Vec::new();
+
+    for branch in &summary.orphaned_branches {
+        items.push(PlanItem {
+            action: PlanAction::DeleteBranch,
+            name: branch.clone(),
+            branch: Some(branch.clone()),
+            path: None,
+            reason: "kild branch is not checked out in any worktree".to_string(),
+            disk: None,
+            last_commit: git::health::last_commit_time(&repo_dir, Some(branch)),
+            merged: merged_status(&repo_dir, branch),
+        });
+    }
+
+    for path in &summary.orphaned_worktrees {
+        let exists = path.exists();
+        let reason = match (&strategy, exists) {
+            (_, false) => "worktree directory no longer exists",
+            (CleanupStrategy::Orphans, true) => "worktree has no kild session",
+            (_, true) => "worktree has an invalid HEAD",
+        };
+        items.push(PlanItem {
+            action: PlanAction::RemoveWorktree,
+            name: path
+                .file_name()
+                .map(|n| n.to_string_lossy().to_string())
+                .unwrap_or_else(|| path.display().to_string()),
+            branch: None,
+            path: Some(path.clone()),
+            reason: reason.to_string(),
+            disk: exists.then(|| measure_disk_usage(path)),
+            last_commit: exists
+                .then(|| git::health::last_commit_time(path, None))
+                .flatten(),
+            merged: None,
+        });
+    }
+
+    let reason = session_reason(&strategy);
+    for session_id in &summary.stale_sessions {
+        let loaded = handler::load_session_for_cleanup(&config.sessions_dir(), session_id);
+        let Some((worktree_path, use_main_worktree, branch)) = loaded else {
+            items.push(PlanItem {
+                action: PlanAction::RemoveSession,
+                name: session_id.clone(),
+                branch: None,
+                path: None,
+                reason: reason.clone(),
+                disk: None,
+                last_commit: None,
+                merged: None,
+            });
+            continue;
+        };
+
+        let kild_branch = git::kild_branch_name(&branch);
+        let exists = worktree_path.exists();
+        // Main-worktree kilds never delete the project checkout
+        let disk = (exists && !use_main_worktree).then(|| measure_disk_usage(&worktree_path));
+        let last_commit = if exists {
+            git::health::last_commit_time(&worktree_path, None)
+        } else {
+            git::health::last_commit_time(&repo_dir, Some(&kild_branch))
+        };
+        let merged = merged_status(
+            if exists { &worktree_path } else { &repo_dir },
+            &kild_branch,
+        );
+
+        items.push(PlanItem {
+            action: PlanAction::RemoveSession,
+            name: branch.clone(),
+            branch: Some(kild_branch),
+            path: Some(worktree_path),
+            reason: reason.clone(),
+            disk,
+            last_commit,
+            merged,
+        });
+    }
+
+    let plan = CleanupPlan::new(strategy.to_string(), items);
+    info!(
+        event = "core.cleanup.plan_completed",
+        strategy = %strategy,
+        items = plan.items.len(),
+        reclaimable_bytes = plan.reclaimable_bytes
+    );
+    Ok(plan)
+}
+
+/// Suggest the largest idle kilds of the current project to remove so its
+/// worktrees fit in `max_disk_bytes`.
+///
+/// A kild is idle when it is stopped or its agent last reported `idle` or
+/// `done`. Suggestions are never applied automatically.
+pub fn plan_max_disk(max_disk_bytes: u64) -> Result<CleanupPlan, CleanupError> {
+    info!(event = "core.cleanup.max_disk_plan_started", max_disk_bytes);
+
+    let project = git::detect_project().map_err(|e| CleanupError::GitError { source: e })?;
+    let config = Config::new();
+    let sessions_dir = config.sessions_dir();
+    let (sessions, _) = persistence::load_sessions_from_files(&sessions_dir)?;
+
+    let forge = crate::forge::get_forge_backend(&project.path, handler::load_forge_override());
+
+    let mut total_worktree_bytes = 0;
+    let mut candidates = Vec::new();
+    for session in sessions.iter().filter(|s| {
+        *s.project_id == *project.id && !s.use_main_worktree && s.worktree_path.exists()
+    }) {
+        let disk = measure_disk_usage(&session.worktree_path);
+        total_worktree_bytes += disk.total_bytes;
+
+        let Some(reason) = idle_reason(session, &sessions_dir) else {
+            continue;
+        };
+        let kild_branch = git::kild_branch_name(&session.branch);
+        candidates.push(PlanItem {
+            action: PlanAction::Suggest,
+            name: session.branch.to_string(),
+            merged: forge.and_then(|f| check_merged(f, &session.worktree_path, &kild_branch)),
+            branch: Some(kild_branch),
+            path: Some(session.worktree_path.clone()),
+            reason,
+            disk: Some(disk),
+            last_commit: git::health::last_commit_time(&session.worktree_path, None),
+        });
+    }
+
+    let items = select_for_budget(candidates, total_worktree_bytes, max_disk_bytes);
+    let mut plan = CleanupPlan::new(format!("max-disk-{}", max_disk_bytes), items);
+    plan.max_disk_bytes = Some(max_disk_bytes);
+    plan.total_worktree_bytes = Some(total_worktree_bytes);
+
+    info!(
+        event = "core.cleanup.max_disk_plan_completed",
+        total_worktree_bytes,
+        suggestions = plan.items.len(),
+        reclaimable_bytes = plan.reclaimable_bytes
+    );
+    Ok(plan)
+}
+
+/// Disk space used by a worktree. Symlinks are not followed.
+///
+/// `node_modules/` is split out at any depth. Cargo output is recognised as
+/// the top-level `target/` or any directory carrying a `CACHEDIR.TAG`.
+pub fn measure_disk_usage(path: &Path) -> DiskUsage {
+    let mut usage = DiskUsage::default();
+    walk(path, Bucket::Other, true, &mut usage);
+    usage
+}
+
+#[derive(Clone, Copy, PartialEq)]
+enum Bucket {
+    Other,
+    Target,
+    NodeModules,
+}
+
+fn walk(dir: &Path, bucket: Bucket, top_level: bool, usage: &mut DiskUsage) {
+    let entries = match std::fs::read_dir(dir) {
+        Ok(entries) => entries,
+        Err(e) => {
+            debug!(
+                event = "core.cleanup.disk_scan_unreadable",
+                path = %dir.display(),
+                error = %e
+            );
+            return;
+        }
+    };
+
+    for entry in entries.flatten() {
+        let Ok(meta) = entry.path().symlink_metadata() else {
+            continue;
+        };
+        if meta.is_dir() {
+            let path = entry.path();
+            let name = entry.file_name();
+            let child_bucket = match bucket {
+                Bucket::Other if name == "node_modules" => Bucket::NodeModules,
+                Bucket::Other
+                    if (top_level && name == "target") || path.join("CACHEDIR.TAG").exists() =>
+                {
+                    Bucket::Target
+                }
+                other => other,
+            };
+            walk(&path, child_bucket, false, usage);
+        } else {
+            let len = meta.len();
+            usage.total_bytes += len;
+            match bucket {
+                Bucket::Target => usage.target_bytes += len,
+                Bucket::NodeModules => usage.node_modules_bytes += len,
+                Bucket::Other => {}
+            }
+        }
+    }
+}
+
+/// Why a session is picked by a session-based strategy.
+fn session_reason(strategy: &CleanupStrategy) -> String {
+    match strategy {
+        CleanupStrategy::OlderThan(days) => format!("no activity for more than {} days", days),
+        CleanupStrategy::Merged => "PR is merged upstream and the kild is stopped".to_string(),
+        _ => "worktree is missing or invalid".to_string(),
+    }
+}
+
+/// Why a kild counts as idle for `--max-disk`, or `None` if it is busy.
+fn idle_reason(session: &Session, sessions_dir: &Path) -> Option<String> {
+    if session.status == SessionStatus::Stopped {
+        return Some("kild is stopped".to_string());
+    }
+    let record = persistence::read_agent_status(sessions_dir, &session.id)?;
+    match record.status {
+        AgentStatus::Idle | AgentStatus::Done => Some(format!(
+            "agent is {} since {}",
+            record.status, record.updated_at
+        )),
+        _ => None,
+    }
+}
+
+/// Largest candidates first until the remaining total fits the budget.
+fn select_for_budget(mut candidates: Vec<PlanItem>, total: u64, budget: u64) -> Vec<PlanItem> {
+    let size = |item: &PlanItem| item.disk.map_or(0, |d| d.total_bytes);
+    candidates.sort_by_key(|item| std::cmp::Reverse(size(item)));
+
+    let mut remaining = total;
+    candidates
+        .into_iter()
+        .take_while(|item| {
+            if remaining <= budget {
+                return false;
+            }
+            remaining = remaining.saturating_sub(size(item));
+            true
+        })
+        .collect()
+}
+
+fn check_merged(forge: &dyn ForgeBackend, dir: &Path, branch: &str) -> Option<bool> {
+    forge
+        .is_pr_merged(dir, branch)
+        .inspect_err(|e| {
+            warn!(
+                event = "core.cleanup.pr_merge_check_failed",
+                branch = branch,
+                error = %e
+            );
+        })
+        .ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::fs;
+    use tempfile::TempDir;
+
+    fn write_bytes(path: &Path, len: usize) {
+        fs::create_dir_all(path.parent().unwrap()).unwrap();
+        fs::write(path, vec![0u8; len]).unwrap();
+    }
+
+    #[test]
+    fn test_measure_disk_usage_splits_target_and_node_modules() {
+        let dir = TempDir::new().unwrap();
+        let root = dir.path();
+        write_bytes(&root.join("src/main.rs"), 10);
+        write_bytes(&root.join("target/debug/app"), 100);
+        write_bytes(&root.join("web/node_modules/react/index.js"), 40);
+        // Nested cargo target dirs are tagged, not named at top level
+        write_bytes(&root.join("crates/x/out/CACHEDIR.TAG"), 5);
+        write_bytes(&root.join("crates/x/out/lib.rlib"), 20);
+        // Directories named target below the top aren't build output
+        write_bytes(&root.join("docs/target/page.md"), 3);
+
+        let usage = measure_disk_usage(root);
+        assert_eq!(usage.total_bytes, 178);
+        assert_eq!(usage.target_bytes, 125);
+        assert_eq!(usage.node_modules_bytes, 40);
+    }
+
+    #[test]
+    fn test_measure_disk_usage_missing_dir_is_zero() {
+        let dir = TempDir::new().unwrap();
+        assert_eq!(
+            measure_disk_usage(&dir.path().join("gone")),
+            DiskUsage::default()
+        );
+    }
+
+    fn suggestion(name: &str, bytes: u64) -> PlanItem {
+        PlanItem {
+            action: PlanAction::Suggest,
+            name: name.to_string(),
+            branch: None,
+            path: None,
+            reason: "kild is stopped".to_string(),
+            disk: Some(DiskUsage {
+                total_bytes: bytes,
+                ..Default::default()
+            }),
+            last_commit: None,
+            merged: None,
+        }
+    }
+
+    #[test]
+    fn test_select_for_budget_takes_largest_first() {
+        let candidates = vec![
+            suggestion("small", 10),
+            suggestion("huge", 500),
+            suggestion("mid", 100),
+        ];
+        // 800 total, 600 idle; need to drop below 250
+        let picked = select_for_budget(candidates, 800, 250);
+        let names: Vec<_> = picked.iter().map(|i| i.name.as_str()).collect();
+        assert_eq!(names, vec!["huge", "mid"]);
+    }
+
+    #[test]
+    fn test_select_for_budget_within_budget_suggests_nothing() {
+        let picked = select_for_budget(vec![suggestion("a", 10)], 100, 100);
+        assert!(picked.is_empty());
+    }
+
+    #[test]
+    fn test_session_reason_per_strategy() {
+        assert_eq!(
+            session_reason(&CleanupStrategy::OlderThan(7)),
+            "no activity for more than 7 days"
+        );
+        assert!(session_reason(&CleanupStrategy::Merged).contains("merged"));
+        assert_eq!(
+            session_reason(&CleanupStrategy::NoPid),
+            "worktree is missing or invalid"
+        );
+    }
+
+    #[test]
+    fn test_plan_serializes_snake_case_actions() {
+        let plan = CleanupPlan::new("stopped", vec![suggestion("a", 42)]);
+        assert_eq!(plan.reclaimable_bytes, 42);
+        let json = serde_json::to_value(&plan).unwrap();
+        assert_eq!(json["items"][0]["action"], "suggest");
+        assert_eq!(json["items"][0]["disk"]["total_bytes"], 42);
+        assert!(json.get("max_disk_bytes").is_none());
+    }
+}
@@ -15,6 +15,94 @@ pub enum CleanupStrategy {
     Stopped,        // Only sessions with stopped processes
     OlderThan(u64), // Only sessions older than N days
     Orphans,        // Worktrees in kild dir without sessions
+    Merged,         // Stopped kilds whose PR is merged upstream
+}
+
+impl std::fmt::Display for CleanupStrategy {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            CleanupStrategy::All => write!(f, "all"),
+            CleanupStrategy::NoPid => write!(f, "no-pid"),
+            CleanupStrategy::Stopped => write!(f, "stopped"),
+            CleanupStrategy::OlderThan(days) => write!(f, "older-than-{}d", days),
+            CleanupStrategy::Orphans => write!(f, "orphans"),
+            CleanupStrategy::Merged => write!(f, "merged"),
+        }
+    }
+}
+
+/// What applying a cleanup plan entry would do.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum PlanAction {
+    DeleteBranch,
+    RemoveWorktree,
+    /// Remove the session, its worktree and its kild branch.
+    RemoveSession,
+    /// Suggested by `--max-disk`; never applied automatically.
+    Suggest,
+}
+
+/// Disk space used by a worktree, with the usual build-output hogs split out.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize)]
+pub struct DiskUsage {
+    pub total_bytes: u64,
+    /// Cargo `target/` directories.
+    pub target_bytes: u64,
+    /// `node_modules/` directories at any depth.
+    pub node_modules_bytes: u64,
+}
+
+/// One entry of a cleanup plan.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct PlanItem {
+    pub action: PlanAction,
+    /// Branch name, session ID or worktree directory name.
+    pub name: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub branch: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub path: Option<PathBuf>,
+    pub reason: String,
+    /// `None` when the worktree is gone or is the project root.
+    pub disk: Option<DiskUsage>,
+    /// RFC3339 time of the branch's last commit.
+    pub last_commit: Option<String>,
+    /// Whether the branch's PR is merged upstream. `None` when no forge is
+    /// available or the check failed.
+    pub merged: Option<bool>,
+}
+
+/// Everything a cleanup would remove, with reasons — produced by
+/// `kild cleanup --dry-run` and `--max-disk`.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct CleanupPlan {
+    pub strategy: String,
+    pub items: Vec<PlanItem>,
+    /// Disk space freed by applying every item.
+    pub reclaimable_bytes: u64,
+    /// Disk budget for `--max-disk` plans.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub max_disk_bytes: Option<u64>,
+    /// Combined size of the project's kild worktrees (`--max-disk` plans).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub total_worktree_bytes: Option<u64>,
+}
+
+impl CleanupPlan {
+    pub fn new(strategy: impl Into<String>, items: Vec<PlanItem>) -> Self {
+        let reclaimable_bytes = items
+            .iter()
+            .filter_map(|i| i.disk.map(|d| d.total_bytes))
+            .sum();
+        Self {
+            strategy: strategy.into(),
+            items,
+            reclaimable_bytes,
+            max_disk_bytes: None,
+            total_worktree_bytes: None,
+        }
+    }
 }
 
 #[derive(Debug, Clone, PartialEq)]
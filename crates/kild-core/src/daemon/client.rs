@@ -462,6 +462,16 @@ pub fn get_session_status(
 pub fn get_session_info(
     daemon_session_id: &str,
 ) -> Result<Option<(SessionStatus, Option<i32>)>, DaemonClientError> {
+    Ok(get_session_details(daemon_session_id)?.map(|s| (s.status, s.exit_code)))
+}
+
+/// Query the daemon for the full wire status of a session, including PTY
+/// activity timestamps.
+///
+/// Returns `Ok(None)` if the daemon is not running or the session is not found.
+pub fn get_session_details(
+    daemon_session_id: &str,
+) -> Result<Option<kild_protocol::DaemonSessionStatus>, DaemonClientError> {
     let request = ClientMessage::GetSession {
         id: format!("info-{}", daemon_session_id),
         session_id: SessionId::new(daemon_session_id),
@@ -481,7 +491,7 @@ pub fn get_session_info(
             if restored {
                 return_connection(conn);
             }
-            Ok(Some((session.status, session.exit_code)))
+            Ok(Some(session))
         }
         Ok(unexpected) => {
             warn!(
@@ -668,6 +678,90 @@ pub fn list_daemon_sessions() -> Result<Vec<kild_protocol::DaemonSessionStatus>,
     }
 }
 
+/// List agent permission requests waiting in the daemon's approval broker.
+pub fn list_approvals() -> Result<Vec<kild_protocol::PendingApproval>, DaemonClientError> {
+    debug!(event = "core.daemon.list_approvals_started");
+
+    let request = ClientMessage::ListApprovals {
+        id: "list-approvals".to_string(),
+    };
+
+    let mut conn = get_connection()?;
+
+    match conn.send(&request) {
+        Ok(DaemonMessage::ApprovalList { approvals, .. }) => {
+            return_connection(conn);
+            debug!(
+                event = "core.daemon.list_approvals_completed",
+                count = approvals.len()
+            );
+            Ok(approvals)
+        }
+        Ok(_) => Err(DaemonClientError::ProtocolError {
+            message: "Expected ApprovalList response".to_string(),
+        }),
+        Err(IpcError::DaemonError { code, message }) => {
+            return_connection(conn);
+            Err(DaemonClientError::DaemonError { code, message })
+        }
+        Err(e) => {
+            warn!(
+                event = "core.daemon.list_approvals_failed",
+                error = %e,
+            );
+            Err(e.into())
+        }
+    }
+}
+
+/// Approve or deny a pending permission request.
+///
+/// The daemon returns the decision to the waiting agent. `message` is shown
+/// to the agent as the reason on deny.
+pub fn resolve_approval(
+    approval_id: &str,
+    decision: kild_protocol::ApprovalDecision,
+    message: Option<String>,
+) -> Result<(), DaemonClientError> {
+    info!(
+        event = "core.daemon.resolve_approval_started",
+        approval_id = approval_id,
+        decision = %decision
+    );
+
+    let request = ClientMessage::ResolveApproval {
+        id: format!("resolve-{}", approval_id),
+        approval_id: approval_id.to_string(),
+        decision,
+        message,
+    };
+
+    let mut conn = get_connection()?;
+
+    match conn.send(&request) {
+        Ok(_) => {
+            return_connection(conn);
+            info!(
+                event = "core.daemon.resolve_approval_completed",
+                approval_id = approval_id
+            );
+            Ok(())
+        }
+        Err(IpcError::DaemonError { code, message }) => {
+            return_connection(conn);
+            Err(DaemonClientError::DaemonError { code, message })
+        }
+        Err(e) => {
+            warn!(
+                event = "core.daemon.resolve_approval_failed",
+                approval_id = approval_id,
+                error = %e,
+            );
+            Err(e.into())
+        }
+    }
+}
+
 /// Request the daemon to shut down gracefully.
 pub fn request_shutdown() -> Result<(), DaemonClientError> {
     info!(event = "core.daemon.shutdown_started");
@@ -4,6 +4,7 @@ use tracing::{error, info};
 
 use crate::editor::errors::EditorError;
 use crate::editor::traits::EditorBackend;
+use crate::editor::types::FilePosition;
 use crate::terminal::common::escape::shell_escape;
 use crate::terminal::handler as terminal_ops;
 use kild_config::KildConfig;
@@ -38,32 +39,76 @@ impl EditorBackend for VimBackend {
         flags: &[String],
         config: &KildConfig,
     ) -> Result<(), EditorError> {
-        let escaped_path = shell_escape(&path.display().to_string());
-        let escaped_flags: Vec<String> = flags.iter().map(|f| shell_escape(f)).collect();
-
-        let mut parts = vec![editor_cmd.to_string()];
-        parts.extend(escaped_flags);
-        parts.push(escaped_path);
-        let command = parts.join(" ");
-
-        match terminal_ops::spawn_terminal(path, &command, config, None, None) {
-            Ok(_) => {
-                info!(
-                    event = "core.editor.open_completed",
-                    editor = editor_cmd,
-                    terminal = true
-                );
-                Ok(())
-            }
-            Err(e) => {
-                error!(
-                    event = "core.editor.open_failed",
-                    editor = editor_cmd,
-                    error = %e,
-                    terminal = true
-                );
-                Err(EditorError::TerminalSpawnFailed { source: e })
-            }
+        let target = path.display().to_string();
+        spawn_in_terminal(editor_cmd, path, flags, &[target], config)
+    }
+
+    fn open_at(
+        &self,
+        editor_cmd: &str,
+        path: &Path,
+        position: FilePosition,
+        flags: &[String],
+        config: &KildConfig,
+    ) -> Result<(), EditorError> {
+        let working_dir = path.parent().unwrap_or(path);
+        spawn_in_terminal(
+            editor_cmd,
+            working_dir,
+            flags,
+            &location_args(editor_cmd, path, position),
+            config,
+        )
+    }
+}
+
+/// Editor arguments that open `path` at `position`.
+///
+/// Helix takes `path:line:col`; vim and neovim take a `+` command.
+fn location_args(editor_cmd: &str, path: &Path, position: FilePosition) -> Vec<String> {
+    let program = Path::new(editor_cmd)
+        .file_name()
+        .and_then(|n| n.to_str())
+        .unwrap_or(editor_cmd);
+    if matches!(program, "hx" | "helix") {
+        return vec![position.append_to(path)];
+    }
+    let jump = match position.column {
+        Some(column) => format!("+call cursor({}, {})", position.line, column),
+        None => format!("+{}", position.line),
+    };
+    vec![jump, path.display().to_string()]
+}
+
+fn spawn_in_terminal(
+    editor_cmd: &str,
+    working_dir: &Path,
+    flags: &[String],
+    args: &[String],
+    config: &KildConfig,
+) -> Result<(), EditorError> {
+    let mut parts = vec![editor_cmd.to_string()];
+    parts.extend(flags.iter().map(|f| shell_escape(f)));
+    parts.extend(args.iter().map(|a| shell_escape(a)));
+    let command = parts.join(" ");
+
+    match terminal_ops::spawn_terminal(working_dir, &command, config, None, None) {
+        Ok(_) => {
+            info!(
+                event = "core.editor.open_completed",
+                editor = editor_cmd,
+                terminal = true
+            );
+            Ok(())
+        }
+        Err(e) => {
+            error!(
+                event = "core.editor.open_failed",
+                editor = editor_cmd,
+                error = %e,
+                terminal = true
+            );
+            Err(EditorError::TerminalSpawnFailed { source: e })
         }
     }
 }
@@ -104,4 +149,29 @@ mod tests {
             "flags with semicolons should be quoted"
         );
     }
+
+    #[test]
+    fn test_vim_location_args() {
+        let path = Path::new("/repo/src/lib.rs");
+        let line_only = FilePosition {
+            line: 12,
+            column: None,
+        };
+        assert_eq!(
+            location_args("nvim", path, line_only),
+            vec!["+12", "/repo/src/lib.rs"]
+        );
+        let with_column = FilePosition {
+            line: 12,
+            column: Some(4),
+        };
+        assert_eq!(
+            location_args("/usr/bin/vim", path, with_column),
+            vec!["+call cursor(12, 4)", "/repo/src/lib.rs"]
+        );
+        assert_eq!(
+            location_args("hx", path, with_column),
+            vec!["/repo/src/lib.rs:12:4"]
+        );
+    }
 }
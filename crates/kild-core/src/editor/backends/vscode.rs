@@ -1,3 +1,4 @@
+use std::ffi::OsStr;
 use std::path::Path;
 use std::process::Command;
 
@@ -5,6 +6,7 @@ use tracing::{error, info};
 
 use crate::editor::errors::EditorError;
 use crate::editor::traits::EditorBackend;
+use crate::editor::types::FilePosition;
 use kild_config::KildConfig;
 
 pub struct VSCodeBackend;
@@ -27,27 +29,43 @@ impl EditorBackend for VSCodeBackend {
     }
 
     fn open(&self, path: &Path, flags: &[String], _config: &KildConfig) -> Result<(), EditorError> {
-        let mut cmd = Command::new("code");
-        for flag in flags {
-            cmd.arg(flag);
-        }
-        cmd.arg(path);
+        spawn(flags, &[path.as_os_str()])
+    }
+
+    fn open_at(
+        &self,
+        _command_override: &str,
+        path: &Path,
+        position: FilePosition,
+        flags: &[String],
+        _config: &KildConfig,
+    ) -> Result<(), EditorError> {
+        let target = position.append_to(path);
+        spawn(flags, &[OsStr::new("--goto"), OsStr::new(&target)])
+    }
+}
+
+fn spawn(flags: &[String], args: &[&OsStr]) -> Result<(), EditorError> {
+    let mut cmd = Command::new("code");
+    for flag in flags {
+        cmd.arg(flag);
+    }
+    cmd.args(args);
 
-        match cmd.spawn() {
-            Ok(_) => {
-                info!(event = "core.editor.open_completed", editor = "code");
-                Ok(())
-            }
-            Err(e) => {
-                error!(
-                    event = "core.editor.open_failed",
-                    editor = "code",
-                    error = %e
-                );
-                Err(EditorError::SpawnFailed {
-                    message: format!("code: {}", e),
-                })
-            }
+    match cmd.spawn() {
+        Ok(_) => {
+            info!(event = "core.editor.open_completed", editor = "code");
+            Ok(())
+        }
+        Err(e) => {
+            error!(
+                event = "core.editor.open_failed",
+                editor = "code",
+                error = %e
+            );
+            Err(EditorError::SpawnFailed {
+                message: format!("code: {}", e),
+            })
         }
     }
 }
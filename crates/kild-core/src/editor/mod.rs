@@ -6,6 +6,6 @@ pub mod types;
 
 // Re-export public API
 pub use errors::EditorError;
-pub use registry::{detect_editor, get_backend, open_editor};
+pub use registry::{detect_editor, get_backend, open_editor, open_editor_at};
 pub use traits::EditorBackend;
-pub use types::EditorType;
+pub use types::{EditorType, FilePosition};
@@ -9,7 +9,7 @@ use kild_config::KildConfig;
 use super::backends::{GenericBackend, VSCodeBackend, VimBackend, ZedBackend};
 use super::errors::EditorError;
 use super::traits::EditorBackend;
-use super::types::EditorType;
+use super::types::{EditorType, FilePosition};
 
 /// Global registry of all known editor backends.
 static REGISTRY: LazyLock<EditorRegistry> = LazyLock::new(EditorRegistry::new);
@@ -230,6 +230,27 @@ pub fn open_editor(
     path: &Path,
     cli_override: Option<&str>,
     config: &KildConfig,
+) -> Result<(), EditorError> {
+    open_resolved(path, None, cli_override, config)
+}
+
+/// Open a file in the resolved editor, jumping to `position`.
+///
+/// Editors without a known line/column syntax open the file at the top.
+pub fn open_editor_at(
+    path: &Path,
+    position: FilePosition,
+    cli_override: Option<&str>,
+    config: &KildConfig,
+) -> Result<(), EditorError> {
+    open_resolved(path, Some(position), cli_override, config)
+}
+
+fn open_resolved(
+    path: &Path,
+    position: Option<FilePosition>,
+    cli_override: Option<&str>,
+    config: &KildConfig,
 ) -> Result<(), EditorError> {
     let (editor_name, editor_type) = resolve_editor(cli_override, config)?;
 
@@ -248,29 +269,31 @@ pub fn open_editor(
         event = "core.editor.open_started",
         editor = %editor_name,
         editor_type = ?editor_type,
-        path = %path.display()
+        path = %path.display(),
+        line = position.map(|p| p.line)
     );
 
-    match editor_type {
-        Some(et) => {
-            let backend = get_backend(&et).ok_or_else(|| EditorError::EditorNotFound {
-                editor: editor_name.clone(),
-            })?;
-            backend.open_with_command(&editor_name, path, &flags, config)
-        }
+    let generic;
+    let backend: &dyn EditorBackend = match editor_type {
+        Some(et) => get_backend(&et).ok_or_else(|| EditorError::EditorNotFound {
+            editor: editor_name.clone(),
+        })?,
         None => {
             // Unknown editor — use GenericBackend
-            let terminal = config.editor.terminal();
-            let backend = GenericBackend::new(editor_name.clone(), terminal);
+            generic = GenericBackend::new(editor_name.clone(), config.editor.terminal());
 
-            if !backend.is_available() {
+            if !generic.is_available() {
                 return Err(EditorError::EditorNotFound {
                     editor: editor_name,
                 });
             }
-
-            backend.open(path, &flags, config)
+            &generic
         }
+    };
+
+    match position {
+        Some(position) => backend.open_at(&editor_name, path, position, &flags, config),
+        None => backend.open_with_command(&editor_name, path, &flags, config),
     }
 }
 
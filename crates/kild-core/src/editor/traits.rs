@@ -3,6 +3,7 @@ use std::path::Path;
 use kild_config::KildConfig;
 
 use super::errors::EditorError;
+use super::types::FilePosition;
 
 /// Trait defining the interface for editor backends.
 ///
@@ -45,6 +46,21 @@ pub trait EditorBackend: Send + Sync {
     ) -> Result<(), EditorError> {
         self.open(path, flags, config)
     }
+
+    /// Open a file at `position`, with an override command name.
+    ///
+    /// The default implementation opens the file without jumping to the
+    /// position, for editors with no known command-line syntax for it.
+    fn open_at(
+        &self,
+        command_override: &str,
+        path: &Path,
+        _position: FilePosition,
+        flags: &[String],
+        config: &KildConfig,
+    ) -> Result<(), EditorError> {
+        self.open_with_command(command_override, path, flags, config)
+    }
 }
 
 #[cfg(test)]
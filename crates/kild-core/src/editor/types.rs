@@ -45,6 +45,23 @@ impl std::str::FromStr for EditorType {
     }
 }
 
+/// A 1-based line, and optionally column, to jump to when opening a file.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct FilePosition {
+    pub line: u32,
+    pub column: Option<u32>,
+}
+
+impl FilePosition {
+    /// `path:line[:column]`, the form Zed, VS Code (`--goto`) and Helix accept.
+    pub fn append_to(&self, path: &std::path::Path) -> String {
+        match self.column {
+            Some(column) => format!("{}:{}:{}", path.display(), self.line, column),
+            None => format!("{}:{}", path.display(), self.line),
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -119,4 +136,19 @@ mod tests {
         set.insert(EditorType::Vim);
         assert_eq!(set.len(), 3);
     }
+
+    #[test]
+    fn test_file_position_append_to() {
+        let path = std::path::Path::new("/repo/src/main.rs");
+        let line_only = FilePosition {
+            line: 42,
+            column: None,
+        };
+        assert_eq!(line_only.append_to(path), "/repo/src/main.rs:42");
+        let with_column = FilePosition {
+            line: 42,
+            column: Some(7),
+        };
+        assert_eq!(with_column.append_to(path), "/repo/src/main.rs:42:7");
+    }
 }
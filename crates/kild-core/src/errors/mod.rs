@@ -32,9 +32,15 @@ impl KildError for kild_git::GitError {
             kild_git::GitError::RemoteBranchDeleteFailed { .. } => {
                 "GIT_REMOTE_BRANCH_DELETE_FAILED"
             }
+            kild_git::GitError::PushFailed { .. } => "GIT_PUSH_FAILED",
             kild_git::GitError::DiffFailed { .. } => "GIT_DIFF_FAILED",
             kild_git::GitError::MergeAnalysisFailed { .. } => "GIT_MERGE_ANALYSIS_FAILED",
             kild_git::GitError::LogFailed { .. } => "GIT_LOG_FAILED",
+            kild_git::GitError::InvalidSparsePath { .. } => "GIT_INVALID_SPARSE_PATH",
+            kild_git::GitError::SparseCheckoutFailed { .. } => "GIT_SPARSE_CHECKOUT_FAILED",
+            kild_git::GitError::CheckpointNotFound { .. } => "GIT_CHECKPOINT_NOT_FOUND",
+            kild_git::GitError::BundleFailed { .. } => "GIT_BUNDLE_FAILED",
+            kild_git::GitError::CherryPickConflict { .. } => "GIT_CHERRY_PICK_CONFLICT",
             kild_git::GitError::Git2Error { .. } => "GIT2_ERROR",
             kild_git::GitError::IoError { .. } => "GIT_IO_ERROR",
         }
@@ -49,6 +55,9 @@ impl KildError for kild_git::GitError {
                 | kild_git::GitError::WorktreeAlreadyExists { .. }
                 | kild_git::GitError::RebaseConflict { .. }
                 | kild_git::GitError::RemoteBranchDeleteFailed { .. }
+                | kild_git::GitError::InvalidSparsePath { .. }
+                | kild_git::GitError::CheckpointNotFound { .. }
+                | kild_git::GitError::CherryPickConflict { .. }
         )
     }
 }
@@ -1,16 +1,20 @@
 use crate::files::{
     errors::FileError,
     operations,
-    types::{CopyOptions, IncludeConfig},
+    types::{CopyOptions, CopyReport, IncludeConfig, MatchedFile},
 };
+use std::fs;
 use std::path::Path;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::thread;
 use tracing::{error, info, warn};
 
 /// Copy files matching include patterns from source to destination.
 ///
-/// Returns `Ok((copied_count, failed_count))` where:
-/// - `copied_count`: Number of files successfully copied
-/// - `failed_count`: Number of files that failed to copy
+/// Each file is reflinked, hardlinked or copied according to the strategy of
+/// the pattern that matched it (see [`IncludeConfig::strategy_for`]), on a
+/// few threads in parallel. Returns a [`CopyReport`] with per-method counts
+/// and the bytes saved over plain copies.
 ///
 /// Individual file failures are logged but don't stop the operation.
 /// Returns `Err` only for fatal errors like pattern validation failure.
@@ -18,7 +22,7 @@ pub fn copy_include_files(
     source_root: &Path,
     destination_root: &Path,
     config: &IncludeConfig,
-) -> Result<(usize, usize), FileError> {
+) -> Result<CopyReport, FileError> {
     info!(
         event = "core.files.copy.started",
         source_root = %source_root.display(),
@@ -33,7 +37,7 @@ pub fn copy_include_files(
             event = "core.files.copy.skipped",
             reason = "include_patterns disabled in config"
         );
-        return Ok((0, 0));
+        return Ok(CopyReport::default());
     }
 
     // Early return if no patterns
@@ -42,7 +46,7 @@ pub fn copy_include_files(
             event = "core.files.copy.skipped",
             reason = "no patterns configured"
         );
-        return Ok((0, 0));
+        return Ok(CopyReport::default());
     }
 
     // Validate patterns
@@ -79,7 +83,7 @@ pub fn copy_include_files(
             files_copied = 0,
             reason = "no matching files found"
         );
-        return Ok((0, 0));
+        return Ok(CopyReport::default());
     }
 
     // Parse max file size if configured - fail fast on invalid format
@@ -97,72 +101,136 @@ pub fn copy_include_files(
         None
     };
 
-    // Create copy options
     let copy_options = CopyOptions { max_file_size };
+    let report = copy_parallel(
+        source_root,
+        destination_root,
+        &matching_files,
+        config,
+        &copy_options,
+    );
 
-    // Copy each matching file
-    let mut copied_count = 0;
-    let mut error_count = 0;
-
-    for source_file in &matching_files {
-        // Calculate relative path and destination
-        let relative_path = match source_file.strip_prefix(source_root) {
-            Ok(rel) => rel,
-            Err(_) => {
-                warn!(
-                    event = "core.files.copy.warning",
-                    warning_type = "path_calculation",
-                    source_file = %source_file.display(),
-                    message = "Could not calculate relative path, skipping"
-                );
-                error_count += 1;
-                continue;
-            }
-        };
-
-        let destination_file = destination_root.join(relative_path);
-
-        // Copy the file
-        match operations::copy_file_safely(source_file, &destination_file, &copy_options) {
-            Ok(()) => {
-                copied_count += 1;
-                info!(
-                    event = "core.files.copy.file_completed",
-                    source = %source_file.display(),
-                    destination = %destination_file.display(),
-                    relative_path = %relative_path.display()
-                );
-            }
-            Err(e) => {
-                error_count += 1;
-                warn!(
-                    event = "core.files.copy.file_failed",
-                    error = %e,
-                    source = %source_file.display(),
-                    destination = %destination_file.display(),
-                    relative_path = %relative_path.display(),
-                    message = "Failed to copy file, continuing with others"
-                );
-            }
-        }
-    }
-
-    if error_count > 0 {
+    if report.failed > 0 {
         warn!(
             event = "core.files.copy.completed_with_errors",
-            files_copied = copied_count,
-            files_failed = error_count,
-            total_files = matching_files.len()
+            files_copied = report.copied,
+            files_failed = report.failed,
+            total_files = matching_files.len(),
+            bytes_saved = report.bytes_saved
         );
     } else {
         info!(
             event = "core.files.copy.completed",
-            files_copied = copied_count,
-            total_files = matching_files.len()
+            files_copied = report.copied,
+            total_files = matching_files.len(),
+            reflinked = report.reflinked,
+            hardlinked = report.hardlinked,
+            bytes_saved = report.bytes_saved
         );
     }
 
-    Ok((copied_count, error_count))
+    Ok(report)
+}
+
+/// Upper bound on copy threads; beyond this the disk, not the CPU, is the limit.
+const MAX_COPY_WORKERS: usize = 8;
+
+/// Copy `files` on a small pool of scoped threads pulling from a shared index.
+fn copy_parallel(
+    source_root: &Path,
+    destination_root: &Path,
+    files: &[MatchedFile],
+    config: &IncludeConfig,
+    options: &CopyOptions,
+) -> CopyReport {
+    let workers = thread::available_parallelism()
+        .map_or(1, |n| n.get())
+        .min(MAX_COPY_WORKERS)
+        .min(files.len());
+    let next = AtomicUsize::new(0);
+
+    thread::scope(|scope| {
+        let handles: Vec<_> = (0..workers)
+            .map(|_| {
+                scope.spawn(|| {
+                    let mut report = CopyReport::default();
+                    while let Some(file) = files.get(next.fetch_add(1, Ordering::Relaxed)) {
+                        copy_one(
+                            source_root,
+                            destination_root,
+                            file,
+                            config,
+                            options,
+                            &mut report,
+                        );
+                    }
+                    report
+                })
+            })
+            .collect();
+
+        let mut report = CopyReport::default();
+        for handle in handles {
+            match handle.join() {
+                Ok(partial) => report.merge(partial),
+                Err(_) => error!(event = "core.files.copy.worker_panicked"),
+            }
+        }
+        report
+    })
+}
+
+fn copy_one(
+    source_root: &Path,
+    destination_root: &Path,
+    file: &MatchedFile,
+    config: &IncludeConfig,
+    options: &CopyOptions,
+    report: &mut CopyReport,
+) {
+    let source_file = &file.path;
+    // Calculate relative path and destination
+    let relative_path = match source_file.strip_prefix(source_root) {
+        Ok(rel) => rel,
+        Err(_) => {
+            warn!(
+                event = "core.files.copy.warning",
+                warning_type = "path_calculation",
+                source_file = %source_file.display(),
+                message = "Could not calculate relative path, skipping"
+            );
+            report.failed += 1;
+            return;
+        }
+    };
+
+    let destination_file = destination_root.join(relative_path);
+    let strategy = config.strategy_for(&file.pattern);
+
+    match operations::copy_file_with_strategy(source_file, &destination_file, strategy, options) {
+        Ok(method) => {
+            let size = fs::metadata(&destination_file).map_or(0, |m| m.len());
+            report.record(method, size);
+            info!(
+                event = "core.files.copy.file_completed",
+                source = %source_file.display(),
+                destination = %destination_file.display(),
+                relative_path = %relative_path.display(),
+                method = ?method
+            );
+        }
+        Err(e) => {
+            report.failed += 1;
+            warn!(
+                event = "core.files.copy.file_failed",
+                error = %e,
+                source = %source_file.display(),
+                destination = %destination_file.display(),
+                relative_path = %relative_path.display(),
+                message = "Failed to copy file, continuing with others"
+            );
+        }
+    }
 }
 
 #[cfg(test)]
@@ -184,11 +252,12 @@ mod tests {
             patterns: vec![".env*".to_string()],
             enabled: false,
             max_file_size: None,
+            ..Default::default()
         };
 
         let result = copy_include_files(&source, &dest, &config);
         assert!(result.is_ok());
-        assert_eq!(result.unwrap(), (0, 0));
+        assert_eq!(result.unwrap(), CopyReport::default());
     }
 
     #[test]
@@ -203,11 +272,12 @@ mod tests {
             patterns: vec![],
             enabled: true,
             max_file_size: None,
+            ..Default::default()
         };
 
         let result = copy_include_files(&source, &dest, &config);
         assert!(result.is_ok());
-        assert_eq!(result.unwrap(), (0, 0));
+        assert_eq!(result.unwrap(), CopyReport::default());
     }
 
     #[test]
@@ -222,6 +292,7 @@ mod tests {
             patterns: vec!["[invalid".to_string()],
             enabled: true,
             max_file_size: None,
+            ..Default::default()
         };
 
         let result = copy_include_files(&source, &dest, &config);
@@ -258,14 +329,58 @@ mod tests {
             patterns: vec![".env*".to_string()],
             enabled: true,
             max_file_size: None,
+            ..Default::default()
         };
 
         let result = copy_include_files(&source, &dest, &config);
         assert!(result.is_ok());
-        let (copied, failed) = result.unwrap();
-        assert_eq!(copied, 2);
-        assert_eq!(failed, 0);
+        let report = result.unwrap();
+        assert_eq!(report.copied, 2);
+        assert_eq!(report.failed, 0);
         assert!(dest.join(".env").exists());
         assert!(dest.join(".env.local").exists());
     }
+
+    #[test]
+    fn test_copy_include_files_per_pattern_strategy() {
+        use crate::files::types::CopyStrategy;
+        use std::os::unix::fs::MetadataExt;
+
+        let temp_dir = TempDir::new().unwrap();
+        let source = temp_dir.path().join("source");
+        let dest = temp_dir.path().join("dest");
+        fs::create_dir_all(source.join("models")).unwrap();
+        fs::create_dir_all(&dest).unwrap();
+        for i in 0..20 {
+            fs::write(source.join(format!("models/w{i}.bin")), vec![7u8; 100]).unwrap();
+        }
+        fs::write(source.join(".env"), "SECRET=value\n").unwrap();
+
+        let mut config = IncludeConfig {
+            patterns: vec!["models/**".to_string(), ".env*".to_string()],
+            enabled: true,
+            max_file_size: None,
+            strategy: Some(CopyStrategy::Copy),
+            ..Default::default()
+        };
+        config
+            .strategies
+            .insert("models/**".to_string(), CopyStrategy::Hardlink);
+
+        let report = copy_include_files(&source, &dest, &config).unwrap();
+        assert_eq!(report.copied, 21);
+        assert_eq!(report.failed, 0);
+        assert_eq!(report.hardlinked, 20);
+        assert_eq!(report.reflinked, 0);
+        assert_eq!(report.bytes_saved, 2000);
+        assert_eq!(report.bytes, 2000 + 13);
+        assert_eq!(
+            fs::metadata(source.join("models/w3.bin")).unwrap().ino(),
+            fs::metadata(dest.join("models/w3.bin")).unwrap().ino()
+        );
+        assert_ne!(
+            fs::metadata(source.join(".env")).unwrap().ino(),
+            fs::metadata(dest.join(".env")).unwrap().ino()
+        );
+    }
 }
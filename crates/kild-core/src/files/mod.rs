@@ -6,4 +6,4 @@ pub mod types;
 // Re-export commonly used types and functions
 pub use errors::FileError;
 pub use handler::copy_include_files;
-pub use types::IncludeConfig;
+pub use types::{CopyReport, IncludeConfig};
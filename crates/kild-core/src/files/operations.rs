@@ -1,10 +1,10 @@
 use crate::files::{
     errors::FileError,
-    types::{CopyOptions, IncludeConfig, PatternRule},
+    types::{CopyMethod, CopyOptions, CopyStrategy, IncludeConfig, MatchedFile, PatternRule},
 };
 use ignore::WalkBuilder;
 use std::fs;
-use std::path::{Path, PathBuf};
+use std::path::Path;
 use tempfile::NamedTempFile;
 use tracing::{debug, warn};
 
@@ -50,7 +50,7 @@ pub fn validate_patterns(config: &IncludeConfig) -> Result<Vec<PatternRule>, Fil
 pub fn find_matching_files(
     source_root: &Path,
     rules: &[PatternRule],
-) -> Result<Vec<PathBuf>, FileError> {
+) -> Result<Vec<MatchedFile>, FileError> {
     let mut matching_files = Vec::new();
 
     // Create override builder to ignore gitignore for our patterns
@@ -101,7 +101,10 @@ pub fn find_matching_files(
                 let path_str = relative_path.to_string_lossy();
                 for rule in rules {
                     if rule.compiled().matches(&path_str) {
-                        matching_files.push(path.to_path_buf());
+                        matching_files.push(MatchedFile {
+                            path: path.to_path_buf(),
+                            pattern: rule.pattern.clone(),
+                        });
                         debug!(
                             event = "core.files.pattern.matched",
                             pattern = rule.pattern,
@@ -189,6 +192,68 @@ pub fn copy_file_safely(
     Ok(())
 }
 
+/// Materialize `source` at `destination` using `strategy`.
+///
+/// Reflink and hardlink fall back to [`copy_file_safely`] when the
+/// filesystem refuses (no clone support, different device). The size limit
+/// in `options` only applies to that byte copy. An existing destination is
+/// replaced atomically, as with a copy.
+pub fn copy_file_with_strategy(
+    source: &Path,
+    destination: &Path,
+    strategy: CopyStrategy,
+    options: &CopyOptions,
+) -> Result<CopyMethod, FileError> {
+    let linked = match strategy {
+        CopyStrategy::Copy => None,
+        CopyStrategy::Reflink => {
+            link_into_place(source, destination, reflink_file).map(|()| CopyMethod::Reflinked)
+        }
+        CopyStrategy::Hardlink => {
+            link_into_place(source, destination, |src, dst| fs::hard_link(src, dst))
+                .map(|()| CopyMethod::Hardlinked)
+        }
+    };
+
+    if let Some(method) = linked {
+        return Ok(method);
+    }
+    copy_file_safely(source, destination, options)?;
+    Ok(CopyMethod::Copied)
+}
+
+/// Link `source` to a temporary name next to `destination`, then rename it
+/// over `destination`. Returns `None` when linking failed and the caller
+/// should fall back to copying.
+fn link_into_place(
+    source: &Path,
+    destination: &Path,
+    link: impl Fn(&Path, &Path) -> std::io::Result<()>,
+) -> Option<()> {
+    let parent = destination.parent().unwrap_or_else(|| Path::new("."));
+    let name = destination.file_name()?.to_string_lossy();
+    let temp = parent.join(format!(".{}.kild-{}", name, std::process::id()));
+
+    let result = fs::create_dir_all(parent)
+        .and_then(|()| link(source, &temp))
+        .and_then(|()| {
+            fs::rename(&temp, destination).inspect_err(|_| {
+                let _ = fs::remove_file(&temp);
+            })
+        });
+    match result {
+        Ok(()) => Some(()),
+        Err(e) => {
+            debug!(
+                event = "core.files.link.fallback_to_copy",
+                source = %source.display(),
+                error = %e
+            );
+            None
+        }
+    }
+}
+
 /// Parse max file size string (e.g., "10MB", "1GB") to bytes
 pub fn parse_file_size(size_str: &str) -> Result<u64, FileError> {
     let size_str = size_str.trim().to_uppercase();
@@ -214,6 +279,68 @@ pub fn parse_file_size(size_str: &str) -> Result<u64, FileError> {
     Ok(number * unit_part)
 }
 
+/// Clone `source` to a new file at `destination` sharing its data blocks
+/// (FICLONE on Linux btrfs/XFS, `clonefile` on macOS APFS).
+///
+/// Fails with the OS error — typically `EOPNOTSUPP`, `EXDEV` or `EINVAL` —
+/// when the filesystem can't clone, leaving no partial destination behind.
+/// Permissions are copied; the modification time is kept from `source`.
+#[cfg(target_os = "linux")]
+pub fn reflink_file(source: &Path, destination: &Path) -> std::io::Result<()> {
+    use std::os::fd::AsRawFd;
+
+    let src = fs::File::open(source)?;
+    let metadata = src.metadata()?;
+    let dst = fs::OpenOptions::new()
+        .write(true)
+        .create_new(true)
+        .open(destination)?;
+
+    // SAFETY: both descriptors are open for the duration of the call and
+    // FICLONE takes the source fd by value.
+    let ret = unsafe { nix::libc::ioctl(dst.as_raw_fd(), nix::libc::FICLONE, src.as_raw_fd()) };
+    if ret != 0 {
+        let err = std::io::Error::last_os_error();
+        drop(dst);
+        let _ = fs::remove_file(destination);
+        return Err(err);
+    }
+
+    dst.set_permissions(metadata.permissions())?;
+    dst.set_modified(metadata.modified()?)?;
+    Ok(())
+}
+
+/// Clone `source` to a new file at `destination` sharing its data blocks
+/// (FICLONE on Linux btrfs/XFS, `clonefile` on macOS APFS).
+///
+/// `clonefile` keeps permissions and timestamps.
+#[cfg(target_os = "macos")]
+pub fn reflink_file(source: &Path, destination: &Path) -> std::io::Result<()> {
+    use std::ffi::CString;
+    use std::os::unix::ffi::OsStrExt;
+
+    let to_c = |p: &Path| {
+        CString::new(p.as_os_str().as_bytes())
+            .map_err(|e| std::io::Error::new(std::io::ErrorKind::InvalidInput, e))
+    };
+    let (src, dst) = (to_c(source)?, to_c(destination)?);
+    // SAFETY: both pointers come from live NUL-terminated CStrings.
+    let ret = unsafe { nix::libc::clonefile(src.as_ptr(), dst.as_ptr(), 0) };
+    if ret != 0 {
+        return Err(std::io::Error::last_os_error());
+    }
+    Ok(())
+}
+
+#[cfg(not(any(target_os = "linux", target_os = "macos")))]
+pub fn reflink_file(_source: &Path, _destination: &Path) -> std::io::Result<()> {
+    Err(std::io::Error::new(
+        std::io::ErrorKind::Unsupported,
+        "reflinks are not supported on this platform",
+    ))
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -224,6 +351,7 @@ mod tests {
             patterns: vec![".env*".to_string(), "*.local.json".to_string()],
             enabled: true,
             max_file_size: None,
+            ..Default::default()
         };
 
         let result = validate_patterns(&config);
@@ -241,6 +369,7 @@ mod tests {
             patterns: vec!["[invalid".to_string()], // Invalid glob pattern
             enabled: true,
             max_file_size: None,
+            ..Default::default()
         };
 
         let result = validate_patterns(&config);
@@ -264,4 +393,92 @@ mod tests {
         assert!(parse_file_size("invalid").is_err());
         assert!(parse_file_size("1XB").is_err());
     }
+
+    #[test]
+    fn test_copy_file_with_strategy_hardlink_shares_inode() {
+        use std::os::unix::fs::MetadataExt;
+
+        let temp_dir = tempfile::TempDir::new().unwrap();
+        let source = temp_dir.path().join("weights.bin");
+        let destination = temp_dir.path().join("wt/models/weights.bin");
+        fs::write(&source, b"large").unwrap();
+        let options = CopyOptions {
+            max_file_size: Some(1),
+        };
+
+        // Size limit doesn't apply to links
+        let method =
+            copy_file_with_strategy(&source, &destination, CopyStrategy::Hardlink, &options)
+                .unwrap();
+        assert_eq!(method, CopyMethod::Hardlinked);
+        assert_eq!(
+            fs::metadata(&source).unwrap().ino(),
+            fs::metadata(&destination).unwrap().ino()
+        );
+
+        // Replaces an existing destination
+        let method =
+            copy_file_with_strategy(&source, &destination, CopyStrategy::Hardlink, &options)
+                .unwrap();
+        assert_eq!(method, CopyMethod::Hardlinked);
+    }
+
+    #[test]
+    fn test_copy_file_with_strategy_reflink_falls_back_to_copy() {
+        let temp_dir = tempfile::TempDir::new().unwrap();
+        let source = temp_dir.path().join(".env");
+        let destination = temp_dir.path().join("wt/.env");
+        fs::write(&source, b"SECRET=1").unwrap();
+        let options = CopyOptions {
+            max_file_size: None,
+        };
+
+        let method =
+            copy_file_with_strategy(&source, &destination, CopyStrategy::Reflink, &options)
+                .unwrap();
+        assert!(matches!(method, CopyMethod::Reflinked | CopyMethod::Copied));
+        assert_eq!(fs::read(&destination).unwrap(), b"SECRET=1");
+
+        // Writing the worktree copy must not touch the source
+        fs::write(&destination, b"SECRET=2").unwrap();
+        assert_eq!(fs::read(&source).unwrap(), b"SECRET=1");
+    }
+
+    #[test]
+    fn test_copy_file_with_strategy_copy_enforces_size_limit() {
+        let temp_dir = tempfile::TempDir::new().unwrap();
+        let source = temp_dir.path().join("big.bin");
+        fs::write(&source, b"too big").unwrap();
+        let options = CopyOptions {
+            max_file_size: Some(1),
+        };
+
+        let result = copy_file_with_strategy(
+            &source,
+            &temp_dir.path().join("wt/big.bin"),
+            CopyStrategy::Copy,
+            &options,
+        );
+        assert!(matches!(result, Err(FileError::FileTooLarge { .. })));
+    }
+
+    #[test]
+    fn test_reflink_file_clones_or_fails_cleanly() {
+        let temp_dir = tempfile::TempDir::new().unwrap();
+        let source = temp_dir.path().join("source.bin");
+        let destination = temp_dir.path().join("clone.bin");
+        fs::write(&source, b"shared blocks").unwrap();
+
+        match reflink_file(&source, &destination) {
+            Ok(()) => {
+                assert_eq!(fs::read(&destination).unwrap(), b"shared blocks");
+                assert_eq!(
+                    fs::metadata(&destination).unwrap().modified().unwrap(),
+                    fs::metadata(&source).unwrap().modified().unwrap()
+                );
+            }
+            // tmpfs/ext4 can't clone; nothing may be left behind
+            Err(_) => assert!(!destination.exists()),
+        }
+    }
 }
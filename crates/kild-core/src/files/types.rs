@@ -1,3 +1,58 @@
 // Re-export types from kild-config; they live there now.
 pub use kild_config::include_config::default_include_patterns;
-pub use kild_config::include_config::{CopyOptions, IncludeConfig, PatternRule};
+pub use kild_config::include_config::{CopyOptions, CopyStrategy, IncludeConfig, PatternRule};
+
+use std::path::PathBuf;
+
+/// A file matched by an include pattern.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MatchedFile {
+    pub path: PathBuf,
+    /// The first pattern that matched, selecting the copy strategy.
+    pub pattern: String,
+}
+
+/// How a single file ended up in the worktree.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CopyMethod {
+    Copied,
+    Reflinked,
+    Hardlinked,
+}
+
+/// Outcome of copying include-pattern files into a new worktree.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct CopyReport {
+    /// Files now present in the worktree, however they got there.
+    pub copied: usize,
+    pub failed: usize,
+    pub reflinked: usize,
+    pub hardlinked: usize,
+    /// Total size of the files in `copied`.
+    pub bytes: u64,
+    /// Size of reflinked and hardlinked files — disk not consumed and bytes
+    /// not written compared to a plain copy.
+    pub bytes_saved: u64,
+}
+
+impl CopyReport {
+    pub(crate) fn record(&mut self, method: CopyMethod, size: u64) {
+        self.copied += 1;
+        self.bytes += size;
+        match method {
+            CopyMethod::Copied => return,
+            CopyMethod::Reflinked => self.reflinked += 1,
+            CopyMethod::Hardlinked => self.hardlinked += 1,
+        }
+        self.bytes_saved += size;
+    }
+
+    pub(crate) fn merge(&mut self, other: CopyReport) {
+        self.copied += other.copied;
+        self.failed += other.failed;
+        self.reflinked += other.reflinked;
+        self.hardlinked += other.hardlinked;
+        self.bytes += other.bytes;
+        self.bytes_saved += other.bytes_saved;
+    }
+}
@@ -236,6 +236,72 @@ impl ForgeBackend for GitHubBackend {
             Err(e) => Err(ForgeError::from(e)),
         }
     }
+
+    fn create_pr(
+        &self,
+        worktree_path: &Path,
+        branch: &str,
+        base_branch: &str,
+    ) -> Result<String, ForgeError> {
+        let branch = normalize_branch(branch);
+        info!(
+            event = "core.forge.pr_create_started",
+            branch = %branch,
+            base = base_branch,
+            worktree_path = %worktree_path.display()
+        );
+
+        let output = std::process::Command::new("gh")
+            .current_dir(worktree_path)
+            .args([
+                "pr",
+                "create",
+                "--head",
+                &branch,
+                "--base",
+                base_branch,
+                "--fill",
+            ])
+            .output();
+
+        match output {
+            Ok(output) if output.status.success() => {
+                let stdout = String::from_utf8_lossy(&output.stdout);
+                let url = parse_created_pr_url(&stdout).ok_or_else(|| ForgeError::ParseError {
+                    message: format!("gh pr create printed no PR URL: {}", stdout.trim()),
+                })?;
+                info!(
+                    event = "core.forge.pr_create_completed",
+                    branch = %branch,
+                    url = url
+                );
+                Ok(url.to_string())
+            }
+            Ok(output) => {
+                let exit_code = output.status.code().unwrap_or(-1);
+                let stderr = String::from_utf8_lossy(&output.stderr).trim().to_string();
+                error!(
+                    event = "core.forge.pr_create_failed",
+                    branch = %branch,
+                    exit_code = exit_code,
+                    stderr = %stderr
+                );
+                Err(ForgeError::CliError {
+                    message: format!("gh pr create failed (exit {}): {}", exit_code, stderr),
+                })
+            }
+            Err(e) => Err(ForgeError::from(e)),
+        }
+    }
+}
+
+/// Extract the PR URL from `gh pr create` output (its last non-empty line).
+fn parse_created_pr_url(stdout: &str) -> Option<&str> {
+    stdout
+        .lines()
+        .map(str::trim)
+        .rfind(|line| !line.is_empty())
+        .filter(|line| line.starts_with("https://") || line.starts_with("http://"))
 }
 
 /// Parse the JSON output from `gh pr view` into a `PullRequest`.
@@ -496,6 +562,17 @@ mod tests {
         assert_eq!(result.as_ref(), "kild/feature/auth");
     }
 
+    #[test]
+    fn test_parse_created_pr_url() {
+        let out = "Creating pull request for kild/x into main\n\nhttps://github.com/o/r/pull/7\n";
+        assert_eq!(
+            parse_created_pr_url(out),
+            Some("https://github.com/o/r/pull/7")
+        );
+        assert_eq!(parse_created_pr_url(""), None);
+        assert_eq!(parse_created_pr_url("something else\n"), None);
+    }
+
     #[test]
     fn test_parse_gh_pr_json_valid() {
         let json = r#"{
@@ -51,6 +51,17 @@ pub trait ForgeBackend: Send + Sync {
         branch: &str,
         strategy: MergeStrategy,
     ) -> Result<(), ForgeError>;
+
+    /// Open a PR from `branch` into `base_branch`, titled and described from
+    /// the branch's commits. The branch must already be pushed.
+    ///
+    /// Returns the URL of the new PR.
+    fn create_pr(
+        &self,
+        worktree_path: &Path,
+        branch: &str,
+        base_branch: &str,
+    ) -> Result<String, ForgeError>;
 }
 
 #[cfg(test)]
@@ -96,6 +107,15 @@ mod tests {
         ) -> Result<(), ForgeError> {
             Ok(())
         }
+
+        fn create_pr(
+            &self,
+            _worktree_path: &Path,
+            branch: &str,
+            _base_branch: &str,
+        ) -> Result<String, ForgeError> {
+            Ok(format!("https://example.com/pr/{}", branch))
+        }
     }
 
     #[test]
@@ -113,5 +133,9 @@ mod tests {
         assert!(!backend.is_pr_merged(path, "test").unwrap());
         assert!(backend.check_pr_exists(path, "test").is_unavailable());
         assert!(backend.fetch_pr_info(path, "test").unwrap().is_none());
+        assert_eq!(
+            backend.create_pr(path, "test", "main").unwrap(),
+            "https://example.com/pr/test"
+        );
     }
 }
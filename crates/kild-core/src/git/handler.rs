@@ -2,9 +2,7 @@ use git2::{BranchType, Repository, WorktreeAddOptions};
 use std::path::Path;
 use tracing::{debug, error, info, warn};
 
-use crate::files;
 use kild_config::GitConfig;
-use kild_config::KildConfig;
 use kild_git::{
     errors::GitError,
     naming,
@@ -71,8 +69,8 @@ pub fn create_worktree(
     base_dir: &Path,
     project: &GitProjectState,
     branch: &str,
-    config: Option<&KildConfig>,
     git_config: &GitConfig,
+    sparse_paths: &[String],
 ) -> Result<WorktreeState, GitError> {
     let validated_branch = validation::validate_branch_name(branch)?;
 
@@ -173,7 +171,18 @@ pub fn create_worktree(
     let mut opts = WorktreeAddOptions::new();
     opts.reference(Some(&reference));
 
-    add_git_worktree_with_retry(&repo, &worktree_name, &worktree_path, &opts)?;
+    if sparse_paths.is_empty() {
+        add_git_worktree_with_retry(&repo, &worktree_name, &worktree_path, &opts)?;
+    } else {
+        // libgit2 can't check out a subset of the tree; the admin name then
+        // follows the worktree directory, which removal doesn't depend on.
+        kild_git::cli::add_sparse_worktree(
+            &project.path,
+            &worktree_path,
+            &kild_branch,
+            sparse_paths,
+        )?;
+    }
 
     let worktree_info = WorktreeState::new(
         worktree_path.clone(),
@@ -189,48 +198,6 @@ pub fn create_worktree(
         worktree_path = %worktree_path.display()
     );
 
-    // Copy include pattern files if configured
-    if let Some(config) = config
-        && let Some(include_config) = &config.include_patterns
-    {
-        info!(
-            event = "core.git.worktree.file_copy_started",
-            project_id = project.id,
-            branch = %validated_branch,
-            patterns = ?include_config.patterns
-        );
-
-        match files::handler::copy_include_files(&project.path, &worktree_path, include_config) {
-            Ok((copied_count, failed_count)) => {
-                if failed_count > 0 {
-                    warn!(
-                        event = "core.git.worktree.file_copy_completed_with_errors",
-                        project_id = project.id,
-                        branch = %validated_branch,
-                        files_copied = copied_count,
-                        files_failed = failed_count
-                    );
-                } else {
-                    info!(
-                        event = "core.git.worktree.file_copy_completed",
-                        project_id = project.id,
-                        branch = %validated_branch,
-                        files_copied = copied_count
-                    );
-                }
-            }
-            Err(e) => {
-                warn!(
-                    event = "core.git.worktree.file_copy_failed",
-                    project_id = project.id,
-                    branch = %validated_branch,
-                    error = %e,
-                    message = "File copying failed, but worktree creation succeeded"
-                );
-            }
-        }
-    }
-
     Ok(worktree_info)
 }
 
@@ -335,7 +302,7 @@ mod tests {
             fetch_before_create: Some(false),
             ..GitConfig::default()
         };
-        let result = create_worktree(&base_dir, &project, "my-feature", None, &git_config);
+        let result = create_worktree(&base_dir, &project, "my-feature", &git_config, &[]);
         assert!(result.is_ok(), "create_worktree should succeed");
 
         let repo = Repository::open(&temp_dir).unwrap();
@@ -385,7 +352,7 @@ mod tests {
             fetch_before_create: Some(false),
             ..GitConfig::default()
         };
-        let result = create_worktree(&base_dir, &project, "feature/auth", None, &git_config);
+        let result = create_worktree(&base_dir, &project, "feature/auth", &git_config, &[]);
         assert!(result.is_ok(), "create_worktree should succeed");
 
         let repo = Repository::open(&temp_dir).unwrap();
@@ -494,7 +461,7 @@ mod tests {
             ..GitConfig::default()
         };
 
-        let result = create_worktree(&base_dir, &project, "test-branch", None, &git_config);
+        let result = create_worktree(&base_dir, &project, "test-branch", &git_config, &[]);
         assert!(
             result.is_ok(),
             "should succeed when remote doesn't exist (fetch skipped): {:?}",
@@ -521,7 +488,7 @@ mod tests {
         let base_dir = create_temp_test_dir("kild_test_no_remote_base");
         let git_config = GitConfig::default(); // fetch_before_create defaults to true
 
-        let result = create_worktree(&base_dir, &project, "test-branch", None, &git_config);
+        let result = create_worktree(&base_dir, &project, "test-branch", &git_config, &[]);
         assert!(
             result.is_ok(),
             "should succeed in repo without remote even with fetch enabled: {:?}",
@@ -562,7 +529,7 @@ mod tests {
             ..GitConfig::default()
         };
 
-        let result = create_worktree(&base_dir, &project, "test-branch", None, &git_config);
+        let result = create_worktree(&base_dir, &project, "test-branch", &git_config, &[]);
         assert!(
             result.is_ok(),
             "should succeed when fetch is disabled: {:?}",
@@ -613,7 +580,7 @@ mod tests {
                     // libgit2 non-atomic mkdir path since both threads call mkdir(2) directly.
                     barrier.wait();
 
-                    create_worktree(&base_dir, &project, &branch, None, &git_config)
+                    create_worktree(&base_dir, &project, &branch, &git_config, &[])
                 })
             })
             .collect();
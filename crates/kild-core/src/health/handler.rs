@@ -70,6 +70,7 @@ fn enrich_session_with_metrics(session: &sessions::types::Session) -> KildHealth
         .filter_map(|a| a.process_id())
         .find(|&pid| matches!(process::is_process_running(pid), Ok(true)));
 
+    let mut pty_activity = None;
     let (process_metrics, process_running) = if let Some(pid) = running_pid {
         (get_metrics_for_pid(pid, &session.branch), true)
     } else {
@@ -77,11 +78,18 @@ fn enrich_session_with_metrics(session: &sessions::types::Session) -> KildHealth
         let mut daemon_running = false;
         for agent in session.agents() {
             if let Some(daemon_sid) = agent.daemon_session_id() {
-                match crate::daemon::client::get_session_status(daemon_sid) {
-                    Ok(Some(
-                        kild_protocol::SessionStatus::Running
-                        | kild_protocol::SessionStatus::Creating,
-                    )) => {
+                match crate::daemon::client::get_session_details(daemon_sid) {
+                    Ok(Some(details))
+                        if matches!(
+                            details.status,
+                            kild_protocol::SessionStatus::Running
+                                | kild_protocol::SessionStatus::Creating
+                        ) =>
+                    {
+                        pty_activity = Some(PtyActivity {
+                            last_output_at: details.last_output_at,
+                            last_input_at: details.last_input_at,
+                        });
                         daemon_running = true;
                         break;
                     }
@@ -115,6 +123,7 @@ fn enrich_session_with_metrics(session: &sessions::types::Session) -> KildHealth
         process_running,
         agent_status,
         agent_status_updated_at,
+        pty_activity.as_ref(),
     )
 }
 
@@ -0,0 +1,419 @@
+//! Trend reporting over stored health snapshots
+//!
+//! Aggregates [`HealthSnapshot`]s into per-day or per-hour buckets and
+//! per-kild activity timelines for `kild health --history` and the UI dashboard.
+
+use std::collections::BTreeMap;
+
+use chrono::{DateTime, Duration, DurationRound, Utc};
+use serde::{Deserialize, Serialize};
+
+use crate::health::storage::HealthSnapshot;
+use crate::health::types::HealthStatus;
+
+/// Time bucket size for history aggregation.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum HistoryGranularity {
+    Day,
+    Hour,
+}
+
+impl HistoryGranularity {
+    fn duration(self) -> Duration {
+        match self {
+            HistoryGranularity::Day => Duration::days(1),
+            HistoryGranularity::Hour => Duration::hours(1),
+        }
+    }
+
+    /// Truncate a timestamp to the start of its bucket (UTC).
+    pub fn bucket_start(self, ts: DateTime<Utc>) -> DateTime<Utc> {
+        ts.duration_trunc(self.duration()).unwrap_or(ts)
+    }
+}
+
+impl std::str::FromStr for HistoryGranularity {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "day" => Ok(HistoryGranularity::Day),
+            "hour" => Ok(HistoryGranularity::Hour),
+            other => Err(format!(
+                "Invalid granularity '{}': expected 'day' or 'hour'",
+                other
+            )),
+        }
+    }
+}
+
+/// Averaged fleet metrics for one time bucket.
+///
+/// Counts are averaged across all snapshots that fell in the bucket, so a
+/// bucket with `avg_working = 2.5` had on average two and a half kilds working.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct HistoryBucket {
+    pub start: DateTime<Utc>,
+    pub samples: usize,
+    pub avg_total: f32,
+    pub avg_working: f32,
+    pub avg_idle: f32,
+    pub avg_stuck: f32,
+    pub avg_crashed: f32,
+    pub avg_cpu_percent: Option<f32>,
+    pub avg_memory_mb: Option<u64>,
+}
+
+impl HistoryBucket {
+    /// Fraction of kilds that were working in this bucket (0.0 when empty).
+    pub fn working_ratio(&self) -> f32 {
+        if self.avg_total > 0.0 {
+            self.avg_working / self.avg_total
+        } else {
+            0.0
+        }
+    }
+}
+
+/// Dominant status of one kild within one time bucket.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TimelineEntry {
+    pub start: DateTime<Utc>,
+    pub status: HealthStatus,
+}
+
+/// Activity timeline for a single kild, ordered by bucket start.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct KildTimeline {
+    pub branch: String,
+    pub entries: Vec<TimelineEntry>,
+}
+
+/// Full history report for a time window.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct HistoryReport {
+    pub days: u64,
+    pub granularity: HistoryGranularity,
+    pub snapshot_count: usize,
+    pub buckets: Vec<HistoryBucket>,
+    pub timelines: Vec<KildTimeline>,
+}
+
+impl HistoryReport {
+    /// Average working ratio across all buckets, weighted by sample count.
+    pub fn overall_working_ratio(&self) -> f32 {
+        overall_working_ratio(&self.buckets)
+    }
+}
+
+/// Average working ratio across buckets, weighted by sample count.
+pub fn overall_working_ratio(buckets: &[HistoryBucket]) -> f32 {
+    let (working, total) = buckets.iter().fold((0.0, 0.0), |(w, t), b| {
+        (
+            w + b.avg_working * b.samples as f32,
+            t + b.avg_total * b.samples as f32,
+        )
+    });
+    if total > 0.0 { working / total } else { 0.0 }
+}
+
+/// Build a history report from snapshots.
+pub fn build_report(
+    snapshots: &[HealthSnapshot],
+    days: u64,
+    granularity: HistoryGranularity,
+) -> HistoryReport {
+    HistoryReport {
+        days,
+        granularity,
+        snapshot_count: snapshots.len(),
+        buckets: aggregate_buckets(snapshots, granularity),
+        timelines: kild_timelines(snapshots, granularity),
+    }
+}
+
+/// Group snapshots into time buckets and average their metrics.
+pub fn aggregate_buckets(
+    snapshots: &[HealthSnapshot],
+    granularity: HistoryGranularity,
+) -> Vec<HistoryBucket> {
+    let mut grouped: BTreeMap<DateTime<Utc>, Vec<&HealthSnapshot>> = BTreeMap::new();
+    for snapshot in snapshots {
+        grouped
+            .entry(granularity.bucket_start(snapshot.timestamp))
+            .or_default()
+            .push(snapshot);
+    }
+
+    grouped
+        .into_iter()
+        .map(|(start, group)| {
+            let n = group.len() as f32;
+            let avg = |f: fn(&HealthSnapshot) -> usize| -> f32 {
+                group.iter().map(|s| f(s) as f32).sum::<f32>() / n
+            };
+
+            let cpus: Vec<f32> = group.iter().filter_map(|s| s.avg_cpu_percent).collect();
+            let mems: Vec<u64> = group.iter().filter_map(|s| s.total_memory_mb).collect();
+
+            HistoryBucket {
+                start,
+                samples: group.len(),
+                avg_total: avg(|s| s.total_kilds),
+                avg_working: avg(|s| s.working),
+                avg_idle: avg(|s| s.idle),
+                avg_stuck: avg(|s| s.stuck),
+                avg_crashed: avg(|s| s.crashed),
+                avg_cpu_percent: if cpus.is_empty() {
+                    None
+                } else {
+                    Some(cpus.iter().sum::<f32>() / cpus.len() as f32)
+                },
+                avg_memory_mb: if mems.is_empty() {
+                    None
+                } else {
+                    Some(mems.iter().sum::<u64>() / mems.len() as u64)
+                },
+            }
+        })
+        .collect()
+}
+
+/// Build per-kild timelines from the per-kild entries recorded in snapshots.
+///
+/// Each entry holds the most frequently observed status for that kild in the
+/// bucket. Ties resolve towards the more severe status so a stuck period is
+/// not hidden by an equal amount of idle time.
+pub fn kild_timelines(
+    snapshots: &[HealthSnapshot],
+    granularity: HistoryGranularity,
+) -> Vec<KildTimeline> {
+    let mut counts: BTreeMap<&str, BTreeMap<DateTime<Utc>, [usize; 5]>> = BTreeMap::new();
+    for snapshot in snapshots {
+        let start = granularity.bucket_start(snapshot.timestamp);
+        for kild in &snapshot.kilds {
+            counts
+                .entry(kild.branch.as_str())
+                .or_default()
+                .entry(start)
+                .or_insert([0; 5])[status_index(&kild.status)] += 1;
+        }
+    }
+
+    counts
+        .into_iter()
+        .map(|(branch, buckets)| KildTimeline {
+            branch: branch.to_string(),
+            entries: buckets
+                .into_iter()
+                .map(|(start, tally)| TimelineEntry {
+                    start,
+                    status: dominant_status(&tally),
+                })
+                .collect(),
+        })
+        .collect()
+}
+
+// Ordered by severity so that `max_by_key` over (count, index) breaks ties
+// towards the more severe status.
+const STATUS_ORDER: [HealthStatus; 5] = [
+    HealthStatus::Unknown,
+    HealthStatus::Working,
+    HealthStatus::Idle,
+    HealthStatus::Stuck,
+    HealthStatus::Crashed,
+];
+
+fn status_index(status: &HealthStatus) -> usize {
+    match status {
+        HealthStatus::Unknown => 0,
+        HealthStatus::Working => 1,
+        HealthStatus::Idle => 2,
+        HealthStatus::Stuck => 3,
+        HealthStatus::Crashed => 4,
+    }
+}
+
+fn dominant_status(tally: &[usize; 5]) -> HealthStatus {
+    let (ix, _) = tally
+        .iter()
+        .enumerate()
+        .max_by_key(|(ix, count)| (**count, *ix))
+        .unwrap_or((0, &0));
+    STATUS_ORDER[ix].clone()
+}
+
+/// Render snapshots as CSV, one row per snapshot.
+pub fn snapshots_to_csv(snapshots: &[HealthSnapshot]) -> String {
+    let mut out = String::from(
+        "timestamp,total_kilds,working,idle,stuck,crashed,avg_cpu_percent,total_memory_mb\n",
+    );
+    for s in snapshots {
+        out.push_str(&format!(
+            "{},{},{},{},{},{},{},{}\n",
+            s.timestamp.to_rfc3339(),
+            s.total_kilds,
+            s.working,
+            s.idle,
+            s.stuck,
+            s.crashed,
+            s.avg_cpu_percent
+                .map(|c| format!("{:.1}", c))
+                .unwrap_or_default(),
+            s.total_memory_mb.map(|m| m.to_string()).unwrap_or_default(),
+        ));
+    }
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::health::storage::KildSnapshot;
+    use chrono::TimeZone;
+
+    fn snapshot_at(ts: DateTime<Utc>, working: usize, idle: usize) -> HealthSnapshot {
+        HealthSnapshot {
+            timestamp: ts,
+            total_kilds: working + idle,
+            working,
+            idle,
+            stuck: 0,
+            crashed: 0,
+            avg_cpu_percent: Some(10.0),
+            total_memory_mb: Some(100),
+            kilds: vec![],
+        }
+    }
+
+    fn kild(branch: &str, status: HealthStatus) -> KildSnapshot {
+        KildSnapshot {
+            branch: branch.to_string(),
+            status,
+            cpu_usage_percent: None,
+            memory_usage_mb: None,
+        }
+    }
+
+    #[test]
+    fn test_granularity_from_str() {
+        assert_eq!(
+            "day".parse::<HistoryGranularity>().unwrap(),
+            HistoryGranularity::Day
+        );
+        assert_eq!(
+            "hour".parse::<HistoryGranularity>().unwrap(),
+            HistoryGranularity::Hour
+        );
+        assert!("week".parse::<HistoryGranularity>().is_err());
+    }
+
+    #[test]
+    fn test_aggregate_buckets_by_day_averages_counts() {
+        let day1 = Utc.with_ymd_and_hms(2026, 3, 1, 9, 0, 0).unwrap();
+        let day2 = Utc.with_ymd_and_hms(2026, 3, 2, 9, 0, 0).unwrap();
+        let snapshots = vec![
+            snapshot_at(day1, 2, 0),
+            snapshot_at(day1 + Duration::hours(3), 0, 2),
+            snapshot_at(day2, 3, 1),
+        ];
+
+        let buckets = aggregate_buckets(&snapshots, HistoryGranularity::Day);
+
+        assert_eq!(buckets.len(), 2);
+        assert_eq!(buckets[0].samples, 2);
+        assert_eq!(buckets[0].avg_working, 1.0);
+        assert_eq!(buckets[0].avg_idle, 1.0);
+        assert_eq!(buckets[0].working_ratio(), 0.5);
+        assert_eq!(buckets[1].avg_working, 3.0);
+        assert_eq!(buckets[1].avg_cpu_percent, Some(10.0));
+        assert_eq!(buckets[1].avg_memory_mb, Some(100));
+    }
+
+    #[test]
+    fn test_aggregate_buckets_by_hour_splits_within_day() {
+        let t = Utc.with_ymd_and_hms(2026, 3, 1, 9, 15, 0).unwrap();
+        let snapshots = vec![
+            snapshot_at(t, 1, 0),
+            snapshot_at(t + Duration::minutes(30), 1, 0),
+            snapshot_at(t + Duration::hours(1), 0, 1),
+        ];
+
+        let buckets = aggregate_buckets(&snapshots, HistoryGranularity::Hour);
+
+        assert_eq!(buckets.len(), 2);
+        assert_eq!(
+            buckets[0].start,
+            Utc.with_ymd_and_hms(2026, 3, 1, 9, 0, 0).unwrap()
+        );
+        assert_eq!(buckets[0].samples, 2);
+    }
+
+    #[test]
+    fn test_aggregate_buckets_missing_metrics() {
+        let t = Utc.with_ymd_and_hms(2026, 3, 1, 9, 0, 0).unwrap();
+        let mut s = snapshot_at(t, 0, 0);
+        s.avg_cpu_percent = None;
+        s.total_memory_mb = None;
+
+        let buckets = aggregate_buckets(&[s], HistoryGranularity::Day);
+
+        assert_eq!(buckets[0].avg_cpu_percent, None);
+        assert_eq!(buckets[0].avg_memory_mb, None);
+        assert_eq!(buckets[0].working_ratio(), 0.0);
+    }
+
+    #[test]
+    fn test_kild_timelines_dominant_status() {
+        let t = Utc.with_ymd_and_hms(2026, 3, 1, 9, 0, 0).unwrap();
+        let mut s1 = snapshot_at(t, 1, 1);
+        s1.kilds = vec![
+            kild("feat-a", HealthStatus::Working),
+            kild("feat-b", HealthStatus::Idle),
+        ];
+        let mut s2 = snapshot_at(t + Duration::minutes(10), 1, 0);
+        s2.kilds = vec![
+            kild("feat-a", HealthStatus::Working),
+            kild("feat-b", HealthStatus::Stuck),
+        ];
+
+        let timelines = kild_timelines(&[s1, s2], HistoryGranularity::Hour);
+
+        assert_eq!(timelines.len(), 2);
+        assert_eq!(timelines[0].branch, "feat-a");
+        assert_eq!(timelines[0].entries[0].status, HealthStatus::Working);
+        // Idle/Stuck tie resolves to the more severe status
+        assert_eq!(timelines[1].entries[0].status, HealthStatus::Stuck);
+    }
+
+    #[test]
+    fn test_overall_working_ratio_weights_by_samples() {
+        let t = Utc.with_ymd_and_hms(2026, 3, 1, 9, 0, 0).unwrap();
+        let snapshots = vec![
+            snapshot_at(t, 2, 0),
+            snapshot_at(t + Duration::minutes(1), 2, 0),
+            snapshot_at(t + Duration::days(1), 0, 2),
+        ];
+
+        let report = build_report(&snapshots, 7, HistoryGranularity::Day);
+
+        assert_eq!(report.snapshot_count, 3);
+        assert!((report.overall_working_ratio() - 2.0 / 3.0).abs() < 1e-6);
+    }
+
+    #[test]
+    fn test_snapshots_to_csv() {
+        let t = Utc.with_ymd_and_hms(2026, 3, 1, 9, 0, 0).unwrap();
+        let mut s = snapshot_at(t, 1, 2);
+        s.total_memory_mb = None;
+
+        let csv = snapshots_to_csv(&[s]);
+        let lines: Vec<&str> = csv.lines().collect();
+
+        assert_eq!(lines.len(), 2);
+        assert!(lines[0].starts_with("timestamp,"));
+        assert_eq!(lines[1], "2026-03-01T09:00:00+00:00,3,1,2,0,0,10.0,");
+    }
+}
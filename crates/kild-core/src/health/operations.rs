@@ -1,4 +1,4 @@
-use crate::health::types::{HealthMetrics, HealthOutput, HealthStatus, KildHealth};
+use crate::health::types::{HealthMetrics, HealthOutput, HealthStatus, KildHealth, PtyActivity};
 use crate::process::types::ProcessMetrics;
 use crate::sessions::types::Session;
 use chrono::{DateTime, Utc};
@@ -69,25 +69,62 @@ fn most_recent_activity(a: Option<&str>, b: Option<&str>) -> Option<String> {
     }
 }
 
+/// Output arriving within this window after an input write is treated as the
+/// terminal echoing the input, not as the agent responding to it.
+const INPUT_ECHO_GRACE_SECS: i64 = 2;
+
+/// Whether the user spoke last: input was written and the PTY has produced no
+/// output since, beyond the echo of that input.
+///
+/// A quiet session where this is false is sitting at its prompt waiting for
+/// the user (Idle); a quiet session where it is true has been given work and
+/// gone silent (Stuck).
+fn input_is_latest(activity: &PtyActivity) -> bool {
+    let Some(input) = activity
+        .last_input_at
+        .as_deref()
+        .and_then(|t| DateTime::parse_from_rfc3339(t).ok())
+    else {
+        return false;
+    };
+    match activity
+        .last_output_at
+        .as_deref()
+        .and_then(|t| DateTime::parse_from_rfc3339(t).ok())
+    {
+        Some(output) => output <= input + chrono::Duration::seconds(INPUT_ECHO_GRACE_SECS),
+        None => true,
+    }
+}
+
 /// Enrich session with health metrics
+///
+/// For daemon sessions, `pty_activity` carries the daemon's last-output and
+/// last-input timestamps. When present, PTY output replaces `kild.json`
+/// `last_activity` as the activity signal, since the daemon sees every byte
+/// the agent writes while `last_activity` only moves on lifecycle events.
 pub fn enrich_session_with_health(
     session: &Session,
     process_metrics: Option<ProcessMetrics>,
     process_running: bool,
     agent_status: Option<AgentStatus>,
     agent_status_updated_at: Option<String>,
+    pty_activity: Option<&PtyActivity>,
 ) -> KildHealth {
-    // Use the most recent of kild.json last_activity and sidecar updated_at.
+    // Use the most recent of the base activity signal and sidecar updated_at.
     // Agent hook updates only touch the sidecar; kild.json last_activity
     // reflects lifecycle events only.
-    let effective_last_activity = most_recent_activity(
-        session.last_activity.as_deref(),
-        agent_status_updated_at.as_deref(),
-    );
+    let base_activity = match pty_activity.and_then(|a| a.last_output_at.as_deref()) {
+        Some(output) => Some(output),
+        None => session.last_activity.as_deref(),
+    };
+    let effective_last_activity =
+        most_recent_activity(base_activity, agent_status_updated_at.as_deref());
+    let last_message_from_user = pty_activity.is_some_and(input_is_latest);
     let status = calculate_health_status(
         process_running,
         effective_last_activity.as_deref(),
-        false, // TODO: Track last message sender in future
+        last_message_from_user,
     );
 
     let status_icon = match status {
@@ -107,6 +144,8 @@ pub fn enrich_session_with_health(
             "Stopped".to_string()
         },
         last_activity: effective_last_activity,
+        last_output_at: pty_activity.and_then(|a| a.last_output_at.clone()),
+        last_input_at: pty_activity.and_then(|a| a.last_input_at.clone()),
         status,
         status_icon: status_icon.to_string(),
     };
@@ -246,7 +285,7 @@ mod tests {
             memory_usage_bytes: 100 * 1024 * 1024,
         };
 
-        let health = enrich_session_with_health(&session, Some(metrics), true, None, None);
+        let health = enrich_session_with_health(&session, Some(metrics), true, None, None, None);
 
         assert_eq!(health.branch, "test-branch");
         assert_eq!(health.metrics.process_status, "Running");
@@ -258,7 +297,7 @@ mod tests {
     fn test_enrich_session_stopped_no_metrics() {
         let session = Session::new_for_test("stopped".to_string(), PathBuf::from("/tmp/test"));
 
-        let health = enrich_session_with_health(&session, None, false, None, None);
+        let health = enrich_session_with_health(&session, None, false, None, None, None);
 
         assert_eq!(health.metrics.process_status, "Stopped");
         assert_eq!(health.metrics.status, HealthStatus::Crashed);
@@ -271,7 +310,7 @@ mod tests {
     fn test_enrich_session_copies_session_fields() {
         let session = Session::new_for_test("my-branch".to_string(), PathBuf::from("/tmp/wt"));
 
-        let health = enrich_session_with_health(&session, None, false, None, None);
+        let health = enrich_session_with_health(&session, None, false, None, None, None);
 
         assert_eq!(health.session_id, session.id.to_string());
         assert_eq!(health.project_id, session.project_id.to_string());
@@ -293,6 +332,7 @@ mod tests {
             true,
             Some(AgentStatus::Working),
             Some("2026-02-05T12:00:00Z".to_string()),
+            None,
         );
 
         assert_eq!(health.agent_status, Some(AgentStatus::Working));
@@ -306,7 +346,7 @@ mod tests {
     fn test_enrich_session_without_agent_status() {
         let session = Session::new_for_test("test".to_string(), PathBuf::from("/tmp"));
 
-        let health = enrich_session_with_health(&session, None, true, None, None);
+        let health = enrich_session_with_health(&session, None, true, None, None, None);
 
         assert_eq!(health.agent_status, None);
         assert_eq!(health.agent_status_updated_at, None);
@@ -329,6 +369,7 @@ mod tests {
                 true,
                 Some(status),
                 Some("2026-02-05T12:00:00Z".to_string()),
+                None,
             );
 
             assert_eq!(health.agent_status, Some(status));
@@ -345,6 +386,7 @@ mod tests {
             true,
             Some(AgentStatus::Idle),
             Some("2026-02-05T12:00:00Z".to_string()),
+            None,
         );
 
         let json = serde_json::to_string(&health).unwrap();
@@ -358,7 +400,7 @@ mod tests {
     fn test_agent_status_none_json_serialization() {
         let session = Session::new_for_test("test".to_string(), PathBuf::from("/tmp"));
 
-        let health = enrich_session_with_health(&session, None, true, None, None);
+        let health = enrich_session_with_health(&session, None, true, None, None, None);
 
         let json = serde_json::to_string(&health).unwrap();
         let parsed: serde_json::Value = serde_json::from_str(&json).unwrap();
@@ -367,6 +409,74 @@ mod tests {
         assert!(parsed["agent_status_updated_at"].is_null());
     }
 
+    // --- PTY activity tests ---
+
+    fn pty_activity(output_mins_ago: Option<i64>, input_mins_ago: Option<i64>) -> PtyActivity {
+        let ago = |m: i64| (Utc::now() - chrono::Duration::minutes(m)).to_rfc3339();
+        PtyActivity {
+            last_output_at: output_mins_ago.map(ago),
+            last_input_at: input_mins_ago.map(ago),
+        }
+    }
+
+    #[test]
+    fn test_pty_output_overrides_stale_last_activity() {
+        set_idle_threshold_minutes(10);
+        let mut session = Session::new_for_test("busy".to_string(), PathBuf::from("/tmp"));
+        session.last_activity = Some((Utc::now() - chrono::Duration::minutes(300)).to_rfc3339());
+        let activity = pty_activity(Some(0), None);
+
+        let health = enrich_session_with_health(&session, None, true, None, None, Some(&activity));
+
+        assert_eq!(health.metrics.status, HealthStatus::Working);
+        assert_eq!(health.metrics.last_output_at, activity.last_output_at);
+    }
+
+    #[test]
+    fn test_pty_silence_overrides_recent_last_activity() {
+        set_idle_threshold_minutes(10);
+        let mut session = Session::new_for_test("hung".to_string(), PathBuf::from("/tmp"));
+        session.last_activity = Some(Utc::now().to_rfc3339());
+        let activity = pty_activity(Some(200), Some(300));
+
+        let health = enrich_session_with_health(&session, None, true, None, None, Some(&activity));
+
+        // Output after the last input: agent answered and is waiting at its prompt
+        assert_eq!(health.metrics.status, HealthStatus::Idle);
+    }
+
+    #[test]
+    fn test_pty_silent_after_input_is_stuck() {
+        set_idle_threshold_minutes(10);
+        let session = Session::new_for_test("stuck".to_string(), PathBuf::from("/tmp"));
+        let activity = pty_activity(Some(200), Some(200));
+
+        let health = enrich_session_with_health(&session, None, true, None, None, Some(&activity));
+
+        // Only the echo of the input followed it: the agent never responded
+        assert_eq!(health.metrics.status, HealthStatus::Stuck);
+        assert_eq!(health.metrics.last_input_at, activity.last_input_at);
+    }
+
+    #[test]
+    fn test_input_is_latest() {
+        assert!(!input_is_latest(&PtyActivity::default()));
+        assert!(input_is_latest(&pty_activity(None, Some(5))));
+        assert!(input_is_latest(&pty_activity(Some(6), Some(5))));
+        assert!(!input_is_latest(&pty_activity(Some(1), Some(5))));
+    }
+
+    #[test]
+    fn test_pty_activity_fields_omitted_from_json_when_absent() {
+        let session = Session::new_for_test("test".to_string(), PathBuf::from("/tmp"));
+
+        let health = enrich_session_with_health(&session, None, true, None, None, None);
+        let json = serde_json::to_string(&health).unwrap();
+
+        assert!(!json.contains("last_output_at"));
+        assert!(!json.contains("last_input_at"));
+    }
+
     // --- aggregate_health_stats tests ---
 
     #[test]
@@ -383,16 +493,16 @@ mod tests {
     fn test_aggregate_mixed_statuses() {
         let session = Session::new_for_test("b".to_string(), PathBuf::from("/tmp"));
 
-        let mut working = enrich_session_with_health(&session, None, true, None, None);
+        let mut working = enrich_session_with_health(&session, None, true, None, None, None);
         working.metrics.status = HealthStatus::Working;
 
-        let mut idle = enrich_session_with_health(&session, None, true, None, None);
+        let mut idle = enrich_session_with_health(&session, None, true, None, None, None);
         idle.metrics.status = HealthStatus::Idle;
 
-        let mut crashed = enrich_session_with_health(&session, None, false, None, None);
+        let mut crashed = enrich_session_with_health(&session, None, false, None, None, None);
         crashed.metrics.status = HealthStatus::Crashed;
 
-        let mut unknown = enrich_session_with_health(&session, None, true, None, None);
+        let mut unknown = enrich_session_with_health(&session, None, true, None, None, None);
         unknown.metrics.status = HealthStatus::Unknown;
 
         let output = aggregate_health_stats(&[working, idle, crashed, unknown]);
@@ -2,7 +2,7 @@
 //!
 //! Stores health snapshots over time for trend analysis.
 
-use crate::health::types::HealthOutput;
+use crate::health::types::{HealthOutput, HealthStatus};
 use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
 use std::fs;
@@ -21,6 +21,19 @@ pub struct HealthSnapshot {
     pub crashed: usize,
     pub avg_cpu_percent: Option<f32>,
     pub total_memory_mb: Option<u64>,
+    /// Per-kild status at snapshot time. Empty for snapshots written before
+    /// per-kild tracking was added.
+    #[serde(default)]
+    pub kilds: Vec<KildSnapshot>,
+}
+
+/// Status of a single kild captured in a [`HealthSnapshot`].
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct KildSnapshot {
+    pub branch: String,
+    pub status: HealthStatus,
+    pub cpu_usage_percent: Option<f32>,
+    pub memory_usage_mb: Option<u64>,
 }
 
 impl From<&HealthOutput> for HealthSnapshot {
@@ -50,6 +63,16 @@ impl From<&HealthOutput> for HealthSnapshot {
                 None
             },
             total_memory_mb: if total_mem > 0 { Some(total_mem) } else { None },
+            kilds: output
+                .kilds
+                .iter()
+                .map(|k| KildSnapshot {
+                    branch: k.branch.clone(),
+                    status: k.metrics.status.clone(),
+                    cpu_usage_percent: k.metrics.cpu_usage_percent,
+                    memory_usage_mb: k.metrics.memory_usage_mb,
+                })
+                .collect(),
         }
     }
 }
@@ -264,6 +287,7 @@ mod tests {
             crashed,
             avg_cpu_percent: Some(15.0),
             total_memory_mb: Some(512),
+            kilds: vec![],
         }
     }
 
@@ -273,6 +297,8 @@ mod tests {
             memory_usage_mb: Some(256),
             process_status: "Running".to_string(),
             last_activity: None,
+            last_output_at: None,
+            last_input_at: None,
             status: HealthStatus::Working,
             status_icon: "check".to_string(),
         };
@@ -308,6 +334,24 @@ mod tests {
         assert_eq!(snapshot.working, 1);
         assert_eq!(snapshot.avg_cpu_percent, Some(20.0));
         assert_eq!(snapshot.total_memory_mb, Some(256));
+        assert_eq!(snapshot.kilds.len(), 1);
+        assert_eq!(snapshot.kilds[0].branch, "main");
+        assert_eq!(snapshot.kilds[0].status, HealthStatus::Working);
+    }
+
+    #[test]
+    fn test_load_history_accepts_snapshots_without_kilds() {
+        let dir = TempDir::new().unwrap();
+        let today = Utc::now().format("%Y-%m-%d").to_string();
+        let legacy = format!(
+            r#"[{{"timestamp":"{}","total_kilds":1,"working":1,"idle":0,"stuck":0,"crashed":0,"avg_cpu_percent":null,"total_memory_mb":null}}]"#,
+            Utc::now().to_rfc3339()
+        );
+        fs::write(dir.path().join(format!("{}.json", today)), legacy).unwrap();
+
+        let loaded = load_history_from(dir.path(), 1).unwrap();
+        assert_eq!(loaded.len(), 1);
+        assert!(loaded[0].kilds.is_empty());
     }
 
     #[test]
@@ -334,6 +378,8 @@ mod tests {
             memory_usage_mb: None,
             process_status: "Stopped".to_string(),
             last_activity: None,
+            last_output_at: None,
+            last_input_at: None,
             status: HealthStatus::Crashed,
             status_icon: "x".to_string(),
         };
@@ -506,6 +552,7 @@ mod tests {
             crashed: 0,
             avg_cpu_percent: None,
             total_memory_mb: None,
+            kilds: vec![],
         };
         let old_date = old_ts.format("%Y-%m-%d").to_string();
         let old_path = dir.path().join(format!("{}.json", old_date));
@@ -16,10 +16,25 @@ pub struct HealthMetrics {
     pub memory_usage_mb: Option<u64>,
     pub process_status: String,
     pub last_activity: Option<String>,
+    /// Last PTY output time, for daemon-managed sessions.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub last_output_at: Option<String>,
+    /// Last PTY input time, for daemon-managed sessions.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub last_input_at: Option<String>,
     pub status: HealthStatus,
     pub status_icon: String,
 }
 
+/// PTY output/input timestamps reported by the daemon for a session.
+///
+/// Sourced from `DaemonSessionStatus`; both are RFC3339 strings.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct PtyActivity {
+    pub last_output_at: Option<String>,
+    pub last_input_at: Option<String>,
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct KildHealth {
     pub session_id: String,
@@ -12,6 +12,7 @@
 //! - [`agents`] - Agent backend management
 
 pub mod agents;
+pub mod cache;
 pub mod cleanup;
 pub mod daemon;
 pub mod editor;
@@ -29,6 +30,7 @@ pub mod projects;
 pub mod sessions;
 pub mod state;
 pub mod terminal;
+pub mod usage;
 
 // Re-export newtypes and shared domain enums from kild-protocol
 pub use kild_protocol::{
@@ -42,23 +44,27 @@ pub use forge::types::{
 };
 pub use forge::{ForgeBackend, ForgeError, ForgeType};
 pub use git::types::{
-    BaseBranchDrift, BranchHealth, CleanKild, CommitActivity, ConflictStatus, DiffStats,
-    FileOverlap, GitStats, OverlapReport, UncommittedDetails, WorktreeStatus,
+    BaseBranchDrift, BranchHealth, Checkpoint, CheckpointTrigger, CleanKild, CommitActivity,
+    CompareSide, ComparedFile, ConflictStatus, DiffStats, FileChange, FileChangeKind, FileOverlap,
+    GitStats, KildComparison, OverlapReport, UncommittedDetails, WorktreeStatus,
 };
 pub use kild_config::ConfigError;
 pub use kild_config::{
-    AgentConfig, AgentSettings, Config, DaemonRuntimeConfig, EditorConfig, GitConfig, HealthConfig,
-    Keybindings, KildConfig, TerminalConfig, UiConfig, VALID_TERMINALS,
+    AgentConfig, AgentSettings, ApprovalsConfig, CheckpointsConfig, Config, DaemonRuntimeConfig,
+    EditorConfig, GitConfig, HealthConfig, Keybindings, KildConfig, ModelPrice, NotifyConfig,
+    NotifyEvent, TerminalConfig, UiConfig, UsageConfig, VALID_TERMINALS,
 };
 pub use kild_config::{CopyOptions, IncludeConfig, PatternRule};
 pub use projects::{Project, ProjectError, ProjectRegistry, ProjectsData};
+pub use sessions::CreateOutcome;
 pub use sessions::agent_status::AgentStatusResult;
 pub use sessions::info::SessionSnapshot;
 pub use sessions::types::{
     AgentProcess, AgentStatusRecord, CompleteRequest, CompleteResult, CreateSessionRequest,
     DestroySafety, GitStatus, ProcessStatus, Session, SessionStatus,
 };
-pub use state::{Command, CoreStore, DispatchError, Event, Store};
+pub use state::{Command, CoreStore, CreateKildOptions, DispatchError, Event, Store};
+pub use usage::{KildUsage, ProjectUsage, TokenUsage};
 
 // Re-export handler modules as the primary API
 pub use cleanup::handler as cleanup_ops;
@@ -0,0 +1,140 @@
+//! Command sink channel: runs a shell command per notification.
+
+use std::io::Write;
+use std::process::{Command, Stdio};
+use std::time::{Duration, Instant};
+
+use kild_config::{CommandChannel as CommandConfig, NotifyEvent};
+
+use crate::notify::errors::NotifyError;
+use crate::notify::traits::NotificationChannel;
+use crate::notify::types::Notification;
+
+/// Commands still running after this are killed.
+const COMMAND_TIMEOUT: Duration = Duration::from_secs(10);
+
+/// Runs `sh -c <command>` with the JSON payload on stdin and
+/// `KILD_NOTIFY_{EVENT,BRANCH,PROJECT,AGENT,STATUS,TITLE,MESSAGE}` set.
+pub struct CommandChannel {
+    config: CommandConfig,
+}
+
+impl CommandChannel {
+    pub fn new(config: CommandConfig) -> Self {
+        Self { config }
+    }
+}
+
+impl NotificationChannel for CommandChannel {
+    fn name(&self) -> &'static str {
+        "command"
+    }
+
+    fn events(&self) -> Option<&[NotifyEvent]> {
+        self.config.events.as_deref()
+    }
+
+    fn send(&self, notification: &Notification) -> Result<(), NotifyError> {
+        let payload = serde_json::to_vec(notification).map_err(|e| NotifyError::SendFailed {
+            message: format!("failed to serialize payload: {}", e),
+        })?;
+
+        let mut child = Command::new("sh")
+            .arg("-c")
+            .arg(&self.config.command)
+            .env("KILD_NOTIFY_EVENT", notification.event.to_string())
+            .env("KILD_NOTIFY_BRANCH", &notification.branch)
+            .env("KILD_NOTIFY_PROJECT", &notification.project)
+            .env("KILD_NOTIFY_AGENT", &notification.agent)
+            .env("KILD_NOTIFY_STATUS", &notification.status)
+            .env("KILD_NOTIFY_TITLE", &notification.title)
+            .env("KILD_NOTIFY_MESSAGE", &notification.message)
+            .stdin(Stdio::piped())
+            .stdout(Stdio::null())
+            .stderr(Stdio::null())
+            .spawn()
+            .map_err(|e| NotifyError::SendFailed {
+                message: format!("notify command exec failed: {}", e),
+            })?;
+
+        if let Some(mut stdin) = child.stdin.take() {
+            // Commands are free to ignore stdin
+            let _ = stdin.write_all(&payload);
+        }
+
+        let deadline = Instant::now() + COMMAND_TIMEOUT;
+        loop {
+            match child.try_wait() {
+                Ok(Some(status)) if status.success() => return Ok(()),
+                Ok(Some(status)) => {
+                    return Err(NotifyError::SendFailed {
+                        message: format!("notify command exited with {}", status),
+                    });
+                }
+                Ok(None) if Instant::now() >= deadline => {
+                    let _ = child.kill();
+                    let _ = child.wait();
+                    return Err(NotifyError::SendFailed {
+                        message: format!(
+                            "notify command timed out after {}s",
+                            COMMAND_TIMEOUT.as_secs()
+                        ),
+                    });
+                }
+                Ok(None) => std::thread::sleep(Duration::from_millis(20)),
+                Err(e) => {
+                    return Err(NotifyError::SendFailed {
+                        message: format!("notify command wait failed: {}", e),
+                    });
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn notification() -> Notification {
+        Notification {
+            event: NotifyEvent::Error,
+            title: "KILD".to_string(),
+            message: "Agent claude in feat needs input (error)".to_string(),
+            branch: "feat".to_string(),
+            project: "app".to_string(),
+            agent: "claude".to_string(),
+            status: "error".to_string(),
+            timestamp: "2026-01-01T00:00:00Z".to_string(),
+        }
+    }
+
+    #[test]
+    fn test_command_receives_env_and_stdin() {
+        let tmp = tempfile::TempDir::new().unwrap();
+        let out = tmp.path().join("out");
+        let channel = CommandChannel::new(CommandConfig {
+            command: format!(
+                "{{ echo \"$KILD_NOTIFY_EVENT $KILD_NOTIFY_BRANCH $KILD_NOTIFY_PROJECT\"; cat; }} > '{}'",
+                out.display()
+            ),
+            events: None,
+        });
+        channel.send(&notification()).unwrap();
+
+        let written = std::fs::read_to_string(&out).unwrap();
+        let (env_line, stdin) = written.split_once('\n').unwrap();
+        assert_eq!(env_line, "error feat app");
+        let json: serde_json::Value = serde_json::from_str(stdin).unwrap();
+        assert_eq!(json["event"], "error");
+    }
+
+    #[test]
+    fn test_command_failure_is_reported() {
+        let channel = CommandChannel::new(CommandConfig {
+            command: "exit 3".to_string(),
+            events: None,
+        });
+        assert!(channel.send(&notification()).is_err());
+    }
+}
@@ -0,0 +1,27 @@
+//! Desktop channel: the platform [`NotificationBackend`](crate::notify::NotificationBackend).
+
+use kild_config::NotifyEvent;
+
+use crate::notify::errors::NotifyError;
+use crate::notify::registry;
+use crate::notify::traits::NotificationChannel;
+use crate::notify::types::Notification;
+
+/// Desktop notifications via notify-send/osascript.
+///
+/// Sending is a no-op when no desktop backend is available (e.g. over SSH).
+pub struct DesktopChannel;
+
+impl NotificationChannel for DesktopChannel {
+    fn name(&self) -> &'static str {
+        "desktop"
+    }
+
+    fn events(&self) -> Option<&[NotifyEvent]> {
+        None
+    }
+
+    fn send(&self, notification: &Notification) -> Result<(), NotifyError> {
+        registry::send_via_backend(&notification.title, &notification.message).map(|_| ())
+    }
+}
@@ -0,0 +1,175 @@
+//! Minimal HTTP POST helper for webhook and push channels.
+//!
+//! Shells out to `curl` (like the agent hook scripts) so HTTPS works without
+//! pulling a TLS client into kild-core.
+
+use std::io::Write;
+use std::process::{Command, Stdio};
+
+use crate::notify::errors::NotifyError;
+
+/// Upper bound on a single delivery so a dead endpoint can't stall a hook.
+const REQUEST_TIMEOUT_SECS: &str = "10";
+
+/// POST `body` to `url` with the given headers. Non-2xx responses are errors.
+pub(super) fn post(
+    url: &str,
+    headers: &[(String, String)],
+    body: &[u8],
+) -> Result<(), NotifyError> {
+    if which::which("curl").is_err() {
+        return Err(NotifyError::ToolNotFound {
+            tool: "curl".to_string(),
+        });
+    }
+
+    let mut cmd = Command::new("curl");
+    cmd.args([
+        "--silent",
+        "--show-error",
+        "--fail",
+        "--max-time",
+        REQUEST_TIMEOUT_SECS,
+        "--request",
+        "POST",
+        "--data-binary",
+        "@-",
+    ]);
+    for (name, value) in headers {
+        cmd.arg("--header").arg(format!("{}: {}", name, value));
+    }
+    cmd.arg("--")
+        .arg(url)
+        .stdin(Stdio::piped())
+        .stdout(Stdio::null())
+        .stderr(Stdio::piped());
+
+    let mut child = cmd.spawn().map_err(|e| NotifyError::SendFailed {
+        message: format!("curl exec failed: {}", e),
+    })?;
+    if let Some(mut stdin) = child.stdin.take() {
+        // A write error surfaces as a curl failure below
+        let _ = stdin.write_all(body);
+    }
+    let output = child
+        .wait_with_output()
+        .map_err(|e| NotifyError::SendFailed {
+            message: format!("curl wait failed: {}", e),
+        })?;
+
+    if output.status.success() {
+        Ok(())
+    } else {
+        let stderr = String::from_utf8_lossy(&output.stderr);
+        Err(NotifyError::SendFailed {
+            message: format!("POST {} failed: {}", url, stderr.trim()),
+        })
+    }
+}
+
+/// Single-request HTTP stand-in for channel tests.
+#[cfg(test)]
+pub(super) mod test_server {
+    use std::io::{BufRead, BufReader, Read, Write};
+    use std::net::TcpListener;
+    use std::sync::mpsc;
+
+    /// A captured request.
+    pub struct Request {
+        pub request_line: String,
+        pub headers: Vec<(String, String)>,
+        pub body: String,
+    }
+
+    impl Request {
+        pub fn header(&self, name: &str) -> Option<&str> {
+            self.headers
+                .iter()
+                .find(|(n, _)| n.eq_ignore_ascii_case(name))
+                .map(|(_, v)| v.as_str())
+        }
+    }
+
+    /// Serve one request with `status`, returning the base URL and a
+    /// receiver for the captured request.
+    pub fn serve_once(status: u16) -> (String, mpsc::Receiver<Request>) {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let url = format!("http://{}", listener.local_addr().unwrap());
+        let (tx, rx) = mpsc::channel();
+        std::thread::spawn(move || {
+            let Ok((stream, _)) = listener.accept() else {
+                return;
+            };
+            let mut reader = BufReader::new(stream);
+            let mut request_line = String::new();
+            reader.read_line(&mut request_line).unwrap();
+            let mut headers = Vec::new();
+            loop {
+                let mut line = String::new();
+                reader.read_line(&mut line).unwrap();
+                let line = line.trim_end();
+                if line.is_empty() {
+                    break;
+                }
+                if let Some((name, value)) = line.split_once(':') {
+                    headers.push((name.trim().to_string(), value.trim().to_string()));
+                }
+            }
+            let len = headers
+                .iter()
+                .find(|(n, _)| n.eq_ignore_ascii_case("content-length"))
+                .and_then(|(_, v)| v.parse().ok())
+                .unwrap_or(0);
+            let mut body = vec![0; len];
+            reader.read_exact(&mut body).unwrap();
+
+            let mut stream = reader.into_inner();
+            let _ = write!(
+                stream,
+                "HTTP/1.1 {} X\r\nContent-Length: 0\r\nConnection: close\r\n\r\n",
+                status
+            );
+            let _ = tx.send(Request {
+                request_line: request_line.trim_end().to_string(),
+                headers,
+                body: String::from_utf8_lossy(&body).to_string(),
+            });
+        });
+        (url, rx)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::time::Duration;
+
+    #[test]
+    fn test_post_sends_body_and_headers() {
+        if which::which("curl").is_err() {
+            return;
+        }
+        let (url, rx) = test_server::serve_once(200);
+        post(
+            &format!("{}/hook", url),
+            &[("X-Test".to_string(), "yes".to_string())],
+            b"hello",
+        )
+        .unwrap();
+
+        let req = rx.recv_timeout(Duration::from_secs(5)).unwrap();
+        assert_eq!(req.request_line, "POST /hook HTTP/1.1");
+        assert_eq!(req.header("x-test"), Some("yes"));
+        assert_eq!(req.body, "hello");
+    }
+
+    #[test]
+    fn test_post_fails_on_server_error() {
+        if which::which("curl").is_err() {
+            return;
+        }
+        let (url, _rx) = test_server::serve_once(500);
+        let err = post(&url, &[], b"{}").unwrap_err();
+        assert!(matches!(err, NotifyError::SendFailed { .. }));
+    }
+}
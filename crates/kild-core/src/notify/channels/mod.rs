@@ -0,0 +1,66 @@
+//! Configurable notification channels built from `[notify]` config.
+
+mod command;
+mod desktop;
+mod http;
+mod push;
+mod webhook;
+
+pub use command::CommandChannel;
+pub use desktop::DesktopChannel;
+pub use push::PushChannel;
+pub use webhook::WebhookChannel;
+
+use kild_config::NotifyConfig;
+
+use super::traits::NotificationChannel;
+
+/// Build every channel enabled in the config, desktop first.
+pub fn from_config(config: &NotifyConfig) -> Vec<Box<dyn NotificationChannel>> {
+    let mut channels: Vec<Box<dyn NotificationChannel>> = Vec::new();
+    if config.desktop() {
+        channels.push(Box::new(DesktopChannel));
+    }
+    for webhook in &config.webhook {
+        channels.push(Box::new(WebhookChannel::new(webhook.clone())));
+    }
+    for push in &config.push {
+        channels.push(Box::new(PushChannel::new(push.clone())));
+    }
+    for command in &config.command {
+        channels.push(Box::new(CommandChannel::new(command.clone())));
+    }
+    channels
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_from_config_defaults_to_desktop_only() {
+        let channels = from_config(&NotifyConfig::default());
+        let names: Vec<&str> = channels.iter().map(|c| c.name()).collect();
+        assert_eq!(names, vec!["desktop"]);
+    }
+
+    #[test]
+    fn test_from_config_combines_channels() {
+        let config: NotifyConfig = toml::from_str(
+            r#"
+desktop = false
+[[webhook]]
+url = "http://a"
+[[push]]
+url = "http://b/topic"
+[[command]]
+command = "true"
+[[command]]
+command = "false"
+"#,
+        )
+        .unwrap();
+        let names: Vec<&str> = from_config(&config).iter().map(|c| c.name()).collect();
+        assert_eq!(names, vec!["webhook", "push", "command", "command"]);
+    }
+}
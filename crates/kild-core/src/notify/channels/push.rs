@@ -0,0 +1,164 @@
+//! Phone push channel for ntfy- and Gotify-compatible servers.
+
+use kild_config::{NotifyEvent, PushChannel as PushConfig, PushService};
+
+use super::http;
+use crate::notify::errors::NotifyError;
+use crate::notify::traits::NotificationChannel;
+use crate::notify::types::Notification;
+
+/// Pushes notifications to an ntfy topic or a Gotify application.
+pub struct PushChannel {
+    config: PushConfig,
+}
+
+impl PushChannel {
+    pub fn new(config: PushConfig) -> Self {
+        Self { config }
+    }
+
+    fn title(notification: &Notification) -> String {
+        format!("{}: {}", notification.project, notification.branch)
+    }
+
+    /// Events that need the user get high priority; `done` is informational.
+    fn is_urgent(event: NotifyEvent) -> bool {
+        !matches!(event, NotifyEvent::Done)
+    }
+
+    /// ntfy: message as the body, metadata in headers.
+    fn send_ntfy(&self, notification: &Notification) -> Result<(), NotifyError> {
+        let priority = if Self::is_urgent(notification.event) {
+            "high"
+        } else {
+            "default"
+        };
+        let mut headers = vec![
+            ("Title".to_string(), Self::title(notification)),
+            ("Priority".to_string(), priority.to_string()),
+            ("Tags".to_string(), format!("kild,{}", notification.event)),
+        ];
+        if let Some(token) = &self.config.token {
+            headers.push(("Authorization".to_string(), format!("Bearer {}", token)));
+        }
+        http::post(&self.config.url, &headers, notification.message.as_bytes())
+    }
+
+    /// Gotify: JSON message posted to `/message` with an app token.
+    fn send_gotify(&self, notification: &Notification) -> Result<(), NotifyError> {
+        let Some(token) = &self.config.token else {
+            return Err(NotifyError::SendFailed {
+                message: "gotify push channel requires a `token`".to_string(),
+            });
+        };
+        let priority = if Self::is_urgent(notification.event) {
+            8
+        } else {
+            5
+        };
+        let body = serde_json::json!({
+            "title": Self::title(notification),
+            "message": notification.message,
+            "priority": priority,
+        });
+        let url = format!("{}/message", self.config.url.trim_end_matches('/'));
+        let headers = [
+            ("Content-Type".to_string(), "application/json".to_string()),
+            ("X-Gotify-Key".to_string(), token.clone()),
+        ];
+        http::post(&url, &headers, body.to_string().as_bytes())
+    }
+}
+
+impl NotificationChannel for PushChannel {
+    fn name(&self) -> &'static str {
+        "push"
+    }
+
+    fn events(&self) -> Option<&[NotifyEvent]> {
+        self.config.events.as_deref()
+    }
+
+    fn send(&self, notification: &Notification) -> Result<(), NotifyError> {
+        match self.config.service {
+            PushService::Ntfy => self.send_ntfy(notification),
+            PushService::Gotify => self.send_gotify(notification),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::http::test_server;
+    use super::*;
+    use std::time::Duration;
+
+    fn notification(event: NotifyEvent) -> Notification {
+        Notification {
+            event,
+            title: "KILD".to_string(),
+            message: "Agent claude in feat finished (idle)".to_string(),
+            branch: "feat".to_string(),
+            project: "app".to_string(),
+            agent: "claude".to_string(),
+            status: "idle".to_string(),
+            timestamp: "2026-01-01T00:00:00Z".to_string(),
+        }
+    }
+
+    #[test]
+    fn test_ntfy_push_uses_headers() {
+        if which::which("curl").is_err() {
+            return;
+        }
+        let (url, rx) = test_server::serve_once(200);
+        let channel = PushChannel::new(PushConfig {
+            url: format!("{}/my-kilds", url),
+            service: PushService::Ntfy,
+            token: Some("tk".to_string()),
+            events: None,
+        });
+        channel.send(&notification(NotifyEvent::Done)).unwrap();
+
+        let req = rx.recv_timeout(Duration::from_secs(5)).unwrap();
+        assert_eq!(req.request_line, "POST /my-kilds HTTP/1.1");
+        assert_eq!(req.header("title"), Some("app: feat"));
+        assert_eq!(req.header("priority"), Some("default"));
+        assert_eq!(req.header("tags"), Some("kild,done"));
+        assert_eq!(req.header("authorization"), Some("Bearer tk"));
+        assert_eq!(req.body, "Agent claude in feat finished (idle)");
+    }
+
+    #[test]
+    fn test_gotify_push_posts_json_message() {
+        if which::which("curl").is_err() {
+            return;
+        }
+        let (url, rx) = test_server::serve_once(200);
+        let channel = PushChannel::new(PushConfig {
+            url: format!("{}/", url),
+            service: PushService::Gotify,
+            token: Some("app-token".to_string()),
+            events: None,
+        });
+        channel.send(&notification(NotifyEvent::Crashed)).unwrap();
+
+        let req = rx.recv_timeout(Duration::from_secs(5)).unwrap();
+        assert_eq!(req.request_line, "POST /message HTTP/1.1");
+        assert_eq!(req.header("x-gotify-key"), Some("app-token"));
+        let json: serde_json::Value = serde_json::from_str(&req.body).unwrap();
+        assert_eq!(json["title"], "app: feat");
+        assert_eq!(json["priority"], 8);
+    }
+
+    #[test]
+    fn test_gotify_requires_token() {
+        let channel = PushChannel::new(PushConfig {
+            url: "http://127.0.0.1:9".to_string(),
+            service: PushService::Gotify,
+            token: None,
+            events: None,
+        });
+        assert!(channel.send(&notification(NotifyEvent::Done)).is_err());
+    }
+}
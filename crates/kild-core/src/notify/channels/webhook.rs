@@ -0,0 +1,86 @@
+//! Generic HTTP webhook channel.
+
+use kild_config::{NotifyEvent, WebhookChannel as WebhookConfig};
+
+use super::http;
+use crate::notify::errors::NotifyError;
+use crate::notify::traits::NotificationChannel;
+use crate::notify::types::Notification;
+
+/// POSTs the [`Notification`] as JSON to a configured URL.
+pub struct WebhookChannel {
+    config: WebhookConfig,
+}
+
+impl WebhookChannel {
+    pub fn new(config: WebhookConfig) -> Self {
+        Self { config }
+    }
+}
+
+impl NotificationChannel for WebhookChannel {
+    fn name(&self) -> &'static str {
+        "webhook"
+    }
+
+    fn events(&self) -> Option<&[NotifyEvent]> {
+        self.config.events.as_deref()
+    }
+
+    fn send(&self, notification: &Notification) -> Result<(), NotifyError> {
+        let body = serde_json::to_vec(notification).map_err(|e| NotifyError::SendFailed {
+            message: format!("failed to serialize payload: {}", e),
+        })?;
+        let mut headers = vec![("Content-Type".to_string(), "application/json".to_string())];
+        headers.extend(
+            self.config
+                .headers
+                .iter()
+                .map(|(k, v)| (k.clone(), v.clone())),
+        );
+        http::post(&self.config.url, &headers, &body)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::http::test_server;
+    use super::*;
+    use std::collections::HashMap;
+    use std::time::Duration;
+
+    #[test]
+    fn test_webhook_posts_json_payload() {
+        if which::which("curl").is_err() {
+            return;
+        }
+        let (url, rx) = test_server::serve_once(200);
+        let channel = WebhookChannel::new(WebhookConfig {
+            url: format!("{}/kild", url),
+            headers: HashMap::from([("Authorization".to_string(), "Bearer t".to_string())]),
+            events: None,
+        });
+        let notification = Notification {
+            event: NotifyEvent::Waiting,
+            title: "KILD".to_string(),
+            message: "Agent claude in feat needs input (waiting)".to_string(),
+            branch: "feat".to_string(),
+            project: "app".to_string(),
+            agent: "claude".to_string(),
+            status: "waiting".to_string(),
+            timestamp: "2026-01-01T00:00:00Z".to_string(),
+        };
+        channel.send(&notification).unwrap();
+
+        let req = rx.recv_timeout(Duration::from_secs(5)).unwrap();
+        assert_eq!(req.request_line, "POST /kild HTTP/1.1");
+        assert_eq!(req.header("content-type"), Some("application/json"));
+        assert_eq!(req.header("authorization"), Some("Bearer t"));
+        let json: serde_json::Value = serde_json::from_str(&req.body).unwrap();
+        assert_eq!(json["event"], "waiting");
+        assert_eq!(json["branch"], "feat");
+        assert_eq!(json["project"], "app");
+        assert_eq!(json["status"], "waiting");
+        assert_eq!(json["message"], notification.message);
+    }
+}
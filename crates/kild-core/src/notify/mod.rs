@@ -1,37 +1,89 @@
-//! Platform-native desktop notification dispatch.
+//! Notification dispatch for session events.
 //!
 //! Best-effort notifications — failures are logged but never propagate.
-//! Used by `kild agent-status --notify` to alert when an agent enters
-//! `Waiting` or `Error` status.
+//! Used by `kild agent-status --notify` when an agent reports a status, and
+//! when a daemon-managed agent exits while its kild is still active.
 //!
-//! Notifications are dispatched via the [`NotificationBackend`] trait,
-//! with platform-specific backends registered in [`registry`].
+//! Events fan out to the channels configured in `[notify]` (see
+//! [`channels`]): desktop notifications via the [`NotificationBackend`]
+//! trait (platform backends registered in [`registry`]), HTTP webhooks,
+//! ntfy/Gotify push and command sinks. Each channel filters by event.
 
 pub mod backends;
+pub mod channels;
 pub mod errors;
 pub mod registry;
 pub mod traits;
+pub mod types;
 
 pub use errors::NotifyError;
-pub use traits::NotificationBackend;
+pub use traits::{NotificationBackend, NotificationChannel};
+pub use types::{Notification, event_for_status, format_event_message};
 
+use kild_config::{KildConfig, NotifyConfig};
 use kild_protocol::AgentStatus;
-use tracing::{info, warn};
+use tracing::{debug, info, warn};
 
-/// Returns `true` if a notification should be sent for the given status.
+/// Format the notification message for an agent status change.
 ///
-/// Only `Waiting` and `Error` require user attention.
-pub fn should_notify(notify: bool, status: AgentStatus) -> bool {
-    notify && matches!(status, AgentStatus::Waiting | AgentStatus::Error)
+/// `Waiting` and `Error` read "needs input": `Waiting` means literal input
+/// is required and `Error` means the user must inspect and unblock the agent.
+pub fn format_notification_message(agent: &str, branch: &str, status: AgentStatus) -> String {
+    let event = event_for_status(status).unwrap_or(kild_config::NotifyEvent::Waiting);
+    format_event_message(event, agent, branch, &status.to_string())
 }
 
-/// Format the notification message for an agent status change.
+/// Send a notification to every channel in the loaded `[notify]` config
+/// whose filter includes its event (best-effort).
+pub fn notify_event(notification: &Notification) {
+    let config = match KildConfig::load_hierarchy() {
+        Ok(config) => config.notify,
+        Err(e) => {
+            warn!(
+                event = "core.notify.config_load_failed",
+                error = %e,
+                "Using default notification settings"
+            );
+            NotifyConfig::default()
+        }
+    };
+    dispatch(&config, notification);
+}
+
+/// Send a notification to the configured channels that want its event.
 ///
-/// The message body always reads "needs input" regardless of status.
-/// This covers both `Waiting` (literal input required) and `Error`
-/// (user must inspect and unblock the agent).
-pub fn format_notification_message(agent: &str, branch: &str, status: AgentStatus) -> String {
-    format!("Agent {} in {} needs input ({})", agent, branch, status)
+/// Returns the number of channels that delivered it. Failures are logged
+/// at warn level and don't stop delivery to the remaining channels.
+pub fn dispatch(config: &NotifyConfig, notification: &Notification) -> usize {
+    let mut delivered = 0;
+    for channel in channels::from_config(config) {
+        if !config.wants(channel.events(), notification.event) {
+            debug!(
+                event = "core.notify.channel_skipped",
+                channel = channel.name(),
+                notify_event = %notification.event,
+            );
+            continue;
+        }
+        match channel.send(notification) {
+            Ok(()) => {
+                delivered += 1;
+                info!(
+                    event = "core.notify.channel_send_completed",
+                    channel = channel.name(),
+                    notify_event = %notification.event,
+                    branch = %notification.branch,
+                );
+            }
+            Err(e) => warn!(
+                event = "core.notify.channel_send_failed",
+                channel = channel.name(),
+                notify_event = %notification.event,
+                error = %e,
+            ),
+        }
+    }
+    delivered
 }
 
 /// Send a platform-native desktop notification (best-effort).
@@ -66,35 +118,72 @@ pub fn send_notification(title: &str, message: &str) {
 mod tests {
     use super::*;
 
-    #[test]
-    fn test_should_notify_fires_for_waiting() {
-        assert!(should_notify(true, AgentStatus::Waiting));
-    }
-
-    #[test]
-    fn test_should_notify_fires_for_error() {
-        assert!(should_notify(true, AgentStatus::Error));
-    }
-
-    #[test]
-    fn test_should_notify_skips_working() {
-        assert!(!should_notify(true, AgentStatus::Working));
+    fn notify_config(toml_str: &str) -> NotifyConfig {
+        toml::from_str(toml_str).unwrap()
     }
 
-    #[test]
-    fn test_should_notify_skips_idle() {
-        assert!(!should_notify(true, AgentStatus::Idle));
+    fn notification(event: kild_config::NotifyEvent) -> Notification {
+        Notification {
+            event,
+            title: "KILD".to_string(),
+            message: "msg".to_string(),
+            branch: "feat".to_string(),
+            project: "app".to_string(),
+            agent: "claude".to_string(),
+            status: event.to_string(),
+            timestamp: "2026-01-01T00:00:00Z".to_string(),
+        }
     }
 
     #[test]
-    fn test_should_notify_skips_done() {
-        assert!(!should_notify(true, AgentStatus::Done));
+    fn test_dispatch_filters_by_event() {
+        let tmp = tempfile::TempDir::new().unwrap();
+        let log = tmp.path().join("log");
+        let config = notify_config(&format!(
+            r#"
+desktop = false
+[[command]]
+command = "echo default >> '{log}'"
+[[command]]
+command = "echo crashed-only >> '{log}'"
+events = ["crashed"]
+"#,
+            log = log.display()
+        ));
+
+        assert_eq!(
+            dispatch(&config, &notification(kild_config::NotifyEvent::Waiting)),
+            1
+        );
+        assert_eq!(
+            dispatch(&config, &notification(kild_config::NotifyEvent::Done)),
+            0
+        );
+        assert_eq!(
+            dispatch(&config, &notification(kild_config::NotifyEvent::Crashed)),
+            1
+        );
+        assert_eq!(
+            std::fs::read_to_string(&log).unwrap(),
+            "default\ncrashed-only\n"
+        );
     }
 
     #[test]
-    fn test_should_notify_suppressed_when_flag_false() {
-        assert!(!should_notify(false, AgentStatus::Waiting));
-        assert!(!should_notify(false, AgentStatus::Error));
+    fn test_dispatch_continues_after_channel_failure() {
+        let config = notify_config(
+            r#"
+desktop = false
+[[command]]
+command = "exit 1"
+[[command]]
+command = "true"
+"#,
+        );
+        assert_eq!(
+            dispatch(&config, &notification(kild_config::NotifyEvent::Error)),
+            1
+        );
     }
 
     #[test]
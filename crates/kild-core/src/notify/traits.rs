@@ -1,6 +1,9 @@
 //! Notification backend trait definition.
 
+use kild_config::NotifyEvent;
+
 use crate::notify::errors::NotifyError;
+use crate::notify::types::Notification;
 
 /// Trait defining the interface for notification backends.
 ///
@@ -21,6 +24,22 @@ pub trait NotificationBackend: Send + Sync {
     fn send(&self, title: &str, message: &str) -> Result<(), NotifyError>;
 }
 
+/// Trait for configurable notification channels (desktop, webhook, push,
+/// command).
+///
+/// Channels are built from `[notify]` config and receive the full structured
+/// [`Notification`] rather than just a title and message.
+pub trait NotificationChannel: Send + Sync {
+    /// The channel kind (e.g., "webhook", "push").
+    fn name(&self) -> &'static str;
+
+    /// The channel's own event filter, or `None` to use `[notify] events`.
+    fn events(&self) -> Option<&[NotifyEvent]>;
+
+    /// Deliver a notification.
+    fn send(&self, notification: &Notification) -> Result<(), NotifyError>;
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -0,0 +1,133 @@
+//! Structured notification payload shared by all channels.
+
+use std::path::Path;
+
+use kild_config::NotifyEvent;
+use kild_protocol::AgentStatus;
+use serde::Serialize;
+
+use crate::sessions::types::Session;
+
+/// A notification about a kild, as delivered to every channel.
+///
+/// Serialized as the JSON body for webhooks and on stdin for command sinks.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct Notification {
+    pub event: NotifyEvent,
+    pub title: String,
+    pub message: String,
+    pub branch: String,
+    pub project: String,
+    pub agent: String,
+    /// Agent status (`waiting`, `idle`, ...) or `stopped` for crashes.
+    pub status: String,
+    /// RFC 3339 timestamp of when the event was raised.
+    pub timestamp: String,
+}
+
+impl Notification {
+    /// Build a notification for a session event with the standard message.
+    pub fn for_session(session: &Session, event: NotifyEvent, status: &str) -> Self {
+        Self {
+            event,
+            title: "KILD".to_string(),
+            message: format_event_message(event, &session.agent, &session.branch, status),
+            branch: session.branch.to_string(),
+            project: project_name(&session.worktree_path)
+                .unwrap_or_else(|| session.project_id.to_string()),
+            agent: session.agent.clone(),
+            status: status.to_string(),
+            timestamp: chrono::Utc::now().to_rfc3339(),
+        }
+    }
+}
+
+/// Map an agent status report to the event it raises, if any.
+///
+/// `Idle` means the agent finished its turn, so it raises `done` just like an
+/// explicit `Done` report. `Working` never notifies.
+pub fn event_for_status(status: AgentStatus) -> Option<NotifyEvent> {
+    match status {
+        AgentStatus::Waiting => Some(NotifyEvent::Waiting),
+        AgentStatus::Error => Some(NotifyEvent::Error),
+        AgentStatus::Idle | AgentStatus::Done => Some(NotifyEvent::Done),
+        // Working, and any status added later, doesn't need the user
+        _ => None,
+    }
+}
+
+/// Human-readable message for an event.
+pub fn format_event_message(event: NotifyEvent, agent: &str, branch: &str, status: &str) -> String {
+    match event {
+        NotifyEvent::Waiting | NotifyEvent::Error => {
+            format!("Agent {} in {} needs input ({})", agent, branch, status)
+        }
+        NotifyEvent::Done => format!("Agent {} in {} finished ({})", agent, branch, status),
+        NotifyEvent::Crashed => format!("Agent {} in {} exited unexpectedly", agent, branch),
+    }
+}
+
+/// Project name from a worktree path (`.../worktrees/<project>/<branch>`),
+/// or the directory's own name for main-worktree kilds.
+fn project_name(worktree_path: &Path) -> Option<String> {
+    let parent = worktree_path.parent();
+    let in_worktrees = parent
+        .and_then(Path::parent)
+        .and_then(Path::file_name)
+        .is_some_and(|n| n == "worktrees");
+    let name = if in_worktrees {
+        parent.and_then(Path::file_name)
+    } else {
+        worktree_path.file_name()
+    };
+    name.map(|n| n.to_string_lossy().to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::path::PathBuf;
+
+    #[test]
+    fn test_event_for_status() {
+        assert_eq!(
+            event_for_status(AgentStatus::Waiting),
+            Some(NotifyEvent::Waiting)
+        );
+        assert_eq!(
+            event_for_status(AgentStatus::Error),
+            Some(NotifyEvent::Error)
+        );
+        assert_eq!(event_for_status(AgentStatus::Idle), Some(NotifyEvent::Done));
+        assert_eq!(event_for_status(AgentStatus::Done), Some(NotifyEvent::Done));
+        assert_eq!(event_for_status(AgentStatus::Working), None);
+    }
+
+    #[test]
+    fn test_for_session_fills_payload() {
+        let session = Session::new_for_test(
+            "feat-auth",
+            PathBuf::from("/home/u/.kild/worktrees/my-app/feat-auth"),
+        );
+        let n = Notification::for_session(&session, NotifyEvent::Crashed, "stopped");
+        assert_eq!(n.branch, "feat-auth");
+        assert_eq!(n.project, "my-app");
+        assert_eq!(n.status, "stopped");
+        assert_eq!(
+            n.message,
+            format!("Agent {} in feat-auth exited unexpectedly", session.agent)
+        );
+
+        let json = serde_json::to_value(&n).unwrap();
+        assert_eq!(json["event"], "crashed");
+        assert_eq!(json["project"], "my-app");
+    }
+
+    #[test]
+    fn test_format_event_message_done() {
+        assert_eq!(
+            format_event_message(NotifyEvent::Done, "claude", "feat", "idle"),
+            "Agent claude in feat finished (idle)"
+        );
+    }
+}
@@ -49,15 +49,16 @@ pub fn update_agent_status(
         status = %status,
     );
 
-    if crate::notify::should_notify(notify, status) {
+    if notify && let Some(event) = crate::notify::event_for_status(status) {
         info!(
             event = "core.session.agent_status_notify_triggered",
             branch = %session.branch,
             status = %status,
+            notify_event = %event,
         );
-        let message =
-            crate::notify::format_notification_message(&session.agent, &session.branch, status);
-        crate::notify::send_notification("KILD", &message);
+        let notification =
+            crate::notify::Notification::for_session(&session, event, &status.to_string());
+        crate::notify::notify_event(&notification);
     }
 
     Ok(AgentStatusResult {
@@ -0,0 +1,201 @@
+//! Worktree checkpoints for kilds (`kild checkpoint`, `kild rollback`).
+
+use std::path::Path;
+
+use kild_config::{Config, KildConfig};
+use tracing::{info, warn};
+
+use crate::git;
+use crate::git::types::{Checkpoint, CheckpointTrigger};
+use crate::sessions::{errors::SessionError, persistence, types::Session};
+
+fn load_valid_session(sessions_dir: &Path, name: &str) -> Result<Session, SessionError> {
+    let session = persistence::load_session_from_file(name, sessions_dir)?;
+    if !session.is_worktree_valid() {
+        return Err(SessionError::WorktreeNotFound {
+            path: session.worktree_path.clone(),
+        });
+    }
+    Ok(session)
+}
+
+/// Record a manual checkpoint of a kild's worktree.
+pub fn create_checkpoint(name: &str, message: Option<&str>) -> Result<Checkpoint, SessionError> {
+    let config = Config::new();
+    create_checkpoint_in(&config.sessions_dir(), name, message)
+}
+
+fn create_checkpoint_in(
+    sessions_dir: &Path,
+    name: &str,
+    message: Option<&str>,
+) -> Result<Checkpoint, SessionError> {
+    info!(event = "core.session.checkpoint_started", name = name);
+
+    let session = load_valid_session(sessions_dir, name)?;
+    let checkpoint = git::checkpoint::create_checkpoint(
+        &session.worktree_path,
+        &session.branch,
+        message,
+        CheckpointTrigger::Manual,
+    )
+    .map_err(|e| SessionError::GitError { source: e })?
+    .ok_or_else(|| SessionError::GitError {
+        source: git::GitError::OperationFailed {
+            message: "checkpoint was not recorded".to_string(),
+        },
+    })?;
+
+    info!(
+        event = "core.session.checkpoint_completed",
+        name = name,
+        id = checkpoint.id
+    );
+    Ok(checkpoint)
+}
+
+/// List a kild's checkpoints, oldest first.
+pub fn list_checkpoints(name: &str) -> Result<Vec<Checkpoint>, SessionError> {
+    let config = Config::new();
+    let session = load_valid_session(&config.sessions_dir(), name)?;
+    git::checkpoint::list_checkpoints(&session.worktree_path, &session.branch)
+        .map_err(|e| SessionError::GitError { source: e })
+}
+
+/// Restore a kild's worktree to checkpoint `id`.
+///
+/// Returns the checkpoint that holds the state from before the rollback.
+pub fn rollback(name: &str, id: u32) -> Result<Checkpoint, SessionError> {
+    let config = Config::new();
+    rollback_in(&config.sessions_dir(), name, id)
+}
+
+fn rollback_in(sessions_dir: &Path, name: &str, id: u32) -> Result<Checkpoint, SessionError> {
+    info!(
+        event = "core.session.rollback_started",
+        name = name,
+        id = id
+    );
+
+    let session = load_valid_session(sessions_dir, name)?;
+    let saved =
+        git::checkpoint::rollback_to_checkpoint(&session.worktree_path, &session.branch, id)
+            .map_err(|e| SessionError::GitError { source: e })?;
+
+    info!(
+        event = "core.session.rollback_completed",
+        name = name,
+        id = id,
+        saved_id = saved.id
+    );
+    Ok(saved)
+}
+
+/// Record an automatic checkpoint if `[checkpoints]` enables it for `trigger`.
+///
+/// Best-effort: failures are logged and never block the inject or hook that
+/// asked for the checkpoint. Older automatic checkpoints beyond
+/// `checkpoints.keep` are pruned.
+pub fn auto_checkpoint(name: &str, trigger: CheckpointTrigger) -> Option<Checkpoint> {
+    let config = Config::new();
+    let session = match persistence::load_session_from_file(name, &config.sessions_dir()) {
+        Ok(session) if session.is_worktree_valid() => session,
+        Ok(_) => return None,
+        Err(e) => {
+            warn!(
+                event = "core.session.auto_checkpoint_failed",
+                name = name,
+                error = %e
+            );
+            return None;
+        }
+    };
+
+    let settings = match KildConfig::load_hierarchy_for(&session.worktree_path) {
+        Ok(kild_config) => kild_config.checkpoints,
+        Err(e) => {
+            warn!(
+                event = "core.session.auto_checkpoint_config_failed",
+                name = name,
+                error = %e
+            );
+            return None;
+        }
+    };
+    let enabled = match trigger {
+        CheckpointTrigger::Inject => settings.before_inject(),
+        CheckpointTrigger::Stop => settings.on_stop(),
+        CheckpointTrigger::Manual | CheckpointTrigger::Rollback => false,
+    };
+    if !enabled {
+        return None;
+    }
+
+    let result =
+        git::checkpoint::create_checkpoint(&session.worktree_path, &session.branch, None, trigger);
+    match result {
+        Ok(checkpoint) => {
+            if let Err(e) = git::checkpoint::prune_checkpoints(
+                &session.worktree_path,
+                &session.branch,
+                settings.keep(),
+            ) {
+                warn!(
+                    event = "core.session.checkpoint_prune_failed",
+                    name = name,
+                    error = %e
+                );
+            }
+            checkpoint
+        }
+        Err(e) => {
+            warn!(
+                event = "core.session.auto_checkpoint_failed",
+                name = name,
+                trigger = trigger.as_str(),
+                error = %e
+            );
+            None
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_checkpoint_and_rollback_session() {
+        let dir = tempfile::TempDir::new().unwrap();
+        let worktree = dir.path().join("wt");
+        std::fs::create_dir_all(&worktree).unwrap();
+        git::test_support::init_repo_with_commit(&worktree).unwrap();
+        let sessions_dir = dir.path().join("sessions");
+        std::fs::create_dir_all(&sessions_dir).unwrap();
+        let session = Session::new_for_test("cp-test", worktree.clone());
+        persistence::save_session_to_file(&session, &sessions_dir).unwrap();
+
+        std::fs::write(worktree.join("notes.md"), "draft").unwrap();
+        let checkpoint = create_checkpoint_in(&sessions_dir, "cp-test", Some("draft")).unwrap();
+        assert_eq!(checkpoint.id, 1);
+        assert_eq!(checkpoint.branch, "cp-test");
+
+        std::fs::write(worktree.join("notes.md"), "rewritten").unwrap();
+        let saved = rollback_in(&sessions_dir, "cp-test", 1).unwrap();
+        assert_eq!(saved.id, 2);
+        assert_eq!(
+            std::fs::read_to_string(worktree.join("notes.md")).unwrap(),
+            "draft"
+        );
+    }
+
+    #[test]
+    fn test_checkpoint_missing_worktree() {
+        let dir = tempfile::TempDir::new().unwrap();
+        let session = Session::new_for_test("gone", dir.path().join("missing"));
+        persistence::save_session_to_file(&session, dir.path()).unwrap();
+
+        let result = create_checkpoint_in(dir.path(), "gone", None);
+        assert!(matches!(result, Err(SessionError::WorktreeNotFound { .. })));
+    }
+}
@@ -11,10 +11,19 @@ use super::daemon_helpers::{
     spawn_and_save_attach_window, spawn_daemon_agent, spawn_terminal_agent,
 };
 
+/// A newly created kild and what setting up its worktree did.
+#[derive(Debug)]
+pub struct CreateOutcome {
+    pub session: Session,
+    /// Result of copying `[include_patterns]` files. `None` when no patterns
+    /// are configured or the kild runs in the main worktree.
+    pub include_copy: Option<crate::files::CopyReport>,
+}
+
 pub fn create_session(
     request: CreateSessionRequest,
     kild_config: &KildConfig,
-) -> Result<Session, SessionError> {
+) -> Result<CreateOutcome, SessionError> {
     // Determine agent name and command based on AgentMode
     let (agent, agent_command) = match &request.agent_mode {
         AgentMode::BareShell => {
@@ -100,6 +109,7 @@ pub fn create_session(
 
     // 1. Validate input (pure)
     let validated = validation::validate_session_request(&request.branch, &agent_command, &agent)?;
+    validation::validate_create_request(&request)?;
 
     // 2. Detect git project (I/O)
     // Use explicit project path if provided (UI context), otherwise use cwd (CLI context)
@@ -187,6 +197,8 @@ pub fn create_session(
         git_config.fetch_before_create = Some(false);
     }
 
+    let mut include_copy = None;
+    let mut sparse_paths = Vec::new();
     let worktree = if request.use_main_worktree {
         // Skip worktree creation: run from the project root (main branch).
         // Used for supervisory sessions (e.g. honryu brain) that don't write code.
@@ -206,12 +218,13 @@ pub fn create_session(
             project_id: project.id.clone(),
         }
     } else {
+        sparse_paths = resolve_sparse_paths(request.sparse_paths.as_deref(), &git_config)?;
         let wt = git::handler::create_worktree(
             base_config.kild_dir(),
             &project,
             &validated.name,
-            Some(kild_config),
             &git_config,
+            &sparse_paths,
         )
         .map_err(|e| SessionError::GitError { source: e })?;
 
@@ -221,6 +234,11 @@ pub fn create_session(
             worktree_path = %wt.path.display(),
             branch = wt.branch
         );
+
+        include_copy = copy_include_files(&project, &wt.path, &validated.name, kild_config);
+
+        // Opt-in: seed target/ and share node_modules with the main checkout
+        crate::cache::setup_shared_cache(&project, &wt.path, &kild_config.worktree.shared_cache);
         wt
     };
 
@@ -312,6 +330,7 @@ pub fn create_session(
     );
 
     session.use_main_worktree = request.use_main_worktree;
+    session.sparse_paths = sparse_paths;
 
     // 7. Save session BEFORE spawning attach window so `kild attach` can find it
     persistence::save_session_to_file(&session, &config.sessions_dir())?;
@@ -347,7 +366,79 @@ pub fn create_session(
         process_name = ?session.latest_agent().map(|a| a.process_name())
     );
 
-    Ok(session)
+    Ok(CreateOutcome {
+        session,
+        include_copy,
+    })
+}
+
+/// Sparse-checkout directories for a new worktree: the CLI list if given,
+/// else `[git] sparse_paths`, normalized to cone-mode directories.
+fn resolve_sparse_paths(
+    requested: Option<&[String]>,
+    git_config: &kild_config::GitConfig,
+) -> Result<Vec<String>, SessionError> {
+    let mut paths: Vec<String> = Vec::new();
+    for path in requested.unwrap_or(git_config.sparse_paths()) {
+        let normalized =
+            git::normalize_sparse_path(path).map_err(|e| SessionError::GitError { source: e })?;
+        if !paths.contains(&normalized) {
+            paths.push(normalized);
+        }
+    }
+    Ok(paths)
+}
+
+/// Copy gitignored files matching `[include_patterns]` into the new worktree.
+///
+/// Failures are logged and never fail the create: the worktree is usable
+/// without them.
+pub(super) fn copy_include_files(
+    project: &git::types::GitProjectState,
+    worktree_path: &std::path::Path,
+    branch: &str,
+    kild_config: &KildConfig,
+) -> Option<crate::files::CopyReport> {
+    let include_config = kild_config.include_patterns.as_ref()?;
+    info!(
+        event = "core.session.file_copy_started",
+        project_id = project.id,
+        branch = branch,
+        patterns = ?include_config.patterns
+    );
+
+    match crate::files::copy_include_files(&project.path, worktree_path, include_config) {
+        Ok(report) => {
+            if report.failed > 0 {
+                warn!(
+                    event = "core.session.file_copy_completed_with_errors",
+                    project_id = project.id,
+                    branch = branch,
+                    files_copied = report.copied,
+                    files_failed = report.failed
+                );
+            } else {
+                info!(
+                    event = "core.session.file_copy_completed",
+                    project_id = project.id,
+                    branch = branch,
+                    files_copied = report.copied,
+                    bytes_saved = report.bytes_saved
+                );
+            }
+            Some(report)
+        }
+        Err(e) => {
+            warn!(
+                event = "core.session.file_copy_failed",
+                project_id = project.id,
+                branch = branch,
+                error = %e,
+                message = "File copying failed, but worktree creation succeeded"
+            );
+            None
+        }
+    }
 }
 
 #[cfg(test)]
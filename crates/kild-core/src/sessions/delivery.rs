@@ -0,0 +1,654 @@
+//! PTY message delivery with confirmation.
+//!
+//! Agents without a structured inbox (everything except Claude Code) receive
+//! brain messages by having them typed into their PTY. Typing blind is
+//! unreliable: the agent may be mid-turn, or the Enter may land before the
+//! TUI accepted the text. Delivery runs a small state machine instead:
+//!
+//! `AwaitingPrompt` → `Typing` → `Submitted` → `Verifying` → `Delivered`
+//!
+//! - **AwaitingPrompt** — poll the scrollback until the agent's idle prompt
+//!   is on screen (per-agent [`AgentBackend::prompt_patterns`]).
+//! - **Typing / Submitted** — write the text, then Enter.
+//! - **Verifying** — wait for the agent to start working: a `working` status
+//!   hook report, a busy indicator on screen ([`AgentBackend::busy_patterns`]),
+//!   or — when the agent has no known busy indicator — fresh output, measured
+//!   by the daemon's total output counter (the scrollback stops growing once
+//!   it is full).
+//!   If nothing happens, Enter is re-sent up to `max_attempts` times.
+//!
+//! Terminal states are `Delivered`, `Undelivered` (with a reason), and
+//! `Unverified` when the screen can't be inspected at all.
+//!
+//! [`AgentBackend::prompt_patterns`]: crate::agents::AgentBackend::prompt_patterns
+//! [`AgentBackend::busy_patterns`]: crate::agents::AgentBackend::busy_patterns
+
+use std::time::{Duration, Instant};
+
+use serde::Serialize;
+use tracing::{debug, info, warn};
+
+use crate::sessions::types::AgentStatus;
+
+/// Only the tail of the scrollback is inspected — the current screen of a
+/// TUI is always the most recently drawn output.
+const SCREEN_TAIL_BYTES: usize = 8 * 1024;
+
+/// New output (in bytes) after submit that counts as "agent is working" for
+/// agents with no known busy indicator. Large enough to ignore the echo of
+/// a short message and cursor blinks.
+const OUTPUT_GROWTH_BYTES: u64 = 512;
+
+/// Delivery state machine states.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum DeliveryState {
+    AwaitingPrompt,
+    Typing,
+    Submitted,
+    Verifying,
+    Delivered,
+    Unverified,
+    Undelivered,
+}
+
+impl std::fmt::Display for DeliveryState {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::AwaitingPrompt => write!(f, "awaiting_prompt"),
+            Self::Typing => write!(f, "typing"),
+            Self::Submitted => write!(f, "submitted"),
+            Self::Verifying => write!(f, "verifying"),
+            Self::Delivered => write!(f, "delivered"),
+            Self::Unverified => write!(f, "unverified"),
+            Self::Undelivered => write!(f, "undelivered"),
+        }
+    }
+}
+
+/// Outcome of a delivery attempt.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct DeliveryReport {
+    /// Terminal state: `Delivered`, `Unverified`, or `Undelivered`.
+    pub state: DeliveryState,
+    /// Number of times Enter was sent.
+    pub attempts: u32,
+    /// Why delivery failed (or couldn't be verified).
+    pub reason: Option<String>,
+    /// Every state the machine passed through, in order.
+    pub trace: Vec<DeliveryState>,
+}
+
+impl DeliveryReport {
+    pub fn is_delivered(&self) -> bool {
+        self.state == DeliveryState::Delivered
+    }
+}
+
+/// Timing knobs for delivery.
+#[derive(Debug, Clone, Copy)]
+pub struct DeliveryOptions {
+    /// How long to wait for the agent to reach its idle prompt.
+    pub prompt_timeout: Duration,
+    /// How long to wait for the agent to start working after each Enter.
+    pub verify_timeout: Duration,
+    /// Maximum number of times Enter is sent.
+    pub max_attempts: u32,
+    /// Scrollback polling interval.
+    pub poll_interval: Duration,
+    /// Pause between typing the text and pressing Enter, so TUIs that
+    /// debounce pasted input don't swallow the submit.
+    pub submit_delay: Duration,
+}
+
+impl Default for DeliveryOptions {
+    fn default() -> Self {
+        Self {
+            prompt_timeout: Duration::from_secs(30),
+            verify_timeout: Duration::from_secs(5),
+            max_attempts: 3,
+            poll_interval: Duration::from_millis(250),
+            submit_delay: Duration::from_millis(50),
+        }
+    }
+}
+
+/// Per-agent screen patterns, taken from the agent backend.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct ScreenPatterns {
+    pub prompt: &'static [&'static str],
+    pub busy: &'static [&'static str],
+}
+
+impl ScreenPatterns {
+    /// Patterns for an agent by name. Unknown agents get empty patterns,
+    /// which disables prompt detection and falls back to output growth.
+    pub fn for_agent(agent: &str) -> Self {
+        crate::agents::get_agent(agent)
+            .map(|backend| Self {
+                prompt: backend.prompt_patterns(),
+                busy: backend.busy_patterns(),
+            })
+            .unwrap_or_default()
+    }
+}
+
+/// What the tail of the screen shows.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ScreenState {
+    /// Idle prompt drawn more recently than any busy indicator.
+    Idle,
+    /// Busy indicator drawn more recently than the idle prompt.
+    Busy,
+    /// Neither pattern found.
+    Unknown,
+}
+
+/// Classify a screen by which pattern was drawn last.
+///
+/// TUIs redraw in place, so both the prompt and a stale busy indicator can
+/// appear in the scrollback tail; the later one reflects the current screen.
+pub fn classify_screen(screen: &str, patterns: &ScreenPatterns) -> ScreenState {
+    let last_match = |candidates: &[&str]| {
+        candidates
+            .iter()
+            .filter_map(|p| screen.rfind(&normalize(p)))
+            .max()
+    };
+    match (last_match(patterns.prompt), last_match(patterns.busy)) {
+        (Some(p), Some(b)) if b > p => ScreenState::Busy,
+        (Some(_), _) => ScreenState::Idle,
+        (None, Some(_)) => ScreenState::Busy,
+        (None, None) => ScreenState::Unknown,
+    }
+}
+
+/// Render the tail of raw PTY output as searchable text.
+///
+/// Strips escape sequences, treats cursor-forward as a space (TUIs use it
+/// in place of blanks), lowercases, and collapses whitespace runs.
+pub fn screen_text(raw: &[u8]) -> String {
+    let tail = &raw[raw.len().saturating_sub(SCREEN_TAIL_BYTES)..];
+    let text = String::from_utf8_lossy(tail);
+    let mut out = String::with_capacity(text.len());
+    let mut chars = text.chars().peekable();
+
+    while let Some(c) = chars.next() {
+        if c != '\x1b' {
+            if c.is_control() {
+                out.push(' ');
+            } else {
+                out.push(c);
+            }
+            continue;
+        }
+        match chars.next() {
+            // CSI: parameters then a final byte in '@'..='~'
+            Some('[') => {
+                for c in chars.by_ref() {
+                    if ('@'..='~').contains(&c) {
+                        if c == 'C' {
+                            out.push(' ');
+                        }
+                        break;
+                    }
+                }
+            }
+            // OSC: terminated by BEL or ST (ESC \)
+            Some(']') => {
+                while let Some(c) = chars.next() {
+                    if c == '\x07' {
+                        break;
+                    }
+                    if c == '\x1b' {
+                        chars.next_if_eq(&'\\');
+                        break;
+                    }
+                }
+            }
+            _ => {}
+        }
+    }
+
+    normalize(&out)
+}
+
+fn normalize(text: &str) -> String {
+    text.split_whitespace()
+        .collect::<Vec<_>>()
+        .join(" ")
+        .to_lowercase()
+}
+
+/// The PTY being delivered to. Implemented over the daemon IPC for real
+/// sessions and by scripted fakes in tests.
+pub trait DeliveryTarget {
+    /// Raw scrollback bytes, or `None` if the screen can't be read.
+    fn scrollback(&mut self) -> Option<Vec<u8>>;
+
+    /// Total bytes the PTY has output so far, or `None` if unknown.
+    /// Monotonic, unlike the length of the capped scrollback.
+    fn output_bytes(&mut self) -> Option<u64>;
+
+    /// Write bytes to the PTY's stdin.
+    fn write(&mut self, data: &[u8]) -> Result<(), String>;
+
+    /// Whether the agent's status hook has reported `working` since
+    /// delivery started.
+    fn reported_working(&mut self) -> bool;
+}
+
+/// Deliver `text` to an agent's PTY, confirming it was picked up.
+///
+/// Errors writing to the PTY end delivery as `Undelivered`.
+pub fn deliver(
+    target: &mut dyn DeliveryTarget,
+    patterns: &ScreenPatterns,
+    text: &str,
+    options: &DeliveryOptions,
+) -> DeliveryReport {
+    let mut trace = Vec::new();
+
+    // AwaitingPrompt
+    trace.push(DeliveryState::AwaitingPrompt);
+    let Some(initial) = target.scrollback() else {
+        return match type_and_submit(target, text, options, &mut trace) {
+            Ok(_) => finish(
+                &mut trace,
+                DeliveryState::Unverified,
+                1,
+                Some("agent screen is not readable".to_string()),
+            ),
+            Err(e) => finish(&mut trace, DeliveryState::Undelivered, 0, Some(e)),
+        };
+    };
+    if !patterns.prompt.is_empty() {
+        let deadline = Instant::now() + options.prompt_timeout;
+        let mut screen = initial;
+        while classify_screen(&screen_text(&screen), patterns) != ScreenState::Idle {
+            if Instant::now() >= deadline {
+                return finish(
+                    &mut trace,
+                    DeliveryState::Undelivered,
+                    0,
+                    Some(format!(
+                        "agent did not reach its input prompt within {}s",
+                        options.prompt_timeout.as_secs()
+                    )),
+                );
+            }
+            std::thread::sleep(options.poll_interval);
+            screen = target.scrollback().unwrap_or_default();
+        }
+    }
+
+    // Typing → Submitted
+    let mut baseline = match type_and_submit(target, text, options, &mut trace) {
+        Ok(baseline) => baseline,
+        Err(e) => return finish(&mut trace, DeliveryState::Undelivered, 0, Some(e)),
+    };
+    let mut attempts = 1;
+
+    // Verifying (re-sending Enter on timeout)
+    loop {
+        trace.push(DeliveryState::Verifying);
+        let deadline = Instant::now() + options.verify_timeout;
+        loop {
+            if target.reported_working() {
+                return finish(&mut trace, DeliveryState::Delivered, attempts, None);
+            }
+            let started = if patterns.busy.is_empty() {
+                baseline
+                    .zip(target.output_bytes())
+                    .is_some_and(|(before, now)| now >= before + OUTPUT_GROWTH_BYTES)
+            } else {
+                target.scrollback().is_some_and(|screen| {
+                    classify_screen(&screen_text(&screen), patterns) == ScreenState::Busy
+                })
+            };
+            if started {
+                return finish(&mut trace, DeliveryState::Delivered, attempts, None);
+            }
+            if Instant::now() >= deadline {
+                break;
+            }
+            std::thread::sleep(options.poll_interval);
+        }
+
+        if attempts >= options.max_attempts {
+            return finish(
+                &mut trace,
+                DeliveryState::Undelivered,
+                attempts,
+                Some(format!(
+                    "agent did not start working after {} submit attempt{}",
+                    attempts,
+                    if attempts == 1 { "" } else { "s" }
+                )),
+            );
+        }
+
+        debug!(event = "core.delivery.resubmit", attempt = attempts + 1);
+        baseline = target.output_bytes();
+        if let Err(e) = target.write(b"\r") {
+            return finish(&mut trace, DeliveryState::Undelivered, attempts, Some(e));
+        }
+        trace.push(DeliveryState::Submitted);
+        attempts += 1;
+    }
+}
+
+fn finish(
+    trace: &mut Vec<DeliveryState>,
+    state: DeliveryState,
+    attempts: u32,
+    reason: Option<String>,
+) -> DeliveryReport {
+    trace.push(state);
+    DeliveryReport {
+        state,
+        attempts,
+        reason,
+        trace: std::mem::take(trace),
+    }
+}
+
+/// Type the text, then press Enter. Returns the output byte count just
+/// before Enter, so output growth excludes the echo of the typed text.
+fn type_and_submit(
+    target: &mut dyn DeliveryTarget,
+    text: &str,
+    options: &DeliveryOptions,
+    trace: &mut Vec<DeliveryState>,
+) -> Result<Option<u64>, String> {
+    trace.push(DeliveryState::Typing);
+    target.write(text.as_bytes())?;
+    std::thread::sleep(options.submit_delay);
+    let baseline = target.output_bytes();
+    target.write(b"\r")?;
+    trace.push(DeliveryState::Submitted);
+    Ok(baseline)
+}
+
+/// A daemon-managed PTY session.
+pub struct DaemonPtyTarget {
+    daemon_session_id: String,
+    session_id: String,
+    started_at: chrono::DateTime<chrono::Utc>,
+}
+
+impl DaemonPtyTarget {
+    pub fn new(daemon_session_id: &str, session_id: &str) -> Self {
+        Self {
+            daemon_session_id: daemon_session_id.to_string(),
+            session_id: session_id.to_string(),
+            started_at: chrono::Utc::now(),
+        }
+    }
+}
+
+impl DeliveryTarget for DaemonPtyTarget {
+    fn scrollback(&mut self) -> Option<Vec<u8>> {
+        match crate::daemon::client::read_scrollback(&self.daemon_session_id) {
+            Ok(data) => data,
+            Err(e) => {
+                warn!(
+                    event = "core.delivery.scrollback_failed",
+                    daemon_session_id = %self.daemon_session_id,
+                    error = %e
+                );
+                None
+            }
+        }
+    }
+
+    fn output_bytes(&mut self) -> Option<u64> {
+        match crate::daemon::client::get_session_details(&self.daemon_session_id) {
+            Ok(details) => details.and_then(|d| d.output_bytes),
+            Err(e) => {
+                warn!(
+                    event = "core.delivery.output_bytes_failed",
+                    daemon_session_id = %self.daemon_session_id,
+                    error = %e
+                );
+                None
+            }
+        }
+    }
+
+    fn write(&mut self, data: &[u8]) -> Result<(), String> {
+        crate::daemon::client::write_stdin(&self.daemon_session_id, data)
+            .map_err(|e| format!("PTY write failed: {}", e))
+    }
+
+    fn reported_working(&mut self) -> bool {
+        crate::sessions::read_agent_status(&self.session_id).is_some_and(|record| {
+            record.status == AgentStatus::Working
+                && chrono::DateTime::parse_from_rfc3339(&record.updated_at)
+                    .is_ok_and(|at| at >= self.started_at)
+        })
+    }
+}
+
+/// Deliver `text` to a session's daemon PTY using its agent's screen patterns.
+pub fn deliver_to_daemon_session(
+    session: &crate::sessions::types::Session,
+    daemon_session_id: &str,
+    text: &str,
+    options: &DeliveryOptions,
+) -> DeliveryReport {
+    info!(
+        event = "core.delivery.started",
+        branch = %session.branch,
+        agent = %session.agent
+    );
+    let patterns = ScreenPatterns::for_agent(&session.agent);
+    let mut target = DaemonPtyTarget::new(daemon_session_id, &session.id);
+    let report = deliver(&mut target, &patterns, text, options);
+    info!(
+        event = "core.delivery.completed",
+        branch = %session.branch,
+        state = %report.state,
+        attempts = report.attempts,
+        reason = ?report.reason
+    );
+    report
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const PATTERNS: ScreenPatterns = ScreenPatterns {
+        prompt: &["? for shortcuts"],
+        busy: &["esc to interrupt"],
+    };
+
+    fn fast() -> DeliveryOptions {
+        DeliveryOptions {
+            prompt_timeout: Duration::from_millis(50),
+            verify_timeout: Duration::from_millis(20),
+            max_attempts: 3,
+            poll_interval: Duration::from_millis(1),
+            submit_delay: Duration::ZERO,
+        }
+    }
+
+    /// Fake PTY: each write advances through a script of screens.
+    struct FakePty {
+        screens: Vec<String>,
+        readable: bool,
+        written: Vec<String>,
+        /// Number of Enter presses after which the status hook reports working.
+        working_after_enters: Option<usize>,
+        /// Output the agent produces per Enter; typed text is echoed 1:1.
+        output_per_enter: u64,
+    }
+
+    impl FakePty {
+        fn new(screens: &[&str]) -> Self {
+            Self {
+                screens: screens.iter().map(|s| s.to_string()).collect(),
+                readable: true,
+                written: Vec::new(),
+                working_after_enters: None,
+                output_per_enter: 0,
+            }
+        }
+
+        fn enters(&self) -> usize {
+            self.written.iter().filter(|w| *w == "\r").count()
+        }
+    }
+
+    impl DeliveryTarget for FakePty {
+        fn scrollback(&mut self) -> Option<Vec<u8>> {
+            if !self.readable {
+                return None;
+            }
+            let ix = self.written.len().min(self.screens.len() - 1);
+            Some(self.screens[ix].as_bytes().to_vec())
+        }
+
+        fn output_bytes(&mut self) -> Option<u64> {
+            let echoed: usize = self
+                .written
+                .iter()
+                .filter(|w| *w != "\r")
+                .map(String::len)
+                .sum();
+            Some(echoed as u64 + self.enters() as u64 * self.output_per_enter)
+        }
+
+        fn write(&mut self, data: &[u8]) -> Result<(), String> {
+            self.written
+                .push(String::from_utf8_lossy(data).into_owned());
+            Ok(())
+        }
+
+        fn reported_working(&mut self) -> bool {
+            self.working_after_enters
+                .is_some_and(|n| self.enters() >= n)
+        }
+    }
+
+    #[test]
+    fn test_screen_text_strips_escapes_and_normalizes() {
+        let raw = b"\x1b[2K\x1b[38;5;244m?\x1b[1Cfor   Shortcuts\x1b[0m\x1b]0;title\x07\r\n";
+        assert_eq!(screen_text(raw), "? for shortcuts");
+    }
+
+    #[test]
+    fn test_classify_screen_uses_latest_pattern() {
+        assert_eq!(
+            classify_screen("esc to interrupt ... ? for shortcuts", &PATTERNS),
+            ScreenState::Idle
+        );
+        assert_eq!(
+            classify_screen("? for shortcuts ... esc to interrupt", &PATTERNS),
+            ScreenState::Busy
+        );
+        assert_eq!(classify_screen("$ ", &PATTERNS), ScreenState::Unknown);
+    }
+
+    #[test]
+    fn test_deliver_confirms_via_busy_indicator() {
+        // screens indexed by write count: idle, after text, after Enter → busy
+        let mut pty = FakePty::new(&[
+            "> ? for shortcuts",
+            "> do the thing ? for shortcuts",
+            "working… (esc to interrupt)",
+        ]);
+        let report = deliver(&mut pty, &PATTERNS, "do the thing", &fast());
+
+        assert_eq!(report.state, DeliveryState::Delivered);
+        assert_eq!(report.attempts, 1);
+        assert_eq!(pty.written, vec!["do the thing", "\r"]);
+        assert_eq!(
+            report.trace,
+            vec![
+                DeliveryState::AwaitingPrompt,
+                DeliveryState::Typing,
+                DeliveryState::Submitted,
+                DeliveryState::Verifying,
+                DeliveryState::Delivered,
+            ]
+        );
+    }
+
+    #[test]
+    fn test_deliver_waits_for_prompt_and_gives_up_when_busy() {
+        let mut pty = FakePty::new(&["thinking (esc to interrupt)"]);
+        let report = deliver(&mut pty, &PATTERNS, "hello", &fast());
+
+        assert_eq!(report.state, DeliveryState::Undelivered);
+        assert!(report.reason.unwrap().contains("input prompt"));
+        assert!(pty.written.is_empty(), "nothing typed into a busy agent");
+    }
+
+    #[test]
+    fn test_deliver_resends_enter_until_status_hook_reports_working() {
+        let mut pty = FakePty::new(&["? for shortcuts"]);
+        pty.working_after_enters = Some(2);
+        let report = deliver(&mut pty, &PATTERNS, "hello", &fast());
+
+        assert_eq!(report.state, DeliveryState::Delivered);
+        assert_eq!(report.attempts, 2);
+        assert_eq!(pty.written, vec!["hello", "\r", "\r"]);
+    }
+
+    #[test]
+    fn test_deliver_reports_undelivered_after_max_attempts() {
+        let mut pty = FakePty::new(&["? for shortcuts"]);
+        let report = deliver(&mut pty, &PATTERNS, "hello", &fast());
+
+        assert_eq!(report.state, DeliveryState::Undelivered);
+        assert_eq!(report.attempts, 3);
+        assert_eq!(pty.enters(), 3);
+    }
+
+    #[test]
+    fn test_deliver_without_patterns_uses_output_growth() {
+        let mut pty = FakePty::new(&["$ "]);
+        pty.output_per_enter = OUTPUT_GROWTH_BYTES + 100;
+        let report = deliver(&mut pty, &ScreenPatterns::default(), "hi", &fast());
+
+        assert_eq!(report.state, DeliveryState::Delivered);
+        assert_eq!(report.attempts, 1);
+    }
+
+    #[test]
+    fn test_deliver_without_patterns_ignores_full_scrollback() {
+        // The scrollback is already at capacity, so its length never changes;
+        // only the output counter shows the agent reacting.
+        let full = "x".repeat(64 * 1024);
+        let mut pty = FakePty::new(&[&full]);
+        pty.output_per_enter = OUTPUT_GROWTH_BYTES;
+        let report = deliver(&mut pty, &ScreenPatterns::default(), "hi", &fast());
+
+        assert_eq!(report.state, DeliveryState::Delivered);
+        assert_eq!(pty.enters(), 1, "no extra Enters sent");
+
+        // Echo of a long message alone is not mistaken for the agent working
+        let mut pty = FakePty::new(&[&full]);
+        let long = "y".repeat(OUTPUT_GROWTH_BYTES as usize * 2);
+        let report = deliver(&mut pty, &ScreenPatterns::default(), &long, &fast());
+        assert_eq!(report.state, DeliveryState::Undelivered);
+    }
+
+    #[test]
+    fn test_deliver_unreadable_screen_is_unverified() {
+        let mut pty = FakePty::new(&[""]);
+        pty.readable = false;
+        let report = deliver(&mut pty, &PATTERNS, "hello", &fast());
+
+        assert_eq!(report.state, DeliveryState::Unverified);
+        assert_eq!(pty.written, vec!["hello", "\r"]);
+    }
+
+    #[test]
+    fn test_screen_patterns_for_agent() {
+        assert!(!ScreenPatterns::for_agent("claude").prompt.is_empty());
+        assert!(ScreenPatterns::for_agent("nonexistent").prompt.is_empty());
+    }
+}
@@ -359,10 +359,21 @@ pub fn destroy_session(name: &str, force: bool) -> Result<(), SessionError> {
         git::removal::delete_branch_if_exists(repo_path, &kild_branch);
     }
 
-    // 7. Clean up PID files (best-effort, don't fail if missing)
+    // 7. Delete checkpoint refs (best-effort, don't block destroy)
+    if let Some(repo_path) = &main_repo_path
+        && let Err(e) = git::checkpoint::delete_checkpoints(repo_path, &session.branch)
+    {
+        warn!(
+            event = "core.session.destroy_checkpoints_failed",
+            session_id = %session.id,
+            error = %e
+        );
+    }
+
+    // 8. Clean up PID files (best-effort, don't fail if missing)
     crate::process::cleanup_pid_files(&session.pid_keys(), config.kild_dir(), "destroy");
 
-    // 8. Remove session directory (includes kild.json, status sidecar, pr sidecar)
+    // 9. Remove session directory (includes kild.json, status sidecar, pr sidecar)
     persistence::remove_session_file(&config.sessions_dir(), &session.id)?;
 
     info!(
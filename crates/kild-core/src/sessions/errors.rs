@@ -27,6 +27,9 @@ pub enum SessionError {
     #[error("Invalid session structure: {field}")]
     InvalidStructure { field: String },
 
+    #[error("Invalid create option: {message}")]
+    InvalidOption { message: String },
+
     #[error("Invalid port count: must be greater than 0")]
     InvalidPortCount,
 
@@ -136,6 +139,14 @@ pub enum SessionError {
         "Pane '{pane_id}' not found in session '{branch}'. Use 'kild teammates {branch}' to list panes."
     )]
     PaneNotFound { pane_id: String, branch: String },
+
+    #[error(
+        "Kild '{name}' has a full checkout; there is nothing to expand.\n  Sparse kilds are created with: kild create <branch> --sparse <dir>..."
+    )]
+    NotSparse { name: String },
+
+    #[error("Kild transfer failed: {message}")]
+    TransferFailed { message: String },
 }
 
 impl KildError for SessionError {
@@ -148,6 +159,7 @@ impl KildError for SessionError {
             SessionError::InvalidName => "INVALID_SESSION_NAME",
             SessionError::InvalidCommand => "INVALID_COMMAND",
             SessionError::InvalidStructure { .. } => "INVALID_SESSION_STRUCTURE",
+            SessionError::InvalidOption { .. } => "INVALID_CREATE_OPTION",
             SessionError::InvalidPortCount => "INVALID_PORT_COUNT",
             SessionError::PortRangeExhausted => "PORT_RANGE_EXHAUSTED",
             SessionError::PortAllocationFailed { .. } => "PORT_ALLOCATION_FAILED",
@@ -173,6 +185,8 @@ impl KildError for SessionError {
             SessionError::NoTeammates { .. } => "SESSION_NO_TEAMMATES",
             SessionError::PaneNotFound { .. } => "SESSION_PANE_NOT_FOUND",
             SessionError::LeaderPaneStop { .. } => "SESSION_LEADER_PANE_STOP",
+            SessionError::NotSparse { .. } => "SESSION_NOT_SPARSE",
+            SessionError::TransferFailed { .. } => "SESSION_TRANSFER_FAILED",
         }
     }
 
@@ -189,6 +203,7 @@ impl KildError for SessionError {
                 | SessionError::InvalidName
                 | SessionError::InvalidCommand
                 | SessionError::InvalidStructure { .. }
+                | SessionError::InvalidOption { .. }
                 | SessionError::InvalidPortCount
                 | SessionError::PortRangeExhausted
                 | SessionError::PortAllocationFailed { .. }
@@ -205,6 +220,8 @@ impl KildError for SessionError {
                 | SessionError::NoTeammates { .. }
                 | SessionError::PaneNotFound { .. }
                 | SessionError::LeaderPaneStop { .. }
+                | SessionError::NotSparse { .. }
+                | SessionError::TransferFailed { .. }
         )
     }
 }
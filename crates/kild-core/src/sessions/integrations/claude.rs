@@ -6,8 +6,12 @@ use tracing::{info, warn};
 
 use kild_protocol::DEFAULT_HOOKS_PORT;
 
+/// Timeout for the PermissionRequest HTTP hook. Must exceed the daemon's
+/// maximum approval wait so the daemon always answers first.
+const PERMISSION_HOOK_TIMEOUT_SECS: u64 = 600;
+
 /// Resolve the configured hooks port from kild-config.
-fn resolve_hooks_port() -> u16 {
+pub(super) fn resolve_hooks_port() -> u16 {
     match KildConfig::load_hierarchy() {
         Ok(c) => c.daemon.hooks_port(),
         Err(e) => {
@@ -112,6 +116,7 @@ pub fn ensure_claude_status_hook() -> Result<(), String> {
 ///
 /// Patches `~/.claude/settings.json` with:
 /// - **HTTP hooks** for Stop and SubagentStop → daemon HTTP endpoint
+/// - **HTTP hook** for PermissionRequest → daemon approval broker (`kild approvals`)
 /// - **Command hooks** for TeammateIdle, TaskCompleted, Notification → shell script
 ///
 /// Preserves all existing settings and hooks.
@@ -193,6 +198,27 @@ fn ensure_claude_settings_with_home(home: &Path, paths: &KildPaths) -> Result<()
         added += 1;
     }
 
+    // --- HTTP hook for PermissionRequest (daemon holds the request until a user decides) ---
+    // Long timeout: the daemon answers with no decision before this expires,
+    // falling back to Claude's own prompt.
+    let permission_entries = hooks_obj
+        .entry("PermissionRequest")
+        .or_insert_with(|| serde_json::json!([]));
+
+    if !has_our_hook(permission_entries) {
+        let arr = permission_entries
+            .as_array_mut()
+            .ok_or("\"PermissionRequest\" field in settings.json is not an array")?;
+        arr.push(serde_json::json!({
+            "hooks": [{
+                "type": "http",
+                "url": hooks_url,
+                "timeout": PERMISSION_HOOK_TIMEOUT_SECS
+            }]
+        }));
+        added += 1;
+    }
+
     // --- Command hooks for TeammateIdle, TaskCompleted (need exit-code blocking) ---
     let command_hook = serde_json::json!({
         "type": "command",
@@ -503,6 +529,11 @@ mod tests {
         let subagent_hooks = &parsed["hooks"]["SubagentStop"][0]["hooks"][0];
         assert_eq!(subagent_hooks["type"], "http");
 
+        // Verify PermissionRequest routes to the daemon approval broker
+        let permission_hook = &parsed["hooks"]["PermissionRequest"][0]["hooks"][0];
+        assert_eq!(permission_hook["type"], "http");
+        assert_eq!(permission_hook["timeout"], PERMISSION_HOOK_TIMEOUT_SECS);
+
         // Verify TeammateIdle is command type
         let teammate_hooks = &parsed["hooks"]["TeammateIdle"][0]["hooks"][0];
         assert_eq!(teammate_hooks["type"], "command");
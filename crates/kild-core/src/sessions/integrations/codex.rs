@@ -8,12 +8,15 @@ use tracing::{debug, info, warn};
 /// This script is called by Codex CLI's `notify` config. It reads JSON from stdin,
 /// maps event types to KILD agent statuses, and calls `kild agent-status`.
 /// Event mappings: `agent-turn-complete` → `idle`, `approval-requested` → `waiting`.
-/// Idempotent: skips if script already exists.
+/// `approval-requested` events are also posted to the daemon hook endpoint so
+/// they show up in `kild approvals`.
+/// Idempotent: only rewrites the script when its content (e.g. the hooks port) changed.
 fn ensure_codex_notify_hook_with_paths(paths: &KildPaths) -> Result<(), String> {
     let hooks_dir = paths.hooks_dir();
     let hook_path = paths.codex_notify_hook();
+    let script = codex_notify_script(super::claude::resolve_hooks_port());
 
-    if hook_path.exists() {
+    if std::fs::read_to_string(&hook_path).is_ok_and(|existing| existing == script) {
         debug!(
             event = "core.session.codex_notify_hook_already_exists",
             path = %hook_path.display()
@@ -24,18 +27,6 @@ fn ensure_codex_notify_hook_with_paths(paths: &KildPaths) -> Result<(), String>
     std::fs::create_dir_all(&hooks_dir)
         .map_err(|e| format!("failed to create {}: {}", hooks_dir.display(), e))?;
 
-    let script = r#"#!/bin/sh
-# KILD Codex notify hook — auto-generated, do not edit.
-# Called by Codex CLI via notify config with JSON on stdin.
-# Maps Codex events to KILD agent statuses.
-INPUT=$(cat)
-EVENT_TYPE=$(echo "$INPUT" | grep -o '"type":"[^"]*"' | head -1 | sed 's/"type":"//;s/"//')
-case "$EVENT_TYPE" in
-  agent-turn-complete) kild agent-status --self idle --notify ;;
-  approval-requested)  kild agent-status --self waiting --notify ;;
-esac
-"#;
-
     std::fs::write(&hook_path, script)
         .map_err(|e| format!("failed to write {}: {}", hook_path.display(), e))?;
 
@@ -54,6 +45,31 @@ esac
     Ok(())
 }
 
+fn codex_notify_script(hooks_port: u16) -> String {
+    format!(
+        r#"#!/bin/sh
+# KILD Codex notify hook — auto-generated, do not edit.
+# Called by Codex CLI via notify config with JSON on stdin.
+# Maps Codex events to KILD agent statuses.
+INPUT=$(cat)
+EVENT_TYPE=$(echo "$INPUT" | grep -o '"type":"[^"]*"' | head -1 | sed 's/"type":"//;s/"//')
+case "$EVENT_TYPE" in
+  agent-turn-complete) kild agent-status --self idle --notify ;;
+  approval-requested)
+    kild agent-status --self waiting --notify
+    # Queue in the daemon approval broker so `kild approvals` can answer it.
+    if [ -n "$KILD_SESSION_BRANCH" ] && command -v curl >/dev/null 2>&1; then
+      printf '{{"hook_event_name":"CodexApprovalRequested","session_id":"%s","codex_event":%s}}' \
+        "$KILD_SESSION_BRANCH" "$INPUT" | \
+        curl -s -m 2 -X POST -H 'Content-Type: application/json' --data-binary @- \
+        http://127.0.0.1:{hooks_port}/hooks >/dev/null 2>&1 || true
+    fi
+    ;;
+esac
+"#
+    )
+}
+
 pub(crate) fn ensure_codex_notify_hook() -> Result<(), String> {
     let paths = KildPaths::resolve().map_err(|e| e.to_string())?;
     ensure_codex_notify_hook_with_paths(&paths)
@@ -202,6 +218,10 @@ mod tests {
             content.contains("kild agent-status --self waiting --notify"),
             "Script should call kild agent-status for waiting"
         );
+        assert!(
+            content.contains("CodexApprovalRequested") && content.contains("/hooks"),
+            "Script should post approval requests to the daemon hook endpoint"
+        );
 
         #[cfg(unix)]
         {
@@ -137,6 +137,14 @@ pub fn sync_daemon_session_status(session: &mut Session) -> bool {
         );
     }
 
+    // The agent went away without `kild stop` — let configured channels know
+    let notification = crate::notify::Notification::for_session(
+        session,
+        kild_config::NotifyEvent::Crashed,
+        "stopped",
+    );
+    crate::notify::notify_event(&notification);
+
     true
 }
 
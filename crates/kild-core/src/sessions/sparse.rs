@@ -0,0 +1,122 @@
+//! Expanding a sparse kild's checkout (`kild sparse add`).
+
+use std::path::Path;
+
+use kild_config::Config;
+use tracing::info;
+
+use crate::git;
+use crate::sessions::{errors::SessionError, persistence, types::Session};
+
+/// Add directories to a sparse kild's cone, check them out and record them
+/// on the session. Directories already in the cone are skipped.
+pub fn add_sparse_paths(name: &str, paths: &[String]) -> Result<Session, SessionError> {
+    let config = Config::new();
+    add_sparse_paths_in(&config.sessions_dir(), name, paths)
+}
+
+fn add_sparse_paths_in(
+    sessions_dir: &Path,
+    name: &str,
+    paths: &[String],
+) -> Result<Session, SessionError> {
+    info!(event = "core.session.sparse_add_started", name = name, paths = ?paths);
+
+    let mut session = persistence::load_session_from_file(name, sessions_dir)?;
+    if session.sparse_paths.is_empty() {
+        return Err(SessionError::NotSparse {
+            name: name.to_string(),
+        });
+    }
+    if !session.is_worktree_valid() {
+        return Err(SessionError::WorktreeNotFound {
+            path: session.worktree_path.clone(),
+        });
+    }
+
+    let mut added = Vec::new();
+    for path in paths {
+        let normalized =
+            git::normalize_sparse_path(path).map_err(|e| SessionError::GitError { source: e })?;
+        if !session.sparse_paths.contains(&normalized) && !added.contains(&normalized) {
+            added.push(normalized);
+        }
+    }
+    if added.is_empty() {
+        info!(event = "core.session.sparse_add_skipped", name = name);
+        return Ok(session);
+    }
+
+    git::cli::sparse_checkout_add(&session.worktree_path, &added)
+        .map_err(|e| SessionError::GitError { source: e })?;
+    session.sparse_paths.extend(added);
+    persistence::save_session_to_file(&session, sessions_dir)?;
+
+    info!(
+        event = "core.session.sparse_add_completed",
+        name = name,
+        sparse_paths = ?session.sparse_paths
+    );
+    Ok(session)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::git::test_support::git;
+
+    #[test]
+    fn test_add_sparse_paths_expands_cone_and_records_it() {
+        let dir = tempfile::TempDir::new().unwrap();
+        let repo = dir.path().join("repo");
+        for file in ["services/billing/a.rs", "libs/shared/b.rs", "docs/c.md"] {
+            let path = repo.join(file);
+            std::fs::create_dir_all(path.parent().unwrap()).unwrap();
+            std::fs::write(path, "x\n").unwrap();
+        }
+        git(&repo, &["init", "-q"]);
+        git(&repo, &["add", "-A"]);
+        git(&repo, &["commit", "-qm", "tree"]);
+        git(&repo, &["branch", "kild/billing"]);
+
+        let worktree = dir.path().join("wt");
+        git::cli::add_sparse_worktree(
+            &repo,
+            &worktree,
+            "kild/billing",
+            &["services/billing".to_string()],
+        )
+        .unwrap();
+
+        let sessions_dir = dir.path().join("sessions");
+        let mut session = Session::new_for_test("billing", worktree.clone());
+        session.sparse_paths = vec!["services/billing".to_string()];
+        persistence::save_session_to_file(&session, &sessions_dir).unwrap();
+
+        let updated = add_sparse_paths_in(
+            &sessions_dir,
+            "billing",
+            &["libs/shared/**".to_string(), "services/billing".to_string()],
+        )
+        .unwrap();
+
+        assert_eq!(updated.sparse_paths, ["services/billing", "libs/shared"]);
+        assert!(worktree.join("libs/shared/b.rs").exists());
+        assert!(!worktree.join("docs").exists());
+        let reloaded = persistence::load_session_from_file("billing", &sessions_dir).unwrap();
+        assert_eq!(reloaded.sparse_paths, updated.sparse_paths);
+    }
+
+    #[test]
+    fn test_add_sparse_paths_rejects_full_checkout() {
+        let dir = tempfile::TempDir::new().unwrap();
+        let sessions_dir = dir.path().join("sessions");
+        let worktree = dir.path().join("wt");
+        std::fs::create_dir_all(&worktree).unwrap();
+        let session = Session::new_for_test("full", worktree);
+        persistence::save_session_to_file(&session, &sessions_dir).unwrap();
+
+        let result = add_sparse_paths_in(&sessions_dir, "full", &["docs".to_string()]);
+        assert!(matches!(result, Err(SessionError::NotSparse { .. })));
+    }
+}
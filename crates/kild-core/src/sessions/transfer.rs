@@ -0,0 +1,769 @@
+//! Handing a kild between machines (`kild export`, `kild import`).
+//!
+//! An export is a tar archive holding:
+//! - `manifest.json`: the [`ExportManifest`]
+//! - `branch.bundle`: the kild branch's commits since the base branch, if any
+//! - `dirty.patch`: uncommitted and untracked work, if any
+//! - `inbox/` and `tasks/`: fleet inbox state and the Claude task list, if any
+//!
+//! Import recreates the branch, worktree and session in the current project
+//! with freshly allocated ports. The receiving repository must have the base
+//! branch commit the export was made against.
+
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use kild_config::{Config, KildConfig};
+use kild_paths::KildPaths;
+use serde::{Deserialize, Serialize};
+use tracing::{info, warn};
+
+use crate::git;
+use crate::sessions::{errors::SessionError, persistence, ports, types::*};
+
+/// Bumped when the archive layout changes incompatibly.
+pub const EXPORT_FORMAT_VERSION: u32 = 1;
+
+const MANIFEST_FILE: &str = "manifest.json";
+const BUNDLE_FILE: &str = "branch.bundle";
+const PATCH_FILE: &str = "dirty.patch";
+const INBOX_DIR: &str = "inbox";
+const TASKS_DIR: &str = "tasks";
+
+/// Everything about an exported kild except its files.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ExportManifest {
+    pub format_version: u32,
+    pub exported_at: String,
+    pub branch: String,
+    /// Git branch in the source repository (normally `kild/<branch>`).
+    pub git_branch: String,
+    pub head_commit: String,
+    /// Commit the bundle was cut against. The importing repository needs it.
+    pub base_commit: Option<String>,
+    pub remote_url: Option<String>,
+    pub agent: String,
+    pub note: Option<String>,
+    pub issue: Option<u32>,
+    pub agent_session_id: Option<String>,
+    #[serde(default)]
+    pub agent_session_id_history: Vec<String>,
+    pub task_list_id: Option<String>,
+    pub runtime_mode: Option<kild_protocol::RuntimeMode>,
+    #[serde(default)]
+    pub sparse_paths: Vec<String>,
+    pub has_bundle: bool,
+    pub has_dirty_patch: bool,
+    pub has_inbox: bool,
+    pub has_task_list: bool,
+}
+
+/// Result of [`import_session`].
+#[derive(Debug)]
+pub struct ImportOutcome {
+    pub session: Session,
+    pub manifest: ExportManifest,
+    /// Set when the dirty-state patch did not apply; the patch was saved here.
+    pub unapplied_patch: Option<PathBuf>,
+    pub inbox_restored: bool,
+    pub task_list_restored: bool,
+}
+
+/// Locations export and import read and write outside the project.
+struct TransferEnv {
+    paths: KildPaths,
+    home_dir: PathBuf,
+    port_count: u16,
+    base_port: u16,
+}
+
+impl TransferEnv {
+    fn from_config(config: &Config) -> Result<Self, SessionError> {
+        let home_dir = dirs::home_dir().ok_or_else(|| SessionError::TransferFailed {
+            message: "could not determine home directory".to_string(),
+        })?;
+        Ok(Self {
+            paths: config.paths().clone(),
+            home_dir,
+            port_count: config.default_port_count,
+            base_port: config.base_port_range,
+        })
+    }
+
+    fn task_list_dir(&self, task_list_id: &str) -> PathBuf {
+        self.home_dir
+            .join(".claude")
+            .join("tasks")
+            .join(task_list_id)
+    }
+}
+
+/// The task list ID names a directory under `~/.claude/tasks`. It comes
+/// from the archive, so anything but a single plain path component is
+/// refused before it is joined onto a path.
+fn validate_task_list_id(id: &str) -> Result<(), SessionError> {
+    let mut components = Path::new(id).components();
+    match (components.next(), components.next()) {
+        (Some(std::path::Component::Normal(_)), None) => Ok(()),
+        _ => Err(SessionError::TransferFailed {
+            message: format!("invalid task list ID '{}' in manifest", id),
+        }),
+    }
+}
+
+fn transfer_error(context: &str, e: impl std::fmt::Display) -> SessionError {
+    SessionError::TransferFailed {
+        message: format!("{}: {}", context, e),
+    }
+}
+
+/// Write kild `name` to a tar archive at `output`.
+pub fn export_session(name: &str, output: &Path) -> Result<ExportManifest, SessionError> {
+    let config = Config::new();
+    export_session_in(&TransferEnv::from_config(&config)?, name, output)
+}
+
+fn export_session_in(
+    env: &TransferEnv,
+    name: &str,
+    output: &Path,
+) -> Result<ExportManifest, SessionError> {
+    info!(event = "core.session.export_started", name = name);
+
+    let session = persistence::load_session_from_file(name, &env.paths.sessions_dir())?;
+    if session.use_main_worktree {
+        return Err(SessionError::TransferFailed {
+            message: format!(
+                "'{}' runs in the main checkout; only worktree kilds can be exported",
+                name
+            ),
+        });
+    }
+    if !session.is_worktree_valid() {
+        return Err(SessionError::WorktreeNotFound {
+            path: session.worktree_path.clone(),
+        });
+    }
+    let worktree = &session.worktree_path;
+    let git_err = |e| SessionError::GitError { source: e };
+
+    let git_branch = git::head_branch_name(worktree)
+        .map_err(git_err)?
+        .filter(|b| b != "HEAD")
+        .ok_or_else(|| SessionError::TransferFailed {
+            message: format!("'{}' has a detached HEAD; check out its branch first", name),
+        })?;
+    let head_commit = git::bundle::head_commit(worktree).map_err(git_err)?;
+
+    let git_config = KildConfig::load_hierarchy_for(worktree)
+        .map(|c| c.git)
+        .unwrap_or_default();
+    let base_candidates = [
+        format!("{}/{}", git_config.remote(), git_config.base_branch()),
+        git_config.base_branch().to_string(),
+    ];
+    let base_commit = git::bundle::merge_base_with(worktree, &base_candidates);
+
+    let staging = tempfile::tempdir().map_err(|e| transfer_error("staging directory", e))?;
+
+    // A branch with no commits past the base needs no bundle: import can
+    // branch straight from the base commit.
+    let has_bundle = base_commit.as_deref() != Some(head_commit.as_str());
+    if has_bundle {
+        git::bundle::create_bundle(
+            worktree,
+            &staging.path().join(BUNDLE_FILE),
+            &git_branch,
+            base_commit.as_deref(),
+        )
+        .map_err(git_err)?;
+    }
+
+    let patch = git::bundle::working_state_patch(worktree).map_err(git_err)?;
+    let has_dirty_patch = !patch.is_empty();
+    if has_dirty_patch {
+        fs::write(staging.path().join(PATCH_FILE), &patch)?;
+    }
+
+    let inbox = env.paths.inbox_dir(&session.project_id, &session.branch);
+    let task_list = session
+        .task_list_id
+        .as_deref()
+        .map(|id| env.task_list_dir(id));
+
+    let manifest = ExportManifest {
+        format_version: EXPORT_FORMAT_VERSION,
+        exported_at: chrono::Utc::now().to_rfc3339(),
+        branch: session.branch.to_string(),
+        git_branch,
+        head_commit,
+        base_commit,
+        remote_url: git::get_origin_url(worktree),
+        agent: session.agent.clone(),
+        note: session.note.clone(),
+        issue: session.issue,
+        agent_session_id: session.agent_session_id.clone(),
+        agent_session_id_history: session.agent_session_id_history.clone(),
+        task_list_id: session.task_list_id.clone(),
+        runtime_mode: session.runtime_mode.clone(),
+        sparse_paths: session.sparse_paths.clone(),
+        has_bundle,
+        has_dirty_patch,
+        has_inbox: inbox.is_dir(),
+        has_task_list: task_list.as_ref().is_some_and(|dir| dir.is_dir()),
+    };
+    let manifest_json = serde_json::to_vec_pretty(&manifest)
+        .map_err(|e| transfer_error("serializing manifest", e))?;
+    fs::write(staging.path().join(MANIFEST_FILE), manifest_json)?;
+
+    let file = fs::File::create(output)?;
+    let mut archive = tar::Builder::new(file);
+    archive.follow_symlinks(false);
+    archive.append_dir_all(".", staging.path())?;
+    if manifest.has_inbox {
+        archive.append_dir_all(INBOX_DIR, &inbox)?;
+    }
+    if let Some(dir) = task_list.filter(|_| manifest.has_task_list) {
+        archive.append_dir_all(TASKS_DIR, &dir)?;
+    }
+    archive.finish()?;
+
+    info!(
+        event = "core.session.export_completed",
+        name = name,
+        path = %output.display(),
+        bundle = manifest.has_bundle,
+        dirty = manifest.has_dirty_patch
+    );
+    Ok(manifest)
+}
+
+/// Read the manifest of an export archive without importing it.
+pub fn read_manifest(archive: &Path) -> Result<ExportManifest, SessionError> {
+    let staging = unpack(archive)?;
+    load_manifest(staging.path())
+}
+
+fn unpack(archive: &Path) -> Result<tempfile::TempDir, SessionError> {
+    let staging = tempfile::tempdir().map_err(|e| transfer_error("staging directory", e))?;
+    let file = fs::File::open(archive)?;
+    tar::Archive::new(file)
+        .unpack(staging.path())
+        .map_err(|e| transfer_error(&format!("reading {}", archive.display()), e))?;
+    Ok(staging)
+}
+
+fn load_manifest(dir: &Path) -> Result<ExportManifest, SessionError> {
+    let content = fs::read_to_string(dir.join(MANIFEST_FILE))
+        .map_err(|e| transfer_error("archive has no readable manifest.json", e))?;
+    let manifest: ExportManifest =
+        serde_json::from_str(&content).map_err(|e| transfer_error("invalid manifest", e))?;
+    if manifest.format_version != EXPORT_FORMAT_VERSION {
+        return Err(SessionError::TransferFailed {
+            message: format!(
+                "unsupported export format version {} (expected {})",
+                manifest.format_version, EXPORT_FORMAT_VERSION
+            ),
+        });
+    }
+    Ok(manifest)
+}
+
+/// Recreate an exported kild in the project at the current directory.
+///
+/// `rename` imports it under a different kild name. The session is saved
+/// stopped; `kild open` starts an agent in it.
+pub fn import_session(archive: &Path, rename: Option<&str>) -> Result<ImportOutcome, SessionError> {
+    let config = Config::new();
+    let project = git::detect_project().map_err(|e| SessionError::GitError { source: e })?;
+    import_session_in(
+        &TransferEnv::from_config(&config)?,
+        archive,
+        &project,
+        rename,
+    )
+}
+
+fn import_session_in(
+    env: &TransferEnv,
+    archive: &Path,
+    project: &git::types::GitProjectState,
+    rename: Option<&str>,
+) -> Result<ImportOutcome, SessionError> {
+    info!(
+        event = "core.session.import_started",
+        path = %archive.display(),
+        project_id = project.id
+    );
+
+    let staging = unpack(archive)?;
+    let manifest = load_manifest(staging.path())?;
+    if let Some(id) = &manifest.task_list_id {
+        validate_task_list_id(id)?;
+    }
+    let git_err = |e| SessionError::GitError { source: e };
+
+    let name = git::validate_branch_name(rename.unwrap_or(&manifest.branch)).map_err(git_err)?;
+    let sessions_dir = env.paths.sessions_dir();
+    persistence::ensure_sessions_directory(&sessions_dir)?;
+    if persistence::find_session_by_name(&sessions_dir, &name)?.is_some() {
+        return Err(SessionError::AlreadyExists {
+            name: name.into_inner(),
+        });
+    }
+
+    if let (Some(source), Some(target)) = (&manifest.remote_url, git::get_origin_url(&project.path))
+        && source != &target
+    {
+        warn!(
+            event = "core.session.import_remote_mismatch",
+            source = source,
+            target = target
+        );
+    }
+
+    // 1. Recreate the branch: from the bundle, or at the base commit.
+    let kild_branch = git::kild_branch_name(&name);
+    if manifest.has_bundle {
+        git::bundle::fetch_bundle(
+            &project.path,
+            &staging.path().join(BUNDLE_FILE),
+            &manifest.git_branch,
+            &kild_branch,
+        )
+    } else {
+        git::bundle::create_branch_at(&project.path, &kild_branch, &manifest.head_commit)
+    }
+    .map_err(git_err)?;
+
+    // 2. Worktree on the restored branch (create_worktree reuses it).
+    let kild_config = KildConfig::load_hierarchy_for(&project.path).unwrap_or_else(|e| {
+        warn!(event = "core.session.import_config_failed", error = %e);
+        KildConfig::default()
+    });
+    let worktree = match git::handler::create_worktree(
+        env.paths.kild_dir(),
+        project,
+        &name,
+        &kild_config.git,
+        &manifest.sparse_paths,
+    ) {
+        Ok(wt) => wt,
+        Err(e) => {
+            let _ = git::delete_local_branch(&project.path, &kild_branch);
+            return Err(git_err(e));
+        }
+    };
+
+    // Past this point a failure must not leave the branch and worktree
+    // behind with no session file pointing at them.
+    match finish_import(
+        env,
+        staging.path(),
+        manifest,
+        project,
+        &name,
+        &kild_config,
+        &worktree.path,
+    ) {
+        Ok(outcome) => Ok(outcome),
+        Err(e) => {
+            warn!(
+                event = "core.session.import_rolled_back",
+                name = %name,
+                error = %e
+            );
+            if let Err(e) = git::removal::remove_worktree_force(&worktree.path) {
+                warn!(event = "core.session.import_rollback_worktree_failed", error = %e);
+            }
+            let _ = git::delete_local_branch(&project.path, &kild_branch);
+            Err(e)
+        }
+    }
+}
+
+/// Steps of an import that run once the worktree exists: uncommitted
+/// work, include files, the session record, inbox and task list.
+fn finish_import(
+    env: &TransferEnv,
+    staging: &Path,
+    manifest: ExportManifest,
+    project: &git::types::GitProjectState,
+    name: &kild_protocol::BranchName,
+    kild_config: &KildConfig,
+    worktree_path: &Path,
+) -> Result<ImportOutcome, SessionError> {
+    let sessions_dir = env.paths.sessions_dir();
+
+    // 3. Uncommitted work. A patch that no longer applies is kept, not fatal.
+    let mut unapplied_patch = None;
+    if manifest.has_dirty_patch {
+        let patch = staging.join(PATCH_FILE);
+        if let Err(e) = git::bundle::apply_patch(worktree_path, &patch) {
+            let saved = env
+                .paths
+                .imports_dir()
+                .join(format!("{}.patch", git::sanitize_for_path(name)));
+            fs::create_dir_all(env.paths.imports_dir())?;
+            fs::copy(&patch, &saved)?;
+            warn!(
+                event = "core.session.import_patch_failed",
+                name = %name,
+                saved = %saved.display(),
+                error = %e
+            );
+            unapplied_patch = Some(saved);
+        }
+    }
+
+    super::create::copy_include_files(project, worktree_path, name, kild_config);
+    crate::cache::setup_shared_cache(project, worktree_path, &kild_config.worktree.shared_cache);
+
+    // 4. Session record with fresh ports.
+    let project_id: kild_protocol::ProjectId = project.id.clone().into();
+    let session_id = ports::generate_session_id(&project_id, name);
+    let (port_start, port_end) =
+        ports::allocate_port_range(&sessions_dir, env.port_count, env.base_port)?;
+    let now = chrono::Utc::now().to_rfc3339();
+    let mut session = Session::new(
+        session_id,
+        project_id,
+        name.clone(),
+        worktree_path.to_path_buf(),
+        manifest.agent.clone(),
+        SessionStatus::Stopped,
+        now.clone(),
+        port_start,
+        port_end,
+        env.port_count,
+        Some(now),
+        manifest.note.clone(),
+        manifest.issue,
+        vec![],
+        manifest.agent_session_id.clone(),
+        manifest.task_list_id.clone(),
+        manifest.runtime_mode.clone(),
+    );
+    session.agent_session_id_history = manifest.agent_session_id_history.clone();
+    session.sparse_paths = manifest.sparse_paths.clone();
+    persistence::save_session_to_file(&session, &sessions_dir)?;
+
+    // 5. Inbox and task list. Existing state on this machine wins.
+    let inbox_restored = manifest.has_inbox
+        && restore_dir(
+            &staging.join(INBOX_DIR),
+            &env.paths.inbox_dir(&session.project_id, &session.branch),
+        );
+    let task_list_restored = manifest.has_task_list
+        && manifest
+            .task_list_id
+            .as_deref()
+            .is_some_and(|id| restore_dir(&staging.join(TASKS_DIR), &env.task_list_dir(id)));
+
+    info!(
+        event = "core.session.import_completed",
+        name = %name,
+        worktree = %worktree_path.display(),
+        port_range_start = port_start,
+        patch_applied = manifest.has_dirty_patch && unapplied_patch.is_none()
+    );
+    Ok(ImportOutcome {
+        session,
+        manifest,
+        unapplied_patch,
+        inbox_restored,
+        task_list_restored,
+    })
+}
+
+/// Copy `from` to `to` unless `to` already exists. Best-effort.
+fn restore_dir(from: &Path, to: &Path) -> bool {
+    if to.exists() {
+        warn!(
+            event = "core.session.import_restore_skipped",
+            path = %to.display(),
+            reason = "already_exists"
+        );
+        return false;
+    }
+    match copy_dir(from, to) {
+        Ok(()) => true,
+        Err(e) => {
+            warn!(
+                event = "core.session.import_restore_failed",
+                path = %to.display(),
+                error = %e
+            );
+            false
+        }
+    }
+}
+
+/// Copy a directory unpacked from an archive. Symlinks are skipped: the
+/// archive is untrusted, and copying one would read whatever it points at on
+/// this machine (`~/.ssh/id_ed25519`) into the restored directory.
+fn copy_dir(from: &Path, to: &Path) -> std::io::Result<()> {
+    if fs::symlink_metadata(from)?.file_type().is_symlink() {
+        return Err(std::io::Error::new(
+            std::io::ErrorKind::InvalidData,
+            format!("{} is a symlink", from.display()),
+        ));
+    }
+    fs::create_dir_all(to)?;
+    for entry in fs::read_dir(from)? {
+        let entry = entry?;
+        let file_type = entry.file_type()?;
+        let target = to.join(entry.file_name());
+        if file_type.is_symlink() {
+            warn!(
+                event = "core.session.import_symlink_skipped",
+                path = %entry.path().display()
+            );
+        } else if file_type.is_dir() {
+            copy_dir(&entry.path(), &target)?;
+        } else {
+            fs::copy(entry.path(), &target)?;
+        }
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::git::test_support::git;
+
+    fn env(root: &Path, name: &str) -> TransferEnv {
+        TransferEnv {
+            paths: KildPaths::from_dir(root.join(name).join(".kild")),
+            home_dir: root.join(name),
+            port_count: 10,
+            base_port: 3000,
+        }
+    }
+
+    /// Set up a source project with a kild "feat" (a commit, dirty work,
+    /// inbox and task list) and a clone of it as the target project.
+    /// Returns the target repo path.
+    fn source_with_kild(root: &Path) -> PathBuf {
+        // Source machine: project with a kild that has a commit and dirty work.
+        let source_repo = root.join("source");
+        fs::create_dir_all(&source_repo).unwrap();
+        git(&source_repo, &["init", "-q", "-b", "main"]);
+        fs::write(source_repo.join("a.txt"), "base\n").unwrap();
+        git(&source_repo, &["add", "-A"]);
+        git(&source_repo, &["commit", "-qm", "base"]);
+        let target_repo = root.join("target");
+        git(
+            root,
+            &["clone", "-q", source_repo.to_str().unwrap(), "target"],
+        );
+
+        let source_env = env(root, "home-a");
+        let worktree = root.join("wt");
+        git(&source_repo, &["branch", "kild/feat"]);
+        git(
+            &source_repo,
+            &[
+                "worktree",
+                "add",
+                "-q",
+                worktree.to_str().unwrap(),
+                "kild/feat",
+            ],
+        );
+        fs::write(worktree.join("a.txt"), "committed\n").unwrap();
+        git(&worktree, &["commit", "-qam", "work"]);
+        fs::write(worktree.join("notes.md"), "dirty\n").unwrap();
+
+        let mut session = Session::new_for_test("feat", worktree.clone());
+        session.note = Some("auth refactor".to_string());
+        session.issue = Some(42);
+        session.task_list_id = Some("tl_feat".to_string());
+        let sessions_dir = source_env.paths.sessions_dir();
+        fs::create_dir_all(&sessions_dir).unwrap();
+        persistence::save_session_to_file(&session, &sessions_dir).unwrap();
+        let inbox = source_env.paths.inbox_dir("test-project", "feat");
+        fs::create_dir_all(&inbox).unwrap();
+        fs::write(inbox.join("task.md"), "do it").unwrap();
+        let tasks = source_env.task_list_dir("tl_feat");
+        fs::create_dir_all(&tasks).unwrap();
+        fs::write(tasks.join("1.json"), "{}").unwrap();
+
+        target_repo
+    }
+
+    #[test]
+    fn test_export_import_round_trip() {
+        let dir = tempfile::tempdir().unwrap();
+        let root = dir.path();
+        let target_repo = source_with_kild(root);
+        let source_env = env(root, "home-a");
+
+        let archive = root.join("feat.tar");
+        let manifest = export_session_in(&source_env, "feat", &archive).unwrap();
+        assert!(manifest.has_bundle && manifest.has_dirty_patch);
+        assert!(manifest.has_inbox && manifest.has_task_list);
+        assert_eq!(read_manifest(&archive).unwrap().issue, Some(42));
+
+        // Target machine: import under a new name.
+        let target_env = env(root, "home-b");
+        let project = git::detect_project_at(&target_repo).unwrap();
+        let outcome = import_session_in(&target_env, &archive, &project, Some("feat-2")).unwrap();
+
+        let session = outcome.session;
+        assert_eq!(&*session.branch, "feat-2");
+        assert_eq!(session.status, SessionStatus::Stopped);
+        assert_eq!(session.note.as_deref(), Some("auth refactor"));
+        assert_eq!(session.task_list_id.as_deref(), Some("tl_feat"));
+        assert_eq!(session.port_range_start, 3000);
+        assert!(outcome.unapplied_patch.is_none());
+        assert!(outcome.inbox_restored && outcome.task_list_restored);
+        assert_eq!(
+            fs::read_to_string(session.worktree_path.join("a.txt")).unwrap(),
+            "committed\n"
+        );
+        assert_eq!(
+            fs::read_to_string(session.worktree_path.join("notes.md")).unwrap(),
+            "dirty\n"
+        );
+        let restored_inbox = target_env.paths.inbox_dir(&project.id, "feat-2");
+        assert!(restored_inbox.join("task.md").exists());
+        assert!(target_env.task_list_dir("tl_feat").join("1.json").exists());
+
+        // Importing again under the same name is refused.
+        let again = import_session_in(&target_env, &archive, &project, Some("feat-2"));
+        assert!(matches!(again, Err(SessionError::AlreadyExists { .. })));
+    }
+
+    #[test]
+    fn test_export_rejects_main_worktree_session() {
+        let dir = tempfile::tempdir().unwrap();
+        let env = env(dir.path(), "home");
+        let mut session = Session::new_for_test("brain", dir.path().to_path_buf());
+        session.use_main_worktree = true;
+        fs::create_dir_all(env.paths.sessions_dir()).unwrap();
+        persistence::save_session_to_file(&session, &env.paths.sessions_dir()).unwrap();
+
+        let result = export_session_in(&env, "brain", &dir.path().join("out.tar"));
+        assert!(matches!(result, Err(SessionError::TransferFailed { .. })));
+    }
+
+    /// Rewrite the unpacked contents of `archive` in place.
+    fn edit_archive(archive: &Path, edit: impl FnOnce(&Path)) {
+        let staging = unpack(archive).unwrap();
+        edit(staging.path());
+        let mut builder = tar::Builder::new(fs::File::create(archive).unwrap());
+        builder.follow_symlinks(false);
+        builder.append_dir_all(".", staging.path()).unwrap();
+        builder.finish().unwrap();
+    }
+
+    /// Rewrite the manifest inside `archive` in place.
+    fn edit_manifest(archive: &Path, edit: impl FnOnce(&mut ExportManifest)) {
+        edit_archive(archive, |staging| {
+            let mut manifest = load_manifest(staging).unwrap();
+            edit(&mut manifest);
+            fs::write(
+                staging.join(MANIFEST_FILE),
+                serde_json::to_string_pretty(&manifest).unwrap(),
+            )
+            .unwrap();
+        });
+    }
+
+    fn branch_exists(repo: &Path, branch: &str) -> bool {
+        std::process::Command::new("git")
+            .current_dir(repo)
+            .args(["rev-parse", "--verify", "-q", branch])
+            .output()
+            .unwrap()
+            .status
+            .success()
+    }
+
+    #[test]
+    fn test_validate_task_list_id() {
+        assert!(validate_task_list_id("tl_feat").is_ok());
+        for id in ["", "..", ".", "../x", "a/b", "/etc", "../../.config/x"] {
+            assert!(validate_task_list_id(id).is_err(), "{:?} accepted", id);
+        }
+    }
+
+    #[test]
+    fn test_import_rejects_task_list_id_outside_tasks_dir() {
+        let dir = tempfile::tempdir().unwrap();
+        let root = dir.path();
+        let target_repo = source_with_kild(root);
+        let archive = root.join("feat.tar");
+        export_session_in(&env(root, "home-a"), "feat", &archive).unwrap();
+        edit_manifest(&archive, |m| {
+            m.task_list_id = Some("../../.config/evil".to_string());
+        });
+
+        let target_env = env(root, "home-b");
+        let project = git::detect_project_at(&target_repo).unwrap();
+        let result = import_session_in(&target_env, &archive, &project, None);
+
+        assert!(matches!(result, Err(SessionError::TransferFailed { .. })));
+        assert!(!root.join(".config").exists());
+        assert!(!branch_exists(&target_repo, "kild/feat"));
+    }
+
+    #[test]
+    fn test_import_skips_symlinks_in_inbox_and_task_list() {
+        let dir = tempfile::tempdir().unwrap();
+        let root = dir.path();
+        let target_repo = source_with_kild(root);
+        let archive = root.join("feat.tar");
+        export_session_in(&env(root, "home-a"), "feat", &archive).unwrap();
+
+        // A crafted archive pointing at a private file on the importing machine
+        let secret = root.join("id_ed25519");
+        fs::write(&secret, "PRIVATE KEY").unwrap();
+        edit_archive(&archive, |staging| {
+            std::os::unix::fs::symlink(&secret, staging.join(INBOX_DIR).join("key.md")).unwrap();
+            fs::remove_dir_all(staging.join(TASKS_DIR)).unwrap();
+            std::os::unix::fs::symlink(root, staging.join(TASKS_DIR)).unwrap();
+        });
+
+        let target_env = env(root, "home-b");
+        let project = git::detect_project_at(&target_repo).unwrap();
+        let outcome = import_session_in(&target_env, &archive, &project, None).unwrap();
+
+        let inbox = target_env.paths.inbox_dir(&project.id, "feat");
+        assert!(outcome.inbox_restored);
+        assert!(inbox.join("task.md").exists());
+        assert!(fs::symlink_metadata(inbox.join("key.md")).is_err());
+        assert!(!outcome.task_list_restored);
+        assert!(
+            !target_env
+                .task_list_dir("tl_feat")
+                .join("id_ed25519")
+                .exists()
+        );
+    }
+
+    #[test]
+    fn test_import_failure_after_worktree_rolls_back() {
+        let dir = tempfile::tempdir().unwrap();
+        let root = dir.path();
+        let target_repo = source_with_kild(root);
+        let archive = root.join("feat.tar");
+        export_session_in(&env(root, "home-a"), "feat", &archive).unwrap();
+
+        // A zero port count fails port allocation, after the worktree exists.
+        let mut target_env = env(root, "home-b");
+        target_env.port_count = 0;
+        let project = git::detect_project_at(&target_repo).unwrap();
+        let result = import_session_in(&target_env, &archive, &project, None);
+        assert!(matches!(result, Err(SessionError::InvalidPortCount)));
+        assert!(!branch_exists(&target_repo, "kild/feat"));
+        let listed = git(&target_repo, &["worktree", "list", "--porcelain"]);
+        assert_eq!(listed.matches("worktree ").count(), 1, "{}", listed);
+
+        // Nothing left behind: a retry succeeds.
+        target_env.port_count = 10;
+        import_session_in(&target_env, &archive, &project, None).unwrap();
+    }
+}
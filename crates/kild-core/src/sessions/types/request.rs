@@ -42,6 +42,9 @@ pub struct CreateSessionRequest {
     /// Override initial PTY columns (daemon sessions only).
     /// Takes precedence over config `[daemon] default_cols` and terminal ioctl.
     pub cols: Option<u16>,
+    /// Sparse-checkout directories (CLI --sparse / --no-sparse).
+    /// `None` uses `[git] sparse_paths`; an empty list forces a full checkout.
+    pub sparse_paths: Option<Vec<String>>,
 }
 
 impl CreateSessionRequest {
@@ -59,6 +62,7 @@ impl CreateSessionRequest {
             initial_prompt: None,
             rows: None,
             cols: None,
+            sparse_paths: None,
         }
     }
 
@@ -82,6 +86,7 @@ impl CreateSessionRequest {
             initial_prompt: None,
             rows: None,
             cols: None,
+            sparse_paths: None,
         }
     }
 
@@ -120,6 +125,11 @@ impl CreateSessionRequest {
         self.cols = cols;
         self
     }
+
+    pub fn with_sparse_paths(mut self, paths: Option<Vec<String>>) -> Self {
+        self.sparse_paths = paths;
+        self
+    }
 }
 
 /// Parameters for opening an agent in an existing kild session.
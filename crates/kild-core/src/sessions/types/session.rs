@@ -102,6 +102,13 @@ pub struct Session {
     #[serde(default)]
     pub use_main_worktree: bool,
 
+    /// Directories checked out in this kild's sparse worktree (cone mode).
+    ///
+    /// Empty for a full checkout. Set by `kild create --sparse` or
+    /// `[git] sparse_paths`, extended by `kild sparse add`.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub sparse_paths: Vec<String>,
+
     /// All agent processes opened in this kild session.
     ///
     /// Populated by `kild create` (initial agent) and `kild open` (additional agents).
@@ -153,6 +160,7 @@ impl Session {
             task_list_id,
             runtime_mode,
             use_main_worktree: false,
+            sparse_paths: Vec::new(),
         }
     }
 
@@ -275,6 +283,7 @@ impl Session {
             task_list_id: None,
             runtime_mode: None,
             use_main_worktree: false,
+            sparse_paths: Vec::new(),
         }
     }
 }
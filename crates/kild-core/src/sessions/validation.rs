@@ -39,6 +39,44 @@ pub fn validate_branch_name(branch: &str) -> Result<String, SessionError> {
     Ok(trimmed.to_string())
 }
 
+/// Validate the options of a create request.
+///
+/// `kild create` rejects most of these combinations at argument parsing;
+/// this is the shared check for callers without clap, such as the UI.
+pub fn validate_create_request(request: &CreateSessionRequest) -> Result<(), SessionError> {
+    let invalid = |message: String| Err(SessionError::InvalidOption { message });
+
+    validate_branch_name(&request.branch)?;
+    if let Some(base) = &request.base_branch
+        && validate_branch_name(base).is_err()
+    {
+        return invalid(format!("invalid base branch '{}'", base.trim()));
+    }
+    if request.issue == Some(0) {
+        return invalid("issue number must be at least 1".to_string());
+    }
+    if let Some(prompt) = &request.initial_prompt {
+        if prompt.trim().is_empty() {
+            return invalid("initial prompt is empty".to_string());
+        }
+        if request.agent_mode == kild_protocol::AgentMode::BareShell {
+            return invalid("an initial prompt needs an agent".to_string());
+        }
+    }
+    if request.use_main_worktree
+        && request
+            .sparse_paths
+            .as_ref()
+            .is_some_and(|paths| !paths.is_empty())
+    {
+        return invalid("sparse paths cannot be used with the main worktree".to_string());
+    }
+    if request.rows == Some(0) || request.cols == Some(0) {
+        return invalid("PTY size must be at least 1x1".to_string());
+    }
+    Ok(())
+}
+
 fn validate_field_not_empty(field_value: &str, field_name: &str) -> Result<(), SessionError> {
     if field_value.trim().is_empty() {
         return Err(SessionError::InvalidStructure {
@@ -73,6 +111,7 @@ pub(crate) fn validate_session_structure(session: &Session) -> Result<(), Sessio
 #[cfg(test)]
 mod tests {
     use super::*;
+    use kild_protocol::AgentMode;
     use std::path::PathBuf;
 
     #[test]
@@ -120,6 +159,51 @@ mod tests {
         assert!(validate_branch_name("branch name").is_err());
     }
 
+    #[test]
+    fn test_validate_create_request() {
+        let request = || CreateSessionRequest::new("feat", AgentMode::DefaultAgent, None);
+        assert!(validate_create_request(&request()).is_ok());
+        assert!(
+            validate_create_request(
+                &request()
+                    .with_issue(Some(7))
+                    .with_base_branch(Some("develop".to_string()))
+                    .with_initial_prompt(Some("go".to_string()))
+            )
+            .is_ok()
+        );
+
+        assert!(matches!(
+            validate_create_request(&CreateSessionRequest::new(
+                "bad name",
+                AgentMode::DefaultAgent,
+                None
+            )),
+            Err(SessionError::InvalidName)
+        ));
+        let rejected = [
+            request().with_base_branch(Some("-main".to_string())),
+            request().with_issue(Some(0)),
+            request().with_initial_prompt(Some("  ".to_string())),
+            CreateSessionRequest::new("feat", AgentMode::BareShell, None)
+                .with_initial_prompt(Some("go".to_string())),
+            request()
+                .with_main_worktree(true)
+                .with_sparse_paths(Some(vec!["src".to_string()])),
+            request().with_pty_size(Some(0), None),
+        ];
+        for request in rejected {
+            assert!(
+                matches!(
+                    validate_create_request(&request),
+                    Err(SessionError::InvalidOption { .. })
+                ),
+                "expected rejection: {:?}",
+                request
+            );
+        }
+    }
+
     #[test]
     fn test_validate_session_structure() {
         use std::env;
@@ -1,4 +1,4 @@
-use tracing::{debug, error, info};
+use tracing::{debug, error, info, warn};
 
 use crate::projects::{Project, load_projects, save_projects};
 use crate::sessions::handler as session_ops;
@@ -6,9 +6,9 @@ use crate::sessions::types::CreateSessionRequest;
 use crate::state::errors::DispatchError;
 use crate::state::events::Event;
 use crate::state::store::Store;
-use crate::state::types::Command;
+use crate::state::types::{Command, CreateKildOptions};
 use kild_config::KildConfig;
-use kild_protocol::RuntimeMode;
+use kild_protocol::{AgentMode, RuntimeMode};
 
 /// Default Store implementation that routes commands to kild-core handlers.
 ///
@@ -23,6 +23,30 @@ impl CoreStore {
     pub fn new(config: KildConfig) -> Self {
         Self { config }
     }
+
+    /// Config for one create: `--flags` replaces the agent flags and
+    /// `--yolo` prepends the agent's autonomy flags, as in `kild create`.
+    fn create_config(&self, agent_mode: &AgentMode, options: &CreateKildOptions) -> KildConfig {
+        let mut config = self.config.clone();
+        if let AgentMode::Agent(agent) = agent_mode {
+            config.agent.default = agent.clone();
+        }
+        if let Some(flags) = &options.flags {
+            config.agent.flags = Some(flags.clone());
+        }
+        if options.yolo {
+            let agent = &config.agent.default;
+            match crate::agents::with_yolo_flags(agent, config.agent.flags.as_deref()) {
+                Some(flags) => config.agent.flags = Some(flags),
+                None => warn!(
+                    event = "core.state.yolo_not_supported",
+                    agent = %agent,
+                    "Agent does not support yolo mode, ignoring"
+                ),
+            }
+        }
+        config
+    }
 }
 
 impl Store for CoreStore {
@@ -37,22 +61,34 @@ impl Store for CoreStore {
                 agent_mode,
                 note,
                 project_path,
+                options,
             } => {
+                let config = self.create_config(&agent_mode, &options);
                 let request = match project_path {
                     Some(path) => {
                         CreateSessionRequest::with_project_path(branch, agent_mode, note, path)
                     }
                     None => CreateSessionRequest::new(branch, agent_mode, note),
                 };
-                // Apply config-based runtime mode. The constructors default to
-                // Terminal, but the user's config may have daemon.enabled = true.
-                // Without this, the UI always spawns external terminals.
-                let request = if self.config.is_daemon_enabled() {
-                    request.with_runtime_mode(RuntimeMode::Daemon)
-                } else {
-                    request
-                };
-                let session = session_ops::create_session(request, &self.config)?;
+                // Without an explicit runtime mode, apply the config-based one.
+                // The constructors default to Terminal, but the user's config may
+                // have daemon.enabled = true. Without this, the UI always spawns
+                // external terminals.
+                let runtime_mode = options
+                    .runtime_mode
+                    .unwrap_or(if config.is_daemon_enabled() {
+                        RuntimeMode::Daemon
+                    } else {
+                        RuntimeMode::Terminal
+                    });
+                let request = request
+                    .with_issue(options.issue)
+                    .with_base_branch(options.base_branch)
+                    .with_no_fetch(options.no_fetch)
+                    .with_runtime_mode(runtime_mode)
+                    .with_main_worktree(options.use_main_worktree)
+                    .with_initial_prompt(options.initial_prompt);
+                let session = session_ops::create_session(request, &config)?.session;
                 Ok(vec![Event::KildCreated {
                     branch: session.branch,
                     session_id: session.id,
@@ -216,6 +252,23 @@ mod tests {
         assert_store(&store);
     }
 
+    #[test]
+    fn test_create_config_applies_flags_and_yolo() {
+        let store = CoreStore::new(KildConfig::default());
+        let options = CreateKildOptions {
+            flags: Some("--verbose".to_string()),
+            yolo: true,
+            ..Default::default()
+        };
+        let config = store.create_config(&AgentMode::Agent("claude".to_string()), &options);
+        let yolo = crate::agents::get_yolo_flags("claude").unwrap();
+        assert_eq!(config.agent.default, "claude");
+        assert_eq!(config.agent.flags, Some(format!("{} --verbose", yolo)));
+
+        let config = store.create_config(&AgentMode::DefaultAgent, &CreateKildOptions::default());
+        assert_eq!(config.agent.flags, KildConfig::default().agent.flags);
+    }
+
     #[test]
     fn test_core_store_add_project_validates_path() {
         let mut store = CoreStore::new(KildConfig::default());
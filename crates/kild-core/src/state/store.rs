@@ -112,6 +112,7 @@ mod tests {
                 agent_mode: kild_protocol::AgentMode::DefaultAgent,
                 note: None,
                 project_path: None,
+                options: Default::default(),
             })
             .unwrap();
         assert!(matches!(&events[0], Event::KildCreated { branch, .. } if &**branch == "feat"));
@@ -237,6 +238,7 @@ mod tests {
                 agent_mode: kild_protocol::AgentMode::DefaultAgent,
                 note: None,
                 project_path: None,
+                options: Default::default(),
             },
             Command::DestroyKild {
                 branch: "b".into(),
@@ -20,6 +20,9 @@ pub enum Command {
         note: Option<String>,
         /// Project path for session tracking. Uses current directory if `None`.
         project_path: Option<PathBuf>,
+        /// The rest of the `kild create` options.
+        #[serde(default)]
+        options: CreateKildOptions,
     },
     /// Destroy a kild session, removing worktree and session file.
     DestroyKild {
@@ -64,6 +67,30 @@ pub enum Command {
     SelectProject { path: Option<PathBuf> },
 }
 
+/// Optional `kild create` settings carried by [`Command::CreateKild`].
+///
+/// Defaults match `kild create` with none of the corresponding flags.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct CreateKildOptions {
+    /// GitHub issue number linked to the kild (`--issue`).
+    pub issue: Option<u32>,
+    /// Branch to create the worktree from (`--base`).
+    pub base_branch: Option<String>,
+    /// Skip fetching before create (`--no-fetch`).
+    pub no_fetch: bool,
+    /// `None` = no flag passed; use `daemon.enabled` from config.
+    pub runtime_mode: Option<RuntimeMode>,
+    /// Run in the project root instead of a new worktree (`--main`).
+    pub use_main_worktree: bool,
+    /// Agent flags replacing `agent.flags` from config (`--flags`).
+    pub flags: Option<String>,
+    /// Prepend the agent's autonomy flags (`--yolo`).
+    pub yolo: bool,
+    /// Prompt written to the agent once it starts (`--initial-prompt`).
+    pub initial_prompt: Option<String>,
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -76,6 +103,7 @@ mod tests {
             agent_mode: AgentMode::BareShell,
             note: None,
             project_path: None,
+            options: CreateKildOptions::default(),
         };
         let json = serde_json::to_string(&cmd).unwrap();
         let deserialized: Command = serde_json::from_str(&json).unwrap();
@@ -89,6 +117,14 @@ mod tests {
             agent_mode: AgentMode::Agent("claude".to_string()),
             note: Some("Working on auth".to_string()),
             project_path: Some(PathBuf::from("/home/user/project")),
+            options: CreateKildOptions {
+                issue: Some(42),
+                base_branch: Some("develop".to_string()),
+                runtime_mode: Some(RuntimeMode::Daemon),
+                yolo: true,
+                initial_prompt: Some("Fix the login bug".to_string()),
+                ..Default::default()
+            },
         };
         let json = serde_json::to_string(&cmd).unwrap();
         let deserialized: Command = serde_json::from_str(&json).unwrap();
@@ -103,6 +139,7 @@ mod tests {
                 agent_mode: AgentMode::Agent("claude".to_string()),
                 note: None,
                 project_path: None,
+                options: CreateKildOptions::default(),
             },
             Command::DestroyKild {
                 branch: "feature".into(),
@@ -165,6 +202,7 @@ mod tests {
                 agent_mode: AgentMode::Agent("kiro".to_string()),
                 note: Some("test note".to_string()),
                 project_path: Some(PathBuf::from("/tmp/project")),
+                options: CreateKildOptions::default(),
             },
             Command::DestroyKild {
                 branch: "test".into(),
@@ -231,4 +269,14 @@ mod tests {
         // Verify the resume field is actually in the JSON
         assert!(json.contains("\"resume\":true"));
     }
+
+    #[test]
+    fn test_create_kild_without_options_deserializes() {
+        let json = r#"{"CreateKild":{"branch":"feat","agent_mode":"DefaultAgent","note":null,"project_path":null}}"#;
+        let cmd: Command = serde_json::from_str(json).unwrap();
+        assert!(matches!(
+            cmd,
+            Command::CreateKild { options, .. } if options == CreateKildOptions::default()
+        ));
+    }
 }
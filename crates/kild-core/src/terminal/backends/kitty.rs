@@ -0,0 +1,387 @@
+//! Kitty terminal backend using `kitten @` remote control.
+//!
+//! kild talks to one kitty instance it starts itself, listening on
+//! `~/.kild/kitty.sock` with remote control restricted to that socket. This
+//! keeps window IDs stable no matter which terminal `kild` is run from and
+//! works without `allow_remote_control` in the user's kitty.conf. The kitty
+//! window ID printed by `kitten @ launch` is persisted as the terminal window
+//! ID; focus, hide, close and liveness checks (`kitten @ ls`) match on it.
+
+use std::path::{Path, PathBuf};
+use std::process::{Command, Output, Stdio};
+use std::time::{Duration, Instant};
+
+use kild_paths::KildPaths;
+use serde::Deserialize;
+use tracing::{debug, warn};
+
+use crate::terminal::{
+    common::{detection::has_graphical_session, escape::build_cd_command, helpers::stderr_lossy},
+    errors::TerminalError,
+    traits::TerminalBackend,
+    types::SpawnConfig,
+};
+
+/// How long to wait for a freshly started kitty to accept remote control.
+const STARTUP_TIMEOUT: Duration = Duration::from_secs(5);
+const STARTUP_POLL: Duration = Duration::from_millis(100);
+
+/// Backend implementation for kitty (macOS and Linux).
+#[derive(Debug, Default)]
+pub struct KittyBackend {
+    /// Remote-control socket. `None` uses `~/.kild/kitty.sock`.
+    socket: Option<PathBuf>,
+}
+
+/// One OS window of `kitten @ ls` output.
+#[derive(Debug, Deserialize)]
+struct KittyOsWindow {
+    #[serde(default)]
+    tabs: Vec<KittyTab>,
+}
+
+#[derive(Debug, Deserialize)]
+struct KittyTab {
+    #[serde(default)]
+    windows: Vec<KittyWindow>,
+}
+
+#[derive(Debug, Deserialize)]
+struct KittyWindow {
+    id: u64,
+}
+
+/// All kitty window IDs in `kitten @ ls` output.
+fn parse_window_ids(json: &str) -> Result<Vec<u64>, serde_json::Error> {
+    let os_windows: Vec<KittyOsWindow> = serde_json::from_str(json)?;
+    Ok(os_windows
+        .iter()
+        .flat_map(|w| &w.tabs)
+        .flat_map(|t| &t.windows)
+        .map(|w| w.id)
+        .collect())
+}
+
+impl KittyBackend {
+    /// Backend talking to a kitty instance on a specific socket.
+    pub fn with_socket(socket: impl Into<PathBuf>) -> Self {
+        Self {
+            socket: Some(socket.into()),
+        }
+    }
+
+    fn socket(&self) -> PathBuf {
+        self.socket.clone().unwrap_or_else(|| {
+            KildPaths::resolve()
+                .map(|p| p.kitty_socket())
+                .unwrap_or_else(|_| std::env::temp_dir().join("kild-kitty.sock"))
+        })
+    }
+
+    fn kitten(&self, args: &[&str]) -> Result<Output, std::io::Error> {
+        Command::new("kitten")
+            .arg("@")
+            .arg("--to")
+            .arg(format!("unix:{}", self.socket().display()))
+            .args(args)
+            .stdin(Stdio::null())
+            .output()
+    }
+
+    /// All window IDs of kild's kitty, or `None` if it isn't running.
+    fn window_ids(&self) -> Result<Option<Vec<u64>>, TerminalError> {
+        let output = self
+            .kitten(&["ls"])
+            .map_err(|e| TerminalError::TerminalNotFound {
+                terminal: format!("kitten ({})", e),
+            })?;
+        if !output.status.success() {
+            // Nothing listening on the socket — nothing is open
+            return Ok(None);
+        }
+        let ids = parse_window_ids(&String::from_utf8_lossy(&output.stdout)).map_err(|e| {
+            TerminalError::RemoteControlFailed {
+                terminal: "kitty".to_string(),
+                message: format!("unparseable `kitten @ ls` output: {}", e),
+            }
+        })?;
+        Ok(Some(ids))
+    }
+
+    /// Open an OS window in the running instance. `Ok(None)` means kild's
+    /// kitty isn't running.
+    fn launch_window(
+        &self,
+        cwd: &str,
+        title: &str,
+        cd_command: &str,
+    ) -> Result<Option<String>, TerminalError> {
+        let output = self
+            .kitten(&[
+                "launch",
+                "--type=os-window",
+                "--cwd",
+                cwd,
+                "--title",
+                title,
+                "sh",
+                "-c",
+                cd_command,
+            ])
+            .map_err(|e| TerminalError::SpawnFailed {
+                message: format!("Failed to execute kitten: {}", e),
+            })?;
+
+        if !output.status.success() {
+            debug!(
+                event = "core.terminal.spawn_kitty_instance_unavailable",
+                stderr = %stderr_lossy(&output)
+            );
+            return Ok(None);
+        }
+
+        let window_id = String::from_utf8_lossy(&output.stdout).trim().to_string();
+        Ok((!window_id.is_empty()).then_some(window_id))
+    }
+
+    /// Start kild's kitty instance with the command as its first window.
+    fn start_instance(
+        &self,
+        working_directory: &Path,
+        title: &str,
+        cd_command: &str,
+    ) -> Result<Option<String>, TerminalError> {
+        let socket = self.socket();
+        if let Some(parent) = socket.parent() {
+            std::fs::create_dir_all(parent)?;
+        }
+        // A socket left behind by a crashed instance would block the bind
+        let _ = std::fs::remove_file(&socket);
+
+        // Detach stdio like the other GUI backends so the terminal never
+        // holds kild's own file descriptors.
+        Command::new("kitty")
+            .arg("--listen-on")
+            .arg(format!("unix:{}", socket.display()))
+            .arg("-o")
+            .arg("allow_remote_control=socket-only")
+            .arg("--title")
+            .arg(title)
+            .arg("--directory")
+            .arg(working_directory)
+            .arg("sh")
+            .arg("-c")
+            .arg(cd_command)
+            .stdin(Stdio::null())
+            .stdout(Stdio::null())
+            .stderr(Stdio::null())
+            .spawn()
+            .map_err(|e| TerminalError::SpawnFailed {
+                message: format!("Failed to start kitty: {}", e),
+            })?;
+
+        // The new instance holds exactly one window — ours
+        let deadline = Instant::now() + STARTUP_TIMEOUT;
+        while Instant::now() < deadline {
+            std::thread::sleep(STARTUP_POLL);
+            if let Ok(Some(ids)) = self.window_ids()
+                && let Some(id) = ids.into_iter().max()
+            {
+                return Ok(Some(id.to_string()));
+            }
+        }
+
+        warn!(
+            event = "core.terminal.spawn_kitty_window_not_found",
+            socket = %socket.display(),
+            message = "kitty started but did not answer on its remote-control socket"
+        );
+        Ok(None)
+    }
+
+    /// Run a `kitten @` command against one window, mapping failures.
+    fn window_command(
+        &self,
+        args: &[&str],
+        window_id: &str,
+        to_error: impl Fn(String) -> TerminalError,
+    ) -> Result<(), TerminalError> {
+        let matcher = format!("id:{}", window_id);
+        let mut full_args = args.to_vec();
+        full_args.extend(["--match", matcher.as_str()]);
+
+        let output = self
+            .kitten(&full_args)
+            .map_err(|e| to_error(format!("Failed to execute kitten: {}", e)))?;
+        if !output.status.success() {
+            return Err(to_error(format!(
+                "kitten @ {} failed for window {}: {}",
+                args.join(" "),
+                window_id,
+                stderr_lossy(&output)
+            )));
+        }
+        Ok(())
+    }
+}
+
+impl TerminalBackend for KittyBackend {
+    fn name(&self) -> &'static str {
+        "kitty"
+    }
+
+    fn display_name(&self) -> &'static str {
+        "Kitty"
+    }
+
+    fn is_available(&self) -> bool {
+        // `kitten` ships with kitty 0.28+ and is what remote control uses
+        let kitty = which::which("kitty").is_ok() && which::which("kitten").is_ok();
+        let graphical = has_graphical_session();
+        debug!(
+            event = "core.terminal.kitty_availability_checked",
+            kitty_available = kitty,
+            display_available = graphical
+        );
+        kitty && graphical
+    }
+
+    fn execute_spawn(
+        &self,
+        config: &SpawnConfig,
+        window_title: Option<&str>,
+    ) -> Result<Option<String>, TerminalError> {
+        let cd_command = build_cd_command(config.working_directory(), config.command());
+        let title = window_title.unwrap_or("kild-session");
+        let cwd = config.working_directory().to_string_lossy().to_string();
+
+        debug!(
+            event = "core.terminal.spawn_kitty_started",
+            working_directory = %cwd,
+            window_title = %title
+        );
+
+        let window_id = match self.launch_window(&cwd, title, &cd_command)? {
+            Some(id) => Some(id),
+            None => self.start_instance(config.working_directory(), title, &cd_command)?,
+        };
+
+        debug!(
+            event = "core.terminal.spawn_kitty_completed",
+            window_id = ?window_id
+        );
+        Ok(window_id)
+    }
+
+    fn close_window_by_id(&self, window_id: &str) {
+        debug!(
+            event = "core.terminal.close_kitty_started",
+            window_id = %window_id
+        );
+
+        match self.window_command(&["close-window"], window_id, |message| {
+            TerminalError::RemoteControlFailed {
+                terminal: "kitty".to_string(),
+                message,
+            }
+        }) {
+            Ok(()) => debug!(
+                event = "core.terminal.close_kitty_completed",
+                window_id = %window_id
+            ),
+            Err(e) => warn!(
+                event = "core.terminal.close_kitty_failed",
+                window_id = %window_id,
+                error = %e
+            ),
+        }
+    }
+
+    fn focus_window(&self, window_id: &str) -> Result<(), TerminalError> {
+        debug!(
+            event = "core.terminal.focus_kitty_started",
+            window_id = %window_id
+        );
+
+        self.window_command(&["focus-window"], window_id, |message| {
+            TerminalError::FocusFailed { message }
+        })
+    }
+
+    fn hide_window(&self, window_id: &str) -> Result<(), TerminalError> {
+        debug!(
+            event = "core.terminal.hide_kitty_started",
+            window_id = %window_id
+        );
+
+        self.window_command(
+            &["resize-os-window", "--action=hide"],
+            window_id,
+            |message| TerminalError::HideFailed { message },
+        )
+    }
+
+    fn is_window_open(&self, window_id: &str) -> Result<Option<bool>, TerminalError> {
+        debug!(
+            event = "core.terminal.kitty_window_check_started",
+            window_id = %window_id
+        );
+
+        let Ok(id) = window_id.parse::<u64>() else {
+            return Ok(Some(false));
+        };
+        Ok(Some(
+            self.window_ids()?.is_some_and(|ids| ids.contains(&id)),
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const LS_JSON: &str = r#"[
+        {"id": 1, "is_focused": true, "tabs": [
+            {"id": 1, "title": "kild-one", "windows": [
+                {"id": 1, "title": "kild-one", "cwd": "/tmp/a"},
+                {"id": 4, "title": "vim", "cwd": "/tmp/a"}
+            ]}
+        ]},
+        {"id": 2, "is_focused": false, "tabs": [
+            {"id": 3, "title": "kild-two", "windows": [
+                {"id": 9, "title": "kild-two", "cwd": "/tmp/b"}
+            ]}
+        ]}
+    ]"#;
+
+    #[test]
+    fn test_kitty_backend_name() {
+        let backend = KittyBackend::default();
+        assert_eq!(backend.name(), "kitty");
+        assert_eq!(backend.display_name(), "Kitty");
+    }
+
+    #[test]
+    fn test_parse_window_ids_flattens_os_windows_and_tabs() {
+        assert_eq!(parse_window_ids(LS_JSON).unwrap(), vec![1, 4, 9]);
+        assert_eq!(parse_window_ids("[]").unwrap(), Vec::<u64>::new());
+        assert!(parse_window_ids("{").is_err());
+    }
+
+    #[test]
+    fn test_kitty_socket_override() {
+        let backend = KittyBackend::with_socket("/tmp/test-kitty.sock");
+        assert_eq!(backend.socket(), PathBuf::from("/tmp/test-kitty.sock"));
+    }
+
+    #[test]
+    fn test_kitty_non_numeric_window_id_is_closed() {
+        let backend = KittyBackend::with_socket("/nonexistent/kitty.sock");
+        assert_eq!(backend.is_window_open("kild-one").unwrap(), Some(false));
+    }
+
+    #[test]
+    fn test_kitty_close_window_skips_when_no_id() {
+        KittyBackend::default().close_window(None);
+    }
+}
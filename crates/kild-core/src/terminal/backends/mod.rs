@@ -3,9 +3,15 @@
 mod alacritty;
 mod ghostty;
 mod iterm;
+mod kitty;
 mod terminal_app;
+mod tmux;
+mod wezterm;
 
 pub use alacritty::AlacrittyBackend;
 pub use ghostty::GhosttyBackend;
 pub use iterm::ITermBackend;
+pub use kitty::KittyBackend;
 pub use terminal_app::TerminalAppBackend;
+pub use tmux::{TmuxBackend, tmux_session_name};
+pub use wezterm::WeztermBackend;
@@ -0,0 +1,371 @@
+//! tmux terminal backend for headless servers.
+//!
+//! Each project gets a detached tmux session named `kild-<project>`; every
+//! kild runs in its own window in that session. The tmux window ID (`@N`) is
+//! returned from spawn and persisted as the terminal window ID, so focus,
+//! hide and close target the exact window. Attach with
+//! `tmux attach -t kild-<project>`.
+
+use std::path::{Path, PathBuf};
+use std::process::{Command, Output};
+
+use tracing::{debug, warn};
+
+use crate::terminal::{
+    common::{escape::build_cd_command, helpers::stderr_lossy},
+    errors::TerminalError,
+    traits::TerminalBackend,
+    types::SpawnConfig,
+};
+
+/// Backend implementation for tmux (works with or without a GUI).
+#[derive(Debug, Default)]
+pub struct TmuxBackend {
+    /// Server socket path (`tmux -S`). `None` uses the default server.
+    socket: Option<PathBuf>,
+}
+
+impl TmuxBackend {
+    /// Backend talking to a tmux server on a specific socket.
+    pub fn with_socket(socket: impl Into<PathBuf>) -> Self {
+        Self {
+            socket: Some(socket.into()),
+        }
+    }
+
+    fn tmux(&self, args: &[&str]) -> Result<Output, std::io::Error> {
+        let mut cmd = Command::new("tmux");
+        if let Some(socket) = &self.socket {
+            cmd.arg("-S").arg(socket);
+        }
+        cmd.args(args).output()
+    }
+
+    fn has_session(&self, session: &str) -> bool {
+        self.tmux(&["has-session", "-t", &format!("={}", session)])
+            .is_ok_and(|o| o.status.success())
+    }
+
+    /// All window IDs on the server, or `None` if no server is running.
+    fn window_ids(&self) -> Result<Option<Vec<String>>, TerminalError> {
+        let output = self
+            .tmux(&["list-windows", "-a", "-F", "#{window_id}"])
+            .map_err(|e| TerminalError::TerminalNotFound {
+                terminal: format!("tmux ({})", e),
+            })?;
+        if !output.status.success() {
+            // "no server running" / "error connecting to ..." — nothing is open
+            return Ok(None);
+        }
+        Ok(Some(
+            String::from_utf8_lossy(&output.stdout)
+                .lines()
+                .map(str::to_string)
+                .collect(),
+        ))
+    }
+}
+
+/// tmux session name for a working directory: `kild-<project>`.
+///
+/// Kild worktrees live at `<base>/worktrees/<project>/<branch>`, so the
+/// project is the parent directory's name; other directories use their own
+/// name. `.` and `:` are replaced since tmux reserves them in targets.
+pub fn tmux_session_name(working_directory: &Path) -> String {
+    let parent = working_directory.parent();
+    let in_worktrees = parent
+        .and_then(Path::parent)
+        .and_then(Path::file_name)
+        .is_some_and(|n| n == "worktrees");
+    let project = if in_worktrees {
+        parent.and_then(Path::file_name)
+    } else {
+        working_directory.file_name()
+    };
+    let project = project
+        .map(|n| n.to_string_lossy().replace(['.', ':'], "_"))
+        .unwrap_or_else(|| "default".to_string());
+    format!("kild-{}", project)
+}
+
+impl TerminalBackend for TmuxBackend {
+    fn name(&self) -> &'static str {
+        "tmux"
+    }
+
+    fn display_name(&self) -> &'static str {
+        "tmux"
+    }
+
+    fn is_available(&self) -> bool {
+        let available = which::which("tmux").is_ok();
+        debug!(
+            event = "core.terminal.tmux_availability_checked",
+            available = available
+        );
+        available
+    }
+
+    fn execute_spawn(
+        &self,
+        config: &SpawnConfig,
+        window_title: Option<&str>,
+    ) -> Result<Option<String>, TerminalError> {
+        let cd_command = build_cd_command(config.working_directory(), config.command());
+        let title = window_title.unwrap_or("kild-session");
+        let session = tmux_session_name(config.working_directory());
+        let cwd = config.working_directory().to_string_lossy().to_string();
+
+        debug!(
+            event = "core.terminal.spawn_tmux_started",
+            session = %session,
+            working_directory = %cwd,
+            window_title = %title
+        );
+
+        // -d: never switch an attached client; -P -F: print the new window ID
+        let target = format!("={}:", session);
+        let mut args = if self.has_session(&session) {
+            vec!["new-window", "-d", "-t", target.as_str()]
+        } else {
+            vec!["new-session", "-d", "-s", session.as_str()]
+        };
+        args.extend([
+            "-n",
+            title,
+            "-c",
+            cwd.as_str(),
+            "-P",
+            "-F",
+            "#{window_id}",
+            "sh",
+            "-c",
+            cd_command.as_str(),
+        ]);
+
+        let output = self.tmux(&args).map_err(|e| TerminalError::SpawnFailed {
+            message: format!("Failed to execute tmux: {}", e),
+        })?;
+        if !output.status.success() {
+            return Err(TerminalError::SpawnFailed {
+                message: format!(
+                    "tmux failed to create window in session '{}': {}",
+                    session,
+                    stderr_lossy(&output)
+                ),
+            });
+        }
+
+        let window_id = String::from_utf8_lossy(&output.stdout).trim().to_string();
+        debug!(
+            event = "core.terminal.spawn_tmux_completed",
+            session = %session,
+            window_id = %window_id
+        );
+
+        Ok((!window_id.is_empty()).then_some(window_id))
+    }
+
+    fn close_window_by_id(&self, window_id: &str) {
+        debug!(
+            event = "core.terminal.close_tmux_started",
+            window_id = %window_id
+        );
+
+        match self.tmux(&["kill-window", "-t", window_id]) {
+            Ok(output) if output.status.success() => {
+                debug!(
+                    event = "core.terminal.close_tmux_completed",
+                    window_id = %window_id
+                );
+            }
+            Ok(output) => warn!(
+                event = "core.terminal.close_tmux_failed",
+                window_id = %window_id,
+                stderr = %stderr_lossy(&output)
+            ),
+            Err(e) => warn!(
+                event = "core.terminal.close_tmux_failed",
+                window_id = %window_id,
+                error = %e
+            ),
+        }
+    }
+
+    fn focus_window(&self, window_id: &str) -> Result<(), TerminalError> {
+        debug!(
+            event = "core.terminal.focus_tmux_started",
+            window_id = %window_id
+        );
+
+        let output = self
+            .tmux(&["select-window", "-t", window_id])
+            .map_err(|e| TerminalError::FocusFailed {
+                message: format!("Failed to execute tmux: {}", e),
+            })?;
+        if !output.status.success() {
+            return Err(TerminalError::FocusFailed {
+                message: format!(
+                    "tmux select-window failed for '{}': {}",
+                    window_id,
+                    stderr_lossy(&output)
+                ),
+            });
+        }
+
+        // Bring attached clients along (no-op error when none is attached)
+        let _ = self.tmux(&["switch-client", "-t", window_id]);
+        Ok(())
+    }
+
+    /// tmux windows can't be minimised; hiding switches the session back to
+    /// its previous window when this one is current.
+    fn hide_window(&self, window_id: &str) -> Result<(), TerminalError> {
+        debug!(
+            event = "core.terminal.hide_tmux_started",
+            window_id = %window_id
+        );
+
+        let output = self
+            .tmux(&[
+                "display-message",
+                "-p",
+                "-t",
+                window_id,
+                "#{window_active} #{session_id}",
+            ])
+            .map_err(|e| TerminalError::HideFailed {
+                message: format!("Failed to execute tmux: {}", e),
+            })?;
+        if !output.status.success() {
+            return Err(TerminalError::HideFailed {
+                message: format!(
+                    "tmux window '{}' not found: {}",
+                    window_id,
+                    stderr_lossy(&output)
+                ),
+            });
+        }
+
+        let info = String::from_utf8_lossy(&output.stdout);
+        let mut parts = info.split_whitespace();
+        if let (Some("1"), Some(session_id)) = (parts.next(), parts.next()) {
+            // Fails when the session has a single window — nothing to hide behind
+            let _ = self.tmux(&["last-window", "-t", session_id]);
+        }
+        Ok(())
+    }
+
+    fn is_window_open(&self, window_id: &str) -> Result<Option<bool>, TerminalError> {
+        debug!(
+            event = "core.terminal.tmux_window_check_started",
+            window_id = %window_id
+        );
+
+        Ok(Some(
+            self.window_ids()?
+                .is_some_and(|ids| ids.iter().any(|id| id == window_id)),
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_tmux_backend_name() {
+        let backend = TmuxBackend::default();
+        assert_eq!(backend.name(), "tmux");
+        assert_eq!(backend.display_name(), "tmux");
+    }
+
+    #[test]
+    fn test_tmux_session_name_uses_project_for_worktrees() {
+        let path = Path::new("/home/u/.kild/worktrees/my-app/feature-auth");
+        assert_eq!(tmux_session_name(path), "kild-my-app");
+    }
+
+    #[test]
+    fn test_tmux_session_name_outside_worktrees_uses_dir_name() {
+        assert_eq!(
+            tmux_session_name(Path::new("/src/web.site")),
+            "kild-web_site"
+        );
+        assert_eq!(tmux_session_name(Path::new("/")), "kild-default");
+    }
+
+    #[test]
+    fn test_tmux_close_window_skips_when_no_id() {
+        TmuxBackend::default().close_window(None);
+    }
+
+    /// Private tmux server torn down on drop.
+    struct PrivateServer {
+        _dir: tempfile::TempDir,
+        backend: TmuxBackend,
+    }
+
+    impl PrivateServer {
+        fn start() -> Option<Self> {
+            if which::which("tmux").is_err() {
+                return None;
+            }
+            let dir = tempfile::TempDir::new().unwrap();
+            let backend = TmuxBackend::with_socket(dir.path().join("tmux.sock"));
+            Some(Self { _dir: dir, backend })
+        }
+    }
+
+    impl Drop for PrivateServer {
+        fn drop(&mut self) {
+            let _ = self.backend.tmux(&["kill-server"]);
+        }
+    }
+
+    #[test]
+    fn test_tmux_window_lifecycle_on_private_server() {
+        let Some(server) = PrivateServer::start() else {
+            return;
+        };
+        let backend = &server.backend;
+        let base = tempfile::TempDir::new().unwrap();
+        let worktree = base.path().join("worktrees").join("proj").join("branch");
+        std::fs::create_dir_all(&worktree).unwrap();
+        let config = SpawnConfig::new(
+            crate::terminal::types::TerminalType::Tmux,
+            worktree.clone(),
+            "sleep 30".to_string(),
+        );
+
+        assert_eq!(backend.is_window_open("@999").unwrap(), Some(false));
+
+        let first = backend
+            .execute_spawn(&config, Some("kild-one"))
+            .unwrap()
+            .expect("window id");
+        let second = backend
+            .execute_spawn(&config, Some("kild-two"))
+            .unwrap()
+            .expect("window id");
+        assert!(first.starts_with('@'));
+        assert_ne!(first, second);
+        assert!(backend.has_session("kild-proj"));
+
+        // Both windows live in the same per-project session
+        let windows = backend
+            .tmux(&["list-windows", "-t", "=kild-proj", "-F", "#{window_name}"])
+            .unwrap();
+        let names = String::from_utf8_lossy(&windows.stdout);
+        assert!(names.contains("kild-one") && names.contains("kild-two"));
+
+        assert_eq!(backend.is_window_open(&first).unwrap(), Some(true));
+        backend.focus_window(&second).unwrap();
+        backend.hide_window(&second).unwrap();
+        assert!(backend.focus_window("@999").is_err());
+
+        backend.close_window(Some(&first));
+        assert_eq!(backend.is_window_open(&first).unwrap(), Some(false));
+        assert_eq!(backend.is_window_open(&second).unwrap(), Some(true));
+    }
+}
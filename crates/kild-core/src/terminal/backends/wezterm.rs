@@ -0,0 +1,353 @@
+//! WezTerm terminal backend using `wezterm cli` remote control.
+//!
+//! Spawns go through `wezterm cli spawn --new-window`, which prints the new
+//! pane ID. The pane ID is stable for the life of the pane and is persisted
+//! as the terminal window ID, so focus, close and liveness checks
+//! (`wezterm cli list`) target the exact pane without AppleScript or
+//! compositor IPC. When no WezTerm GUI is running, a new one is started with
+//! `wezterm start` and the pane is found in `wezterm cli list` by its cwd.
+
+use std::path::Path;
+use std::process::{Command, Output, Stdio};
+use std::time::{Duration, Instant};
+
+use serde::Deserialize;
+use tracing::{debug, warn};
+
+use crate::terminal::{
+    common::{detection::has_graphical_session, escape::build_cd_command, helpers::stderr_lossy},
+    errors::TerminalError,
+    traits::TerminalBackend,
+    types::SpawnConfig,
+};
+
+/// How long to wait for a freshly started WezTerm GUI to report its pane.
+const GUI_STARTUP_TIMEOUT: Duration = Duration::from_secs(5);
+const GUI_STARTUP_POLL: Duration = Duration::from_millis(100);
+
+/// Backend implementation for WezTerm (macOS and Linux).
+pub struct WeztermBackend;
+
+/// One entry of `wezterm cli list --format json`.
+#[derive(Debug, Deserialize)]
+struct WeztermPane {
+    pane_id: u64,
+    /// `file://<host>/<path>` URL, empty when unknown.
+    #[serde(default)]
+    cwd: String,
+}
+
+/// Parse `wezterm cli list --format json` output.
+fn parse_panes(json: &str) -> Result<Vec<WeztermPane>, serde_json::Error> {
+    serde_json::from_str(json)
+}
+
+/// Whether a pane's `file://` cwd URL points at `dir`.
+fn cwd_matches(cwd_url: &str, dir: &Path) -> bool {
+    let Some(rest) = cwd_url.strip_prefix("file://") else {
+        return false;
+    };
+    // Skip the host component; the path starts at the next '/'
+    let Some(path) = rest.find('/').map(|i| &rest[i..]) else {
+        return false;
+    };
+    let path = path.trim_end_matches('/').replace("%20", " ");
+    path == dir.to_string_lossy().trim_end_matches('/')
+}
+
+/// Run `wezterm cli` without auto-starting a headless mux server.
+///
+/// Without `--no-auto-start` a missing GUI makes WezTerm start an invisible
+/// mux server and the spawned pane would never show up on screen.
+fn wezterm_cli(args: &[&str]) -> Result<Output, std::io::Error> {
+    Command::new("wezterm")
+        .arg("cli")
+        .arg("--no-auto-start")
+        .args(args)
+        .stdin(Stdio::null())
+        .output()
+}
+
+/// Panes of the running WezTerm GUI, or `None` if none is reachable.
+fn list_panes() -> Result<Option<Vec<WeztermPane>>, TerminalError> {
+    let output = wezterm_cli(&["list", "--format", "json"]).map_err(|e| {
+        TerminalError::TerminalNotFound {
+            terminal: format!("wezterm ({})", e),
+        }
+    })?;
+    if !output.status.success() {
+        // No GUI running — nothing is open
+        return Ok(None);
+    }
+    let panes = parse_panes(&String::from_utf8_lossy(&output.stdout)).map_err(|e| {
+        TerminalError::RemoteControlFailed {
+            terminal: "wezterm".to_string(),
+            message: format!("unparseable `wezterm cli list` output: {}", e),
+        }
+    })?;
+    Ok(Some(panes))
+}
+
+impl WeztermBackend {
+    /// Spawn into the running GUI. `Ok(None)` means no GUI is reachable.
+    fn spawn_via_cli(&self, cwd: &str, cd_command: &str) -> Result<Option<String>, TerminalError> {
+        let output = wezterm_cli(&[
+            "spawn",
+            "--new-window",
+            "--cwd",
+            cwd,
+            "--",
+            "sh",
+            "-c",
+            cd_command,
+        ])
+        .map_err(|e| TerminalError::SpawnFailed {
+            message: format!("Failed to execute wezterm: {}", e),
+        })?;
+
+        if !output.status.success() {
+            debug!(
+                event = "core.terminal.spawn_wezterm_cli_unavailable",
+                stderr = %stderr_lossy(&output)
+            );
+            return Ok(None);
+        }
+
+        let pane_id = String::from_utf8_lossy(&output.stdout).trim().to_string();
+        Ok((!pane_id.is_empty()).then_some(pane_id))
+    }
+
+    /// Start a new WezTerm GUI and find its pane by working directory.
+    fn spawn_via_gui(
+        &self,
+        working_directory: &Path,
+        cd_command: &str,
+    ) -> Result<Option<String>, TerminalError> {
+        // Detach stdio like the other GUI backends so the terminal never
+        // holds kild's own file descriptors.
+        Command::new("wezterm")
+            .arg("start")
+            .arg("--cwd")
+            .arg(working_directory)
+            .arg("--")
+            .arg("sh")
+            .arg("-c")
+            .arg(cd_command)
+            .stdin(Stdio::null())
+            .stdout(Stdio::null())
+            .stderr(Stdio::null())
+            .spawn()
+            .map_err(|e| TerminalError::SpawnFailed {
+                message: format!("Failed to start wezterm: {}", e),
+            })?;
+
+        let deadline = Instant::now() + GUI_STARTUP_TIMEOUT;
+        while Instant::now() < deadline {
+            std::thread::sleep(GUI_STARTUP_POLL);
+            if let Ok(Some(panes)) = list_panes()
+                && let Some(pane) = panes
+                    .iter()
+                    .filter(|p| cwd_matches(&p.cwd, working_directory))
+                    .max_by_key(|p| p.pane_id)
+            {
+                return Ok(Some(pane.pane_id.to_string()));
+            }
+        }
+
+        warn!(
+            event = "core.terminal.spawn_wezterm_pane_not_found",
+            working_directory = %working_directory.display(),
+            message = "WezTerm started but its pane did not appear in `wezterm cli list`"
+        );
+        Ok(None)
+    }
+}
+
+impl TerminalBackend for WeztermBackend {
+    fn name(&self) -> &'static str {
+        "wezterm"
+    }
+
+    fn display_name(&self) -> &'static str {
+        "WezTerm"
+    }
+
+    fn is_available(&self) -> bool {
+        let wezterm = which::which("wezterm").is_ok();
+        let graphical = has_graphical_session();
+        debug!(
+            event = "core.terminal.wezterm_availability_checked",
+            wezterm_available = wezterm,
+            display_available = graphical
+        );
+        wezterm && graphical
+    }
+
+    fn execute_spawn(
+        &self,
+        config: &SpawnConfig,
+        window_title: Option<&str>,
+    ) -> Result<Option<String>, TerminalError> {
+        let cd_command = build_cd_command(config.working_directory(), config.command());
+        let cwd = config.working_directory().to_string_lossy().to_string();
+
+        debug!(
+            event = "core.terminal.spawn_wezterm_started",
+            working_directory = %cwd,
+            window_title = ?window_title
+        );
+
+        let pane_id = match self.spawn_via_cli(&cwd, &cd_command)? {
+            Some(id) => Some(id),
+            None => self.spawn_via_gui(config.working_directory(), &cd_command)?,
+        };
+
+        if let (Some(id), Some(title)) = (&pane_id, window_title) {
+            // Cosmetic — the pane ID, not the title, identifies the window
+            let _ = wezterm_cli(&["set-tab-title", "--pane-id", id, title]);
+        }
+
+        debug!(
+            event = "core.terminal.spawn_wezterm_completed",
+            pane_id = ?pane_id
+        );
+        Ok(pane_id)
+    }
+
+    fn close_window_by_id(&self, window_id: &str) {
+        debug!(
+            event = "core.terminal.close_wezterm_started",
+            pane_id = %window_id
+        );
+
+        match wezterm_cli(&["kill-pane", "--pane-id", window_id]) {
+            Ok(output) if output.status.success() => {
+                debug!(
+                    event = "core.terminal.close_wezterm_completed",
+                    pane_id = %window_id
+                );
+            }
+            Ok(output) => warn!(
+                event = "core.terminal.close_wezterm_failed",
+                pane_id = %window_id,
+                stderr = %stderr_lossy(&output)
+            ),
+            Err(e) => warn!(
+                event = "core.terminal.close_wezterm_failed",
+                pane_id = %window_id,
+                error = %e
+            ),
+        }
+    }
+
+    fn focus_window(&self, window_id: &str) -> Result<(), TerminalError> {
+        debug!(
+            event = "core.terminal.focus_wezterm_started",
+            pane_id = %window_id
+        );
+
+        let output = wezterm_cli(&["activate-pane", "--pane-id", window_id]).map_err(|e| {
+            TerminalError::FocusFailed {
+                message: format!("Failed to execute wezterm: {}", e),
+            }
+        })?;
+        if !output.status.success() {
+            return Err(TerminalError::FocusFailed {
+                message: format!(
+                    "wezterm activate-pane failed for pane {}: {}",
+                    window_id,
+                    stderr_lossy(&output)
+                ),
+            });
+        }
+        Ok(())
+    }
+
+    /// `wezterm cli` has no command to minimise a window.
+    fn hide_window(&self, window_id: &str) -> Result<(), TerminalError> {
+        debug!(
+            event = "core.terminal.hide_wezterm_not_supported",
+            pane_id = %window_id
+        );
+        Err(TerminalError::HideFailed {
+            message: "WezTerm's remote control cannot minimise windows; \
+                      hide it from your window manager instead"
+                .to_string(),
+        })
+    }
+
+    fn is_window_open(&self, window_id: &str) -> Result<Option<bool>, TerminalError> {
+        debug!(
+            event = "core.terminal.wezterm_window_check_started",
+            pane_id = %window_id
+        );
+
+        let Ok(pane_id) = window_id.parse::<u64>() else {
+            return Ok(Some(false));
+        };
+        Ok(Some(list_panes()?.is_some_and(|panes| {
+            panes.iter().any(|p| p.pane_id == pane_id)
+        })))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const LIST_JSON: &str = r#"[
+        {"window_id":0,"tab_id":0,"pane_id":0,"workspace":"default",
+         "size":{"rows":24,"cols":80},"title":"zsh",
+         "cwd":"file://host/home/u/.kild/worktrees/app/feature",
+         "is_active":true,"is_zoomed":false},
+        {"window_id":1,"tab_id":1,"pane_id":7,"workspace":"default",
+         "size":{"rows":24,"cols":80},"title":"claude","cwd":"",
+         "is_active":true,"is_zoomed":false}
+    ]"#;
+
+    #[test]
+    fn test_wezterm_backend_name() {
+        assert_eq!(WeztermBackend.name(), "wezterm");
+        assert_eq!(WeztermBackend.display_name(), "WezTerm");
+    }
+
+    #[test]
+    fn test_parse_panes() {
+        let panes = parse_panes(LIST_JSON).unwrap();
+        let ids: Vec<u64> = panes.iter().map(|p| p.pane_id).collect();
+        assert_eq!(ids, vec![0, 7]);
+        assert!(parse_panes("not json").is_err());
+    }
+
+    #[test]
+    fn test_cwd_matches() {
+        let dir = Path::new("/home/u/.kild/worktrees/app/feature");
+        assert!(cwd_matches(
+            "file://host/home/u/.kild/worktrees/app/feature",
+            dir
+        ));
+        assert!(cwd_matches(
+            "file:///home/u/.kild/worktrees/app/feature/",
+            dir
+        ));
+        assert!(!cwd_matches("file://host/home/u", dir));
+        assert!(!cwd_matches("", dir));
+        assert!(cwd_matches(
+            "file://h/tmp/my%20dir",
+            Path::new("/tmp/my dir")
+        ));
+    }
+
+    #[test]
+    fn test_wezterm_hide_is_unsupported() {
+        assert!(matches!(
+            WeztermBackend.hide_window("3"),
+            Err(TerminalError::HideFailed { .. })
+        ));
+    }
+
+    #[test]
+    fn test_wezterm_close_window_skips_when_no_id() {
+        WeztermBackend.close_window(None);
+    }
+}
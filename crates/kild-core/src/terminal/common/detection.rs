@@ -50,6 +50,19 @@ pub fn app_exists_linux(_app_name: &str) -> bool {
     false
 }
 
+/// Check whether GUI terminals can open windows in this session.
+///
+/// Always true on macOS; on Linux an X11 or Wayland display must be set
+/// (SSH sessions on headless servers have neither).
+pub fn has_graphical_session() -> bool {
+    if cfg!(target_os = "macos") {
+        return true;
+    }
+    ["DISPLAY", "WAYLAND_DISPLAY"]
+        .iter()
+        .any(|var| std::env::var_os(var).is_some_and(|v| !v.is_empty()))
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -2,7 +2,9 @@ use crate::errors::KildError;
 
 #[derive(Debug, thiserror::Error)]
 pub enum TerminalError {
-    #[error("No supported terminal found (tried: Ghostty, iTerm, Terminal.app, Alacritty)")]
+    #[error(
+        "No supported terminal found (tried: Ghostty, iTerm, Terminal.app, Alacritty, WezTerm, Kitty, tmux)"
+    )]
     NoTerminalFound,
 
     #[error("Terminal '{terminal}' not found or not executable")]
@@ -26,6 +28,11 @@ pub enum TerminalError {
     #[error("Hyprland IPC failed: {message}")]
     HyprlandIpcFailed { message: String },
 
+    /// A terminal's scriptable remote control (`wezterm cli`, `kitten @`)
+    /// returned an error or unparseable output.
+    #[error("{terminal} remote control failed: {message}")]
+    RemoteControlFailed { terminal: String, message: String },
+
     #[error("Failed to focus terminal window: {message}")]
     FocusFailed { message: String },
 
@@ -55,6 +62,7 @@ impl KildError for TerminalError {
             TerminalError::AppleScriptExecution { .. } => "APPLESCRIPT_EXECUTION_FAILED",
             TerminalError::AppleScriptFailed { .. } => "APPLESCRIPT_FAILED",
             TerminalError::HyprlandIpcFailed { .. } => "HYPRLAND_IPC_FAILED",
+            TerminalError::RemoteControlFailed { .. } => "TERMINAL_REMOTE_CONTROL_FAILED",
             TerminalError::FocusFailed { .. } => "TERMINAL_FOCUS_FAILED",
             TerminalError::HideFailed { .. } => "TERMINAL_HIDE_FAILED",
             TerminalError::NativeWindowError { .. } => "NATIVE_WINDOW_ERROR",
@@ -71,6 +79,7 @@ impl KildError for TerminalError {
                 | TerminalError::AppleScriptExecution { .. }
                 | TerminalError::AppleScriptFailed { .. }
                 | TerminalError::HyprlandIpcFailed { .. }
+                | TerminalError::RemoteControlFailed { .. }
                 | TerminalError::FocusFailed { .. }
                 | TerminalError::HideFailed { .. }
                 | TerminalError::NativeWindowError { .. }
@@ -87,7 +96,7 @@ mod tests {
         let error = TerminalError::NoTerminalFound;
         assert_eq!(
             error.to_string(),
-            "No supported terminal found (tried: Ghostty, iTerm, Terminal.app, Alacritty)"
+            "No supported terminal found (tried: Ghostty, iTerm, Terminal.app, Alacritty, WezTerm, Kitty, tmux)"
         );
         assert_eq!(error.error_code(), "NO_TERMINAL_FOUND");
         assert!(error.is_user_error());
@@ -103,6 +112,20 @@ mod tests {
         assert!(error.is_user_error());
     }
 
+    #[test]
+    fn test_remote_control_failed() {
+        let error = TerminalError::RemoteControlFailed {
+            terminal: "kitty".to_string(),
+            message: "remote control is disabled".to_string(),
+        };
+        assert_eq!(
+            error.to_string(),
+            "kitty remote control failed: remote control is disabled"
+        );
+        assert_eq!(error.error_code(), "TERMINAL_REMOTE_CONTROL_FAILED");
+        assert!(error.is_user_error());
+    }
+
     #[test]
     fn test_terminal_not_found() {
         let error = TerminalError::TerminalNotFound {
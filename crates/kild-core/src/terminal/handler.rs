@@ -42,6 +42,9 @@ pub fn spawn_terminal(
             "terminal" => TerminalType::TerminalApp,
             "ghostty" => TerminalType::Ghostty,
             "alacritty" => TerminalType::Alacritty,
+            "wezterm" => TerminalType::Wezterm,
+            "kitty" => TerminalType::Kitty,
+            "tmux" => TerminalType::Tmux,
             "native" => TerminalType::Native,
             _ => {
                 warn!(
@@ -16,7 +16,7 @@ pub use crate::terminal::common::escape::{build_cd_command, escape_regex, shell_
 ///
 /// Checks terminals in preference order
 ///   macOs: (Ghostty > iTerm > Terminal.app)
-///   Linux: (Alacritty with Hyprland)
+///   Linux: (Alacritty with Hyprland > WezTerm > Kitty > tmux)
 pub fn detect_terminal() -> Result<TerminalType, TerminalError> {
     registry::detect_terminal()
 }
@@ -7,7 +7,10 @@ use std::sync::LazyLock;
 use tracing::debug;
 use tracing::warn;
 
-use super::backends::{AlacrittyBackend, GhosttyBackend, ITermBackend, TerminalAppBackend};
+use super::backends::{
+    AlacrittyBackend, GhosttyBackend, ITermBackend, KittyBackend, TerminalAppBackend, TmuxBackend,
+    WeztermBackend,
+};
 use super::errors::TerminalError;
 use super::traits::TerminalBackend;
 use super::types::TerminalType;
@@ -27,6 +30,9 @@ impl TerminalRegistry {
         backends.insert(TerminalType::ITerm, Box::new(ITermBackend));
         backends.insert(TerminalType::TerminalApp, Box::new(TerminalAppBackend));
         backends.insert(TerminalType::Alacritty, Box::new(AlacrittyBackend));
+        backends.insert(TerminalType::Wezterm, Box::new(WeztermBackend));
+        backends.insert(TerminalType::Kitty, Box::new(KittyBackend::default()));
+        backends.insert(TerminalType::Tmux, Box::new(TmuxBackend::default()));
         // Note: Native is NOT registered - it delegates to detected type
         Self { backends }
     }
@@ -45,7 +51,7 @@ pub fn get_backend(terminal_type: &TerminalType) -> Option<&'static dyn Terminal
     REGISTRY.get(terminal_type)
 }
 
-/// Detect available terminal (Ghostty > iTerm > Terminal.app).
+/// Detect available terminal (Ghostty > iTerm > Terminal.app > tmux).
 ///
 /// Checks terminals in preference order and returns the first available one.
 /// This function will never return `TerminalType::Native`.
@@ -53,11 +59,12 @@ pub fn get_backend(terminal_type: &TerminalType) -> Option<&'static dyn Terminal
 pub fn detect_terminal() -> Result<TerminalType, TerminalError> {
     debug!(event = "core.terminal.detection_started");
 
-    // Check in preference order: Ghostty > iTerm > Terminal.app
+    // Check in preference order: Ghostty > iTerm > Terminal.app > tmux
     let terminals = [
         TerminalType::Ghostty,
         TerminalType::ITerm,
         TerminalType::TerminalApp,
+        TerminalType::Tmux,
     ];
 
     for terminal_type in terminals {
@@ -71,12 +78,12 @@ pub fn detect_terminal() -> Result<TerminalType, TerminalError> {
 
     warn!(
         event = "core.terminal.none_found",
-        checked = "Ghostty,iTerm,Terminal"
+        checked = "Ghostty,iTerm,Terminal,tmux"
     );
     Err(TerminalError::NoTerminalFound)
 }
 
-/// Detect available terminal on Linux (Alacritty + Hyprland).
+/// Detect available terminal on Linux (Alacritty + Hyprland > WezTerm > Kitty > tmux).
 ///
 /// Checks terminals in preference order and returns the first available one.
 /// This function will never return `TerminalType::Native`.
@@ -84,8 +91,13 @@ pub fn detect_terminal() -> Result<TerminalType, TerminalError> {
 pub fn detect_terminal() -> Result<TerminalType, TerminalError> {
     debug!(event = "core.terminal.detection_started");
 
-    // Check in preference order (currently only Alacritty with Hyprland is supported)
-    let terminals = [TerminalType::Alacritty];
+    // Check in preference order: a GUI terminal first, tmux for headless servers
+    let terminals = [
+        TerminalType::Alacritty,
+        TerminalType::Wezterm,
+        TerminalType::Kitty,
+        TerminalType::Tmux,
+    ];
 
     for terminal_type in terminals {
         if let Some(backend) = get_backend(&terminal_type)
@@ -96,7 +108,10 @@ pub fn detect_terminal() -> Result<TerminalType, TerminalError> {
         }
     }
 
-    warn!(event = "core.terminal.none_found", checked = "Alacritty");
+    warn!(
+        event = "core.terminal.none_found",
+        checked = "Alacritty,WezTerm,Kitty,tmux"
+    );
     Err(TerminalError::NoTerminalFound)
 }
 
@@ -141,6 +156,27 @@ mod tests {
         assert_eq!(backend.unwrap().name(), "alacritty");
     }
 
+    #[test]
+    fn test_get_backend_wezterm() {
+        let backend = get_backend(&TerminalType::Wezterm);
+        assert!(backend.is_some());
+        assert_eq!(backend.unwrap().name(), "wezterm");
+    }
+
+    #[test]
+    fn test_get_backend_kitty() {
+        let backend = get_backend(&TerminalType::Kitty);
+        assert!(backend.is_some());
+        assert_eq!(backend.unwrap().name(), "kitty");
+    }
+
+    #[test]
+    fn test_get_backend_tmux() {
+        let backend = get_backend(&TerminalType::Tmux);
+        assert!(backend.is_some());
+        assert_eq!(backend.unwrap().name(), "tmux");
+    }
+
     #[test]
     fn test_get_backend_native_returns_none() {
         // Native is not registered - must use detect_terminal() first
@@ -161,6 +197,9 @@ mod tests {
             TerminalType::ITerm,
             TerminalType::TerminalApp,
             TerminalType::Alacritty,
+            TerminalType::Wezterm,
+            TerminalType::Kitty,
+            TerminalType::Tmux,
         ];
         for terminal_type in expected {
             let backend = get_backend(&terminal_type);
@@ -179,6 +218,9 @@ mod tests {
             (TerminalType::ITerm, "iterm"),
             (TerminalType::TerminalApp, "terminal"),
             (TerminalType::Alacritty, "alacritty"),
+            (TerminalType::Wezterm, "wezterm"),
+            (TerminalType::Kitty, "kitty"),
+            (TerminalType::Tmux, "tmux"),
         ];
         for (terminal_type, expected_name) in checks {
             let backend = get_backend(&terminal_type).unwrap();
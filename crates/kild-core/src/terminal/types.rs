@@ -13,6 +13,9 @@ pub enum TerminalType {
     Ghostty,
     #[serde(alias = "Alacritty")]
     Alacritty,
+    Wezterm,
+    Kitty,
+    Tmux,
     #[serde(alias = "Native")]
     Native, // System default
 }
@@ -134,6 +137,9 @@ impl std::fmt::Display for TerminalType {
             TerminalType::TerminalApp => write!(f, "terminal"),
             TerminalType::Ghostty => write!(f, "ghostty"),
             TerminalType::Alacritty => write!(f, "alacritty"),
+            TerminalType::Wezterm => write!(f, "wezterm"),
+            TerminalType::Kitty => write!(f, "kitty"),
+            TerminalType::Tmux => write!(f, "tmux"),
             TerminalType::Native => write!(f, "native"),
         }
     }
@@ -149,6 +155,9 @@ mod tests {
         assert_eq!(TerminalType::TerminalApp.to_string(), "terminal");
         assert_eq!(TerminalType::Ghostty.to_string(), "ghostty");
         assert_eq!(TerminalType::Alacritty.to_string(), "alacritty");
+        assert_eq!(TerminalType::Wezterm.to_string(), "wezterm");
+        assert_eq!(TerminalType::Kitty.to_string(), "kitty");
+        assert_eq!(TerminalType::Tmux.to_string(), "tmux");
         assert_eq!(TerminalType::Native.to_string(), "native");
     }
 
@@ -354,6 +363,9 @@ mod tests {
             TerminalType::TerminalApp,
             TerminalType::Ghostty,
             TerminalType::Alacritty,
+            TerminalType::Wezterm,
+            TerminalType::Kitty,
+            TerminalType::Tmux,
             TerminalType::Native,
         ] {
             let json = serde_json::to_string(&tt).unwrap();
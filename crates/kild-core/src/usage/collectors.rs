@@ -0,0 +1,528 @@
+//! Parsers for agent-native usage records.
+//!
+//! Each agent stores usage differently:
+//! - Claude Code: `<config>/projects/<encoded-cwd>/<session-id>.jsonl` transcripts,
+//!   one `assistant` line per content block carrying `message.usage`.
+//! - Codex: `<codex-home>/sessions/YYYY/MM/DD/rollout-*.jsonl`, where a
+//!   `session_meta` line records the cwd and `token_count` events carry
+//!   cumulative totals.
+//! - OpenCode: `<data>/opencode/storage/session/<project>/<id>.json` records the
+//!   directory; per-message JSON files under `storage/message/<id>/` carry
+//!   tokens and a recorded cost.
+//!
+//! All parsers are best-effort: unreadable files and malformed lines are
+//! skipped so one bad transcript never hides the rest of the fleet's usage.
+
+use std::collections::{BTreeMap, HashMap};
+use std::fs;
+use std::io::{BufRead, BufReader};
+use std::path::{Path, PathBuf};
+
+use serde_json::Value;
+use tracing::{debug, warn};
+
+use super::types::TokenUsage;
+
+/// Codex nests rollouts under `YYYY/MM/DD`; don't descend further than that.
+const CODEX_MAX_DEPTH: usize = 4;
+
+/// Per-model accumulator: tokens plus the agent-recorded cost, if any.
+pub(crate) type UsageByModel = BTreeMap<String, (TokenUsage, Option<f64>)>;
+
+/// Root directories where agents keep their usage data.
+///
+/// A `None` root disables that source.
+#[derive(Debug, Clone, Default)]
+pub struct UsageSources {
+    pub claude_dir: Option<PathBuf>,
+    pub codex_dir: Option<PathBuf>,
+    pub opencode_dir: Option<PathBuf>,
+}
+
+impl UsageSources {
+    /// Resolve the default data locations, honouring `CLAUDE_CONFIG_DIR`,
+    /// `CODEX_HOME` and `XDG_DATA_HOME`.
+    pub fn detect() -> Self {
+        let home = dirs::home_dir();
+        let claude_dir = std::env::var_os("CLAUDE_CONFIG_DIR")
+            .map(PathBuf::from)
+            .or_else(|| home.as_ref().map(|h| h.join(".claude")));
+        let codex_dir = std::env::var_os("CODEX_HOME")
+            .map(PathBuf::from)
+            .or_else(|| home.as_ref().map(|h| h.join(".codex")));
+        let opencode_dir = std::env::var_os("XDG_DATA_HOME")
+            .map(PathBuf::from)
+            .or_else(|| home.as_ref().map(|h| h.join(".local").join("share")))
+            .map(|d| d.join("opencode"));
+        Self {
+            claude_dir,
+            codex_dir,
+            opencode_dir,
+        }
+    }
+}
+
+/// Index of path-keyed usage sources, built once per collection pass.
+///
+/// Codex and OpenCode don't know kild's session IDs, so their records are
+/// matched to kilds by working directory. Scanning their stores is the
+/// expensive part, so it happens once and is reused for every kild.
+#[derive(Debug, Default)]
+pub(crate) struct UsageIndex {
+    claude_dir: Option<PathBuf>,
+    codex_rollouts: Vec<(PathBuf, PathBuf)>,
+    opencode_dir: Option<PathBuf>,
+    opencode_sessions: Vec<(PathBuf, String)>,
+}
+
+impl UsageIndex {
+    pub(crate) fn build(sources: &UsageSources) -> Self {
+        let codex_rollouts = sources
+            .codex_dir
+            .as_deref()
+            .map(|dir| index_codex_rollouts(&dir.join("sessions")))
+            .unwrap_or_default();
+        let opencode_sessions = sources
+            .opencode_dir
+            .as_deref()
+            .map(index_opencode_sessions)
+            .unwrap_or_default();
+        debug!(
+            event = "core.usage.index_completed",
+            codex_rollouts = codex_rollouts.len(),
+            opencode_sessions = opencode_sessions.len()
+        );
+        Self {
+            claude_dir: sources.claude_dir.clone(),
+            codex_rollouts,
+            opencode_dir: sources.opencode_dir.clone(),
+            opencode_sessions,
+        }
+    }
+
+    /// Collect usage for one kild from every source.
+    ///
+    /// `worktree` is `None` for sessions without an isolated worktree, which
+    /// disables path matching so project-root agent runs aren't misattributed.
+    pub(crate) fn collect(&self, session_ids: &[&str], worktree: Option<&Path>) -> UsageByModel {
+        let mut acc = UsageByModel::new();
+
+        if let Some(dir) = &self.claude_dir {
+            for id in session_ids {
+                if let Some(path) = find_claude_transcript(dir, id) {
+                    add_claude_transcript(&path, &mut acc);
+                }
+            }
+        }
+
+        let Some(worktree) = worktree else {
+            return acc;
+        };
+
+        for (cwd, rollout) in &self.codex_rollouts {
+            if cwd.starts_with(worktree)
+                && let Some((model, tokens)) = parse_codex_rollout(rollout)
+            {
+                add(&mut acc, model, &tokens, None);
+            }
+        }
+
+        if let Some(dir) = &self.opencode_dir {
+            for (directory, id) in &self.opencode_sessions {
+                if directory.starts_with(worktree) {
+                    add_opencode_messages(&dir.join("storage").join("message").join(id), &mut acc);
+                }
+            }
+        }
+
+        acc
+    }
+}
+
+fn add(acc: &mut UsageByModel, model: String, tokens: &TokenUsage, cost: Option<f64>) {
+    let entry = acc.entry(model).or_default();
+    entry.0.add(tokens);
+    if let Some(cost) = cost {
+        *entry.1.get_or_insert(0.0) += cost;
+    }
+}
+
+fn u64_at(value: &Value, key: &str) -> u64 {
+    value.get(key).and_then(Value::as_u64).unwrap_or(0)
+}
+
+fn read_lines(path: &Path) -> Option<impl Iterator<Item = Value>> {
+    match fs::File::open(path) {
+        Ok(file) => Some(
+            BufReader::new(file)
+                .lines()
+                .map_while(Result::ok)
+                .filter_map(|line| serde_json::from_str::<Value>(&line).ok()),
+        ),
+        Err(e) => {
+            warn!(
+                event = "core.usage.transcript_read_failed",
+                path = %path.display(),
+                error = %e
+            );
+            None
+        }
+    }
+}
+
+// --- Claude Code ---
+
+/// Find `<session_id>.jsonl` under any project directory.
+fn find_claude_transcript(claude_dir: &Path, session_id: &str) -> Option<PathBuf> {
+    let file_name = format!("{}.jsonl", session_id);
+    fs::read_dir(claude_dir.join("projects"))
+        .ok()?
+        .flatten()
+        .map(|entry| entry.path().join(&file_name))
+        .find(|path| path.is_file())
+}
+
+/// Add a Claude transcript's usage to `acc`.
+///
+/// Claude writes one line per content block, each repeating the message's
+/// usage, so lines are de-duplicated by `message.id` (last one wins, since
+/// streamed lines can carry partial output counts).
+pub(crate) fn add_claude_transcript(path: &Path, acc: &mut UsageByModel) {
+    let Some(lines) = read_lines(path) else {
+        return;
+    };
+
+    let mut by_message: HashMap<String, (String, TokenUsage)> = HashMap::new();
+    let mut anonymous: Vec<(String, TokenUsage)> = Vec::new();
+    for line in lines {
+        if line.get("type").and_then(Value::as_str) != Some("assistant") {
+            continue;
+        }
+        let Some(message) = line.get("message") else {
+            continue;
+        };
+        let Some(usage) = message.get("usage") else {
+            continue;
+        };
+        let model = message
+            .get("model")
+            .and_then(Value::as_str)
+            .unwrap_or("unknown")
+            .to_string();
+        // Synthetic messages (e.g. interrupted turns) carry zero usage.
+        if model == "<synthetic>" {
+            continue;
+        }
+        let tokens = TokenUsage {
+            input_tokens: u64_at(usage, "input_tokens"),
+            output_tokens: u64_at(usage, "output_tokens"),
+            cache_read_tokens: u64_at(usage, "cache_read_input_tokens"),
+            cache_write_tokens: u64_at(usage, "cache_creation_input_tokens"),
+        };
+        match message.get("id").and_then(Value::as_str) {
+            Some(id) => {
+                by_message.insert(id.to_string(), (model, tokens));
+            }
+            None => anonymous.push((model, tokens)),
+        }
+    }
+
+    for (model, tokens) in by_message.into_values().chain(anonymous) {
+        add(acc, model, &tokens, None);
+    }
+}
+
+// --- Codex ---
+
+fn index_codex_rollouts(sessions_dir: &Path) -> Vec<(PathBuf, PathBuf)> {
+    let mut files = Vec::new();
+    collect_files(sessions_dir, CODEX_MAX_DEPTH, &mut files, |path| {
+        path.extension().is_some_and(|ext| ext == "jsonl")
+    });
+    files
+        .into_iter()
+        .filter_map(|path| codex_rollout_cwd(&path).map(|cwd| (cwd, path)))
+        .collect()
+}
+
+fn collect_files(dir: &Path, depth: usize, out: &mut Vec<PathBuf>, keep: fn(&Path) -> bool) {
+    let Ok(entries) = fs::read_dir(dir) else {
+        return;
+    };
+    for entry in entries.flatten() {
+        let path = entry.path();
+        if path.is_dir() {
+            if depth > 0 {
+                collect_files(&path, depth - 1, out, keep);
+            }
+        } else if keep(&path) {
+            out.push(path);
+        }
+    }
+}
+
+/// Read only the `session_meta` header to find the rollout's working directory.
+fn codex_rollout_cwd(path: &Path) -> Option<PathBuf> {
+    let first = read_lines(path)?.next()?;
+    if first.get("type").and_then(Value::as_str) != Some("session_meta") {
+        return None;
+    }
+    first
+        .get("payload")?
+        .get("cwd")?
+        .as_str()
+        .map(PathBuf::from)
+}
+
+/// Parse a Codex rollout, returning the model and final cumulative usage.
+///
+/// Codex reports `input_tokens` inclusive of `cached_input_tokens`; they are
+/// split so cached reads can be priced separately.
+pub(crate) fn parse_codex_rollout(path: &Path) -> Option<(String, TokenUsage)> {
+    let mut model: Option<String> = None;
+    let mut totals: Option<TokenUsage> = None;
+
+    for line in read_lines(path)? {
+        let Some(payload) = line.get("payload") else {
+            continue;
+        };
+        match line.get("type").and_then(Value::as_str) {
+            Some("turn_context") => {
+                if let Some(m) = payload.get("model").and_then(Value::as_str) {
+                    model = Some(m.to_string());
+                }
+            }
+            Some("event_msg")
+                if payload.get("type").and_then(Value::as_str) == Some("token_count") =>
+            {
+                if let Some(total) = payload
+                    .get("info")
+                    .and_then(|info| info.get("total_token_usage"))
+                {
+                    let input = u64_at(total, "input_tokens");
+                    let cached = u64_at(total, "cached_input_tokens");
+                    totals = Some(TokenUsage {
+                        input_tokens: input.saturating_sub(cached),
+                        output_tokens: u64_at(total, "output_tokens"),
+                        cache_read_tokens: cached,
+                        cache_write_tokens: 0,
+                    });
+                }
+            }
+            _ => {}
+        }
+    }
+
+    let totals = totals?;
+    Some((model.unwrap_or_else(|| "codex".to_string()), totals))
+}
+
+// --- OpenCode ---
+
+fn read_json(path: &Path) -> Option<Value> {
+    let content = fs::read_to_string(path).ok()?;
+    serde_json::from_str(&content).ok()
+}
+
+fn index_opencode_sessions(opencode_dir: &Path) -> Vec<(PathBuf, String)> {
+    let mut files = Vec::new();
+    collect_files(
+        &opencode_dir.join("storage").join("session"),
+        1,
+        &mut files,
+        |path| path.extension().is_some_and(|ext| ext == "json"),
+    );
+    files
+        .iter()
+        .filter_map(|path| {
+            let session = read_json(path)?;
+            let id = session.get("id")?.as_str()?.to_string();
+            let directory = PathBuf::from(session.get("directory")?.as_str()?);
+            Some((directory, id))
+        })
+        .collect()
+}
+
+/// Add every assistant message in an OpenCode session's message directory.
+pub(crate) fn add_opencode_messages(message_dir: &Path, acc: &mut UsageByModel) {
+    let Ok(entries) = fs::read_dir(message_dir) else {
+        return;
+    };
+    for entry in entries.flatten() {
+        let Some(message) = read_json(&entry.path()) else {
+            continue;
+        };
+        if message.get("role").and_then(Value::as_str) != Some("assistant") {
+            continue;
+        }
+        let Some(tokens) = message.get("tokens") else {
+            continue;
+        };
+        let cache = tokens.get("cache");
+        let usage = TokenUsage {
+            input_tokens: u64_at(tokens, "input"),
+            // Reasoning tokens are billed as output.
+            output_tokens: u64_at(tokens, "output") + u64_at(tokens, "reasoning"),
+            cache_read_tokens: cache.map_or(0, |c| u64_at(c, "read")),
+            cache_write_tokens: cache.map_or(0, |c| u64_at(c, "write")),
+        };
+        let model = message
+            .get("modelID")
+            .and_then(Value::as_str)
+            .unwrap_or("unknown")
+            .to_string();
+        let cost = message.get("cost").and_then(Value::as_f64);
+        add(acc, model, &usage, cost);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+
+    fn write(path: &Path, content: &str) {
+        fs::create_dir_all(path.parent().unwrap()).unwrap();
+        fs::write(path, content).unwrap();
+    }
+
+    const CLAUDE_TRANSCRIPT: &str = r#"{"type":"user","message":{"role":"user","content":"hi"}}
+{"type":"assistant","message":{"id":"msg_1","model":"claude-sonnet-4-5","usage":{"input_tokens":10,"output_tokens":1,"cache_read_input_tokens":100,"cache_creation_input_tokens":50}}}
+{"type":"assistant","message":{"id":"msg_1","model":"claude-sonnet-4-5","usage":{"input_tokens":10,"output_tokens":20,"cache_read_input_tokens":100,"cache_creation_input_tokens":50}}}
+not json
+{"type":"assistant","message":{"id":"msg_2","model":"claude-opus-4-1","usage":{"input_tokens":5,"output_tokens":7}}}
+{"type":"assistant","message":{"id":"msg_3","model":"<synthetic>","usage":{"input_tokens":0,"output_tokens":0}}}
+"#;
+
+    #[test]
+    fn test_claude_transcript_dedupes_by_message_id() {
+        let tmp = TempDir::new().unwrap();
+        let path = tmp.path().join("t.jsonl");
+        write(&path, CLAUDE_TRANSCRIPT);
+
+        let mut acc = UsageByModel::new();
+        add_claude_transcript(&path, &mut acc);
+
+        let (sonnet, _) = acc["claude-sonnet-4-5"];
+        assert_eq!(sonnet.input_tokens, 10);
+        assert_eq!(sonnet.output_tokens, 20);
+        assert_eq!(sonnet.cache_read_tokens, 100);
+        assert_eq!(sonnet.cache_write_tokens, 50);
+        assert_eq!(acc["claude-opus-4-1"].0.output_tokens, 7);
+        assert!(!acc.contains_key("<synthetic>"));
+    }
+
+    #[test]
+    fn test_find_claude_transcript_searches_project_dirs() {
+        let tmp = TempDir::new().unwrap();
+        let path = tmp.path().join("projects/-some-worktree/abc.jsonl");
+        write(&path, CLAUDE_TRANSCRIPT);
+
+        assert_eq!(find_claude_transcript(tmp.path(), "abc"), Some(path));
+        assert_eq!(find_claude_transcript(tmp.path(), "missing"), None);
+    }
+
+    #[test]
+    fn test_codex_rollout_uses_last_token_count_and_splits_cache() {
+        let tmp = TempDir::new().unwrap();
+        let path = tmp.path().join("rollout.jsonl");
+        write(
+            &path,
+            r#"{"type":"session_meta","payload":{"id":"x","cwd":"/wt/feat"}}
+{"type":"turn_context","payload":{"cwd":"/wt/feat","model":"gpt-5-codex"}}
+{"type":"event_msg","payload":{"type":"token_count","info":null}}
+{"type":"event_msg","payload":{"type":"token_count","info":{"total_token_usage":{"input_tokens":100,"cached_input_tokens":40,"output_tokens":10}}}}
+{"type":"event_msg","payload":{"type":"token_count","info":{"total_token_usage":{"input_tokens":300,"cached_input_tokens":200,"output_tokens":30}}}}
+"#,
+        );
+
+        assert_eq!(codex_rollout_cwd(&path), Some(PathBuf::from("/wt/feat")));
+        let (model, tokens) = parse_codex_rollout(&path).unwrap();
+        assert_eq!(model, "gpt-5-codex");
+        assert_eq!(tokens.input_tokens, 100);
+        assert_eq!(tokens.cache_read_tokens, 200);
+        assert_eq!(tokens.output_tokens, 30);
+    }
+
+    #[test]
+    fn test_codex_rollout_without_token_count_is_none() {
+        let tmp = TempDir::new().unwrap();
+        let path = tmp.path().join("rollout.jsonl");
+        write(
+            &path,
+            r#"{"type":"session_meta","payload":{"id":"x","cwd":"/wt/feat"}}
+"#,
+        );
+        assert!(parse_codex_rollout(&path).is_none());
+    }
+
+    #[test]
+    fn test_opencode_messages_sum_tokens_and_recorded_cost() {
+        let tmp = TempDir::new().unwrap();
+        let dir = tmp.path().join("ses_1");
+        write(
+            &dir.join("msg_1.json"),
+            r#"{"role":"assistant","modelID":"kimi-k2","cost":0.25,"tokens":{"input":10,"output":5,"reasoning":2,"cache":{"read":3,"write":4}}}"#,
+        );
+        write(
+            &dir.join("msg_2.json"),
+            r#"{"role":"assistant","modelID":"kimi-k2","cost":0.5,"tokens":{"input":1,"output":1,"cache":{"read":0,"write":0}}}"#,
+        );
+        write(&dir.join("msg_0.json"), r#"{"role":"user"}"#);
+
+        let mut acc = UsageByModel::new();
+        add_opencode_messages(&dir, &mut acc);
+
+        let (tokens, cost) = acc["kimi-k2"];
+        assert_eq!(tokens.input_tokens, 11);
+        assert_eq!(tokens.output_tokens, 8);
+        assert_eq!(tokens.cache_read_tokens, 3);
+        assert_eq!(tokens.cache_write_tokens, 4);
+        assert_eq!(cost, Some(0.75));
+    }
+
+    #[test]
+    fn test_index_collects_by_session_id_and_worktree() {
+        let tmp = TempDir::new().unwrap();
+        let claude = tmp.path().join("claude");
+        let codex = tmp.path().join("codex");
+        let opencode = tmp.path().join("opencode");
+
+        write(&claude.join("projects/p/old.jsonl"), CLAUDE_TRANSCRIPT);
+        write(
+            &codex.join("sessions/2026/01/02/rollout-a.jsonl"),
+            r#"{"type":"session_meta","payload":{"cwd":"/wt/feat/sub"}}
+{"type":"event_msg","payload":{"type":"token_count","info":{"total_token_usage":{"input_tokens":9,"output_tokens":1}}}}
+"#,
+        );
+        write(
+            &codex.join("sessions/2026/01/02/rollout-b.jsonl"),
+            r#"{"type":"session_meta","payload":{"cwd":"/wt/other"}}
+{"type":"event_msg","payload":{"type":"token_count","info":{"total_token_usage":{"input_tokens":999,"output_tokens":1}}}}
+"#,
+        );
+        write(
+            &opencode.join("storage/session/proj/ses_1.json"),
+            r#"{"id":"ses_1","directory":"/wt/feat"}"#,
+        );
+        write(
+            &opencode.join("storage/message/ses_1/msg_1.json"),
+            r#"{"role":"assistant","modelID":"kimi-k2","tokens":{"input":4,"output":4}}"#,
+        );
+
+        let index = UsageIndex::build(&UsageSources {
+            claude_dir: Some(claude),
+            codex_dir: Some(codex),
+            opencode_dir: Some(opencode),
+        });
+        let acc = index.collect(&["old", "new"], Some(Path::new("/wt/feat")));
+
+        let main_only = index.collect(&["old"], None);
+        assert!(main_only.contains_key("claude-sonnet-4-5"));
+        assert!(!main_only.contains_key("codex"));
+
+        assert_eq!(acc["claude-sonnet-4-5"].0.output_tokens, 20);
+        assert_eq!(acc["codex"].0.input_tokens, 9);
+        assert_eq!(acc["kimi-k2"].0.input_tokens, 4);
+    }
+}
@@ -0,0 +1,198 @@
+use std::collections::BTreeMap;
+
+use kild_config::UsageConfig;
+use tracing::info;
+
+use super::collectors::{UsageByModel, UsageIndex, UsageSources};
+use super::types::{KildUsage, ModelUsage, ProjectUsage};
+use crate::sessions::types::Session;
+
+/// Collect token usage for every session from the default agent data locations.
+pub fn collect_usage(sessions: &[Session], config: &UsageConfig) -> Vec<KildUsage> {
+    collect_usage_from(&UsageSources::detect(), sessions, config)
+}
+
+/// Collect token usage for a single session.
+pub fn collect_usage_for_session(session: &Session, config: &UsageConfig) -> KildUsage {
+    collect_usage(std::slice::from_ref(session), config)
+        .pop()
+        .expect("one usage entry per session")
+}
+
+/// Collect token usage for every session from explicit data locations.
+pub fn collect_usage_from(
+    sources: &UsageSources,
+    sessions: &[Session],
+    config: &UsageConfig,
+) -> Vec<KildUsage> {
+    info!(event = "core.usage.collect_started", count = sessions.len());
+
+    let index = UsageIndex::build(sources);
+    let usages: Vec<KildUsage> = sessions
+        .iter()
+        .map(|session| {
+            let mut ids: Vec<&str> = session
+                .agent_session_id_history
+                .iter()
+                .map(String::as_str)
+                .collect();
+            ids.extend(session.agent_session_id.as_deref());
+            let worktree = (!session.use_main_worktree).then_some(session.worktree_path.as_path());
+
+            KildUsage::from_models(
+                session.branch.to_string(),
+                session.project_id.to_string(),
+                session.agent.clone(),
+                price_models(index.collect(&ids, worktree), config),
+            )
+        })
+        .collect();
+
+    info!(
+        event = "core.usage.collect_completed",
+        count = usages.len(),
+        with_usage = usages.iter().filter(|u| !u.tokens.is_empty()).count()
+    );
+    usages
+}
+
+/// Attach cost estimates. A configured price takes precedence over the
+/// agent-recorded cost so all agents are priced consistently.
+fn price_models(acc: UsageByModel, config: &UsageConfig) -> Vec<ModelUsage> {
+    acc.into_iter()
+        .map(|(model, (tokens, recorded_cost))| {
+            let cost_usd = config
+                .price_for(&model)
+                .map(|price| tokens.cost_usd(price))
+                .or(recorded_cost);
+            ModelUsage {
+                model,
+                tokens,
+                cost_usd,
+            }
+        })
+        .collect()
+}
+
+/// Sum kild usage per project, ordered by project ID.
+pub fn aggregate_by_project(usages: &[KildUsage]) -> Vec<ProjectUsage> {
+    let mut by_project: BTreeMap<&str, ProjectUsage> = BTreeMap::new();
+    for usage in usages {
+        let entry = by_project
+            .entry(usage.project_id.as_str())
+            .or_insert_with(|| ProjectUsage {
+                project_id: usage.project_id.clone(),
+                kild_count: 0,
+                tokens: Default::default(),
+                cost_usd: None,
+            });
+        entry.kild_count += 1;
+        entry.tokens.add(&usage.tokens);
+        if let Some(cost) = usage.cost_usd {
+            *entry.cost_usd.get_or_insert(0.0) += cost;
+        }
+    }
+    by_project.into_values().collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::usage::types::TokenUsage;
+    use kild_config::ModelPrice;
+    use std::collections::HashMap;
+    use tempfile::TempDir;
+
+    fn kild(project: &str, input: u64, cost: Option<f64>) -> KildUsage {
+        KildUsage::from_models(
+            "b".to_string(),
+            project.to_string(),
+            "claude".to_string(),
+            vec![ModelUsage {
+                model: "m".to_string(),
+                tokens: TokenUsage {
+                    input_tokens: input,
+                    ..Default::default()
+                },
+                cost_usd: cost,
+            }],
+        )
+    }
+
+    #[test]
+    fn test_aggregate_by_project() {
+        let projects = aggregate_by_project(&[
+            kild("p2", 1, None),
+            kild("p1", 10, Some(1.0)),
+            kild("p1", 5, Some(0.5)),
+        ]);
+        assert_eq!(projects.len(), 2);
+        assert_eq!(projects[0].project_id, "p1");
+        assert_eq!(projects[0].kild_count, 2);
+        assert_eq!(projects[0].tokens.input_tokens, 15);
+        assert_eq!(projects[0].cost_usd, Some(1.5));
+        assert_eq!(projects[1].cost_usd, None);
+    }
+
+    #[test]
+    fn test_price_models_prefers_configured_price_over_recorded() {
+        let mut acc = UsageByModel::new();
+        let tokens = TokenUsage {
+            input_tokens: 1_000_000,
+            ..Default::default()
+        };
+        acc.insert("priced".to_string(), (tokens, Some(99.0)));
+        acc.insert("recorded".to_string(), (tokens, Some(0.25)));
+        acc.insert("unknown".to_string(), (tokens, None));
+
+        let mut prices = HashMap::new();
+        prices.insert(
+            "priced".to_string(),
+            ModelPrice {
+                input_per_mtok: 2.0,
+                output_per_mtok: 8.0,
+                cache_read_per_mtok: None,
+                cache_write_per_mtok: None,
+            },
+        );
+        let models = price_models(acc, &UsageConfig { prices });
+
+        assert_eq!(models[0].cost_usd, Some(2.0));
+        assert_eq!(models[1].cost_usd, Some(0.25));
+        assert_eq!(models[2].cost_usd, None);
+    }
+
+    #[test]
+    fn test_collect_usage_from_uses_session_id_history() {
+        let tmp = TempDir::new().unwrap();
+        let transcript = |id: &str, output: u64| {
+            let path = tmp.path().join(format!("projects/p/{}.jsonl", id));
+            std::fs::create_dir_all(path.parent().unwrap()).unwrap();
+            std::fs::write(
+                path,
+                format!(
+                    r#"{{"type":"assistant","message":{{"id":"{id}","model":"claude-x","usage":{{"input_tokens":1,"output_tokens":{output}}}}}}}"#
+                ),
+            )
+            .unwrap();
+        };
+        transcript("old", 3);
+        transcript("new", 4);
+
+        let mut session = Session::new_for_test("feat", tmp.path().join("wt"));
+        session.agent_session_id = Some("new".to_string());
+        session.agent_session_id_history = vec!["old".to_string()];
+
+        let sources = UsageSources {
+            claude_dir: Some(tmp.path().to_path_buf()),
+            ..Default::default()
+        };
+        let usages = collect_usage_from(&sources, &[session], &UsageConfig::default());
+
+        assert_eq!(usages.len(), 1);
+        assert_eq!(usages[0].branch, "feat");
+        assert_eq!(usages[0].tokens.input_tokens, 2);
+        assert_eq!(usages[0].tokens.output_tokens, 7);
+        assert_eq!(usages[0].cost_usd, None);
+    }
+}
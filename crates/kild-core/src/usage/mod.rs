@@ -0,0 +1,15 @@
+//! Token usage and cost accounting per kild
+//!
+//! Parses agent-native usage records (Claude Code transcripts, Codex rollout
+//! logs, OpenCode storage) and attributes them to kilds, either by agent
+//! session ID or by worktree path.
+
+pub mod collectors;
+pub mod handler;
+pub mod types;
+
+pub use collectors::UsageSources;
+pub use handler::{
+    aggregate_by_project, collect_usage, collect_usage_for_session, collect_usage_from,
+};
+pub use types::{KildUsage, ModelUsage, ProjectUsage, TokenUsage, format_cost, format_tokens};
@@ -0,0 +1,203 @@
+use kild_config::ModelPrice;
+use serde::{Deserialize, Serialize};
+
+/// Token counts for one model or one kild.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct TokenUsage {
+    /// Uncached input tokens.
+    pub input_tokens: u64,
+    pub output_tokens: u64,
+    pub cache_read_tokens: u64,
+    pub cache_write_tokens: u64,
+}
+
+impl TokenUsage {
+    pub fn add(&mut self, other: &TokenUsage) {
+        self.input_tokens += other.input_tokens;
+        self.output_tokens += other.output_tokens;
+        self.cache_read_tokens += other.cache_read_tokens;
+        self.cache_write_tokens += other.cache_write_tokens;
+    }
+
+    /// All tokens processed, including cache reads and writes.
+    pub fn total(&self) -> u64 {
+        self.input_tokens + self.output_tokens + self.cache_read_tokens + self.cache_write_tokens
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.total() == 0
+    }
+
+    /// Estimated cost in USD under `price`. Cache prices fall back to the input price.
+    pub fn cost_usd(&self, price: &ModelPrice) -> f64 {
+        let cache_read = price.cache_read_per_mtok.unwrap_or(price.input_per_mtok);
+        let cache_write = price.cache_write_per_mtok.unwrap_or(price.input_per_mtok);
+        (self.input_tokens as f64 * price.input_per_mtok
+            + self.output_tokens as f64 * price.output_per_mtok
+            + self.cache_read_tokens as f64 * cache_read
+            + self.cache_write_tokens as f64 * cache_write)
+            / 1_000_000.0
+    }
+}
+
+/// Compact token count for tables and badges: `950`, `12.3k`, `4.1M`.
+pub fn format_tokens(n: u64) -> String {
+    match n {
+        0..1_000 => n.to_string(),
+        1_000..1_000_000 => format!("{:.1}k", n as f64 / 1_000.0),
+        _ => format!("{:.1}M", n as f64 / 1_000_000.0),
+    }
+}
+
+/// Cost in USD with two decimals, or `-` when unknown.
+pub fn format_cost(cost_usd: Option<f64>) -> String {
+    cost_usd.map_or_else(|| "-".to_string(), |c| format!("${:.2}", c))
+}
+
+/// Usage attributed to a single model within a kild.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ModelUsage {
+    pub model: String,
+    #[serde(flatten)]
+    pub tokens: TokenUsage,
+    /// Estimated cost in USD. `None` when no price is configured for the
+    /// model and the agent did not record a cost itself.
+    pub cost_usd: Option<f64>,
+}
+
+/// Usage totals for one kild.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct KildUsage {
+    pub branch: String,
+    pub project_id: String,
+    pub agent: String,
+    #[serde(flatten)]
+    pub tokens: TokenUsage,
+    /// Sum of the priced models. `None` when no model could be priced.
+    pub cost_usd: Option<f64>,
+    pub models: Vec<ModelUsage>,
+}
+
+impl KildUsage {
+    /// Build kild totals from per-model usage.
+    pub fn from_models(
+        branch: String,
+        project_id: String,
+        agent: String,
+        models: Vec<ModelUsage>,
+    ) -> Self {
+        let mut tokens = TokenUsage::default();
+        let mut cost_usd: Option<f64> = None;
+        for m in &models {
+            tokens.add(&m.tokens);
+            if let Some(cost) = m.cost_usd {
+                *cost_usd.get_or_insert(0.0) += cost;
+            }
+        }
+        Self {
+            branch,
+            project_id,
+            agent,
+            tokens,
+            cost_usd,
+            models,
+        }
+    }
+
+    /// Models that have tokens but no cost estimate.
+    pub fn unpriced_models(&self) -> impl Iterator<Item = &str> {
+        self.models
+            .iter()
+            .filter(|m| m.cost_usd.is_none())
+            .map(|m| m.model.as_str())
+    }
+}
+
+/// Usage totals for all kilds of one project.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ProjectUsage {
+    pub project_id: String,
+    pub kild_count: usize,
+    #[serde(flatten)]
+    pub tokens: TokenUsage,
+    pub cost_usd: Option<f64>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn model(name: &str, input: u64, cost: Option<f64>) -> ModelUsage {
+        ModelUsage {
+            model: name.to_string(),
+            tokens: TokenUsage {
+                input_tokens: input,
+                ..Default::default()
+            },
+            cost_usd: cost,
+        }
+    }
+
+    #[test]
+    fn test_cost_usd_uses_cache_prices_with_input_fallback() {
+        let tokens = TokenUsage {
+            input_tokens: 1_000_000,
+            output_tokens: 1_000_000,
+            cache_read_tokens: 1_000_000,
+            cache_write_tokens: 1_000_000,
+        };
+        let price = ModelPrice {
+            input_per_mtok: 3.0,
+            output_per_mtok: 15.0,
+            cache_read_per_mtok: Some(0.3),
+            cache_write_per_mtok: None,
+        };
+        let cost = tokens.cost_usd(&price);
+        assert!((cost - (3.0 + 15.0 + 0.3 + 3.0)).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_kild_usage_from_models_sums_priced_only() {
+        let usage = KildUsage::from_models(
+            "feat".to_string(),
+            "p".to_string(),
+            "claude".to_string(),
+            vec![model("a", 10, Some(1.5)), model("b", 5, None)],
+        );
+        assert_eq!(usage.tokens.input_tokens, 15);
+        assert_eq!(usage.cost_usd, Some(1.5));
+        assert_eq!(usage.unpriced_models().collect::<Vec<_>>(), vec!["b"]);
+    }
+
+    #[test]
+    fn test_kild_usage_from_models_no_prices_is_none() {
+        let usage = KildUsage::from_models(
+            "feat".to_string(),
+            "p".to_string(),
+            "claude".to_string(),
+            vec![model("a", 10, None)],
+        );
+        assert_eq!(usage.cost_usd, None);
+    }
+
+    #[test]
+    fn test_format_tokens() {
+        assert_eq!(format_tokens(0), "0");
+        assert_eq!(format_tokens(999), "999");
+        assert_eq!(format_tokens(12_345), "12.3k");
+        assert_eq!(format_tokens(4_100_000), "4.1M");
+    }
+
+    #[test]
+    fn test_format_cost() {
+        assert_eq!(format_cost(None), "-");
+        assert_eq!(format_cost(Some(1.234)), "$1.23");
+    }
+
+    #[test]
+    fn test_token_usage_serializes_flat_in_model_usage() {
+        let json = serde_json::to_value(model("a", 7, None)).unwrap();
+        assert_eq!(json["input_tokens"], 7);
+        assert_eq!(json["model"], "a");
+    }
+}
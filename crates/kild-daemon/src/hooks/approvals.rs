@@ -0,0 +1,284 @@
+//! In-memory permission broker — queues agent permission requests until a
+//! user approves or denies them via `kild approvals` or the UI.
+//!
+//! Two kinds of requester are supported:
+//! - **Hook**: Claude Code's `PermissionRequest` HTTP hook blocks until we
+//!   respond, so the decision is sent back over a oneshot channel.
+//! - **Pty**: Codex's notify hook is fire-and-forget, so the decision is
+//!   typed into the agent's approval prompt through the session PTY.
+
+use std::sync::{Arc, Mutex};
+
+use kild_protocol::{ApprovalDecision, PendingApproval};
+use tokio::sync::oneshot;
+use tracing::{debug, info};
+
+/// A decision and optional reason, delivered to the waiting requester.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Resolution {
+    pub decision: ApprovalDecision,
+    pub message: Option<String>,
+}
+
+/// How a resolution gets back to the agent.
+#[derive(Debug)]
+pub enum Responder {
+    /// An HTTP hook request is waiting on the receiving end.
+    Hook(oneshot::Sender<Resolution>),
+    /// Type the decision into the daemon PTY session with this ID.
+    Pty { daemon_session_id: String },
+}
+
+/// Details of a permission request as reported by the agent hook.
+#[derive(Debug, Clone)]
+pub struct ApprovalRequest {
+    pub branch: String,
+    pub agent: String,
+    pub tool: String,
+    pub summary: String,
+    pub details: Option<serde_json::Value>,
+}
+
+struct Entry {
+    approval: PendingApproval,
+    responder: Responder,
+}
+
+#[derive(Default)]
+struct Inner {
+    next_id: u64,
+    pending: Vec<Entry>,
+}
+
+/// Queue of pending approvals shared by the hook endpoint and IPC connections.
+///
+/// Uses a std mutex: critical sections are short and never await, and
+/// [`PendingGuard`] needs to remove entries from `Drop`.
+#[derive(Default)]
+pub struct ApprovalBroker {
+    inner: Mutex<Inner>,
+}
+
+impl ApprovalBroker {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Queue a request and return its ID.
+    ///
+    /// A PTY-delivered request replaces any earlier PTY request from the same
+    /// branch: the agent only shows one prompt at a time, and Codex gives no
+    /// signal when a prompt is answered directly in the terminal.
+    pub fn submit(&self, request: ApprovalRequest, responder: Responder) -> String {
+        let mut inner = self.inner.lock().unwrap_or_else(|e| e.into_inner());
+        if matches!(responder, Responder::Pty { .. }) {
+            inner.pending.retain(|e| {
+                !(e.approval.branch == request.branch
+                    && matches!(e.responder, Responder::Pty { .. }))
+            });
+        }
+
+        inner.next_id += 1;
+        let id = format!("a{}", inner.next_id);
+        info!(
+            event = "daemon.approvals.submitted",
+            approval_id = %id,
+            branch = %request.branch,
+            agent = %request.agent,
+            tool = %request.tool,
+        );
+        inner.pending.push(Entry {
+            approval: PendingApproval {
+                id: id.clone(),
+                branch: request.branch,
+                agent: request.agent,
+                tool: request.tool,
+                summary: request.summary,
+                details: request.details,
+                requested_at: chrono::Utc::now().to_rfc3339(),
+            },
+            responder,
+        });
+        id
+    }
+
+    /// Snapshot of pending approvals, oldest first.
+    pub fn list(&self) -> Vec<PendingApproval> {
+        let inner = self.inner.lock().unwrap_or_else(|e| e.into_inner());
+        inner.pending.iter().map(|e| e.approval.clone()).collect()
+    }
+
+    /// Remove a pending approval and hand back its responder for delivery.
+    ///
+    /// Returns `None` if the ID is unknown (already resolved or timed out).
+    pub fn take(&self, id: &str) -> Option<(PendingApproval, Responder)> {
+        let mut inner = self.inner.lock().unwrap_or_else(|e| e.into_inner());
+        let idx = inner.pending.iter().position(|e| e.approval.id == id)?;
+        let entry = inner.pending.remove(idx);
+        Some((entry.approval, entry.responder))
+    }
+
+    /// Drop a pending approval without resolving it.
+    pub fn cancel(&self, id: &str) {
+        if self.take(id).is_some() {
+            debug!(event = "daemon.approvals.cancelled", approval_id = id);
+        }
+    }
+
+    /// Drop PTY-delivered approvals for a branch, e.g. when its agent goes idle
+    /// and the prompt is evidently no longer showing.
+    pub fn clear_pty_for_branch(&self, branch: &str) {
+        let mut inner = self.inner.lock().unwrap_or_else(|e| e.into_inner());
+        inner.pending.retain(|e| {
+            !(e.approval.branch == branch && matches!(e.responder, Responder::Pty { .. }))
+        });
+    }
+}
+
+/// Removes a hook-delivered approval when the waiting request ends, whether it
+/// was resolved, timed out, or the agent dropped the connection.
+pub struct PendingGuard {
+    broker: Arc<ApprovalBroker>,
+    id: String,
+}
+
+impl PendingGuard {
+    pub fn new(broker: Arc<ApprovalBroker>, id: String) -> Self {
+        Self { broker, id }
+    }
+}
+
+impl Drop for PendingGuard {
+    fn drop(&mut self) {
+        self.broker.cancel(&self.id);
+    }
+}
+
+/// Bytes typed into a Codex approval prompt for a decision.
+///
+/// `y` approves. `n` declines; a deny message is then typed as feedback.
+pub fn pty_keystrokes(resolution: &Resolution) -> Vec<u8> {
+    match resolution.decision {
+        ApprovalDecision::Allow => b"y".to_vec(),
+        ApprovalDecision::Deny => match &resolution.message {
+            Some(msg) if !msg.is_empty() => format!("n{}\r", msg).into_bytes(),
+            _ => b"n".to_vec(),
+        },
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn request(branch: &str) -> ApprovalRequest {
+        ApprovalRequest {
+            branch: branch.to_string(),
+            agent: "claude".to_string(),
+            tool: "Bash".to_string(),
+            summary: "cargo test".to_string(),
+            details: None,
+        }
+    }
+
+    fn pty() -> Responder {
+        Responder::Pty {
+            daemon_session_id: "s".to_string(),
+        }
+    }
+
+    #[test]
+    fn submit_list_take() {
+        let broker = ApprovalBroker::new();
+        let (tx, mut rx) = oneshot::channel();
+        let id = broker.submit(request("feat"), Responder::Hook(tx));
+
+        let listed = broker.list();
+        assert_eq!(listed.len(), 1);
+        assert_eq!(listed[0].id, id);
+        assert_eq!(listed[0].summary, "cargo test");
+
+        let (approval, responder) = broker.take(&id).expect("pending");
+        assert_eq!(approval.branch, "feat");
+        let Responder::Hook(tx) = responder else {
+            panic!("expected hook responder");
+        };
+        tx.send(Resolution {
+            decision: ApprovalDecision::Allow,
+            message: None,
+        })
+        .unwrap();
+        assert_eq!(rx.try_recv().unwrap().decision, ApprovalDecision::Allow);
+
+        assert!(broker.list().is_empty());
+        assert!(broker.take(&id).is_none());
+    }
+
+    #[test]
+    fn ids_are_unique() {
+        let broker = ApprovalBroker::new();
+        let a = broker.submit(request("a"), pty());
+        let b = broker.submit(request("b"), pty());
+        assert_ne!(a, b);
+    }
+
+    #[test]
+    fn pty_request_replaces_previous_for_same_branch() {
+        let broker = ApprovalBroker::new();
+        let (tx, _rx) = oneshot::channel();
+        broker.submit(request("feat"), Responder::Hook(tx));
+        broker.submit(request("feat"), pty());
+        let latest = broker.submit(request("feat"), pty());
+        broker.submit(request("other"), pty());
+
+        let ids: Vec<String> = broker
+            .list()
+            .into_iter()
+            .filter(|a| a.branch == "feat")
+            .map(|a| a.id)
+            .collect();
+        assert_eq!(ids.len(), 2, "hook entry kept, older pty entry replaced");
+        assert!(ids.contains(&latest));
+    }
+
+    #[test]
+    fn guard_removes_entry_on_drop() {
+        let broker = Arc::new(ApprovalBroker::new());
+        let (tx, _rx) = oneshot::channel();
+        let id = broker.submit(request("feat"), Responder::Hook(tx));
+        {
+            let _guard = PendingGuard::new(broker.clone(), id);
+            assert_eq!(broker.list().len(), 1);
+        }
+        assert!(broker.list().is_empty());
+    }
+
+    #[test]
+    fn clear_pty_for_branch_keeps_hook_entries() {
+        let broker = ApprovalBroker::new();
+        let (tx, _rx) = oneshot::channel();
+        broker.submit(request("feat"), Responder::Hook(tx));
+        broker.submit(request("feat"), pty());
+        broker.clear_pty_for_branch("feat");
+        assert_eq!(broker.list().len(), 1);
+    }
+
+    #[test]
+    fn pty_keystrokes_for_decisions() {
+        let allow = Resolution {
+            decision: ApprovalDecision::Allow,
+            message: Some("ignored".to_string()),
+        };
+        assert_eq!(pty_keystrokes(&allow), b"y");
+        let deny = Resolution {
+            decision: ApprovalDecision::Deny,
+            message: None,
+        };
+        assert_eq!(pty_keystrokes(&deny), b"n");
+        let deny_msg = Resolution {
+            decision: ApprovalDecision::Deny,
+            message: Some("use cargo nextest".to_string()),
+        };
+        assert_eq!(pty_keystrokes(&deny_msg), b"nuse cargo nextest\r");
+    }
+}
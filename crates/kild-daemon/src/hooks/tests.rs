@@ -8,6 +8,18 @@ mod tests {
             session_id: branch.map(|b| b.to_string()),
             transcript_summary: summary.map(|s| s.to_string()),
             stop_hook_active: None,
+            tool_name: None,
+            tool_input: None,
+            cwd: None,
+            codex_event: None,
+        }
+    }
+
+    fn tool_payload(event: &str, tool: &str, input: serde_json::Value) -> HookPayload {
+        HookPayload {
+            tool_name: Some(tool.to_string()),
+            tool_input: Some(input),
+            ..make_payload(event, Some("worker-a"), None)
         }
     }
 
@@ -82,4 +94,137 @@ mod tests {
             "Stop events should not block via HTTP"
         );
     }
+
+    #[test]
+    fn permission_request_raises_queued_check() {
+        let payload = tool_payload(
+            "PermissionRequest",
+            "Bash",
+            serde_json::json!({"command": "cargo test"}),
+        );
+        let result = process_hook(&payload, false);
+        assert_eq!(result.agent_status, Some(kild_core::AgentStatus::Waiting));
+        let check = result.approval.expect("should raise approval check");
+        assert_eq!(check.kind, ApprovalKind::PermissionRequest);
+        assert_eq!(check.request.tool, "Bash");
+        assert_eq!(check.request.summary, "cargo test");
+        assert_eq!(check.request.agent, "claude");
+    }
+
+    #[test]
+    fn pre_tool_use_does_not_set_waiting() {
+        let payload = tool_payload(
+            "PreToolUse",
+            "Edit",
+            serde_json::json!({"file_path": "src/lib.rs"}),
+        );
+        let result = process_hook(&payload, false);
+        assert!(result.agent_status.is_none());
+        let check = result.approval.expect("should raise approval check");
+        assert_eq!(check.kind, ApprovalKind::PreToolUse);
+        assert_eq!(check.request.summary, "src/lib.rs");
+    }
+
+    #[test]
+    fn permission_request_without_branch_is_ignored() {
+        let mut payload = tool_payload("PermissionRequest", "Bash", serde_json::json!({}));
+        payload.session_id = None;
+        let result = process_hook(&payload, false);
+        assert!(result.approval.is_none());
+        assert!(result.agent_status.is_none());
+    }
+
+    #[test]
+    fn codex_approval_uses_command_array() {
+        let payload = HookPayload {
+            codex_event: Some(serde_json::json!({
+                "type": "approval-requested",
+                "command": ["git", "push"]
+            })),
+            ..make_payload("CodexApprovalRequested", Some("worker-a"), None)
+        };
+        let check = process_hook(&payload, false).approval.unwrap();
+        assert_eq!(check.kind, ApprovalKind::Codex);
+        assert_eq!(check.request.agent, "codex");
+        assert_eq!(check.request.tool, "Bash");
+        assert_eq!(check.request.summary, "git push");
+    }
+
+    #[test]
+    fn permission_request_response_format() {
+        let resolution = approvals::Resolution {
+            decision: kild_protocol::ApprovalDecision::Deny,
+            message: Some("no".to_string()),
+        };
+        let json =
+            serde_json::to_value(ApprovalKind::PermissionRequest.response(&resolution)).unwrap();
+        assert_eq!(
+            json["hookSpecificOutput"]["hookEventName"],
+            "PermissionRequest"
+        );
+        assert_eq!(json["hookSpecificOutput"]["decision"]["behavior"], "deny");
+        assert_eq!(json["hookSpecificOutput"]["decision"]["message"], "no");
+        assert!(json.get("decision").is_none());
+    }
+
+    #[test]
+    fn pre_tool_use_response_format() {
+        let resolution = approvals::Resolution {
+            decision: kild_protocol::ApprovalDecision::Allow,
+            message: Some("rule".to_string()),
+        };
+        let json = serde_json::to_value(ApprovalKind::PreToolUse.response(&resolution)).unwrap();
+        assert_eq!(json["hookSpecificOutput"]["permissionDecision"], "allow");
+        assert_eq!(
+            json["hookSpecificOutput"]["permissionDecisionReason"],
+            "rule"
+        );
+    }
+
+    #[test]
+    fn empty_response_serializes_to_empty_object() {
+        let json = serde_json::to_string(&HookResponse::none()).unwrap();
+        assert_eq!(json, "{}");
+    }
+
+    #[test]
+    fn approval_rules_come_from_main_checkout_not_worktree() {
+        let git = |dir: &std::path::Path, args: &[&str]| {
+            let status = std::process::Command::new("git")
+                .current_dir(dir)
+                .args(["-c", "user.name=Test", "-c", "user.email=test@test.com"])
+                .args(args)
+                .status()
+                .unwrap();
+            assert!(status.success(), "git {:?} failed", args);
+        };
+        let dir = tempfile::tempdir().unwrap();
+        let main = dir.path().join("main");
+        std::fs::create_dir_all(&main).unwrap();
+        git(&main, &["init", "-q"]);
+        git(&main, &["commit", "-q", "--allow-empty", "-m", "init"]);
+        let worktree = dir.path().join("worker");
+        git(
+            &main,
+            &[
+                "worktree",
+                "add",
+                "-q",
+                "-b",
+                "kild/worker",
+                worktree.to_str().unwrap(),
+            ],
+        );
+        // The agent writes itself a blanket allow rule in its worktree.
+        std::fs::create_dir_all(worktree.join(".kild")).unwrap();
+        std::fs::write(
+            worktree.join(".kild/config.toml"),
+            "[approvals]\nallow = [\"Bash(*)\"]\n",
+        )
+        .unwrap();
+
+        let root = project_root(&worktree, false).unwrap();
+        assert_eq!(root.canonicalize().unwrap(), main.canonicalize().unwrap());
+        assert_eq!(project_root(&main, true).unwrap(), main);
+    }
 }
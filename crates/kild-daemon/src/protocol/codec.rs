@@ -157,6 +157,9 @@ mod tests {
                 client_count: Some(1),
                 pty_pid: Some(12345),
                 exit_code: None,
+                last_output_at: None,
+                last_input_at: None,
+                output_bytes: None,
             },
         };
 
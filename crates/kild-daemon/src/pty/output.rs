@@ -1,5 +1,6 @@
 use std::collections::VecDeque;
 use std::io::Read;
+use std::sync::atomic::{AtomicI64, AtomicU64, Ordering};
 use std::sync::{Arc, RwLock};
 
 use bytes::Bytes;
@@ -61,6 +62,60 @@ impl ScrollbackBuffer {
     }
 }
 
+/// Last-output and last-input timestamps for a PTY session, plus a total
+/// output byte count.
+///
+/// Updated lock-free from the PTY reader task (output) and the stdin write
+/// path (input), so health checks can tell a busy agent from one that is
+/// sitting at its prompt waiting for the user. The byte count keeps growing
+/// after the scrollback buffer reaches capacity.
+#[derive(Debug, Default)]
+pub struct PtyActivity {
+    // Unix epoch milliseconds; 0 means "never".
+    last_output_ms: AtomicI64,
+    last_input_ms: AtomicI64,
+    output_bytes: AtomicU64,
+}
+
+impl PtyActivity {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Record that the PTY produced `bytes` of output now.
+    pub fn record_output(&self, bytes: usize) {
+        self.last_output_ms
+            .store(chrono::Utc::now().timestamp_millis(), Ordering::Relaxed);
+        self.output_bytes.fetch_add(bytes as u64, Ordering::Relaxed);
+    }
+
+    /// Record that input was written to the PTY now.
+    pub fn record_input(&self) {
+        self.last_input_ms
+            .store(chrono::Utc::now().timestamp_millis(), Ordering::Relaxed);
+    }
+
+    pub fn last_output_at(&self) -> Option<chrono::DateTime<chrono::Utc>> {
+        millis_to_datetime(self.last_output_ms.load(Ordering::Relaxed))
+    }
+
+    pub fn last_input_at(&self) -> Option<chrono::DateTime<chrono::Utc>> {
+        millis_to_datetime(self.last_input_ms.load(Ordering::Relaxed))
+    }
+
+    /// Total bytes of output since the PTY started.
+    pub fn output_bytes(&self) -> u64 {
+        self.output_bytes.load(Ordering::Relaxed)
+    }
+}
+
+fn millis_to_datetime(ms: i64) -> Option<chrono::DateTime<chrono::Utc>> {
+    if ms == 0 {
+        return None;
+    }
+    chrono::DateTime::from_timestamp_millis(ms)
+}
+
 /// Spawn a blocking task that reads from a PTY reader and feeds output
 /// to the broadcaster.
 ///
@@ -78,6 +133,7 @@ pub fn spawn_pty_reader(
     mut reader: Box<dyn Read + Send>,
     output_tx: broadcast::Sender<Bytes>,
     scrollback: Arc<RwLock<ScrollbackBuffer>>,
+    activity: Arc<PtyActivity>,
     exit_tx: Option<tokio::sync::mpsc::UnboundedSender<PtyExitEvent>>,
 ) -> tokio::task::JoinHandle<()> {
     tokio::task::spawn_blocking(move || {
@@ -89,6 +145,7 @@ pub fn spawn_pty_reader(
                     break;
                 }
                 Ok(n) => {
+                    activity.record_output(n);
                     // Feed scrollback buffer for replay on attach
                     match scrollback.write() {
                         Ok(mut sb) => sb.push(&buf[..n]),
@@ -226,6 +283,47 @@ mod tests {
         assert_eq!(buf.contents(), b"4567890abc");
     }
 
+    #[test]
+    fn test_pty_activity_starts_empty() {
+        let activity = PtyActivity::new();
+        assert!(activity.last_output_at().is_none());
+        assert!(activity.last_input_at().is_none());
+    }
+
+    #[test]
+    fn test_pty_activity_records_output_and_input_independently() {
+        let activity = PtyActivity::new();
+        activity.record_output(3);
+        assert!(activity.last_output_at().is_some());
+        assert!(activity.last_input_at().is_none());
+
+        activity.record_input();
+        assert!(activity.last_input_at().unwrap() >= activity.last_output_at().unwrap());
+    }
+
+    #[tokio::test]
+    async fn test_pty_reader_records_output_activity() {
+        let (tx, _rx) = broadcast::channel(4);
+        let scrollback = Arc::new(RwLock::new(ScrollbackBuffer::new(64)));
+        let activity = Arc::new(PtyActivity::new());
+        let reader: Box<dyn Read + Send> = Box::new(std::io::Cursor::new(b"hello".to_vec()));
+
+        spawn_pty_reader(
+            "s1".to_string(),
+            reader,
+            tx,
+            scrollback.clone(),
+            activity.clone(),
+            None,
+        )
+        .await
+        .unwrap();
+
+        assert!(activity.last_output_at().is_some());
+        assert_eq!(activity.output_bytes(), 5);
+        assert_eq!(scrollback.read().unwrap().contents(), b"hello");
+    }
+
     #[test]
     #[should_panic(expected = "ScrollbackBuffer capacity must be non-zero")]
     fn test_scrollback_buffer_zero_capacity_panics() {
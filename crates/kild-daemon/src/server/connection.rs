@@ -8,6 +8,7 @@ use tracing::{debug, error, info, warn};
 
 use kild_core::errors::KildError;
 
+use crate::hooks::approvals::{ApprovalBroker, Resolution, Responder};
 use crate::protocol::codec::{read_message, write_message, write_message_flush};
 use crate::protocol::messages::{ClientMessage, DaemonMessage, ErrorCode};
 use crate::session::manager::DaemonSessionStore;
@@ -23,6 +24,7 @@ use crate::session::state::ClientId;
 pub async fn handle_connection<S>(
     stream: S,
     session_manager: Arc<RwLock<DaemonSessionStore>>,
+    approvals: Arc<ApprovalBroker>,
     shutdown: tokio_util::sync::CancellationToken,
 ) where
     S: AsyncRead + AsyncWrite + Send + Unpin + 'static,
@@ -52,6 +54,7 @@ pub async fn handle_connection<S>(
                             msg,
                             client_id,
                             &session_manager,
+                            &approvals,
                             writer.clone(),
                             &shutdown,
                         ).await;
@@ -107,6 +110,7 @@ async fn dispatch_message<W>(
     msg: ClientMessage,
     client_id: ClientId,
     session_manager: &Arc<RwLock<DaemonSessionStore>>,
+    approvals: &ApprovalBroker,
     writer: Arc<Mutex<W>>,
     shutdown: &tokio_util::sync::CancellationToken,
 ) -> Option<DaemonMessage>
@@ -475,6 +479,66 @@ where
 
         ClientMessage::Ping { id } => Some(DaemonMessage::Ack { id }),
 
+        ClientMessage::ListApprovals { id } => Some(DaemonMessage::ApprovalList {
+            id,
+            approvals: approvals.list(),
+        }),
+
+        ClientMessage::ResolveApproval {
+            id,
+            approval_id,
+            decision,
+            message,
+        } => {
+            let Some((approval, responder)) = approvals.take(&approval_id) else {
+                return Some(DaemonMessage::Error {
+                    id,
+                    code: ErrorCode::ApprovalNotFound,
+                    message: format!(
+                        "No pending approval '{}' (already resolved or timed out)",
+                        approval_id
+                    ),
+                });
+            };
+            info!(
+                event = "daemon.approvals.resolve_requested",
+                approval_id = %approval_id,
+                branch = %approval.branch,
+                decision = %decision,
+                client_id = client_id,
+            );
+            let resolution = Resolution { decision, message };
+            match responder {
+                Responder::Hook(tx) => {
+                    if tx.send(resolution).is_err() {
+                        // The hook request ended between take() and send().
+                        return Some(DaemonMessage::Error {
+                            id,
+                            code: ErrorCode::ApprovalNotFound,
+                            message: format!("Approval '{}' is no longer waiting", approval_id),
+                        });
+                    }
+                    Some(DaemonMessage::Ack { id })
+                }
+                Responder::Pty { daemon_session_id } => {
+                    match crate::hooks::deliver_to_pty(
+                        session_manager,
+                        &daemon_session_id,
+                        &resolution,
+                    )
+                    .await
+                    {
+                        Ok(()) => Some(DaemonMessage::Ack { id }),
+                        Err(e) => Some(DaemonMessage::Error {
+                            id,
+                            code: ErrorCode::from_code(e.error_code()),
+                            message: e.to_string(),
+                        }),
+                    }
+                }
+            }
+        }
+
         other => {
             warn!(
                 event = "daemon.connection.unhandled_message",
@@ -82,6 +82,7 @@ pub async fn run_server(config: DaemonConfig) -> Result<(), DaemonError> {
         pty_exit_tx,
     )));
     let shutdown = CancellationToken::new();
+    let approvals = Arc::new(hooks::approvals::ApprovalBroker::new());
 
     // Spawn signal handler
     let signal_shutdown = shutdown.clone();
@@ -132,13 +133,17 @@ pub async fn run_server(config: DaemonConfig) -> Result<(), DaemonError> {
             tcp_listener,
             acceptor,
             mgr_clone,
+            approvals.clone(),
             shutdown_clone,
         ));
     }
 
     // Optionally start HTTP hook endpoint for Claude Code `type: "http"` hooks.
     if config.hooks_port > 0 {
-        let hook_state = Arc::new(hooks::HookState::new());
+        let hook_state = Arc::new(hooks::HookState::new(
+            approvals.clone(),
+            session_manager.clone(),
+        ));
         let hooks_shutdown = shutdown.clone();
         let hooks_port = config.hooks_port;
         tokio::spawn(async move {
@@ -167,6 +172,7 @@ pub async fn run_server(config: DaemonConfig) -> Result<(), DaemonError> {
                         tokio::spawn(connection::handle_connection(
                             stream,
                             mgr,
+                            approvals.clone(),
                             shutdown_token,
                         ));
                     }
@@ -217,6 +223,7 @@ async fn tcp_accept_loop(
     listener: TcpListener,
     acceptor: TlsAcceptor,
     session_manager: Arc<RwLock<DaemonSessionStore>>,
+    approvals: Arc<hooks::approvals::ApprovalBroker>,
     shutdown: CancellationToken,
 ) {
     loop {
@@ -226,6 +233,7 @@ async fn tcp_accept_loop(
                     Ok((tcp_stream, addr)) => {
                         let acceptor = acceptor.clone();
                         let mgr = session_manager.clone();
+                        let approvals = approvals.clone();
                         let shutdown_clone = shutdown.clone();
                         tokio::spawn(async move {
                             match acceptor.accept(tcp_stream).await {
@@ -234,7 +242,7 @@ async fn tcp_accept_loop(
                                         event = "daemon.server.tls_connection_accepted",
                                         addr = %addr,
                                     );
-                                    connection::handle_connection(tls_stream, mgr, shutdown_clone).await;
+                                    connection::handle_connection(tls_stream, mgr, approvals, shutdown_clone).await;
                                 }
                                 Err(e) => {
                                     warn!(
@@ -112,8 +112,9 @@ impl DaemonSessionStore {
         let (output_tx, _) = broadcast::channel(broadcast_capacity);
         let reader_tx = output_tx.clone();
 
-        // Get shared scrollback buffer so PTY reader can feed it
+        // Get shared scrollback buffer and activity tracker so PTY reader can feed them
         let shared_scrollback = session.shared_scrollback();
+        let shared_activity = session.shared_activity();
 
         // Spawn background task to read PTY output
         spawn_pty_reader(
@@ -121,6 +122,7 @@ impl DaemonSessionStore {
             reader,
             reader_tx,
             shared_scrollback,
+            shared_activity,
             Some(self.pty_exit_tx.clone()),
         );
 
@@ -223,13 +225,20 @@ impl DaemonSessionStore {
     }
 
     /// Write data to a session's PTY stdin.
+    ///
+    /// Records the write as input activity so health checks can tell a
+    /// session waiting on the user from one the user is waiting on.
     pub fn write_stdin(&self, session_id: &str, data: &[u8]) -> Result<(), DaemonError> {
         let pty = self
             .pty_store
             .get(session_id)
             .ok_or_else(|| DaemonError::SessionNotFound(session_id.to_string()))?;
 
-        pty.write_stdin(data)
+        pty.write_stdin(data)?;
+        if let Some(session) = self.sessions.get(session_id) {
+            session.record_input();
+        }
+        Ok(())
     }
 
     /// Stop a session's agent process.
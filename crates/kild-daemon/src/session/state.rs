@@ -6,7 +6,7 @@ use tokio::sync::broadcast;
 use tracing::error;
 
 use crate::errors::DaemonError;
-use crate::pty::output::ScrollbackBuffer;
+use crate::pty::output::{PtyActivity, ScrollbackBuffer};
 use crate::types::{DaemonSessionStatus, SessionStatus};
 
 /// Unique identifier for a connected client.
@@ -52,6 +52,8 @@ pub struct DaemonSession {
     pty_pid: Option<u32>,
     /// Exit code of the PTY child process. Set when the process exits.
     exit_code: Option<i32>,
+    /// Last output/input timestamps, shared with the PTY reader task.
+    activity: Arc<PtyActivity>,
 }
 
 impl DaemonSession {
@@ -74,6 +76,7 @@ impl DaemonSession {
             attached_clients: HashSet::new(),
             pty_pid: None,
             exit_code: None,
+            activity: Arc::new(PtyActivity::new()),
         }
     }
 
@@ -211,6 +214,16 @@ impl DaemonSession {
         self.scrollback.clone()
     }
 
+    /// Get a clone of the shared activity tracker for the PTY reader task.
+    pub fn shared_activity(&self) -> Arc<PtyActivity> {
+        self.activity.clone()
+    }
+
+    /// Record that input was written to this session's PTY.
+    pub fn record_input(&self) {
+        self.activity.record_input();
+    }
+
     /// Convert to wire format `DaemonSessionStatus`.
     pub fn to_daemon_session_status(&self) -> DaemonSessionStatus {
         let status = match self.state {
@@ -227,6 +240,9 @@ impl DaemonSession {
             client_count: Some(self.client_count()),
             pty_pid: self.pty_pid,
             exit_code: self.exit_code,
+            last_output_at: self.activity.last_output_at().map(|t| t.to_rfc3339()),
+            last_input_at: self.activity.last_input_at().map(|t| t.to_rfc3339()),
+            output_bytes: Some(self.activity.output_bytes()),
         }
     }
 }
@@ -0,0 +1,273 @@
+//! Moving a kild's branch and working state between repositories.
+//!
+//! Used by `kild export` / `kild import`. History travels as a git bundle
+//! (thin when the receiving side is expected to have the base branch), and
+//! uncommitted work as a binary patch against the branch HEAD.
+
+use std::path::Path;
+
+use git2::{BranchType, Repository};
+use tracing::{info, warn};
+
+use crate::checkpoint::snapshot_tree;
+use crate::errors::GitError;
+use crate::validation::validate_git_arg;
+
+/// SHA of the commit checked out in `worktree_path`.
+pub fn head_commit(worktree_path: &Path) -> Result<String, GitError> {
+    let repo = Repository::open(worktree_path)?;
+    Ok(repo.head()?.peel_to_commit()?.id().to_string())
+}
+
+/// Merge base of `HEAD` in `worktree_path` with the first resolvable of `bases`.
+///
+/// Candidates are revisions such as `origin/main` or `main`. Returns `None`
+/// when none resolves or there is no common ancestor.
+pub fn merge_base_with(worktree_path: &Path, bases: &[String]) -> Option<String> {
+    let repo = Repository::open(worktree_path).ok()?;
+    let head = repo.head().ok()?.peel_to_commit().ok()?.id();
+    bases.iter().find_map(|base| {
+        let base = repo.revparse_single(base).ok()?.peel_to_commit().ok()?.id();
+        repo.merge_base(head, base).ok().map(|oid| oid.to_string())
+    })
+}
+
+/// Write `branch` (and the history it needs) to a bundle file.
+///
+/// With `exclude`, commits reachable from it are left out; the receiving
+/// repository must already have them.
+pub fn create_bundle(
+    repo_path: &Path,
+    bundle_path: &Path,
+    branch: &str,
+    exclude: Option<&str>,
+) -> Result<(), GitError> {
+    validate_git_arg(branch, "branch name")?;
+    let mut args = vec![
+        "bundle".to_string(),
+        "create".to_string(),
+        "--quiet".to_string(),
+        bundle_path.display().to_string(),
+        branch.to_string(),
+    ];
+    if let Some(exclude) = exclude {
+        validate_git_arg(exclude, "commit")?;
+        args.push(format!("^{}", exclude));
+    }
+    run_bundle_git(repo_path, &args)?;
+
+    info!(
+        event = "core.git.bundle_create_completed",
+        branch = branch,
+        path = %bundle_path.display(),
+        thin = exclude.is_some()
+    );
+    Ok(())
+}
+
+/// Fetch `branch` from a bundle into a new local branch `target_branch`.
+///
+/// Fails with `BranchAlreadyExists` if `target_branch` exists, and with
+/// `BundleFailed` if the bundle needs commits this repository lacks.
+pub fn fetch_bundle(
+    repo_path: &Path,
+    bundle_path: &Path,
+    branch: &str,
+    target_branch: &str,
+) -> Result<(), GitError> {
+    validate_git_arg(branch, "branch name")?;
+    validate_git_arg(target_branch, "branch name")?;
+    ensure_branch_absent(repo_path, target_branch)?;
+
+    run_bundle_git(
+        repo_path,
+        &[
+            "fetch".to_string(),
+            "--quiet".to_string(),
+            bundle_path.display().to_string(),
+            format!("refs/heads/{}:refs/heads/{}", branch, target_branch),
+        ],
+    )?;
+
+    info!(
+        event = "core.git.bundle_fetch_completed",
+        branch = target_branch,
+        path = %bundle_path.display()
+    );
+    Ok(())
+}
+
+/// Create local branch `branch` at an existing commit.
+pub fn create_branch_at(repo_path: &Path, branch: &str, commit: &str) -> Result<(), GitError> {
+    ensure_branch_absent(repo_path, branch)?;
+    let repo = Repository::open(repo_path)?;
+    let target = git2::Oid::from_str(commit)
+        .and_then(|oid| repo.find_commit(oid))
+        .map_err(|_| GitError::BundleFailed {
+            message: format!(
+                "commit {} is not in this repository; fetch the base branch first",
+                commit
+            ),
+        })?;
+    repo.branch(branch, &target, false)?;
+    Ok(())
+}
+
+fn ensure_branch_absent(repo_path: &Path, branch: &str) -> Result<(), GitError> {
+    let repo = Repository::open(repo_path)?;
+    if repo.find_branch(branch, BranchType::Local).is_ok() {
+        return Err(GitError::BranchAlreadyExists {
+            branch: branch.to_string(),
+        });
+    }
+    Ok(())
+}
+
+/// Binary patch of everything in the worktree that differs from `HEAD`:
+/// staged, unstaged and untracked (non-ignored) files.
+///
+/// Returns an empty patch for a clean worktree.
+pub fn working_state_patch(worktree_path: &Path) -> Result<Vec<u8>, GitError> {
+    let repo = Repository::open(worktree_path)?;
+    let head = repo.head()?.peel_to_commit()?.id().to_string();
+    let tree = snapshot_tree(&repo)?.to_string();
+
+    let output = std::process::Command::new("git")
+        .current_dir(worktree_path)
+        .args([
+            "diff",
+            "--binary",
+            "--no-color",
+            "--no-ext-diff",
+            &head,
+            &tree,
+        ])
+        .output()
+        .map_err(|e| GitError::DiffFailed {
+            message: format!("Failed to execute git: {}", e),
+        })?;
+    if !output.status.success() {
+        let stderr = String::from_utf8_lossy(&output.stderr);
+        return Err(GitError::DiffFailed {
+            message: format!("git diff failed: {}", stderr.trim()),
+        });
+    }
+    Ok(output.stdout)
+}
+
+/// Apply a patch from [`working_state_patch`] to a worktree without staging it.
+pub fn apply_patch(worktree_path: &Path, patch_path: &Path) -> Result<(), GitError> {
+    let output = std::process::Command::new("git")
+        .current_dir(worktree_path)
+        .args(["apply", "--whitespace=nowarn"])
+        .arg(patch_path)
+        .output()
+        .map_err(|e| GitError::OperationFailed {
+            message: format!("Failed to execute git apply: {}", e),
+        })?;
+    if !output.status.success() {
+        let stderr = String::from_utf8_lossy(&output.stderr);
+        warn!(
+            event = "core.git.patch_apply_failed",
+            path = %worktree_path.display(),
+            stderr = %stderr.trim()
+        );
+        return Err(GitError::OperationFailed {
+            message: format!("git apply failed: {}", stderr.trim()),
+        });
+    }
+    Ok(())
+}
+
+fn run_bundle_git(dir: &Path, args: &[String]) -> Result<(), GitError> {
+    let output = std::process::Command::new("git")
+        .current_dir(dir)
+        .args(args)
+        .output()
+        .map_err(|e| GitError::BundleFailed {
+            message: format!("Failed to execute git: {}", e),
+        })?;
+    if output.status.success() {
+        return Ok(());
+    }
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    warn!(
+        event = "core.git.bundle_command_failed",
+        command = args.first().map(String::as_str).unwrap_or_default(),
+        path = %dir.display(),
+        stderr = %stderr.trim()
+    );
+    Err(GitError::BundleFailed {
+        message: format!("git {} failed: {}", args[0], stderr.trim()),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_support::git;
+    use std::fs;
+
+    #[test]
+    fn test_thin_bundle_and_patch_round_trip() {
+        let dir = tempfile::tempdir().unwrap();
+        let source = dir.path().join("source");
+        fs::create_dir_all(&source).unwrap();
+        git(&source, &["init", "-q", "-b", "main"]);
+        fs::write(source.join("a.txt"), "base\n").unwrap();
+        git(&source, &["add", "-A"]);
+        git(&source, &["commit", "-qm", "base"]);
+        let dest = dir.path().join("dest");
+        git(
+            dir.path(),
+            &["clone", "-q", source.to_str().unwrap(), "dest"],
+        );
+
+        git(&source, &["checkout", "-qb", "kild/feat"]);
+        fs::write(source.join("a.txt"), "feature\n").unwrap();
+        git(&source, &["commit", "-qam", "feature"]);
+        fs::write(source.join("a.txt"), "dirty\n").unwrap();
+        fs::write(source.join("new.bin"), [0u8, 159, 146, 150]).unwrap();
+
+        assert_ne!(head_commit(&source).unwrap(), head_commit(&dest).unwrap());
+        let base =
+            merge_base_with(&source, &["origin/main".to_string(), "main".to_string()]).unwrap();
+        let bundle = dir.path().join("feat.bundle");
+        create_bundle(&source, &bundle, "kild/feat", Some(&base)).unwrap();
+        let patch = working_state_patch(&source).unwrap();
+        assert!(!patch.is_empty());
+        let patch_path = dir.path().join("dirty.patch");
+        fs::write(&patch_path, &patch).unwrap();
+
+        fetch_bundle(&dest, &bundle, "kild/feat", "kild/other").unwrap();
+        assert!(matches!(
+            fetch_bundle(&dest, &bundle, "kild/feat", "kild/other"),
+            Err(GitError::BranchAlreadyExists { .. })
+        ));
+        git(&dest, &["checkout", "-q", "kild/other"]);
+        assert_eq!(fs::read_to_string(dest.join("a.txt")).unwrap(), "feature\n");
+
+        apply_patch(&dest, &patch_path).unwrap();
+        assert_eq!(fs::read_to_string(dest.join("a.txt")).unwrap(), "dirty\n");
+        assert_eq!(
+            fs::read(dest.join("new.bin")).unwrap(),
+            [0u8, 159, 146, 150]
+        );
+        // Restored as uncommitted work, nothing staged.
+        assert_eq!(git(&dest, &["diff", "--cached", "--name-only"]), "");
+    }
+
+    #[test]
+    fn test_create_branch_at_missing_commit() {
+        let dir = tempfile::tempdir().unwrap();
+        crate::test_support::init_repo_with_commit(dir.path()).unwrap();
+
+        let result = create_branch_at(
+            dir.path(),
+            "kild/x",
+            "0123456789abcdef0123456789abcdef01234567",
+        );
+        assert!(matches!(result, Err(GitError::BundleFailed { .. })));
+        assert!(working_state_patch(dir.path()).unwrap().is_empty());
+    }
+}
@@ -0,0 +1,490 @@
+//! Worktree checkpoints stored as hidden refs.
+//!
+//! A checkpoint is a commit that captures the whole working tree — committed,
+//! staged, unstaged and untracked (non-ignored) files — on top of the branch
+//! HEAD. It lives at `refs/kild/checkpoints/<branch>/<id>` and never touches
+//! the branch, the index or the working directory when it is taken.
+
+use std::path::Path;
+
+use git2::build::CheckoutBuilder;
+use git2::{IndexAddOption, Oid, Repository, ResetType, Signature};
+use tracing::{info, warn};
+
+use crate::errors::GitError;
+use crate::status::skip_worktree_paths;
+use crate::types::{Checkpoint, CheckpointTrigger};
+
+/// Ref namespace holding every kild's checkpoints.
+pub const CHECKPOINT_REF_PREFIX: &str = "refs/kild/checkpoints";
+
+const TRIGGER_TRAILER: &str = "Kild-Trigger: ";
+
+fn checkpoint_ref(branch: &str, id: u32) -> String {
+    format!("{CHECKPOINT_REF_PREFIX}/{branch}/{id}")
+}
+
+/// Record the current state of a worktree as a new checkpoint.
+///
+/// The snapshot is built from an in-memory copy of the index, so the on-disk
+/// index and the branch stay untouched. Files outside a sparse checkout's cone
+/// keep their committed content.
+///
+/// Automatic triggers skip the snapshot and return `None` when nothing changed
+/// since the branch's latest checkpoint.
+pub fn create_checkpoint(
+    worktree_path: &Path,
+    branch: &str,
+    message: Option<&str>,
+    trigger: CheckpointTrigger,
+) -> Result<Option<Checkpoint>, GitError> {
+    let repo = Repository::open(worktree_path)?;
+    let head = repo.head()?.peel_to_commit()?;
+
+    let tree_id = snapshot_tree(&repo)?;
+
+    let existing = list_checkpoints_in(&repo, branch)?;
+    if trigger.is_automatic()
+        && let Some(latest) = existing.last()
+        && let Ok(commit) = repo.find_commit(Oid::from_str(&latest.commit)?)
+        && commit.tree_id() == tree_id
+        && commit.parent_id(0).ok() == Some(head.id())
+    {
+        info!(
+            event = "core.git.checkpoint_skipped",
+            branch = branch,
+            reason = "unchanged"
+        );
+        return Ok(None);
+    }
+
+    let id = existing.last().map(|c| c.id + 1).unwrap_or(1);
+    let summary = message
+        .map(str::trim)
+        .filter(|m| !m.is_empty())
+        .map(str::to_string)
+        .unwrap_or_else(|| default_message(trigger));
+    let full_message = format!("{summary}\n\n{TRIGGER_TRAILER}{}\n", trigger.as_str());
+
+    let sig = repo
+        .signature()
+        .or_else(|_| Signature::now("kild", "kild@localhost"))?;
+    let tree = repo.find_tree(tree_id)?;
+    let oid = repo.commit(None, &sig, &sig, &full_message, &tree, &[&head])?;
+    repo.reference(
+        &checkpoint_ref(branch, id),
+        oid,
+        false,
+        &format!("kild checkpoint #{id}"),
+    )?;
+
+    info!(
+        event = "core.git.checkpoint_completed",
+        branch = branch,
+        id = id,
+        trigger = trigger.as_str(),
+        commit = %oid
+    );
+
+    let commit = repo.find_commit(oid)?;
+    Ok(Some(to_checkpoint(branch, id, &commit)))
+}
+
+/// Write a tree of the worktree's current files to the object database.
+///
+/// Uses an in-memory copy of the index with every non-ignored file added, so
+/// the on-disk index is untouched. Files outside a sparse checkout's cone keep
+/// their indexed content.
+pub(crate) fn snapshot_tree(repo: &Repository) -> Result<Oid, GitError> {
+    let skipped = skip_worktree_paths(repo);
+    let mut index = repo.index()?;
+    // Skip-worktree entries are absent on disk, not deleted; libgit2 would
+    // drop them from the index otherwise.
+    let mut keep_sparse = |path: &Path, _: &[u8]| -> i32 {
+        i32::from(skipped.contains(path.as_os_str().as_encoded_bytes()))
+    };
+    index.add_all(["*"], IndexAddOption::DEFAULT, Some(&mut keep_sparse))?;
+    index.update_all(["*"], Some(&mut keep_sparse))?;
+    Ok(index.write_tree()?)
+}
+
+fn default_message(trigger: CheckpointTrigger) -> String {
+    match trigger {
+        CheckpointTrigger::Manual => "Checkpoint".to_string(),
+        CheckpointTrigger::Inject => "Before inject".to_string(),
+        CheckpointTrigger::Stop => "Agent stopped".to_string(),
+        CheckpointTrigger::Rollback => "Before rollback".to_string(),
+    }
+}
+
+/// List a branch's checkpoints, oldest first.
+///
+/// `repo_path` may be the main repository or any of its worktrees; checkpoint
+/// refs are shared between them.
+pub fn list_checkpoints(repo_path: &Path, branch: &str) -> Result<Vec<Checkpoint>, GitError> {
+    let repo = Repository::open(repo_path)?;
+    list_checkpoints_in(&repo, branch)
+}
+
+fn list_checkpoints_in(repo: &Repository, branch: &str) -> Result<Vec<Checkpoint>, GitError> {
+    let prefix = format!("{CHECKPOINT_REF_PREFIX}/{branch}/");
+    let mut checkpoints = Vec::new();
+    for reference in repo.references_glob(&format!("{prefix}*"))? {
+        let reference = reference?;
+        // `*` also matches `/`, so `feature/*` would pick up `feature/x/1`.
+        let Some(id) = reference
+            .name()
+            .and_then(|name| name.strip_prefix(&prefix))
+            .and_then(|rest| rest.parse::<u32>().ok())
+        else {
+            continue;
+        };
+        match reference.peel_to_commit() {
+            Ok(commit) => checkpoints.push(to_checkpoint(branch, id, &commit)),
+            Err(e) => warn!(
+                event = "core.git.checkpoint_read_failed",
+                branch = branch,
+                id = id,
+                error = %e
+            ),
+        }
+    }
+    checkpoints.sort_by_key(|c| c.id);
+    Ok(checkpoints)
+}
+
+fn to_checkpoint(branch: &str, id: u32, commit: &git2::Commit<'_>) -> Checkpoint {
+    let raw = commit.message().unwrap_or_default();
+    let trigger = raw
+        .lines()
+        .rev()
+        .find_map(|line| line.strip_prefix(TRIGGER_TRAILER))
+        .and_then(|t| CheckpointTrigger::parse(t.trim()))
+        .unwrap_or(CheckpointTrigger::Manual);
+    let created_at = chrono::DateTime::from_timestamp(commit.time().seconds(), 0)
+        .map(|t| t.to_rfc3339())
+        .unwrap_or_default();
+    Checkpoint {
+        id,
+        branch: branch.to_string(),
+        commit: commit.id().to_string(),
+        base_commit: commit
+            .parent_id(0)
+            .map(|p| p.to_string())
+            .unwrap_or_default(),
+        message: commit.summary().unwrap_or_default().to_string(),
+        trigger,
+        created_at,
+    }
+}
+
+/// Restore a worktree to a checkpoint.
+///
+/// The current state is saved as a new checkpoint first, so a rollback can
+/// itself be undone. The branch is reset to the commit the checkpoint was
+/// taken on, the working directory is replaced with the snapshot (untracked
+/// files not in the snapshot are removed, ignored files are kept), and the
+/// index is reset to the branch HEAD so restored changes show as uncommitted.
+///
+/// Returns the checkpoint holding the pre-rollback state.
+pub fn rollback_to_checkpoint(
+    worktree_path: &Path,
+    branch: &str,
+    id: u32,
+) -> Result<Checkpoint, GitError> {
+    let repo = Repository::open(worktree_path)?;
+    let target = repo
+        .find_reference(&checkpoint_ref(branch, id))
+        .and_then(|r| r.peel_to_commit())
+        .map_err(|_| GitError::CheckpointNotFound {
+            branch: branch.to_string(),
+            id,
+        })?;
+    let base = target.parent(0)?;
+    let sparse = !skip_worktree_paths(&repo).is_empty();
+
+    let saved = create_checkpoint(
+        worktree_path,
+        branch,
+        Some(&format!("Before rollback to #{id}")),
+        CheckpointTrigger::Rollback,
+    )?
+    .ok_or_else(|| GitError::OperationFailed {
+        message: "failed to save current state before rollback".to_string(),
+    })?;
+
+    info!(
+        event = "core.git.rollback_started",
+        branch = branch,
+        id = id,
+        saved_id = saved.id
+    );
+
+    repo.reset(base.as_object(), ResetType::Soft, None)?;
+    let mut checkout = CheckoutBuilder::new();
+    checkout.force().remove_untracked(true);
+    repo.checkout_tree(target.as_object(), Some(&mut checkout))?;
+
+    let mut index = repo.index()?;
+    index.read_tree(&base.tree()?)?;
+    index.write()?;
+    if sparse {
+        crate::cli::sparse_checkout_reapply(worktree_path)?;
+    }
+
+    info!(
+        event = "core.git.rollback_completed",
+        branch = branch,
+        id = id,
+        base_commit = %base.id()
+    );
+    Ok(saved)
+}
+
+/// Delete the oldest automatic checkpoints beyond `keep`.
+///
+/// Manual and rollback checkpoints are never pruned. Returns the number of
+/// checkpoints removed.
+pub fn prune_checkpoints(repo_path: &Path, branch: &str, keep: usize) -> Result<usize, GitError> {
+    let repo = Repository::open(repo_path)?;
+    let automatic: Vec<Checkpoint> = list_checkpoints_in(&repo, branch)?
+        .into_iter()
+        .filter(|c| c.trigger.is_automatic())
+        .collect();
+    let excess = automatic.len().saturating_sub(keep);
+    for checkpoint in &automatic[..excess] {
+        repo.find_reference(&checkpoint_ref(branch, checkpoint.id))?
+            .delete()?;
+    }
+    if excess > 0 {
+        info!(
+            event = "core.git.checkpoint_prune_completed",
+            branch = branch,
+            removed = excess
+        );
+    }
+    Ok(excess)
+}
+
+/// Delete every checkpoint of a branch. Returns the number removed.
+pub fn delete_checkpoints(repo_path: &Path, branch: &str) -> Result<usize, GitError> {
+    let repo = Repository::open(repo_path)?;
+    let checkpoints = list_checkpoints_in(&repo, branch)?;
+    for checkpoint in &checkpoints {
+        repo.find_reference(&checkpoint_ref(branch, checkpoint.id))?
+            .delete()?;
+    }
+    Ok(checkpoints.len())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_support::{git, init_repo_with_commit};
+    use std::fs;
+
+    fn commit_file(dir: &Path, name: &str, content: &str) {
+        fs::write(dir.join(name), content).unwrap();
+        let repo = Repository::open(dir).unwrap();
+        let mut index = repo.index().unwrap();
+        index.add_path(Path::new(name)).unwrap();
+        index.write().unwrap();
+        let tree = repo.find_tree(index.write_tree().unwrap()).unwrap();
+        let sig = Signature::now("Test", "test@test.com").unwrap();
+        let parent = repo.head().unwrap().peel_to_commit().unwrap();
+        repo.commit(Some("HEAD"), &sig, &sig, "commit", &tree, &[&parent])
+            .unwrap();
+    }
+
+    fn head_id(dir: &Path) -> Oid {
+        Repository::open(dir)
+            .unwrap()
+            .head()
+            .unwrap()
+            .target()
+            .unwrap()
+    }
+
+    #[test]
+    fn test_checkpoint_captures_worktree_without_touching_branch_or_index() {
+        let dir = tempfile::tempdir().unwrap();
+        init_repo_with_commit(dir.path()).unwrap();
+        commit_file(dir.path(), "a.txt", "one");
+        fs::write(dir.path().join("a.txt"), "changed").unwrap();
+        fs::write(dir.path().join("new.txt"), "untracked").unwrap();
+        let head = head_id(dir.path());
+
+        let cp = create_checkpoint(dir.path(), "feat", Some("wip"), CheckpointTrigger::Manual)
+            .unwrap()
+            .unwrap();
+
+        assert_eq!(cp.id, 1);
+        assert_eq!(cp.message, "wip");
+        assert_eq!(cp.trigger, CheckpointTrigger::Manual);
+        assert_eq!(cp.base_commit, head.to_string());
+        assert_eq!(head_id(dir.path()), head);
+
+        let repo = Repository::open(dir.path()).unwrap();
+        let index = repo.index().unwrap();
+        assert!(index.get_path(Path::new("new.txt"), 0).is_none());
+        let tree = repo
+            .find_commit(Oid::from_str(&cp.commit).unwrap())
+            .unwrap()
+            .tree()
+            .unwrap();
+        assert!(tree.get_name("new.txt").is_some());
+
+        let listed = list_checkpoints(dir.path(), "feat").unwrap();
+        assert_eq!(listed, vec![cp]);
+    }
+
+    #[test]
+    fn test_automatic_checkpoint_skips_unchanged_state() {
+        let dir = tempfile::tempdir().unwrap();
+        init_repo_with_commit(dir.path()).unwrap();
+        fs::write(dir.path().join("a.txt"), "one").unwrap();
+
+        let first = create_checkpoint(dir.path(), "feat", None, CheckpointTrigger::Stop).unwrap();
+        assert_eq!(first.unwrap().message, "Agent stopped");
+        let second = create_checkpoint(dir.path(), "feat", None, CheckpointTrigger::Stop).unwrap();
+        assert!(second.is_none());
+
+        fs::write(dir.path().join("a.txt"), "two").unwrap();
+        let third = create_checkpoint(dir.path(), "feat", None, CheckpointTrigger::Inject)
+            .unwrap()
+            .unwrap();
+        assert_eq!(third.id, 2);
+    }
+
+    #[test]
+    fn test_list_checkpoints_ignores_nested_branches() {
+        let dir = tempfile::tempdir().unwrap();
+        init_repo_with_commit(dir.path()).unwrap();
+        create_checkpoint(dir.path(), "feat", None, CheckpointTrigger::Manual).unwrap();
+        create_checkpoint(dir.path(), "feat/sub", None, CheckpointTrigger::Manual).unwrap();
+
+        assert_eq!(list_checkpoints(dir.path(), "feat").unwrap().len(), 1);
+        assert_eq!(list_checkpoints(dir.path(), "feat/sub").unwrap().len(), 1);
+    }
+
+    #[test]
+    fn test_rollback_restores_files_and_branch() {
+        let dir = tempfile::tempdir().unwrap();
+        init_repo_with_commit(dir.path()).unwrap();
+        commit_file(dir.path(), "a.txt", "one");
+        let base = head_id(dir.path());
+        fs::write(dir.path().join("a.txt"), "dirty").unwrap();
+        fs::write(dir.path().join("keep.txt"), "untracked").unwrap();
+        create_checkpoint(dir.path(), "feat", None, CheckpointTrigger::Manual).unwrap();
+
+        // Agent commits, edits and creates more files afterwards.
+        commit_file(dir.path(), "b.txt", "later");
+        fs::write(dir.path().join("a.txt"), "worse").unwrap();
+        fs::write(dir.path().join("junk.txt"), "junk").unwrap();
+
+        let saved = rollback_to_checkpoint(dir.path(), "feat", 1).unwrap();
+
+        assert_eq!(saved.id, 2);
+        assert_eq!(saved.trigger, CheckpointTrigger::Rollback);
+        assert_eq!(head_id(dir.path()), base);
+        assert_eq!(
+            fs::read_to_string(dir.path().join("a.txt")).unwrap(),
+            "dirty"
+        );
+        assert!(dir.path().join("keep.txt").exists());
+        assert!(!dir.path().join("junk.txt").exists());
+        assert!(!dir.path().join("b.txt").exists());
+
+        // Restored changes are uncommitted, not staged.
+        let repo = Repository::open(dir.path()).unwrap();
+        let index = repo.index().unwrap();
+        assert!(index.get_path(Path::new("keep.txt"), 0).is_none());
+
+        // The rollback itself can be undone.
+        rollback_to_checkpoint(dir.path(), "feat", saved.id).unwrap();
+        assert!(dir.path().join("b.txt").exists());
+        assert!(dir.path().join("junk.txt").exists());
+        assert_eq!(
+            fs::read_to_string(dir.path().join("a.txt")).unwrap(),
+            "worse"
+        );
+    }
+
+    #[test]
+    fn test_checkpoint_and_rollback_in_sparse_worktree() {
+        let dir = tempfile::tempdir().unwrap();
+        let repo_path = dir.path().join("repo");
+        for file in ["in/a.rs", "out/b.rs"] {
+            let path = repo_path.join(file);
+            fs::create_dir_all(path.parent().unwrap()).unwrap();
+            fs::write(path, "x\n").unwrap();
+        }
+        git(&repo_path, &["init", "-q"]);
+        git(&repo_path, &["add", "-A"]);
+        git(&repo_path, &["commit", "-qm", "tree"]);
+        git(&repo_path, &["branch", "kild/sparse"]);
+        let worktree = dir.path().join("wt");
+        crate::cli::add_sparse_worktree(&repo_path, &worktree, "kild/sparse", &["in".to_string()])
+            .unwrap();
+
+        fs::write(worktree.join("in/a.rs"), "changed\n").unwrap();
+        let cp = create_checkpoint(&worktree, "sparse", None, CheckpointTrigger::Manual)
+            .unwrap()
+            .unwrap();
+        let repo = Repository::open(&worktree).unwrap();
+        let tree = repo
+            .find_commit(Oid::from_str(&cp.commit).unwrap())
+            .unwrap()
+            .tree()
+            .unwrap();
+        assert!(tree.get_path(Path::new("out/b.rs")).is_ok());
+
+        fs::write(worktree.join("in/a.rs"), "worse\n").unwrap();
+        rollback_to_checkpoint(&worktree, "sparse", cp.id).unwrap();
+
+        assert_eq!(
+            fs::read_to_string(worktree.join("in/a.rs")).unwrap(),
+            "changed\n"
+        );
+        assert!(!worktree.join("out").exists());
+        let details = crate::status::get_worktree_status(&worktree)
+            .unwrap()
+            .uncommitted_details
+            .unwrap();
+        assert_eq!(details.modified_files, 1);
+    }
+
+    #[test]
+    fn test_rollback_unknown_checkpoint() {
+        let dir = tempfile::tempdir().unwrap();
+        init_repo_with_commit(dir.path()).unwrap();
+
+        let result = rollback_to_checkpoint(dir.path(), "feat", 7);
+        assert!(matches!(
+            result,
+            Err(GitError::CheckpointNotFound { id: 7, .. })
+        ));
+    }
+
+    #[test]
+    fn test_prune_keeps_manual_checkpoints() {
+        let dir = tempfile::tempdir().unwrap();
+        init_repo_with_commit(dir.path()).unwrap();
+        create_checkpoint(dir.path(), "feat", None, CheckpointTrigger::Manual).unwrap();
+        for i in 0..3 {
+            fs::write(dir.path().join("a.txt"), i.to_string()).unwrap();
+            create_checkpoint(dir.path(), "feat", None, CheckpointTrigger::Stop).unwrap();
+        }
+
+        assert_eq!(prune_checkpoints(dir.path(), "feat", 1).unwrap(), 2);
+        let ids: Vec<u32> = list_checkpoints(dir.path(), "feat")
+            .unwrap()
+            .iter()
+            .map(|c| c.id)
+            .collect();
+        assert_eq!(ids, vec![1, 4]);
+
+        assert_eq!(delete_checkpoints(dir.path(), "feat").unwrap(), 2);
+        assert!(list_checkpoints(dir.path(), "feat").unwrap().is_empty());
+    }
+}
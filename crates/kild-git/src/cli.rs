@@ -117,6 +117,51 @@ pub fn delete_remote_branch(dir: &Path, remote: &str, branch: &str) -> Result<()
     }
 }
 
+/// Push a branch to a remote and set it as the upstream.
+///
+/// Uses `git push -u` CLI so the user's credential helpers and SSH agent apply.
+pub fn push_branch(dir: &Path, remote: &str, branch: &str) -> Result<(), GitError> {
+    validate_git_arg(remote, "remote name")?;
+    validate_git_arg(branch, "branch name")?;
+
+    info!(
+        event = "core.git.push_started",
+        remote = remote,
+        branch = branch,
+        path = %dir.display()
+    );
+
+    let output = std::process::Command::new("git")
+        .current_dir(dir)
+        .args(["push", "-u", remote, branch])
+        .output()
+        .map_err(|e| GitError::PushFailed {
+            branch: branch.to_string(),
+            message: format!("Failed to execute git in {}: {}", dir.display(), e),
+        })?;
+
+    if output.status.success() {
+        info!(
+            event = "core.git.push_completed",
+            remote = remote,
+            branch = branch
+        );
+        Ok(())
+    } else {
+        let stderr = String::from_utf8_lossy(&output.stderr);
+        warn!(
+            event = "core.git.push_failed",
+            remote = remote,
+            branch = branch,
+            stderr = %stderr.trim()
+        );
+        Err(GitError::PushFailed {
+            branch: branch.to_string(),
+            message: stderr.trim().to_string(),
+        })
+    }
+}
+
 /// Check if a `git push --delete` stderr indicates the branch was already deleted.
 ///
 /// Matches common "branch doesn't exist" patterns across git versions.
@@ -291,6 +336,159 @@ pub fn show_diff(worktree_path: &Path, staged: bool) -> Result<(), GitError> {
     Ok(())
 }
 
+/// Execute `git diff` between two trees, inheriting stdio for terminal output.
+///
+/// Used to compare two kilds: the trees are snapshots of each worktree and
+/// the prefixes label each side (e.g. `auth-a/` and `auth-b/`). Exit codes
+/// follow [`show_diff`].
+pub fn show_tree_diff(
+    dir: &Path,
+    from_tree: &str,
+    to_tree: &str,
+    prefixes: (&str, &str),
+    stat: bool,
+) -> Result<(), GitError> {
+    validate_git_arg(from_tree, "tree")?;
+    validate_git_arg(to_tree, "tree")?;
+
+    info!(
+        event = "core.git.tree_diff_started",
+        path = %dir.display(),
+        from = from_tree,
+        to = to_tree
+    );
+
+    let mut cmd = std::process::Command::new("git");
+    cmd.current_dir(dir).arg("diff");
+    if stat {
+        cmd.arg("--stat");
+    }
+    cmd.arg(format!("--src-prefix={}/", prefixes.0))
+        .arg(format!("--dst-prefix={}/", prefixes.1))
+        .args([from_tree, to_tree]);
+
+    let status = cmd.status().map_err(|e| GitError::DiffFailed {
+        message: format!("Failed to execute git: {}", e),
+    })?;
+
+    if let Some(code) = status.code()
+        && code >= 128
+    {
+        warn!(
+            event = "core.git.tree_diff_failed",
+            exit_code = code,
+            path = %dir.display()
+        );
+        return Err(GitError::DiffFailed {
+            message: format!("git diff failed with exit code {}", code),
+        });
+    }
+
+    info!(
+        event = "core.git.tree_diff_completed",
+        path = %dir.display(),
+        exit_code = status.code()
+    );
+    Ok(())
+}
+
+/// Cherry-pick commits onto the branch checked out in `dir`.
+///
+/// Uses `git cherry-pick` CLI so commit hooks and the user's identity apply.
+/// On conflict, records the conflicting files, aborts the whole sequence
+/// (commits already applied in this run are undone) and returns
+/// `GitError::CherryPickConflict`.
+pub fn cherry_pick(dir: &Path, commits: &[String]) -> Result<(), GitError> {
+    for commit in commits {
+        validate_git_arg(commit, "commit")?;
+    }
+
+    info!(
+        event = "core.git.cherry_pick_started",
+        path = %dir.display(),
+        count = commits.len()
+    );
+
+    let output = std::process::Command::new("git")
+        .current_dir(dir)
+        .arg("cherry-pick")
+        .args(commits)
+        .output()
+        .map_err(|e| GitError::OperationFailed {
+            message: format!("Failed to execute git cherry-pick: {}", e),
+        })?;
+
+    if output.status.success() {
+        info!(
+            event = "core.git.cherry_pick_completed",
+            path = %dir.display(),
+            count = commits.len()
+        );
+        return Ok(());
+    }
+
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    let git_output = |args: &[&str]| {
+        std::process::Command::new("git")
+            .current_dir(dir)
+            .args(args)
+            .output()
+            .ok()
+            .filter(|o| o.status.success())
+            .map(|o| String::from_utf8_lossy(&o.stdout).trim().to_string())
+    };
+
+    // A sequence in progress means git stopped on a commit (usually a conflict).
+    let Some(stopped_at) = git_output(&["rev-parse", "--short", "CHERRY_PICK_HEAD"]) else {
+        error!(
+            event = "core.git.cherry_pick_failed",
+            path = %dir.display(),
+            stderr = %stderr.trim()
+        );
+        return Err(GitError::OperationFailed {
+            message: format!("git cherry-pick failed: {}", stderr.trim()),
+        });
+    };
+    let files: Vec<String> = git_output(&["diff", "--name-only", "--diff-filter=U"])
+        .unwrap_or_default()
+        .lines()
+        .map(str::to_string)
+        .collect();
+
+    let abort = std::process::Command::new("git")
+        .current_dir(dir)
+        .args(["cherry-pick", "--abort"])
+        .output();
+    if !abort.as_ref().is_ok_and(|o| o.status.success()) {
+        let message = match abort {
+            Ok(o) => String::from_utf8_lossy(&o.stderr).trim().to_string(),
+            Err(e) => e.to_string(),
+        };
+        error!(
+            event = "core.git.cherry_pick_abort_failed",
+            path = %dir.display(),
+            error = %message
+        );
+        return Err(GitError::OperationFailed {
+            message: format!(
+                "cherry-pick of {} stopped and `git cherry-pick --abort` failed: {}",
+                stopped_at, message
+            ),
+        });
+    }
+
+    warn!(
+        event = "core.git.cherry_pick_conflicts",
+        path = %dir.display(),
+        commit = %stopped_at,
+        files = ?files
+    );
+    Err(GitError::CherryPickConflict {
+        commit: stopped_at,
+        files,
+    })
+}
+
 /// Get recent commits from a worktree as a formatted string.
 ///
 /// Executes `git log --oneline -n <count>` and returns the output.
@@ -330,9 +528,114 @@ pub fn get_commits(worktree_path: &Path, count: usize) -> Result<String, GitErro
     Ok(commits)
 }
 
+/// Create a worktree for `branch` with only `paths` checked out, in cone mode.
+///
+/// libgit2 has no sparse-checkout support, so this uses the git CLI:
+/// `worktree add --no-checkout`, `sparse-checkout set --cone`, then
+/// `checkout` to materialize just the cone. Sparse settings live in the
+/// worktree's own config, leaving the main checkout untouched. On failure
+/// the half-created worktree is removed.
+pub fn add_sparse_worktree(
+    repo_path: &Path,
+    worktree_path: &Path,
+    branch: &str,
+    paths: &[String],
+) -> Result<(), GitError> {
+    validate_git_arg(branch, "branch name")?;
+    for path in paths {
+        validate_git_arg(path, "sparse path")?;
+    }
+
+    info!(
+        event = "core.git.sparse_worktree_started",
+        branch = branch,
+        path = %worktree_path.display(),
+        sparse_paths = ?paths
+    );
+
+    let worktree = worktree_path.to_string_lossy();
+    run_sparse_git(
+        repo_path,
+        &["worktree", "add", "--no-checkout", &worktree, branch],
+    )?;
+
+    let mut set_args = vec!["sparse-checkout", "set", "--cone", "--"];
+    set_args.extend(paths.iter().map(String::as_str));
+    let result = run_sparse_git(worktree_path, &set_args)
+        .and_then(|()| run_sparse_git(worktree_path, &["checkout", branch]));
+
+    if let Err(e) = result {
+        let _ = run_sparse_git(repo_path, &["worktree", "remove", "--force", &worktree]);
+        return Err(e);
+    }
+
+    info!(
+        event = "core.git.sparse_worktree_completed",
+        branch = branch,
+        path = %worktree_path.display()
+    );
+    Ok(())
+}
+
+/// Add directories to a sparse worktree's cone and check them out.
+pub fn sparse_checkout_add(worktree_path: &Path, paths: &[String]) -> Result<(), GitError> {
+    for path in paths {
+        validate_git_arg(path, "sparse path")?;
+    }
+
+    info!(
+        event = "core.git.sparse_add_started",
+        path = %worktree_path.display(),
+        sparse_paths = ?paths
+    );
+
+    let mut args = vec!["sparse-checkout", "add", "--"];
+    args.extend(paths.iter().map(String::as_str));
+    run_sparse_git(worktree_path, &args)?;
+
+    info!(
+        event = "core.git.sparse_add_completed",
+        path = %worktree_path.display()
+    );
+    Ok(())
+}
+
+/// Re-apply a worktree's sparse-checkout patterns.
+///
+/// Removes files outside the cone from the working directory and restores
+/// their skip-worktree bits after an operation rewrote the index.
+pub fn sparse_checkout_reapply(worktree_path: &Path) -> Result<(), GitError> {
+    run_sparse_git(worktree_path, &["sparse-checkout", "reapply"])
+}
+
+fn run_sparse_git(dir: &Path, args: &[&str]) -> Result<(), GitError> {
+    let output = std::process::Command::new("git")
+        .current_dir(dir)
+        .args(args)
+        .output()
+        .map_err(|e| GitError::SparseCheckoutFailed {
+            message: format!("Failed to execute git: {}", e),
+        })?;
+
+    if output.status.success() {
+        return Ok(());
+    }
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    warn!(
+        event = "core.git.sparse_command_failed",
+        command = args.first().copied().unwrap_or_default(),
+        path = %dir.display(),
+        stderr = %stderr.trim()
+    );
+    Err(GitError::SparseCheckoutFailed {
+        message: format!("git {} failed: {}", args.join(" "), stderr.trim()),
+    })
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::test_support::git;
 
     #[test]
     fn test_validate_git_arg_rejects_dash_prefix() {
@@ -496,4 +799,92 @@ mod tests {
         let result = get_commits(Path::new("/nonexistent/path"), 10);
         assert!(result.is_err());
     }
+
+    #[test]
+    fn test_add_sparse_worktree_checks_out_only_cone() {
+        let dir = tempfile::TempDir::new().unwrap();
+        let repo_path = dir.path().join("repo");
+        for file in [
+            "services/billing/a.rs",
+            "services/auth/b.rs",
+            "libs/shared/c.rs",
+        ] {
+            let path = repo_path.join(file);
+            std::fs::create_dir_all(path.parent().unwrap()).unwrap();
+            std::fs::write(path, "x\n").unwrap();
+        }
+        git(&repo_path, &["init", "-q"]);
+        git(&repo_path, &["add", "-A"]);
+        git(&repo_path, &["commit", "-qm", "tree"]);
+        git(&repo_path, &["branch", "kild/sparse"]);
+
+        let worktree = dir.path().join("wt");
+        add_sparse_worktree(
+            &repo_path,
+            &worktree,
+            "kild/sparse",
+            &["services/billing".to_string()],
+        )
+        .unwrap();
+
+        assert!(worktree.join("services/billing/a.rs").exists());
+        assert!(!worktree.join("services/auth").exists());
+        assert!(!worktree.join("libs").exists());
+        // Paths outside the cone are not reported as deleted
+        let status = crate::status::get_worktree_status(&worktree).unwrap();
+        assert!(!status.has_uncommitted_changes);
+        assert_eq!(
+            crate::status::get_diff_stats(&worktree)
+                .unwrap()
+                .files_changed,
+            0
+        );
+        assert_eq!(
+            crate::query::has_uncommitted_changes(&worktree),
+            Some(false)
+        );
+        // The main checkout stays a full checkout
+        assert!(repo_path.join("services/auth/b.rs").exists());
+
+        sparse_checkout_add(&worktree, &["libs/shared".to_string()]).unwrap();
+        assert!(worktree.join("libs/shared/c.rs").exists());
+        assert!(!worktree.join("services/auth").exists());
+    }
+
+    #[test]
+    fn test_cherry_pick_applies_commits_and_reports_conflicts() {
+        let dir = tempfile::TempDir::new().unwrap();
+        let repo = dir.path();
+        std::fs::write(repo.join("a.txt"), "base\n").unwrap();
+        git(repo, &["init", "-q"]);
+        // cherry_pick runs plain `git`, so identity must come from repo config.
+        git(repo, &["config", "user.name", "Test"]);
+        git(repo, &["config", "user.email", "test@test.com"]);
+        git(repo, &["add", "-A"]);
+        git(repo, &["commit", "-qm", "base"]);
+        git(repo, &["checkout", "-qb", "other"]);
+        std::fs::write(repo.join("b.txt"), "new\n").unwrap();
+        git(repo, &["add", "-A"]);
+        git(repo, &["commit", "-qm", "add b"]);
+        let clean = git(repo, &["rev-parse", "HEAD"]);
+        std::fs::write(repo.join("a.txt"), "other\n").unwrap();
+        git(repo, &["commit", "-qam", "edit a"]);
+        let conflicting = git(repo, &["rev-parse", "HEAD"]);
+        git(repo, &["checkout", "-q", "-"]);
+        std::fs::write(repo.join("a.txt"), "mine\n").unwrap();
+        git(repo, &["commit", "-qam", "edit a here"]);
+        let head = git(repo, &["rev-parse", "HEAD"]);
+
+        let err = cherry_pick(repo, &[clean.clone(), conflicting]).unwrap_err();
+        match err {
+            GitError::CherryPickConflict { files, .. } => assert_eq!(files, vec!["a.txt"]),
+            other => panic!("expected conflict, got {other:?}"),
+        }
+        // The whole sequence was aborted.
+        assert_eq!(git(repo, &["rev-parse", "HEAD"]), head);
+        assert_eq!(git(repo, &["status", "--porcelain"]), "");
+
+        cherry_pick(repo, &[clean]).unwrap();
+        assert!(repo.join("b.txt").exists());
+    }
 }
@@ -0,0 +1,273 @@
+//! Comparing two kilds' worktrees and picking commits between them.
+//!
+//! Both sides are read from their working directories (uncommitted and
+//! untracked files included) via in-memory snapshot trees. Worktrees of the
+//! same repository share one object database, so trees and commits from
+//! either side can be looked up in the other.
+
+use std::collections::BTreeMap;
+use std::path::Path;
+
+use git2::{Delta, Diff, Oid, Patch, Repository, Sort};
+use tracing::info;
+
+use crate::checkpoint::snapshot_tree;
+use crate::errors::GitError;
+use crate::types::{
+    CompareSide, ComparedFile, DiffStats, FileChange, FileChangeKind, KildComparison,
+};
+
+/// Write a snapshot tree of a worktree's current files and return its SHA.
+///
+/// The tree is reachable from any worktree of the same repository, so it can
+/// be passed to `git diff` in either kild.
+pub fn worktree_tree_id(worktree_path: &Path) -> Result<String, GitError> {
+    let repo = Repository::open(worktree_path)?;
+    Ok(snapshot_tree(&repo)?.to_string())
+}
+
+/// Summarise how two kilds' worktrees differ from their common ancestor.
+pub fn compare_worktrees(
+    left_path: &Path,
+    left_branch: &str,
+    right_path: &Path,
+    right_branch: &str,
+) -> Result<KildComparison, GitError> {
+    info!(
+        event = "core.git.compare_started",
+        left = left_branch,
+        right = right_branch
+    );
+
+    let left_repo = Repository::open(left_path)?;
+    let right_repo = Repository::open(right_path)?;
+    let left_head = left_repo.head()?.peel_to_commit()?.id();
+    let right_head = right_repo.head()?.peel_to_commit()?.id();
+    let base = left_repo.merge_base(left_head, right_head)?;
+    let base_tree = left_repo.find_commit(base)?.tree()?;
+
+    let left_tree = left_repo.find_tree(snapshot_tree(&left_repo)?)?;
+    let right_tree = left_repo.find_tree(snapshot_tree(&right_repo)?)?;
+
+    let left_diff = left_repo.diff_tree_to_tree(Some(&base_tree), Some(&left_tree), None)?;
+    let right_diff = left_repo.diff_tree_to_tree(Some(&base_tree), Some(&right_tree), None)?;
+    let left_changes = file_changes(&left_diff)?;
+    let right_changes = file_changes(&right_diff)?;
+
+    let mut files: BTreeMap<String, ComparedFile> = BTreeMap::new();
+    for (path, (change, _)) in &left_changes {
+        files.insert(
+            path.clone(),
+            ComparedFile {
+                path: path.clone(),
+                left: Some(*change),
+                right: None,
+                identical: false,
+            },
+        );
+    }
+    for (path, (change, blob)) in &right_changes {
+        let entry = files.entry(path.clone()).or_insert_with(|| ComparedFile {
+            path: path.clone(),
+            left: None,
+            right: None,
+            identical: false,
+        });
+        entry.right = Some(*change);
+        entry.identical = left_changes
+            .get(path)
+            .is_some_and(|(_, left_blob)| left_blob == blob);
+    }
+
+    let side = |branch: &str, head: Oid, changes: &BTreeMap<String, (FileChange, Oid)>| {
+        let commits_ahead = left_repo
+            .graph_ahead_behind(head, base)
+            .map(|(ahead, _)| ahead)?;
+        Ok::<_, GitError>(CompareSide {
+            branch: branch.to_string(),
+            head_commit: head.to_string(),
+            commits_ahead,
+            diff: DiffStats {
+                insertions: changes.values().map(|(c, _)| c.insertions).sum(),
+                deletions: changes.values().map(|(c, _)| c.deletions).sum(),
+                files_changed: changes.len(),
+            },
+        })
+    };
+
+    let comparison = KildComparison {
+        base_commit: base.to_string(),
+        left: side(left_branch, left_head, &left_changes)?,
+        right: side(right_branch, right_head, &right_changes)?,
+        files: files.into_values().collect(),
+    };
+
+    info!(
+        event = "core.git.compare_completed",
+        left = left_branch,
+        right = right_branch,
+        files = comparison.files.len()
+    );
+    Ok(comparison)
+}
+
+/// Per-file changes in a diff, keyed by path, with the resulting blob ID.
+fn file_changes(diff: &Diff<'_>) -> Result<BTreeMap<String, (FileChange, Oid)>, GitError> {
+    let mut changes = BTreeMap::new();
+    for (idx, delta) in diff.deltas().enumerate() {
+        let kind = match delta.status() {
+            Delta::Added | Delta::Untracked => FileChangeKind::Added,
+            Delta::Deleted => FileChangeKind::Deleted,
+            _ => FileChangeKind::Modified,
+        };
+        let file = if kind == FileChangeKind::Deleted {
+            delta.old_file()
+        } else {
+            delta.new_file()
+        };
+        let Some(path) = file.path() else { continue };
+        let (insertions, deletions) = match Patch::from_diff(diff, idx)? {
+            Some(patch) => {
+                let (_, insertions, deletions) = patch.line_stats()?;
+                (insertions, deletions)
+            }
+            None => (0, 0),
+        };
+        changes.insert(
+            path.to_string_lossy().into_owned(),
+            (
+                FileChange {
+                    kind,
+                    insertions,
+                    deletions,
+                },
+                delta.new_file().id(),
+            ),
+        );
+    }
+    Ok(changes)
+}
+
+/// Commits to apply from one kild onto another, oldest first.
+///
+/// With no `revs`, picks every commit on `from`'s branch that `to` does not
+/// have, skipping merge commits. Otherwise each rev (SHA, `HEAD~2`, ...) is
+/// resolved in `from`'s worktree, in the order given.
+pub fn commits_to_pick(
+    from_path: &Path,
+    to_path: &Path,
+    revs: &[String],
+) -> Result<Vec<String>, GitError> {
+    let from_repo = Repository::open(from_path)?;
+
+    if !revs.is_empty() {
+        return revs
+            .iter()
+            .map(|rev| {
+                let commit = from_repo
+                    .revparse_single(rev)
+                    .and_then(|obj| obj.peel_to_commit())
+                    .map_err(|e| GitError::OperationFailed {
+                        message: format!("unknown commit '{}': {}", rev, e.message()),
+                    })?;
+                Ok(commit.id().to_string())
+            })
+            .collect();
+    }
+
+    let to_repo = Repository::open(to_path)?;
+    let from_head = from_repo.head()?.peel_to_commit()?.id();
+    let to_head = to_repo.head()?.peel_to_commit()?.id();
+
+    let mut walk = from_repo.revwalk()?;
+    walk.push(from_head)?;
+    walk.hide(to_head)?;
+    walk.set_sorting(Sort::TOPOLOGICAL | Sort::REVERSE)?;
+
+    let mut commits = Vec::new();
+    for oid in walk {
+        let commit = from_repo.find_commit(oid?)?;
+        if commit.parent_count() <= 1 {
+            commits.push(commit.id().to_string());
+        }
+    }
+    Ok(commits)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_support::{
+        commit_all, create_branch, create_worktree_for_branch, init_repo_with_commit,
+    };
+    use std::fs;
+
+    /// Main repo with `shared.txt` committed and two kild worktrees.
+    fn setup() -> (tempfile::TempDir, std::path::PathBuf, std::path::PathBuf) {
+        let dir = tempfile::tempdir().unwrap();
+        let repo_path = dir.path().join("repo");
+        fs::create_dir_all(&repo_path).unwrap();
+        init_repo_with_commit(&repo_path).unwrap();
+        fs::write(repo_path.join("shared.txt"), "base\n").unwrap();
+        commit_all(&repo_path, "base").unwrap();
+
+        let left = dir.path().join("left");
+        let right = dir.path().join("right");
+        create_branch(&repo_path, "kild/left").unwrap();
+        create_branch(&repo_path, "kild/right").unwrap();
+        create_worktree_for_branch(&repo_path, "left", &left, "kild/left").unwrap();
+        create_worktree_for_branch(&repo_path, "right", &right, "kild/right").unwrap();
+        (dir, left, right)
+    }
+
+    #[test]
+    fn test_compare_worktrees_includes_uncommitted_changes() {
+        let (_dir, left, right) = setup();
+        fs::write(left.join("shared.txt"), "same\n").unwrap();
+        fs::write(left.join("left.txt"), "a\nb\n").unwrap();
+        commit_all(&left, "left work").unwrap();
+        // Right kild has only uncommitted work.
+        fs::write(right.join("shared.txt"), "same\n").unwrap();
+        fs::write(right.join("right.txt"), "c\n").unwrap();
+
+        let comparison = compare_worktrees(&left, "left", &right, "right").unwrap();
+
+        assert_eq!(comparison.left.commits_ahead, 1);
+        assert_eq!(comparison.right.commits_ahead, 0);
+        assert_eq!(comparison.left.diff.files_changed, 2);
+        assert_eq!(comparison.right.diff.insertions, 2);
+
+        let shared: Vec<_> = comparison.shared().collect();
+        assert_eq!(shared.len(), 1);
+        assert_eq!(shared[0].path, "shared.txt");
+        assert!(shared[0].identical);
+        assert_eq!(
+            comparison
+                .left_only()
+                .map(|f| f.path.as_str())
+                .collect::<Vec<_>>(),
+            vec!["left.txt"]
+        );
+        let right_only: Vec<_> = comparison.right_only().collect();
+        assert_eq!(right_only[0].path, "right.txt");
+        assert_eq!(right_only[0].right.unwrap().kind, FileChangeKind::Added);
+    }
+
+    #[test]
+    fn test_commits_to_pick_defaults_to_missing_commits() {
+        let (_dir, left, right) = setup();
+        fs::write(left.join("one.txt"), "1").unwrap();
+        let first = commit_all(&left, "one").unwrap();
+        fs::write(left.join("two.txt"), "2").unwrap();
+        let second = commit_all(&left, "two").unwrap();
+
+        let picks = commits_to_pick(&left, &right, &[]).unwrap();
+        assert_eq!(picks, vec![first.to_string(), second.to_string()]);
+
+        let picks = commits_to_pick(&left, &right, &["HEAD~1".to_string()]).unwrap();
+        assert_eq!(picks, vec![first.to_string()]);
+
+        let result = commits_to_pick(&left, &right, &["nope".to_string()]);
+        assert!(matches!(result, Err(GitError::OperationFailed { .. })));
+    }
+}
@@ -52,6 +52,9 @@ pub enum GitError {
     #[error("Failed to delete remote branch '{branch}': {message}")]
     RemoteBranchDeleteFailed { branch: String, message: String },
 
+    #[error("Failed to push branch '{branch}': {message}")]
+    PushFailed { branch: String, message: String },
+
     #[error("Git diff failed: {message}")]
     DiffFailed { message: String },
 
@@ -61,6 +64,21 @@ pub enum GitError {
     #[error("Git log failed: {message}")]
     LogFailed { message: String },
 
+    #[error("Invalid sparse path '{path}': {message}")]
+    InvalidSparsePath { path: String, message: String },
+
+    #[error("Sparse checkout failed: {message}")]
+    SparseCheckoutFailed { message: String },
+
+    #[error("Cherry-pick of {commit} conflicts in: {}", files.join(", "))]
+    CherryPickConflict { commit: String, files: Vec<String> },
+
+    #[error("Git bundle failed: {message}")]
+    BundleFailed { message: String },
+
+    #[error("Checkpoint #{id} not found for branch '{branch}'")]
+    CheckpointNotFound { branch: String, id: u32 },
+
     #[error("IO error during git operation: {source}")]
     IoError {
         #[from]
@@ -105,6 +123,18 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_sparse_errors() {
+        let error = GitError::InvalidSparsePath {
+            path: "src/*.rs".to_string(),
+            message: "cone mode takes directories, not globs".to_string(),
+        };
+        assert_eq!(
+            error.to_string(),
+            "Invalid sparse path 'src/*.rs': cone mode takes directories, not globs"
+        );
+    }
+
     #[test]
     fn test_worktree_errors() {
         let exists_error = GitError::WorktreeAlreadyExists {
@@ -64,6 +64,25 @@ fn get_last_commit_time(repo: &Repository) -> Option<String> {
             return None;
         }
     };
+    commit_time_rfc3339(&commit)
+}
+
+/// Last commit time of `rev` (HEAD when `None`) in the repository containing
+/// `path`, as RFC3339.
+///
+/// Returns `None` if the repository or revision can't be resolved.
+pub fn last_commit_time(path: &Path, rev: Option<&str>) -> Option<String> {
+    let repo = Repository::discover(path).ok()?;
+    match rev {
+        None => get_last_commit_time(&repo),
+        Some(rev) => {
+            let commit = repo.revparse_single(rev).ok()?.peel_to_commit().ok()?;
+            commit_time_rfc3339(&commit)
+        }
+    }
+}
+
+fn commit_time_rfc3339(commit: &git2::Commit<'_>) -> Option<String> {
     let time = commit.time();
     let secs = time.seconds();
     let offset_mins = time.offset_minutes();
@@ -365,6 +384,25 @@ mod tests {
             .unwrap();
     }
 
+    #[test]
+    fn test_last_commit_time_head_and_rev() {
+        let dir = TempDir::new().unwrap();
+        init_git_repo(dir.path());
+        fs::write(dir.path().join("a.txt"), "a").unwrap();
+        git_add_commit(dir.path(), "first");
+        Command::new("git")
+            .args(["branch", "side"])
+            .current_dir(dir.path())
+            .output()
+            .unwrap();
+
+        let head = last_commit_time(dir.path(), None).expect("HEAD commit time");
+        assert!(chrono::DateTime::parse_from_rfc3339(&head).is_ok());
+        assert_eq!(last_commit_time(dir.path(), Some("side")), Some(head));
+        assert_eq!(last_commit_time(dir.path(), Some("missing")), None);
+        assert_eq!(last_commit_time(Path::new("/nonexistent/path"), None), None);
+    }
+
     #[test]
     fn test_collect_branch_health_nonexistent_path() {
         let result = collect_branch_health(
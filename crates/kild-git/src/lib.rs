@@ -1,4 +1,7 @@
+pub mod bundle;
+pub mod checkpoint;
 pub mod cli;
+pub mod compare;
 pub mod errors;
 pub mod health;
 pub mod naming;
@@ -6,6 +9,7 @@ pub mod project;
 pub mod query;
 pub mod remote;
 pub mod removal;
+pub mod review;
 pub mod status;
 pub mod types;
 pub mod validation;
@@ -14,6 +18,11 @@ pub mod validation;
 pub mod test_support;
 
 // Re-export commonly used types and functions
+pub use checkpoint::{
+    create_checkpoint, delete_checkpoints, list_checkpoints, prune_checkpoints,
+    rollback_to_checkpoint,
+};
+pub use compare::{commits_to_pick, compare_worktrees, worktree_tree_id};
 pub use errors::GitError;
 pub use health::collect_branch_health;
 pub use naming::{
@@ -25,20 +34,23 @@ pub use project::{detect_project, detect_project_at};
 pub use query::{
     WorktreeEntry, delete_local_branch, ensure_in_repo, get_origin_url, has_any_remote,
     has_uncommitted_changes, head_branch_name, is_git_repo, is_worktree_valid,
-    list_local_branch_names, list_worktree_entries, worktree_active_branches,
+    list_local_branch_names, list_remote_branch_names, list_worktree_entries,
+    worktree_active_branches,
 };
 pub use remote::{fetch_remote, rebase_worktree};
 pub use removal::{
     delete_branch_if_exists, find_main_repo_root, remove_worktree, remove_worktree_by_path,
     remove_worktree_force,
 };
+pub use review::{MAX_REVIEW_LINES, restore_file_to_base, review_diff};
 pub use status::{collect_git_stats, get_diff_stats, get_worktree_status};
 pub use types::{
-    BaseBranchDrift, BranchHealth, BranchState, CleanKild, CommitActivity, ConflictStatus,
-    DiffStats, FileOverlap, GitProjectState, GitStats, OverlapReport, UncommittedDetails,
-    WorktreeState, WorktreeStatus,
+    BaseBranchDrift, BranchHealth, BranchState, Checkpoint, CheckpointTrigger, CleanKild,
+    CommitActivity, CompareSide, ComparedFile, ConflictStatus, DiffHunk, DiffLine, DiffLineKind,
+    DiffStats, FileChange, FileChangeKind, FileOverlap, GitProjectState, GitStats, KildComparison,
+    OverlapReport, ReviewDiff, ReviewFile, UncommittedDetails, WorktreeState, WorktreeStatus,
 };
 pub use validation::{
-    get_current_branch, is_valid_git_directory, should_use_current_branch, validate_branch_name,
-    validate_git_arg,
+    get_current_branch, is_valid_git_directory, normalize_sparse_path, should_use_current_branch,
+    validate_branch_name, validate_git_arg,
 };
@@ -127,7 +127,12 @@ pub fn has_uncommitted_changes(path: &Path) -> Option<bool> {
     opts.include_untracked(true);
     opts.include_ignored(false);
     match repo.statuses(Some(&mut opts)) {
-        Ok(statuses) => Some(!statuses.is_empty()),
+        Ok(statuses) => {
+            let skipped = crate::status::skip_worktree_paths(&repo);
+            Some(statuses.iter().any(|entry| {
+                entry.status() != git2::Status::WT_DELETED || !skipped.contains(entry.path_bytes())
+            }))
+        }
         Err(e) => {
             warn!(
                 event = "core.git.query.status_check_failed",
@@ -141,9 +146,21 @@ pub fn has_uncommitted_changes(path: &Path) -> Option<bool> {
 
 /// List all local branch names in the repository discovered from `path`.
 pub fn list_local_branch_names(path: &Path) -> Result<Vec<String>, GitError> {
+    list_branch_names(path, BranchType::Local)
+}
+
+/// List remote-tracking branch names (`origin/main`) in the repository
+/// discovered from `path`, without the symbolic `<remote>/HEAD` entries.
+pub fn list_remote_branch_names(path: &Path) -> Result<Vec<String>, GitError> {
+    let mut names = list_branch_names(path, BranchType::Remote)?;
+    names.retain(|name| !name.ends_with("/HEAD"));
+    Ok(names)
+}
+
+fn list_branch_names(path: &Path, branch_type: BranchType) -> Result<Vec<String>, GitError> {
     let repo = Repository::discover(path).map_err(|e| GitError::Git2Error { source: e })?;
     let branches = repo
-        .branches(Some(BranchType::Local))
+        .branches(Some(branch_type))
         .map_err(|e| GitError::Git2Error { source: e })?;
 
     let mut names = Vec::new();
@@ -488,6 +505,36 @@ mod tests {
         assert!(branches.contains(&"feature-a".to_string()));
     }
 
+    #[test]
+    fn test_list_remote_branch_names() {
+        let temp = TempDir::new().unwrap();
+        init_git_repo(temp.path());
+        create_initial_commit(temp.path());
+
+        for args in [
+            ["update-ref", "refs/remotes/origin/feature-b", "HEAD"],
+            [
+                "symbolic-ref",
+                "refs/remotes/origin/HEAD",
+                "refs/remotes/origin/feature-b",
+            ],
+        ] {
+            Command::new("git")
+                .args(args)
+                .current_dir(temp.path())
+                .output()
+                .unwrap();
+        }
+
+        let branches = list_remote_branch_names(temp.path()).unwrap();
+        assert_eq!(branches, vec!["origin/feature-b".to_string()]);
+        assert!(
+            !list_local_branch_names(temp.path())
+                .unwrap()
+                .contains(&"origin/feature-b".to_string())
+        );
+    }
+
     #[test]
     fn test_head_branch_name() {
         let temp = TempDir::new().unwrap();
@@ -0,0 +1,346 @@
+//! Reviewing a kild's changes against its base branch.
+//!
+//! The diff runs from the merge base with the base branch to a snapshot of the
+//! worktree, so committed, uncommitted and untracked changes all show up —
+//! what the PR will contain once the agent commits its work.
+
+use std::path::{Component, Path};
+
+use git2::build::CheckoutBuilder;
+use git2::{Delta, DiffOptions, Patch, Repository};
+use tracing::info;
+
+use crate::checkpoint::snapshot_tree;
+use crate::errors::GitError;
+use crate::health::{find_merge_base, resolve_branch_oid};
+use crate::types::{
+    DiffHunk, DiffLine, DiffLineKind, FileChange, FileChangeKind, ReviewDiff, ReviewFile,
+};
+
+/// Diff lines kept per file; larger diffs are truncated (`ReviewFile::truncated`).
+pub const MAX_REVIEW_LINES: usize = 5000;
+
+/// Collect every file a kild changed versus `base_branch`, with parsed hunks.
+pub fn review_diff(worktree_path: &Path, base_branch: &str) -> Result<ReviewDiff, GitError> {
+    info!(
+        event = "core.git.review_diff_started",
+        path = %worktree_path.display(),
+        base = base_branch
+    );
+
+    let repo = Repository::open(worktree_path)?;
+    let base_commit = merge_base_with(&repo, base_branch)?;
+    let base_tree = repo.find_commit(base_commit)?.tree()?;
+    let work_tree = repo.find_tree(snapshot_tree(&repo)?)?;
+
+    let mut opts = DiffOptions::new();
+    opts.context_lines(3);
+    let diff = repo.diff_tree_to_tree(Some(&base_tree), Some(&work_tree), Some(&mut opts))?;
+
+    let mut files = Vec::new();
+    for (idx, delta) in diff.deltas().enumerate() {
+        let kind = match delta.status() {
+            Delta::Added | Delta::Untracked => FileChangeKind::Added,
+            Delta::Deleted => FileChangeKind::Deleted,
+            _ => FileChangeKind::Modified,
+        };
+        let file = if kind == FileChangeKind::Deleted {
+            delta.old_file()
+        } else {
+            delta.new_file()
+        };
+        let Some(path) = file.path() else { continue };
+
+        let mut review = ReviewFile {
+            path: path.to_string_lossy().into_owned(),
+            change: FileChange {
+                kind,
+                insertions: 0,
+                deletions: 0,
+            },
+            binary: delta.flags().is_binary(),
+            truncated: false,
+            hunks: Vec::new(),
+        };
+        if let Some(patch) = Patch::from_diff(&diff, idx)? {
+            let (_, insertions, deletions) = patch.line_stats()?;
+            review.change.insertions = insertions;
+            review.change.deletions = deletions;
+            review.binary |= patch.delta().flags().is_binary();
+            if !review.binary {
+                (review.hunks, review.truncated) = patch_hunks(&patch)?;
+            }
+        }
+        files.push(review);
+    }
+    files.sort_by(|a, b| a.path.cmp(&b.path));
+
+    info!(
+        event = "core.git.review_diff_completed",
+        path = %worktree_path.display(),
+        files = files.len()
+    );
+    Ok(ReviewDiff {
+        base_commit: base_commit.to_string(),
+        files,
+    })
+}
+
+/// Undo a kild's change to one file: restore the base version, or delete a
+/// file the kild added. Updates both the working directory and the index.
+pub fn restore_file_to_base(
+    worktree_path: &Path,
+    base_branch: &str,
+    file: &str,
+) -> Result<(), GitError> {
+    let relative = Path::new(file);
+    if file.is_empty()
+        || !relative
+            .components()
+            .all(|c| matches!(c, Component::Normal(_)))
+    {
+        return Err(GitError::InvalidPath {
+            path: file.to_string(),
+            message: "must be relative to the repository root".to_string(),
+        });
+    }
+
+    info!(
+        event = "core.git.restore_file_started",
+        path = %worktree_path.display(),
+        file = file,
+        base = base_branch
+    );
+
+    let repo = Repository::open(worktree_path)?;
+    let base_commit = merge_base_with(&repo, base_branch)?;
+    let base_tree = repo.find_commit(base_commit)?.tree()?;
+    let target = worktree_path.join(relative);
+
+    match base_tree.get_path(relative) {
+        Ok(_) => {
+            // Checkout writes the blob with its file mode and updates the index
+            let mut checkout = CheckoutBuilder::new();
+            checkout.path(literal_pathspec(file)).force();
+            repo.checkout_tree(base_tree.as_object(), Some(&mut checkout))?;
+        }
+        Err(e) if e.code() == git2::ErrorCode::NotFound => {
+            if target.exists() {
+                std::fs::remove_file(&target)?;
+            }
+            let mut index = repo.index()?;
+            if index.get_path(relative, 0).is_some() {
+                index.remove_path(relative)?;
+                index.write()?;
+            }
+        }
+        Err(e) => return Err(e.into()),
+    }
+
+    info!(
+        event = "core.git.restore_file_completed",
+        path = %worktree_path.display(),
+        file = file
+    );
+    Ok(())
+}
+
+/// Escape glob characters so a checkout pathspec matches only `path` itself —
+/// `app/[id]/page.tsx` must not also match `app/i/page.tsx`.
+fn literal_pathspec(path: &str) -> String {
+    let mut escaped = String::with_capacity(path.len());
+    for c in path.chars() {
+        if matches!(c, '[' | ']' | '*' | '?' | '\\') {
+            escaped.push('\\');
+        }
+        escaped.push(c);
+    }
+    escaped
+}
+
+/// Merge base of the worktree's HEAD and `base_branch` (local, then origin).
+fn merge_base_with(repo: &Repository, base_branch: &str) -> Result<git2::Oid, GitError> {
+    let head = repo.head()?.peel_to_commit()?.id();
+    let base = resolve_branch_oid(repo, base_branch).ok_or_else(|| GitError::BranchNotFound {
+        branch: base_branch.to_string(),
+    })?;
+    find_merge_base(repo, head, base).ok_or_else(|| GitError::OperationFailed {
+        message: format!("no common ancestor with base branch '{}'", base_branch),
+    })
+}
+
+/// Parse a patch into hunks, stopping after [`MAX_REVIEW_LINES`] lines.
+fn patch_hunks(patch: &Patch<'_>) -> Result<(Vec<DiffHunk>, bool), GitError> {
+    let mut hunks = Vec::new();
+    let mut total = 0;
+    for hunk_idx in 0..patch.num_hunks() {
+        let (hunk, line_count) = patch.hunk(hunk_idx)?;
+        let mut lines = Vec::with_capacity(line_count);
+        for line_idx in 0..line_count {
+            if total == MAX_REVIEW_LINES {
+                hunks.push(DiffHunk {
+                    header: hunk_header(hunk.header()),
+                    lines,
+                });
+                return Ok((hunks, true));
+            }
+            let line = patch.line_in_hunk(hunk_idx, line_idx)?;
+            let kind = match line.origin() {
+                '+' => DiffLineKind::Added,
+                '-' => DiffLineKind::Removed,
+                ' ' => DiffLineKind::Context,
+                // "\ No newline at end of file" markers
+                _ => continue,
+            };
+            let content = String::from_utf8_lossy(line.content());
+            lines.push(DiffLine {
+                kind,
+                old_lineno: line.old_lineno(),
+                new_lineno: line.new_lineno(),
+                content: content.trim_end_matches(['\n', '\r']).to_string(),
+            });
+            total += 1;
+        }
+        hunks.push(DiffHunk {
+            header: hunk_header(hunk.header()),
+            lines,
+        });
+    }
+    Ok((hunks, false))
+}
+
+fn hunk_header(raw: &[u8]) -> String {
+    String::from_utf8_lossy(raw).trim_end().to_string()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_support::{
+        commit_all, create_branch, create_worktree_for_branch, git, init_repo_with_commit,
+    };
+    use std::fs;
+    use std::os::unix::fs::PermissionsExt;
+
+    /// Main repo on `main` with `lib.rs` and an executable `run.sh`
+    /// committed, plus a kild worktree.
+    fn setup() -> (tempfile::TempDir, std::path::PathBuf) {
+        let dir = tempfile::tempdir().unwrap();
+        let repo_path = dir.path().join("repo");
+        fs::create_dir_all(&repo_path).unwrap();
+        init_repo_with_commit(&repo_path).unwrap();
+        fs::write(repo_path.join("lib.rs"), "fn a() {}\nfn b() {}\n").unwrap();
+        fs::write(repo_path.join("run.sh"), "#!/bin/sh\n").unwrap();
+        fs::set_permissions(repo_path.join("run.sh"), fs::Permissions::from_mode(0o755)).unwrap();
+        commit_all(&repo_path, "base").unwrap();
+        create_branch(&repo_path, "base").unwrap();
+
+        let kild = dir.path().join("kild");
+        create_branch(&repo_path, "kild/work").unwrap();
+        create_worktree_for_branch(&repo_path, "work", &kild, "kild/work").unwrap();
+        (dir, kild)
+    }
+
+    #[test]
+    fn test_review_diff_includes_committed_and_uncommitted_changes() {
+        let (_dir, kild) = setup();
+        fs::write(kild.join("lib.rs"), "fn a() {}\nfn c() {}\n").unwrap();
+        commit_all(&kild, "edit").unwrap();
+        fs::write(kild.join("new.rs"), "fn new() {}\n").unwrap();
+
+        let review = review_diff(&kild, "base").unwrap();
+
+        let paths: Vec<_> = review.files.iter().map(|f| f.path.as_str()).collect();
+        assert_eq!(paths, vec!["lib.rs", "new.rs"]);
+
+        let lib = &review.files[0];
+        assert_eq!(lib.change.kind, FileChangeKind::Modified);
+        assert_eq!((lib.change.insertions, lib.change.deletions), (1, 1));
+        let lines = &lib.hunks[0].lines;
+        assert!(lib.hunks[0].header.starts_with("@@"));
+        assert_eq!(lines[0].kind, DiffLineKind::Context);
+        assert_eq!(lines[1].kind, DiffLineKind::Removed);
+        assert_eq!(lines[1].content, "fn b() {}");
+        assert_eq!(lines[1].old_lineno, Some(2));
+        assert_eq!(lines[2].kind, DiffLineKind::Added);
+        assert_eq!(lines[2].new_lineno, Some(2));
+
+        assert_eq!(review.files[1].change.kind, FileChangeKind::Added);
+    }
+
+    #[test]
+    fn test_review_diff_unknown_base_branch() {
+        let (_dir, kild) = setup();
+        let result = review_diff(&kild, "nope");
+        assert!(matches!(result, Err(GitError::BranchNotFound { .. })));
+    }
+
+    #[test]
+    fn test_restore_file_to_base_reverts_and_removes() {
+        let (_dir, kild) = setup();
+        fs::write(kild.join("lib.rs"), "changed\n").unwrap();
+        commit_all(&kild, "edit").unwrap();
+        fs::create_dir_all(kild.join("src")).unwrap();
+        fs::write(kild.join("src/added.rs"), "x\n").unwrap();
+
+        restore_file_to_base(&kild, "base", "lib.rs").unwrap();
+        restore_file_to_base(&kild, "base", "src/added.rs").unwrap();
+
+        assert_eq!(
+            fs::read_to_string(kild.join("lib.rs")).unwrap(),
+            "fn a() {}\nfn b() {}\n"
+        );
+        assert!(!kild.join("src/added.rs").exists());
+        assert!(review_diff(&kild, "base").unwrap().files.is_empty());
+    }
+
+    #[test]
+    fn test_restore_file_to_base_keeps_file_mode() {
+        let (_dir, kild) = setup();
+        let script = kild.join("run.sh");
+        fs::write(&script, "#!/bin/sh\nexit 1\n").unwrap();
+        fs::set_permissions(&script, fs::Permissions::from_mode(0o644)).unwrap();
+        commit_all(&kild, "edit script").unwrap();
+
+        restore_file_to_base(&kild, "base", "run.sh").unwrap();
+
+        assert_eq!(fs::read_to_string(&script).unwrap(), "#!/bin/sh\n");
+        let mode = fs::metadata(&script).unwrap().permissions().mode();
+        assert_eq!(mode & 0o111, 0o111, "mode {:o}", mode);
+        assert!(review_diff(&kild, "base").unwrap().files.is_empty());
+    }
+
+    #[test]
+    fn test_restore_file_to_base_treats_name_literally() {
+        let (_dir, kild) = setup();
+        let files = ["app/[id]/page.tsx", "app/i/page.tsx", "app/d/page.tsx"];
+        for file in files {
+            fs::create_dir_all(kild.join(file).parent().unwrap()).unwrap();
+            fs::write(kild.join(file), "base\n").unwrap();
+        }
+        commit_all(&kild, "routes").unwrap();
+        git(&kild, &["branch", "-f", "base", "HEAD"]);
+        for file in files {
+            fs::write(kild.join(file), "changed\n").unwrap();
+        }
+
+        restore_file_to_base(&kild, "base", "app/[id]/page.tsx").unwrap();
+
+        let read = |file: &str| fs::read_to_string(kild.join(file)).unwrap();
+        assert_eq!(read("app/[id]/page.tsx"), "base\n");
+        assert_eq!(read("app/i/page.tsx"), "changed\n");
+        assert_eq!(read("app/d/page.tsx"), "changed\n");
+    }
+
+    #[test]
+    fn test_restore_file_to_base_rejects_escaping_paths() {
+        let (_dir, kild) = setup();
+        for bad in ["", "../outside", "/etc/passwd", "a/../../b"] {
+            let result = restore_file_to_base(&kild, "base", bad);
+            assert!(
+                matches!(result, Err(GitError::InvalidPath { .. })),
+                "{bad:?} should be rejected"
+            );
+        }
+    }
+}
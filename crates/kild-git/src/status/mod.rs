@@ -3,6 +3,7 @@ mod stats;
 mod worktree;
 
 pub use stats::collect_git_stats;
+pub(crate) use worktree::skip_worktree_paths;
 pub use worktree::{get_diff_stats, get_worktree_status};
 
 // Re-export for internal use by sibling submodules
@@ -1,6 +1,7 @@
+use std::collections::HashSet;
 use std::path::Path;
 
-use git2::{Repository, Status, StatusOptions};
+use git2::{Delta, Patch, Repository, Status, StatusOptions};
 use tracing::warn;
 
 use crate::errors::GitError;
@@ -12,7 +13,8 @@ use super::count_unpushed_commits;
 ///
 /// Returns the number of insertions, deletions, and files changed
 /// between the index (staging area) and the working directory.
-/// This does not include staged changes.
+/// This does not include staged changes. Files outside a sparse checkout's
+/// cone are not counted as deleted.
 ///
 /// # Errors
 ///
@@ -25,15 +27,62 @@ pub fn get_diff_stats(worktree_path: &Path) -> Result<DiffStats, GitError> {
         .diff_index_to_workdir(None, None)
         .map_err(|e| GitError::Git2Error { source: e })?;
 
-    let stats = diff
-        .stats()
-        .map_err(|e| GitError::Git2Error { source: e })?;
+    let skipped = skip_worktree_paths(&repo);
+    if skipped.is_empty() {
+        let stats = diff
+            .stats()
+            .map_err(|e| GitError::Git2Error { source: e })?;
+
+        return Ok(DiffStats {
+            insertions: stats.insertions(),
+            deletions: stats.deletions(),
+            files_changed: stats.files_changed(),
+        });
+    }
 
-    Ok(DiffStats {
-        insertions: stats.insertions(),
-        deletions: stats.deletions(),
-        files_changed: stats.files_changed(),
-    })
+    let mut stats = DiffStats {
+        insertions: 0,
+        deletions: 0,
+        files_changed: 0,
+    };
+    for (idx, delta) in diff.deltas().enumerate() {
+        if is_sparse_deletion(delta.status(), delta.old_file().path_bytes(), &skipped) {
+            continue;
+        }
+        if let Some(patch) =
+            Patch::from_diff(&diff, idx).map_err(|e| GitError::Git2Error { source: e })?
+        {
+            let (_, insertions, deletions) = patch
+                .line_stats()
+                .map_err(|e| GitError::Git2Error { source: e })?;
+            stats.insertions += insertions;
+            stats.deletions += deletions;
+        }
+        stats.files_changed += 1;
+    }
+    Ok(stats)
+}
+
+/// Index paths carrying the skip-worktree bit, i.e. outside a sparse
+/// checkout's cone. libgit2 doesn't know about sparse checkouts and reports
+/// these as deleted from the working directory.
+pub(crate) fn skip_worktree_paths(repo: &Repository) -> HashSet<Vec<u8>> {
+    // GIT_INDEX_ENTRY_SKIP_WORKTREE
+    const SKIP_WORKTREE: u16 = 1 << 14;
+
+    repo.index()
+        .map(|index| {
+            index
+                .iter()
+                .filter(|entry| entry.flags_extended & SKIP_WORKTREE != 0)
+                .map(|entry| entry.path)
+                .collect()
+        })
+        .unwrap_or_default()
+}
+
+fn is_sparse_deletion(delta: Delta, path: Option<&[u8]>, skipped: &HashSet<Vec<u8>>) -> bool {
+    delta == Delta::Deleted && path.is_some_and(|p| skipped.contains(p))
 }
 
 /// Get comprehensive worktree status for destroy safety checks.
@@ -108,9 +157,13 @@ pub(super) fn check_uncommitted_changes(repo: &Repository) -> (Option<Uncommitte
     let mut staged_files = 0;
     let mut modified_files = 0;
     let mut untracked_files = 0;
+    let skipped = skip_worktree_paths(repo);
 
     for entry in statuses.iter() {
         let status = entry.status();
+        if status == Status::WT_DELETED && skipped.contains(entry.path_bytes()) {
+            continue;
+        }
 
         // Check for staged changes (index changes)
         if status.intersects(
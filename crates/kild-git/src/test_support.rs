@@ -5,7 +5,7 @@
 
 use std::path::Path;
 
-use git2::{BranchType, Repository, WorktreeAddOptions};
+use git2::{BranchType, IndexAddOption, Oid, Repository, WorktreeAddOptions};
 
 use crate::errors::GitError;
 
@@ -58,3 +58,52 @@ pub fn create_worktree_for_branch(
         .map_err(|e| GitError::Git2Error { source: e })?;
     Ok(())
 }
+
+/// Stage every change in the working directory (including deletions) and
+/// commit it on HEAD.
+pub fn commit_all(dir: &Path, message: &str) -> Result<Oid, GitError> {
+    let repo = Repository::open(dir).map_err(|e| GitError::Git2Error { source: e })?;
+    let mut index = repo
+        .index()
+        .map_err(|e| GitError::Git2Error { source: e })?;
+    index
+        .add_all(["*"], IndexAddOption::DEFAULT, None)
+        .and_then(|()| index.update_all(["*"], None))
+        .and_then(|()| index.write())
+        .map_err(|e| GitError::Git2Error { source: e })?;
+    let tree_id = index
+        .write_tree()
+        .map_err(|e| GitError::Git2Error { source: e })?;
+    let tree = repo
+        .find_tree(tree_id)
+        .map_err(|e| GitError::Git2Error { source: e })?;
+    let sig = git2::Signature::now("Test", "test@test.com")
+        .map_err(|e| GitError::Git2Error { source: e })?;
+    let parent = repo
+        .head()
+        .and_then(|head| head.peel_to_commit())
+        .map_err(|e| GitError::Git2Error { source: e })?;
+    repo.commit(Some("HEAD"), &sig, &sig, message, &tree, &[&parent])
+        .map_err(|e| GitError::Git2Error { source: e })
+}
+
+/// Run the `git` CLI in `dir` with a fixed test identity and return its
+/// trimmed stdout.
+///
+/// For fixtures git2 can't build easily (sparse checkouts, bundles,
+/// cherry-picks). Panics if git fails.
+pub fn git(dir: &Path, args: &[&str]) -> String {
+    let output = std::process::Command::new("git")
+        .current_dir(dir)
+        .args(["-c", "user.name=Test", "-c", "user.email=test@test.com"])
+        .args(args)
+        .output()
+        .expect("failed to run git");
+    assert!(
+        output.status.success(),
+        "git {:?} failed: {}",
+        args,
+        String::from_utf8_lossy(&output.stderr)
+    );
+    String::from_utf8_lossy(&output.stdout).trim().to_string()
+}
@@ -315,6 +315,199 @@ pub struct OverlapReport {
     pub clean_kilds: Vec<CleanKild>,
 }
 
+/// What caused a checkpoint to be recorded.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum CheckpointTrigger {
+    /// `kild checkpoint`.
+    Manual,
+    /// Automatic checkpoint before `kild inject`.
+    Inject,
+    /// Automatic checkpoint when the agent finished its turn.
+    Stop,
+    /// Current state saved by `kild rollback` before restoring.
+    Rollback,
+}
+
+impl CheckpointTrigger {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            CheckpointTrigger::Manual => "manual",
+            CheckpointTrigger::Inject => "inject",
+            CheckpointTrigger::Stop => "stop",
+            CheckpointTrigger::Rollback => "rollback",
+        }
+    }
+
+    pub fn parse(s: &str) -> Option<Self> {
+        match s {
+            "manual" => Some(CheckpointTrigger::Manual),
+            "inject" => Some(CheckpointTrigger::Inject),
+            "stop" => Some(CheckpointTrigger::Stop),
+            "rollback" => Some(CheckpointTrigger::Rollback),
+            _ => None,
+        }
+    }
+
+    /// Whether kild recorded this checkpoint on its own (subject to pruning).
+    pub fn is_automatic(&self) -> bool {
+        matches!(self, CheckpointTrigger::Inject | CheckpointTrigger::Stop)
+    }
+}
+
+impl std::fmt::Display for CheckpointTrigger {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+/// A snapshot of a kild's worktree stored under `refs/kild/checkpoints/<branch>/<id>`.
+///
+/// The snapshot is a commit whose parent is the branch HEAD at the time it was
+/// taken and whose tree includes uncommitted and untracked (non-ignored) files.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct Checkpoint {
+    /// Per-branch sequence number, starting at 1.
+    pub id: u32,
+    pub branch: String,
+    /// Snapshot commit SHA.
+    pub commit: String,
+    /// Branch HEAD the snapshot was taken on top of.
+    pub base_commit: String,
+    pub message: String,
+    pub trigger: CheckpointTrigger,
+    /// RFC 3339 timestamp.
+    pub created_at: String,
+}
+
+/// How a file differs from the comparison base.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum FileChangeKind {
+    Added,
+    Modified,
+    Deleted,
+}
+
+/// One kild's change to a file in a [`KildComparison`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+pub struct FileChange {
+    pub kind: FileChangeKind,
+    pub insertions: usize,
+    pub deletions: usize,
+}
+
+/// A file changed by at least one of the compared kilds.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct ComparedFile {
+    /// Path relative to the repository root.
+    pub path: String,
+    pub left: Option<FileChange>,
+    pub right: Option<FileChange>,
+    /// Both kilds changed the file and ended up with the same content.
+    pub identical: bool,
+}
+
+/// Per-kild totals in a [`KildComparison`].
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct CompareSide {
+    /// Branch name (user-facing, not kild/ prefixed).
+    pub branch: String,
+    pub head_commit: String,
+    /// Commits on this kild's branch since the comparison base.
+    pub commits_ahead: usize,
+    /// Changes since the comparison base, including uncommitted files.
+    pub diff: DiffStats,
+}
+
+/// Summary of how two kilds' worktrees differ from their common ancestor.
+///
+/// Invariants (enforced at construction in `compare_worktrees`):
+/// - `files` is sorted by path
+/// - every entry has `left` or `right` set
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct KildComparison {
+    /// Merge base of the two branches.
+    pub base_commit: String,
+    pub left: CompareSide,
+    pub right: CompareSide,
+    pub files: Vec<ComparedFile>,
+}
+
+impl KildComparison {
+    /// Files changed by both kilds.
+    pub fn shared(&self) -> impl Iterator<Item = &ComparedFile> {
+        self.files
+            .iter()
+            .filter(|f| f.left.is_some() && f.right.is_some())
+    }
+
+    /// Files changed only by the left kild.
+    pub fn left_only(&self) -> impl Iterator<Item = &ComparedFile> {
+        self.files
+            .iter()
+            .filter(|f| f.left.is_some() && f.right.is_none())
+    }
+
+    /// Files changed only by the right kild.
+    pub fn right_only(&self) -> impl Iterator<Item = &ComparedFile> {
+        self.files
+            .iter()
+            .filter(|f| f.left.is_none() && f.right.is_some())
+    }
+}
+
+/// Kind of a line in a [`DiffHunk`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum DiffLineKind {
+    Context,
+    Added,
+    Removed,
+}
+
+/// One line of a unified diff, without its trailing newline.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct DiffLine {
+    pub kind: DiffLineKind,
+    /// Line number on the base side (`None` for added lines).
+    pub old_lineno: Option<u32>,
+    /// Line number on the kild side (`None` for removed lines).
+    pub new_lineno: Option<u32>,
+    pub content: String,
+}
+
+/// A hunk of a unified diff.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct DiffHunk {
+    /// `@@ -a,b +c,d @@ ...` header, trimmed.
+    pub header: String,
+    pub lines: Vec<DiffLine>,
+}
+
+/// A file changed by a kild, with its diff against the base.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct ReviewFile {
+    /// Path relative to the repository root.
+    pub path: String,
+    pub change: FileChange,
+    /// Binary files carry no hunks.
+    pub binary: bool,
+    /// Hunks were cut off at `MAX_REVIEW_LINES` lines.
+    pub truncated: bool,
+    pub hunks: Vec<DiffHunk>,
+}
+
+/// Everything a kild changed versus its base branch, ready for review.
+///
+/// Covers committed, uncommitted and untracked changes. `files` is sorted by path.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct ReviewDiff {
+    /// Merge base of the kild's HEAD and the base branch.
+    pub base_commit: String,
+    pub files: Vec<ReviewFile>,
+}
+
 impl GitProjectState {
     pub fn new(id: String, name: String, path: PathBuf, remote_url: Option<String>) -> Self {
         Self {
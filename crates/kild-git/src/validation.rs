@@ -51,6 +51,39 @@ pub fn validate_git_arg(value: &str, label: &str) -> Result<(), GitError> {
     Ok(())
 }
 
+/// Normalize a sparse-checkout path to the directory form cone mode expects.
+///
+/// Accepts `services/billing`, `services/billing/` and `services/billing/**`
+/// (all become `services/billing`). Rejects other globs, absolute-looking
+/// paths that escape the repo, and option-like values.
+pub fn normalize_sparse_path(path: &str) -> Result<String, GitError> {
+    let invalid = |message: &str| GitError::InvalidSparsePath {
+        path: path.to_string(),
+        message: message.to_string(),
+    };
+
+    let mut normalized = path.trim().trim_start_matches("./").trim_start_matches('/');
+    for suffix in ["/**", "/*", "/"] {
+        normalized = normalized.strip_suffix(suffix).unwrap_or(normalized);
+    }
+    let normalized = normalized.trim_end_matches('/');
+
+    if normalized.is_empty() {
+        return Err(invalid("path is empty"));
+    }
+    if normalized.contains(['*', '?', '[', '\\', '!']) {
+        return Err(invalid("cone mode takes directories, not globs"));
+    }
+    if normalized
+        .split('/')
+        .any(|part| part == ".." || part == ".")
+    {
+        return Err(invalid("path must stay inside the repository"));
+    }
+    validate_git_arg(normalized, "sparse path").map_err(|_| invalid("not a plain path"))?;
+    Ok(normalized.to_string())
+}
+
 /// Gets the current branch name from the repository.
 ///
 /// Returns `None` if the repository is in a detached HEAD state.
@@ -99,6 +132,25 @@ mod tests {
         assert!(validate_branch_name("branch\nname").is_err());
     }
 
+    #[test]
+    fn test_normalize_sparse_path() {
+        assert_eq!(
+            normalize_sparse_path("services/billing/**").unwrap(),
+            "services/billing"
+        );
+        assert_eq!(
+            normalize_sparse_path("./libs/shared/").unwrap(),
+            "libs/shared"
+        );
+        assert_eq!(normalize_sparse_path("docs").unwrap(), "docs");
+
+        assert!(normalize_sparse_path("").is_err());
+        assert!(normalize_sparse_path("/**").is_err());
+        assert!(normalize_sparse_path("src/*.rs").is_err());
+        assert!(normalize_sparse_path("../outside").is_err());
+        assert!(normalize_sparse_path("-evil").is_err());
+    }
+
     #[test]
     fn test_is_valid_git_directory() {
         // This will fail in most test environments, but tests the logic
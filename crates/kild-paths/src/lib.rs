@@ -79,12 +79,34 @@ impl KildPaths {
         self.inbox_project_dir(project_id).join(safe_branch)
     }
 
+    // --- Shared build caches ---
+
+    pub fn cache_dir(&self) -> PathBuf {
+        self.kild_dir.join("cache")
+    }
+
+    pub fn project_cache_dir(&self, project_id: &str) -> PathBuf {
+        self.cache_dir().join(project_id)
+    }
+
+    // --- Export/import ---
+
+    /// Where `kild import` keeps dirty-state patches it could not apply.
+    pub fn imports_dir(&self) -> PathBuf {
+        self.kild_dir.join("imports")
+    }
+
     // --- Top-level files ---
 
     pub fn daemon_socket(&self) -> PathBuf {
         self.kild_dir.join("daemon.sock")
     }
 
+    /// Remote-control socket of the kitty instance kild launches.
+    pub fn kitty_socket(&self) -> PathBuf {
+        self.kild_dir.join("kitty.sock")
+    }
+
     pub fn tls_cert_path(&self) -> PathBuf {
         self.kild_dir.join("certs").join("daemon.crt")
     }
@@ -113,6 +135,26 @@ impl KildPaths {
         self.kild_dir.join("keybindings.toml")
     }
 
+    /// Pane grid layouts saved by kild-ui (per project, plus named layouts).
+    pub fn ui_layouts_file(&self) -> PathBuf {
+        self.kild_dir.join("layouts.json")
+    }
+
+    /// User color themes for kild-ui (`<name>.toml`).
+    pub fn ui_themes_dir(&self) -> PathBuf {
+        self.kild_dir.join("themes")
+    }
+
+    /// Activity feed entries persisted by kild-ui across restarts.
+    pub fn ui_activity_file(&self) -> PathBuf {
+        self.kild_dir.join("activity.json")
+    }
+
+    /// Last-used create dialog options in kild-ui, per project.
+    pub fn ui_create_options_file(&self) -> PathBuf {
+        self.kild_dir.join("create_options.json")
+    }
+
     // --- Parameterized paths ---
 
     pub fn shim_session_dir(&self, session_id: &str) -> PathBuf {
@@ -265,6 +307,14 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_kitty_socket() {
+        assert_eq!(
+            test_paths().kitty_socket(),
+            PathBuf::from("/home/user/.kild/kitty.sock")
+        );
+    }
+
     #[test]
     fn test_tls_cert_path() {
         assert_eq!(
@@ -505,6 +555,38 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_ui_layouts_file() {
+        assert_eq!(
+            test_paths().ui_layouts_file(),
+            PathBuf::from("/home/user/.kild/layouts.json")
+        );
+    }
+
+    #[test]
+    fn test_ui_themes_dir() {
+        assert_eq!(
+            test_paths().ui_themes_dir(),
+            PathBuf::from("/home/user/.kild/themes")
+        );
+    }
+
+    #[test]
+    fn test_ui_activity_file() {
+        assert_eq!(
+            test_paths().ui_activity_file(),
+            PathBuf::from("/home/user/.kild/activity.json")
+        );
+    }
+
+    #[test]
+    fn test_ui_create_options_file() {
+        assert_eq!(
+            test_paths().ui_create_options_file(),
+            PathBuf::from("/home/user/.kild/create_options.json")
+        );
+    }
+
     #[test]
     fn test_project_keybindings() {
         assert_eq!(
@@ -513,6 +595,18 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_project_cache_dir() {
+        assert_eq!(
+            test_paths().cache_dir(),
+            PathBuf::from("/home/user/.kild/cache")
+        );
+        assert_eq!(
+            test_paths().project_cache_dir("abc123"),
+            PathBuf::from("/home/user/.kild/cache/abc123")
+        );
+    }
+
     #[test]
     fn test_inbox_base_dir() {
         assert_eq!(
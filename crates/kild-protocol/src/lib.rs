@@ -18,6 +18,6 @@ pub use async_client::AsyncIpcClient;
 pub use client::{IpcConnection, IpcError};
 pub use messages::{ClientMessage, DaemonMessage, ErrorCode};
 pub use types::{
-    AgentMode, AgentStatus, BranchName, DaemonSessionStatus, ForgeType, OpenMode, ProjectId,
-    RuntimeMode, SessionId, SessionStatus,
+    AgentMode, AgentStatus, ApprovalDecision, BranchName, DaemonSessionStatus, ForgeType, OpenMode,
+    PendingApproval, ProjectId, RuntimeMode, SessionId, SessionStatus,
 };
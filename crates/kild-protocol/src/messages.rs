@@ -2,7 +2,7 @@ use std::collections::HashMap;
 
 use serde::{Deserialize, Serialize};
 
-use crate::types::{DaemonSessionStatus, ProjectId, SessionId};
+use crate::types::{ApprovalDecision, DaemonSessionStatus, PendingApproval, ProjectId, SessionId};
 
 /// Error codes returned by the daemon in error responses.
 ///
@@ -27,6 +27,7 @@ pub enum ErrorCode {
     SerializationError,
     Base64DecodeError,
     SessionError,
+    ApprovalNotFound,
     #[serde(other)]
     Unknown,
 }
@@ -57,6 +58,7 @@ impl std::fmt::Display for ErrorCode {
             ErrorCode::SerializationError => write!(f, "serialization_error"),
             ErrorCode::Base64DecodeError => write!(f, "base64_decode_error"),
             ErrorCode::SessionError => write!(f, "session_error"),
+            ErrorCode::ApprovalNotFound => write!(f, "approval_not_found"),
             ErrorCode::Unknown => write!(f, "unknown"),
         }
     }
@@ -157,6 +159,21 @@ pub enum ClientMessage {
 
     #[serde(rename = "ping")]
     Ping { id: String },
+
+    /// List agent permission requests waiting for a decision.
+    #[serde(rename = "list_approvals")]
+    ListApprovals { id: String },
+
+    /// Resolve a pending permission request and return the decision to the agent.
+    #[serde(rename = "resolve_approval")]
+    ResolveApproval {
+        id: String,
+        approval_id: String,
+        decision: ApprovalDecision,
+        /// Reason shown to the agent on deny.
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        message: Option<String>,
+    },
 }
 
 /// Daemon -> Client response and streaming messages.
@@ -225,6 +242,12 @@ pub enum DaemonMessage {
 
     #[serde(rename = "ack")]
     Ack { id: String },
+
+    #[serde(rename = "approval_list")]
+    ApprovalList {
+        id: String,
+        approvals: Vec<PendingApproval>,
+    },
 }
 
 fn default_rows() -> u16 {
@@ -250,7 +273,9 @@ impl ClientMessage {
             | ClientMessage::GetSession { id, .. }
             | ClientMessage::ReadScrollback { id, .. }
             | ClientMessage::DaemonStop { id, .. }
-            | ClientMessage::Ping { id, .. } => id,
+            | ClientMessage::Ping { id, .. }
+            | ClientMessage::ListApprovals { id, .. }
+            | ClientMessage::ResolveApproval { id, .. } => id,
         }
     }
 }
@@ -379,6 +404,15 @@ mod tests {
             ClientMessage::Ping {
                 id: "11".to_string(),
             },
+            ClientMessage::ListApprovals {
+                id: "12".to_string(),
+            },
+            ClientMessage::ResolveApproval {
+                id: "13".to_string(),
+                approval_id: "a1".to_string(),
+                decision: ApprovalDecision::Deny,
+                message: Some("not now".to_string()),
+            },
         ];
 
         for msg in messages {
@@ -401,6 +435,9 @@ mod tests {
                 client_count: None,
                 pty_pid: None,
                 exit_code: None,
+                last_output_at: None,
+                last_input_at: None,
+                output_bytes: None,
             },
         };
         let json = serde_json::to_string(&msg).unwrap();
@@ -450,6 +487,47 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_daemon_message_approval_list_roundtrip() {
+        let msg = DaemonMessage::ApprovalList {
+            id: "req-020".to_string(),
+            approvals: vec![PendingApproval {
+                id: "a1".to_string(),
+                branch: "feat".to_string(),
+                agent: "claude".to_string(),
+                tool: "Bash".to_string(),
+                summary: "cargo test".to_string(),
+                details: None,
+                requested_at: "2026-01-01T00:00:00Z".to_string(),
+            }],
+        };
+        let json = serde_json::to_string(&msg).unwrap();
+        assert!(json.contains(r#""type":"approval_list""#));
+        assert!(!json.contains("details"));
+        let parsed: DaemonMessage = serde_json::from_str(&json).unwrap();
+        match parsed {
+            DaemonMessage::ApprovalList { approvals, .. } => {
+                assert_eq!(approvals[0].summary, "cargo test");
+            }
+            _ => panic!("expected ApprovalList"),
+        }
+    }
+
+    #[test]
+    fn test_client_message_resolve_approval_wire_format() {
+        let json = r#"{"type":"resolve_approval","id":"r","approval_id":"a1","decision":"allow"}"#;
+        let parsed: ClientMessage = serde_json::from_str(json).unwrap();
+        match parsed {
+            ClientMessage::ResolveApproval {
+                decision, message, ..
+            } => {
+                assert_eq!(decision, ApprovalDecision::Allow);
+                assert!(message.is_none());
+            }
+            _ => panic!("expected ResolveApproval"),
+        }
+    }
+
     #[test]
     fn test_error_code_from_code() {
         assert_eq!(
@@ -153,6 +153,57 @@ pub struct DaemonSessionStatus {
     pub pty_pid: Option<u32>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub exit_code: Option<i32>,
+    /// RFC3339 time the PTY last produced output. `None` if it never has.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub last_output_at: Option<String>,
+    /// RFC3339 time input was last written to the PTY. `None` if never.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub last_input_at: Option<String>,
+    /// Total bytes the PTY has output since it started. Keeps growing after
+    /// the scrollback is full. `None` from daemons that don't report it.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub output_bytes: Option<u64>,
+}
+
+/// User decision on a pending agent permission request.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum ApprovalDecision {
+    Allow,
+    Deny,
+}
+
+impl std::fmt::Display for ApprovalDecision {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Allow => write!(f, "allow"),
+            Self::Deny => write!(f, "deny"),
+        }
+    }
+}
+
+/// An agent permission request queued in the daemon's approval broker.
+///
+/// Created when an agent hook reports a permission prompt (Claude Code
+/// `PermissionRequest`, Codex `approval-requested`) and removed once a user
+/// resolves it, the agent gives up waiting, or the session goes away.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct PendingApproval {
+    /// Broker-assigned ID, unique for the daemon's lifetime.
+    pub id: String,
+    /// Kild branch the request came from.
+    pub branch: String,
+    /// Agent that asked ("claude", "codex").
+    pub agent: String,
+    /// Tool the agent wants to run (e.g. "Bash", "Edit").
+    pub tool: String,
+    /// Human-readable subject: the command, file path, or URL.
+    pub summary: String,
+    /// Raw tool input as reported by the agent.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub details: Option<serde_json::Value>,
+    /// RFC3339 time the request was queued.
+    pub requested_at: String,
 }
 
 /// Agent-reported activity status, written via `kild agent-status` command.
@@ -262,6 +313,9 @@ mod tests {
             client_count: Some(2),
             pty_pid: Some(12345),
             exit_code: None,
+            last_output_at: None,
+            last_input_at: None,
+            output_bytes: None,
         };
         let json = serde_json::to_string(&info).unwrap();
         assert!(json.contains(r#""status":"running""#));
@@ -283,6 +337,9 @@ mod tests {
             client_count: None,
             pty_pid: None,
             exit_code: None,
+            last_output_at: None,
+            last_input_at: None,
+            output_bytes: None,
         };
         let json = serde_json::to_string(&info).unwrap();
         assert!(!json.contains("client_count"));
@@ -301,6 +358,9 @@ mod tests {
             client_count: None,
             pty_pid: None,
             exit_code: Some(1),
+            last_output_at: None,
+            last_input_at: None,
+            output_bytes: None,
         };
         let json = serde_json::to_string(&info).unwrap();
         assert!(json.contains("\"exit_code\":1"));
@@ -317,12 +377,41 @@ mod tests {
             client_count: None,
             pty_pid: None,
             exit_code: Some(127),
+            last_output_at: None,
+            last_input_at: None,
+            output_bytes: None,
         };
         let json = serde_json::to_string(&info).unwrap();
         let parsed: DaemonSessionStatus = serde_json::from_str(&json).unwrap();
         assert_eq!(parsed.exit_code, Some(127));
     }
 
+    #[test]
+    fn test_daemon_session_status_activity_timestamps_roundtrip() {
+        let info = DaemonSessionStatus {
+            id: SessionId::new("test"),
+            working_directory: "/tmp".to_string(),
+            command: "claude".to_string(),
+            status: SessionStatus::Running,
+            created_at: "2026-02-09T14:30:00Z".to_string(),
+            client_count: None,
+            pty_pid: None,
+            exit_code: None,
+            last_output_at: Some("2026-02-09T14:31:00Z".to_string()),
+            last_input_at: None,
+            output_bytes: None,
+        };
+        let json = serde_json::to_string(&info).unwrap();
+        assert!(json.contains("last_output_at"));
+        assert!(!json.contains("last_input_at"));
+        let parsed: DaemonSessionStatus = serde_json::from_str(&json).unwrap();
+        assert_eq!(
+            parsed.last_output_at.as_deref(),
+            Some("2026-02-09T14:31:00Z")
+        );
+        assert!(parsed.last_input_at.is_none());
+    }
+
     #[test]
     fn test_session_status_display() {
         assert_eq!(SessionStatus::Creating.to_string(), "creating");
@@ -5,7 +5,10 @@
 
 use std::path::PathBuf;
 
-use kild_core::{Command, CoreStore, Event, KildConfig, OpenMode, Store, session_ops};
+use kild_core::{
+    Command, CoreStore, CreateKildOptions, CreateSessionRequest, Event, KildConfig, OpenMode,
+    Store, session_ops,
+};
 
 use kild_core::SessionSnapshot;
 
@@ -38,7 +41,42 @@ fn dispatch_command(command: Command, event_prefix: &str) -> Result<Vec<Event>,
     }
 }
 
-/// Create a new kild with the given branch name, agent, optional note, and optional project path.
+/// Check create dialog input with the same rules `kild create` applies.
+///
+/// Pure and fast, so the dialog can report mistakes before going to the
+/// background executor.
+pub fn validate_create(
+    branch: &str,
+    agent: &str,
+    options: &CreateKildOptions,
+) -> Result<(), String> {
+    // `kild create` rejects `--initial-prompt` with `--no-daemon` at parse time
+    if options.initial_prompt.is_some()
+        && options.runtime_mode == Some(kild_core::RuntimeMode::Terminal)
+    {
+        tracing::warn!(
+            event = "ui.create_dialog.validation_failed",
+            reason = "initial prompt with terminal runtime"
+        );
+        return Err("An initial prompt needs the daemon runtime".to_string());
+    }
+    let request =
+        CreateSessionRequest::new(branch, kild_core::AgentMode::Agent(agent.to_string()), None)
+            .with_issue(options.issue)
+            .with_base_branch(options.base_branch.clone())
+            .with_main_worktree(options.use_main_worktree)
+            .with_initial_prompt(options.initial_prompt.clone());
+    kild_core::sessions::validation::validate_create_request(&request).map_err(|e| {
+        tracing::warn!(
+            event = "ui.create_dialog.validation_failed",
+            reason = %e
+        );
+        e.to_string()
+    })
+}
+
+/// Create a new kild with the given branch name, agent, optional note, optional project path
+/// and the remaining `kild create` options.
 ///
 /// When `project_path` is provided (UI context), detects project from that path.
 /// When `None` (shouldn't happen in UI), falls back to current working directory detection.
@@ -50,13 +88,15 @@ pub fn create_kild(
     agent: String,
     note: Option<String>,
     project_path: Option<PathBuf>,
+    options: CreateKildOptions,
 ) -> Result<Vec<Event>, String> {
     tracing::info!(
         event = "ui.create_kild.started",
         branch = %branch,
         agent = %agent,
         note = ?note,
-        project_path = ?project_path
+        project_path = ?project_path,
+        options = ?options
     );
 
     if branch.trim().is_empty() {
@@ -74,6 +114,7 @@ pub fn create_kild(
             agent_mode: kild_core::AgentMode::Agent(agent),
             note,
             project_path,
+            options,
         },
         "ui.create_kild",
     )
@@ -168,6 +209,212 @@ pub fn stop_kild(branch: String) -> Result<Vec<Event>, String> {
     )
 }
 
+/// Send text to the agent running in a kild, like `kild inject`.
+///
+/// Writes the task inbox, then delivers via the Claude inbox or the daemon PTY.
+/// Takes owned parameters so this function can be called from background threads.
+pub fn inject_text(branch: String, text: String) -> Result<(), String> {
+    use kild_core::agents::is_claude_agent;
+    use kild_core::sessions::delivery::{self, DeliveryOptions, DeliveryState};
+    use kild_core::sessions::{checkpoints, fleet, inbox};
+
+    tracing::info!(event = "ui.inject_text.started", branch = %branch);
+
+    if text.trim().is_empty() {
+        return Err("Inject text cannot be empty".to_string());
+    }
+
+    let mut session = session_ops::get_session(&branch).map_err(|e| e.to_string())?;
+    session_ops::sync_daemon_session_status(&mut session);
+    if session.status != kild_core::SessionStatus::Active {
+        return Err(format!(
+            "Kild '{}' is not running. Open it before injecting text.",
+            branch
+        ));
+    }
+
+    checkpoints::auto_checkpoint(&branch, kild_core::CheckpointTrigger::Inject);
+
+    if let Err(e) = inbox::write_task(&session.project_id, &session.branch, &text) {
+        tracing::warn!(event = "ui.inject_text.inbox_write_failed", branch = %branch, error = %e);
+    }
+
+    let result = if is_claude_agent(&session.agent) {
+        fleet::write_to_inbox(fleet::BRAIN_BRANCH, &fleet::fleet_safe_name(&branch), &text)
+    } else {
+        let daemon_session_id = session
+            .latest_agent()
+            .and_then(|a| a.daemon_session_id())
+            .ok_or_else(|| format!("Kild '{}' has no daemon terminal to type into.", branch))?;
+        let report = delivery::deliver_to_daemon_session(
+            &session,
+            daemon_session_id,
+            &text,
+            &DeliveryOptions::default(),
+        );
+        match report.state {
+            DeliveryState::Delivered | DeliveryState::Unverified => Ok(()),
+            _ => Err(format!(
+                "Undelivered: {}. The task is still in the inbox.",
+                report.reason.as_deref().unwrap_or("unknown reason")
+            )),
+        }
+    };
+
+    match &result {
+        Ok(()) => tracing::info!(event = "ui.inject_text.completed", branch = %branch),
+        Err(e) => tracing::error!(event = "ui.inject_text.failed", branch = %branch, error = %e),
+    }
+    result
+}
+
+/// Open a kild's pull request in the browser.
+///
+/// Uses the cached PR sidecar when present; otherwise refreshes it through
+/// `Command::RefreshPrStatus` first.
+pub fn open_pr(branch: String) -> Result<(), String> {
+    tracing::info!(event = "ui.open_pr.started", branch = %branch);
+
+    let session = session_ops::get_session(&branch).map_err(|e| e.to_string())?;
+    let pr = match session_ops::read_pr_info(&session.id) {
+        Some(pr) => pr,
+        None => {
+            dispatch_command(
+                Command::RefreshPrStatus {
+                    branch: branch.clone().into(),
+                },
+                "ui.refresh_pr_status",
+            )?;
+            session_ops::read_pr_info(&session.id)
+                .ok_or_else(|| format!("No pull request found for '{}'.", branch))?
+        }
+    };
+
+    open::that(&pr.url).map_err(|e| {
+        tracing::error!(event = "ui.open_pr.failed", branch = %branch, error = %e);
+        format!("Failed to open {}: {}", pr.url, e)
+    })?;
+    tracing::info!(event = "ui.open_pr.completed", branch = %branch, number = pr.number);
+    Ok(())
+}
+
+/// Open a file at a line in the configured editor.
+///
+/// Unlike [`open_in_editor`], this goes through `kild_core::editor`, so the
+/// `[editor]` config, `$VISUAL`/`$EDITOR` and auto-detection all apply.
+/// Used for `file:line` links in terminal output.
+pub fn open_file_at(
+    path: &std::path::Path,
+    position: kild_core::editor::FilePosition,
+) -> Result<(), String> {
+    let config = KildConfig::load_hierarchy().map_err(|e| {
+        tracing::error!(event = "ui.config_load_failed", error = %e);
+        format!("Failed to load config: {e}")
+    })?;
+    kild_core::editor::open_editor_at(path, position, None, &config).map_err(|e| e.to_string())
+}
+
+/// Base branch and remote from the config hierarchy.
+fn git_config() -> Result<kild_config::GitConfig, String> {
+    KildConfig::load_hierarchy().map(|c| c.git).map_err(|e| {
+        tracing::error!(event = "ui.config_load_failed", error = %e);
+        format!("Failed to load config: {e}")
+    })
+}
+
+/// Load a kild's diff against its base branch and its commits, for the review panel.
+///
+/// Takes owned parameters so this function can be called from background threads.
+pub fn load_review(
+    worktree_path: PathBuf,
+) -> Result<
+    (
+        kild_core::git::types::ReviewDiff,
+        Vec<crate::state::review::ReviewCommit>,
+    ),
+    String,
+> {
+    tracing::info!(event = "ui.load_review.started", path = %worktree_path.display());
+
+    let git = git_config()?;
+    let diff = kild_core::git::review_diff(&worktree_path, git.base_branch())
+        .map_err(|e| e.to_string())?;
+    let commits = kild_core::git::cli::get_commits(&worktree_path, REVIEW_COMMIT_COUNT)
+        .map_err(|e| e.to_string())?;
+
+    tracing::info!(
+        event = "ui.load_review.completed",
+        path = %worktree_path.display(),
+        files = diff.files.len()
+    );
+    Ok((diff, crate::state::review::parse_commits(&commits)))
+}
+
+/// Commits listed in the review panel.
+const REVIEW_COMMIT_COUNT: usize = 30;
+
+/// Undo a kild's change to one file, restoring the base branch version.
+pub fn discard_review_file(worktree_path: PathBuf, file: String) -> Result<(), String> {
+    tracing::info!(event = "ui.discard_review_file.started", file = %file);
+
+    let git = git_config()?;
+    kild_core::git::restore_file_to_base(&worktree_path, git.base_branch(), &file).map_err(
+        |e| {
+            tracing::error!(event = "ui.discard_review_file.failed", file = %file, error = %e);
+            e.to_string()
+        },
+    )?;
+    tracing::info!(event = "ui.discard_review_file.completed", file = %file);
+    Ok(())
+}
+
+/// Push a kild's branch, open a PR for it (or reuse the existing one) and
+/// show it in the browser. Returns the PR URL.
+pub fn approve_and_open_pr(branch: String) -> Result<String, String> {
+    tracing::info!(event = "ui.approve_pr.started", branch = %branch);
+
+    let session = session_ops::get_session(&branch).map_err(|e| e.to_string())?;
+    if session.use_main_worktree {
+        return Err(format!(
+            "'{}' runs in the project root, so it has no branch to open a PR for.",
+            branch
+        ));
+    }
+    let git = git_config()?;
+    let kild_branch = kild_core::git::kild_branch_name(&branch);
+    let worktree = &session.worktree_path;
+
+    kild_core::git::cli::push_branch(worktree, git.remote(), &kild_branch)
+        .map_err(|e| e.to_string())?;
+
+    let backend = kild_core::forge::get_forge_backend(worktree, git.forge()).ok_or_else(|| {
+        "No forge CLI available for this repository (is gh installed?)".to_string()
+    })?;
+    let url = match backend.fetch_pr_info(worktree, &kild_branch) {
+        Ok(Some(pr)) => pr.url,
+        Ok(None) => backend
+            .create_pr(worktree, &kild_branch, git.base_branch())
+            .map_err(|e| e.to_string())?,
+        Err(e) => return Err(e.to_string()),
+    };
+
+    if let Err(e) = dispatch_command(
+        Command::RefreshPrStatus {
+            branch: branch.clone().into(),
+        },
+        "ui.refresh_pr_status",
+    ) {
+        tracing::warn!(event = "ui.approve_pr.refresh_failed", branch = %branch, error = %e);
+    }
+
+    open::that(&url).map_err(|e| {
+        tracing::error!(event = "ui.approve_pr.failed", branch = %branch, error = %e);
+        format!("PR is at {} but the browser failed to open: {}", url, e)
+    })?;
+    tracing::info!(event = "ui.approve_pr.completed", branch = %branch, url = %url);
+    Ok(url)
+}
+
 // --- Project Management Actions (dispatch-based) ---
 
 /// Add a project via Store dispatch.
@@ -5,31 +5,45 @@
 //! - `list_sessions_async()` / `find_first_running_session()` — session discovery
 //! - `get_session_async()` — query a single session by ID
 //! - `stop_session_async()` — stop a running daemon session
+//! - `list_approvals_async()` / `resolve_approval_async()` — agent permission broker
 //! - `connect_for_attach()` — two-connection attach for streaming PTY output
 //! - `send_write_stdin()` / `send_resize()` / `send_detach()` — write operations
 //!
 //! Transport routing: when `remote_host` is set in config (or via KILD remote
 //! override), connections use TCP+TLS instead of the local Unix socket. Both
 //! paths are unified under `ErasedUiClient` via type erasure.
+//!
+//! Session listing and attach take a `DaemonEndpoint`, so the UI can also
+//! reach the extra daemons configured under `[[daemon.hosts]]`.
 
 use std::os::unix::net::UnixStream;
 use std::pin::Pin;
 use std::sync::Arc;
 use std::sync::atomic::{AtomicU64, Ordering};
+use std::time::Duration;
 
 use base64::Engine;
 use futures::io::BufReader;
 use futures_rustls::TlsConnector;
-use kild_config::KildConfig;
+use kild_config::{DaemonHost, KildConfig};
 use kild_protocol::{
-    AsyncIpcClient, ClientMessage, DaemonMessage, DaemonSessionStatus, ErrorCode, IpcError,
-    SessionId, SessionStatus,
+    ApprovalDecision, AsyncIpcClient, ClientMessage, DaemonMessage, DaemonSessionStatus, ErrorCode,
+    IpcError, PendingApproval, SessionId, SessionStatus,
 };
 use smol::Async;
 use smol::io::split;
 use thiserror::Error;
 use tracing::{debug, error, info, warn};
 
+/// How long to wait for a TCP connection to a remote daemon. Unreachable
+/// hosts would otherwise hold a poll for the OS connect timeout.
+const REMOTE_CONNECT_TIMEOUT: Duration = Duration::from_secs(5);
+
+/// How long a session list may take end to end — connect, TLS handshake and
+/// response. A host that accepts the connection but never answers is then
+/// marked disconnected and retried with backoff instead of stalling its poll.
+const LIST_SESSIONS_TIMEOUT: Duration = Duration::from_secs(10);
+
 /// Monotonic counter for generating unique request IDs within this process.
 static REQUEST_COUNTER: AtomicU64 = AtomicU64::new(1);
 
@@ -50,7 +64,7 @@ pub enum DaemonClientError {
     Io(std::io::Error),
 
     #[error("unexpected response from daemon: {0:?}")]
-    UnexpectedResponse(DaemonMessage),
+    UnexpectedResponse(Box<DaemonMessage>),
 
     #[error("daemon error ({code}): {message}")]
     DaemonError { code: ErrorCode, message: String },
@@ -83,6 +97,37 @@ impl From<IpcError> for DaemonClientError {
     }
 }
 
+/// A daemon the UI talks to.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum DaemonEndpoint {
+    /// The default daemon: the local socket, or `remote_host` when configured.
+    Default,
+    /// A `[[daemon.hosts]]` entry, reached over TCP+TLS.
+    Remote {
+        name: String,
+        address: String,
+        fingerprint: String,
+    },
+}
+
+impl DaemonEndpoint {
+    pub fn from_host(host: &DaemonHost) -> Self {
+        Self::Remote {
+            name: host.name.clone(),
+            address: host.address.clone(),
+            fingerprint: host.cert_fingerprint.clone(),
+        }
+    }
+
+    /// Host name for remote endpoints, `None` for the default daemon.
+    pub fn host_name(&self) -> Option<&str> {
+        match self {
+            Self::Default => None,
+            Self::Remote { name, .. } => Some(name),
+        }
+    }
+}
+
 /// Type-erased reader: boxes any `AsyncBufRead + Send + Unpin`.
 ///
 /// Unifies Unix and TCP/TLS halves under a single type so both transport paths
@@ -122,9 +167,15 @@ async fn connect_tcp(
     addr: &str,
     fingerprint: [u8; 32],
 ) -> Result<ErasedUiClient, DaemonClientError> {
-    let stream = smol::net::TcpStream::connect(addr)
-        .await
-        .map_err(DaemonClientError::Connect)?;
+    let stream = smol::future::or(smol::net::TcpStream::connect(addr), async {
+        smol::Timer::after(REMOTE_CONNECT_TIMEOUT).await;
+        Err(std::io::Error::new(
+            std::io::ErrorKind::TimedOut,
+            format!("connection to {addr} timed out"),
+        ))
+    })
+    .await
+    .map_err(DaemonClientError::Connect)?;
 
     let provider = Arc::new(rustls::crypto::ring::default_provider());
     let verifier = kild_core::daemon::tofu::TofuVerifier::new(fingerprint);
@@ -197,6 +248,23 @@ async fn connect_for_config() -> Result<ErasedUiClient, DaemonClientError> {
     connect().await
 }
 
+/// Connect to a specific endpoint. Remote fingerprints are verified with the
+/// same TOFU pinning as `remote_host`.
+async fn connect_endpoint(endpoint: &DaemonEndpoint) -> Result<ErasedUiClient, DaemonClientError> {
+    match endpoint {
+        DaemonEndpoint::Default => connect_for_config().await,
+        DaemonEndpoint::Remote {
+            address,
+            fingerprint,
+            ..
+        } => {
+            let fingerprint = kild_core::daemon::tofu::parse_fingerprint(fingerprint)
+                .map_err(DaemonClientError::Protocol)?;
+            connect_tcp(address, fingerprint).await
+        }
+    }
+}
+
 /// Async ping to the kild daemon via smol.
 ///
 /// Returns `Ok(true)` if daemon responded with Ack, `Ok(false)` if daemon
@@ -236,22 +304,39 @@ pub async fn ping_daemon_async() -> Result<bool, DaemonClientError> {
                 result = "unexpected_response",
                 response = ?other,
             );
-            Err(DaemonClientError::UnexpectedResponse(other))
+            Err(DaemonClientError::UnexpectedResponse(Box::new(other)))
         }
     }
 }
 
-/// List all daemon sessions.
-#[allow(dead_code)]
-pub async fn list_sessions_async() -> Result<Vec<DaemonSessionStatus>, DaemonClientError> {
-    debug!(event = "ui.daemon.list_sessions_started");
+/// List all sessions on a daemon.
+pub async fn list_sessions_async(
+    endpoint: &DaemonEndpoint,
+) -> Result<Vec<DaemonSessionStatus>, DaemonClientError> {
+    debug!(
+        event = "ui.daemon.list_sessions_started",
+        host = endpoint.host_name()
+    );
 
-    let mut client = connect_for_config().await?;
-    let request = ClientMessage::ListSessions {
-        id: next_request_id(),
-        project_id: None,
+    let request = async {
+        let mut client = connect_endpoint(endpoint).await?;
+        let request = ClientMessage::ListSessions {
+            id: next_request_id(),
+            project_id: None,
+        };
+        client.send(&request).await.map_err(DaemonClientError::from)
     };
-    let response = client.send(&request).await?;
+    let response = smol::future::or(request, async {
+        smol::Timer::after(LIST_SESSIONS_TIMEOUT).await;
+        Err(DaemonClientError::Io(std::io::Error::new(
+            std::io::ErrorKind::TimedOut,
+            format!(
+                "daemon did not answer within {}s",
+                LIST_SESSIONS_TIMEOUT.as_secs()
+            ),
+        )))
+    })
+    .await?;
 
     match response {
         DaemonMessage::SessionList { sessions, .. } => {
@@ -261,7 +346,7 @@ pub async fn list_sessions_async() -> Result<Vec<DaemonSessionStatus>, DaemonCli
             );
             Ok(sessions)
         }
-        other => Err(DaemonClientError::UnexpectedResponse(other)),
+        other => Err(DaemonClientError::UnexpectedResponse(Box::new(other))),
     }
 }
 
@@ -271,7 +356,7 @@ pub async fn list_sessions_async() -> Result<Vec<DaemonSessionStatus>, DaemonCli
 /// replaces this with explicit sidebar-driven session selection.
 #[allow(dead_code)]
 pub async fn find_first_running_session() -> Result<DaemonSessionStatus, DaemonClientError> {
-    let sessions = list_sessions_async().await?;
+    let sessions = list_sessions_async(&DaemonEndpoint::Default).await?;
     sessions
         .into_iter()
         .find(|s| s.status == SessionStatus::Running)
@@ -323,7 +408,7 @@ pub async fn get_session_async(
             );
             Ok(Some(session))
         }
-        other => Err(DaemonClientError::UnexpectedResponse(other)),
+        other => Err(DaemonClientError::UnexpectedResponse(Box::new(other))),
     }
 }
 
@@ -352,7 +437,74 @@ pub async fn stop_session_async(session_id: &str) -> Result<(), DaemonClientErro
             );
             Ok(())
         }
-        other => Err(DaemonClientError::UnexpectedResponse(other)),
+        other => Err(DaemonClientError::UnexpectedResponse(Box::new(other))),
+    }
+}
+
+/// List agent permission requests waiting in the daemon's approval broker.
+///
+/// Returns an empty list when the daemon is not running — nothing can be
+/// pending without it.
+pub async fn list_approvals_async() -> Result<Vec<PendingApproval>, DaemonClientError> {
+    debug!(event = "ui.daemon.list_approvals_started");
+
+    let mut client = match connect_for_config().await {
+        Ok(c) => c,
+        Err(DaemonClientError::Connect(e))
+            if e.kind() == std::io::ErrorKind::NotFound
+                || e.kind() == std::io::ErrorKind::ConnectionRefused =>
+        {
+            return Ok(Vec::new());
+        }
+        Err(e) => return Err(e),
+    };
+    let request = ClientMessage::ListApprovals {
+        id: next_request_id(),
+    };
+    let response = client.send(&request).await?;
+
+    match response {
+        DaemonMessage::ApprovalList { approvals, .. } => {
+            debug!(
+                event = "ui.daemon.list_approvals_completed",
+                count = approvals.len()
+            );
+            Ok(approvals)
+        }
+        other => Err(DaemonClientError::UnexpectedResponse(Box::new(other))),
+    }
+}
+
+/// Allow or deny a pending agent permission request.
+pub async fn resolve_approval_async(
+    approval_id: &str,
+    decision: ApprovalDecision,
+) -> Result<(), DaemonClientError> {
+    debug!(
+        event = "ui.daemon.resolve_approval_started",
+        approval_id = approval_id,
+        decision = %decision
+    );
+
+    let mut client = connect_for_config().await?;
+    let request = ClientMessage::ResolveApproval {
+        id: next_request_id(),
+        approval_id: approval_id.to_string(),
+        decision,
+        message: None,
+    };
+    let response = client.send(&request).await?;
+
+    match response {
+        DaemonMessage::Ack { .. } => {
+            info!(
+                event = "ui.daemon.resolve_approval_completed",
+                approval_id = approval_id,
+                decision = %decision
+            );
+            Ok(())
+        }
+        other => Err(DaemonClientError::UnexpectedResponse(Box::new(other))),
     }
 }
 
@@ -392,7 +544,7 @@ pub async fn create_session_async(
             );
             Ok(session.id.into_inner())
         }
-        other => Err(DaemonClientError::UnexpectedResponse(other)),
+        other => Err(DaemonClientError::UnexpectedResponse(Box::new(other))),
     }
 }
 
@@ -407,6 +559,7 @@ pub struct DaemonConnection {
     reader: DynReader,
     writer: DynWriter,
     session_id: String,
+    endpoint: DaemonEndpoint,
 }
 
 impl DaemonConnection {
@@ -416,6 +569,11 @@ impl DaemonConnection {
         &self.session_id
     }
 
+    /// The daemon this connection is attached to.
+    pub fn endpoint(&self) -> &DaemonEndpoint {
+        &self.endpoint
+    }
+
     /// Consume the connection, returning its parts for use in reader/writer tasks.
     pub fn into_parts(self) -> (DynReader, DynWriter, String) {
         (self.reader, self.writer, self.session_id)
@@ -425,6 +583,7 @@ impl DaemonConnection {
 /// Open two connections to the daemon: one for streaming reads (Attach),
 /// one for writes (WriteStdin/ResizePty/Detach).
 pub async fn connect_for_attach(
+    endpoint: &DaemonEndpoint,
     session_id: &str,
     rows: u16,
     cols: u16,
@@ -432,12 +591,13 @@ pub async fn connect_for_attach(
     info!(
         event = "ui.daemon.attach_started",
         session_id = session_id,
+        host = endpoint.host_name(),
         rows = rows,
         cols = cols
     );
 
     // Connection 1: reader — send Attach, read Ack, then stream PtyOutput
-    let mut read_client = connect_for_config().await?;
+    let mut read_client = connect_endpoint(endpoint).await?;
     let attach_request = ClientMessage::Attach {
         id: next_request_id(),
         session_id: SessionId::from(session_id),
@@ -453,7 +613,7 @@ pub async fn connect_for_attach(
             );
         }
         other => {
-            return Err(DaemonClientError::UnexpectedResponse(other));
+            return Err(DaemonClientError::UnexpectedResponse(Box::new(other)));
         }
     }
 
@@ -464,7 +624,7 @@ pub async fn connect_for_attach(
     // No protocol handshake is sent on this connection: the daemon dispatches
     // WriteStdin/ResizePty/Detach by session_id from each message's payload,
     // not by connection-level attachment state.
-    let write_client = connect_for_config().await?;
+    let write_client = connect_endpoint(endpoint).await?;
     let (_, writer) = write_client.into_parts();
 
     info!(
@@ -476,6 +636,7 @@ pub async fn connect_for_attach(
         reader,
         writer,
         session_id: session_id.to_string(),
+        endpoint: endpoint.clone(),
     })
 }
 
@@ -714,4 +875,31 @@ mod tests {
         let ce: DaemonClientError = e.into();
         assert!(matches!(ce, DaemonClientError::Protocol(_)));
     }
+
+    #[test]
+    fn test_endpoint_from_host() {
+        let host = DaemonHost {
+            name: "build-box".to_string(),
+            address: "build-box:7432".to_string(),
+            cert_fingerprint: "sha256:00".to_string(),
+        };
+        let endpoint = DaemonEndpoint::from_host(&host);
+        assert_eq!(endpoint.host_name(), Some("build-box"));
+        assert!(matches!(
+            &endpoint,
+            DaemonEndpoint::Remote { address, .. } if address == "build-box:7432"
+        ));
+        assert_eq!(DaemonEndpoint::Default.host_name(), None);
+    }
+
+    #[test]
+    fn test_remote_endpoint_rejects_bad_fingerprint() {
+        let endpoint = DaemonEndpoint::Remote {
+            name: "x".to_string(),
+            address: "127.0.0.1:1".to_string(),
+            fingerprint: "not-a-fingerprint".to_string(),
+        };
+        let result = smol::block_on(connect_endpoint(&endpoint));
+        assert!(matches!(result, Err(DaemonClientError::Protocol(_))));
+    }
 }
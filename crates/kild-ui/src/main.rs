@@ -17,6 +17,7 @@ mod teams;
 mod terminal;
 mod theme;
 mod theme_bridge;
+mod themes;
 mod views;
 mod watcher;
 
@@ -32,8 +33,8 @@ fn main() {
         // Initialize gpui-component (must be first)
         gpui_component::init(cx);
 
-        // Apply Tallinn Night theme
-        theme_bridge::apply_tallinn_night_theme(cx);
+        // The theme is applied (and hot reloaded) by MainView, which can
+        // report theme file errors in its banner.
 
         let bounds = Bounds::centered(None, size(px(800.0), px(600.0)), cx);
         cx.open_window(
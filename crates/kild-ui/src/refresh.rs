@@ -17,3 +17,22 @@ pub const DEBOUNCE_INTERVAL: Duration = Duration::from_millis(100);
 /// Fast poll interval used when file watching is unavailable.
 /// Falls back to previous behavior if watcher fails to initialize.
 pub const FAST_POLL_INTERVAL: Duration = Duration::from_secs(5);
+
+/// Poll interval for pending agent approvals. Agents block until answered,
+/// so this is much tighter than the session poll.
+pub const APPROVALS_POLL_INTERVAL: Duration = Duration::from_secs(2);
+
+/// Poll interval for `[[daemon.hosts]]` session lists. Disconnected hosts
+/// back off from here.
+pub const REMOTE_HOSTS_POLL_INTERVAL: Duration = Duration::from_secs(5);
+
+/// How often the dashboard checks whether health history changed. Only a
+/// directory listing unless it did, so this can stay tight.
+pub const FLEET_HISTORY_POLL_INTERVAL: Duration = Duration::from_secs(2);
+
+/// Poll interval for agent token usage. Parsing transcripts is the most
+/// expensive refresh, so it runs well behind the session poll.
+pub const USAGE_POLL_INTERVAL: Duration = Duration::from_secs(120);
+
+/// Poll interval for theme and `[ui]` config changes (hot reload).
+pub const THEME_POLL_INTERVAL: Duration = Duration::from_secs(1);
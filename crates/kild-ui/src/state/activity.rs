@@ -0,0 +1,518 @@
+//! Activity feed: what happened across the fleet, newest first.
+//!
+//! Entries come from two sources. Core events dispatched by the UI
+//! (create, stop, destroy, complete) are recorded as they are applied.
+//! Everything that happens outside the UI — agent status sidecars, PR CI
+//! results, agents exiting on their own — is detected by comparing each
+//! session refresh with the previous one. Entries persist in
+//! `~/.kild/activity.json` so unread items survive a restart.
+
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+
+use kild_core::{AgentStatus, CiStatus, ProcessStatus, SessionSnapshot, SessionStatus};
+use serde::{Deserialize, Serialize};
+
+use crate::state::json_file;
+
+/// Entries kept in the feed (and on disk); older ones are dropped.
+pub const MAX_ENTRIES: usize = 500;
+
+/// What an activity entry reports.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ActivityKind {
+    AgentWaiting,
+    AgentDone,
+    AgentError,
+    /// The agent process exited while the session was still active.
+    Crashed,
+    CiPassing,
+    CiFailing,
+    KildCreated,
+    KildStopped,
+    KildDestroyed,
+    KildCompleted,
+}
+
+impl ActivityKind {
+    pub fn label(self) -> &'static str {
+        match self {
+            ActivityKind::AgentWaiting => "Waiting for input",
+            ActivityKind::AgentDone => "Agent done",
+            ActivityKind::AgentError => "Agent error",
+            ActivityKind::Crashed => "Agent exited unexpectedly",
+            ActivityKind::CiPassing => "CI passing",
+            ActivityKind::CiFailing => "CI failing",
+            ActivityKind::KildCreated => "Created",
+            ActivityKind::KildStopped => "Stopped",
+            ActivityKind::KildDestroyed => "Destroyed",
+            ActivityKind::KildCompleted => "Completed",
+        }
+    }
+
+    /// Whether the entry asks the user to do something.
+    pub fn needs_attention(self) -> bool {
+        matches!(
+            self,
+            ActivityKind::AgentWaiting
+                | ActivityKind::AgentError
+                | ActivityKind::Crashed
+                | ActivityKind::CiFailing
+        )
+    }
+
+    fn category(self) -> ActivityFilter {
+        match self {
+            ActivityKind::AgentWaiting
+            | ActivityKind::AgentDone
+            | ActivityKind::AgentError
+            | ActivityKind::Crashed => ActivityFilter::Agent,
+            ActivityKind::CiPassing | ActivityKind::CiFailing => ActivityFilter::Ci,
+            ActivityKind::KildCreated
+            | ActivityKind::KildStopped
+            | ActivityKind::KildDestroyed
+            | ActivityKind::KildCompleted => ActivityFilter::Lifecycle,
+        }
+    }
+}
+
+/// Which entries the feed shows.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum ActivityFilter {
+    #[default]
+    All,
+    Unread,
+    Agent,
+    Ci,
+    Lifecycle,
+}
+
+impl ActivityFilter {
+    pub const ALL: [ActivityFilter; 5] = [
+        ActivityFilter::All,
+        ActivityFilter::Unread,
+        ActivityFilter::Agent,
+        ActivityFilter::Ci,
+        ActivityFilter::Lifecycle,
+    ];
+
+    pub fn label(self) -> &'static str {
+        match self {
+            ActivityFilter::All => "All",
+            ActivityFilter::Unread => "Unread",
+            ActivityFilter::Agent => "Agents",
+            ActivityFilter::Ci => "CI",
+            ActivityFilter::Lifecycle => "Kilds",
+        }
+    }
+
+    fn matches(self, entry: &ActivityEntry) -> bool {
+        match self {
+            ActivityFilter::All => true,
+            ActivityFilter::Unread => !entry.read,
+            category => entry.kind.category() == category,
+        }
+    }
+}
+
+/// One line of the activity feed.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct ActivityEntry {
+    pub id: u64,
+    /// RFC 3339 timestamp.
+    pub at: String,
+    /// Session ID, empty for kilds that no longer exist when recorded.
+    pub session_id: String,
+    pub branch: String,
+    pub kind: ActivityKind,
+    /// Extra context, e.g. the CI summary.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub detail: Option<String>,
+    #[serde(default)]
+    pub read: bool,
+}
+
+/// State of one kild at a refresh, compared with the next refresh to
+/// detect changes.
+#[derive(Clone, Debug, PartialEq)]
+pub struct KildObservation {
+    pub session_id: String,
+    pub branch: String,
+    pub session_active: bool,
+    pub process: ProcessStatus,
+    pub agent: Option<AgentStatus>,
+    pub ci: Option<CiStatus>,
+    pub ci_summary: Option<String>,
+}
+
+impl KildObservation {
+    /// Observe a kild, reading its agent status and PR sidecars.
+    pub fn read(snapshot: &SessionSnapshot) -> Self {
+        let session_id = snapshot.session.id.to_string();
+        let pr = kild_core::session_ops::read_pr_info(&session_id);
+        Self {
+            agent: kild_core::session_ops::read_agent_status(&session_id).map(|r| r.status),
+            ci: pr.as_ref().map(|pr| pr.ci_status.clone()),
+            ci_summary: pr.and_then(|pr| pr.ci_summary),
+            session_id,
+            branch: snapshot.session.branch.to_string(),
+            session_active: snapshot.session.status == SessionStatus::Active,
+            process: snapshot.process_status,
+        }
+    }
+}
+
+/// On-disk form of the feed.
+#[derive(Debug, Default, Serialize, Deserialize)]
+pub struct ActivityFile {
+    #[serde(default)]
+    pub entries: Vec<ActivityEntry>,
+}
+
+/// The activity feed with read tracking.
+#[derive(Debug, Default)]
+pub struct ActivityFeed {
+    /// Oldest first.
+    entries: Vec<ActivityEntry>,
+    next_id: u64,
+    /// Last observation per session ID.
+    last_seen: HashMap<String, KildObservation>,
+}
+
+impl ActivityFeed {
+    pub fn from_entries(mut entries: Vec<ActivityEntry>) -> Self {
+        let excess = entries.len().saturating_sub(MAX_ENTRIES);
+        entries.drain(..excess);
+        let next_id = entries.iter().map(|e| e.id + 1).max().unwrap_or(0);
+        Self {
+            entries,
+            next_id,
+            last_seen: HashMap::new(),
+        }
+    }
+
+    /// All entries, oldest first (for testing).
+    #[cfg(test)]
+    pub fn entries(&self) -> &[ActivityEntry] {
+        &self.entries
+    }
+
+    /// Entries matching `filter`, newest first.
+    pub fn filtered(&self, filter: ActivityFilter) -> Vec<&ActivityEntry> {
+        self.entries
+            .iter()
+            .rev()
+            .filter(|e| filter.matches(e))
+            .collect()
+    }
+
+    pub fn unread_count(&self) -> usize {
+        self.entries.iter().filter(|e| !e.read).count()
+    }
+
+    pub fn unread_count_for(&self, session_id: &str) -> usize {
+        self.entries
+            .iter()
+            .filter(|e| !e.read && e.session_id == session_id)
+            .count()
+    }
+
+    /// Kilds whose agent is currently waiting on the user.
+    pub fn waiting_count(&self) -> usize {
+        self.last_seen
+            .values()
+            .filter(|o| o.agent == Some(AgentStatus::Waiting))
+            .count()
+    }
+
+    /// Mark a kild's entries as read. Returns whether anything changed.
+    pub fn mark_read(&mut self, session_id: &str) -> bool {
+        let mut changed = false;
+        for entry in self.entries.iter_mut() {
+            if !entry.read && entry.session_id == session_id {
+                entry.read = true;
+                changed = true;
+            }
+        }
+        changed
+    }
+
+    /// Mark every entry as read. Returns whether anything changed.
+    pub fn mark_all_read(&mut self) -> bool {
+        let changed = self.unread_count() > 0;
+        for entry in self.entries.iter_mut() {
+            entry.read = true;
+        }
+        changed
+    }
+
+    /// Compare a refresh with the previous one and record what changed.
+    ///
+    /// The first observation of a kild only sets the baseline, so starting
+    /// the UI does not replay the current state as new activity. Returns
+    /// whether any entry was added.
+    pub fn observe(&mut self, observations: Vec<KildObservation>, now: &str) -> bool {
+        let mut added = false;
+        let mut seen = HashMap::with_capacity(observations.len());
+        for current in observations {
+            if let Some(previous) = self.last_seen.get(&current.session_id) {
+                for (kind, detail) in changes(previous, &current) {
+                    self.push(&current.session_id, &current.branch, kind, detail, now);
+                    added = true;
+                }
+            }
+            seen.insert(current.session_id.clone(), current);
+        }
+        self.last_seen = seen;
+        added
+    }
+
+    /// Record a core event dispatched by the UI. Returns whether an entry
+    /// was added.
+    pub fn record_event(&mut self, event: &kild_core::Event, now: &str) -> bool {
+        let (branch, kind) = match event {
+            kild_core::Event::KildCreated { branch, .. } => (branch, ActivityKind::KildCreated),
+            kild_core::Event::KildStopped { branch } => (branch, ActivityKind::KildStopped),
+            kild_core::Event::KildDestroyed { branch } => (branch, ActivityKind::KildDestroyed),
+            kild_core::Event::KildCompleted { branch } => (branch, ActivityKind::KildCompleted),
+            _ => return false,
+        };
+        let session_id = match event {
+            kild_core::Event::KildCreated { session_id, .. } => session_id.to_string(),
+            _ => self
+                .last_seen
+                .values()
+                .find(|o| o.branch == branch.as_ref())
+                .map(|o| o.session_id.clone())
+                .unwrap_or_default(),
+        };
+        // A stop from the UI is not a crash.
+        if kind == ActivityKind::KildStopped
+            && let Some(observation) = self.last_seen.get_mut(&session_id)
+        {
+            observation.process = ProcessStatus::Stopped;
+        }
+        self.push(&session_id, branch.as_ref(), kind, None, now);
+        true
+    }
+
+    fn push(
+        &mut self,
+        session_id: &str,
+        branch: &str,
+        kind: ActivityKind,
+        detail: Option<String>,
+        now: &str,
+    ) {
+        tracing::info!(event = "ui.activity.recorded", branch = branch, kind = ?kind);
+        self.entries.push(ActivityEntry {
+            id: self.next_id,
+            at: now.to_string(),
+            session_id: session_id.to_string(),
+            branch: branch.to_string(),
+            kind,
+            detail,
+            read: false,
+        });
+        self.next_id += 1;
+        let excess = self.entries.len().saturating_sub(MAX_ENTRIES);
+        self.entries.drain(..excess);
+    }
+
+    pub fn to_file(&self) -> ActivityFile {
+        ActivityFile {
+            entries: self.entries.clone(),
+        }
+    }
+}
+
+/// Entries for the differences between two observations of one kild.
+fn changes(
+    previous: &KildObservation,
+    current: &KildObservation,
+) -> Vec<(ActivityKind, Option<String>)> {
+    let mut found = Vec::new();
+    if current.agent != previous.agent {
+        let kind = match current.agent {
+            Some(AgentStatus::Waiting) => Some(ActivityKind::AgentWaiting),
+            Some(AgentStatus::Done) => Some(ActivityKind::AgentDone),
+            Some(AgentStatus::Error) => Some(ActivityKind::AgentError),
+            _ => None,
+        };
+        found.extend(kind.map(|k| (k, None)));
+    }
+    if previous.process == ProcessStatus::Running
+        && current.process == ProcessStatus::Stopped
+        && current.session_active
+    {
+        found.push((ActivityKind::Crashed, None));
+    }
+    if current.ci != previous.ci {
+        let kind = match current.ci {
+            Some(CiStatus::Passing) => Some(ActivityKind::CiPassing),
+            Some(CiStatus::Failing) => Some(ActivityKind::CiFailing),
+            _ => None,
+        };
+        found.extend(kind.map(|k| (k, current.ci_summary.clone())));
+    }
+    found
+}
+
+/// Path of the activity file, or `None` if the home directory is unknown.
+pub fn activity_file_path() -> Option<PathBuf> {
+    json_file::file_path("activity", |paths| paths.ui_activity_file())
+}
+
+/// Load the feed from `path`. A missing file is an empty feed.
+pub fn load_activity(path: &Path) -> Result<ActivityFile, String> {
+    json_file::load(path, "activity")
+}
+
+/// Write the feed to `path`, creating its directory if needed.
+pub fn save_activity(path: &Path, file: &ActivityFile) -> Result<(), String> {
+    json_file::save(path, "activity", file)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const NOW: &str = "2026-01-01T00:00:00Z";
+
+    fn observation(id: &str) -> KildObservation {
+        KildObservation {
+            session_id: id.to_string(),
+            branch: format!("branch-{}", id),
+            session_active: true,
+            process: ProcessStatus::Running,
+            agent: Some(AgentStatus::Working),
+            ci: None,
+            ci_summary: None,
+        }
+    }
+
+    fn kinds(feed: &ActivityFeed) -> Vec<ActivityKind> {
+        feed.entries().iter().map(|e| e.kind).collect()
+    }
+
+    #[test]
+    fn test_first_observation_is_baseline() {
+        let mut feed = ActivityFeed::default();
+        let mut waiting = observation("a");
+        waiting.agent = Some(AgentStatus::Waiting);
+        assert!(!feed.observe(vec![waiting], NOW));
+        assert!(feed.entries().is_empty());
+        assert_eq!(feed.waiting_count(), 1);
+    }
+
+    #[test]
+    fn test_observe_records_status_ci_and_crash_changes() {
+        let mut feed = ActivityFeed::default();
+        feed.observe(vec![observation("a"), observation("b")], NOW);
+
+        let mut a = observation("a");
+        a.agent = Some(AgentStatus::Waiting);
+        a.ci = Some(CiStatus::Failing);
+        a.ci_summary = Some("1 of 3 failed".to_string());
+        let mut b = observation("b");
+        b.process = ProcessStatus::Stopped;
+        assert!(feed.observe(vec![a.clone(), b], NOW));
+
+        assert_eq!(
+            kinds(&feed),
+            vec![
+                ActivityKind::AgentWaiting,
+                ActivityKind::CiFailing,
+                ActivityKind::Crashed
+            ]
+        );
+        assert_eq!(feed.entries()[1].detail.as_deref(), Some("1 of 3 failed"));
+        assert_eq!(feed.unread_count_for("a"), 2);
+
+        // Unchanged state adds nothing
+        assert!(!feed.observe(vec![a], NOW));
+    }
+
+    #[test]
+    fn test_stop_from_ui_is_not_a_crash() {
+        let mut feed = ActivityFeed::default();
+        feed.observe(vec![observation("a")], NOW);
+        feed.record_event(
+            &kild_core::Event::KildStopped {
+                branch: "branch-a".into(),
+            },
+            NOW,
+        );
+        let mut stopped = observation("a");
+        stopped.process = ProcessStatus::Stopped;
+        feed.observe(vec![stopped], NOW);
+
+        assert_eq!(kinds(&feed), vec![ActivityKind::KildStopped]);
+        assert_eq!(feed.entries()[0].session_id, "a");
+    }
+
+    #[test]
+    fn test_filters_and_read_tracking() {
+        let mut feed = ActivityFeed::default();
+        feed.observe(vec![observation("a"), observation("b")], NOW);
+        let mut a = observation("a");
+        a.agent = Some(AgentStatus::Done);
+        let mut b = observation("b");
+        b.ci = Some(CiStatus::Passing);
+        feed.observe(vec![a, b], NOW);
+
+        assert_eq!(feed.filtered(ActivityFilter::Ci).len(), 1);
+        assert_eq!(feed.filtered(ActivityFilter::Agent).len(), 1);
+        assert!(feed.filtered(ActivityFilter::Lifecycle).is_empty());
+        // Newest first
+        assert_eq!(
+            feed.filtered(ActivityFilter::All)[0].kind,
+            ActivityKind::CiPassing
+        );
+
+        assert!(feed.mark_read("a"));
+        assert!(!feed.mark_read("a"));
+        assert_eq!(feed.unread_count(), 1);
+        assert_eq!(feed.filtered(ActivityFilter::Unread)[0].session_id, "b");
+        assert!(feed.mark_all_read());
+        assert_eq!(feed.unread_count(), 0);
+    }
+
+    #[test]
+    fn test_feed_is_capped_and_ids_continue_after_load() {
+        let mut feed = ActivityFeed::default();
+        for _ in 0..MAX_ENTRIES + 10 {
+            feed.record_event(
+                &kild_core::Event::KildCreated {
+                    branch: "x".into(),
+                    session_id: "id".into(),
+                },
+                NOW,
+            );
+        }
+        assert_eq!(feed.entries().len(), MAX_ENTRIES);
+
+        let reloaded = ActivityFeed::from_entries(feed.to_file().entries);
+        assert_eq!(reloaded.next_id, MAX_ENTRIES as u64 + 10);
+    }
+
+    #[test]
+    fn test_save_and_load_roundtrip() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("nested/activity.json");
+        assert!(load_activity(&path).unwrap().entries.is_empty());
+
+        let mut feed = ActivityFeed::default();
+        feed.record_event(
+            &kild_core::Event::KildDestroyed {
+                branch: "gone".into(),
+            },
+            NOW,
+        );
+        save_activity(&path, &feed.to_file()).unwrap();
+
+        let loaded = load_activity(&path).unwrap();
+        assert_eq!(loaded.entries, feed.entries().to_vec());
+    }
+}
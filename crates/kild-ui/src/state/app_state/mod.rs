@@ -3,4 +3,4 @@ mod state;
 #[cfg(test)]
 mod tests;
 
-pub use state::AppState;
+pub use state::{AppState, FleetHistoryStamp, load_fleet_history, load_kild_usage};
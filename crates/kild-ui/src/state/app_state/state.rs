@@ -1,9 +1,15 @@
-use kild_core::SessionSnapshot;
+use std::collections::HashMap;
+
+use kild_core::health::history::HistoryBucket;
 use kild_core::projects::{Project, ProjectRegistry};
+use kild_core::{KildUsage, SessionSnapshot};
+use kild_protocol::PendingApproval;
 
+use crate::state::activity::{self, ActivityFeed, KildObservation};
 use crate::state::dialog::DialogState;
 use crate::state::errors::{OperationError, OperationErrors};
 use crate::state::loading::LoadingState;
+use crate::state::palette::{PaletteAction, PaletteHistory};
 use crate::state::selection::SelectionState;
 use crate::state::sessions::SessionStore;
 
@@ -33,8 +39,30 @@ pub struct AppState {
 
     /// In-progress operation tracking (prevents double-dispatch).
     pub(super) loading: LoadingState,
+
+    /// Per-day fleet health aggregates for the dashboard trend panel.
+    pub(super) fleet_history: Vec<HistoryBucket>,
+
+    /// Token usage per session ID, for dashboard card badges.
+    pub(super) kild_usage: HashMap<String, KildUsage>,
+
+    /// Agent permission requests waiting in the daemon, oldest first.
+    pub(super) pending_approvals: Vec<PendingApproval>,
+
+    /// Recently run command palette entries, for ranking.
+    pub(super) palette_history: PaletteHistory,
+
+    /// Activity feed (agent status, CI, crashes, lifecycle events).
+    pub(super) activity: ActivityFeed,
+
+    /// Where the activity feed is saved. `None` disables saving (unknown
+    /// home, or an activity file that failed to load).
+    pub(super) activity_path: Option<std::path::PathBuf>,
 }
 
+/// Days of health history shown in the dashboard trend panel.
+const FLEET_HISTORY_DAYS: u64 = 7;
+
 impl AppState {
     /// Create new application state, loading sessions from disk.
     pub fn new() -> Self {
@@ -57,20 +85,44 @@ impl AppState {
         }
         let projects = ProjectRegistry::from_data(projects_data.projects, projects_data.active);
 
-        Self {
-            sessions: SessionStore::new(),
+        let sessions = SessionStore::new();
+
+        let mut activity_path = activity::activity_file_path();
+        let entries = match activity_path.as_deref().map(activity::load_activity) {
+            Some(Ok(file)) => file.entries,
+            Some(Err(e)) => {
+                tracing::warn!(event = "ui.activity.load_failed", error = %e);
+                startup_errors.push(e);
+                activity_path = None;
+                Vec::new()
+            }
+            None => Vec::new(),
+        };
+
+        let mut state = Self {
+            sessions,
             dialog: DialogState::None,
             errors: OperationErrors::new(),
             selection: SelectionState::default(),
             projects,
             startup_errors,
             loading: LoadingState::new(),
-        }
+            fleet_history: Vec::new(),
+            kild_usage: HashMap::new(),
+            pending_approvals: Vec::new(),
+            palette_history: PaletteHistory::default(),
+            activity: ActivityFeed::from_entries(entries),
+            activity_path,
+        };
+        // Baseline for change detection
+        state.observe_activity();
+        state
     }
 
     /// Refresh sessions from disk.
     pub fn refresh_sessions(&mut self) {
         self.sessions.refresh();
+        self.observe_activity();
     }
 
     /// Update only the process status of existing kilds without reloading from disk.
@@ -87,6 +139,60 @@ impl AppState {
     /// for a full refresh that includes git information.
     pub fn update_statuses_only(&mut self) {
         self.sessions.update_statuses_only();
+        self.observe_activity();
+    }
+
+    /// Replace the dashboard's fleet health history with a result of
+    /// [`load_fleet_history`].
+    pub fn set_fleet_history(&mut self, history: Vec<HistoryBucket>) {
+        self.fleet_history = history;
+    }
+
+    /// Per-day fleet health aggregates, oldest first.
+    pub fn fleet_history(&self) -> &[HistoryBucket] {
+        &self.fleet_history
+    }
+
+    /// Sessions to collect token usage for, cloned so collection can run on
+    /// a background thread.
+    pub fn usage_sessions(&self) -> Vec<kild_core::Session> {
+        self.sessions
+            .displays()
+            .iter()
+            .map(|d| d.session.clone())
+            .collect()
+    }
+
+    /// Replace token usage with a result of [`load_kild_usage`].
+    ///
+    /// Returns whether anything changed.
+    pub fn set_kild_usage(&mut self, usage: HashMap<String, KildUsage>) -> bool {
+        if self.kild_usage == usage {
+            return false;
+        }
+        self.kild_usage = usage;
+        true
+    }
+
+    /// Token usage for a session, if any was collected.
+    pub fn kild_usage(&self, session_id: &str) -> Option<&KildUsage> {
+        self.kild_usage.get(session_id)
+    }
+
+    /// Replace the pending approvals with the daemon's latest list.
+    pub fn set_pending_approvals(&mut self, approvals: Vec<PendingApproval>) {
+        self.pending_approvals = approvals;
+    }
+
+    /// Drop an approval locally once it has been answered, so the panel
+    /// updates before the next poll.
+    pub fn remove_pending_approval(&mut self, approval_id: &str) {
+        self.pending_approvals.retain(|a| a.id != approval_id);
+    }
+
+    /// Agent permission requests waiting for an answer.
+    pub fn pending_approvals(&self) -> &[PendingApproval] {
+        &self.pending_approvals
     }
 
     /// Apply core events to update application state.
@@ -103,6 +209,11 @@ impl AppState {
         for ev in events {
             tracing::debug!(event = "ui.state.event_applied", event_type = ?ev);
 
+            // Before the refresh below, so a stop is not mistaken for a crash
+            if self.activity.record_event(ev, &now_rfc3339()) {
+                self.save_activity();
+            }
+
             match ev {
                 kild_core::Event::KildCreated { .. } => {
                     self.close_dialog();
@@ -205,6 +316,61 @@ impl AppState {
         self.dialog = DialogState::open_add_project();
     }
 
+    /// Open the command palette.
+    pub fn open_palette_dialog(&mut self) {
+        self.dialog = DialogState::open_palette();
+    }
+
+    /// Recently run palette entries.
+    pub fn palette_history(&self) -> &PaletteHistory {
+        &self.palette_history
+    }
+
+    /// Remember that a palette entry was run so it ranks higher next time.
+    pub fn record_palette_use(&mut self, action: &PaletteAction) {
+        self.palette_history.record(action);
+    }
+
+    /// The activity feed.
+    pub fn activity(&self) -> &ActivityFeed {
+        &self.activity
+    }
+
+    /// Mark a kild's activity as read.
+    pub fn mark_activity_read(&mut self, session_id: &str) {
+        if self.activity.mark_read(session_id) {
+            self.save_activity();
+        }
+    }
+
+    /// Mark all activity as read.
+    pub fn mark_all_activity_read(&mut self) {
+        if self.activity.mark_all_read() {
+            self.save_activity();
+        }
+    }
+
+    /// Compare the current sessions with the last refresh and record changes.
+    fn observe_activity(&mut self) {
+        let observations = self
+            .sessions
+            .displays()
+            .iter()
+            .map(KildObservation::read)
+            .collect();
+        if self.activity.observe(observations, &now_rfc3339()) {
+            self.save_activity();
+        }
+    }
+
+    fn save_activity(&self) {
+        if let Some(path) = &self.activity_path
+            && let Err(e) = activity::save_activity(path, &self.activity.to_file())
+        {
+            tracing::warn!(event = "ui.activity.save_failed", error = %e);
+        }
+    }
+
     /// Set error message in the current dialog.
     /// No-op if no dialog is open.
     pub fn set_dialog_error(&mut self, error: String) {
@@ -218,6 +384,7 @@ impl AppState {
             DialogState::Create { error: e, .. } => *e = Some(error),
             DialogState::Confirm { error: e, .. } => *e = Some(error),
             DialogState::AddProject { error: e, .. } => *e = Some(error),
+            DialogState::Palette { error: e, .. } => *e = Some(error),
         }
     }
 
@@ -380,6 +547,7 @@ impl AppState {
 
     /// Select a kild by ID.
     pub fn select_kild(&mut self, id: String) {
+        self.mark_activity_read(&id);
         self.selection.select(id);
     }
 
@@ -467,6 +635,12 @@ impl AppState {
             projects: ProjectRegistry::new(),
             startup_errors: Vec::new(),
             loading: LoadingState::new(),
+            fleet_history: Vec::new(),
+            kild_usage: HashMap::new(),
+            pending_approvals: Vec::new(),
+            palette_history: PaletteHistory::default(),
+            activity: ActivityFeed::default(),
+            activity_path: None,
         }
     }
 
@@ -477,6 +651,72 @@ impl AppState {
     }
 }
 
+fn now_rfc3339() -> String {
+    chrono::Utc::now().to_rfc3339()
+}
+
+/// Load and aggregate the last [`FLEET_HISTORY_DAYS`] of health history.
+///
+/// Reads every daily history file: call this on the background executor.
+pub fn load_fleet_history() -> Vec<HistoryBucket> {
+    match kild_core::health::load_history(FLEET_HISTORY_DAYS) {
+        Ok(snapshots) => kild_core::health::history::aggregate_buckets(
+            &snapshots,
+            kild_core::health::HistoryGranularity::Day,
+        ),
+        Err(e) => {
+            tracing::warn!(event = "ui.health_history.load_failed", error = %e);
+            Vec::new()
+        }
+    }
+}
+
+/// Modification times of the health history files, plus the UTC day the
+/// trend window ends on. Two stamps differ when `kild health --watch`
+/// recorded a snapshot, history was cleaned up, or the window moved on.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct FleetHistoryStamp {
+    day: chrono::NaiveDate,
+    files: Vec<(std::path::PathBuf, Option<std::time::SystemTime>)>,
+}
+
+impl FleetHistoryStamp {
+    pub fn current() -> Self {
+        let mut files: Vec<_> = kild_core::health::storage::get_history_dir()
+            .and_then(std::fs::read_dir)
+            .map(|entries| {
+                entries
+                    .flatten()
+                    .map(|entry| {
+                        let modified = entry.metadata().and_then(|m| m.modified()).ok();
+                        (entry.path(), modified)
+                    })
+                    .collect()
+            })
+            .unwrap_or_default();
+        files.sort();
+        Self {
+            day: chrono::Utc::now().date_naive(),
+            files,
+        }
+    }
+}
+
+/// Parse agent transcripts for token usage, keyed by session ID.
+///
+/// Transcripts can be large: call this on the background executor.
+pub fn load_kild_usage(sessions: &[kild_core::Session]) -> HashMap<String, KildUsage> {
+    let config = kild_core::KildConfig::load_hierarchy().unwrap_or_else(|e| {
+        tracing::warn!(event = "ui.usage.config_load_failed", error = %e);
+        kild_core::KildConfig::default()
+    });
+    kild_core::usage::collect_usage(sessions, &config.usage)
+        .into_iter()
+        .zip(sessions)
+        .map(|(usage, session)| (session.id.to_string(), usage))
+        .collect()
+}
+
 impl Default for AppState {
     fn default() -> Self {
         Self::new()
@@ -48,6 +48,25 @@ fn test_set_dialog_error_sets_error_on_confirm() {
     }
 }
 
+#[test]
+fn test_palette_history_survives_closing_palette() {
+    let mut state = AppState::test_new();
+    state.open_palette_dialog();
+    state.set_dialog_error("Inject failed".to_string());
+    if let DialogState::Palette { error, .. } = state.dialog() {
+        assert_eq!(error.as_deref(), Some("Inject failed"));
+    } else {
+        panic!("Expected Palette dialog");
+    }
+
+    let action = crate::state::palette::PaletteAction::CreateKild;
+    state.record_palette_use(&action);
+    state.close_dialog();
+
+    assert!(!state.dialog().is_palette());
+    assert!(state.palette_history().bonus(&action) > 0);
+}
+
 #[test]
 fn test_clear_error() {
     let mut state = AppState::test_new();
@@ -864,3 +883,27 @@ fn test_error_persists_through_loading_lifecycle() {
     state.clear_loading("branch-1");
     assert!(state.get_error("branch-1").is_some());
 }
+
+#[test]
+fn test_remove_pending_approval() {
+    let approval = |id: &str| kild_protocol::PendingApproval {
+        id: id.to_string(),
+        branch: "feature".to_string(),
+        agent: "claude".to_string(),
+        tool: "Bash".to_string(),
+        summary: "cargo test".to_string(),
+        details: None,
+        requested_at: "2026-01-01T00:00:00Z".to_string(),
+    };
+    let mut state = AppState::test_new();
+    state.set_pending_approvals(vec![approval("a1"), approval("a2")]);
+
+    state.remove_pending_approval("a1");
+
+    let ids: Vec<&str> = state
+        .pending_approvals()
+        .iter()
+        .map(|a| a.id.as_str())
+        .collect();
+    assert_eq!(ids, vec!["a2"]);
+}
@@ -0,0 +1,137 @@
+//! Remembered create dialog options.
+//!
+//! The create dialog reopens with the options last used for the active
+//! project: agent, base branch, agent flags, runtime and skip fetch. Branch
+//! name, issue, note and initial prompt belong to one kild and are not kept.
+//! Neither are yolo and main worktree: carrying either over silently would
+//! let the next kild skip permission prompts or run in the main checkout
+//! without anyone asking for it.
+//! `~/.kild/create_options.json` is keyed like the layouts file.
+
+use std::collections::BTreeMap;
+use std::path::{Path, PathBuf};
+
+use kild_protocol::RuntimeMode;
+use serde::{Deserialize, Serialize};
+
+use crate::state::json_file;
+
+/// Runtime selection in the create dialog, mirroring `--daemon` and
+/// `--no-daemon`.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum RuntimeChoice {
+    /// Neither flag: `daemon.enabled` from config decides.
+    #[default]
+    Config,
+    Daemon,
+    Terminal,
+}
+
+impl RuntimeChoice {
+    /// The choice after this one when cycling in the dialog.
+    pub fn next(self) -> Self {
+        match self {
+            RuntimeChoice::Config => RuntimeChoice::Daemon,
+            RuntimeChoice::Daemon => RuntimeChoice::Terminal,
+            RuntimeChoice::Terminal => RuntimeChoice::Config,
+        }
+    }
+
+    pub fn label(self) -> &'static str {
+        match self {
+            RuntimeChoice::Config => "config default",
+            RuntimeChoice::Daemon => "daemon",
+            RuntimeChoice::Terminal => "terminal",
+        }
+    }
+
+    /// Explicit runtime mode, `None` to defer to config.
+    pub fn runtime_mode(self) -> Option<RuntimeMode> {
+        match self {
+            RuntimeChoice::Config => None,
+            RuntimeChoice::Daemon => Some(RuntimeMode::Daemon),
+            RuntimeChoice::Terminal => Some(RuntimeMode::Terminal),
+        }
+    }
+}
+
+/// Options the create dialog remembers for a project.
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct CreateOptions {
+    pub agent: Option<String>,
+    pub base_branch: Option<String>,
+    pub flags: Option<String>,
+    pub runtime: RuntimeChoice,
+    pub no_fetch: bool,
+}
+
+/// Contents of `~/.kild/create_options.json`.
+#[derive(Clone, Debug, Default, Serialize, Deserialize)]
+pub struct CreateOptionsFile {
+    /// Options keyed by project ID (or [`super::layouts::ALL_PROJECTS_KEY`]).
+    #[serde(default)]
+    pub projects: BTreeMap<String, CreateOptions>,
+}
+
+/// Path of the create options file, or `None` if the home directory can't
+/// be resolved.
+pub fn create_options_file_path() -> Option<PathBuf> {
+    json_file::file_path("create options", |paths| paths.ui_create_options_file())
+}
+
+/// Load remembered options from `path`.
+///
+/// A missing file means nothing is remembered yet. An unreadable or
+/// unparsable file is an error, so the caller can avoid overwriting it.
+pub fn load_create_options(path: &Path) -> Result<CreateOptionsFile, String> {
+    json_file::load(path, "create options")
+}
+
+/// Write remembered options to `path`, creating its directory if needed.
+pub fn save_create_options(path: &Path, file: &CreateOptionsFile) -> Result<(), String> {
+    json_file::save(path, "create options", file)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_runtime_choice_cycles_through_all() {
+        let mut choice = RuntimeChoice::default();
+        let mut modes = Vec::new();
+        for _ in 0..3 {
+            modes.push(choice.runtime_mode());
+            choice = choice.next();
+        }
+        assert_eq!(choice, RuntimeChoice::Config);
+        assert_eq!(
+            modes,
+            vec![None, Some(RuntimeMode::Daemon), Some(RuntimeMode::Terminal)]
+        );
+    }
+
+    #[test]
+    fn test_save_and_load_round_trip() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("nested").join("create_options.json");
+        assert!(load_create_options(&path).unwrap().projects.is_empty());
+
+        let mut file = CreateOptionsFile::default();
+        file.projects.insert(
+            "proj".to_string(),
+            CreateOptions {
+                agent: Some("codex".to_string()),
+                base_branch: Some("develop".to_string()),
+                runtime: RuntimeChoice::Daemon,
+                ..Default::default()
+            },
+        );
+        save_create_options(&path, &file).unwrap();
+
+        let loaded = load_create_options(&path).unwrap();
+        assert_eq!(loaded.projects, file.projects);
+    }
+}
@@ -1,5 +1,11 @@
 use kild_core::DestroySafety;
 
+use crate::state::create_options::{CreateOptions, RuntimeChoice};
+use crate::state::palette::PaletteState;
+
+/// Most branch completions shown under a create dialog field.
+const MAX_BRANCH_SUGGESTIONS: usize = 6;
+
 /// Dialog state for the application.
 ///
 /// Only one dialog can be open at a time. This enum enforces mutual exclusion
@@ -26,6 +32,11 @@ pub enum DialogState {
     },
     /// Add project dialog is open.
     AddProject { error: Option<String> },
+    /// Command palette is open.
+    Palette {
+        palette: PaletteState,
+        error: Option<String>,
+    },
 }
 
 impl DialogState {
@@ -44,6 +55,11 @@ impl DialogState {
         matches!(self, DialogState::AddProject { .. })
     }
 
+    /// Returns true if the command palette is open.
+    pub fn is_palette(&self) -> bool {
+        matches!(self, DialogState::Palette { .. })
+    }
+
     /// Open the create dialog with default form state.
     pub fn open_create() -> Self {
         DialogState::Create {
@@ -65,6 +81,14 @@ impl DialogState {
     pub fn open_add_project() -> Self {
         DialogState::AddProject { error: None }
     }
+
+    /// Open the command palette in search mode.
+    pub fn open_palette() -> Self {
+        DialogState::Palette {
+            palette: PaletteState::default(),
+            error: None,
+        }
+    }
 }
 
 /// Which field is focused in the create dialog.
@@ -80,11 +104,19 @@ pub enum CreateDialogField {
 /// Form state for creating a new kild.
 ///
 /// Text input state is managed by gpui-component's `InputState` entities
-/// in MainView. This struct only tracks agent selection and focus state.
+/// in MainView. This struct tracks agent selection, focus state, the
+/// `kild create` toggles and the branch names offered as completions.
 #[derive(Clone, Debug)]
 pub struct CreateFormState {
     selected_agent_index: usize,
     pub focused_field: CreateDialogField,
+    pub yolo: bool,
+    pub runtime: RuntimeChoice,
+    pub use_main: bool,
+    pub no_fetch: bool,
+    /// Local and remote branch names of the project, loaded in the
+    /// background when the dialog opens.
+    known_branches: Vec<String>,
 }
 
 impl CreateFormState {
@@ -107,6 +139,58 @@ impl CreateFormState {
         let agents = kild_core::agents::valid_agent_names();
         agents[self.selected_agent_index].to_string()
     }
+
+    /// Restore the options last used for the project. A remembered agent
+    /// that is no longer known keeps the default selection. Yolo and main
+    /// worktree always start off.
+    pub fn apply_remembered(&mut self, options: &CreateOptions) {
+        if let Some(agent) = &options.agent
+            && let Some(index) = kild_core::agents::valid_agent_names()
+                .iter()
+                .position(|name| name == agent)
+        {
+            self.selected_agent_index = index;
+        }
+        self.runtime = options.runtime;
+        self.no_fetch = options.no_fetch;
+    }
+
+    /// Set the completion candidates from the project's branches.
+    ///
+    /// Existing kild branches are skipped and remote names lose their
+    /// `<remote>/` prefix, so `origin/fix-login` completes to `fix-login`.
+    pub fn set_known_branches(&mut self, local: Vec<String>, remote: Vec<String>) {
+        let local = local
+            .into_iter()
+            .filter(|name| !name.starts_with(kild_core::git::KILD_BRANCH_PREFIX));
+        let remote = remote
+            .into_iter()
+            .filter_map(|name| name.split_once('/').map(|(_, branch)| branch.to_string()));
+        let mut names: Vec<String> = local.chain(remote).collect();
+        names.sort();
+        names.dedup();
+        self.known_branches = names;
+    }
+
+    /// Branch names completing `query`: prefix matches first, then other
+    /// case-insensitive substring matches. Empty for an empty query or once
+    /// the query is an exact match.
+    pub fn branch_suggestions(&self, query: &str) -> Vec<&str> {
+        let query = query.trim();
+        if query.is_empty() || self.known_branches.iter().any(|name| name == query) {
+            return Vec::new();
+        }
+        let lower = query.to_lowercase();
+        let (mut prefixed, contained): (Vec<&str>, Vec<&str>) = self
+            .known_branches
+            .iter()
+            .map(String::as_str)
+            .filter(|name| name.to_lowercase().contains(&lower))
+            .partition(|name| name.to_lowercase().starts_with(&lower));
+        prefixed.extend(contained);
+        prefixed.truncate(MAX_BRANCH_SUGGESTIONS);
+        prefixed
+    }
 }
 
 impl Default for CreateFormState {
@@ -122,6 +206,11 @@ impl Default for CreateFormState {
             return Self {
                 selected_agent_index: 0,
                 focused_field: CreateDialogField::default(),
+                yolo: false,
+                runtime: RuntimeChoice::default(),
+                use_main: false,
+                no_fetch: false,
+                known_branches: Vec::new(),
             };
         }
 
@@ -141,6 +230,11 @@ impl Default for CreateFormState {
         Self {
             selected_agent_index: index,
             focused_field: CreateDialogField::default(),
+            yolo: false,
+            runtime: RuntimeChoice::default(),
+            use_main: false,
+            no_fetch: false,
+            known_branches: Vec::new(),
         }
     }
 }
@@ -168,10 +262,16 @@ mod tests {
         assert!(!add_project.is_confirm());
         assert!(add_project.is_add_project());
 
+        let palette = DialogState::open_palette();
+        assert!(palette.is_palette());
+        assert!(!palette.is_create());
+        assert!(!palette.is_add_project());
+
         let none = DialogState::None;
         assert!(!none.is_create());
         assert!(!none.is_confirm());
         assert!(!none.is_add_project());
+        assert!(!none.is_palette());
     }
 
     #[test]
@@ -209,6 +309,58 @@ mod tests {
         assert_eq!(form.selected_agent(), agents[agents.len() - 1]);
     }
 
+    #[test]
+    fn test_create_form_state_apply_remembered() {
+        let agents = kild_core::agents::valid_agent_names();
+        let mut form = CreateFormState::default();
+        form.apply_remembered(&CreateOptions {
+            agent: Some(agents[agents.len() - 1].to_string()),
+            runtime: RuntimeChoice::Terminal,
+            no_fetch: true,
+            ..Default::default()
+        });
+        assert_eq!(form.selected_agent(), agents[agents.len() - 1]);
+        assert!(form.no_fetch && !form.yolo && !form.use_main);
+        assert_eq!(form.runtime, RuntimeChoice::Terminal);
+
+        // An unknown agent keeps the current selection
+        form.apply_remembered(&CreateOptions {
+            agent: Some("no-such-agent".to_string()),
+            ..Default::default()
+        });
+        assert_eq!(form.selected_agent(), agents[agents.len() - 1]);
+    }
+
+    #[test]
+    fn test_branch_suggestions() {
+        let mut form = CreateFormState::default();
+        form.set_known_branches(
+            vec![
+                "main".to_string(),
+                "kild/old-work".to_string(),
+                "fix-login".to_string(),
+            ],
+            vec![
+                "origin/fix-login".to_string(),
+                "origin/feature/login-page".to_string(),
+                "upstream/main".to_string(),
+            ],
+        );
+
+        assert_eq!(
+            form.branch_suggestions("login"),
+            vec!["feature/login-page", "fix-login"]
+        );
+        assert_eq!(form.branch_suggestions("FIX"), vec!["fix-login"]);
+        assert_eq!(
+            form.branch_suggestions("f"),
+            vec!["feature/login-page", "fix-login"]
+        );
+        assert!(form.branch_suggestions("old").is_empty());
+        assert!(form.branch_suggestions("main").is_empty());
+        assert!(form.branch_suggestions("").is_empty());
+    }
+
     #[test]
     fn test_note_allows_spaces() {
         let mut note = String::new();
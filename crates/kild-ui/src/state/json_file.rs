@@ -0,0 +1,114 @@
+//! JSON files the UI keeps under `~/.kild/` (activity, layouts, create
+//! options).
+//!
+//! Saves go through a temp file renamed into place, so a crash mid-write
+//! leaves the previous file intact rather than a truncated one that fails
+//! to load.
+
+use std::path::{Path, PathBuf};
+
+use kild_paths::KildPaths;
+use serde::Serialize;
+use serde::de::DeserializeOwned;
+
+/// Path of a UI state file, or `None` if the home directory can't be
+/// resolved.
+pub fn file_path(what: &str, file: impl FnOnce(&KildPaths) -> PathBuf) -> Option<PathBuf> {
+    match KildPaths::resolve() {
+        Ok(paths) => Some(file(&paths)),
+        Err(e) => {
+            tracing::warn!(event = "ui.state_file.path_resolve_failed", file = what, error = %e);
+            None
+        }
+    }
+}
+
+/// Load `path`, named `what` in errors.
+///
+/// A missing file is `T::default()`. An unreadable or unparsable file is an
+/// error, so the caller can avoid overwriting it.
+pub fn load<T: DeserializeOwned + Default>(path: &Path, what: &str) -> Result<T, String> {
+    let content = match std::fs::read_to_string(path) {
+        Ok(content) => content,
+        Err(e) if e.kind() == std::io::ErrorKind::NotFound => return Ok(T::default()),
+        Err(e) => return Err(format!("Failed to read {}: {}", path.display(), e)),
+    };
+    serde_json::from_str(&content)
+        .map_err(|e| format!("Invalid {} file {}: {}", what, path.display(), e))
+}
+
+/// Write `value` to `path` atomically, creating its directory if needed.
+pub fn save<T: Serialize>(path: &Path, what: &str, value: &T) -> Result<(), String> {
+    if let Some(parent) = path.parent() {
+        std::fs::create_dir_all(parent)
+            .map_err(|e| format!("Failed to create {}: {}", parent.display(), e))?;
+    }
+    let json = serde_json::to_string_pretty(value)
+        .map_err(|e| format!("Failed to serialize {}: {}", what, e))?;
+
+    let mut temp = path.as_os_str().to_owned();
+    temp.push(".tmp");
+    let temp = PathBuf::from(temp);
+    std::fs::write(&temp, json)
+        .and_then(|()| std::fs::rename(&temp, path))
+        .map_err(|e| {
+            let _ = std::fs::remove_file(&temp);
+            format!("Failed to write {}: {}", path.display(), e)
+        })?;
+    tracing::debug!(event = "ui.state_file.saved", file = what, path = %path.display());
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::BTreeMap;
+
+    type Sample = BTreeMap<String, u32>;
+
+    #[test]
+    fn test_missing_file_loads_default() {
+        let dir = tempfile::tempdir().unwrap();
+        let loaded: Sample = load(&dir.path().join("missing.json"), "sample").unwrap();
+        assert!(loaded.is_empty());
+    }
+
+    #[test]
+    fn test_save_and_load_round_trip() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("nested/sample.json");
+        let sample = Sample::from([("a".to_string(), 1)]);
+
+        save(&path, "sample", &sample).unwrap();
+        assert_eq!(load::<Sample>(&path, "sample").unwrap(), sample);
+        assert!(!dir.path().join("nested/sample.json.tmp").exists());
+
+        // Overwrites in place
+        let sample = Sample::from([("b".to_string(), 2)]);
+        save(&path, "sample", &sample).unwrap();
+        assert_eq!(load::<Sample>(&path, "sample").unwrap(), sample);
+    }
+
+    #[test]
+    fn test_invalid_file_is_an_error() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("sample.json");
+        std::fs::write(&path, "not json").unwrap();
+
+        let err = load::<Sample>(&path, "sample").unwrap_err();
+        assert!(err.starts_with("Invalid sample file"), "{err}");
+    }
+
+    #[test]
+    fn test_failed_save_keeps_previous_file() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("sample.json");
+        let sample = Sample::from([("a".to_string(), 1)]);
+        save(&path, "sample", &sample).unwrap();
+
+        // A directory where the temp file goes makes the write fail
+        std::fs::create_dir(dir.path().join("sample.json.tmp")).unwrap();
+        assert!(save(&path, "sample", &Sample::new()).is_err());
+        assert_eq!(load::<Sample>(&path, "sample").unwrap(), sample);
+    }
+}
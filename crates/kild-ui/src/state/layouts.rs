@@ -0,0 +1,335 @@
+//! Pane grid layouts and their persistence.
+//!
+//! A layout is a tree of row/column splits whose leaves are pane slots.
+//! Presets cover the common shapes; `Custom` trees can be written into the
+//! layouts file by hand. `~/.kild/layouts.json` also remembers each
+//! project's workspaces (layout plus which kild tab sits in which slot) and
+//! the layouts the user saved under a name.
+
+use std::collections::BTreeMap;
+use std::path::{Path, PathBuf};
+
+use serde::{Deserialize, Serialize};
+
+use crate::state::json_file;
+
+/// Upper bound on panes in one layout.
+pub const MAX_SLOTS: usize = 9;
+
+/// Key under which the "All Projects" selection stores its workspaces.
+pub const ALL_PROJECTS_KEY: &str = "all";
+
+/// One node of a layout tree.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum SplitNode {
+    /// A pane slot.
+    Pane,
+    /// Children side by side, left to right.
+    Row(Vec<SplitNode>),
+    /// Children stacked, top to bottom.
+    Column(Vec<SplitNode>),
+}
+
+impl SplitNode {
+    /// Number of pane slots in this subtree.
+    pub fn pane_count(&self) -> usize {
+        match self {
+            SplitNode::Pane => 1,
+            SplitNode::Row(children) | SplitNode::Column(children) => {
+                children.iter().map(SplitNode::pane_count).sum()
+            }
+        }
+    }
+
+    fn has_empty_split(&self) -> bool {
+        match self {
+            SplitNode::Pane => false,
+            SplitNode::Row(children) | SplitNode::Column(children) => {
+                children.is_empty() || children.iter().any(SplitNode::has_empty_split)
+            }
+        }
+    }
+}
+
+/// Arrangement of panes in a workspace.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(tag = "kind", rename_all = "snake_case")]
+pub enum PaneLayout {
+    /// `rows` x `cols` equal panes.
+    Grid { rows: usize, cols: usize },
+    /// One large pane on the left, `stack` panes stacked on the right.
+    MainStack { stack: usize },
+    /// Any split tree.
+    Custom { root: SplitNode },
+}
+
+impl Default for PaneLayout {
+    fn default() -> Self {
+        PaneLayout::Grid { rows: 2, cols: 2 }
+    }
+}
+
+impl PaneLayout {
+    /// Built-in layouts offered in the command palette.
+    pub fn presets() -> Vec<PaneLayout> {
+        vec![
+            PaneLayout::Grid { rows: 1, cols: 2 },
+            PaneLayout::Grid { rows: 2, cols: 2 },
+            PaneLayout::Grid { rows: 3, cols: 3 },
+            PaneLayout::MainStack { stack: 3 },
+        ]
+    }
+
+    /// Short description, e.g. "2x2" or "main + 3".
+    pub fn label(&self) -> String {
+        match self {
+            PaneLayout::Grid { rows, cols } => format!("{rows}x{cols}"),
+            PaneLayout::MainStack { stack } => format!("main + {stack}"),
+            PaneLayout::Custom { root } => format!("custom ({} panes)", root.pane_count()),
+        }
+    }
+
+    /// The split tree this layout renders as.
+    pub fn tree(&self) -> SplitNode {
+        match self {
+            PaneLayout::Grid { rows, cols } => {
+                let row = || match cols {
+                    1 => SplitNode::Pane,
+                    n => SplitNode::Row(vec![SplitNode::Pane; *n]),
+                };
+                match rows {
+                    1 => row(),
+                    n => SplitNode::Column((0..*n).map(|_| row()).collect()),
+                }
+            }
+            PaneLayout::MainStack { stack } => SplitNode::Row(vec![
+                SplitNode::Pane,
+                SplitNode::Column(vec![SplitNode::Pane; *stack]),
+            ]),
+            PaneLayout::Custom { root } => root.clone(),
+        }
+    }
+
+    /// Number of pane slots.
+    pub fn slot_count(&self) -> usize {
+        self.tree().pane_count()
+    }
+
+    /// Reject layouts that can't be rendered: empty splits, zero panes or
+    /// more than [`MAX_SLOTS`].
+    pub fn validate(&self) -> Result<(), String> {
+        let tree = self.tree();
+        if tree.has_empty_split() {
+            return Err(format!("layout {} has an empty split", self.label()));
+        }
+        let count = tree.pane_count();
+        if !(1..=MAX_SLOTS).contains(&count) {
+            return Err(format!(
+                "layout {} has {count} panes (allowed: 1-{MAX_SLOTS})",
+                self.label()
+            ));
+        }
+        Ok(())
+    }
+}
+
+/// A kild terminal tab placed in a slot.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SavedSlot {
+    pub session_id: String,
+    pub tab: usize,
+}
+
+/// A workspace: its layout and what each slot shows (`None` = empty).
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SavedWorkspace {
+    pub layout: PaneLayout,
+    #[serde(default)]
+    pub slots: Vec<Option<SavedSlot>>,
+}
+
+/// The Control view workspaces of one project.
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ProjectLayout {
+    pub workspaces: Vec<SavedWorkspace>,
+    #[serde(default)]
+    pub active_workspace: usize,
+}
+
+/// Contents of `~/.kild/layouts.json`.
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct LayoutsFile {
+    /// Workspaces keyed by project ID (or [`ALL_PROJECTS_KEY`]).
+    #[serde(default)]
+    pub projects: BTreeMap<String, ProjectLayout>,
+    /// Layouts saved under a name, e.g. "review" or "monitor".
+    #[serde(default)]
+    pub named: BTreeMap<String, SavedWorkspace>,
+}
+
+impl LayoutsFile {
+    /// Name of the named layout after `current`, wrapping around.
+    pub fn next_named(&self, current: Option<&str>) -> Option<&str> {
+        let names = || self.named.keys().map(String::as_str);
+        current
+            .and_then(|current| names().find(|name| *name > current))
+            .or_else(|| names().next())
+    }
+}
+
+/// Path of the layouts file, or `None` if the home directory is unknown.
+pub fn layouts_file_path() -> Option<PathBuf> {
+    json_file::file_path("layouts", |paths| paths.ui_layouts_file())
+}
+
+/// Load layouts from `path`.
+///
+/// A missing file is an empty set of layouts. Invalid layouts inside an
+/// otherwise valid file are logged and dropped. An unreadable or unparsable
+/// file is an error, so the caller can avoid overwriting it.
+pub fn load_layouts(path: &Path) -> Result<LayoutsFile, String> {
+    let mut file: LayoutsFile = json_file::load(path, "layouts")?;
+
+    let keep = |name: &str, workspace: &SavedWorkspace| match workspace.layout.validate() {
+        Ok(()) => true,
+        Err(e) => {
+            tracing::warn!(event = "ui.layouts.invalid_layout_dropped", name = name, error = %e);
+            false
+        }
+    };
+    file.named.retain(|name, workspace| keep(name, workspace));
+    for (key, project) in &mut file.projects {
+        project.workspaces.retain(|workspace| keep(key, workspace));
+    }
+    file.projects
+        .retain(|_, project| !project.workspaces.is_empty());
+    Ok(file)
+}
+
+/// Write layouts to `path`, creating its directory if needed.
+pub fn save_layouts(path: &Path, file: &LayoutsFile) -> Result<(), String> {
+    json_file::save(path, "layouts", file)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_preset_slot_counts() {
+        let counts: Vec<usize> = PaneLayout::presets()
+            .iter()
+            .map(PaneLayout::slot_count)
+            .collect();
+        assert_eq!(counts, vec![2, 4, 9, 4]);
+        assert_eq!(PaneLayout::default().slot_count(), 4);
+        assert!(PaneLayout::presets().iter().all(|l| l.validate().is_ok()));
+    }
+
+    #[test]
+    fn test_grid_tree_collapses_single_row_and_column() {
+        assert_eq!(
+            PaneLayout::Grid { rows: 1, cols: 2 }.tree(),
+            SplitNode::Row(vec![SplitNode::Pane, SplitNode::Pane])
+        );
+        assert_eq!(
+            PaneLayout::Grid { rows: 2, cols: 1 }.tree(),
+            SplitNode::Column(vec![SplitNode::Pane, SplitNode::Pane])
+        );
+        assert_eq!(
+            PaneLayout::Grid { rows: 1, cols: 1 }.tree(),
+            SplitNode::Pane
+        );
+    }
+
+    #[test]
+    fn test_validate_rejects_empty_and_oversized_layouts() {
+        assert!(PaneLayout::Grid { rows: 0, cols: 2 }.validate().is_err());
+        assert!(PaneLayout::Grid { rows: 4, cols: 3 }.validate().is_err());
+        let empty_split = PaneLayout::Custom {
+            root: SplitNode::Row(vec![SplitNode::Pane, SplitNode::Column(vec![])]),
+        };
+        assert!(empty_split.validate().is_err());
+    }
+
+    #[test]
+    fn test_custom_layout_json_shape() {
+        let json = r#"{
+            "kind": "custom",
+            "root": {"row": ["pane", {"column": ["pane", "pane"]}]}
+        }"#;
+        let layout: PaneLayout = serde_json::from_str(json).unwrap();
+        assert_eq!(layout.slot_count(), 3);
+        assert_eq!(layout.label(), "custom (3 panes)");
+    }
+
+    #[test]
+    fn test_save_and_load_round_trip() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("nested").join("layouts.json");
+        let mut file = LayoutsFile::default();
+        file.projects.insert(
+            "abc".to_string(),
+            ProjectLayout {
+                workspaces: vec![SavedWorkspace {
+                    layout: PaneLayout::MainStack { stack: 2 },
+                    slots: vec![
+                        Some(SavedSlot {
+                            session_id: "s1".to_string(),
+                            tab: 1,
+                        }),
+                        None,
+                        None,
+                    ],
+                }],
+                active_workspace: 0,
+            },
+        );
+        file.named
+            .insert("review".to_string(), SavedWorkspace::default());
+
+        save_layouts(&path, &file).unwrap();
+        assert_eq!(load_layouts(&path).unwrap(), file);
+    }
+
+    #[test]
+    fn test_load_drops_invalid_layouts() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("layouts.json");
+        assert_eq!(load_layouts(&path).unwrap(), LayoutsFile::default());
+
+        std::fs::write(
+            &path,
+            r#"{
+                "named": {
+                    "ok": {"layout": {"kind": "grid", "rows": 1, "cols": 2}},
+                    "huge": {"layout": {"kind": "grid", "rows": 5, "cols": 5}}
+                },
+                "projects": {
+                    "p": {"workspaces": [{"layout": {"kind": "main_stack", "stack": 0}}]}
+                }
+            }"#,
+        )
+        .unwrap();
+        let file = load_layouts(&path).unwrap();
+        assert_eq!(file.named.keys().collect::<Vec<_>>(), vec!["ok"]);
+        // main + 0 is a single pane plus an empty column: dropped, and the
+        // project with no workspaces left goes with it.
+        assert!(file.projects.is_empty());
+    }
+
+    #[test]
+    fn test_next_named_cycles_in_name_order() {
+        let mut file = LayoutsFile::default();
+        assert_eq!(file.next_named(None), None);
+        for name in ["review", "monitor"] {
+            file.named
+                .insert(name.to_string(), SavedWorkspace::default());
+        }
+        assert_eq!(file.next_named(None), Some("monitor"));
+        assert_eq!(file.next_named(Some("monitor")), Some("review"));
+        assert_eq!(file.next_named(Some("review")), Some("monitor"));
+        assert_eq!(file.next_named(Some("gone")), Some("monitor"));
+    }
+}
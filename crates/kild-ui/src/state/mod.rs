@@ -4,12 +4,20 @@
 //! which provides a facade over internal state modules. Use `AppState` methods
 //! to interact with state; internal modules are implementation details.
 
+pub mod activity;
 pub mod app_state;
+pub mod create_options;
 pub mod dialog;
 pub mod errors;
+pub mod json_file;
+pub mod layouts;
 pub mod loading;
+pub mod palette;
+pub mod remote_hosts;
+pub mod review;
 pub mod selection;
 pub mod sessions;
+pub mod syntax;
 
 // Re-export all public types at module level so consumers use `crate::state::*`
 pub use app_state::AppState;
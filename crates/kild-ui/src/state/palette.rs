@@ -0,0 +1,571 @@
+//! Command palette model.
+//!
+//! Builds the list of palette entries from the current kilds, fuzzy-matches
+//! them against the typed query, and ranks recently used entries higher.
+//! Rendering lives in `views::command_palette`.
+
+use std::collections::VecDeque;
+
+use kild_core::{ProcessStatus, SessionSnapshot};
+
+use crate::state::layouts::PaneLayout;
+use crate::terminal::broadcast::BroadcastScope;
+
+/// How many recently used entries are remembered for ranking.
+const HISTORY_LIMIT: usize = 32;
+
+/// Upper bound on entries returned by [`rank_items`].
+pub const MAX_RESULTS: usize = 50;
+
+/// Score added per history slot; the most recent entry gets the largest bonus.
+const RECENCY_WEIGHT: i64 = 2;
+
+/// An action the palette can run.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum PaletteAction {
+    /// Open the create kild dialog.
+    CreateKild,
+    /// Select a kild and focus its terminal.
+    FocusTerminal { session_id: String, branch: String },
+    /// Open a new agent in a kild (`None` uses the default agent).
+    OpenKild {
+        branch: String,
+        agent: Option<String>,
+    },
+    /// Stop the agent in a running kild.
+    StopKild { branch: String },
+    /// Open the destroy confirmation for a kild.
+    DestroyKild { branch: String },
+    /// Switch the palette to text entry and inject the text into a kild.
+    InjectText { branch: String },
+    /// Open the kild's pull request in the browser.
+    OpenPr { branch: String },
+    /// Turn broadcast input on for the given scope.
+    StartBroadcast { scope: BroadcastScope },
+    /// Turn broadcast input off.
+    StopBroadcast,
+    /// Rearrange the active workspace into a preset layout.
+    SetLayout { layout: PaneLayout },
+    /// Replace the active workspace with a named layout.
+    ApplyNamedLayout { name: String },
+    /// Switch the palette to text entry and save the active workspace under a name.
+    SaveLayout,
+}
+
+impl PaletteAction {
+    /// Stable identifier used for recent-items ranking.
+    pub fn key(&self) -> String {
+        match self {
+            PaletteAction::CreateKild => "create".to_string(),
+            PaletteAction::FocusTerminal { branch, .. } => format!("focus:{branch}"),
+            PaletteAction::OpenKild { branch, agent } => {
+                format!("open:{branch}:{}", agent.as_deref().unwrap_or(""))
+            }
+            PaletteAction::StopKild { branch } => format!("stop:{branch}"),
+            PaletteAction::DestroyKild { branch } => format!("destroy:{branch}"),
+            PaletteAction::InjectText { branch } => format!("inject:{branch}"),
+            PaletteAction::OpenPr { branch } => format!("pr:{branch}"),
+            PaletteAction::StartBroadcast { scope } => format!("broadcast:{}", scope.label()),
+            PaletteAction::StopBroadcast => "broadcast:stop".to_string(),
+            PaletteAction::SetLayout { layout } => format!("layout:{}", layout.label()),
+            PaletteAction::ApplyNamedLayout { name } => format!("layout-named:{name}"),
+            PaletteAction::SaveLayout => "layout:save".to_string(),
+        }
+    }
+}
+
+/// A single palette entry.
+#[derive(Clone, Debug)]
+pub struct PaletteItem {
+    pub action: PaletteAction,
+    /// Primary text, e.g. "Open feature-auth with codex".
+    pub label: String,
+    /// Secondary text: the kild's note and issue number, if any.
+    pub detail: Option<String>,
+    /// Lowercased text the query is matched against.
+    search_text: String,
+}
+
+impl PaletteItem {
+    fn new(action: PaletteAction, label: String, detail: Option<String>) -> Self {
+        let search_text = match &detail {
+            Some(detail) => format!("{label} {detail}"),
+            None => label.clone(),
+        }
+        .to_lowercase();
+        Self {
+            action,
+            label,
+            detail,
+            search_text,
+        }
+    }
+}
+
+/// Build every palette entry for the given kilds.
+///
+/// Stop and inject are only offered for running kilds. Each kild gets an
+/// "Open" entry for the default agent plus one per agent in `agents`.
+pub fn build_items(displays: &[&SessionSnapshot], agents: &[&str]) -> Vec<PaletteItem> {
+    let mut items = vec![PaletteItem::new(
+        PaletteAction::CreateKild,
+        "Create new kild".to_string(),
+        None,
+    )];
+
+    for display in displays {
+        let session = &display.session;
+        let branch = session.branch.to_string();
+        let detail = kild_detail(session.note.as_deref(), session.issue);
+        let running = display.process_status == ProcessStatus::Running;
+        let item = |action, label: String| PaletteItem::new(action, label, detail.clone());
+
+        items.push(item(
+            PaletteAction::FocusTerminal {
+                session_id: session.id.to_string(),
+                branch: branch.clone(),
+            },
+            format!("Focus terminal: {branch}"),
+        ));
+        items.push(item(
+            PaletteAction::OpenKild {
+                branch: branch.clone(),
+                agent: None,
+            },
+            format!("Open {branch}"),
+        ));
+        for agent in agents {
+            items.push(item(
+                PaletteAction::OpenKild {
+                    branch: branch.clone(),
+                    agent: Some(agent.to_string()),
+                },
+                format!("Open {branch} with {agent}"),
+            ));
+        }
+        if running {
+            items.push(item(
+                PaletteAction::InjectText {
+                    branch: branch.clone(),
+                },
+                format!("Inject text into {branch}"),
+            ));
+            items.push(item(
+                PaletteAction::StopKild {
+                    branch: branch.clone(),
+                },
+                format!("Stop {branch}"),
+            ));
+        }
+        items.push(item(
+            PaletteAction::OpenPr {
+                branch: branch.clone(),
+            },
+            format!("Open PR for {branch}"),
+        ));
+        items.push(item(
+            PaletteAction::DestroyKild { branch },
+            format!("Destroy {}", session.branch),
+        ));
+    }
+
+    items
+}
+
+/// Broadcast input entries: start either scope, or stop and switch scope
+/// while `active` is set.
+pub fn broadcast_items(active: Option<BroadcastScope>) -> Vec<PaletteItem> {
+    let start = |scope, label: &str| {
+        PaletteItem::new(
+            PaletteAction::StartBroadcast { scope },
+            label.to_string(),
+            None,
+        )
+    };
+    let mut items = Vec::new();
+    if active.is_some() {
+        items.push(PaletteItem::new(
+            PaletteAction::StopBroadcast,
+            "Stop broadcasting input".to_string(),
+            None,
+        ));
+    }
+    if active != Some(BroadcastScope::Panes) {
+        items.push(start(
+            BroadcastScope::Panes,
+            "Broadcast input to selected panes",
+        ));
+    }
+    if active != Some(BroadcastScope::Project) {
+        items.push(start(
+            BroadcastScope::Project,
+            "Broadcast input to all project sessions",
+        ));
+    }
+    items
+}
+
+/// Layout entries: each preset, each named layout, and "save as".
+pub fn layout_items(named: &[&str]) -> Vec<PaletteItem> {
+    let mut items: Vec<PaletteItem> = PaneLayout::presets()
+        .into_iter()
+        .map(|layout| {
+            let label = format!("Layout: {}", layout.label());
+            PaletteItem::new(PaletteAction::SetLayout { layout }, label, None)
+        })
+        .collect();
+    items.extend(named.iter().map(|name| {
+        PaletteItem::new(
+            PaletteAction::ApplyNamedLayout {
+                name: name.to_string(),
+            },
+            format!("Layout: {name}"),
+            Some("saved layout".to_string()),
+        )
+    }));
+    items.push(PaletteItem::new(
+        PaletteAction::SaveLayout,
+        "Save layout as...".to_string(),
+        None,
+    ));
+    items
+}
+
+/// "note · #42", "note", "#42" or `None`.
+fn kild_detail(note: Option<&str>, issue: Option<u32>) -> Option<String> {
+    let issue = issue.map(|n| format!("#{n}"));
+    match (note, issue) {
+        (Some(note), Some(issue)) => Some(format!("{note} · {issue}")),
+        (Some(note), None) => Some(note.to_string()),
+        (None, issue) => issue,
+    }
+}
+
+/// Score `text` against `query`, or `None` if it doesn't match.
+///
+/// Every whitespace-separated query token must match, either as a substring
+/// (scored highest, more so at a word start) or as an in-order subsequence
+/// (bonuses for consecutive characters and word starts). Matching is
+/// case-insensitive; an empty query matches everything with score 0.
+pub fn fuzzy_score(query: &str, text: &str) -> Option<i64> {
+    let text = text.to_lowercase();
+    query
+        .split_whitespace()
+        .map(|token| token_score(&token.to_lowercase(), &text))
+        .sum()
+}
+
+fn token_score(token: &str, text: &str) -> Option<i64> {
+    if let Some(pos) = text.find(token) {
+        let boundary = if is_word_start(text, pos) { 50 } else { 0 };
+        let len = token.chars().count() as i64;
+        return Some(100 + 10 * len + boundary - (pos as i64).min(50));
+    }
+
+    let chars: Vec<char> = text.chars().collect();
+    let mut score = 0;
+    let mut next = 0;
+    let mut prev: Option<usize> = None;
+    for qc in token.chars() {
+        let idx = (next..chars.len()).find(|&i| chars[i] == qc)?;
+        score += 1;
+        if idx > 0 && prev == Some(idx - 1) {
+            score += 5;
+        }
+        if idx == 0 || !chars[idx - 1].is_alphanumeric() {
+            score += 8;
+        }
+        prev = Some(idx);
+        next = idx + 1;
+    }
+    Some(score)
+}
+
+fn is_word_start(text: &str, byte_pos: usize) -> bool {
+    text[..byte_pos]
+        .chars()
+        .next_back()
+        .is_none_or(|c| !c.is_alphanumeric())
+}
+
+/// Recently run palette entries, most recent first.
+#[derive(Clone, Debug, Default)]
+pub struct PaletteHistory {
+    recent: VecDeque<String>,
+}
+
+impl PaletteHistory {
+    /// Move `action` to the front of the history.
+    pub fn record(&mut self, action: &PaletteAction) {
+        let key = action.key();
+        self.recent.retain(|k| *k != key);
+        self.recent.push_front(key);
+        self.recent.truncate(HISTORY_LIMIT);
+    }
+
+    /// Ranking bonus for `action`: larger the more recently it was run, 0 if never.
+    pub fn bonus(&self, action: &PaletteAction) -> i64 {
+        let key = action.key();
+        self.recent
+            .iter()
+            .position(|k| *k == key)
+            .map_or(0, |pos| (HISTORY_LIMIT - pos) as i64 * RECENCY_WEIGHT)
+    }
+}
+
+/// Filter and order `items` for `query`, best first.
+///
+/// Ties keep the catalogue order from [`build_items`]. At most
+/// [`MAX_RESULTS`] entries are returned.
+pub fn rank_items<'a>(
+    items: &'a [PaletteItem],
+    query: &str,
+    history: &PaletteHistory,
+) -> Vec<&'a PaletteItem> {
+    let mut scored: Vec<(i64, &PaletteItem)> = items
+        .iter()
+        .filter_map(|item| {
+            let score = fuzzy_score(query, &item.search_text)?;
+            Some((score + history.bonus(&item.action), item))
+        })
+        .collect();
+    scored.sort_by_key(|(score, _)| std::cmp::Reverse(*score));
+    scored
+        .into_iter()
+        .take(MAX_RESULTS)
+        .map(|(_, item)| item)
+        .collect()
+}
+
+/// What the palette input is currently used for.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub enum PaletteMode {
+    /// Filtering palette entries.
+    #[default]
+    Search,
+    /// Typing text to inject into `branch`.
+    Inject { branch: String },
+    /// Typing the name to save the active workspace layout under.
+    SaveLayout,
+}
+
+/// Palette dialog state: mode and highlighted row.
+///
+/// The highlighted row belongs to the query it was chosen for, so typing
+/// resets the highlight to the best match without needing input callbacks.
+#[derive(Clone, Debug, Default)]
+pub struct PaletteState {
+    pub mode: PaletteMode,
+    selected: usize,
+    selected_query: String,
+}
+
+impl PaletteState {
+    /// Index of the highlighted row among `len` results for `query`.
+    pub fn selected_index(&self, query: &str, len: usize) -> usize {
+        if len == 0 || query != self.selected_query {
+            return 0;
+        }
+        self.selected.min(len - 1)
+    }
+
+    /// Move the highlight by `delta` rows, wrapping around.
+    pub fn move_selection(&mut self, query: &str, len: usize, delta: isize) {
+        if len == 0 {
+            return;
+        }
+        let current = self.selected_index(query, len) as isize;
+        self.selected = (current + delta).rem_euclid(len as isize) as usize;
+        self.selected_query = query.to_string();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use kild_core::sessions::types::SessionStatus;
+    use kild_core::{GitStatus, Session};
+    use std::path::PathBuf;
+
+    fn make_display(branch: &str, note: Option<&str>, issue: Option<u32>) -> SessionSnapshot {
+        SessionSnapshot {
+            session: Session::new(
+                format!("id-{branch}").into(),
+                "test-project".into(),
+                branch.into(),
+                PathBuf::from("/tmp/test"),
+                "claude".to_string(),
+                SessionStatus::Active,
+                "2024-01-01T00:00:00Z".to_string(),
+                0,
+                0,
+                0,
+                None,
+                note.map(str::to_string),
+                issue,
+                vec![],
+                None,
+                None,
+                None,
+            ),
+            process_status: ProcessStatus::Stopped,
+            git_status: GitStatus::Unknown,
+            uncommitted_diff: None,
+        }
+    }
+
+    #[test]
+    fn test_fuzzy_score_prefers_substring_at_word_start() {
+        let word_start = fuzzy_score("auth", "open feature-auth").unwrap();
+        let mid_word = fuzzy_score("auth", "open oauthflow").unwrap();
+        let subsequence = fuzzy_score("auth", "a unit test harness").unwrap();
+        assert!(word_start > mid_word);
+        assert!(mid_word > subsequence);
+        assert_eq!(fuzzy_score("zz", "open feature-auth"), None);
+    }
+
+    #[test]
+    fn test_fuzzy_score_requires_every_token() {
+        assert!(fuzzy_score("stop AUTH", "Stop feature-auth").is_some());
+        assert!(fuzzy_score("stop billing", "Stop feature-auth").is_none());
+        assert_eq!(fuzzy_score("  ", "anything"), Some(0));
+    }
+
+    #[test]
+    fn test_build_items_matches_note_and_issue() {
+        let display = make_display("feature-auth", Some("login flow"), Some(42));
+        let items = build_items(&[&display], &["codex"]);
+
+        let ranked = rank_items(&items, "#42", &PaletteHistory::default());
+        assert!(!ranked.is_empty());
+        let ranked = rank_items(&items, "login codex", &PaletteHistory::default());
+        assert_eq!(
+            ranked[0].action,
+            PaletteAction::OpenKild {
+                branch: "feature-auth".to_string(),
+                agent: Some("codex".to_string()),
+            }
+        );
+    }
+
+    #[test]
+    fn test_build_items_hides_running_only_actions_for_stopped_kild() {
+        let display = make_display("feature-auth", None, None);
+        let items = build_items(&[&display], &[]);
+        assert!(
+            !items
+                .iter()
+                .any(|i| matches!(i.action, PaletteAction::StopKild { .. }))
+        );
+
+        let mut running = display.clone();
+        running.process_status = ProcessStatus::Running;
+        let items = build_items(&[&running], &[]);
+        assert!(
+            items
+                .iter()
+                .any(|i| matches!(i.action, PaletteAction::InjectText { .. }))
+        );
+    }
+
+    #[test]
+    fn test_broadcast_items_follow_active_scope() {
+        let actions = |active| -> Vec<PaletteAction> {
+            broadcast_items(active)
+                .into_iter()
+                .map(|i| i.action)
+                .collect()
+        };
+        assert_eq!(
+            actions(None),
+            vec![
+                PaletteAction::StartBroadcast {
+                    scope: BroadcastScope::Panes
+                },
+                PaletteAction::StartBroadcast {
+                    scope: BroadcastScope::Project
+                },
+            ]
+        );
+        assert_eq!(
+            actions(Some(BroadcastScope::Panes)),
+            vec![
+                PaletteAction::StopBroadcast,
+                PaletteAction::StartBroadcast {
+                    scope: BroadcastScope::Project
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn test_layout_items_list_presets_named_and_save() {
+        let items = layout_items(&["review"]);
+        let labels: Vec<&str> = items.iter().map(|i| i.label.as_str()).collect();
+        assert_eq!(labels.first(), Some(&"Layout: 1x2"));
+        assert!(labels.contains(&"Layout: review"));
+        assert_eq!(labels.last(), Some(&"Save layout as..."));
+        assert_eq!(items.len(), PaneLayout::presets().len() + 2);
+
+        let ranked = rank_items(&items, "review", &PaletteHistory::default());
+        assert_eq!(
+            ranked[0].action,
+            PaletteAction::ApplyNamedLayout {
+                name: "review".to_string()
+            }
+        );
+    }
+
+    #[test]
+    fn test_rank_items_boosts_recent_actions() {
+        let display = make_display("feature-auth", None, None);
+        let items = build_items(&[&display], &[]);
+        let destroy = PaletteAction::DestroyKild {
+            branch: "feature-auth".to_string(),
+        };
+
+        let before = rank_items(&items, "", &PaletteHistory::default());
+        assert_eq!(before[0].action, PaletteAction::CreateKild);
+
+        let mut history = PaletteHistory::default();
+        history.record(&destroy);
+        let after = rank_items(&items, "", &history);
+        assert_eq!(after[0].action, destroy);
+    }
+
+    #[test]
+    fn test_history_moves_repeat_to_front_and_caps() {
+        let mut history = PaletteHistory::default();
+        for i in 0..HISTORY_LIMIT + 5 {
+            history.record(&PaletteAction::StopKild {
+                branch: format!("b{i}"),
+            });
+        }
+        history.record(&PaletteAction::CreateKild);
+        history.record(&PaletteAction::CreateKild);
+
+        assert_eq!(history.recent.len(), HISTORY_LIMIT);
+        assert_eq!(history.recent[0], "create");
+        assert!(history.bonus(&PaletteAction::CreateKild) > 0);
+        assert_eq!(
+            history.bonus(&PaletteAction::StopKild {
+                branch: "b0".to_string()
+            }),
+            0
+        );
+    }
+
+    #[test]
+    fn test_selection_wraps_and_resets_on_new_query() {
+        let mut state = PaletteState::default();
+        state.move_selection("op", 3, -1);
+        assert_eq!(state.selected_index("op", 3), 2);
+        state.move_selection("op", 3, 1);
+        assert_eq!(state.selected_index("op", 3), 0);
+
+        state.move_selection("op", 3, 2);
+        assert_eq!(state.selected_index("op", 2), 1);
+        assert_eq!(state.selected_index("ope", 3), 0);
+        assert_eq!(state.selected_index("op", 0), 0);
+    }
+}
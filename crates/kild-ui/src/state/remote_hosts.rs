@@ -0,0 +1,253 @@
+//! Remote daemon hosts.
+//!
+//! Hosts come from `[[daemon.hosts]]` in config and are shown in the
+//! sidebar next to the local kilds. Each host is polled for its session
+//! list; a host that stops answering is marked disconnected and retried
+//! with backoff, and its terminals reconnect once it answers again.
+//!
+//! Terminal tabs for remote sessions are keyed by [`terminal_key`], which
+//! cannot collide with a kild session ID.
+
+use std::time::{Duration, Instant};
+
+use kild_config::DaemonHost;
+use kild_protocol::DaemonSessionStatus;
+
+use crate::daemon_client::DaemonEndpoint;
+
+/// Longest wait between retries of a disconnected host.
+const MAX_BACKOFF: Duration = Duration::from_secs(60);
+
+/// Prefix of terminal tab keys for remote sessions.
+const KEY_PREFIX: &str = "remote:";
+
+/// Connection state of a remote host.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum HostStatus {
+    /// Not polled yet.
+    Connecting,
+    Connected,
+    /// Last poll failed with this message.
+    Disconnected(String),
+}
+
+/// One remote daemon and what was last seen on it.
+#[derive(Debug)]
+pub struct RemoteHost {
+    endpoint: DaemonEndpoint,
+    name: String,
+    status: HostStatus,
+    sessions: Vec<DaemonSessionStatus>,
+    /// Consecutive failed polls, drives the retry backoff.
+    failures: u32,
+    next_poll: Instant,
+    in_flight: bool,
+}
+
+impl RemoteHost {
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    pub fn endpoint(&self) -> &DaemonEndpoint {
+        &self.endpoint
+    }
+
+    pub fn status(&self) -> &HostStatus {
+        &self.status
+    }
+
+    /// Sessions from the last successful poll. Kept while disconnected so
+    /// the sidebar doesn't flicker through short outages.
+    pub fn sessions(&self) -> &[DaemonSessionStatus] {
+        &self.sessions
+    }
+}
+
+/// All configured remote hosts, in config order.
+#[derive(Debug, Default)]
+pub struct RemoteHosts {
+    hosts: Vec<RemoteHost>,
+}
+
+impl RemoteHosts {
+    pub fn from_config(hosts: &[DaemonHost], now: Instant) -> Self {
+        Self {
+            hosts: hosts
+                .iter()
+                .map(|host| RemoteHost {
+                    endpoint: DaemonEndpoint::from_host(host),
+                    name: host.name.clone(),
+                    status: HostStatus::Connecting,
+                    sessions: Vec::new(),
+                    failures: 0,
+                    next_poll: now,
+                    in_flight: false,
+                })
+                .collect(),
+        }
+    }
+
+    pub fn hosts(&self) -> &[RemoteHost] {
+        &self.hosts
+    }
+
+    pub fn get(&self, name: &str) -> Option<&RemoteHost> {
+        self.hosts.iter().find(|h| h.name == name)
+    }
+
+    /// Endpoints due for a poll. They are marked in flight until
+    /// [`RemoteHosts::record`] is called for them.
+    pub fn take_due(&mut self, now: Instant) -> Vec<DaemonEndpoint> {
+        self.hosts
+            .iter_mut()
+            .filter(|h| !h.in_flight && h.next_poll <= now)
+            .map(|h| {
+                h.in_flight = true;
+                h.endpoint.clone()
+            })
+            .collect()
+    }
+
+    /// Record a poll result for `name`. Returns true when the host answered
+    /// after being disconnected, so its terminals can reconnect.
+    pub fn record(
+        &mut self,
+        name: &str,
+        result: Result<Vec<DaemonSessionStatus>, String>,
+        now: Instant,
+    ) -> bool {
+        let Some(host) = self.hosts.iter_mut().find(|h| h.name == name) else {
+            return false;
+        };
+        host.in_flight = false;
+        match result {
+            Ok(sessions) => {
+                let came_back = matches!(host.status, HostStatus::Disconnected(_));
+                if came_back {
+                    tracing::info!(event = "ui.remote_host.reconnected", host = name);
+                }
+                host.status = HostStatus::Connected;
+                host.sessions = sessions;
+                host.failures = 0;
+                host.next_poll = now + crate::refresh::REMOTE_HOSTS_POLL_INTERVAL;
+                came_back
+            }
+            Err(message) => {
+                if !matches!(host.status, HostStatus::Disconnected(_)) {
+                    tracing::warn!(
+                        event = "ui.remote_host.disconnected",
+                        host = name,
+                        error = message
+                    );
+                }
+                host.status = HostStatus::Disconnected(message);
+                host.failures += 1;
+                host.next_poll = now + backoff(host.failures);
+                false
+            }
+        }
+    }
+
+    /// Poll a host on the next tick instead of waiting out its backoff.
+    pub fn retry(&mut self, name: &str, now: Instant) {
+        if let Some(host) = self.hosts.iter_mut().find(|h| h.name == name) {
+            host.next_poll = now;
+        }
+    }
+}
+
+/// Wait before the next poll after `failures` consecutive failures: the
+/// poll interval, doubling per failure, capped at [`MAX_BACKOFF`].
+fn backoff(failures: u32) -> Duration {
+    let factor = 1u32 << failures.saturating_sub(1).min(6);
+    (crate::refresh::REMOTE_HOSTS_POLL_INTERVAL * factor).min(MAX_BACKOFF)
+}
+
+/// Terminal tab key for `session_id` on `host`.
+pub fn terminal_key(host: &str, session_id: &str) -> String {
+    format!("{}{}/{}", KEY_PREFIX, host, session_id)
+}
+
+/// Split a terminal tab key into host name and daemon session ID. `None`
+/// for kild tab keys.
+pub fn parse_terminal_key(key: &str) -> Option<(&str, &str)> {
+    key.strip_prefix(KEY_PREFIX)?.split_once('/')
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::refresh::REMOTE_HOSTS_POLL_INTERVAL;
+
+    fn hosts(now: Instant) -> RemoteHosts {
+        RemoteHosts::from_config(
+            &[DaemonHost {
+                name: "build-box".to_string(),
+                address: "build-box:7432".to_string(),
+                cert_fingerprint: format!("sha256:{}", "ab".repeat(32)),
+            }],
+            now,
+        )
+    }
+
+    #[test]
+    fn test_terminal_key_round_trip() {
+        let key = terminal_key("build-box", "proj_feature/shell");
+        assert_eq!(
+            parse_terminal_key(&key),
+            Some(("build-box", "proj_feature/shell"))
+        );
+        assert_eq!(parse_terminal_key("proj_feature"), None);
+    }
+
+    #[test]
+    fn test_take_due_skips_hosts_in_flight() {
+        let now = Instant::now();
+        let mut remote = hosts(now);
+        assert_eq!(remote.take_due(now).len(), 1);
+        assert!(remote.take_due(now).is_empty());
+
+        remote.record("build-box", Ok(Vec::new()), now);
+        assert!(remote.take_due(now).is_empty());
+        assert_eq!(remote.take_due(now + REMOTE_HOSTS_POLL_INTERVAL).len(), 1);
+    }
+
+    #[test]
+    fn test_record_reports_reconnect_and_backs_off() {
+        let now = Instant::now();
+        let mut remote = hosts(now);
+        remote.take_due(now);
+        assert!(!remote.record("build-box", Ok(Vec::new()), now));
+        assert_eq!(
+            remote.get("build-box").unwrap().status(),
+            &HostStatus::Connected
+        );
+
+        for _ in 0..3 {
+            remote.take_due(now + MAX_BACKOFF);
+            assert!(!remote.record("build-box", Err("refused".to_string()), now));
+        }
+        assert_eq!(
+            remote.get("build-box").unwrap().status(),
+            &HostStatus::Disconnected("refused".to_string())
+        );
+        // Third failure waits four poll intervals
+        assert!(
+            remote
+                .take_due(now + REMOTE_HOSTS_POLL_INTERVAL * 3)
+                .is_empty()
+        );
+
+        remote.retry("build-box", now);
+        assert_eq!(remote.take_due(now).len(), 1);
+        assert!(remote.record("build-box", Ok(Vec::new()), now));
+    }
+
+    #[test]
+    fn test_backoff_is_capped() {
+        assert_eq!(backoff(1), REMOTE_HOSTS_POLL_INTERVAL);
+        assert_eq!(backoff(2), REMOTE_HOSTS_POLL_INTERVAL * 2);
+        assert_eq!(backoff(50), MAX_BACKOFF);
+    }
+}
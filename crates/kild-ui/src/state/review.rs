@@ -0,0 +1,419 @@
+//! Review panel model.
+//!
+//! Holds the diff of a kild against its base branch, the commit list, which
+//! file is selected and how its diff is laid out. Rendering lives in
+//! `views::review_panel`; loading and actions in the `MainView` review handlers.
+
+use std::path::PathBuf;
+
+use kild_core::git::types::{DiffHunk, DiffLine, DiffLineKind, ReviewDiff, ReviewFile};
+
+/// How the selected file's diff is laid out.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum DiffMode {
+    #[default]
+    Unified,
+    SideBySide,
+}
+
+/// A commit on the kild's branch, from `kild commits` (`git log --oneline`).
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ReviewCommit {
+    pub sha: String,
+    pub summary: String,
+}
+
+/// Parse `git log --oneline` output into commits, newest first.
+pub fn parse_commits(oneline: &str) -> Vec<ReviewCommit> {
+    oneline
+        .lines()
+        .filter_map(|line| {
+            let line = line.trim();
+            if line.is_empty() {
+                return None;
+            }
+            let (sha, summary) = line.split_once(' ').unwrap_or((line, ""));
+            Some(ReviewCommit {
+                sha: sha.to_string(),
+                summary: summary.to_string(),
+            })
+        })
+        .collect()
+}
+
+/// One row of a side-by-side diff: base on the left, kild on the right.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct SplitRow<'a> {
+    pub left: Option<&'a DiffLine>,
+    pub right: Option<&'a DiffLine>,
+}
+
+/// Lay a hunk out side by side.
+///
+/// Context lines appear on both sides. A run of removed lines followed by
+/// added lines is paired up row by row so edits line up; leftovers get an
+/// empty cell on the other side.
+pub fn split_rows(hunk: &DiffHunk) -> Vec<SplitRow<'_>> {
+    let mut rows = Vec::with_capacity(hunk.lines.len());
+    let mut removed: Vec<&DiffLine> = Vec::new();
+    let mut added: Vec<&DiffLine> = Vec::new();
+
+    fn flush<'a>(
+        rows: &mut Vec<SplitRow<'a>>,
+        removed: &mut Vec<&'a DiffLine>,
+        added: &mut Vec<&'a DiffLine>,
+    ) {
+        for i in 0..removed.len().max(added.len()) {
+            rows.push(SplitRow {
+                left: removed.get(i).copied(),
+                right: added.get(i).copied(),
+            });
+        }
+        removed.clear();
+        added.clear();
+    }
+
+    for line in &hunk.lines {
+        match line.kind {
+            DiffLineKind::Removed => {
+                // A removal after additions starts a new edit block.
+                if !added.is_empty() {
+                    flush(&mut rows, &mut removed, &mut added);
+                }
+                removed.push(line);
+            }
+            DiffLineKind::Added => added.push(line),
+            DiffLineKind::Context => {
+                flush(&mut rows, &mut removed, &mut added);
+                rows.push(SplitRow {
+                    left: Some(line),
+                    right: Some(line),
+                });
+            }
+        }
+    }
+    flush(&mut rows, &mut removed, &mut added);
+    rows
+}
+
+/// Text injected into the agent when the reviewer sends feedback.
+pub fn feedback_message(text: &str, file: Option<&str>) -> String {
+    match file {
+        Some(file) => format!("Review feedback on {}:\n\n{}", file, text.trim()),
+        None => format!("Review feedback:\n\n{}", text.trim()),
+    }
+}
+
+/// State of the review panel for one kild.
+#[derive(Clone, Debug)]
+pub struct ReviewState {
+    pub branch: String,
+    pub worktree_path: PathBuf,
+    diff: Option<ReviewDiff>,
+    commits: Vec<ReviewCommit>,
+    loading: bool,
+    /// Load or action failure, shown above the diff.
+    error: Option<String>,
+    /// Outcome of the last action (feedback sent, PR opened).
+    notice: Option<String>,
+    selected: usize,
+    mode: DiffMode,
+    /// File whose discard is awaiting a second click.
+    pending_discard: Option<String>,
+    /// An approve or discard is running.
+    busy: bool,
+}
+
+impl ReviewState {
+    /// Start reviewing `branch`; the diff loads in the background.
+    pub fn new(branch: String, worktree_path: PathBuf) -> Self {
+        Self {
+            branch,
+            worktree_path,
+            diff: None,
+            commits: Vec::new(),
+            loading: true,
+            error: None,
+            notice: None,
+            selected: 0,
+            mode: DiffMode::default(),
+            pending_discard: None,
+            busy: false,
+        }
+    }
+
+    /// Mark a reload in progress, keeping the current diff on screen.
+    pub fn start_loading(&mut self) {
+        self.loading = true;
+        self.pending_discard = None;
+    }
+
+    /// Store a loaded diff. Keeps the selected file if it is still changed.
+    pub fn set_loaded(&mut self, diff: ReviewDiff, commits: Vec<ReviewCommit>) {
+        let selected_path = self.selected_file().map(|f| f.path.clone());
+        self.selected = selected_path
+            .and_then(|path| diff.files.iter().position(|f| f.path == path))
+            .unwrap_or(0)
+            .min(diff.files.len().saturating_sub(1));
+        self.diff = Some(diff);
+        self.commits = commits;
+        self.loading = false;
+        self.error = None;
+    }
+
+    pub fn set_failed(&mut self, message: String) {
+        self.loading = false;
+        self.busy = false;
+        self.error = Some(message);
+    }
+
+    pub fn is_loading(&self) -> bool {
+        self.loading
+    }
+
+    pub fn error(&self) -> Option<&str> {
+        self.error.as_deref()
+    }
+
+    pub fn notice(&self) -> Option<&str> {
+        self.notice.as_deref()
+    }
+
+    pub fn set_notice(&mut self, notice: String) {
+        self.busy = false;
+        self.error = None;
+        self.notice = Some(notice);
+    }
+
+    pub fn is_busy(&self) -> bool {
+        self.busy
+    }
+
+    pub fn set_busy(&mut self) {
+        self.busy = true;
+        self.notice = None;
+        self.error = None;
+    }
+
+    pub fn files(&self) -> &[ReviewFile] {
+        self.diff
+            .as_ref()
+            .map(|d| d.files.as_slice())
+            .unwrap_or(&[])
+    }
+
+    pub fn commits(&self) -> &[ReviewCommit] {
+        &self.commits
+    }
+
+    /// Abbreviated merge base the diff is taken against.
+    pub fn base_commit(&self) -> Option<&str> {
+        self.diff
+            .as_ref()
+            .map(|d| &d.base_commit[..d.base_commit.len().min(8)])
+    }
+
+    pub fn selected_index(&self) -> usize {
+        self.selected
+    }
+
+    pub fn selected_file(&self) -> Option<&ReviewFile> {
+        self.files().get(self.selected)
+    }
+
+    pub fn select(&mut self, idx: usize) {
+        if idx < self.files().len() {
+            self.selected = idx;
+            self.pending_discard = None;
+        }
+    }
+
+    pub fn select_next(&mut self) {
+        self.select(self.selected + 1);
+    }
+
+    pub fn select_prev(&mut self) {
+        if let Some(idx) = self.selected.checked_sub(1) {
+            self.select(idx);
+        }
+    }
+
+    pub fn mode(&self) -> DiffMode {
+        self.mode
+    }
+
+    pub fn toggle_mode(&mut self) {
+        self.mode = match self.mode {
+            DiffMode::Unified => DiffMode::SideBySide,
+            DiffMode::SideBySide => DiffMode::Unified,
+        };
+    }
+
+    /// First click arms the discard for `path`, the second confirms it.
+    ///
+    /// Returns `true` when the discard should run.
+    pub fn request_discard(&mut self, path: &str) -> bool {
+        if self.pending_discard.as_deref() == Some(path) {
+            self.pending_discard = None;
+            true
+        } else {
+            self.pending_discard = Some(path.to_string());
+            false
+        }
+    }
+
+    pub fn is_discard_pending(&self, path: &str) -> bool {
+        self.pending_discard.as_deref() == Some(path)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use kild_core::git::types::{FileChange, FileChangeKind};
+
+    fn line(kind: DiffLineKind, content: &str) -> DiffLine {
+        DiffLine {
+            kind,
+            old_lineno: None,
+            new_lineno: None,
+            content: content.to_string(),
+        }
+    }
+
+    fn file(path: &str) -> ReviewFile {
+        ReviewFile {
+            path: path.to_string(),
+            change: FileChange {
+                kind: FileChangeKind::Modified,
+                insertions: 1,
+                deletions: 0,
+            },
+            binary: false,
+            truncated: false,
+            hunks: Vec::new(),
+        }
+    }
+
+    fn diff(paths: &[&str]) -> ReviewDiff {
+        ReviewDiff {
+            base_commit: "0123456789abcdef".to_string(),
+            files: paths.iter().map(|p| file(p)).collect(),
+        }
+    }
+
+    #[test]
+    fn test_parse_commits() {
+        let commits = parse_commits("abc1234 Fix the thing\n\ndef5678 Add tests\nfff\n");
+        assert_eq!(commits.len(), 3);
+        assert_eq!(commits[0].sha, "abc1234");
+        assert_eq!(commits[0].summary, "Fix the thing");
+        assert_eq!(commits[2].summary, "");
+    }
+
+    #[test]
+    fn test_split_rows_pairs_edits() {
+        let hunk = DiffHunk {
+            header: "@@".to_string(),
+            lines: vec![
+                line(DiffLineKind::Context, "a"),
+                line(DiffLineKind::Removed, "b"),
+                line(DiffLineKind::Removed, "c"),
+                line(DiffLineKind::Added, "B"),
+                line(DiffLineKind::Context, "d"),
+                line(DiffLineKind::Added, "e"),
+            ],
+        };
+        let rows = split_rows(&hunk);
+        fn text(l: Option<&DiffLine>) -> Option<&str> {
+            l.map(|l| l.content.as_str())
+        }
+        let pairs: Vec<_> = rows.iter().map(|r| (text(r.left), text(r.right))).collect();
+        assert_eq!(
+            pairs,
+            vec![
+                (Some("a"), Some("a")),
+                (Some("b"), Some("B")),
+                (Some("c"), None),
+                (Some("d"), Some("d")),
+                (None, Some("e")),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_split_rows_removal_after_addition_starts_new_block() {
+        let hunk = DiffHunk {
+            header: "@@".to_string(),
+            lines: vec![
+                line(DiffLineKind::Added, "x"),
+                line(DiffLineKind::Removed, "y"),
+            ],
+        };
+        let rows = split_rows(&hunk);
+        assert_eq!(rows.len(), 2);
+        assert!(rows[0].left.is_none());
+        assert!(rows[1].right.is_none());
+    }
+
+    #[test]
+    fn test_selection_survives_reload() {
+        let mut review = ReviewState::new("feat".to_string(), PathBuf::from("/tmp"));
+        assert!(review.is_loading());
+        review.set_loaded(diff(&["a.rs", "b.rs", "c.rs"]), Vec::new());
+        review.select(1);
+        assert_eq!(review.selected_file().unwrap().path, "b.rs");
+
+        review.start_loading();
+        review.set_loaded(diff(&["b.rs", "c.rs"]), Vec::new());
+        assert_eq!(review.selected_file().unwrap().path, "b.rs");
+
+        // Selected file gone: clamp into range
+        review.select(1);
+        review.set_loaded(diff(&["a.rs"]), Vec::new());
+        assert_eq!(review.selected_index(), 0);
+        review.set_loaded(diff(&[]), Vec::new());
+        assert!(review.selected_file().is_none());
+    }
+
+    #[test]
+    fn test_select_next_prev_stays_in_range() {
+        let mut review = ReviewState::new("feat".to_string(), PathBuf::from("/tmp"));
+        review.set_loaded(diff(&["a.rs", "b.rs"]), Vec::new());
+        review.select_prev();
+        assert_eq!(review.selected_index(), 0);
+        review.select_next();
+        review.select_next();
+        assert_eq!(review.selected_index(), 1);
+        assert_eq!(review.base_commit(), Some("01234567"));
+    }
+
+    #[test]
+    fn test_discard_needs_two_clicks_on_same_file() {
+        let mut review = ReviewState::new("feat".to_string(), PathBuf::from("/tmp"));
+        review.set_loaded(diff(&["a.rs", "b.rs"]), Vec::new());
+        assert!(!review.request_discard("a.rs"));
+        assert!(review.is_discard_pending("a.rs"));
+        assert!(!review.request_discard("b.rs"));
+        assert!(review.request_discard("b.rs"));
+        assert!(!review.is_discard_pending("b.rs"));
+
+        // Changing selection disarms
+        assert!(!review.request_discard("a.rs"));
+        review.select(1);
+        assert!(!review.is_discard_pending("a.rs"));
+    }
+
+    #[test]
+    fn test_toggle_mode_and_feedback_message() {
+        let mut review = ReviewState::new("feat".to_string(), PathBuf::from("/tmp"));
+        assert_eq!(review.mode(), DiffMode::Unified);
+        review.toggle_mode();
+        assert_eq!(review.mode(), DiffMode::SideBySide);
+        assert_eq!(
+            feedback_message("  use a map ", Some("src/a.rs")),
+            "Review feedback on src/a.rs:\n\nuse a map"
+        );
+        assert_eq!(feedback_message("ok", None), "Review feedback:\n\nok");
+    }
+}
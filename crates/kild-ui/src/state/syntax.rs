@@ -0,0 +1,233 @@
+//! Lightweight syntax highlighting for diff lines.
+//!
+//! Diffs are shown one line at a time, so this is a per-line tokenizer that
+//! knows comments, strings, numbers and keywords for common languages. It
+//! does not track state across lines (block comments, multi-line strings).
+
+use std::ops::Range;
+
+/// Token classes the review panel colors.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum TokenKind {
+    Plain,
+    Keyword,
+    String,
+    Comment,
+    Number,
+}
+
+/// A highlighted span of a line (byte range).
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Token {
+    pub range: Range<usize>,
+    pub kind: TokenKind,
+}
+
+/// Language family, picked from the file extension.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Language {
+    Rust,
+    /// C, C++, Go, Java, JavaScript, TypeScript, Swift, Kotlin, ...
+    CLike,
+    Python,
+    /// Shell, TOML, YAML: `#` comments, no keywords worth coloring.
+    Hash,
+    Plain,
+}
+
+const RUST_KEYWORDS: &[&str] = &[
+    "as", "async", "await", "break", "const", "continue", "crate", "dyn", "else", "enum", "false",
+    "fn", "for", "if", "impl", "in", "let", "loop", "match", "mod", "move", "mut", "pub", "ref",
+    "return", "self", "Self", "static", "struct", "super", "trait", "true", "type", "unsafe",
+    "use", "where", "while",
+];
+
+const C_LIKE_KEYWORDS: &[&str] = &[
+    "async", "await", "break", "case", "catch", "class", "const", "continue", "default", "defer",
+    "do", "else", "enum", "export", "extends", "false", "final", "for", "func", "function", "go",
+    "if", "import", "let", "new", "null", "package", "private", "public", "return", "static",
+    "struct", "switch", "this", "throw", "true", "try", "type", "var", "void", "while",
+];
+
+const PYTHON_KEYWORDS: &[&str] = &[
+    "and", "as", "async", "await", "break", "class", "continue", "def", "elif", "else", "except",
+    "False", "finally", "for", "from", "if", "import", "in", "is", "lambda", "None", "not", "or",
+    "pass", "raise", "return", "self", "True", "try", "while", "with", "yield",
+];
+
+impl Language {
+    pub fn from_path(path: &str) -> Self {
+        let ext = path.rsplit_once('.').map(|(_, ext)| ext).unwrap_or("");
+        match ext {
+            "rs" => Language::Rust,
+            "c" | "h" | "cc" | "cpp" | "hpp" | "go" | "java" | "js" | "jsx" | "ts" | "tsx"
+            | "mjs" | "swift" | "kt" | "cs" | "scala" | "dart" => Language::CLike,
+            "py" => Language::Python,
+            "sh" | "bash" | "zsh" | "toml" | "yaml" | "yml" => Language::Hash,
+            _ => Language::Plain,
+        }
+    }
+
+    fn keywords(self) -> &'static [&'static str] {
+        match self {
+            Language::Rust => RUST_KEYWORDS,
+            Language::CLike => C_LIKE_KEYWORDS,
+            Language::Python => PYTHON_KEYWORDS,
+            Language::Hash | Language::Plain => &[],
+        }
+    }
+
+    fn line_comment(self) -> Option<&'static str> {
+        match self {
+            Language::Rust | Language::CLike => Some("//"),
+            Language::Python | Language::Hash => Some("#"),
+            Language::Plain => None,
+        }
+    }
+}
+
+/// Split a line into colored tokens covering the whole line.
+pub fn highlight(line: &str, language: Language) -> Vec<Token> {
+    let mut tokens: Vec<Token> = Vec::new();
+    if language == Language::Plain {
+        if !line.is_empty() {
+            tokens.push(Token {
+                range: 0..line.len(),
+                kind: TokenKind::Plain,
+            });
+        }
+        return tokens;
+    }
+
+    let mut push = |range: Range<usize>, kind: TokenKind| {
+        if range.is_empty() {
+            return;
+        }
+        match tokens.last_mut() {
+            Some(last) if last.kind == kind && last.range.end == range.start => {
+                last.range.end = range.end;
+            }
+            _ => tokens.push(Token { range, kind }),
+        }
+    };
+
+    let bytes = line.as_bytes();
+    let comment = language.line_comment();
+    let mut i = 0;
+    while i < bytes.len() {
+        let rest = &line[i..];
+        let c = bytes[i];
+        if comment.is_some_and(|marker| rest.starts_with(marker)) {
+            push(i..line.len(), TokenKind::Comment);
+            break;
+        }
+        if c == b'"' || (c == b'\'' && language != Language::Rust) {
+            let end = string_end(bytes, i);
+            push(i..end, TokenKind::String);
+            i = end;
+        } else if c.is_ascii_digit() {
+            let end = word_end(bytes, i);
+            push(i..end, TokenKind::Number);
+            i = end;
+        } else if c.is_ascii_alphabetic() || c == b'_' {
+            let end = word_end(bytes, i);
+            let kind = if language.keywords().contains(&&line[i..end]) {
+                TokenKind::Keyword
+            } else {
+                TokenKind::Plain
+            };
+            push(i..end, kind);
+            i = end;
+        } else {
+            // Advance by a whole char so ranges stay on UTF-8 boundaries.
+            let len = rest.chars().next().map_or(1, char::len_utf8);
+            push(i..i + len, TokenKind::Plain);
+            i += len;
+        }
+    }
+    tokens
+}
+
+/// End of the identifier or number starting at `start`.
+fn word_end(bytes: &[u8], start: usize) -> usize {
+    bytes[start..]
+        .iter()
+        .position(|b| !(b.is_ascii_alphanumeric() || *b == b'_'))
+        .map_or(bytes.len(), |p| start + p)
+}
+
+/// End (exclusive) of the string literal opening at `start`, or end of line.
+fn string_end(bytes: &[u8], start: usize) -> usize {
+    let quote = bytes[start];
+    let mut i = start + 1;
+    while i < bytes.len() {
+        match bytes[i] {
+            b'\\' => i += 2,
+            b if b == quote => return i + 1,
+            _ => i += 1,
+        }
+    }
+    bytes.len()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn kinds(line: &str, language: Language) -> Vec<(&str, TokenKind)> {
+        highlight(line, language)
+            .into_iter()
+            .map(|t| (&line[t.range], t.kind))
+            .collect()
+    }
+
+    #[test]
+    fn test_language_from_path() {
+        assert_eq!(Language::from_path("src/main.rs"), Language::Rust);
+        assert_eq!(Language::from_path("web/app.tsx"), Language::CLike);
+        assert_eq!(Language::from_path("a.py"), Language::Python);
+        assert_eq!(Language::from_path("Cargo.toml"), Language::Hash);
+        assert_eq!(Language::from_path("README"), Language::Plain);
+    }
+
+    #[test]
+    fn test_highlight_rust_line() {
+        let tokens = kinds("let x = \"a\\\"b\"; // 42", Language::Rust);
+        assert_eq!(
+            tokens,
+            vec![
+                ("let", TokenKind::Keyword),
+                (" x = ", TokenKind::Plain),
+                ("\"a\\\"b\"", TokenKind::String),
+                ("; ", TokenKind::Plain),
+                ("// 42", TokenKind::Comment),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_highlight_numbers_and_identifiers() {
+        let tokens = kinds("fn_x(10)", Language::Rust);
+        assert_eq!(
+            tokens,
+            vec![
+                ("fn_x(", TokenKind::Plain),
+                ("10", TokenKind::Number),
+                (")", TokenKind::Plain),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_highlight_covers_whole_line_with_unicode() {
+        for language in [Language::Rust, Language::Python, Language::Plain] {
+            let line = "x = 'é' # ünïcode";
+            let tokens = highlight(line, language);
+            let covered: usize = tokens.iter().map(|t| t.range.len()).sum();
+            assert_eq!(covered, line.len());
+        }
+        assert!(highlight("", Language::Rust).is_empty());
+        // Rust lifetimes are not strings
+        assert_eq!(kinds("'a", Language::Rust)[0], ("'", TokenKind::Plain));
+    }
+}
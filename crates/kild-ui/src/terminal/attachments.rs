@@ -0,0 +1,206 @@
+//! File and image handoff to agent terminals.
+//!
+//! Dropped files and pasted images become paths typed into the PTY. Files
+//! outside the kild's worktree are copied into `.kild/attachments/` first,
+//! so sandboxed agents can read them; files already in the worktree are
+//! referenced by their relative path. The attachments folder ignores itself
+//! so attachments never end up in a commit.
+
+use std::path::{Path, PathBuf};
+
+use kild_core::terminal::operations::shell_escape;
+
+use super::errors::TerminalError;
+
+/// Attachments folder, relative to the worktree root.
+pub const ATTACHMENTS_DIR: &str = ".kild/attachments";
+
+/// Start and end markers of a bracketed paste.
+const PASTE_START: &str = "\x1b[200~";
+const PASTE_END: &str = "\x1b[201~";
+
+/// Make `path` available to the agent in `worktree` and return the path to
+/// type: relative for files in the worktree (or copied into it), absolute
+/// for directories outside it (those are not copied).
+pub fn stage_file(worktree: &Path, path: &Path) -> Result<PathBuf, TerminalError> {
+    let error = |source| TerminalError::Attachment {
+        path: path.display().to_string(),
+        source,
+    };
+    let source = path.canonicalize().map_err(error)?;
+    let root = worktree.canonicalize().map_err(error)?;
+
+    if let Ok(relative) = source.strip_prefix(&root)
+        && !relative.as_os_str().is_empty()
+    {
+        return Ok(relative.to_path_buf());
+    }
+    if source.is_dir() {
+        return Ok(source);
+    }
+
+    let name = source
+        .file_name()
+        .map(|n| n.to_string_lossy().into_owned())
+        .unwrap_or_else(|| "attachment".to_string());
+    let target = attachment_target(worktree, &name).map_err(error)?;
+    std::fs::copy(&source, worktree.join(&target)).map_err(error)?;
+    tracing::info!(
+        event = "ui.terminal.attachment_copied",
+        source = %source.display(),
+        target = %target.display()
+    );
+    Ok(target)
+}
+
+/// Save pasted image bytes into the attachments folder and return the
+/// relative path to type.
+pub fn stage_image(
+    worktree: &Path,
+    bytes: &[u8],
+    extension: &str,
+) -> Result<PathBuf, TerminalError> {
+    let name = format!(
+        "pasted-{}.{}",
+        chrono::Local::now().format("%Y%m%d-%H%M%S"),
+        extension
+    );
+    let error = |source| TerminalError::Attachment {
+        path: name.clone(),
+        source,
+    };
+    let target = attachment_target(worktree, &name).map_err(error)?;
+    std::fs::write(worktree.join(&target), bytes).map_err(error)?;
+    tracing::info!(event = "ui.terminal.image_pasted", target = %target.display());
+    Ok(target)
+}
+
+/// Free path for `name` in the attachments folder (relative to the
+/// worktree), creating the folder and its `.gitignore` if needed.
+fn attachment_target(worktree: &Path, name: &str) -> std::io::Result<PathBuf> {
+    let dir = worktree.join(ATTACHMENTS_DIR);
+    std::fs::create_dir_all(&dir)?;
+    let gitignore = dir.join(".gitignore");
+    if !gitignore.exists() {
+        std::fs::write(&gitignore, "*\n")?;
+    }
+
+    let (stem, extension) = match name.rsplit_once('.') {
+        Some((stem, ext)) if !stem.is_empty() => (stem, Some(ext)),
+        _ => (name, None),
+    };
+    let mut candidate = name.to_string();
+    let mut n = 1;
+    while dir.join(&candidate).exists() {
+        candidate = match extension {
+            Some(ext) => format!("{}-{}.{}", stem, n, ext),
+            None => format!("{}-{}", stem, n),
+        };
+        n += 1;
+    }
+    Ok(Path::new(ATTACHMENTS_DIR).join(candidate))
+}
+
+/// The text to type for `paths`: quoted, space separated, with a trailing
+/// space so the user can keep typing.
+pub fn format_paths(paths: &[PathBuf]) -> String {
+    let mut text = paths
+        .iter()
+        .map(|p| shell_escape(&p.to_string_lossy()))
+        .collect::<Vec<_>>()
+        .join(" ");
+    text.push(' ');
+    text
+}
+
+/// Bytes to write for pasted `text`. In bracketed paste mode the text is
+/// wrapped in paste markers, with any end marker inside it removed so the
+/// paste cannot end early.
+pub fn paste_bytes(text: &str, bracketed: bool) -> Vec<u8> {
+    if bracketed {
+        format!(
+            "{}{}{}",
+            PASTE_START,
+            text.replace(PASTE_END, ""),
+            PASTE_END
+        )
+        .into_bytes()
+    } else {
+        text.as_bytes().to_vec()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::fs;
+
+    #[test]
+    fn test_paste_bytes() {
+        assert_eq!(paste_bytes("a b", false), b"a b");
+        assert_eq!(paste_bytes("a b", true), b"\x1b[200~a b\x1b[201~");
+        assert_eq!(
+            paste_bytes("x\x1b[201~rm", true),
+            b"\x1b[200~xrm\x1b[201~".to_vec()
+        );
+    }
+
+    #[test]
+    fn test_format_paths() {
+        let paths = vec![PathBuf::from("a.rs"), PathBuf::from("b c.png")];
+        assert_eq!(format_paths(&paths), "'a.rs' 'b c.png' ");
+    }
+
+    #[test]
+    fn test_stage_file_inside_worktree_is_referenced() {
+        let worktree = tempfile::tempdir().unwrap();
+        fs::create_dir_all(worktree.path().join("src")).unwrap();
+        fs::write(worktree.path().join("src/lib.rs"), "x").unwrap();
+
+        let staged = stage_file(worktree.path(), &worktree.path().join("src/lib.rs")).unwrap();
+        assert_eq!(staged, PathBuf::from("src/lib.rs"));
+        assert!(!worktree.path().join(ATTACHMENTS_DIR).exists());
+    }
+
+    #[test]
+    fn test_stage_file_outside_worktree_is_copied_with_unique_name() {
+        let worktree = tempfile::tempdir().unwrap();
+        let outside = tempfile::tempdir().unwrap();
+        let shot = outside.path().join("shot.png");
+        fs::write(&shot, "png").unwrap();
+
+        let first = stage_file(worktree.path(), &shot).unwrap();
+        let second = stage_file(worktree.path(), &shot).unwrap();
+
+        assert_eq!(first, Path::new(ATTACHMENTS_DIR).join("shot.png"));
+        assert_eq!(second, Path::new(ATTACHMENTS_DIR).join("shot-1.png"));
+        assert_eq!(
+            fs::read_to_string(worktree.path().join(&second)).unwrap(),
+            "png"
+        );
+        assert_eq!(
+            fs::read_to_string(worktree.path().join(ATTACHMENTS_DIR).join(".gitignore")).unwrap(),
+            "*\n"
+        );
+
+        // Directories outside the worktree are referenced, not copied
+        let staged_dir = stage_file(worktree.path(), outside.path()).unwrap();
+        assert!(staged_dir.is_absolute());
+    }
+
+    #[test]
+    fn test_stage_file_missing_source_fails() {
+        let worktree = tempfile::tempdir().unwrap();
+        let result = stage_file(worktree.path(), Path::new("/no/such/file.png"));
+        assert!(matches!(result, Err(TerminalError::Attachment { .. })));
+    }
+
+    #[test]
+    fn test_stage_image_writes_bytes() {
+        let worktree = tempfile::tempdir().unwrap();
+        let staged = stage_image(worktree.path(), b"\x89PNG", "png").unwrap();
+        assert!(staged.starts_with(ATTACHMENTS_DIR));
+        assert_eq!(staged.extension().unwrap(), "png");
+        assert_eq!(fs::read(worktree.path().join(staged)).unwrap(), b"\x89PNG");
+    }
+}
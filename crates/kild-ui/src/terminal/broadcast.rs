@@ -0,0 +1,216 @@
+//! Broadcast input: mirror what is typed into one terminal to others.
+//!
+//! `MainView` decides who receives input (the selected panes, or every
+//! running daemon session in the project) and publishes that set to a
+//! shared [`BroadcastHub`] each render. `TerminalView` hands every write it
+//! makes to its own PTY to the hub, which forwards it to the other targets
+//! on a dedicated thread so slow daemon IPC never blocks typing. Input is
+//! only mirrored when the terminal it came from is itself a target.
+
+use std::io::Write;
+use std::sync::mpsc::{self, Sender};
+use std::sync::{Arc, Mutex};
+
+/// Shared PTY writer, as held by `Terminal`.
+pub type PtyWriter = Arc<Mutex<Box<dyn Write + Send>>>;
+
+/// Which terminals broadcast mode sends input to.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum BroadcastScope {
+    /// The panes of the active workspace that are not excluded.
+    Panes,
+    /// Every running daemon session in the active project.
+    Project,
+}
+
+impl BroadcastScope {
+    pub fn label(&self) -> &'static str {
+        match self {
+            BroadcastScope::Panes => "panes",
+            BroadcastScope::Project => "project",
+        }
+    }
+}
+
+/// A terminal that receives broadcast input.
+#[derive(Clone)]
+pub enum BroadcastTarget {
+    /// An open terminal, written through its PTY writer.
+    Pty(PtyWriter),
+    /// A daemon session, written via `WriteStdin` (it need not be open in the UI).
+    Daemon(String),
+}
+
+impl BroadcastTarget {
+    /// Whether this target is the terminal identified by `writer` / `daemon_session_id`.
+    fn is_source(&self, writer: &PtyWriter, daemon_session_id: Option<&str>) -> bool {
+        match self {
+            BroadcastTarget::Pty(w) => Arc::ptr_eq(w, writer),
+            BroadcastTarget::Daemon(id) => daemon_session_id == Some(id.as_str()),
+        }
+    }
+}
+
+/// Targets that should receive input typed into the source terminal, or
+/// `None` if the source is not part of the broadcast.
+fn mirror_targets(
+    targets: &[BroadcastTarget],
+    writer: &PtyWriter,
+    daemon_session_id: Option<&str>,
+) -> Option<Vec<BroadcastTarget>> {
+    if !targets
+        .iter()
+        .any(|t| t.is_source(writer, daemon_session_id))
+    {
+        return None;
+    }
+    let others: Vec<BroadcastTarget> = targets
+        .iter()
+        .filter(|t| !t.is_source(writer, daemon_session_id))
+        .cloned()
+        .collect();
+    (!others.is_empty()).then_some(others)
+}
+
+struct MirrorJob {
+    targets: Vec<BroadcastTarget>,
+    bytes: Vec<u8>,
+}
+
+/// Current broadcast targets plus the writer thread. Cheap to clone; every
+/// `TerminalView` holds one.
+#[derive(Clone)]
+pub struct BroadcastHub {
+    targets: Arc<Mutex<Vec<BroadcastTarget>>>,
+    jobs: Sender<MirrorJob>,
+}
+
+impl BroadcastHub {
+    pub fn new() -> Self {
+        let (jobs, rx) = mpsc::channel::<MirrorJob>();
+        // Exits when the last hub clone (and with it the sender) is dropped.
+        std::thread::spawn(move || {
+            for job in rx {
+                for target in &job.targets {
+                    write_target(target, &job.bytes);
+                }
+            }
+        });
+        Self {
+            targets: Arc::new(Mutex::new(Vec::new())),
+            jobs,
+        }
+    }
+
+    /// Replace the set of terminals that receive broadcast input.
+    pub fn set_targets(&self, targets: Vec<BroadcastTarget>) {
+        match self.targets.lock() {
+            Ok(mut current) => *current = targets,
+            Err(e) => tracing::error!(event = "ui.broadcast.lock_failed", error = %e),
+        }
+    }
+
+    /// Forward `bytes`, just written to the source terminal, to every other target.
+    pub fn mirror(&self, writer: &PtyWriter, daemon_session_id: Option<&str>, bytes: &[u8]) {
+        let others = match self.targets.lock() {
+            Ok(targets) => mirror_targets(&targets, writer, daemon_session_id),
+            Err(e) => {
+                tracing::error!(event = "ui.broadcast.lock_failed", error = %e);
+                return;
+            }
+        };
+        let Some(targets) = others else {
+            return;
+        };
+        let job = MirrorJob {
+            targets,
+            bytes: bytes.to_vec(),
+        };
+        if self.jobs.send(job).is_err() {
+            tracing::error!(event = "ui.broadcast.writer_thread_gone");
+        }
+    }
+}
+
+impl Default for BroadcastHub {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+fn write_target(target: &BroadcastTarget, bytes: &[u8]) {
+    match target {
+        BroadcastTarget::Pty(writer) => {
+            let result = match writer.lock() {
+                Ok(mut w) => w.write_all(bytes).and_then(|()| w.flush()),
+                Err(e) => {
+                    tracing::warn!(event = "ui.broadcast.write_failed", error = %e);
+                    return;
+                }
+            };
+            if let Err(e) = result {
+                tracing::warn!(event = "ui.broadcast.write_failed", error = %e);
+            }
+        }
+        BroadcastTarget::Daemon(id) => {
+            if let Err(e) = kild_core::daemon::client::write_stdin(id, bytes) {
+                tracing::warn!(
+                    event = "ui.broadcast.write_failed",
+                    daemon_session_id = id.as_str(),
+                    error = %e
+                );
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn writer() -> PtyWriter {
+        Arc::new(Mutex::new(Box::new(Vec::<u8>::new())))
+    }
+
+    #[test]
+    fn test_mirror_targets_excludes_source() {
+        let (a, b, c) = (writer(), writer(), writer());
+        let targets = vec![
+            BroadcastTarget::Pty(a.clone()),
+            BroadcastTarget::Pty(b.clone()),
+            BroadcastTarget::Pty(c.clone()),
+        ];
+
+        let others = mirror_targets(&targets, &b, None).unwrap();
+        assert_eq!(others.len(), 2);
+        assert!(others.iter().all(|t| !t.is_source(&b, None)));
+    }
+
+    #[test]
+    fn test_mirror_targets_requires_source_membership() {
+        let (a, b, outsider) = (writer(), writer(), writer());
+        let targets = vec![BroadcastTarget::Pty(a), BroadcastTarget::Pty(b)];
+        assert!(mirror_targets(&targets, &outsider, None).is_none());
+        assert!(mirror_targets(&[], &outsider, None).is_none());
+    }
+
+    #[test]
+    fn test_mirror_targets_matches_daemon_source_by_session_id() {
+        let source = writer();
+        let targets = vec![
+            BroadcastTarget::Daemon("d1".to_string()),
+            BroadcastTarget::Daemon("d2".to_string()),
+        ];
+
+        let others = mirror_targets(&targets, &source, Some("d1")).unwrap();
+        assert!(matches!(others.as_slice(), [BroadcastTarget::Daemon(id)] if id == "d2"));
+        assert!(mirror_targets(&targets, &source, Some("d3")).is_none());
+    }
+
+    #[test]
+    fn test_mirror_targets_alone_is_none() {
+        let source = writer();
+        let targets = vec![BroadcastTarget::Pty(source.clone())];
+        assert!(mirror_targets(&targets, &source, None).is_none());
+    }
+}
@@ -1,35 +1,14 @@
-use std::sync::LazyLock;
-
 use alacritty_terminal::vte::ansi::{Color, NamedColor};
 use gpui::{Hsla, Rgba};
 
 use crate::theme;
 
-/// ANSI 16 color lookup table mapped to Tallinn Night palette.
+/// ANSI 16 color from the active palette.
 ///
 /// Index 0-7: standard colors, 8-15: bright colors.
-/// These are intentionally more vivid than the muted brand palette
-/// for terminal readability on dark backgrounds.
-static ANSI_COLORS: LazyLock<[Hsla; 16]> = LazyLock::new(|| {
-    [
-        Hsla::from(theme::ansi_black()),
-        Hsla::from(theme::ansi_red()),
-        Hsla::from(theme::ansi_green()),
-        Hsla::from(theme::ansi_yellow()),
-        Hsla::from(theme::ansi_blue()),
-        Hsla::from(theme::ansi_magenta()),
-        Hsla::from(theme::ansi_cyan()),
-        Hsla::from(theme::ansi_white()),
-        Hsla::from(theme::ansi_bright_black()),
-        Hsla::from(theme::ansi_bright_red()),
-        Hsla::from(theme::ansi_bright_green()),
-        Hsla::from(theme::ansi_bright_yellow()),
-        Hsla::from(theme::ansi_bright_blue()),
-        Hsla::from(theme::ansi_bright_magenta()),
-        Hsla::from(theme::ansi_bright_cyan()),
-        Hsla::from(theme::ansi_bright_white()),
-    ]
-});
+fn ansi(idx: usize) -> Hsla {
+    Hsla::from(theme::ansi(idx))
+}
 
 /// Convert an alacritty_terminal Color to a GPUI Hsla.
 pub fn resolve_color(color: &Color) -> Hsla {
@@ -47,35 +26,34 @@ pub fn resolve_color(color: &Color) -> Hsla {
 
 /// Resolve a named ANSI color to Hsla.
 fn resolve_named(named: NamedColor) -> Hsla {
-    let table = &*ANSI_COLORS;
     match named {
         // Standard 0-7
-        NamedColor::Black => table[0],
-        NamedColor::Red => table[1],
-        NamedColor::Green => table[2],
-        NamedColor::Yellow => table[3],
-        NamedColor::Blue => table[4],
-        NamedColor::Magenta => table[5],
-        NamedColor::Cyan => table[6],
-        NamedColor::White => table[7],
+        NamedColor::Black => ansi(0),
+        NamedColor::Red => ansi(1),
+        NamedColor::Green => ansi(2),
+        NamedColor::Yellow => ansi(3),
+        NamedColor::Blue => ansi(4),
+        NamedColor::Magenta => ansi(5),
+        NamedColor::Cyan => ansi(6),
+        NamedColor::White => ansi(7),
         // Bright 8-15
-        NamedColor::BrightBlack => table[8],
-        NamedColor::BrightRed => table[9],
-        NamedColor::BrightGreen => table[10],
-        NamedColor::BrightYellow => table[11],
-        NamedColor::BrightBlue => table[12],
-        NamedColor::BrightMagenta => table[13],
-        NamedColor::BrightCyan => table[14],
-        NamedColor::BrightWhite => table[15],
+        NamedColor::BrightBlack => ansi(8),
+        NamedColor::BrightRed => ansi(9),
+        NamedColor::BrightGreen => ansi(10),
+        NamedColor::BrightYellow => ansi(11),
+        NamedColor::BrightBlue => ansi(12),
+        NamedColor::BrightMagenta => ansi(13),
+        NamedColor::BrightCyan => ansi(14),
+        NamedColor::BrightWhite => ansi(15),
         // Dim variants — use standard colors at reduced lightness
-        NamedColor::DimBlack => dim(table[0]),
-        NamedColor::DimRed => dim(table[1]),
-        NamedColor::DimGreen => dim(table[2]),
-        NamedColor::DimYellow => dim(table[3]),
-        NamedColor::DimBlue => dim(table[4]),
-        NamedColor::DimMagenta => dim(table[5]),
-        NamedColor::DimCyan => dim(table[6]),
-        NamedColor::DimWhite => dim(table[7]),
+        NamedColor::DimBlack => dim(ansi(0)),
+        NamedColor::DimRed => dim(ansi(1)),
+        NamedColor::DimGreen => dim(ansi(2)),
+        NamedColor::DimYellow => dim(ansi(3)),
+        NamedColor::DimBlue => dim(ansi(4)),
+        NamedColor::DimMagenta => dim(ansi(5)),
+        NamedColor::DimCyan => dim(ansi(6)),
+        NamedColor::DimWhite => dim(ansi(7)),
         // Special colors
         NamedColor::Foreground | NamedColor::BrightForeground => {
             Hsla::from(theme::terminal_foreground())
@@ -90,10 +68,7 @@ fn resolve_named(named: NamedColor) -> Hsla {
 fn resolve_indexed(idx: u8) -> Hsla {
     match idx {
         // 0-15: same as named ANSI colors
-        0..=15 => {
-            let table = &*ANSI_COLORS;
-            table[idx as usize]
-        }
+        0..=15 => ansi(idx as usize),
         // 16-231: 6x6x6 RGB color cube
         16..=231 => {
             let idx = idx - 16;
@@ -38,6 +38,13 @@ pub enum TerminalError {
     #[error("Daemon protocol error: {message}")]
     DaemonProtocol { message: String },
 
+    #[error("Failed to attach '{path}': {source}")]
+    Attachment {
+        path: String,
+        #[source]
+        source: std::io::Error,
+    },
+
     #[error("Base64 decode failed")]
     Base64Decode(#[from] base64::DecodeError),
 }
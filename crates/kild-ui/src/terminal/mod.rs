@@ -1,7 +1,10 @@
+pub mod attachments;
 mod blink;
+pub mod broadcast;
 pub mod colors;
 pub mod errors;
 pub mod input;
+pub mod search;
 pub mod state;
 pub mod terminal_element;
 pub mod terminal_view;
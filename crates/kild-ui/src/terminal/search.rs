@@ -0,0 +1,182 @@
+//! Incremental search over the terminal grid and scrollback.
+//!
+//! Queries are matched literally and smart-case: case-insensitive unless the
+//! query contains an uppercase letter. Highlights are collected around the
+//! viewport each frame; stepping between matches walks the whole scrollback.
+
+use alacritty_terminal::event::EventListener;
+use alacritty_terminal::grid::Dimensions;
+use alacritty_terminal::index::{Boundary, Column, Direction, Line, Point, Side};
+use alacritty_terminal::term::Term;
+use alacritty_terminal::term::search::{Match, RegexIter, RegexSearch};
+
+/// Lines scanned above and below the viewport when collecting highlights, so
+/// a match wrapped across the viewport edge still highlights its visible part.
+const HIGHLIGHT_MARGIN_LINES: i32 = 2;
+
+/// Upper bound on highlights per frame (a one-letter query on a full screen).
+const MAX_HIGHLIGHTS: usize = 1000;
+
+/// Search bar state owned by `TerminalView`.
+#[derive(Default)]
+pub struct TerminalSearch {
+    query: String,
+    regex: Option<RegexSearch>,
+    /// The match the view is scrolled to, in grid coordinates.
+    focused: Option<Match>,
+}
+
+/// Matches to paint for one frame, in grid coordinates.
+pub struct SearchHighlights {
+    pub(crate) matches: Vec<Match>,
+    pub(crate) focused: Option<Match>,
+}
+
+impl TerminalSearch {
+    pub fn query(&self) -> &str {
+        &self.query
+    }
+
+    /// Whether the current query has at least one match.
+    pub fn has_match(&self) -> bool {
+        self.focused.is_some()
+    }
+
+    /// Replace the query and jump to the most recent match.
+    pub fn set_query<T: EventListener>(&mut self, query: &str, term: &mut Term<T>) {
+        self.query = query.to_string();
+        self.focused = None;
+        self.regex = if query.is_empty() {
+            None
+        } else {
+            match RegexSearch::new(&regex::escape(query)) {
+                Ok(regex) => Some(regex),
+                Err(e) => {
+                    tracing::warn!(event = "ui.terminal.search_regex_failed", error = %e);
+                    None
+                }
+            }
+        };
+        let bottom = Point::new(term.bottommost_line(), term.last_column());
+        self.jump(term, bottom, Direction::Left);
+    }
+
+    /// Move to the next match towards older output (`Direction::Left`) or
+    /// newer output (`Direction::Right`), wrapping at either end.
+    pub fn step<T: EventListener>(&mut self, term: &mut Term<T>, direction: Direction) {
+        let origin = match (&self.focused, direction) {
+            (Some(current), Direction::Left) => current.start().sub(term, Boundary::None, 1),
+            (Some(current), Direction::Right) => current.end().add(term, Boundary::None, 1),
+            (None, _) => Point::new(term.bottommost_line(), term.last_column()),
+        };
+        self.jump(term, origin, direction);
+    }
+
+    fn jump<T: EventListener>(&mut self, term: &mut Term<T>, origin: Point, direction: Direction) {
+        let Some(regex) = self.regex.as_mut() else {
+            return;
+        };
+        self.focused = term.search_next(regex, origin, direction, Side::Left, None);
+        if let Some(found) = &self.focused {
+            term.scroll_to_point(*found.start());
+        }
+    }
+
+    /// Matches on or near the visible screen. `None` when there is no query.
+    pub fn highlights<T>(&mut self, term: &Term<T>) -> Option<SearchHighlights> {
+        let regex = self.regex.as_mut()?;
+        let top = -(term.grid().display_offset() as i32);
+        let bottom = top + term.screen_lines() as i32 - 1;
+        let start = Point::new(
+            Line((top - HIGHLIGHT_MARGIN_LINES).max(term.topmost_line().0)),
+            Column(0),
+        );
+        let end = Point::new(
+            Line((bottom + HIGHLIGHT_MARGIN_LINES).min(term.bottommost_line().0)),
+            term.last_column(),
+        );
+        let matches = RegexIter::new(start, end, Direction::Right, term, regex)
+            .take(MAX_HIGHLIGHTS)
+            .collect();
+        Some(SearchHighlights {
+            matches,
+            focused: self.focused.clone(),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alacritty_terminal::event::VoidListener;
+    use alacritty_terminal::term::Config;
+    use alacritty_terminal::term::test::TermSize;
+    use alacritty_terminal::vte::ansi::Processor;
+
+    /// A 20x3 terminal that has scrolled `lines` into history.
+    fn term_with(lines: &[&str]) -> Term<VoidListener> {
+        let mut term = Term::new(Config::default(), &TermSize::new(20, 3), VoidListener);
+        let mut processor: Processor = Processor::new();
+        processor.advance(&mut term, lines.join("\r\n").as_bytes());
+        term
+    }
+
+    #[test]
+    fn test_set_query_focuses_most_recent_match() {
+        let mut term = term_with(&["error one", "ok", "Error two", "ok", "done"]);
+        let mut search = TerminalSearch::default();
+
+        search.set_query("error", &mut term);
+        assert!(search.has_match());
+        // Smart case: lowercase query matches both; the newest is focused.
+        let focused = search.focused.clone().unwrap();
+        assert_eq!(focused.start().column, Column(0));
+        assert_eq!(term.grid()[focused.start().line][Column(0)].c, 'E');
+        // Scrolled back far enough to show it.
+        let top = -(term.grid().display_offset() as i32);
+        assert!(focused.start().line.0 >= top);
+    }
+
+    #[test]
+    fn test_step_wraps_through_scrollback() {
+        let mut term = term_with(&["hit a", "x", "hit b", "x", "x"]);
+        let mut search = TerminalSearch::default();
+        search.set_query("hit", &mut term);
+        let newest = search.focused.clone().unwrap();
+
+        search.step(&mut term, Direction::Left);
+        let older = search.focused.clone().unwrap();
+        assert!(older.start().line < newest.start().line);
+        assert_eq!(term.grid().display_offset(), 2);
+
+        // Wraps around to the newest again.
+        search.step(&mut term, Direction::Left);
+        assert_eq!(search.focused.clone().unwrap(), newest);
+        search.step(&mut term, Direction::Right);
+        assert_eq!(search.focused.clone().unwrap(), older);
+    }
+
+    #[test]
+    fn test_uppercase_query_is_case_sensitive() {
+        let mut term = term_with(&["error", "Error"]);
+        let mut search = TerminalSearch::default();
+        search.set_query("Error", &mut term);
+        let highlights = search.highlights(&term).unwrap();
+        assert_eq!(highlights.matches.len(), 1);
+
+        search.set_query("nothing here", &mut term);
+        assert!(!search.has_match());
+        assert!(search.highlights(&term).unwrap().matches.is_empty());
+    }
+
+    #[test]
+    fn test_query_is_literal() {
+        let mut term = term_with(&["a.b", "axb"]);
+        let mut search = TerminalSearch::default();
+        search.set_query("a.b", &mut term);
+        assert_eq!(search.highlights(&term).unwrap().matches.len(), 1);
+
+        search.set_query("", &mut term);
+        assert!(search.highlights(&term).is_none());
+    }
+}
@@ -16,7 +16,7 @@ use kild_protocol::DaemonMessage;
 use portable_pty::{Child, CommandBuilder, MasterPty, PtySize, native_pty_system};
 
 use super::errors::TerminalError;
-use crate::daemon_client::{self, DaemonConnection};
+use crate::daemon_client::{self, DaemonConnection, DaemonEndpoint};
 
 /// State of a reconnection attempt in a daemon terminal.
 #[derive(Debug, Clone, PartialEq)]
@@ -234,6 +234,8 @@ pub struct Terminal {
     last_mode: TermMode,
     /// Daemon session ID for reconnection. `None` for local terminals.
     daemon_session_id: Option<String>,
+    /// Daemon the session lives on. `None` for local terminals.
+    daemon_endpoint: Option<DaemonEndpoint>,
     /// Reconnection state for daemon terminals. Shared with the view layer.
     reconnect_state: Arc<Mutex<ReconnectState>>,
 }
@@ -392,6 +394,7 @@ impl Terminal {
             current_size,
             last_mode: initial_mode,
             daemon_session_id: None,
+            daemon_endpoint: None,
             reconnect_state: Arc::new(Mutex::new(ReconnectState::Idle)),
         })
     }
@@ -441,6 +444,7 @@ impl Terminal {
         let error_state: Arc<Mutex<Option<String>>> = Arc::new(Mutex::new(None));
 
         // Destructure connection into reader/writer halves
+        let endpoint = conn.endpoint().clone();
         let (reader, writer, _conn_session_id) = conn.into_parts();
 
         // Spawn IPC reader task: reads JSONL from daemon, base64 decodes, feeds byte channel
@@ -622,6 +626,7 @@ impl Terminal {
             current_size,
             last_mode: initial_mode,
             daemon_session_id: Some(session_id),
+            daemon_endpoint: Some(endpoint),
             reconnect_state: Arc::new(Mutex::new(ReconnectState::Idle)),
         })
     }
@@ -822,6 +827,11 @@ impl Terminal {
         self.daemon_session_id.as_deref()
     }
 
+    /// Daemon the session lives on, if this terminal is daemon-backed.
+    pub fn daemon_endpoint(&self) -> Option<&DaemonEndpoint> {
+        self.daemon_endpoint.as_ref()
+    }
+
     /// Current reconnection state (cloned snapshot).
     pub fn reconnect_state(&self) -> ReconnectState {
         self.reconnect_state
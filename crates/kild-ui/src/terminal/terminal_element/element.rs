@@ -1,4 +1,6 @@
-use std::sync::Arc;
+use std::ops::Range;
+use std::path::{Path, PathBuf};
+use std::sync::{Arc, LazyLock};
 
 use alacritty_terminal::index::{Column, Line, Point as AlacPoint, Side};
 use alacritty_terminal::sync::FairMutex;
@@ -8,10 +10,14 @@ use gpui::{
     Pixels, SharedString, Size, Style, TextRun, Window, px,
 };
 use linkify::{LinkFinder, LinkKind};
+use regex::Regex;
 
+use super::super::search::SearchHighlights;
 use super::super::state::{KildListener, ResizeHandle};
 use super::super::types::TerminalContent;
-use super::types::{FONT_NORMAL, LineText, MouseState, PrepaintState, PreparedUrlRegion};
+use super::types::{
+    LineText, MouseState, PrepaintState, PreparedFileRegion, PreparedUrlRegion, terminal_font,
+};
 use crate::theme;
 
 /// Custom GPUI Element that renders terminal cells as GPU draw calls.
@@ -28,6 +34,9 @@ pub struct TerminalElement {
     pub(super) resize_handle: ResizeHandle,
     pub(super) cursor_visible: bool,
     pub(super) mouse_state: MouseState,
+    /// Base for relative `file:line` references (the kild worktree).
+    pub(super) working_dir: Option<PathBuf>,
+    pub(super) search: Option<SearchHighlights>,
 }
 
 impl TerminalElement {
@@ -46,17 +55,33 @@ impl TerminalElement {
             resize_handle,
             cursor_visible,
             mouse_state,
+            working_dir: None,
+            search: None,
         }
     }
 
+    /// Resolve relative `file:line` references against `dir`.
+    pub fn working_dir(mut self, dir: Option<PathBuf>) -> Self {
+        self.working_dir = dir;
+        self
+    }
+
+    /// Highlight search matches.
+    pub fn search_highlights(mut self, search: Option<SearchHighlights>) -> Self {
+        self.search = search;
+        self
+    }
+
     /// Convert pixel position to terminal grid Point + Side.
     /// Clamps negative coordinates to 0. Does not clamp to terminal dimensions
     /// (alacritty's Selection API handles out-of-bounds points).
+    /// `display_offset` shifts viewport rows into scrollback (negative lines).
     pub(super) fn pixel_to_grid(
         position: gpui::Point<Pixels>,
         bounds: Bounds<Pixels>,
         cell_width: Pixels,
         cell_height: Pixels,
+        display_offset: usize,
     ) -> (AlacPoint, Side) {
         let col = ((position.x - bounds.origin.x) / cell_width).max(0.0);
         let line = ((position.y - bounds.origin.y) / cell_height).max(0.0);
@@ -68,15 +93,18 @@ impl TerminalElement {
         // Clamp before casting to prevent overflow on extreme values.
         let line_i32 = (line.floor() as f64).min(i32::MAX as f64) as i32;
         let col_usize = (col.floor() as f64).min(usize::MAX as f64) as usize;
-        (AlacPoint::new(Line(line_i32), Column(col_usize)), side)
+        (
+            AlacPoint::new(Line(line_i32) - display_offset, Column(col_usize)),
+            side,
+        )
     }
 
     /// Measure cell dimensions using a reference character.
     pub(crate) fn measure_cell(window: &mut Window, _cx: &mut App) -> (Pixels, Pixels) {
-        let font_size = px(theme::TEXT_BASE);
+        let font_size = px(theme::mono_font_size());
         let run = TextRun {
             len: 1,
-            font: FONT_NORMAL.clone(),
+            font: terminal_font(false, false),
             color: gpui::black(),
             background_color: None,
             underline: None,
@@ -87,7 +115,9 @@ impl TerminalElement {
                 .text_system()
                 .shape_line(SharedString::from("M"), font_size, &[run], None);
         let cell_width = line.width;
-        let cell_height = window.line_height();
+        // Line height follows the window text style; scale it with the
+        // configured terminal size relative to the default.
+        let cell_height = window.line_height() * (theme::mono_font_size() / theme::TEXT_BASE);
         (cell_width, cell_height)
     }
 }
@@ -179,56 +209,188 @@ pub(crate) fn detect_urls(
     let mut finder = LinkFinder::new();
     finder.kinds(&[LinkKind::Url]);
     for (line_idx, text, col_offsets) in line_texts {
-        // Build byte-offset → char-index lookup for multi-byte char support.
-        // linkify returns byte offsets, but col_offsets is indexed by char position.
-        let byte_to_char: Vec<usize> = {
-            let mut map = vec![0usize; text.len() + 1];
-            for (char_idx, (byte_idx, _)) in text.char_indices().enumerate() {
-                map[byte_idx] = char_idx;
+        let byte_to_char = byte_to_char_map(text);
+        for link in finder.links(text) {
+            let span = (
+                byte_to_char[link.start()],
+                byte_to_char[link.end()], // exclusive char index
+            );
+            if let Some(region) = span_bounds(
+                *line_idx,
+                col_offsets,
+                span,
+                link.as_str(),
+                bounds,
+                cell_width,
+                cell_height,
+            ) {
+                url_regions.push(PreparedUrlRegion {
+                    bounds: region,
+                    url: link.as_str().to_string(),
+                });
             }
-            // Sentinel for exclusive end offset
-            map[text.len()] = text.chars().count();
-            map
-        };
+        }
+    }
+    url_regions
+}
 
-        for link in finder.links(text) {
-            let start_byte = link.start();
-            let end_byte = link.end();
-            let start_char = byte_to_char[start_byte];
-            let end_char = byte_to_char[end_byte]; // exclusive char index
-            if start_char >= col_offsets.len() || end_char == 0 {
+/// `path:line` or `path:line:column`. The last path component must have an
+/// extension, which keeps out most `host:port` and timestamp lookalikes.
+static FILE_REF_RE: LazyLock<Regex> = LazyLock::new(|| {
+    Regex::new(
+        r"(?P<path>/?(?:[\w.@+-]+/)*[\w@+-][\w.@+-]*\.\w+):(?P<line>\d+)(?::(?P<column>\d+))?",
+    )
+    .expect("file reference regex is valid")
+});
+
+/// A `path:line[:column]` reference found in a line of terminal text.
+#[derive(Debug, PartialEq, Eq)]
+pub(crate) struct FileRef {
+    /// Byte range of the whole reference in the line.
+    pub(crate) range: Range<usize>,
+    pub(crate) path: String,
+    pub(crate) line: u32,
+    pub(crate) column: Option<u32>,
+}
+
+/// Find `path:line[:column]` references in `text`. Line 0 is not a line.
+pub(crate) fn parse_file_refs(text: &str) -> Vec<FileRef> {
+    FILE_REF_RE
+        .captures_iter(text)
+        .filter_map(|caps| {
+            let whole = caps.get(0)?;
+            let line = caps["line"].parse::<u32>().ok().filter(|l| *l > 0)?;
+            let column = caps
+                .name("column")
+                .and_then(|c| c.as_str().parse::<u32>().ok())
+                .filter(|c| *c > 0);
+            Some(FileRef {
+                range: whole.range(),
+                path: caps["path"].to_string(),
+                line,
+                column,
+            })
+        })
+        .collect()
+}
+
+/// Resolve a referenced path against `working_dir`, keeping it only if it
+/// names an existing file.
+pub(crate) fn resolve_file_ref(path: &str, working_dir: Option<&Path>) -> Option<PathBuf> {
+    let path = Path::new(path);
+    let full = if path.is_absolute() {
+        path.to_path_buf()
+    } else {
+        working_dir?.join(path)
+    };
+    full.is_file().then_some(full)
+}
+
+/// Extract `file:line` references that point at existing files.
+///
+/// References inside a URL are skipped; those are handled by [`detect_urls`].
+pub(crate) fn detect_file_refs(
+    line_texts: &[LineText],
+    working_dir: Option<&Path>,
+    bounds: Bounds<Pixels>,
+    cell_width: Pixels,
+    cell_height: Pixels,
+) -> Vec<PreparedFileRegion> {
+    let mut regions = Vec::new();
+    let mut finder = LinkFinder::new();
+    finder.kinds(&[LinkKind::Url]);
+    for (line_idx, text, col_offsets) in line_texts {
+        let refs = parse_file_refs(text);
+        if refs.is_empty() {
+            continue;
+        }
+        let urls: Vec<Range<usize>> = finder.links(text).map(|l| l.start()..l.end()).collect();
+        let byte_to_char = byte_to_char_map(text);
+        for file_ref in refs {
+            if urls
+                .iter()
+                .any(|u| u.start < file_ref.range.end && file_ref.range.start < u.end)
+            {
                 continue;
             }
-            let (start_col, _) = col_offsets[start_char];
-            // end_char is exclusive, so subtract 1 to get the last inclusive
-            // char index, then clamp to valid range. The end grid column is
-            // last_col + last_width to account for wide chars occupying 2
-            // grid columns.
-            let unclamped = end_char - 1;
-            let last_idx = unclamped.min(col_offsets.len() - 1);
-            if unclamped != last_idx {
-                tracing::debug!(
-                    event = "ui.terminal.url_bounds_clamped",
-                    url = link.as_str(),
-                    end_char = end_char,
-                    col_offsets_len = col_offsets.len(),
-                );
-            }
-            let (last_col, last_width) = col_offsets[last_idx];
-            let end_col = last_col + last_width;
-            debug_assert!(
-                end_col > start_col,
-                "URL region width must be positive: start_col={start_col}, end_col={end_col}, url={}",
-                link.as_str()
+            let Some(path) = resolve_file_ref(&file_ref.path, working_dir) else {
+                continue;
+            };
+            let span = (
+                byte_to_char[file_ref.range.start],
+                byte_to_char[file_ref.range.end],
             );
-            let x = (bounds.origin.x + start_col as f32 * cell_width).floor();
-            let y = bounds.origin.y + *line_idx as f32 * cell_height;
-            let w = (end_col - start_col) as f32 * cell_width;
-            url_regions.push(PreparedUrlRegion {
-                bounds: Bounds::new(gpui::point(x, y), gpui::size(w, cell_height)),
-                url: link.as_str().to_string(),
-            });
+            if let Some(region) = span_bounds(
+                *line_idx,
+                col_offsets,
+                span,
+                &text[file_ref.range.clone()],
+                bounds,
+                cell_width,
+                cell_height,
+            ) {
+                regions.push(PreparedFileRegion {
+                    bounds: region,
+                    path,
+                    line: file_ref.line,
+                    column: file_ref.column,
+                });
+            }
         }
     }
-    url_regions
+    regions
+}
+
+/// Byte-offset → char-index lookup for multi-byte char support.
+///
+/// Matchers return byte offsets, but col_offsets is indexed by char position.
+fn byte_to_char_map(text: &str) -> Vec<usize> {
+    let mut map = vec![0usize; text.len() + 1];
+    for (char_idx, (byte_idx, _)) in text.char_indices().enumerate() {
+        map[byte_idx] = char_idx;
+    }
+    // Sentinel for exclusive end offset
+    map[text.len()] = text.chars().count();
+    map
+}
+
+/// Pixel bounds of the chars `start_char..end_char` (end exclusive) of a
+/// scanned line. `label` is only used for logging.
+fn span_bounds(
+    line_idx: i32,
+    col_offsets: &[(usize, usize)],
+    (start_char, end_char): (usize, usize),
+    label: &str,
+    bounds: Bounds<Pixels>,
+    cell_width: Pixels,
+    cell_height: Pixels,
+) -> Option<Bounds<Pixels>> {
+    if start_char >= col_offsets.len() || end_char == 0 {
+        return None;
+    }
+    let (start_col, _) = col_offsets[start_char];
+    // end_char is exclusive, so subtract 1 to get the last inclusive
+    // char index, then clamp to valid range. The end grid column is
+    // last_col + last_width to account for wide chars occupying 2
+    // grid columns.
+    let unclamped = end_char - 1;
+    let last_idx = unclamped.min(col_offsets.len() - 1);
+    if unclamped != last_idx {
+        tracing::debug!(
+            event = "ui.terminal.url_bounds_clamped",
+            url = label,
+            end_char = end_char,
+            col_offsets_len = col_offsets.len(),
+        );
+    }
+    let (last_col, last_width) = col_offsets[last_idx];
+    let end_col = last_col + last_width;
+    debug_assert!(
+        end_col > start_col,
+        "link region width must be positive: start_col={start_col}, end_col={end_col}, link={label}"
+    );
+    let x = (bounds.origin.x + start_col as f32 * cell_width).floor();
+    let y = bounds.origin.y + line_idx as f32 * cell_height;
+    let w = (end_col - start_col) as f32 * cell_width;
+    Some(Bounds::new(gpui::point(x, y), gpui::size(w, cell_height)))
 }
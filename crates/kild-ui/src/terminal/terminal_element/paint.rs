@@ -1,12 +1,17 @@
+use std::path::PathBuf;
+
 use alacritty_terminal::selection::{Selection, SelectionType};
 use alacritty_terminal::vte::ansi::CursorShape;
 use gpui::{
-    App, BorderStyle, Bounds, CursorStyle, DispatchPhase, Hsla, MouseButton, MouseDownEvent,
-    MouseMoveEvent, Pixels, SharedString, TextRun, Window, fill, point, px, quad, size,
+    App, BorderStyle, Bounds, ClipboardItem, CursorStyle, DispatchPhase, Hsla, MouseButton,
+    MouseDownEvent, MouseMoveEvent, MouseUpEvent, Pixels, SharedString, TextRun, Window, fill,
+    point, px, quad, size,
 };
+use kild_core::editor::FilePosition;
 
 use super::element::TerminalElement;
-use super::types::{FONT_BOLD, FONT_BOLD_ITALIC, FONT_ITALIC, FONT_NORMAL, PrepaintState};
+use super::types::{PrepaintState, terminal_font};
+use crate::actions;
 use crate::theme;
 
 impl TerminalElement {
@@ -18,7 +23,7 @@ impl TerminalElement {
         cx: &mut App,
     ) {
         let terminal_bg = Hsla::from(theme::terminal_background());
-        let font_size = px(theme::TEXT_BASE);
+        let font_size = px(theme::mono_font_size());
 
         // Painter's algorithm — layers are painted back-to-front so later
         // draws occlude earlier ones without needing a depth buffer.
@@ -36,7 +41,12 @@ impl TerminalElement {
             window.paint_quad(fill(rect.bounds, rect.color));
         }
 
-        // Layer 2.7: URL underline highlights (Cmd+hover)
+        // Layer 2.6: Search match highlights
+        for rect in &prepaint.search_rects {
+            window.paint_quad(fill(rect.bounds, rect.color));
+        }
+
+        // Layer 2.7: URL and file:line underline highlights (Cmd+hover)
         if self.mouse_state.cmd_held
             && let Some(mouse_pos) = self.mouse_state.position
         {
@@ -45,14 +55,19 @@ impl TerminalElement {
                 ..Hsla::from(theme::ice())
             };
             let mut hovering_url = false;
-            for region in &prepaint.url_regions {
-                if region.bounds.contains(&mouse_pos) {
+            let link_bounds = prepaint
+                .url_regions
+                .iter()
+                .map(|r| r.bounds)
+                .chain(prepaint.file_regions.iter().map(|r| r.bounds));
+            for region_bounds in link_bounds {
+                if region_bounds.contains(&mouse_pos) {
                     hovering_url = true;
-                    let underline_y = region.bounds.origin.y + prepaint.cell_height - px(1.5);
+                    let underline_y = region_bounds.origin.y + prepaint.cell_height - px(1.5);
                     window.paint_quad(fill(
                         Bounds::new(
-                            point(region.bounds.origin.x, underline_y),
-                            size(region.bounds.size.width, px(1.5)),
+                            point(region_bounds.origin.x, underline_y),
+                            size(region_bounds.size.width, px(1.5)),
                         ),
                         url_color,
                     ));
@@ -70,12 +85,7 @@ impl TerminalElement {
             for run in &line.runs {
                 let x = (bounds.origin.x + run.start_col() as f32 * prepaint.cell_width).floor();
 
-                let f = match (run.bold(), run.italic()) {
-                    (true, true) => FONT_BOLD_ITALIC.clone(),
-                    (true, false) => FONT_BOLD.clone(),
-                    (false, true) => FONT_ITALIC.clone(),
-                    (false, false) => FONT_NORMAL.clone(),
-                };
+                let f = terminal_font(run.bold(), run.italic());
 
                 let underline = if run.underline() {
                     Some(gpui::UnderlineStyle {
@@ -159,7 +169,7 @@ impl TerminalElement {
 
             let badge_run = TextRun {
                 len: badge_text.len(),
-                font: FONT_NORMAL.clone(),
+                font: terminal_font(false, false),
                 color: badge_fg,
                 background_color: None,
                 underline: None,
@@ -212,7 +222,18 @@ impl TerminalElement {
             .iter()
             .map(|r| (r.bounds, r.url.clone()))
             .collect();
-        window.on_mouse_event::<MouseDownEvent>(move |event, phase, window, _cx| {
+        let click_file_regions: Vec<(Bounds<Pixels>, PathBuf, FilePosition)> = prepaint
+            .file_regions
+            .iter()
+            .map(|r| {
+                let position = FilePosition {
+                    line: r.line,
+                    column: r.column,
+                };
+                (r.bounds, r.path.clone(), position)
+            })
+            .collect();
+        window.on_mouse_event::<MouseDownEvent>(move |event, phase, window, cx| {
             if phase == DispatchPhase::Bubble
                 && event.button == MouseButton::Left
                 && hitbox.is_hovered(window)
@@ -259,14 +280,44 @@ impl TerminalElement {
                     }
                 }
 
-                let (grid_point, side) =
-                    Self::pixel_to_grid(event.position, sel_bounds, cell_width, cell_height);
+                // Cmd+click on file:line → open in the configured editor
+                if event.modifiers.platform
+                    && let Some((_, path, position)) = click_file_regions
+                        .iter()
+                        .find(|(bounds, _, _)| bounds.contains(&event.position))
+                {
+                    let (path, position) = (path.clone(), *position);
+                    cx.background_executor()
+                        .spawn(async move {
+                            // TODO: Surface to user via UI error state (same
+                            // limitation as URL opening above).
+                            if let Err(e) = actions::open_file_at(&path, position) {
+                                tracing::error!(
+                                    event = "ui.terminal.file_open_failed",
+                                    path = %path.display(),
+                                    error = %e,
+                                );
+                            }
+                        })
+                        .detach();
+                    return;
+                }
+
+                let mut term = term.lock();
+                let display_offset = term.grid().display_offset();
+                let (grid_point, side) = Self::pixel_to_grid(
+                    event.position,
+                    sel_bounds,
+                    cell_width,
+                    cell_height,
+                    display_offset,
+                );
                 let ty = match event.click_count {
                     2 => SelectionType::Semantic,
                     3 => SelectionType::Lines,
                     _ => SelectionType::Simple,
                 };
-                term.lock().selection = Some(Selection::new(ty, grid_point, side));
+                term.selection = Some(Selection::new(ty, grid_point, side));
             }
         });
 
@@ -281,12 +332,39 @@ impl TerminalElement {
                 && event.pressed_button == Some(MouseButton::Left)
                 && hitbox.is_hovered(window)
             {
-                let (grid_point, side) =
-                    Self::pixel_to_grid(event.position, sel_bounds, cell_width, cell_height);
-                if let Some(sel) = &mut term.lock().selection {
+                let mut term = term.lock();
+                let display_offset = term.grid().display_offset();
+                let (grid_point, side) = Self::pixel_to_grid(
+                    event.position,
+                    sel_bounds,
+                    cell_width,
+                    cell_height,
+                    display_offset,
+                );
+                if let Some(sel) = &mut term.selection {
                     sel.update(grid_point, side);
                 }
             }
         });
+
+        // Mouse up handler — copy a finished selection to the clipboard.
+        // The selection stays visible; the copy keybinding still works too.
+        let hitbox = prepaint.hitbox.clone();
+        let term = self.term.clone();
+        window.on_mouse_event::<MouseUpEvent>(move |event, phase, window, cx| {
+            if phase == DispatchPhase::Bubble
+                && event.button == MouseButton::Left
+                && hitbox.is_hovered(window)
+            {
+                let term = term.lock();
+                if term.selection.as_ref().is_some_and(|s| !s.is_empty())
+                    && let Some(text) = term.selection_to_string()
+                    && !text.is_empty()
+                {
+                    tracing::debug!(event = "ui.terminal.selection_copied", len = text.len());
+                    cx.write_to_clipboard(ClipboardItem::new_string(text));
+                }
+            }
+        });
     }
 }
@@ -4,7 +4,7 @@ use gpui::{App, Bounds, HitboxBehavior, Hsla, Pixels, Window, point, px, size};
 
 use super::super::colors;
 use super::super::types::{BatchedTextRun, TerminalContent};
-use super::element::{TerminalElement, detect_urls};
+use super::element::{TerminalElement, detect_file_refs, detect_urls};
 use super::types::{LineText, PrepaintState, PreparedBgRegion, PreparedCursor, PreparedLine};
 use crate::theme;
 
@@ -24,6 +24,8 @@ impl TerminalElement {
                 bg_regions: vec![],
                 selection_rects: vec![],
                 url_regions: vec![],
+                file_regions: vec![],
+                search_rects: vec![],
                 cursor: None,
                 cell_width,
                 cell_height,
@@ -41,6 +43,8 @@ impl TerminalElement {
                 bg_regions: vec![],
                 selection_rects: vec![],
                 url_regions: vec![],
+                file_regions: vec![],
+                search_rects: vec![],
                 cursor: None,
                 cell_width,
                 cell_height,
@@ -73,6 +77,9 @@ impl TerminalElement {
 
         let content = &self.content;
         let scrolled_up = content.display_offset > 0;
+        // Cells, selection and cursor come in grid coordinates, where
+        // scrollback lines are negative. Shift them into viewport rows.
+        let display_offset = content.display_offset as i32;
 
         let terminal_bg = Hsla::from(theme::terminal_background());
         let terminal_fg = Hsla::from(theme::terminal_foreground());
@@ -133,7 +140,7 @@ impl TerminalElement {
         };
 
         for indexed in &content.cells {
-            let line_idx = indexed.point.line.0;
+            let line_idx = indexed.point.line.0 + display_offset;
             let col = indexed.point.column.0;
             let cell = &indexed.cell;
 
@@ -355,47 +362,69 @@ impl TerminalElement {
             line_texts.push((url_text_line, current_line_text, current_col_offsets));
         }
 
-        // URL detection — only scan when Cmd is held (URLs are only displayed on Cmd+hover)
-        let url_regions = if cmd_held {
-            detect_urls(&line_texts, bounds, cell_width, cell_height)
+        // URL and file:line detection — only scan when Cmd is held (links are
+        // only displayed on Cmd+hover)
+        let (url_regions, file_regions) = if cmd_held {
+            (
+                detect_urls(&line_texts, bounds, cell_width, cell_height),
+                detect_file_refs(
+                    &line_texts,
+                    self.working_dir.as_deref(),
+                    bounds,
+                    cell_width,
+                    cell_height,
+                ),
+            )
         } else {
-            Vec::new()
+            (Vec::new(), Vec::new())
         };
 
         // Selection highlight rectangles
+        let grid = GridArea {
+            rows,
+            cols,
+            bounds,
+            cell_width,
+            cell_height,
+        };
         let selection_color = Hsla::from(theme::terminal_selection());
         let mut selection_rects: Vec<PreparedBgRegion> = Vec::new();
         if let Some(sel) = &content.selection {
-            if sel.start.line.0 < 0 || sel.end.line.0 < 0 || (sel.end.line.0 as usize) >= rows {
+            let start_line = sel.start.line.0 + display_offset;
+            let end_line = sel.end.line.0 + display_offset;
+            if start_line < 0 || end_line < 0 || (end_line as usize) >= rows {
                 tracing::debug!(
                     event = "ui.terminal.selection_clamped",
-                    start_line = sel.start.line.0,
-                    end_line = sel.end.line.0,
+                    start_line = start_line,
+                    end_line = end_line,
                     rows = rows,
                 );
             }
-            let start_line = sel.start.line.0.max(0) as usize;
-            let end_line = sel.end.line.0.max(0) as usize;
-            for line_idx in start_line..=end_line.min(rows.saturating_sub(1)) {
-                let start_col = if line_idx == start_line {
-                    sel.start.column.0
-                } else {
-                    0
-                };
-                let end_col = if line_idx == end_line {
-                    sel.end.column.0 + 1
+            grid.push_span_rects(
+                (start_line, sel.start.column.0),
+                (end_line, sel.end.column.0),
+                selection_color,
+                &mut selection_rects,
+            );
+        }
+
+        // Search match rectangles; the focused match is drawn stronger
+        let mut search_rects: Vec<PreparedBgRegion> = Vec::new();
+        if let Some(search) = &self.search {
+            let match_color = Hsla::from(theme::terminal_search_match());
+            let focused_color = Hsla::from(theme::terminal_search_focused());
+            for m in &search.matches {
+                let color = if search.focused.as_ref() == Some(m) {
+                    focused_color
                 } else {
-                    cols
+                    match_color
                 };
-                if end_col > start_col {
-                    let x = (bounds.origin.x + start_col as f32 * cell_width).floor();
-                    let y = bounds.origin.y + line_idx as f32 * cell_height;
-                    let w = (end_col - start_col) as f32 * cell_width;
-                    selection_rects.push(PreparedBgRegion {
-                        bounds: Bounds::new(point(x, y), size(w, cell_height)),
-                        color: selection_color,
-                    });
-                }
+                grid.push_span_rects(
+                    (m.start().line.0 + display_offset, m.start().column.0),
+                    (m.end().line.0 + display_offset, m.end().column.0),
+                    color,
+                    &mut search_rects,
+                );
             }
         }
 
@@ -409,7 +438,7 @@ impl TerminalElement {
             // Hidden cursor — nothing to render
             if cursor_shape != CursorShape::Hidden {
                 let cursor_point = content.cursor.point;
-                let cursor_line = cursor_point.line.0;
+                let cursor_line = cursor_point.line.0 + display_offset;
                 let cursor_col = cursor_point.column.0;
                 if cursor_line >= 0 && (cursor_line as usize) < rows && cursor_col < cols {
                     let cx_pos = (bounds.origin.x + cursor_col as f32 * cell_width).floor();
@@ -465,6 +494,8 @@ impl TerminalElement {
             bg_regions,
             selection_rects,
             url_regions,
+            file_regions,
+            search_rects,
             cursor,
             cell_width,
             cell_height,
@@ -474,3 +505,44 @@ impl TerminalElement {
         }
     }
 }
+
+/// The visible grid, for turning cell spans into pixel rectangles.
+struct GridArea {
+    rows: usize,
+    cols: usize,
+    bounds: Bounds<Pixels>,
+    cell_width: Pixels,
+    cell_height: Pixels,
+}
+
+impl GridArea {
+    /// Push one rectangle per visible row of the inclusive span `start..=end`,
+    /// given as `(viewport_line, column)`. Rows outside the viewport are skipped.
+    fn push_span_rects(
+        &self,
+        (start_line, start_col): (i32, usize),
+        (end_line, end_col): (i32, usize),
+        color: Hsla,
+        out: &mut Vec<PreparedBgRegion>,
+    ) {
+        let first = start_line.max(0);
+        let last = end_line.min(self.rows as i32 - 1);
+        for line_idx in first..=last {
+            let from = if line_idx == start_line { start_col } else { 0 };
+            let to = if line_idx == end_line {
+                end_col + 1
+            } else {
+                self.cols
+            };
+            if to > from {
+                let x = (self.bounds.origin.x + from as f32 * self.cell_width).floor();
+                let y = self.bounds.origin.y + line_idx as f32 * self.cell_height;
+                let w = (to - from) as f32 * self.cell_width;
+                out.push(PreparedBgRegion {
+                    bounds: Bounds::new(point(x, y), size(w, self.cell_height)),
+                    color,
+                });
+            }
+        }
+    }
+}
@@ -1,5 +1,9 @@
-use super::element::{detect_urls, scroll_delta_lines};
+use super::element::{
+    TerminalElement, detect_file_refs, detect_urls, parse_file_refs, resolve_file_ref,
+    scroll_delta_lines,
+};
 use super::types::LineText;
+use alacritty_terminal::index::{Column, Line};
 use gpui::{Bounds, point, px, size};
 
 /// Build col_offsets for a line where each char is either wide (2 cols) or normal (1 col).
@@ -274,3 +278,89 @@ fn url_detection_finds_urls_when_lines_accumulated() {
     assert_eq!(regions.len(), 1);
     assert_eq!(regions[0].url, "https://example.com");
 }
+
+// --- file:line references ---
+
+#[test]
+fn file_ref_with_line_and_column() {
+    let refs = parse_file_refs("error at src/main.rs:42:7: oops");
+    assert_eq!(refs.len(), 1);
+    assert_eq!(refs[0].path, "src/main.rs");
+    assert_eq!(refs[0].line, 42);
+    assert_eq!(refs[0].column, Some(7));
+    assert_eq!(refs[0].range, 9..25);
+}
+
+#[test]
+fn file_ref_relative_and_absolute_paths() {
+    let refs = parse_file_refs("./a/b.ts:3 and /tmp/x.py:10");
+    let paths: Vec<_> = refs.iter().map(|r| (r.path.as_str(), r.line)).collect();
+    assert_eq!(paths, vec![("./a/b.ts", 3), ("/tmp/x.py", 10)]);
+    assert!(refs.iter().all(|r| r.column.is_none()));
+}
+
+#[test]
+fn file_ref_requires_extension_and_nonzero_line() {
+    assert!(parse_file_refs("localhost:8080").is_empty());
+    assert!(parse_file_refs("at 12:30:45").is_empty());
+    assert!(parse_file_refs("main.rs:0").is_empty());
+}
+
+#[test]
+fn file_refs_resolve_against_working_dir() {
+    let dir = tempfile::tempdir().unwrap();
+    std::fs::create_dir_all(dir.path().join("src")).unwrap();
+    std::fs::write(dir.path().join("src/lib.rs"), "").unwrap();
+
+    assert_eq!(
+        resolve_file_ref("src/lib.rs", Some(dir.path())),
+        Some(dir.path().join("src/lib.rs"))
+    );
+    assert_eq!(resolve_file_ref("src/missing.rs", Some(dir.path())), None);
+    assert_eq!(resolve_file_ref("src/lib.rs", None), None);
+    // Directories are not files.
+    assert_eq!(resolve_file_ref("src", Some(dir.path())), None);
+
+    let text = "warning: src/lib.rs:5:1 and src/missing.rs:2".to_string();
+    let col_offsets = build_col_offsets(&text, &[]);
+    let line_texts = vec![(1i32, text, col_offsets)];
+    let regions = detect_file_refs(
+        &line_texts,
+        Some(dir.path()),
+        test_bounds(),
+        cell_w(),
+        cell_h(),
+    );
+    assert_eq!(regions.len(), 1);
+    assert_eq!(regions[0].path, dir.path().join("src/lib.rs"));
+    assert_eq!((regions[0].line, regions[0].column), (5, Some(1)));
+    // "src/lib.rs:5:1" starts at column 9 on line 1 and spans 14 columns.
+    assert_eq!(regions[0].bounds.origin, point(px(90.0), px(20.0)));
+    assert_eq!(regions[0].bounds.size.width, px(140.0));
+}
+
+#[test]
+fn file_refs_inside_urls_are_skipped() {
+    let dir = tempfile::tempdir().unwrap();
+    std::fs::write(dir.path().join("a.rs"), "").unwrap();
+    let text = format!("https://example.com/{}/a.rs:3", dir.path().display());
+    let col_offsets = build_col_offsets(&text, &[]);
+    let line_texts = vec![(0i32, text, col_offsets)];
+    let regions = detect_file_refs(&line_texts, None, test_bounds(), cell_w(), cell_h());
+    assert!(regions.is_empty());
+}
+
+// --- scrollback coordinates ---
+
+#[test]
+fn pixel_to_grid_accounts_for_display_offset() {
+    let position = point(px(25.0), px(45.0));
+    let (at_bottom, _) =
+        TerminalElement::pixel_to_grid(position, test_bounds(), cell_w(), cell_h(), 0);
+    assert_eq!((at_bottom.line, at_bottom.column), (Line(2), Column(2)));
+
+    // Scrolled 5 lines into history: the third visible row is grid line -3.
+    let (scrolled, _) =
+        TerminalElement::pixel_to_grid(position, test_bounds(), cell_w(), cell_h(), 5);
+    assert_eq!((scrolled.line, scrolled.column), (Line(-3), Column(2)));
+}
@@ -1,4 +1,4 @@
-use std::sync::LazyLock;
+use std::path::PathBuf;
 
 use alacritty_terminal::vte::ansi::CursorShape;
 use gpui::{Bounds, Font, FontWeight, Hitbox, Hsla, Pixels, font};
@@ -11,6 +11,8 @@ pub struct PrepaintState {
     pub(super) bg_regions: Vec<PreparedBgRegion>,
     pub(super) selection_rects: Vec<PreparedBgRegion>,
     pub(super) url_regions: Vec<PreparedUrlRegion>,
+    pub(super) file_regions: Vec<PreparedFileRegion>,
+    pub(super) search_rects: Vec<PreparedBgRegion>,
     pub(super) cursor: Option<PreparedCursor>,
     pub(super) cell_width: Pixels,
     pub(super) cell_height: Pixels,
@@ -45,6 +47,15 @@ pub(crate) struct PreparedUrlRegion {
     pub(crate) url: String,
 }
 
+/// A detected `path:line[:column]` reference to a file that exists on disk.
+pub(crate) struct PreparedFileRegion {
+    pub(crate) bounds: Bounds<Pixels>,
+    /// Absolute path, resolved against the terminal's working directory.
+    pub(crate) path: PathBuf,
+    pub(crate) line: u32,
+    pub(crate) column: Option<u32>,
+}
+
 /// Mouse interaction state for URL hover detection.
 /// Both fields must be updated together to prevent stale highlights.
 pub(crate) struct MouseState {
@@ -52,19 +63,20 @@ pub(crate) struct MouseState {
     pub(crate) cmd_held: bool,
 }
 
-/// Cached font objects — terminal uses a single monospace font family.
-/// `LazyLock` avoids reconstructing these on every text run per frame.
-pub(super) static FONT_NORMAL: LazyLock<Font> = LazyLock::new(|| font(theme::FONT_MONO));
-pub(super) static FONT_BOLD: LazyLock<Font> = LazyLock::new(|| Font {
-    weight: FontWeight::BOLD,
-    ..font(theme::FONT_MONO)
-});
-pub(super) static FONT_ITALIC: LazyLock<Font> = LazyLock::new(|| Font {
-    style: gpui::FontStyle::Italic,
-    ..font(theme::FONT_MONO)
-});
-pub(super) static FONT_BOLD_ITALIC: LazyLock<Font> = LazyLock::new(|| Font {
-    weight: FontWeight::BOLD,
-    style: gpui::FontStyle::Italic,
-    ..font(theme::FONT_MONO)
-});
+/// Terminal font for a text run — a single monospace family from the active
+/// theme settings, so font changes apply on the next frame.
+pub(super) fn terminal_font(bold: bool, italic: bool) -> Font {
+    Font {
+        weight: if bold {
+            FontWeight::BOLD
+        } else {
+            FontWeight::NORMAL
+        },
+        style: if italic {
+            gpui::FontStyle::Italic
+        } else {
+            gpui::FontStyle::Normal
+        },
+        ..font(theme::mono_font_family())
+    }
+}
@@ -1,13 +1,22 @@
+use std::path::PathBuf;
+
+use alacritty_terminal::index::Direction;
+use alacritty_terminal::term::TermMode;
 use gpui::{
-    ClipboardItem, Context, FocusHandle, Focusable, IntoElement, KeyDownEvent, Render,
-    ScrollWheelEvent, Task, Window, div, prelude::*, px,
+    ClipboardEntry, ClipboardItem, Context, Entity, ExternalPaths, FocusHandle, Focusable,
+    ImageFormat, IntoElement, KeyDownEvent, Render, ScrollWheelEvent, Task, Window, div,
+    prelude::*, px,
 };
+use gpui_component::input::{Input, InputState};
 
+use super::attachments;
 use super::blink::BlinkManager;
+use super::broadcast::BroadcastHub;
 use super::state::ReconnectState;
 use super::terminal_element::scroll_delta_lines;
 
 use super::input;
+use super::search::TerminalSearch;
 use super::state::Terminal;
 use super::terminal_element::{MouseState, TerminalElement};
 use super::types::TerminalContent;
@@ -38,6 +47,18 @@ pub struct TerminalView {
     keybindings: UiKeybindings,
     /// In-flight reconnection task. Stored to prevent cancellation.
     _reconnect_task: Option<Task<()>>,
+    /// Base for relative `file:line` links in the output.
+    working_dir: Option<PathBuf>,
+    /// Open search bar, if any.
+    search: Option<SearchBar>,
+    /// Mirrors input to other terminals while broadcast mode is on.
+    broadcast: Option<BroadcastHub>,
+}
+
+/// The search bar's query input and match state.
+struct SearchBar {
+    input: Entity<InputState>,
+    state: TerminalSearch,
 }
 
 impl TerminalView {
@@ -93,6 +114,9 @@ impl TerminalView {
             },
             keybindings,
             _reconnect_task: None,
+            working_dir: None,
+            search: None,
+            broadcast: None,
         }
     }
 
@@ -144,9 +168,42 @@ impl TerminalView {
             },
             keybindings,
             _reconnect_task: None,
+            working_dir: None,
+            search: None,
+            broadcast: None,
         }
     }
 
+    /// Resolve relative `file:line` links against `dir` (the kild worktree).
+    pub fn with_working_dir(mut self, dir: Option<PathBuf>) -> Self {
+        self.working_dir = dir;
+        self
+    }
+
+    /// Offer this terminal's input to `hub` for broadcast mode.
+    pub fn with_broadcast(mut self, hub: BroadcastHub) -> Self {
+        self.broadcast = Some(hub);
+        self
+    }
+
+    /// This terminal as a broadcast target.
+    pub(crate) fn broadcast_target(&self) -> super::broadcast::BroadcastTarget {
+        super::broadcast::BroadcastTarget::Pty(self.terminal.pty_writer().clone())
+    }
+
+    /// Write user input to the PTY and mirror it to broadcast targets.
+    fn write_input(&self, bytes: &[u8]) -> Result<(), super::errors::TerminalError> {
+        self.terminal.write_to_pty(bytes)?;
+        if let Some(hub) = &self.broadcast {
+            hub.mirror(
+                self.terminal.pty_writer(),
+                self.terminal.daemon_session_id(),
+                bytes,
+            );
+        }
+        Ok(())
+    }
+
     /// Access the underlying terminal state (e.g. to check `has_exited()`).
     pub fn terminal(&self) -> &Terminal {
         &self.terminal
@@ -228,13 +285,20 @@ impl TerminalView {
         self.terminal
             .set_reconnect_state(ReconnectState::Connecting);
         let (rows, cols) = self.terminal.current_size();
+        let endpoint = self
+            .terminal
+            .daemon_endpoint()
+            .cloned()
+            .unwrap_or(daemon_client::DaemonEndpoint::Default);
         cx.notify();
 
         let task = cx.spawn(async move |this, cx: &mut gpui::AsyncApp| {
             let sid = session_id.clone();
             let conn_result = cx
                 .background_executor()
-                .spawn(async move { daemon_client::connect_for_attach(&sid, rows, cols).await })
+                .spawn(async move {
+                    daemon_client::connect_for_attach(&endpoint, &sid, rows, cols).await
+                })
                 .await;
 
             match conn_result {
@@ -324,9 +388,149 @@ impl TerminalView {
         self._reconnect_task = Some(task);
     }
 
-    fn on_key_down(&mut self, event: &KeyDownEvent, _window: &mut Window, cx: &mut Context<Self>) {
+    /// Reconnect if this is a daemon terminal whose connection has dropped.
+    /// Used when a remote host comes back after being unreachable.
+    pub fn reconnect_if_disconnected(&mut self, cx: &mut Context<Self>) {
+        if self.terminal.has_exited() && self.terminal.daemon_session_id().is_some() {
+            self.try_reconnect(cx);
+        }
+    }
+
+    /// Open the search bar, or refocus its input if already open.
+    fn open_search(&mut self, window: &mut Window, cx: &mut Context<Self>) {
+        let bar = self.search.get_or_insert_with(|| {
+            tracing::info!(event = "ui.terminal.search_opened");
+            SearchBar {
+                input: cx.new(|cx| InputState::new(window, cx).placeholder("Search terminal...")),
+                state: TerminalSearch::default(),
+            }
+        });
+        bar.input.update(cx, |input, cx| input.focus(window, cx));
+        cx.notify();
+    }
+
+    fn close_search(&mut self, window: &mut Window, cx: &mut Context<Self>) {
+        tracing::info!(event = "ui.terminal.search_closed");
+        self.search = None;
+        window.focus(&self.focus_handle);
+        cx.notify();
+    }
+
+    /// Keys typed into the search input also bubble here; handle the bar's
+    /// own keys and keep the rest away from the PTY.
+    fn on_search_key_down(
+        &mut self,
+        event: &KeyDownEvent,
+        window: &mut Window,
+        cx: &mut Context<Self>,
+    ) {
+        match event.keystroke.key.as_str() {
+            "escape" => self.close_search(window, cx),
+            "enter" => {
+                // Enter walks back through older output, Shift+Enter forward.
+                let direction = if event.keystroke.modifiers.shift {
+                    Direction::Right
+                } else {
+                    Direction::Left
+                };
+                if let Some(bar) = &mut self.search {
+                    bar.state.step(&mut self.terminal.term().lock(), direction);
+                }
+                cx.notify();
+            }
+            _ if self.keybindings.matches_any_nav_shortcut(&event.keystroke) => cx.propagate(),
+            _ => {}
+        }
+    }
+
+    /// Re-run the search when the query text changed since the last frame.
+    fn sync_search_query(&mut self, cx: &gpui::App) {
+        let Some(bar) = &mut self.search else {
+            return;
+        };
+        let query = bar.input.read(cx).value();
+        if query != bar.state.query() {
+            bar.state
+                .set_query(&query, &mut self.terminal.term().lock());
+        }
+    }
+
+    /// Type pasted text into the PTY, bracketed when the program asked for it.
+    fn write_paste(&self, text: &str) -> Result<(), super::errors::TerminalError> {
+        let bracketed = self
+            .terminal
+            .last_mode()
+            .contains(TermMode::BRACKETED_PASTE);
+        self.write_input(&attachments::paste_bytes(text, bracketed))
+    }
+
+    /// Paste the clipboard: its text if any, otherwise an image saved as an
+    /// attachment (typed as its path).
+    fn paste_clipboard(
+        &self,
+        clipboard: &ClipboardItem,
+    ) -> Result<(), super::errors::TerminalError> {
+        if let Some(text) = clipboard.text() {
+            return self.write_paste(&text);
+        }
+        let image = clipboard.entries().iter().find_map(|entry| match entry {
+            ClipboardEntry::Image(image) => Some(image),
+            _ => None,
+        });
+        let (Some(image), Some(worktree)) = (image, &self.working_dir) else {
+            return Ok(());
+        };
+        let path = attachments::stage_image(worktree, &image.bytes, image_extension(image.format))?;
+        self.write_paste(&attachments::format_paths(&[path]))
+    }
+
+    /// Files dropped on the terminal: stage each one and type the paths.
+    fn on_external_drop(
+        &mut self,
+        paths: &ExternalPaths,
+        window: &mut Window,
+        cx: &mut Context<Self>,
+    ) {
+        tracing::info!(
+            event = "ui.terminal.files_dropped",
+            count = paths.paths().len()
+        );
+        window.focus(&self.focus_handle);
+
+        let mut staged = Vec::new();
+        for path in paths.paths() {
+            // Without a worktree there is nowhere to copy to; type the path as is
+            let result = match &self.working_dir {
+                Some(worktree) => attachments::stage_file(worktree, path),
+                None => Ok(path.clone()),
+            };
+            match result {
+                Ok(path) => staged.push(path),
+                Err(e) => {
+                    tracing::warn!(event = "ui.terminal.attachment_failed", error = %e);
+                    self.set_error(e.to_string());
+                }
+            }
+        }
+        if !staged.is_empty()
+            && let Err(e) = self.write_paste(&attachments::format_paths(&staged))
+        {
+            tracing::error!(event = "ui.terminal.key_write_failed", error = %e);
+            self.set_error(format!("Failed to send input: {e}"));
+        }
+        cx.notify();
+    }
+
+    fn on_key_down(&mut self, event: &KeyDownEvent, window: &mut Window, cx: &mut Context<Self>) {
         self.blink.reset(cx);
 
+        if let Some(bar) = &self.search
+            && bar.input.focus_handle(cx).is_focused(window)
+        {
+            self.on_search_key_down(event, window, cx);
+            return;
+        }
+
         let key = event.keystroke.key.as_str();
         let cmd = event.keystroke.modifiers.platform;
 
@@ -362,6 +566,12 @@ impl TerminalView {
             cmd = cmd,
         );
 
+        // Search: open the search bar over the grid and scrollback
+        if self.keybindings.terminal.search.matches(&event.keystroke) {
+            self.open_search(window, cx);
+            return;
+        }
+
         // Copy: copy selection or send SIGINT
         if self.keybindings.terminal.copy.matches(&event.keystroke) {
             let text = self.terminal.term().lock().selection_to_string();
@@ -369,7 +579,7 @@ impl TerminalView {
                 cx.write_to_clipboard(ClipboardItem::new_string(text));
                 self.terminal.term().lock().selection = None;
                 cx.notify();
-            } else if let Err(e) = self.terminal.write_to_pty(&[0x03]) {
+            } else if let Err(e) = self.write_input(&[0x03]) {
                 tracing::error!(event = "ui.terminal.key_write_failed", error = %e);
                 self.set_error(format!("Failed to send interrupt: {e}"));
                 cx.notify();
@@ -377,11 +587,11 @@ impl TerminalView {
             return;
         }
 
-        // Paste: paste clipboard to PTY stdin
+        // Paste: paste clipboard text to PTY stdin, or hand a pasted image
+        // to the agent as an attachment path
         if self.keybindings.terminal.paste.matches(&event.keystroke) {
             if let Some(clipboard) = cx.read_from_clipboard()
-                && let Some(text) = clipboard.text()
-                && let Err(e) = self.terminal.write_to_pty(text.as_bytes())
+                && let Err(e) = self.paste_clipboard(&clipboard)
             {
                 tracing::error!(event = "ui.terminal.paste_failed", error = %e);
                 self.set_error(format!("Paste failed: {e}"));
@@ -393,14 +603,11 @@ impl TerminalView {
         // Read app cursor mode from cached mode flags (populated by sync() in render()).
         // Mode is set via escape sequence in the batch loop before cx.notify() triggers
         // render, so the cached mode is current by keystroke time after the first render.
-        let app_cursor = self
-            .terminal
-            .last_mode()
-            .contains(alacritty_terminal::term::TermMode::APP_CURSOR);
+        let app_cursor = self.terminal.last_mode().contains(TermMode::APP_CURSOR);
 
         match input::keystroke_to_escape(&event.keystroke, app_cursor) {
             Some(bytes) => {
-                if let Err(e) = self.terminal.write_to_pty(&bytes) {
+                if let Err(e) = self.write_input(&bytes) {
                     tracing::error!(event = "ui.terminal.key_write_failed", error = %e);
                     self.set_error(format!("Failed to send input: {e}"));
                     cx.notify();
@@ -426,6 +633,11 @@ impl Render for TerminalView {
         // Build the full cell snapshot separately — cannot borrow self.terminal twice
         // in one expression (sync takes &mut, from_term borrows term() immutably).
         self.terminal.sync();
+        self.sync_search_query(cx);
+        let search_highlights = self
+            .search
+            .as_mut()
+            .and_then(|bar| bar.state.highlights(&self.terminal.term().lock()));
         let content = TerminalContent::from_term(&*self.terminal.term().lock());
         let term = self.terminal.term().clone();
         let has_focus = self.focus_handle.is_focused(window);
@@ -446,6 +658,11 @@ impl Render for TerminalView {
             .on_scroll_wheel(cx.listener(Self::on_scroll_wheel))
             .on_mouse_move(cx.listener(Self::on_mouse_move))
             .on_modifiers_changed(cx.listener(Self::on_modifiers_changed))
+            .on_drop(cx.listener(Self::on_external_drop))
+            .drag_over::<ExternalPaths>(|style, _, _, _| {
+                style.border_2().border_color(theme::ice())
+            })
+            .relative()
             .size_full()
             .bg(theme::terminal_background());
 
@@ -500,16 +717,69 @@ impl Render for TerminalView {
         // show the cursor (prepaint renders it as a half-opacity hollow block).
         let cursor_visible = !has_focus || self.blink.visible();
 
-        container.child(TerminalElement::new(
-            content,
-            term,
-            has_focus,
-            resize_handle,
-            cursor_visible,
-            MouseState {
-                position: self.mouse_state.position,
-                cmd_held: self.mouse_state.cmd_held,
-            },
-        ))
+        let search_bar = self.search.as_ref().map(|bar| {
+            let no_match = !bar.state.query().is_empty() && !bar.state.has_match();
+            div()
+                .absolute()
+                .bottom(px(theme::SPACE_2))
+                .right(px(theme::SPACE_4))
+                .w(px(360.))
+                .flex()
+                .items_center()
+                .gap(px(theme::SPACE_2))
+                .px(px(theme::SPACE_2))
+                .py(px(theme::SPACE_1))
+                .bg(theme::elevated())
+                .border_1()
+                .border_color(theme::border())
+                .rounded(px(theme::RADIUS_MD))
+                .child(div().flex_1().child(Input::new(&bar.input)))
+                .child(
+                    div()
+                        .text_size(px(theme::TEXT_XS))
+                        .text_color(if no_match {
+                            theme::ember()
+                        } else {
+                            theme::text_muted()
+                        })
+                        .whitespace_nowrap()
+                        .child(if no_match {
+                            "No matches"
+                        } else {
+                            "Enter ↑ · Shift+Enter ↓ · Esc"
+                        }),
+                )
+        });
+
+        container
+            .child(
+                TerminalElement::new(
+                    content,
+                    term,
+                    has_focus,
+                    resize_handle,
+                    cursor_visible,
+                    MouseState {
+                        position: self.mouse_state.position,
+                        cmd_held: self.mouse_state.cmd_held,
+                    },
+                )
+                .working_dir(self.working_dir.clone())
+                .search_highlights(search_highlights),
+            )
+            .children(search_bar)
+    }
+}
+
+/// File extension for a pasted image.
+fn image_extension(format: ImageFormat) -> &'static str {
+    match format {
+        ImageFormat::Png => "png",
+        ImageFormat::Jpeg => "jpg",
+        ImageFormat::Webp => "webp",
+        ImageFormat::Gif => "gif",
+        ImageFormat::Svg => "svg",
+        ImageFormat::Bmp => "bmp",
+        ImageFormat::Tiff => "tiff",
     }
 }
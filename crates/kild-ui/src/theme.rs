@@ -1,6 +1,9 @@
-//! Theme constants for KILD UI.
+//! Theme values for KILD UI.
 //!
-//! Color palette based on the "Tallinn Night" brand system.
+//! Colors come from the active [`Palette`]: the built-in "Tallinn Night"
+//! (dark) and "Tallinn Day" (light) brand palettes, or a user theme loaded by
+//! `themes`. The accessor functions below read the active palette, so a
+//! theme switch takes effect on the next render.
 //! All colors are GPUI Rgba values for direct use in styling.
 //!
 //! # Usage
@@ -19,10 +22,318 @@
 // defined than to remove and re-add later.
 #![allow(dead_code)]
 
-use gpui::Rgba;
+use std::sync::{LazyLock, RwLock};
+
+use gpui::{Rgba, SharedString};
+
+// =============================================================================
+// ACTIVE THEME
+// =============================================================================
+
+/// Whether a palette is meant for a dark or a light background.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, serde::Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Appearance {
+    #[default]
+    Dark,
+    Light,
+}
+
+/// Every themeable color. Field names double as the keys of theme files.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Palette {
+    pub appearance: Appearance,
+    pub void: Rgba,
+    pub obsidian: Rgba,
+    pub surface: Rgba,
+    pub elevated: Rgba,
+    pub border_subtle: Rgba,
+    pub border: Rgba,
+    pub border_strong: Rgba,
+    pub text_muted: Rgba,
+    pub text_subtle: Rgba,
+    pub text: Rgba,
+    pub text_bright: Rgba,
+    pub text_white: Rgba,
+    pub ice: Rgba,
+    pub ice_dim: Rgba,
+    pub ice_bright: Rgba,
+    pub aurora: Rgba,
+    pub aurora_dim: Rgba,
+    pub copper: Rgba,
+    pub copper_dim: Rgba,
+    pub ember: Rgba,
+    pub ember_dim: Rgba,
+    pub kiri: Rgba,
+    pub kiri_dim: Rgba,
+    pub blade: Rgba,
+    pub blade_bright: Rgba,
+    pub overlay: Rgba,
+    /// ANSI colors 0-7 followed by the bright variants 8-15.
+    pub ansi: [Rgba; 16],
+    pub terminal_foreground: Rgba,
+    pub terminal_background: Rgba,
+    pub terminal_cursor: Rgba,
+}
+
+/// Names of the 16 ANSI colors, in index order, as used in theme files.
+pub const ANSI_NAMES: [&str; 16] = [
+    "black",
+    "red",
+    "green",
+    "yellow",
+    "blue",
+    "magenta",
+    "cyan",
+    "white",
+    "bright_black",
+    "bright_red",
+    "bright_green",
+    "bright_yellow",
+    "bright_blue",
+    "bright_magenta",
+    "bright_cyan",
+    "bright_white",
+];
+
+impl Palette {
+    /// The default dark palette.
+    pub fn tallinn_night() -> Self {
+        Self {
+            appearance: Appearance::Dark,
+            // Base surfaces (darkest to lightest)
+            // - void: deepest background, app edges, behind everything
+            // - obsidian: sidebars, panels
+            // - surface: cards, content areas
+            // - elevated: modals, dropdowns, floating elements
+            void: gpui::rgb(0x08090A),
+            obsidian: gpui::rgb(0x0E1012),
+            surface: gpui::rgb(0x151719),
+            elevated: gpui::rgb(0x1C1F22),
+            // Borders (subtle to strong)
+            border_subtle: gpui::rgb(0x1F2328),
+            border: gpui::rgb(0x2D3139),
+            border_strong: gpui::rgb(0x3D434D),
+            // Text (muted to brightest)
+            text_muted: gpui::rgb(0x5C6370),
+            text_subtle: gpui::rgb(0x848D9C),
+            text: gpui::rgb(0xB8C0CC),
+            text_bright: gpui::rgb(0xE8ECF0),
+            text_white: gpui::rgb(0xF8FAFC),
+            // Primary accent - Ice (frosted steel-blue, for primary actions, focus states)
+            ice: gpui::rgb(0x7CB4C8),
+            ice_dim: gpui::rgb(0x5E9AB2),
+            ice_bright: gpui::rgb(0x98CCE0),
+            // Status - Aurora (olive green, active/running/success)
+            aurora: gpui::rgb(0x6B8F5E),
+            aurora_dim: gpui::rgb(0x556F4A),
+            // Status - Copper (warm ochre, stopped/warning/idle)
+            copper: gpui::rgb(0xC49A5C),
+            copper_dim: gpui::rgb(0xA07D45),
+            // Status - Ember (terracotta, error/crashed/danger)
+            ember: gpui::rgb(0xB87060),
+            ember_dim: gpui::rgb(0x9A584A),
+            // Agent indicator - Kiri (twilight mauve, for AI activity)
+            kiri: gpui::rgb(0xA088B0),
+            kiri_dim: gpui::rgb(0x866B92),
+            // Secondary accent - Blade (for secondary actions)
+            blade: gpui::rgb(0x64748B),
+            blade_bright: gpui::rgb(0x94A3B8),
+            // Void at 80% opacity
+            overlay: gpui::rgba(0x08090ACC),
+            // ANSI 16 - intentionally more vivid than the muted brand palette
+            // for terminal readability on dark backgrounds.
+            ansi: [
+                gpui::rgb(0x151719), // Surface
+                gpui::rgb(0xF87171), // Ember (vivid)
+                gpui::rgb(0x34D399), // Aurora (vivid)
+                gpui::rgb(0xFBBF24), // Copper (vivid)
+                gpui::rgb(0x38BDF8), // Ice (vivid)
+                gpui::rgb(0xA78BFA), // Kiri (vivid)
+                gpui::rgb(0x22D3EE), // Frost
+                gpui::rgb(0xB8C0CC), // Text
+                gpui::rgb(0x5C6370), // Text Muted
+                gpui::rgb(0xFCA5A5),
+                gpui::rgb(0x6EE7B7),
+                gpui::rgb(0xFDE68A),
+                gpui::rgb(0x7DD3FC), // Ice Bright
+                gpui::rgb(0xC4B5FD),
+                gpui::rgb(0x67E8F9),
+                gpui::rgb(0xF8FAFC), // Text White
+            ],
+            terminal_foreground: gpui::rgb(0xB8C0CC), // Text
+            terminal_background: gpui::rgb(0x08090A), // Void
+            terminal_cursor: gpui::rgb(0x38BDF8),     // Ice (vivid)
+        }
+    }
+
+    /// The built-in light palette. Same roles as Tallinn Night with the
+    /// surface and text ramps inverted and accents deepened for contrast.
+    pub fn tallinn_day() -> Self {
+        Self {
+            appearance: Appearance::Light,
+            void: gpui::rgb(0xE6E9ED),
+            obsidian: gpui::rgb(0xEEF0F3),
+            surface: gpui::rgb(0xF6F7F9),
+            elevated: gpui::rgb(0xFFFFFF),
+            border_subtle: gpui::rgb(0xDDE1E6),
+            border: gpui::rgb(0xC9CFD6),
+            border_strong: gpui::rgb(0xA9B1BC),
+            text_muted: gpui::rgb(0x8A929E),
+            text_subtle: gpui::rgb(0x5D6672),
+            text: gpui::rgb(0x333A44),
+            text_bright: gpui::rgb(0x1A1F26),
+            text_white: gpui::rgb(0x0B0D10),
+            ice: gpui::rgb(0x2F7A96),
+            ice_dim: gpui::rgb(0x4A90AA),
+            ice_bright: gpui::rgb(0x22657E),
+            aurora: gpui::rgb(0x4F7A42),
+            aurora_dim: gpui::rgb(0x6B8F5E),
+            copper: gpui::rgb(0x9C6F2C),
+            copper_dim: gpui::rgb(0xB48948),
+            ember: gpui::rgb(0xA4523F),
+            ember_dim: gpui::rgb(0xB87060),
+            kiri: gpui::rgb(0x7D5F91),
+            kiri_dim: gpui::rgb(0x9679A8),
+            blade: gpui::rgb(0x64748B),
+            blade_bright: gpui::rgb(0x475569),
+            overlay: gpui::rgba(0x1A1F2666),
+            ansi: [
+                gpui::rgb(0x1A1F26),
+                gpui::rgb(0xC0362C),
+                gpui::rgb(0x2E7D32),
+                gpui::rgb(0x9A6700),
+                gpui::rgb(0x0B63B6),
+                gpui::rgb(0x7B3FB0),
+                gpui::rgb(0x0E7C86),
+                gpui::rgb(0xC9CFD6),
+                gpui::rgb(0x5D6672),
+                gpui::rgb(0xD9534A),
+                gpui::rgb(0x43A047),
+                gpui::rgb(0xB7860B),
+                gpui::rgb(0x2F84D0),
+                gpui::rgb(0x9659C8),
+                gpui::rgb(0x1798A3),
+                gpui::rgb(0xF6F7F9),
+            ],
+            terminal_foreground: gpui::rgb(0x333A44),
+            terminal_background: gpui::rgb(0xF6F7F9),
+            terminal_cursor: gpui::rgb(0x0B63B6),
+        }
+    }
+
+    /// The built-in palette for an appearance.
+    pub fn builtin(appearance: Appearance) -> Self {
+        match appearance {
+            Appearance::Dark => Self::tallinn_night(),
+            Appearance::Light => Self::tallinn_day(),
+        }
+    }
+
+    /// The color a theme file key refers to, e.g. `"surface"` or
+    /// `"bright_red"`. `None` for unknown keys.
+    pub fn color_mut(&mut self, key: &str) -> Option<&mut Rgba> {
+        if let Some(idx) = ANSI_NAMES.iter().position(|name| *name == key) {
+            return Some(&mut self.ansi[idx]);
+        }
+        Some(match key {
+            "void" => &mut self.void,
+            "obsidian" => &mut self.obsidian,
+            "surface" => &mut self.surface,
+            "elevated" => &mut self.elevated,
+            "border_subtle" => &mut self.border_subtle,
+            "border" => &mut self.border,
+            "border_strong" => &mut self.border_strong,
+            "text_muted" => &mut self.text_muted,
+            "text_subtle" => &mut self.text_subtle,
+            "text" => &mut self.text,
+            "text_bright" => &mut self.text_bright,
+            "text_white" => &mut self.text_white,
+            "ice" => &mut self.ice,
+            "ice_dim" => &mut self.ice_dim,
+            "ice_bright" => &mut self.ice_bright,
+            "aurora" => &mut self.aurora,
+            "aurora_dim" => &mut self.aurora_dim,
+            "copper" => &mut self.copper,
+            "copper_dim" => &mut self.copper_dim,
+            "ember" => &mut self.ember,
+            "ember_dim" => &mut self.ember_dim,
+            "kiri" => &mut self.kiri,
+            "kiri_dim" => &mut self.kiri_dim,
+            "blade" => &mut self.blade,
+            "blade_bright" => &mut self.blade_bright,
+            "overlay" => &mut self.overlay,
+            "foreground" => &mut self.terminal_foreground,
+            "background" => &mut self.terminal_background,
+            "cursor" => &mut self.terminal_cursor,
+            _ => return None,
+        })
+    }
+}
+
+/// Font families and sizes.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Fonts {
+    /// Family for UI text.
+    pub ui_family: SharedString,
+    /// Base size for library controls (inputs, buttons, dialogs).
+    pub ui_size: f32,
+    /// Family for terminals and other monospace text.
+    pub mono_family: SharedString,
+    /// Terminal text size.
+    pub mono_size: f32,
+}
+
+impl Default for Fonts {
+    fn default() -> Self {
+        Self {
+            ui_family: SharedString::new_static(FONT_UI),
+            ui_size: TEXT_BASE,
+            mono_family: SharedString::new_static(FONT_MONO),
+            mono_size: TEXT_BASE,
+        }
+    }
+}
+
+static ACTIVE_PALETTE: LazyLock<RwLock<Palette>> =
+    LazyLock::new(|| RwLock::new(Palette::tallinn_night()));
+
+static ACTIVE_FONTS: LazyLock<RwLock<Fonts>> = LazyLock::new(|| RwLock::new(Fonts::default()));
+
+/// Replace the active palette and fonts. Callers must re-render to pick them up.
+pub fn set_active(palette: Palette, fonts: Fonts) {
+    match ACTIVE_PALETTE.write() {
+        Ok(mut active) => *active = palette,
+        Err(e) => tracing::error!(event = "ui.theme.lock_failed", error = %e),
+    }
+    match ACTIVE_FONTS.write() {
+        Ok(mut active) => *active = fonts,
+        Err(e) => tracing::error!(event = "ui.theme.lock_failed", error = %e),
+    }
+}
+
+/// Read from the active palette. A poisoned lock still holds a valid palette.
+fn palette<R>(f: impl FnOnce(&Palette) -> R) -> R {
+    let guard = ACTIVE_PALETTE
+        .read()
+        .unwrap_or_else(|poisoned| poisoned.into_inner());
+    f(&guard)
+}
+
+fn fonts<R>(f: impl FnOnce(&Fonts) -> R) -> R {
+    let guard = ACTIVE_FONTS
+        .read()
+        .unwrap_or_else(|poisoned| poisoned.into_inner());
+    f(&guard)
+}
+
+/// Appearance of the active palette.
+pub fn appearance() -> Appearance {
+    palette(|p| p.appearance)
+}
 
 // =============================================================================
-// COLOR PALETTE - Tallinn Night (Dark Theme)
+// COLOR PALETTE - read from the active palette
 // =============================================================================
 
 // Base surfaces (darkest to lightest)
@@ -31,95 +342,95 @@ use gpui::Rgba;
 // - surface: cards, content areas
 // - elevated: modals, dropdowns, floating elements
 pub fn void() -> Rgba {
-    gpui::rgb(0x08090A)
+    palette(|p| p.void)
 }
 pub fn obsidian() -> Rgba {
-    gpui::rgb(0x0E1012)
+    palette(|p| p.obsidian)
 }
 pub fn surface() -> Rgba {
-    gpui::rgb(0x151719)
+    palette(|p| p.surface)
 }
 pub fn elevated() -> Rgba {
-    gpui::rgb(0x1C1F22)
+    palette(|p| p.elevated)
 }
 
 // Borders (subtle to strong)
 pub fn border_subtle() -> Rgba {
-    gpui::rgb(0x1F2328)
+    palette(|p| p.border_subtle)
 }
 pub fn border() -> Rgba {
-    gpui::rgb(0x2D3139)
+    palette(|p| p.border)
 }
 pub fn border_strong() -> Rgba {
-    gpui::rgb(0x3D434D)
+    palette(|p| p.border_strong)
 }
 
 // Text (muted to brightest)
 pub fn text_muted() -> Rgba {
-    gpui::rgb(0x5C6370)
+    palette(|p| p.text_muted)
 }
 pub fn text_subtle() -> Rgba {
-    gpui::rgb(0x848D9C)
+    palette(|p| p.text_subtle)
 }
 pub fn text() -> Rgba {
-    gpui::rgb(0xB8C0CC)
+    palette(|p| p.text)
 }
 pub fn text_bright() -> Rgba {
-    gpui::rgb(0xE8ECF0)
+    palette(|p| p.text_bright)
 }
 pub fn text_white() -> Rgba {
-    gpui::rgb(0xF8FAFC)
+    palette(|p| p.text_white)
 }
 
 // Primary accent - Ice (frosted steel-blue, for primary actions, focus states)
 pub fn ice() -> Rgba {
-    gpui::rgb(0x7CB4C8)
+    palette(|p| p.ice)
 }
 pub fn ice_dim() -> Rgba {
-    gpui::rgb(0x5E9AB2)
+    palette(|p| p.ice_dim)
 }
 pub fn ice_bright() -> Rgba {
-    gpui::rgb(0x98CCE0)
+    palette(|p| p.ice_bright)
 }
 
 // Status - Aurora (olive green, active/running/success)
 pub fn aurora() -> Rgba {
-    gpui::rgb(0x6B8F5E)
+    palette(|p| p.aurora)
 }
 pub fn aurora_dim() -> Rgba {
-    gpui::rgb(0x556F4A)
+    palette(|p| p.aurora_dim)
 }
 
 // Status - Copper (warm ochre, stopped/warning/idle)
 pub fn copper() -> Rgba {
-    gpui::rgb(0xC49A5C)
+    palette(|p| p.copper)
 }
 pub fn copper_dim() -> Rgba {
-    gpui::rgb(0xA07D45)
+    palette(|p| p.copper_dim)
 }
 
 // Status - Ember (terracotta, error/crashed/danger)
 pub fn ember() -> Rgba {
-    gpui::rgb(0xB87060)
+    palette(|p| p.ember)
 }
 pub fn ember_dim() -> Rgba {
-    gpui::rgb(0x9A584A)
+    palette(|p| p.ember_dim)
 }
 
 // Agent indicator - Kiri (twilight mauve, for AI activity)
 pub fn kiri() -> Rgba {
-    gpui::rgb(0xA088B0)
+    palette(|p| p.kiri)
 }
 pub fn kiri_dim() -> Rgba {
-    gpui::rgb(0x866B92)
+    palette(|p| p.kiri_dim)
 }
 
 // Secondary accent - Blade (for secondary actions)
 pub fn blade() -> Rgba {
-    gpui::rgb(0x64748B)
+    palette(|p| p.blade)
 }
 pub fn blade_bright() -> Rgba {
-    gpui::rgb(0x94A3B8)
+    palette(|p| p.blade_bright)
 }
 
 // =============================================================================
@@ -171,7 +482,7 @@ pub const TEXT_MD: f32 = 14.0;
 pub const TEXT_LG: f32 = 16.0;
 pub const TEXT_XL: f32 = 18.0;
 
-// Font families (for reference - actual fonts set at app level)
+// Default font families (overridable via `[ui]` in config.toml)
 pub const FONT_UI: &str = "Inter";
 pub const FONT_MONO: &str = "JetBrains Mono";
 
@@ -222,77 +533,53 @@ pub const TEXT_BADGE: f32 = 9.0;
 // OVERLAY
 // =============================================================================
 
-/// Semi-transparent overlay for modals (Void at 80% opacity)
+/// Semi-transparent overlay for modals (Void at 80% opacity in Tallinn Night)
 pub fn overlay() -> Rgba {
-    gpui::rgba(0x08090ACC)
+    palette(|p| p.overlay)
 }
 
 // =============================================================================
-// TERMINAL COLORS — ANSI 16 mapped to Tallinn Night (vivid for readability)
+// TERMINAL COLORS — ANSI 16 from the active palette
 // =============================================================================
 
-// Standard ANSI colors (0-7)
-pub fn ansi_black() -> Rgba {
-    gpui::rgb(0x151719)
-} // Surface
-pub fn ansi_red() -> Rgba {
-    gpui::rgb(0xF87171)
-} // Ember (vivid)
-pub fn ansi_green() -> Rgba {
-    gpui::rgb(0x34D399)
-} // Aurora (vivid)
-pub fn ansi_yellow() -> Rgba {
-    gpui::rgb(0xFBBF24)
-} // Copper (vivid)
-pub fn ansi_blue() -> Rgba {
-    gpui::rgb(0x38BDF8)
-} // Ice (vivid)
-pub fn ansi_magenta() -> Rgba {
-    gpui::rgb(0xA78BFA)
-} // Kiri (vivid)
-pub fn ansi_cyan() -> Rgba {
-    gpui::rgb(0x22D3EE)
-} // Frost
-pub fn ansi_white() -> Rgba {
-    gpui::rgb(0xB8C0CC)
-} // Text
-
-// Bright ANSI colors (8-15)
-pub fn ansi_bright_black() -> Rgba {
-    gpui::rgb(0x5C6370)
-} // Text Muted
-pub fn ansi_bright_red() -> Rgba {
-    gpui::rgb(0xFCA5A5)
-}
-pub fn ansi_bright_green() -> Rgba {
-    gpui::rgb(0x6EE7B7)
-}
-pub fn ansi_bright_yellow() -> Rgba {
-    gpui::rgb(0xFDE68A)
-}
-pub fn ansi_bright_blue() -> Rgba {
-    gpui::rgb(0x7DD3FC)
-} // Ice Bright
-pub fn ansi_bright_magenta() -> Rgba {
-    gpui::rgb(0xC4B5FD)
-}
-pub fn ansi_bright_cyan() -> Rgba {
-    gpui::rgb(0x67E8F9)
-}
-pub fn ansi_bright_white() -> Rgba {
-    gpui::rgb(0xF8FAFC)
-} // Text White
+/// ANSI color `idx` (0-7 standard, 8-15 bright). Out-of-range indexes wrap.
+pub fn ansi(idx: usize) -> Rgba {
+    palette(|p| p.ansi[idx % 16])
+}
 
 // Terminal special colors
 pub fn terminal_foreground() -> Rgba {
-    text()
-} // #B8C0CC
+    palette(|p| p.terminal_foreground)
+}
 pub fn terminal_background() -> Rgba {
-    void()
-} // #08090A
+    palette(|p| p.terminal_background)
+}
 pub fn terminal_cursor() -> Rgba {
-    gpui::rgb(0x38BDF8)
-} // Ice (vivid)
+    palette(|p| p.terminal_cursor)
+}
 pub fn terminal_selection() -> Rgba {
     with_alpha(ice(), 0.30)
 }
+pub fn terminal_search_match() -> Rgba {
+    with_alpha(copper(), 0.30)
+}
+pub fn terminal_search_focused() -> Rgba {
+    with_alpha(copper(), 0.65)
+}
+
+// =============================================================================
+// FONTS - read from the active settings
+// =============================================================================
+
+pub fn ui_font_family() -> SharedString {
+    fonts(|f| f.ui_family.clone())
+}
+pub fn ui_font_size() -> f32 {
+    fonts(|f| f.ui_size)
+}
+pub fn mono_font_family() -> SharedString {
+    fonts(|f| f.mono_family.clone())
+}
+pub fn mono_font_size() -> f32 {
+    fonts(|f| f.mono_size)
+}
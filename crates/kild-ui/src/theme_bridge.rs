@@ -1,130 +1,209 @@
-//! Bridge between KILD's Tallinn Night theme and gpui-component's theme system.
+//! Bridge between KILD's theme and gpui-component's theme system.
 //!
-//! Maps our brand colors to gpui-component theme tokens so that
-//! library components (Button, Dialog, Input) render with Tallinn Night colors.
+//! Installs the active palette and fonts (see `themes`) and maps the palette
+//! to gpui-component theme tokens so that library components (Button,
+//! Dialog, Input) render in the same colors.
 
 use std::rc::Rc;
 
-use gpui::{App, SharedString};
+use gpui::{App, Rgba, SharedString};
 use gpui_component::theme::{Theme, ThemeConfig, ThemeConfigColors, ThemeMode};
 
-/// Apply the Tallinn Night dark theme to gpui-component's global theme.
-pub fn apply_tallinn_night_theme(cx: &mut App) {
+use crate::theme::{self, Appearance, Palette};
+use crate::themes::ThemeSettings;
+
+/// Make `settings` the active theme and apply it to gpui-component's global theme.
+///
+/// Safe to call again at runtime (hot reload); open windows are refreshed.
+pub fn apply_theme(settings: &ThemeSettings, cx: &mut App) {
+    theme::set_active(settings.palette.clone(), settings.fonts.clone());
+
     let config = Rc::new(ThemeConfig {
-        name: SharedString::from("Tallinn Night"),
-        mode: ThemeMode::Dark,
+        name: SharedString::from(settings.name.clone()),
+        mode: match settings.palette.appearance {
+            Appearance::Dark => ThemeMode::Dark,
+            Appearance::Light => ThemeMode::Light,
+        },
         is_default: true,
-        font_family: Some("Inter".into()),
-        mono_font_family: Some("JetBrains Mono".into()),
-        font_size: Some(13.0),
-        mono_font_size: Some(13.0),
+        font_family: Some(settings.fonts.ui_family.clone()),
+        mono_font_family: Some(settings.fonts.mono_family.clone()),
+        font_size: Some(settings.fonts.ui_size),
+        mono_font_size: Some(settings.fonts.mono_size),
         radius: Some(6),
         radius_lg: Some(8),
         shadow: Some(true),
-        colors: tallinn_night_colors(),
+        colors: component_colors(&settings.palette),
         highlight: None,
     });
 
     Theme::global_mut(cx).apply_config(&config);
+    cx.refresh_windows();
+    tracing::info!(event = "ui.theme.applied", theme = %settings.name);
 }
 
-fn tallinn_night_colors() -> ThemeConfigColors {
+fn component_colors(p: &Palette) -> ThemeConfigColors {
     // ThemeConfigColors has private base color fields, so we deserialize from JSON
     // to construct it rather than using struct literal syntax.
     //
-    // Hover colors (success.hover, danger.hover, warning.hover) are lightened
+    // Hover colors (success.hover, danger.hover, warning.hover) are shaded
     // variants for gpui-component button states, not part of KILD's base palette.
     //
     // Compatible with gpui-component 0.5.1. Verify theme keys if upgrading.
-    let json = r##"{
-        "background": "#0E1012",
-        "foreground": "#B8C0CC",
-        "border": "#2D3139",
-        "input.border": "#2D3139",
-        "accent.background": "#1C1F22",
-        "accent.foreground": "#E8ECF0",
-        "primary.background": "#7CB4C8",
-        "primary.hover.background": "#98CCE0",
-        "primary.active.background": "#5E9AB2",
-        "primary.foreground": "#F8FAFC",
-        "secondary.background": "#1C1F22",
-        "secondary.hover.background": "#2D3139",
-        "secondary.active.background": "#151719",
-        "secondary.foreground": "#B8C0CC",
-        "success.background": "#6B8F5E",
-        "success.hover.background": "#7DA870",
-        "success.active.background": "#556F4A",
-        "success.foreground": "#F8FAFC",
-        "danger.background": "#B87060",
-        "danger.hover.background": "#CC8878",
-        "danger.active.background": "#9A584A",
-        "danger.foreground": "#F8FAFC",
-        "warning.background": "#C49A5C",
-        "warning.hover.background": "#D4AD72",
-        "warning.active.background": "#A07D45",
-        "warning.foreground": "#0E1012",
-        "info.background": "#5E9AB2",
-        "info.hover.background": "#7CB4C8",
-        "info.active.background": "#4A8298",
-        "info.foreground": "#F8FAFC",
-        "muted.background": "#1C1F22",
-        "muted.foreground": "#5C6370",
-        "ring": "#7CB4C8",
-        "overlay": "#08090ACC",
-        "popover.background": "#1C1F22",
-        "popover.foreground": "#B8C0CC",
-        "sidebar.background": "#0E1012",
-        "sidebar.foreground": "#B8C0CC",
-        "sidebar.accent.background": "#1C1F22",
-        "sidebar.accent.foreground": "#E8ECF0",
-        "sidebar.primary.background": "#7CB4C8",
-        "sidebar.primary.foreground": "#F8FAFC",
-        "sidebar.border": "#1F2328",
-        "list.background": "#0E1012",
-        "list.hover.background": "#1C1F22",
-        "list.active.background": "#7CB4C833",
-        "list.active.border": "#7CB4C8",
-        "list.even.background": "#151719",
-        "list.head.background": "#0E1012",
-        "tab.background": "#0E1012",
-        "tab.active.background": "#151719",
-        "tab.active.foreground": "#E8ECF0",
-        "tab.foreground": "#5C6370",
-        "tab_bar.background": "#08090A",
-        "table.background": "#0E1012",
-        "table.hover.background": "#1C1F22",
-        "table.active.background": "#7CB4C833",
-        "table.active.border": "#7CB4C8",
-        "table.even.background": "#151719",
-        "table.head.background": "#08090A",
-        "table.head.foreground": "#848D9C",
-        "table.row.border": "#1F2328",
-        "scrollbar.background": "#0E1012",
-        "scrollbar.thumb.background": "#2D3139",
-        "scrollbar.thumb.hover.background": "#3D434D",
-        "selection.background": "#7CB4C833",
-        "caret": "#7CB4C8",
-        "title_bar.background": "#08090A",
-        "title_bar.border": "#1F2328",
-        "window.border": "#1F2328",
-        "link": "#7CB4C8",
-        "link.hover": "#98CCE0",
-        "link.active": "#5E9AB2",
-        "skeleton.background": "#1C1F22",
-        "progress.bar.background": "#7CB4C8",
-        "drag.border": "#7CB4C8",
-        "drop_target.background": "#7CB4C822"
-    }"##;
-    match serde_json::from_str(json) {
+    let shade = |color: Rgba| match p.appearance {
+        Appearance::Dark => mix(color, gpui::rgb(0xFFFFFF), 0.12),
+        Appearance::Light => mix(color, gpui::rgb(0x000000), 0.12),
+    };
+    // Text on accent-colored fills (buttons, badges).
+    let on_accent = match p.appearance {
+        Appearance::Dark => p.text_white,
+        Appearance::Light => p.elevated,
+    };
+    let on_warning = match p.appearance {
+        Appearance::Dark => p.obsidian,
+        Appearance::Light => p.elevated,
+    };
+    let ice_wash = theme::with_alpha(p.ice, 0.2);
+
+    let tokens: [(&str, Rgba); 77] = [
+        ("background", p.obsidian),
+        ("foreground", p.text),
+        ("border", p.border),
+        ("input.border", p.border),
+        ("accent.background", p.elevated),
+        ("accent.foreground", p.text_bright),
+        ("primary.background", p.ice),
+        ("primary.hover.background", p.ice_bright),
+        ("primary.active.background", p.ice_dim),
+        ("primary.foreground", on_accent),
+        ("secondary.background", p.elevated),
+        ("secondary.hover.background", p.border),
+        ("secondary.active.background", p.surface),
+        ("secondary.foreground", p.text),
+        ("success.background", p.aurora),
+        ("success.hover.background", shade(p.aurora)),
+        ("success.active.background", p.aurora_dim),
+        ("success.foreground", on_accent),
+        ("danger.background", p.ember),
+        ("danger.hover.background", shade(p.ember)),
+        ("danger.active.background", p.ember_dim),
+        ("danger.foreground", on_accent),
+        ("warning.background", p.copper),
+        ("warning.hover.background", shade(p.copper)),
+        ("warning.active.background", p.copper_dim),
+        ("warning.foreground", on_warning),
+        ("info.background", p.ice_dim),
+        ("info.hover.background", p.ice),
+        ("info.active.background", mix(p.ice_dim, p.void, 0.2)),
+        ("info.foreground", on_accent),
+        ("muted.background", p.elevated),
+        ("muted.foreground", p.text_muted),
+        ("ring", p.ice),
+        ("overlay", p.overlay),
+        ("popover.background", p.elevated),
+        ("popover.foreground", p.text),
+        ("sidebar.background", p.obsidian),
+        ("sidebar.foreground", p.text),
+        ("sidebar.accent.background", p.elevated),
+        ("sidebar.accent.foreground", p.text_bright),
+        ("sidebar.primary.background", p.ice),
+        ("sidebar.primary.foreground", on_accent),
+        ("sidebar.border", p.border_subtle),
+        ("list.background", p.obsidian),
+        ("list.hover.background", p.elevated),
+        ("list.active.background", ice_wash),
+        ("list.active.border", p.ice),
+        ("list.even.background", p.surface),
+        ("list.head.background", p.obsidian),
+        ("tab.background", p.obsidian),
+        ("tab.active.background", p.surface),
+        ("tab.active.foreground", p.text_bright),
+        ("tab.foreground", p.text_muted),
+        ("tab_bar.background", p.void),
+        ("table.background", p.obsidian),
+        ("table.hover.background", p.elevated),
+        ("table.active.background", ice_wash),
+        ("table.active.border", p.ice),
+        ("table.even.background", p.surface),
+        ("table.head.background", p.void),
+        ("table.head.foreground", p.text_subtle),
+        ("table.row.border", p.border_subtle),
+        ("scrollbar.background", p.obsidian),
+        ("scrollbar.thumb.background", p.border),
+        ("scrollbar.thumb.hover.background", p.border_strong),
+        ("selection.background", ice_wash),
+        ("caret", p.ice),
+        ("title_bar.background", p.void),
+        ("title_bar.border", p.border_subtle),
+        ("window.border", p.border_subtle),
+        ("link", p.ice),
+        ("link.hover", p.ice_bright),
+        ("link.active", p.ice_dim),
+        ("skeleton.background", p.elevated),
+        ("progress.bar.background", p.ice),
+        ("drag.border", p.ice),
+        ("drop_target.background", theme::with_alpha(p.ice, 0.13)),
+    ];
+    let json: serde_json::Map<String, serde_json::Value> = tokens
+        .into_iter()
+        .map(|(key, color)| (key.to_string(), serde_json::Value::String(hex(color))))
+        .collect();
+
+    match serde_json::from_value(serde_json::Value::Object(json)) {
         Ok(colors) => colors,
         Err(e) => {
             tracing::error!(
                 event = "ui.theme.deserialization_failed",
                 error = %e,
-                "Tallinn Night theme JSON is invalid — falling back to library defaults. \
-                 This is a bug: the JSON schema may be incompatible with this gpui-component version."
+                "Theme color tokens are invalid — falling back to library defaults. \
+                 This is a bug: the token keys may be incompatible with this gpui-component version."
             );
             ThemeConfigColors::default()
         }
     }
 }
+
+/// `#RRGGBB`, or `#RRGGBBAA` for translucent colors.
+fn hex(color: Rgba) -> String {
+    let channel = |v: f32| (v.clamp(0.0, 1.0) * 255.0).round() as u8;
+    let rgb = format!(
+        "#{:02X}{:02X}{:02X}",
+        channel(color.r),
+        channel(color.g),
+        channel(color.b)
+    );
+    if color.a < 1.0 {
+        format!("{rgb}{:02X}", channel(color.a))
+    } else {
+        rgb
+    }
+}
+
+/// Blend `color` toward `target` by `amount` (0.0-1.0), keeping `color`'s alpha.
+fn mix(color: Rgba, target: Rgba, amount: f32) -> Rgba {
+    let lerp = |a: f32, b: f32| a + (b - a) * amount;
+    Rgba {
+        r: lerp(color.r, target.r),
+        g: lerp(color.g, target.g),
+        b: lerp(color.b, target.b),
+        a: color.a,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_hex_formats_opaque_and_translucent() {
+        assert_eq!(hex(gpui::rgb(0x7CB4C8)), "#7CB4C8");
+        assert_eq!(hex(gpui::rgba(0x08090ACC)), "#08090ACC");
+    }
+
+    #[test]
+    fn test_component_colors_deserialize_for_builtin_palettes() {
+        // Falls back to defaults (and logs) if any token key were rejected.
+        let dark = component_colors(&Palette::tallinn_night());
+        let light = component_colors(&Palette::tallinn_day());
+        assert_ne!(format!("{dark:?}"), format!("{light:?}"));
+    }
+}
@@ -0,0 +1,349 @@
+//! User themes and font settings.
+//!
+//! `[ui] theme` in config.toml names a built-in palette or a TOML file in
+//! `~/.kild/themes/`. A theme file lists only the colors it changes; the rest
+//! come from the built-in palette matching its `appearance`:
+//!
+//! ```toml
+//! name = "Solarized Light"
+//! appearance = "light"
+//!
+//! [ui]
+//! surface = "#FDF6E3"
+//! ice = "#268BD2"
+//!
+//! [terminal]
+//! background = "#FDF6E3"
+//! red = "#DC322F"
+//! bright_red = "#CB4B16"
+//! ```
+//!
+//! `[ui]` takes the names of the `theme` color functions (`void`, `surface`,
+//! `text_muted`, `aurora`, ...). `[terminal]` takes `foreground`,
+//! `background`, `cursor` and the 16 ANSI names (`black` .. `bright_white`).
+//! [`ThemeStamp`] lets `MainView` poll for edits and reload without a restart.
+
+use std::collections::BTreeMap;
+use std::path::{Path, PathBuf};
+use std::time::SystemTime;
+
+use gpui::{Rgba, SharedString};
+use serde::Deserialize;
+
+use crate::theme::{ANSI_NAMES, Appearance, Fonts, Palette};
+
+/// Built-in dark theme, used when `[ui] theme` is unset.
+pub const DEFAULT_THEME: &str = "tallinn-night";
+
+/// Built-in light theme.
+pub const LIGHT_THEME: &str = "tallinn-day";
+
+/// Keys accepted in a theme file's `[terminal]` section besides the ANSI names.
+const TERMINAL_SPECIAL_KEYS: [&str; 3] = ["foreground", "background", "cursor"];
+
+/// On-disk theme file.
+#[derive(Debug, Deserialize)]
+#[serde(deny_unknown_fields)]
+struct ThemeFile {
+    /// Display name; only logged.
+    #[serde(default)]
+    name: Option<String>,
+    #[serde(default)]
+    appearance: Appearance,
+    #[serde(default)]
+    ui: BTreeMap<String, String>,
+    #[serde(default)]
+    terminal: BTreeMap<String, String>,
+}
+
+/// Palette and fonts resolved from config and theme files.
+#[derive(Clone, Debug, PartialEq)]
+pub struct ThemeSettings {
+    pub name: String,
+    pub palette: Palette,
+    pub fonts: Fonts,
+}
+
+impl Default for ThemeSettings {
+    fn default() -> Self {
+        Self {
+            name: DEFAULT_THEME.to_string(),
+            palette: Palette::tallinn_night(),
+            fonts: Fonts::default(),
+        }
+    }
+}
+
+/// Resolve theme settings from the config hierarchy.
+///
+/// Never fails: a broken config or theme file falls back to the defaults for
+/// the affected part and the problem is returned for display.
+pub fn load_settings() -> (ThemeSettings, Option<String>) {
+    let ui = match kild_config::KildConfig::load_hierarchy() {
+        Ok(config) => config.ui,
+        Err(e) => {
+            tracing::warn!(event = "ui.theme.config_load_failed", error = %e);
+            return (
+                ThemeSettings::default(),
+                Some(format!("Config error, using the default theme: {e}")),
+            );
+        }
+    };
+    let name = ui
+        .theme
+        .clone()
+        .unwrap_or_else(|| DEFAULT_THEME.to_string());
+    let fonts = fonts_from_config(&ui);
+    let themes_dir = themes_dir();
+    match resolve_palette(&name, themes_dir.as_deref()) {
+        Ok(palette) => (
+            ThemeSettings {
+                name,
+                palette,
+                fonts,
+            },
+            None,
+        ),
+        Err(e) => {
+            tracing::warn!(event = "ui.theme.load_failed", theme = %name, error = %e);
+            (
+                ThemeSettings {
+                    name: DEFAULT_THEME.to_string(),
+                    palette: Palette::tallinn_night(),
+                    fonts,
+                },
+                Some(format!("Theme '{name}': {e}")),
+            )
+        }
+    }
+}
+
+/// Fonts from `[ui]`, defaulting unset fields.
+fn fonts_from_config(ui: &kild_config::UiConfig) -> Fonts {
+    let defaults = Fonts::default();
+    Fonts {
+        ui_family: ui
+            .font_family
+            .clone()
+            .map(SharedString::from)
+            .unwrap_or(defaults.ui_family),
+        ui_size: ui.font_size.unwrap_or(defaults.ui_size),
+        mono_family: ui
+            .mono_font_family
+            .clone()
+            .map(SharedString::from)
+            .unwrap_or(defaults.mono_family),
+        mono_size: ui.mono_font_size.unwrap_or(defaults.mono_size),
+    }
+}
+
+fn themes_dir() -> Option<PathBuf> {
+    match kild_paths::KildPaths::resolve() {
+        Ok(paths) => Some(paths.ui_themes_dir()),
+        Err(e) => {
+            tracing::warn!(event = "ui.theme.path_resolve_failed", error = %e);
+            None
+        }
+    }
+}
+
+/// Palette for a theme name: a built-in, or `<themes_dir>/<name>.toml`.
+pub fn resolve_palette(name: &str, themes_dir: Option<&Path>) -> Result<Palette, String> {
+    match name {
+        DEFAULT_THEME => return Ok(Palette::tallinn_night()),
+        LIGHT_THEME => return Ok(Palette::tallinn_day()),
+        _ => {}
+    }
+    if name.is_empty() || name.contains(['/', '\\']) || name.starts_with('.') {
+        return Err("theme names must be plain file names".to_string());
+    }
+    let dir = themes_dir.ok_or("home directory unknown, cannot locate ~/.kild/themes")?;
+    let path = dir.join(format!("{name}.toml"));
+    let content = std::fs::read_to_string(&path)
+        .map_err(|e| format!("cannot read {}: {}", path.display(), e))?;
+    parse_theme(&content).map_err(|e| format!("{}: {}", path.display(), e))
+}
+
+/// Parse a theme file on top of the built-in palette for its appearance.
+pub fn parse_theme(content: &str) -> Result<Palette, String> {
+    let file: ThemeFile = toml::from_str(content).map_err(|e| e.to_string())?;
+    tracing::debug!(event = "ui.theme.parsed", name = ?file.name, appearance = ?file.appearance);
+    let mut palette = Palette::builtin(file.appearance);
+    for (section, colors) in [("ui", &file.ui), ("terminal", &file.terminal)] {
+        for (key, value) in colors {
+            let is_terminal_key =
+                ANSI_NAMES.contains(&key.as_str()) || TERMINAL_SPECIAL_KEYS.contains(&key.as_str());
+            let slot = if is_terminal_key == (section == "terminal") {
+                palette.color_mut(key)
+            } else {
+                None
+            };
+            let slot = slot.ok_or_else(|| format!("unknown color '{key}' in [{section}]"))?;
+            *slot = parse_hex(value).map_err(|e| format!("[{section}] {key}: {e}"))?;
+        }
+    }
+    Ok(palette)
+}
+
+/// Parse `#RRGGBB` or `#RRGGBBAA`.
+pub fn parse_hex(value: &str) -> Result<Rgba, String> {
+    let digits = value.strip_prefix('#').unwrap_or(value);
+    let parsed = digits
+        .chars()
+        .all(|c| c.is_ascii_hexdigit())
+        .then(|| u32::from_str_radix(digits, 16).ok())
+        .flatten();
+    match (digits.len(), parsed) {
+        (6, Some(rgb)) => Ok(gpui::rgb(rgb)),
+        (8, Some(rgba)) => Ok(gpui::rgba(rgba)),
+        _ => Err(format!(
+            "'{value}' is not a color (expected #RRGGBB or #RRGGBBAA)"
+        )),
+    }
+}
+
+/// Modification times of every file theme settings are read from. Two
+/// stamps differ when any of them was created, edited or removed.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct ThemeStamp(Vec<(PathBuf, Option<SystemTime>)>);
+
+impl ThemeStamp {
+    /// Stamp the user and project config files and the themes directory.
+    pub fn current() -> Self {
+        let mut files = Vec::new();
+        if let Ok(paths) = kild_paths::KildPaths::resolve() {
+            files.push(paths.user_config());
+        }
+        if let Ok(cwd) = std::env::current_dir() {
+            files.push(kild_paths::KildPaths::project_config(&cwd));
+        }
+        Self::of(&files, themes_dir().as_deref())
+    }
+
+    /// Stamp `files` plus every `.toml` file in `themes_dir`.
+    pub fn of(files: &[PathBuf], themes_dir: Option<&Path>) -> Self {
+        let mut paths = files.to_vec();
+        if let Some(entries) = themes_dir.and_then(|dir| std::fs::read_dir(dir).ok()) {
+            let mut themes: Vec<PathBuf> = entries
+                .filter_map(|entry| entry.ok().map(|e| e.path()))
+                .filter(|path| path.extension().is_some_and(|ext| ext == "toml"))
+                .collect();
+            themes.sort();
+            paths.extend(themes);
+        }
+        Self(
+            paths
+                .into_iter()
+                .map(|path| {
+                    let modified = std::fs::metadata(&path).and_then(|m| m.modified()).ok();
+                    (path, modified)
+                })
+                .collect(),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_hex() {
+        assert_eq!(parse_hex("#FFFFFF").unwrap(), gpui::rgb(0xFFFFFF));
+        assert_eq!(parse_hex("08090ACC").unwrap(), gpui::rgba(0x08090ACC));
+        assert!(parse_hex("#FFF").is_err());
+        assert!(parse_hex("#GGGGGG").is_err());
+    }
+
+    #[test]
+    fn test_parse_theme_overrides_on_top_of_appearance() {
+        let palette = parse_theme(
+            r##"
+name = "Paper"
+appearance = "light"
+
+[ui]
+surface = "#FDF6E3"
+
+[terminal]
+red = "#DC322F"
+background = "#FDF6E3"
+"##,
+        )
+        .unwrap();
+        let base = Palette::tallinn_day();
+        assert_eq!(palette.appearance, Appearance::Light);
+        assert_eq!(palette.surface, gpui::rgb(0xFDF6E3));
+        assert_eq!(palette.ansi[1], gpui::rgb(0xDC322F));
+        assert_eq!(palette.terminal_background, gpui::rgb(0xFDF6E3));
+        // Unlisted colors come from the built-in light palette.
+        assert_eq!(palette.text, base.text);
+        assert_eq!(palette.ansi[2], base.ansi[2]);
+    }
+
+    #[test]
+    fn test_parse_theme_defaults_to_dark() {
+        assert_eq!(parse_theme("").unwrap(), Palette::tallinn_night());
+    }
+
+    #[test]
+    fn test_parse_theme_rejects_unknown_and_misplaced_keys() {
+        let err = parse_theme("[ui]\nsurfce = \"#000000\"").unwrap_err();
+        assert!(err.contains("unknown color 'surfce' in [ui]"), "{err}");
+        let err = parse_theme("[ui]\nred = \"#000000\"").unwrap_err();
+        assert!(err.contains("'red' in [ui]"), "{err}");
+        let err = parse_theme("[terminal]\nsurface = \"#000000\"").unwrap_err();
+        assert!(err.contains("'surface' in [terminal]"), "{err}");
+        let err = parse_theme("[terminal]\nred = \"red\"").unwrap_err();
+        assert!(err.contains("[terminal] red"), "{err}");
+        assert!(parse_theme("colour = 1").is_err());
+    }
+
+    #[test]
+    fn test_resolve_palette_builtins_and_files() {
+        let dir = tempfile::tempdir().unwrap();
+        std::fs::write(dir.path().join("mine.toml"), "[ui]\nice = \"#123456\"\n").unwrap();
+
+        assert_eq!(
+            resolve_palette(LIGHT_THEME, None).unwrap(),
+            Palette::tallinn_day()
+        );
+        let palette = resolve_palette("mine", Some(dir.path())).unwrap();
+        assert_eq!(palette.ice, gpui::rgb(0x123456));
+        assert!(resolve_palette("missing", Some(dir.path())).is_err());
+        assert!(resolve_palette("../mine", Some(dir.path())).is_err());
+    }
+
+    #[test]
+    fn test_fonts_from_config() {
+        let ui = kild_config::UiConfig {
+            mono_font_family: Some("Iosevka".to_string()),
+            mono_font_size: Some(15.0),
+            ..Default::default()
+        };
+        let fonts = fonts_from_config(&ui);
+        assert_eq!(fonts.mono_family.as_ref(), "Iosevka");
+        assert_eq!(fonts.mono_size, 15.0);
+        assert_eq!(fonts.ui_family, Fonts::default().ui_family);
+    }
+
+    #[test]
+    fn test_theme_stamp_changes_with_theme_files() {
+        let dir = tempfile::tempdir().unwrap();
+        let themes = dir.path().join("themes");
+        std::fs::create_dir(&themes).unwrap();
+        let config = [dir.path().join("config.toml")];
+        let stamp = ThemeStamp::of(&config, Some(&themes));
+        assert_eq!(stamp, ThemeStamp::of(&config, Some(&themes)));
+
+        std::fs::write(themes.join("notes.txt"), "").unwrap();
+        assert_eq!(stamp, ThemeStamp::of(&config, Some(&themes)));
+
+        std::fs::write(themes.join("new.toml"), "").unwrap();
+        let with_theme = ThemeStamp::of(&config, Some(&themes));
+        assert_ne!(stamp, with_theme);
+
+        std::fs::write(&config[0], "[ui]\n").unwrap();
+        assert_ne!(with_theme, ThemeStamp::of(&config, Some(&themes)));
+    }
+}
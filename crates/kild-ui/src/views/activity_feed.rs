@@ -0,0 +1,248 @@
+//! Activity feed drawer.
+//!
+//! Right-hand column listing agent status changes, CI results, crashes and
+//! lifecycle events, newest first. Toggled with the `activity_feed`
+//! keybinding or the status bar. Clicking an entry jumps to the kild's
+//! terminal.
+
+use gpui::{Context, FontWeight, IntoElement, div, prelude::*, px};
+
+use crate::state::AppState;
+use crate::state::activity::{ActivityEntry, ActivityFilter};
+use crate::theme;
+use crate::views::helpers::format_relative_time;
+use crate::views::main_view::MainView;
+
+/// Width of the drawer.
+const FEED_WIDTH: f32 = 300.0;
+
+/// Entries rendered at once; the rest stay in the (persisted) feed.
+const MAX_VISIBLE: usize = 200;
+
+/// Render the activity feed drawer.
+pub fn render_activity_feed(
+    state: &AppState,
+    filter: ActivityFilter,
+    cx: &mut Context<MainView>,
+) -> impl IntoElement {
+    let feed = state.activity();
+    let unread = feed.unread_count();
+    let entries = feed.filtered(filter);
+    let rows: Vec<_> = entries
+        .iter()
+        .take(MAX_VISIBLE)
+        .map(|entry| render_entry(entry, state, cx))
+        .collect();
+
+    div()
+        .id("activity-feed")
+        .w(px(FEED_WIDTH))
+        .flex_shrink_0()
+        .h_full()
+        .flex()
+        .flex_col()
+        .bg(theme::obsidian())
+        .border_l_1()
+        .border_color(theme::border_subtle())
+        // Header: title, unread count, mark all read, close
+        .child(
+            div()
+                .px(px(theme::SPACE_3))
+                .py(px(theme::SPACE_2))
+                .flex()
+                .items_center()
+                .justify_between()
+                .border_b_1()
+                .border_color(theme::border_subtle())
+                .child(
+                    div()
+                        .flex()
+                        .items_center()
+                        .gap(px(theme::SPACE_2))
+                        .child(
+                            div()
+                                .text_size(px(theme::TEXT_SM))
+                                .font_weight(FontWeight::SEMIBOLD)
+                                .text_color(theme::text_bright())
+                                .child("Activity"),
+                        )
+                        .when(unread > 0, |this| {
+                            this.child(
+                                div()
+                                    .text_size(px(theme::TEXT_XS))
+                                    .text_color(theme::copper())
+                                    .child(format!("{} unread", unread)),
+                            )
+                        }),
+                )
+                .child(
+                    div()
+                        .flex()
+                        .items_center()
+                        .gap(px(theme::SPACE_3))
+                        .when(unread > 0, |this| {
+                            this.child(
+                                header_link("activity-mark-all-read", "Mark all read").on_click(
+                                    cx.listener(|view, _, _, cx| {
+                                        view.on_activity_mark_all_read(cx)
+                                    }),
+                                ),
+                            )
+                        })
+                        .child(
+                            header_link("activity-close", "×").on_click(
+                                cx.listener(|view, _, _, cx| view.on_toggle_activity(cx)),
+                            ),
+                        ),
+                ),
+        )
+        // Filters
+        .child(
+            div()
+                .px(px(theme::SPACE_3))
+                .py(px(theme::SPACE_1_HALF))
+                .flex()
+                .gap(px(theme::SPACE_1))
+                .border_b_1()
+                .border_color(theme::border_subtle())
+                .children(ActivityFilter::ALL.into_iter().map(|f| {
+                    let active = f == filter;
+                    div()
+                        .id(("activity-filter", f as usize))
+                        .px(px(theme::SPACE_2))
+                        .py(px(theme::SPACE_HALF))
+                        .rounded(px(theme::RADIUS_SM))
+                        .cursor_pointer()
+                        .text_size(px(theme::TEXT_XS))
+                        .when(active, |this| {
+                            this.bg(theme::surface()).text_color(theme::text_bright())
+                        })
+                        .when(!active, |this| {
+                            this.text_color(theme::text_muted())
+                                .hover(|s| s.text_color(theme::text_subtle()))
+                        })
+                        .on_click(cx.listener(move |view, _, _, cx| {
+                            view.on_activity_filter(f, cx);
+                        }))
+                        .child(f.label())
+                })),
+        )
+        // Entries
+        .child(
+            div()
+                .id("activity-feed-list")
+                .flex_1()
+                .overflow_y_scroll()
+                .flex()
+                .flex_col()
+                .children(rows)
+                .when(entries.is_empty(), |this| {
+                    this.child(
+                        div()
+                            .px(px(theme::SPACE_4))
+                            .py(px(theme::SPACE_6))
+                            .text_size(px(theme::TEXT_SM))
+                            .text_color(theme::text_subtle())
+                            .child("No activity"),
+                    )
+                }),
+        )
+}
+
+fn header_link(id: &'static str, label: &'static str) -> gpui::Stateful<gpui::Div> {
+    div()
+        .id(id)
+        .cursor_pointer()
+        .text_size(px(theme::TEXT_XS))
+        .text_color(theme::text_muted())
+        .hover(|s| s.text_color(theme::text_subtle()))
+        .child(label)
+}
+
+fn render_entry(
+    entry: &ActivityEntry,
+    state: &AppState,
+    cx: &Context<MainView>,
+) -> impl IntoElement {
+    let dot_color = if entry.kind.needs_attention() {
+        theme::ember()
+    } else {
+        theme::aurora()
+    };
+    // Kilds that are gone can't be jumped to
+    let exists = state
+        .displays()
+        .iter()
+        .any(|d| *d.session.id == *entry.session_id);
+    let session_id = entry.session_id.clone();
+
+    div()
+        .id(("activity-entry", entry.id as usize))
+        .px(px(theme::SPACE_3))
+        .py(px(theme::SPACE_1_HALF))
+        .flex()
+        .gap(px(theme::SPACE_2))
+        .border_b_1()
+        .border_color(theme::border_subtle())
+        .when(!entry.read, |this| {
+            this.bg(theme::with_alpha(theme::ice(), theme::GLOW_ALPHA))
+        })
+        .when(exists, |this| {
+            this.cursor_pointer()
+                .hover(|s| s.bg(theme::surface()))
+                .on_click(cx.listener(move |view, _, window, cx| {
+                    view.on_activity_entry_click(&session_id, window, cx);
+                }))
+        })
+        .child(
+            div()
+                .mt(px(5.0))
+                .w(px(5.0))
+                .h(px(5.0))
+                .rounded_full()
+                .bg(dot_color)
+                .flex_shrink_0(),
+        )
+        .child(
+            div()
+                .flex_1()
+                .min_w(px(0.0))
+                .flex()
+                .flex_col()
+                .child(
+                    div()
+                        .flex()
+                        .justify_between()
+                        .gap(px(theme::SPACE_2))
+                        .child(
+                            div()
+                                .text_size(px(theme::TEXT_SM))
+                                .text_color(if entry.read {
+                                    theme::text()
+                                } else {
+                                    theme::text_bright()
+                                })
+                                .overflow_hidden()
+                                .text_ellipsis()
+                                .whitespace_nowrap()
+                                .child(entry.branch.clone()),
+                        )
+                        .child(
+                            div()
+                                .flex_shrink_0()
+                                .text_size(px(theme::TEXT_XXS))
+                                .text_color(theme::text_muted())
+                                .child(format_relative_time(&entry.at)),
+                        ),
+                )
+                .child(
+                    div()
+                        .text_size(px(theme::TEXT_XS))
+                        .text_color(theme::text_subtle())
+                        .child(match &entry.detail {
+                            Some(detail) => format!("{} · {}", entry.kind.label(), detail),
+                            None => entry.kind.label().to_string(),
+                        }),
+                ),
+        )
+}
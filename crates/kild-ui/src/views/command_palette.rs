@@ -0,0 +1,196 @@
+//! Command palette overlay.
+//!
+//! Fuzzy search over kilds and their actions, opened with the
+//! `command_palette` keybinding. Up/Down move the highlight, Enter runs it.
+//! "Inject text" and "Save layout as..." reuse the same input to collect the
+//! text to send or the layout name.
+
+use gpui::{Context, Entity, IntoElement, div, prelude::*, px};
+
+use gpui_component::ActiveTheme;
+use gpui_component::input::{Input, InputState, MoveDown, MoveUp};
+
+use crate::state::DialogState;
+use crate::state::palette::{PaletteItem, PaletteMode};
+use crate::theme;
+use crate::views::MainView;
+
+/// Rows shown at once; the window scrolls to keep the highlight visible.
+const VISIBLE_ROWS: usize = 12;
+
+/// Render the command palette.
+///
+/// `results` are already ranked for the current query and `selected` is an
+/// index into them (see `MainView::palette_matches`).
+pub fn render_command_palette(
+    dialog: &DialogState,
+    input: Option<&Entity<InputState>>,
+    results: &[PaletteItem],
+    selected: usize,
+    cx: &mut Context<MainView>,
+) -> impl IntoElement {
+    let DialogState::Palette { palette, error } = dialog else {
+        unreachable!(
+            "render_command_palette called with non-Palette dialog state — this is a bug in MainView render logic"
+        );
+    };
+    let error = error.clone();
+    // Title and key hint while the input collects text instead of a query.
+    let prompt = match &palette.mode {
+        PaletteMode::Search => None,
+        PaletteMode::Inject { branch } => Some((
+            format!("Inject text into {branch}"),
+            "Enter to send · Esc to cancel",
+        )),
+        PaletteMode::SaveLayout => Some((
+            "Save the current layout as".to_string(),
+            "Enter to save · Esc to cancel",
+        )),
+    };
+    let hint = prompt.as_ref().map(|(_, hint)| *hint);
+
+    let first = (selected + 1).saturating_sub(VISIBLE_ROWS);
+    let rows: Vec<_> = results
+        .iter()
+        .enumerate()
+        .skip(first)
+        .take(VISIBLE_ROWS)
+        .map(|(ix, item)| render_row(ix, item, ix == selected, cx))
+        .collect();
+
+    div()
+        .id("command-palette")
+        .absolute()
+        .inset_0()
+        .bg(cx.theme().overlay)
+        .flex()
+        .justify_center()
+        .items_start()
+        .pt(px(96.))
+        .child(
+            div()
+                .id("command-palette-box")
+                .w(px(560.))
+                .bg(cx.theme().background)
+                .rounded(cx.theme().radius_lg)
+                .border_1()
+                .border_color(cx.theme().border)
+                .flex()
+                .flex_col()
+                .overflow_hidden()
+                // Single-line inputs swallow Up/Down; intercept them for the list.
+                .capture_action(cx.listener(|view, _: &MoveUp, _, cx| {
+                    view.on_palette_move(-1, cx);
+                    cx.stop_propagation();
+                }))
+                .capture_action(cx.listener(|view, _: &MoveDown, _, cx| {
+                    view.on_palette_move(1, cx);
+                    cx.stop_propagation();
+                }))
+                .when_some(prompt.map(|(title, _)| title), |this, title| {
+                    this.child(
+                        div()
+                            .px(px(theme::SPACE_4))
+                            .pt(px(theme::SPACE_3))
+                            .text_size(px(theme::TEXT_SM))
+                            .text_color(theme::text_subtle())
+                            .child(title),
+                    )
+                })
+                .child(
+                    div()
+                        .px(px(theme::SPACE_3))
+                        .py(px(theme::SPACE_3))
+                        .border_b_1()
+                        .border_color(theme::border_subtle())
+                        .when_some(input, |this, input| this.child(Input::new(input))),
+                )
+                .when_some(error, |this, error| {
+                    this.child(
+                        div()
+                            .px(px(theme::SPACE_4))
+                            .py(px(theme::SPACE_2))
+                            .text_size(px(theme::TEXT_SM))
+                            .text_color(theme::ember())
+                            .child(error),
+                    )
+                })
+                .when(hint.is_none(), |this| {
+                    this.child(
+                        div()
+                            .flex()
+                            .flex_col()
+                            .py(px(theme::SPACE_1))
+                            .children(rows)
+                            .when(results.is_empty(), |this| {
+                                this.child(
+                                    div()
+                                        .px(px(theme::SPACE_4))
+                                        .py(px(theme::SPACE_2))
+                                        .text_size(px(theme::TEXT_SM))
+                                        .text_color(theme::text_muted())
+                                        .child("No matching kilds or actions"),
+                                )
+                            }),
+                    )
+                })
+                .when_some(hint, |this, hint| {
+                    this.child(
+                        div()
+                            .px(px(theme::SPACE_4))
+                            .py(px(theme::SPACE_2))
+                            .text_size(px(theme::TEXT_XS))
+                            .text_color(theme::text_muted())
+                            .child(hint),
+                    )
+                }),
+        )
+}
+
+fn render_row(
+    ix: usize,
+    item: &PaletteItem,
+    selected: bool,
+    cx: &Context<MainView>,
+) -> impl IntoElement {
+    let action = item.action.clone();
+    div()
+        .id(("palette-item", ix))
+        .mx(px(theme::SPACE_1))
+        .px(px(theme::SPACE_3))
+        .py(px(theme::SPACE_1_HALF))
+        .rounded(px(theme::RADIUS_SM))
+        .flex()
+        .items_center()
+        .justify_between()
+        .gap(px(theme::SPACE_3))
+        .cursor_pointer()
+        .when(selected, |this| {
+            this.bg(theme::with_alpha(theme::ice(), theme::GLOW_ALPHA))
+        })
+        .when(!selected, |this| this.hover(|s| s.bg(theme::surface())))
+        .on_click(cx.listener(move |view, _, window, cx| {
+            view.run_palette_action(action.clone(), window, cx);
+        }))
+        .child(
+            div()
+                .text_size(px(theme::TEXT_BASE))
+                .text_color(if selected {
+                    theme::text_white()
+                } else {
+                    theme::text_bright()
+                })
+                .child(item.label.clone()),
+        )
+        .when_some(item.detail.clone(), |this, detail| {
+            this.child(
+                div()
+                    .text_size(px(theme::TEXT_XS))
+                    .text_color(theme::text_subtle())
+                    .overflow_hidden()
+                    .text_ellipsis()
+                    .whitespace_nowrap()
+                    .child(detail),
+            )
+        })
+}
@@ -1,15 +1,19 @@
 //! Create kild dialog component.
 //!
-//! Modal dialog for creating new kilds with branch name input
-//! and agent selection.
+//! Modal dialog for creating new kilds. Offers the same options as
+//! `kild create`: branch name (with completion from the project's
+//! branches), agent, runtime, base branch, issue, agent flags, yolo, main
+//! worktree, no-fetch, an initial prompt and a note.
 
-use gpui::{Context, IntoElement, div, prelude::*, px};
+use gpui::{AnyElement, Context, Entity, IntoElement, SharedString, div, prelude::*, px};
 
 use gpui_component::ActiveTheme;
 use gpui_component::Disableable;
 use gpui_component::button::{Button, ButtonVariants};
+use gpui_component::checkbox::Checkbox;
 use gpui_component::input::{Input, InputState};
 
+use crate::state::dialog::CreateFormState;
 use crate::state::{CreateDialogField, DialogState};
 use crate::theme;
 use crate::views::MainView;
@@ -19,15 +23,26 @@ pub fn agent_options() -> Vec<&'static str> {
     kild_core::agents::valid_agent_names()
 }
 
+/// Text inputs of the create dialog. The `InputState` entities live in
+/// MainView and are `None` until the dialog opens.
+pub struct CreateDialogInputs<'a> {
+    pub branch: Option<&'a Entity<InputState>>,
+    pub base: Option<&'a Entity<InputState>>,
+    pub issue: Option<&'a Entity<InputState>>,
+    pub flags: Option<&'a Entity<InputState>>,
+    pub prompt: Option<&'a Entity<InputState>>,
+    pub note: Option<&'a Entity<InputState>>,
+}
+
 /// Render the create kild dialog.
 ///
 /// Text input is managed by gpui-component's Input widget via InputState entities
-/// passed from MainView. The dialog reads agent selection from DialogState form state.
+/// passed from MainView. The dialog reads agent selection and toggles from
+/// DialogState form state.
 pub fn render_create_dialog(
     dialog: &DialogState,
     loading: bool,
-    branch_input: Option<&gpui::Entity<InputState>>,
-    note_input: Option<&gpui::Entity<InputState>>,
+    inputs: CreateDialogInputs,
     cx: &mut Context<MainView>,
 ) -> impl IntoElement {
     let DialogState::Create { form, error } = dialog else {
@@ -41,6 +56,15 @@ pub fn render_create_dialog(
     let current_agent = form.selected_agent();
     let focused_field = form.focused_field.clone();
     let selected_agent_index = form.selected_agent_index();
+    let runtime_label = form.runtime.label();
+
+    let branch_suggestions = inputs
+        .branch
+        .map(|input| render_suggestions("branch", input, form, cx));
+    let base_suggestions = inputs
+        .base
+        .map(|input| render_suggestions("base", input, form, cx));
+    let toggles = render_toggles(form, cx);
 
     // Overlay: covers entire screen with semi-transparent background
     div()
@@ -55,7 +79,7 @@ pub fn render_create_dialog(
         .child(
             div()
                 .id("create-dialog-box")
-                .w(px(400.))
+                .w(px(520.))
                 .bg(cx.theme().background)
                 .rounded(cx.theme().radius_lg)
                 .border_1()
@@ -76,121 +100,131 @@ pub fn render_create_dialog(
                                 .child("Create New KILD"),
                         ),
                 )
-                // Body
+                // Body (scrolls when the window is too short for every option)
                 .child(
-                    div().px(px(theme::SPACE_4)).py(px(theme::SPACE_4)).child(
-                        div()
-                            .flex()
-                            .flex_col()
-                            .gap(px(theme::SPACE_4))
-                            // Branch name field
-                            .child(
-                                div()
-                                    .flex()
-                                    .flex_col()
-                                    .gap(px(theme::SPACE_1))
-                                    .child(
-                                        div()
-                                            .text_size(px(theme::TEXT_SM))
-                                            .text_color(theme::text_subtle())
-                                            .child("Branch Name"),
-                                    )
-                                    .when_some(branch_input, |this, input| {
-                                        this.child(Input::new(input))
-                                    }),
-                            )
-                            // Agent selection field (custom - click to cycle)
-                            .child({
-                                let is_focused = focused_field == CreateDialogField::Agent;
-                                div()
-                                    .flex()
-                                    .flex_col()
-                                    .gap(px(theme::SPACE_1))
-                                    .child(
-                                        div()
-                                            .text_size(px(theme::TEXT_SM))
-                                            .text_color(theme::text_subtle())
-                                            .child("Agent"),
-                                    )
-                                    .child(
+                    div()
+                        .id("create-dialog-body")
+                        .max_h(px(560.))
+                        .overflow_y_scroll()
+                        .px(px(theme::SPACE_4))
+                        .py(px(theme::SPACE_4))
+                        .child(
+                            div()
+                                .flex()
+                                .flex_col()
+                                .gap(px(theme::SPACE_4))
+                                // Branch name field with completions
+                                .child(
+                                    render_field("Branch Name")
+                                        .when_some(inputs.branch, |this, input| {
+                                            this.child(Input::new(input))
+                                        })
+                                        .children(branch_suggestions),
+                                )
+                                // Agent and runtime selectors (custom - click to cycle)
+                                .child(
+                                    div()
+                                        .flex()
+                                        .gap(px(theme::SPACE_3))
+                                        .child(
+                                            render_field("Agent").flex_1().child(
+                                                render_selector(
+                                                    "agent-selector",
+                                                    current_agent,
+                                                    Some(format!(
+                                                        "({}/{})",
+                                                        selected_agent_index + 1,
+                                                        agents.len()
+                                                    )),
+                                                    focused_field == CreateDialogField::Agent,
+                                                )
+                                                .on_mouse_up(
+                                                    gpui::MouseButton::Left,
+                                                    cx.listener(|view, _, _, cx| {
+                                                        view.on_agent_cycle(cx);
+                                                    }),
+                                                ),
+                                            ),
+                                        )
+                                        .child(
+                                            render_field("Runtime").flex_1().child(
+                                                render_selector(
+                                                    "runtime-selector",
+                                                    runtime_label.to_string(),
+                                                    None,
+                                                    false,
+                                                )
+                                                .on_mouse_up(
+                                                    gpui::MouseButton::Left,
+                                                    cx.listener(|view, _, _, cx| {
+                                                        view.on_runtime_cycle(cx);
+                                                    }),
+                                                ),
+                                            ),
+                                        ),
+                                )
+                                // Base branch and issue
+                                .child(
+                                    div()
+                                        .flex()
+                                        .gap(px(theme::SPACE_3))
+                                        .child(
+                                            render_field("Base Branch (optional)")
+                                                .flex_1()
+                                                .when_some(inputs.base, |this, input| {
+                                                    this.child(Input::new(input))
+                                                })
+                                                .children(base_suggestions),
+                                        )
+                                        .child(
+                                            render_field("Issue (optional)")
+                                                .w(px(140.))
+                                                .when_some(inputs.issue, |this, input| {
+                                                    this.child(Input::new(input))
+                                                }),
+                                        ),
+                                )
+                                // Agent flags
+                                .child(
+                                    render_field("Agent Flags (optional)")
+                                        .when_some(inputs.flags, |this, input| {
+                                            this.child(Input::new(input))
+                                        }),
+                                )
+                                .child(toggles)
+                                // Initial prompt (multi-line)
+                                .child(
+                                    render_field("Initial Prompt (optional)")
+                                        .when_some(inputs.prompt, |this, input| {
+                                            this.child(Input::new(input))
+                                        }),
+                                )
+                                // Note field (optional)
+                                .child(
+                                    render_field("Note (optional)")
+                                        .when_some(inputs.note, |this, input| {
+                                            this.child(Input::new(input))
+                                        }),
+                                )
+                                // Error message (if any)
+                                .when_some(create_error, |this, error| {
+                                    this.child(
                                         div()
-                                            .id("agent-selector")
                                             .px(px(theme::SPACE_3))
                                             .py(px(theme::SPACE_2))
-                                            .bg(theme::surface())
-                                            .hover(|style| style.bg(theme::elevated()))
+                                            .bg(theme::with_alpha(theme::ember(), 0.2))
                                             .rounded(px(theme::RADIUS_MD))
                                             .border_1()
-                                            .border_color(if is_focused {
-                                                theme::ice()
-                                            } else {
-                                                theme::border()
-                                            })
-                                            .cursor_pointer()
-                                            .on_mouse_up(
-                                                gpui::MouseButton::Left,
-                                                cx.listener(|view, _, _, cx| {
-                                                    view.on_agent_cycle(cx);
-                                                }),
-                                            )
+                                            .border_color(theme::ember())
                                             .child(
                                                 div()
-                                                    .flex()
-                                                    .justify_between()
-                                                    .items_center()
-                                                    .child(
-                                                        div()
-                                                            .text_color(theme::text_bright())
-                                                            .child(current_agent),
-                                                    )
-                                                    .child(
-                                                        div()
-                                                            .text_color(theme::text_subtle())
-                                                            .text_size(px(theme::TEXT_SM))
-                                                            .child(format!(
-                                                                "({}/{})",
-                                                                selected_agent_index + 1,
-                                                                agents.len()
-                                                            )),
-                                                    ),
+                                                    .text_size(px(theme::TEXT_SM))
+                                                    .text_color(theme::ember())
+                                                    .child(error),
                                             ),
                                     )
-                            })
-                            // Note field (optional)
-                            .child(
-                                div()
-                                    .flex()
-                                    .flex_col()
-                                    .gap(px(theme::SPACE_1))
-                                    .child(
-                                        div()
-                                            .text_size(px(theme::TEXT_SM))
-                                            .text_color(theme::text_subtle())
-                                            .child("Note (optional)"),
-                                    )
-                                    .when_some(note_input, |this, input| {
-                                        this.child(Input::new(input))
-                                    }),
-                            )
-                            // Error message (if any)
-                            .when_some(create_error, |this, error| {
-                                this.child(
-                                    div()
-                                        .px(px(theme::SPACE_3))
-                                        .py(px(theme::SPACE_2))
-                                        .bg(theme::with_alpha(theme::ember(), 0.2))
-                                        .rounded(px(theme::RADIUS_MD))
-                                        .border_1()
-                                        .border_color(theme::ember())
-                                        .child(
-                                            div()
-                                                .text_size(px(theme::TEXT_SM))
-                                                .text_color(theme::ember())
-                                                .child(error),
-                                        ),
-                                )
-                            }),
-                    ),
+                                }),
+                        ),
                 )
                 // Footer: buttons
                 .child(
@@ -224,3 +258,123 @@ pub fn render_create_dialog(
                 ),
         )
 }
+
+/// Labelled column for one form field.
+fn render_field(label: &'static str) -> gpui::Div {
+    div().flex().flex_col().gap(px(theme::SPACE_1)).child(
+        div()
+            .text_size(px(theme::TEXT_SM))
+            .text_color(theme::text_subtle())
+            .child(label),
+    )
+}
+
+/// Click-to-cycle selector showing `value` and an optional `detail`.
+fn render_selector(
+    id: &'static str,
+    value: String,
+    detail: Option<String>,
+    is_focused: bool,
+) -> gpui::Stateful<gpui::Div> {
+    div()
+        .id(id)
+        .px(px(theme::SPACE_3))
+        .py(px(theme::SPACE_2))
+        .bg(theme::surface())
+        .hover(|style| style.bg(theme::elevated()))
+        .rounded(px(theme::RADIUS_MD))
+        .border_1()
+        .border_color(if is_focused {
+            theme::ice()
+        } else {
+            theme::border()
+        })
+        .cursor_pointer()
+        .child(
+            div()
+                .flex()
+                .justify_between()
+                .items_center()
+                .child(div().text_color(theme::text_bright()).child(value))
+                .when_some(detail, |this, detail| {
+                    this.child(
+                        div()
+                            .text_color(theme::text_subtle())
+                            .text_size(px(theme::TEXT_SM))
+                            .child(detail),
+                    )
+                }),
+        )
+}
+
+/// Branch completions for the text in `input`. Clicking one fills it in.
+fn render_suggestions(
+    id_prefix: &'static str,
+    input: &Entity<InputState>,
+    form: &CreateFormState,
+    cx: &mut Context<MainView>,
+) -> AnyElement {
+    let query = input.read(cx).value();
+    let suggestions = form.branch_suggestions(&query);
+    div()
+        .flex()
+        .flex_wrap()
+        .gap(px(theme::SPACE_1))
+        .children(suggestions.into_iter().map(|branch| {
+            let input = input.clone();
+            let name = branch.to_string();
+            div()
+                .id(SharedString::from(format!(
+                    "{}-suggestion-{}",
+                    id_prefix, branch
+                )))
+                .px(px(theme::SPACE_2))
+                .py(px(theme::SPACE_1))
+                .rounded(px(theme::RADIUS_SM))
+                .bg(theme::surface())
+                .hover(|style| style.bg(theme::elevated()))
+                .cursor_pointer()
+                .text_size(px(theme::TEXT_SM))
+                .text_color(theme::text())
+                .child(branch.to_string())
+                .on_click(cx.listener(move |view, _, window, cx| {
+                    view.on_branch_suggestion_click(&input, &name, window, cx);
+                }))
+        }))
+        .into_any_element()
+}
+
+/// The `--yolo`, `--main` and `--no-fetch` checkboxes.
+fn render_toggles(form: &CreateFormState, cx: &mut Context<MainView>) -> AnyElement {
+    div()
+        .flex()
+        .gap(px(theme::SPACE_4))
+        .child(
+            Checkbox::new("create-yolo")
+                .label("Yolo")
+                .checked(form.yolo)
+                .on_click(cx.listener(|view, checked: &bool, _, cx| {
+                    let checked = *checked;
+                    view.on_create_toggle(cx, |form| form.yolo = checked);
+                })),
+        )
+        .child(
+            Checkbox::new("create-main")
+                .label("Main worktree")
+                .checked(form.use_main)
+                .on_click(cx.listener(|view, checked: &bool, _, cx| {
+                    let checked = *checked;
+                    view.on_create_toggle(cx, |form| form.use_main = checked);
+                })),
+        )
+        .child(
+            Checkbox::new("create-no-fetch")
+                .label("Skip fetch")
+                .checked(form.no_fetch)
+                .on_click(cx.listener(|view, checked: &bool, _, cx| {
+                    let checked = *checked;
+                    view.on_create_toggle(cx, |form| form.no_fetch = checked);
+                })),
+        )
+        .into_any_element()
+}
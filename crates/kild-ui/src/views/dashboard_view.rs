@@ -1,6 +1,7 @@
 //! Dashboard view component for fleet overview.
 //!
-//! Renders fleet summary bar and responsive grid of kild cards.
+//! Renders fleet summary bar, pending agent approvals, weekly activity trend,
+//! and responsive grid of kild cards.
 
 use gpui::{
     AnyElement, Context, IntoElement, ParentElement, SharedString, Styled, div, prelude::*, px,
@@ -12,11 +13,16 @@ use crate::theme;
 use crate::views::helpers::format_relative_time;
 use crate::views::main_view::MainView;
 use crate::views::terminal_tabs::TerminalTabs;
+use gpui_component::button::{Button, ButtonVariants};
 use kild_core::ProcessStatus;
+use kild_protocol::{ApprovalDecision, PendingApproval};
 
 /// Max note length before truncating on cards (prevents card width overflow).
 const MAX_NOTE_LENGTH: usize = 50;
 
+/// Height of a full bar in the fleet activity trend panel.
+const TREND_BAR_HEIGHT: f32 = 48.0;
+
 /// Render the dashboard view with fleet summary and kild card grid.
 pub fn render_dashboard(
     state: &AppState,
@@ -106,12 +112,27 @@ pub fn render_dashboard(
                         .child(format!("{} terminals", total_terminals)),
                 ),
         )
+        // Pending agent approvals (agents are blocked until answered)
+        .when(!state.pending_approvals().is_empty(), |d| {
+            d.child(render_approvals(state.pending_approvals(), cx))
+        })
+        // Fleet activity trend (only when health history has been recorded)
+        .when(!state.fleet_history().is_empty(), |d| {
+            d.child(render_fleet_trend(state.fleet_history()))
+        })
         // Card grid
         .child({
             let mut cards = Vec::new();
             for (ix, display) in displays.iter().enumerate() {
                 let teammate_count = team_store.teammates_for_session(&display.session.id).len();
-                cards.push(render_card(display, ix, terminal_tabs, teammate_count, cx));
+                cards.push(render_card(
+                    display,
+                    ix,
+                    terminal_tabs,
+                    teammate_count,
+                    state.kild_usage(&display.session.id),
+                    cx,
+                ));
             }
             div()
                 .flex()
@@ -122,12 +143,160 @@ pub fn render_dashboard(
         .into_any_element()
 }
 
+/// Render the pending approvals panel with Allow/Deny buttons per request.
+fn render_approvals(approvals: &[PendingApproval], cx: &mut Context<MainView>) -> AnyElement {
+    let rows = approvals.iter().map(|approval| {
+        let allow_id = approval.id.clone();
+        let deny_id = approval.id.clone();
+        div()
+            .flex()
+            .items_center()
+            .gap(px(theme::SPACE_2))
+            .child(
+                div()
+                    .text_color(theme::text_white())
+                    .font_weight(gpui::FontWeight::SEMIBOLD)
+                    .child(approval.branch.clone()),
+            )
+            .child(
+                div()
+                    .text_color(theme::kiri())
+                    .text_size(px(theme::TEXT_XS))
+                    .child(approval.tool.clone()),
+            )
+            .child(
+                div()
+                    .flex_1()
+                    .overflow_hidden()
+                    .text_ellipsis()
+                    .whitespace_nowrap()
+                    .font_family(theme::mono_font_family())
+                    .text_color(theme::text())
+                    .child(approval.summary.clone()),
+            )
+            .child(
+                Button::new(SharedString::from(format!(
+                    "approval-allow-{}",
+                    approval.id
+                )))
+                .label("Allow")
+                .success()
+                .on_click(cx.listener(move |view, _, _, cx| {
+                    view.on_approval_resolve(&allow_id, ApprovalDecision::Allow, cx);
+                })),
+            )
+            .child(
+                Button::new(SharedString::from(format!("approval-deny-{}", approval.id)))
+                    .label("Deny")
+                    .danger()
+                    .on_click(cx.listener(move |view, _, _, cx| {
+                        view.on_approval_resolve(&deny_id, ApprovalDecision::Deny, cx);
+                    })),
+            )
+    });
+
+    div()
+        .flex()
+        .flex_col()
+        .gap(px(theme::SPACE_2))
+        .px(px(theme::SPACE_3))
+        .py(px(theme::SPACE_2))
+        .bg(theme::surface())
+        .border_1()
+        .border_color(theme::copper())
+        .rounded(px(theme::RADIUS_MD))
+        .mb(px(theme::SPACE_4))
+        .text_size(px(theme::TEXT_SM))
+        .child(
+            div()
+                .text_color(theme::copper())
+                .text_size(px(theme::TEXT_XS))
+                .child(format!("{} awaiting approval", approvals.len())),
+        )
+        .children(rows)
+        .into_any_element()
+}
+
+/// Render the per-day working/idle/stuck/crashed stacked bars from health history.
+fn render_fleet_trend(buckets: &[kild_core::health::history::HistoryBucket]) -> AnyElement {
+    let max_total = buckets
+        .iter()
+        .map(|b| b.avg_total)
+        .fold(0.0_f32, f32::max)
+        .max(1.0);
+    let working_pct = kild_core::health::history::overall_working_ratio(buckets) * 100.0;
+
+    let segment = |value: f32, color: gpui::Rgba| {
+        div()
+            .w_full()
+            .h(px(value / max_total * TREND_BAR_HEIGHT))
+            .bg(color)
+    };
+
+    let bars = buckets.iter().map(|b| {
+        div()
+            .flex()
+            .flex_col()
+            .items_center()
+            .gap(px(theme::SPACE_1))
+            .child(
+                div()
+                    .w(px(18.0))
+                    .h(px(TREND_BAR_HEIGHT))
+                    .flex()
+                    .flex_col()
+                    .justify_end()
+                    .child(segment(b.avg_crashed, theme::ember()))
+                    .child(segment(b.avg_stuck, theme::copper()))
+                    .child(segment(b.avg_idle, theme::text_subtle()))
+                    .child(segment(b.avg_working, theme::aurora())),
+            )
+            .child(
+                div()
+                    .text_color(theme::text_muted())
+                    .text_size(px(theme::TEXT_XXS))
+                    .child(b.start.format("%a").to_string()),
+            )
+    });
+
+    div()
+        .flex()
+        .flex_col()
+        .gap(px(theme::SPACE_2))
+        .px(px(theme::SPACE_3))
+        .py(px(theme::SPACE_2))
+        .bg(theme::surface())
+        .rounded(px(theme::RADIUS_MD))
+        .mb(px(theme::SPACE_4))
+        .child(
+            div()
+                .flex()
+                .justify_between()
+                .text_size(px(theme::TEXT_SM))
+                .child(div().text_color(theme::text()).child("Fleet activity"))
+                .child(
+                    div()
+                        .text_color(theme::text_muted())
+                        .child(format!("{:.0}% working", working_pct)),
+                ),
+        )
+        .child(
+            div()
+                .flex()
+                .items_end()
+                .gap(px(theme::SPACE_2))
+                .children(bars),
+        )
+        .into_any_element()
+}
+
 /// Render a single kild card for the dashboard grid.
 fn render_card(
     display: &kild_core::SessionSnapshot,
     ix: usize,
     terminal_tabs: &std::collections::HashMap<String, TerminalTabs>,
     teammate_count: usize,
+    usage: Option<&kild_core::KildUsage>,
     cx: &mut Context<MainView>,
 ) -> AnyElement {
     let session = &display.session;
@@ -148,6 +317,17 @@ fn render_card(
         .map(|t| t.len())
         .unwrap_or(0);
     let session_id = session.id.to_string();
+    let usage_badge = usage.filter(|u| !u.tokens.is_empty()).map(|u| {
+        let tokens = kild_core::usage::format_tokens(u.tokens.total());
+        match u.cost_usd {
+            Some(_) => format!(
+                "{} tok · {}",
+                tokens,
+                kild_core::usage::format_cost(u.cost_usd)
+            ),
+            None => format!("{} tok", tokens),
+        }
+    });
 
     div()
         .id(SharedString::from(format!("dashboard-card-{}", ix)))
@@ -256,6 +436,9 @@ fn render_card(
                         if teammate_count == 1 { "" } else { "s" }
                     )))
                 })
+                .when_some(usage_badge, |row, badge| {
+                    row.child(div().text_color(theme::copper()).child(badge))
+                })
                 .when(terminal_count > 0, |row| {
                     row.child(
                         div()
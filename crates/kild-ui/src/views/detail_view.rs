@@ -2,6 +2,7 @@
 //!
 //! Renders comprehensive kild information from a dashboard card click:
 //! hero section, note, session info, git stats, terminals, path, and actions.
+//! "Review changes" swaps in the review panel (`views::review_panel`).
 
 use gpui::{
     AnyElement, Context, IntoElement, ParentElement, SharedString, Styled, div, prelude::*, px,
@@ -271,6 +272,14 @@ pub fn render_detail_view(
                         .pt(px(theme::SPACE_4))
                         .border_t_1()
                         .border_color(theme::border_subtle())
+                        .child(
+                            Button::new("detail-review")
+                                .label("Review changes")
+                                .primary()
+                                .on_click(cx.listener(|view, _, window, cx| {
+                                    view.on_review_open(window, cx);
+                                })),
+                        )
                         .child({
                             let wt = worktree_path_for_editor.clone();
                             let br = branch_for_editor.clone();
@@ -350,6 +359,7 @@ fn render_terminal_list(
             TerminalBackend::Daemon { .. } => "daemon",
             TerminalBackend::Local => "local",
             TerminalBackend::Teammate { .. } => "team",
+            TerminalBackend::Remote { .. } => "remote",
         };
         let sid = session_id.to_string();
         let tab_idx = i;
@@ -0,0 +1,37 @@
+//! Activity feed handlers for MainView.
+
+use gpui::{Context, Window};
+
+use crate::state::activity::ActivityFilter;
+
+use super::main_view_def::MainView;
+
+impl MainView {
+    /// Handle the `activity_feed` binding and status bar button: show or hide the feed.
+    pub(crate) fn on_toggle_activity(&mut self, cx: &mut Context<Self>) {
+        self.show_activity = !self.show_activity;
+        tracing::debug!(event = "ui.activity.toggled", visible = self.show_activity);
+        cx.notify();
+    }
+
+    pub(crate) fn on_activity_filter(&mut self, filter: ActivityFilter, cx: &mut Context<Self>) {
+        self.activity_filter = filter;
+        cx.notify();
+    }
+
+    pub(crate) fn on_activity_mark_all_read(&mut self, cx: &mut Context<Self>) {
+        self.state.mark_all_activity_read();
+        cx.notify();
+    }
+
+    /// Jump to the kild's terminal; selecting it marks its activity as read.
+    pub(crate) fn on_activity_entry_click(
+        &mut self,
+        session_id: &str,
+        window: &mut Window,
+        cx: &mut Context<Self>,
+    ) {
+        tracing::info!(event = "ui.activity.entry_clicked", session_id = session_id);
+        self.on_kild_select(session_id, window, cx);
+    }
+}
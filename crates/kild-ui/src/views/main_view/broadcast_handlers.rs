@@ -0,0 +1,125 @@
+//! Broadcast input handlers for MainView.
+
+use std::collections::HashSet;
+
+use gpui::Context;
+use kild_core::ProcessStatus;
+use kild_core::sessions::fleet::BRAIN_BRANCH;
+
+use crate::terminal::broadcast::{BroadcastScope, BroadcastTarget};
+use crate::views::pane_grid::PaneSlot;
+use crate::views::terminal_tabs::TerminalBackend;
+
+use super::main_view_def::MainView;
+use super::types::BroadcastStatus;
+
+impl MainView {
+    /// Turn broadcast input on for `scope`, or off with `None`.
+    pub(super) fn set_broadcast(&mut self, scope: Option<BroadcastScope>, cx: &mut Context<Self>) {
+        match scope {
+            Some(scope) => {
+                tracing::info!(event = "ui.broadcast.enabled", scope = scope.label())
+            }
+            None => tracing::info!(event = "ui.broadcast.disabled"),
+        }
+        self.broadcast = scope;
+        self.sync_broadcast(cx);
+        cx.notify();
+    }
+
+    /// Include or exclude a pane from broadcast input.
+    pub fn on_pane_broadcast_toggle(&mut self, slot_idx: usize, cx: &mut Context<Self>) {
+        self.active_pane_grid_mut().toggle_broadcast(slot_idx);
+        tracing::info!(
+            event = "ui.broadcast.pane_toggled",
+            slot = slot_idx,
+            excluded = self.active_pane_grid().is_broadcast_excluded(slot_idx)
+        );
+        self.sync_broadcast(cx);
+        cx.notify();
+    }
+
+    /// Recompute who receives broadcast input and publish it to the hub.
+    ///
+    /// Called every render so targets follow the pane grid, reconnects and
+    /// session changes. Panes scope sends to the included panes of the
+    /// active workspace. Project scope sends to every running daemon session
+    /// in the active project; a session shown in a pane follows that pane's
+    /// toggle, otherwise the brain session is left out.
+    pub(super) fn sync_broadcast(&self, cx: &gpui::App) -> Option<BroadcastStatus> {
+        let Some(scope) = self.broadcast else {
+            self.broadcast_hub.set_targets(Vec::new());
+            return None;
+        };
+        let grid = self.active_pane_grid();
+        let mut panes = vec![false; grid.slot_count()];
+        let mut targets = Vec::new();
+
+        match scope {
+            BroadcastScope::Panes => {
+                for (idx, slot) in grid.broadcast_slots() {
+                    let PaneSlot::Occupied {
+                        session_id,
+                        tab_idx,
+                        ..
+                    } = slot
+                    else {
+                        continue;
+                    };
+                    if let Some(entry) = self
+                        .terminal_tabs
+                        .get(session_id)
+                        .and_then(|tabs| tabs.get(*tab_idx))
+                    {
+                        targets.push(entry.view().read(cx).broadcast_target());
+                        panes[idx] = true;
+                    }
+                }
+            }
+            BroadcastScope::Project => {
+                let mut daemon_ids = HashSet::new();
+                for display in self.state.filtered_displays() {
+                    if display.process_status != ProcessStatus::Running {
+                        continue;
+                    }
+                    let included = match grid.find_session_slot(&display.session.id) {
+                        Some(slot) => !grid.is_broadcast_excluded(slot),
+                        None => &*display.session.branch != BRAIN_BRANCH,
+                    };
+                    let daemon_id = display
+                        .session
+                        .agents()
+                        .iter()
+                        .find_map(|a| a.daemon_session_id());
+                    if included && let Some(id) = daemon_id {
+                        daemon_ids.insert(id.to_string());
+                    }
+                }
+                for (idx, receives) in panes.iter_mut().enumerate() {
+                    if let PaneSlot::Occupied {
+                        session_id,
+                        tab_idx,
+                        ..
+                    } = grid.slot(idx)
+                        && let Some(entry) = self
+                            .terminal_tabs
+                            .get(session_id)
+                            .and_then(|tabs| tabs.get(*tab_idx))
+                        && let TerminalBackend::Daemon { daemon_session_id } = entry.backend()
+                    {
+                        *receives = daemon_ids.contains(daemon_session_id);
+                    }
+                }
+                targets.extend(daemon_ids.into_iter().map(BroadcastTarget::Daemon));
+            }
+        }
+
+        let status = BroadcastStatus {
+            scope,
+            targets: targets.len(),
+            panes,
+        };
+        self.broadcast_hub.set_targets(targets);
+        Some(status)
+    }
+}
@@ -3,17 +3,35 @@
 use gpui::{Context, Window, prelude::*};
 use gpui_component::input::InputState;
 
+use kild_core::CreateKildOptions;
+
 use crate::actions;
+use crate::state::DialogState;
+use crate::state::create_options::{self, CreateOptions};
+use crate::state::dialog::CreateFormState;
 use crate::views::create_dialog;
 
 use super::main_view_def::MainView;
 
 impl MainView {
     /// Handle click on the Create button in header.
+    ///
+    /// Opens the dialog with the options last used for the active project
+    /// and loads the project's branches for completion in the background.
     pub(crate) fn on_create_button_click(&mut self, window: &mut Window, cx: &mut Context<Self>) {
         tracing::info!(event = "ui.create_dialog.opened");
         self.state.open_create_dialog();
 
+        let remembered = self
+            .create_options
+            .projects
+            .get(&self.layout_key())
+            .cloned()
+            .unwrap_or_default();
+        if let DialogState::Create { form, .. } = self.state.dialog_mut() {
+            form.apply_remembered(&remembered);
+        }
+
         let branch_pattern =
             regex::Regex::new(r"^[a-zA-Z0-9\-_/]*$").expect("branch name regex is valid");
         let branch_input = cx.new(|cx| {
@@ -23,6 +41,32 @@ impl MainView {
         });
         self.branch_input = Some(branch_input);
 
+        let base_pattern =
+            regex::Regex::new(r"^[a-zA-Z0-9\-_/.]*$").expect("base branch regex is valid");
+        let base_input = cx.new(|cx| {
+            InputState::new(window, cx)
+                .placeholder("Config default")
+                .pattern(base_pattern)
+                .default_value(remembered.base_branch.clone().unwrap_or_default())
+        });
+        self.base_input = Some(base_input);
+
+        let issue_pattern = regex::Regex::new(r"^#?[0-9]*$").expect("issue regex is valid");
+        let issue_input = cx.new(|cx| {
+            InputState::new(window, cx)
+                .placeholder("#123")
+                .pattern(issue_pattern)
+        });
+        self.issue_input = Some(issue_input);
+
+        let flags_input = cx.new(|cx| {
+            InputState::new(window, cx)
+                .placeholder("Config default")
+                .validate(|text, _| !text.chars().any(|c| c.is_control()))
+                .default_value(remembered.flags.clone().unwrap_or_default())
+        });
+        self.flags_input = Some(flags_input);
+
         let note_input = cx.new(|cx| {
             InputState::new(window, cx)
                 .placeholder("What is this kild for?")
@@ -30,6 +74,98 @@ impl MainView {
         });
         self.note_input = Some(note_input);
 
+        let prompt_input = cx.new(|cx| {
+            InputState::new(window, cx)
+                .auto_grow(3, 8)
+                .placeholder("Sent to the agent once it starts")
+        });
+        self.prompt_input = Some(prompt_input);
+
+        self.load_branch_suggestions(cx);
+        cx.notify();
+    }
+
+    /// List the project's local and remote branches off the UI thread and
+    /// hand them to the open create dialog for completion.
+    fn load_branch_suggestions(&mut self, cx: &mut Context<Self>) {
+        let path = match self.state.active_project_path() {
+            Some(path) => path.to_path_buf(),
+            None => match std::env::current_dir() {
+                Ok(path) => path,
+                Err(e) => {
+                    tracing::debug!(event = "ui.create_dialog.branches_skipped", error = %e);
+                    return;
+                }
+            },
+        };
+
+        cx.spawn(async move |this, cx: &mut gpui::AsyncApp| {
+            let (local, remote) = cx
+                .background_executor()
+                .spawn(async move {
+                    let list = |result: Result<Vec<String>, kild_core::git::GitError>| {
+                        result.unwrap_or_else(|e| {
+                            tracing::debug!(
+                                event = "ui.create_dialog.branches_list_failed",
+                                error = %e
+                            );
+                            Vec::new()
+                        })
+                    };
+                    (
+                        list(kild_core::git::list_local_branch_names(&path)),
+                        list(kild_core::git::list_remote_branch_names(&path)),
+                    )
+                })
+                .await;
+
+            if let Err(e) = this.update(cx, |view, cx| {
+                if let DialogState::Create { form, .. } = view.state.dialog_mut() {
+                    form.set_known_branches(local, remote);
+                    cx.notify();
+                }
+            }) {
+                tracing::debug!(event = "ui.create_dialog.branches_view_dropped", error = ?e);
+            }
+        })
+        .detach();
+    }
+
+    /// Fill `input` with a branch completion picked from the dialog.
+    pub(crate) fn on_branch_suggestion_click(
+        &mut self,
+        input: &gpui::Entity<InputState>,
+        branch: &str,
+        window: &mut Window,
+        cx: &mut Context<Self>,
+    ) {
+        tracing::debug!(event = "ui.create_dialog.branch_completed", branch = branch);
+        let branch = branch.to_string();
+        input.update(cx, |input, cx| input.set_value(branch, window, cx));
+        cx.notify();
+    }
+
+    /// Cycle the runtime between config default, daemon and terminal.
+    pub(crate) fn on_runtime_cycle(&mut self, cx: &mut Context<Self>) {
+        if let DialogState::Create { form, .. } = self.state.dialog_mut() {
+            form.runtime = form.runtime.next();
+            tracing::info!(
+                event = "ui.create_dialog.runtime_changed",
+                runtime = form.runtime.label()
+            );
+        }
+        cx.notify();
+    }
+
+    /// Apply a toggle change in the create dialog form.
+    pub(crate) fn on_create_toggle(
+        &mut self,
+        cx: &mut Context<Self>,
+        f: impl FnOnce(&mut CreateFormState),
+    ) {
+        if let DialogState::Create { form, .. } = self.state.dialog_mut() {
+            f(form);
+        }
         cx.notify();
     }
 
@@ -49,8 +185,8 @@ impl MainView {
             return;
         }
 
-        // Extract agent from dialog state
-        let crate::state::DialogState::Create { form, .. } = self.state.dialog() else {
+        // Extract agent and toggles from dialog state
+        let DialogState::Create { form, .. } = self.state.dialog() else {
             tracing::error!(
                 event = "ui.dialog_submit.invalid_state",
                 "on_dialog_submit called when Create dialog not open"
@@ -58,24 +194,57 @@ impl MainView {
             return;
         };
         let agent = form.selected_agent();
+        let (yolo, runtime, use_main, no_fetch) =
+            (form.yolo, form.runtime, form.use_main, form.no_fetch);
 
         // Read text values from InputState entities
-        let branch = self
-            .branch_input
-            .as_ref()
-            .map(|i| i.read(cx).value().to_string())
-            .unwrap_or_default();
-        let branch = branch.trim().to_string();
-        let note_text = self
-            .note_input
-            .as_ref()
-            .map(|i| i.read(cx).value().to_string())
-            .unwrap_or_default();
-        let note = if note_text.trim().is_empty() {
-            None
-        } else {
-            Some(note_text.trim().to_string())
+        let read = |input: &Option<gpui::Entity<InputState>>| {
+            input
+                .as_ref()
+                .map(|i| i.read(cx).value().trim().to_string())
+                .filter(|value| !value.is_empty())
+        };
+        let branch = read(&self.branch_input).unwrap_or_default();
+        let note = read(&self.note_input);
+        let base_branch = read(&self.base_input);
+        let flags = read(&self.flags_input);
+        let initial_prompt = read(&self.prompt_input);
+        let issue = match read(&self.issue_input) {
+            Some(text) => match text.trim_start_matches('#').parse::<u32>() {
+                Ok(issue) => Some(issue),
+                Err(_) => {
+                    self.state
+                        .set_dialog_error(format!("Invalid issue number: '{}'", text));
+                    cx.notify();
+                    return;
+                }
+            },
+            None => None,
+        };
+
+        let options = CreateKildOptions {
+            issue,
+            base_branch: base_branch.clone(),
+            no_fetch,
+            runtime_mode: runtime.runtime_mode(),
+            use_main_worktree: use_main,
+            flags: flags.clone(),
+            yolo,
+            initial_prompt,
+        };
+        if let Err(e) = actions::validate_create(&branch, &agent, &options) {
+            self.state.set_dialog_error(e);
+            cx.notify();
+            return;
+        }
+        let remembered = CreateOptions {
+            agent: Some(agent.clone()),
+            base_branch,
+            flags,
+            runtime,
+            no_fetch,
         };
+        let options_key = self.layout_key();
 
         // Get active project path for kild creation context
         let project_path = self.state.active_project_path().map(|p| p.to_path_buf());
@@ -94,7 +263,9 @@ impl MainView {
         cx.spawn(async move |this, cx: &mut gpui::AsyncApp| {
             let result = cx
                 .background_executor()
-                .spawn(async move { actions::create_kild(branch, agent, note, project_path) })
+                .spawn(
+                    async move { actions::create_kild(branch, agent, note, project_path, options) },
+                )
                 .await;
 
             if let Err(e) = this.update(cx, |view, cx| {
@@ -103,6 +274,7 @@ impl MainView {
                     Ok(events) => {
                         view.state.apply_events(&events);
                         view.prune_terminal_cache();
+                        view.remember_create_options(options_key, remembered);
                     }
                     Err(e) => {
                         tracing::warn!(event = "ui.dialog_submit.error_displayed", error = %e);
@@ -120,6 +292,24 @@ impl MainView {
         .detach();
     }
 
+    /// Remember the options of a successful create for the next one in
+    /// the same project.
+    fn remember_create_options(&mut self, key: String, options: CreateOptions) {
+        if self.create_options.projects.get(&key) == Some(&options) {
+            return;
+        }
+        self.create_options.projects.insert(key, options);
+        let Some(path) = self.create_options_path.clone() else {
+            return;
+        };
+        if let Err(e) = create_options::save_create_options(&path, &self.create_options) {
+            tracing::error!(event = "ui.create_options.save_failed", error = %e);
+            self.state
+                .push_error(format!("{e} — create options will not be remembered."));
+            self.create_options_path = None;
+        }
+    }
+
     /// Cycle to the next agent in the list.
     pub fn on_agent_cycle(&mut self, cx: &mut Context<Self>) {
         let agents = create_dialog::agent_options();
@@ -133,7 +323,7 @@ impl MainView {
         }
 
         // Update selected agent index in dialog state
-        if let crate::state::DialogState::Create { form, .. } = self.state.dialog_mut() {
+        if let DialogState::Create { form, .. } = self.state.dialog_mut() {
             let next_index = (form.selected_agent_index() + 1) % agents.len();
             form.set_selected_agent_index(next_index);
             tracing::info!(
@@ -145,7 +335,6 @@ impl MainView {
     }
 
     /// Handle click on the destroy button [×] in a kild row.
-    #[allow(dead_code)]
     pub fn on_destroy_click(&mut self, branch: &str, cx: &mut Context<Self>) {
         tracing::info!(event = "ui.destroy_dialog.opened", branch = branch);
         let branch = branch.to_string();
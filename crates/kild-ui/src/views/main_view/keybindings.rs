@@ -171,6 +171,7 @@ pub(crate) struct UiTerminalKeybindings {
     pub(crate) focus_escape: ParsedKeybinding,
     pub(crate) copy: ParsedKeybinding,
     pub(crate) paste: ParsedKeybinding,
+    pub(crate) search: ParsedKeybinding,
 }
 
 /// Parsed navigation keybindings.
@@ -182,6 +183,9 @@ pub(crate) struct UiNavigationKeybindings {
     pub(crate) toggle_view: ParsedKeybinding,
     pub(crate) next_workspace: ParsedKeybinding,
     pub(crate) prev_workspace: ParsedKeybinding,
+    pub(crate) command_palette: ParsedKeybinding,
+    pub(crate) next_layout: ParsedKeybinding,
+    pub(crate) activity_feed: ParsedKeybinding,
 }
 
 /// All parsed UI keybindings, ready for use in `on_key_down` handlers.
@@ -213,6 +217,7 @@ impl UiKeybindings {
                 focus_escape: parse_or_default(term.focus_escape(), "ctrl+escape"),
                 copy: parse_or_default(term.copy(), "cmd+c"),
                 paste: parse_or_default(term.paste(), "cmd+v"),
+                search: parse_or_default(term.search(), "cmd+f"),
             },
             navigation: UiNavigationKeybindings {
                 next_kild: parse_or_default(nav.next_kild(), "cmd+j"),
@@ -221,6 +226,9 @@ impl UiKeybindings {
                 toggle_view: parse_or_default(nav.toggle_view(), "cmd+d"),
                 next_workspace: parse_or_default(nav.next_workspace(), "cmd+shift+]"),
                 prev_workspace: parse_or_default(nav.prev_workspace(), "cmd+shift+["),
+                command_palette: parse_or_default(nav.command_palette(), "cmd+p"),
+                next_layout: parse_or_default(nav.next_layout(), "cmd+shift+l"),
+                activity_feed: parse_or_default(nav.activity_feed(), "cmd+shift+a"),
             },
         }
     }
@@ -254,6 +262,9 @@ impl UiKeybindings {
             || self.navigation.toggle_view.matches(keystroke)
             || self.navigation.next_workspace.matches(keystroke)
             || self.navigation.prev_workspace.matches(keystroke)
+            || self.navigation.command_palette.matches(keystroke)
+            || self.navigation.next_layout.matches(keystroke)
+            || self.navigation.activity_feed.matches(keystroke)
             || self.terminal.focus_escape.matches(keystroke)
     }
 }
@@ -471,6 +482,24 @@ mod tests {
         assert!(kb.matches_any_nav_shortcut(&make_keystroke("escape", ctrl_mods())));
     }
 
+    #[test]
+    fn test_matches_any_nav_shortcut_command_palette() {
+        let kb = UiKeybindings::default_bindings();
+        assert!(kb.matches_any_nav_shortcut(&make_keystroke("p", cmd_mods())));
+    }
+
+    #[test]
+    fn test_matches_any_nav_shortcut_next_layout() {
+        let kb = UiKeybindings::default_bindings();
+        assert!(kb.matches_any_nav_shortcut(&make_keystroke("l", cmd_shift_mods())));
+    }
+
+    #[test]
+    fn test_matches_any_nav_shortcut_activity_feed() {
+        let kb = UiKeybindings::default_bindings();
+        assert!(kb.matches_any_nav_shortcut(&make_keystroke("a", cmd_shift_mods())));
+    }
+
     #[test]
     fn test_matches_any_nav_shortcut_no_match() {
         let kb = UiKeybindings::default_bindings();
@@ -48,7 +48,12 @@ impl MainView {
             {
                 self.active_terminal_id = Some(id.clone());
                 self.focus_region = FocusRegion::Terminal;
-                self.add_daemon_terminal_tab(&id, dsid, cx);
+                self.add_daemon_terminal_tab(
+                    &id,
+                    crate::daemon_client::DaemonEndpoint::Default,
+                    dsid,
+                    cx,
+                );
                 // Place in pane grid (will be available once daemon attach completes)
                 self.place_in_pane_grid(&id, 0, &branch, status);
                 cx.notify();
@@ -135,14 +140,56 @@ impl MainView {
     pub(crate) fn on_dashboard_card_click(&mut self, session_id: &str, cx: &mut Context<Self>) {
         tracing::debug!(event = "ui.dashboard.card_clicked", session_id = session_id);
         self.state.select_kild(session_id.to_string());
+        self.review = None;
         self.active_view = ActiveView::Detail;
         self.focus_region = FocusRegion::Dashboard;
         cx.notify();
     }
 
+    /// Handle Allow/Deny on a pending agent approval in the dashboard.
+    pub(crate) fn on_approval_resolve(
+        &mut self,
+        approval_id: &str,
+        decision: kild_protocol::ApprovalDecision,
+        cx: &mut Context<Self>,
+    ) {
+        tracing::info!(
+            event = "ui.approvals.resolve_started",
+            approval_id = approval_id,
+            decision = %decision
+        );
+        self.state.remove_pending_approval(approval_id);
+        cx.notify();
+
+        let id = approval_id.to_string();
+        cx.spawn(async move |this, cx: &mut gpui::AsyncApp| {
+            let aid = id.clone();
+            let result =
+                cx.background_executor()
+                    .spawn(async move {
+                        crate::daemon_client::resolve_approval_async(&aid, decision).await
+                    })
+                    .await;
+            if let Err(e) = result {
+                tracing::warn!(
+                    event = "ui.approvals.resolve_failed",
+                    approval_id = id,
+                    error = %e,
+                );
+                let _ = this.update(cx, |view, cx| {
+                    view.state
+                        .push_error(format!("Failed to answer approval {id}: {e}"));
+                    cx.notify();
+                });
+            }
+        })
+        .detach();
+    }
+
     /// Handle Detail view back button — return to Dashboard.
     pub(crate) fn on_detail_back(&mut self, cx: &mut Context<Self>) {
         tracing::debug!(event = "ui.detail.back_clicked");
+        self.review = None;
         self.active_view = ActiveView::Dashboard;
         cx.notify();
     }
@@ -162,10 +209,22 @@ impl MainView {
     ///
     /// Spawns the blocking open_kild operation on the background executor.
     pub fn on_open_click(&mut self, branch: &str, cx: &mut Context<Self>) {
+        self.on_open_with_agent(branch, None, cx);
+    }
+
+    /// Open a new agent in a kild; `None` uses the default agent.
+    ///
+    /// Spawns the blocking open_kild operation on the background executor.
+    pub(crate) fn on_open_with_agent(
+        &mut self,
+        branch: &str,
+        agent: Option<String>,
+        cx: &mut Context<Self>,
+    ) {
         if self.state.is_loading(branch) {
             return;
         }
-        tracing::info!(event = "ui.open_clicked", branch = branch);
+        tracing::info!(event = "ui.open_clicked", branch = branch, agent = ?agent);
         self.state.clear_error(branch);
         self.state.set_loading(branch);
         cx.notify();
@@ -175,7 +234,7 @@ impl MainView {
             let branch_for_action = branch.clone();
             let result = cx
                 .background_executor()
-                .spawn(async move { actions::open_kild(branch_for_action, None) })
+                .spawn(async move { actions::open_kild(branch_for_action, agent) })
                 .await;
 
             if let Err(e) = this.update(cx, |view, cx| {
@@ -263,6 +322,39 @@ impl MainView {
         cx.write_to_clipboard(gpui::ClipboardItem::new_string(path_str));
     }
 
+    /// Open a kild's pull request in the browser.
+    ///
+    /// Runs in the background since a missing PR cache means a forge lookup.
+    /// Surfaces any errors inline in the kild row.
+    pub(crate) fn on_open_pr_click(&mut self, branch: &str, cx: &mut Context<Self>) {
+        tracing::info!(event = "ui.open_pr_clicked", branch = branch);
+        self.state.clear_error(branch);
+        let branch = branch.to_string();
+
+        cx.spawn(async move |this, cx: &mut gpui::AsyncApp| {
+            let branch_for_action = branch.clone();
+            let result = cx
+                .background_executor()
+                .spawn(async move { actions::open_pr(branch_for_action) })
+                .await;
+
+            if let Err(e) = result
+                && let Err(update_err) = this.update(cx, |view, cx| {
+                    tracing::warn!(event = "ui.open_pr_click.error_displayed", branch = %branch, error = %e);
+                    view.state
+                        .set_error(&branch, crate::state::OperationError { message: e });
+                    cx.notify();
+                })
+            {
+                tracing::debug!(
+                    event = "ui.open_pr_click.view_dropped",
+                    error = ?update_err
+                );
+            }
+        })
+        .detach();
+    }
+
     /// Handle click on the Open Editor button in a kild row.
     ///
     /// Opens the worktree in the user's preferred editor ($EDITOR or zed).
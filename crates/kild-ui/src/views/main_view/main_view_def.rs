@@ -25,10 +25,20 @@ pub struct MainView {
     pub(super) branch_input: Option<gpui::Entity<gpui_component::input::InputState>>,
     /// Input state for create dialog note field.
     pub(super) note_input: Option<gpui::Entity<gpui_component::input::InputState>>,
+    /// Input state for create dialog base branch field.
+    pub(super) base_input: Option<gpui::Entity<gpui_component::input::InputState>>,
+    /// Input state for create dialog issue number field.
+    pub(super) issue_input: Option<gpui::Entity<gpui_component::input::InputState>>,
+    /// Input state for create dialog agent flags field.
+    pub(super) flags_input: Option<gpui::Entity<gpui_component::input::InputState>>,
+    /// Input state for create dialog initial prompt (multi-line).
+    pub(super) prompt_input: Option<gpui::Entity<gpui_component::input::InputState>>,
     /// Input state for add project dialog path field.
     pub(super) path_input: Option<gpui::Entity<gpui_component::input::InputState>>,
     /// Input state for add project dialog name field.
     pub(super) name_input: Option<gpui::Entity<gpui_component::input::InputState>>,
+    /// Input state for the command palette query (or inject text).
+    pub(super) palette_input: Option<gpui::Entity<gpui_component::input::InputState>>,
     /// Cached terminal tabs keyed by session ID. Each kild has its own set of tabs.
     pub(super) terminal_tabs: std::collections::HashMap<String, TerminalTabs>,
     /// Session ID of the kild whose terminal tabs are loaded. May be set while
@@ -49,7 +59,7 @@ pub struct MainView {
     /// Counter for generating unique daemon session IDs within this UI instance.
     #[allow(dead_code)]
     pub(super) daemon_session_counter: u64,
-    /// Control view workspaces: each workspace holds a 2x2 pane grid.
+    /// Control view workspaces: each workspace holds a pane grid.
     pub(super) workspaces: Vec<super::super::pane_grid::PaneGrid>,
     /// Index of the active workspace in the Control view.
     pub(super) active_workspace: usize,
@@ -59,6 +69,44 @@ pub struct MainView {
     pub(super) team_store: crate::teams::TeamStore,
     /// Handle to the team watcher task. Must be stored to prevent cancellation.
     pub(super) _team_watcher_task: Task<()>,
+    /// Handle to the pending approvals poll task. Must be stored to prevent cancellation.
+    pub(super) _approvals_task: Task<()>,
+    /// Broadcast input mode, when on.
+    pub(super) broadcast: Option<crate::terminal::broadcast::BroadcastScope>,
+    /// Broadcast targets shared with every terminal view.
+    pub(super) broadcast_hub: crate::terminal::broadcast::BroadcastHub,
+    /// Saved workspace layouts from `~/.kild/layouts.json`.
+    pub(super) layouts: crate::state::layouts::LayoutsFile,
+    /// Where layouts are saved. `None` disables saving (unknown home, or a
+    /// layouts file that failed to load and must not be overwritten).
+    pub(super) layouts_path: Option<std::path::PathBuf>,
+    /// Create dialog options last used per project.
+    pub(super) create_options: crate::state::create_options::CreateOptionsFile,
+    /// Where create options are saved. `None` disables saving, as for
+    /// `layouts_path`.
+    pub(super) create_options_path: Option<std::path::PathBuf>,
+    /// Named layout last applied to the active workspace, for cycling.
+    pub(super) named_layout: Option<String>,
+    /// Review panel for the kild in the Detail view, when open.
+    pub(super) review: Option<crate::state::review::ReviewState>,
+    /// Input state for the review panel's feedback field.
+    pub(super) review_feedback_input: Option<gpui::Entity<gpui_component::input::InputState>>,
+    /// Whether the activity feed drawer is shown.
+    pub(super) show_activity: bool,
+    /// Which entries the activity feed shows.
+    pub(super) activity_filter: crate::state::activity::ActivityFilter,
+    /// Config and theme file timestamps at the last theme load.
+    pub(super) theme_stamp: crate::themes::ThemeStamp,
+    /// Handle to the theme hot reload task. Must be stored to prevent cancellation.
+    pub(super) _theme_task: Task<()>,
+    /// Handle to the fleet history poll task. Must be stored to prevent cancellation.
+    pub(super) _fleet_history_task: Task<()>,
+    /// Handle to the token usage poll task. Must be stored to prevent cancellation.
+    pub(super) _usage_task: Task<()>,
+    /// Daemons from `[[daemon.hosts]]` and their last-seen sessions.
+    pub(super) remote_hosts: crate::state::remote_hosts::RemoteHosts,
+    /// Handle to the remote hosts poll task. Must be stored to prevent cancellation.
+    pub(super) _remote_hosts_task: Task<()>,
 }
 
 impl MainView {
@@ -229,12 +277,203 @@ impl MainView {
             }
         });
 
+        // Approvals poll task: agents block on permission requests, so poll tightly
+        let approvals_task = cx.spawn(async move |this, cx: &mut gpui::AsyncApp| {
+            loop {
+                cx.background_executor()
+                    .timer(crate::refresh::APPROVALS_POLL_INTERVAL)
+                    .await;
+
+                let result = cx
+                    .background_executor()
+                    .spawn(async { crate::daemon_client::list_approvals_async().await })
+                    .await;
+                let approvals = result.unwrap_or_else(|e| {
+                    tracing::debug!(event = "ui.approvals.poll_failed", error = %e);
+                    Vec::new()
+                });
+
+                if let Err(e) = this.update(cx, |view, cx| {
+                    if view.state.pending_approvals() != approvals.as_slice() {
+                        view.state.set_pending_approvals(approvals);
+                        cx.notify();
+                    }
+                }) {
+                    tracing::debug!(
+                        event = "ui.approvals_task.stopped",
+                        reason = "view_dropped",
+                        error = ?e
+                    );
+                    break;
+                }
+            }
+        });
+
+        // Fleet history task: reload health history off the UI thread, and only
+        // while the dashboard shows it and the history files changed
+        let fleet_history_task = cx.spawn(async move |this, cx: &mut gpui::AsyncApp| {
+            let mut loaded: Option<crate::state::app_state::FleetHistoryStamp> = None;
+            loop {
+                let Ok(visible) =
+                    this.update(cx, |view, _| view.active_view == ActiveView::Dashboard)
+                else {
+                    break;
+                };
+
+                if visible {
+                    let stamp = cx
+                        .background_executor()
+                        .spawn(async { crate::state::app_state::FleetHistoryStamp::current() })
+                        .await;
+                    if loaded.as_ref() != Some(&stamp) {
+                        let history = cx
+                            .background_executor()
+                            .spawn(async { crate::state::app_state::load_fleet_history() })
+                            .await;
+                        if let Err(e) = this.update(cx, |view, cx| {
+                            view.state.set_fleet_history(history);
+                            cx.notify();
+                        }) {
+                            tracing::debug!(
+                                event = "ui.fleet_history_task.stopped",
+                                reason = "view_dropped",
+                                error = ?e
+                            );
+                            break;
+                        }
+                        loaded = Some(stamp);
+                    }
+                }
+
+                cx.background_executor()
+                    .timer(crate::refresh::FLEET_HISTORY_POLL_INTERVAL)
+                    .await;
+            }
+        });
+
+        // Usage task: transcripts can be large, so parse them off the UI thread
+        let usage_task = cx.spawn(async move |this, cx: &mut gpui::AsyncApp| {
+            loop {
+                let Ok(sessions) = this.update(cx, |view, _| view.state.usage_sessions()) else {
+                    break;
+                };
+                let usage = cx
+                    .background_executor()
+                    .spawn(async move { crate::state::app_state::load_kild_usage(&sessions) })
+                    .await;
+
+                if let Err(e) = this.update(cx, |view, cx| {
+                    if view.state.set_kild_usage(usage) {
+                        cx.notify();
+                    }
+                }) {
+                    tracing::debug!(
+                        event = "ui.usage_task.stopped",
+                        reason = "view_dropped",
+                        error = ?e
+                    );
+                    break;
+                }
+
+                cx.background_executor()
+                    .timer(crate::refresh::USAGE_POLL_INTERVAL)
+                    .await;
+            }
+        });
+
+        // Theme poll task: reload when config.toml or a theme file changes
+        let theme_task = cx.spawn(async move |this, cx: &mut gpui::AsyncApp| {
+            loop {
+                cx.background_executor()
+                    .timer(crate::refresh::THEME_POLL_INTERVAL)
+                    .await;
+
+                let stamp = cx
+                    .background_executor()
+                    .spawn(async { crate::themes::ThemeStamp::current() })
+                    .await;
+
+                if let Err(e) = this.update(cx, |view, cx| {
+                    if view.theme_stamp != stamp {
+                        tracing::info!(event = "ui.theme.change_detected");
+                        view.theme_stamp = stamp;
+                        view.reload_theme(cx);
+                    }
+                }) {
+                    tracing::debug!(
+                        event = "ui.theme_task.stopped",
+                        reason = "view_dropped",
+                        error = ?e
+                    );
+                    break;
+                }
+            }
+        });
+
+        // Remote hosts task: ticks often, each host keeps its own poll/backoff schedule
+        let remote_hosts_task = cx.spawn(async move |this, cx: &mut gpui::AsyncApp| {
+            loop {
+                cx.background_executor()
+                    .timer(std::time::Duration::from_secs(1))
+                    .await;
+
+                if let Err(e) = this.update(cx, |view, cx| view.poll_remote_hosts(cx)) {
+                    tracing::debug!(
+                        event = "ui.remote_hosts_task.stopped",
+                        reason = "view_dropped",
+                        error = ?e
+                    );
+                    break;
+                }
+            }
+        });
+
         // Load keybindings from hierarchy (~/.kild/keybindings.toml → ./.kild/keybindings.toml)
         let raw = kild_core::Keybindings::load_hierarchy();
         let keybindings = UiKeybindings::from_config(&raw);
 
+        let mut state = AppState::new();
+        let remote_hosts = match kild_config::KildConfig::load_hierarchy() {
+            Ok(config) => crate::state::remote_hosts::RemoteHosts::from_config(
+                &config.daemon.hosts,
+                std::time::Instant::now(),
+            ),
+            Err(e) => {
+                tracing::warn!(event = "ui.remote_hosts.config_load_failed", error = %e);
+                Default::default()
+            }
+        };
+        let mut layouts_path = crate::state::layouts::layouts_file_path();
+        let layouts = match layouts_path
+            .as_deref()
+            .map(crate::state::layouts::load_layouts)
+        {
+            Some(Ok(layouts)) => layouts,
+            Some(Err(e)) => {
+                tracing::error!(event = "ui.layouts.load_failed", error = %e);
+                state.push_error(format!("{e} — layout changes will not be saved."));
+                layouts_path = None;
+                crate::state::layouts::LayoutsFile::default()
+            }
+            None => crate::state::layouts::LayoutsFile::default(),
+        };
+        let mut create_options_path = crate::state::create_options::create_options_file_path();
+        let create_options = match create_options_path
+            .as_deref()
+            .map(crate::state::create_options::load_create_options)
+        {
+            Some(Ok(options)) => options,
+            Some(Err(e)) => {
+                tracing::error!(event = "ui.create_options.load_failed", error = %e);
+                state.push_error(format!("{e} — create options will not be remembered."));
+                create_options_path = None;
+                Default::default()
+            }
+            None => Default::default(),
+        };
+
         let mut view = Self {
-            state: AppState::new(),
+            state,
             focus_handle: cx.focus_handle(),
             focus_region: FocusRegion::Dashboard,
             active_view: ActiveView::Control,
@@ -242,8 +481,13 @@ impl MainView {
             _watcher_task: watcher_task,
             branch_input: None,
             note_input: None,
+            base_input: None,
+            issue_input: None,
+            flags_input: None,
+            prompt_input: None,
             path_input: None,
             name_input: None,
+            palette_input: None,
             terminal_tabs: std::collections::HashMap::new(),
             active_terminal_id: None,
             renaming_tab: None,
@@ -256,8 +500,29 @@ impl MainView {
             keybindings,
             team_store: crate::teams::TeamStore::new(),
             _team_watcher_task: team_watcher_task,
+            _approvals_task: approvals_task,
+            broadcast: None,
+            broadcast_hub: crate::terminal::broadcast::BroadcastHub::new(),
+            layouts,
+            layouts_path,
+            create_options,
+            create_options_path,
+            named_layout: None,
+            review: None,
+            review_feedback_input: None,
+            show_activity: false,
+            activity_filter: Default::default(),
+            theme_stamp: crate::themes::ThemeStamp::current(),
+            _theme_task: theme_task,
+            _fleet_history_task: fleet_history_task,
+            _usage_task: usage_task,
+            remote_hosts,
+            _remote_hosts_task: remote_hosts_task,
         };
+        view.reload_theme(cx);
+        view.reset_pane_grid();
         view.refresh_daemon_available(cx);
+        view.poll_remote_hosts(cx);
         view
     }
 
@@ -271,12 +536,27 @@ impl MainView {
         cx.notify();
     }
 
+    /// Load theme settings and apply them, reporting theme errors in the banner.
+    pub(super) fn reload_theme(&mut self, cx: &mut Context<Self>) {
+        let (settings, error) = crate::themes::load_settings();
+        crate::theme_bridge::apply_theme(&settings, cx);
+        if let Some(error) = error {
+            self.state.push_error(error);
+        }
+        cx.notify();
+    }
+
     /// Drop all input state entities (called when any dialog closes).
     pub(super) fn clear_input_entities(&mut self) {
         self.branch_input = None;
         self.note_input = None;
+        self.base_input = None;
+        self.issue_input = None;
+        self.flags_input = None;
+        self.prompt_input = None;
         self.path_input = None;
         self.name_input = None;
+        self.palette_input = None;
     }
 
     /// Maximum number of workspaces to prevent unbounded creation.
@@ -3,15 +3,20 @@
 //! Root view that composes header, kild list, create dialog, and confirm dialog.
 //! Handles keyboard input and dialog state management.
 
+mod activity_handlers;
+mod broadcast_handlers;
 mod dialog_handlers;
 pub(crate) mod keybindings;
 mod kild_handlers;
 mod main_view_def;
 mod navigation;
+mod palette_handlers;
 mod pane_grid_handlers;
 mod path_utils;
 mod project_handlers;
+mod remote_handlers;
 mod rendering;
+mod review_handlers;
 mod tab_rename;
 mod terminal_handlers;
 mod types;
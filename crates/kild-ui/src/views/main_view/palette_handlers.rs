@@ -0,0 +1,222 @@
+//! Command palette handlers for MainView.
+
+use gpui::{Context, Window, prelude::*};
+use gpui_component::input::InputState;
+
+use crate::actions;
+use crate::state::DialogState;
+use crate::state::palette::{self, PaletteAction, PaletteItem, PaletteMode};
+use crate::views::create_dialog;
+
+use super::main_view_def::MainView;
+use super::types::FocusRegion;
+
+impl MainView {
+    /// Handle the `command_palette` binding: open the palette, or close it if open.
+    ///
+    /// Ignored while another dialog is open.
+    pub(super) fn toggle_palette(&mut self, window: &mut Window, cx: &mut Context<Self>) {
+        match self.state.dialog() {
+            DialogState::None => {
+                tracing::info!(event = "ui.command_palette.opened");
+                self.state.open_palette_dialog();
+                self.set_palette_input("Search kilds and actions...", window, cx);
+                cx.notify();
+            }
+            DialogState::Palette { .. } => self.on_palette_cancel(window, cx),
+            _ => {}
+        }
+    }
+
+    /// Close the palette without running anything.
+    pub(super) fn on_palette_cancel(&mut self, window: &mut Window, cx: &mut Context<Self>) {
+        tracing::info!(event = "ui.command_palette.cancelled");
+        self.close_palette(window, cx);
+    }
+
+    /// Current palette results and the highlighted index into them.
+    pub(super) fn palette_matches(&self, cx: &gpui::App) -> (Vec<PaletteItem>, usize) {
+        let DialogState::Palette { palette: state, .. } = self.state.dialog() else {
+            return (Vec::new(), 0);
+        };
+        let query = self.palette_text(cx);
+        let displays = self.state.filtered_displays();
+        let mut items = palette::build_items(&displays, &create_dialog::agent_options());
+        items.extend(palette::broadcast_items(self.broadcast));
+        let named: Vec<&str> = self.layouts.named.keys().map(String::as_str).collect();
+        items.extend(palette::layout_items(&named));
+        let results: Vec<PaletteItem> =
+            palette::rank_items(&items, &query, self.state.palette_history())
+                .into_iter()
+                .cloned()
+                .collect();
+        let selected = state.selected_index(&query, results.len());
+        (results, selected)
+    }
+
+    /// Move the highlight up (`-1`) or down (`1`).
+    pub(crate) fn on_palette_move(&mut self, delta: isize, cx: &mut Context<Self>) {
+        let query = self.palette_text(cx);
+        let (results, _) = self.palette_matches(cx);
+        if let DialogState::Palette { palette, .. } = self.state.dialog_mut()
+            && palette.mode == PaletteMode::Search
+        {
+            palette.move_selection(&query, results.len(), delta);
+            cx.notify();
+        }
+    }
+
+    /// Enter: run the highlighted entry, send the typed text in inject mode,
+    /// or save the layout under the typed name.
+    pub(super) fn on_palette_confirm(&mut self, window: &mut Window, cx: &mut Context<Self>) {
+        let DialogState::Palette { palette, .. } = self.state.dialog() else {
+            return;
+        };
+        match palette.mode.clone() {
+            PaletteMode::Search => {
+                let (results, selected) = self.palette_matches(cx);
+                if let Some(item) = results.into_iter().nth(selected) {
+                    self.run_palette_action(item.action, window, cx);
+                }
+            }
+            PaletteMode::Inject { branch } => self.submit_palette_inject(branch, window, cx),
+            PaletteMode::SaveLayout => {
+                let name = self.palette_text(cx).trim().to_string();
+                if name.is_empty() {
+                    self.state
+                        .set_dialog_error("Type a name for the layout first.".to_string());
+                    cx.notify();
+                    return;
+                }
+                self.save_named_layout(&name);
+                self.close_palette(window, cx);
+            }
+        }
+    }
+
+    /// Run a palette entry and remember it for ranking.
+    pub(crate) fn run_palette_action(
+        &mut self,
+        action: PaletteAction,
+        window: &mut Window,
+        cx: &mut Context<Self>,
+    ) {
+        tracing::info!(event = "ui.command_palette.action_selected", action = %action.key());
+        self.state.record_palette_use(&action);
+
+        let text_mode = match &action {
+            PaletteAction::InjectText { branch } => Some((
+                PaletteMode::Inject {
+                    branch: branch.clone(),
+                },
+                "Text to send to the agent...",
+            )),
+            PaletteAction::SaveLayout => {
+                Some((PaletteMode::SaveLayout, "Layout name, e.g. review"))
+            }
+            _ => None,
+        };
+        if let Some((mode, placeholder)) = text_mode {
+            if let DialogState::Palette { palette, error } = self.state.dialog_mut() {
+                palette.mode = mode;
+                *error = None;
+            }
+            self.set_palette_input(placeholder, window, cx);
+            cx.notify();
+            return;
+        }
+
+        self.close_palette(window, cx);
+        match action {
+            PaletteAction::CreateKild => self.on_create_button_click(window, cx),
+            PaletteAction::FocusTerminal { session_id, .. } => {
+                self.on_kild_select(&session_id, window, cx)
+            }
+            PaletteAction::OpenKild { branch, agent } => {
+                self.on_open_with_agent(&branch, agent, cx)
+            }
+            PaletteAction::StopKild { branch } => self.on_stop_click(&branch, cx),
+            PaletteAction::DestroyKild { branch } => self.on_destroy_click(&branch, cx),
+            PaletteAction::OpenPr { branch } => self.on_open_pr_click(&branch, cx),
+            PaletteAction::StartBroadcast { scope } => self.set_broadcast(Some(scope), cx),
+            PaletteAction::StopBroadcast => self.set_broadcast(None, cx),
+            PaletteAction::SetLayout { layout } => self.set_active_layout(layout, cx),
+            PaletteAction::ApplyNamedLayout { name } => self.apply_named_layout(&name, cx),
+            PaletteAction::InjectText { .. } | PaletteAction::SaveLayout => {}
+        }
+    }
+
+    /// Send the palette text to `branch` on the background executor.
+    ///
+    /// The palette closes immediately; delivery failures show on the kild row.
+    fn submit_palette_inject(
+        &mut self,
+        branch: String,
+        window: &mut Window,
+        cx: &mut Context<Self>,
+    ) {
+        let text = self.palette_text(cx);
+        if text.trim().is_empty() {
+            self.state
+                .set_dialog_error("Type the text to send first.".to_string());
+            cx.notify();
+            return;
+        }
+        self.close_palette(window, cx);
+        self.state.clear_error(&branch);
+
+        cx.spawn(async move |this, cx: &mut gpui::AsyncApp| {
+            let branch_for_action = branch.clone();
+            let result = cx
+                .background_executor()
+                .spawn(async move { actions::inject_text(branch_for_action, text) })
+                .await;
+
+            if let Err(e) = result
+                && let Err(update_err) = this.update(cx, |view, cx| {
+                    tracing::warn!(event = "ui.command_palette.inject_error_displayed", branch = %branch, error = %e);
+                    view.state
+                        .set_error(&branch, crate::state::OperationError { message: e });
+                    cx.notify();
+                })
+            {
+                tracing::debug!(
+                    event = "ui.command_palette.view_dropped",
+                    error = ?update_err
+                );
+            }
+        })
+        .detach();
+    }
+
+    fn palette_text(&self, cx: &gpui::App) -> String {
+        self.palette_input
+            .as_ref()
+            .map(|i| i.read(cx).value().to_string())
+            .unwrap_or_default()
+    }
+
+    /// Replace the palette input (fresh text) and focus it.
+    fn set_palette_input(
+        &mut self,
+        placeholder: &'static str,
+        window: &mut Window,
+        cx: &mut Context<Self>,
+    ) {
+        let input = cx.new(|cx| InputState::new(window, cx).placeholder(placeholder));
+        input.update(cx, |state, cx| state.focus(window, cx));
+        self.palette_input = Some(input);
+    }
+
+    /// Close the palette and hand focus back to where it was.
+    fn close_palette(&mut self, window: &mut Window, cx: &mut Context<Self>) {
+        self.clear_input_entities();
+        self.state.close_dialog();
+        if self.focus_region == FocusRegion::Terminal && self.active_terminal_view().is_some() {
+            self.focus_active_terminal(window, cx);
+        } else {
+            window.focus(&self.focus_handle);
+        }
+        cx.notify();
+    }
+}
@@ -2,18 +2,145 @@
 
 use gpui::{Context, Window};
 
+use crate::state::layouts::{self, ALL_PROJECTS_KEY, PaneLayout, ProjectLayout};
+use crate::views::pane_grid::{PaneGrid, PaneSlot};
+
 use super::main_view_def::MainView;
 use super::types::FocusRegion;
 
 impl MainView {
-    /// Reset workspaces to a single pane grid and auto-populate from current displays.
+    /// Reset workspaces for the active project: restore its saved layout, or
+    /// fall back to a single default grid auto-populated from current displays.
     pub(super) fn reset_pane_grid(&mut self) {
-        self.workspaces = vec![super::super::pane_grid::PaneGrid::new()];
+        self.named_layout = None;
+        let displays: Vec<kild_core::SessionSnapshot> = self
+            .state
+            .filtered_displays()
+            .into_iter()
+            .cloned()
+            .collect();
+        if let Some(saved) = self.layouts.projects.get(&self.layout_key()) {
+            self.workspaces = saved
+                .workspaces
+                .iter()
+                .take(Self::MAX_WORKSPACES)
+                .map(|ws| PaneGrid::from_saved(ws, &displays))
+                .collect();
+            self.active_workspace = saved.active_workspace.min(self.workspaces.len() - 1);
+            tracing::info!(
+                event = "ui.layouts.restored",
+                workspaces = self.workspaces.len()
+            );
+            return;
+        }
+        self.workspaces = vec![PaneGrid::new()];
         self.active_workspace = 0;
-        let displays = self.state.filtered_displays();
-        let displays_owned: Vec<kild_core::SessionSnapshot> =
-            displays.into_iter().cloned().collect();
-        self.workspaces[0].auto_populate(&displays_owned, &self.terminal_tabs);
+        self.workspaces[0].auto_populate(&displays, &self.terminal_tabs);
+    }
+
+    /// Key of the active project in the layouts and create options files.
+    pub(super) fn layout_key(&self) -> String {
+        self.state
+            .active_project_id()
+            .unwrap_or_else(|| ALL_PROJECTS_KEY.to_string())
+    }
+
+    /// Save the active project's workspaces if they changed since the last save.
+    ///
+    /// Called every render. A failed write is reported once and disables saving.
+    pub(super) fn persist_layout_if_changed(&mut self) {
+        let Some(path) = self.layouts_path.clone() else {
+            return;
+        };
+        let current = ProjectLayout {
+            workspaces: self.workspaces.iter().map(PaneGrid::to_saved).collect(),
+            active_workspace: self.active_workspace,
+        };
+        let key = self.layout_key();
+        if self.layouts.projects.get(&key) == Some(&current) {
+            return;
+        }
+        self.layouts.projects.insert(key, current);
+        self.save_layouts_file(&path);
+    }
+
+    fn save_layouts_file(&mut self, path: &std::path::Path) {
+        if let Err(e) = layouts::save_layouts(path, &self.layouts) {
+            tracing::error!(event = "ui.layouts.save_failed", error = %e);
+            self.state
+                .push_error(format!("{e} — layout changes will not be saved."));
+            self.layouts_path = None;
+        }
+    }
+
+    /// Rearrange the active workspace into `layout`, filling new slots.
+    pub(super) fn set_active_layout(&mut self, layout: PaneLayout, cx: &mut Context<Self>) {
+        tracing::info!(event = "ui.layouts.layout_set", layout = %layout.label());
+        self.named_layout = None;
+        self.active_pane_grid_mut().set_layout(layout);
+        self.fill_active_pane_grid();
+        cx.notify();
+    }
+
+    /// Replace the active workspace with the layout saved as `name`.
+    pub(super) fn apply_named_layout(&mut self, name: &str, cx: &mut Context<Self>) {
+        let Some(saved) = self.layouts.named.get(name) else {
+            tracing::warn!(event = "ui.layouts.named_not_found", name = name);
+            return;
+        };
+        tracing::info!(event = "ui.layouts.named_applied", name = name);
+        let displays: Vec<kild_core::SessionSnapshot> = self
+            .state
+            .filtered_displays()
+            .into_iter()
+            .cloned()
+            .collect();
+        *self.active_pane_grid_mut() = PaneGrid::from_saved(saved, &displays);
+        self.fill_active_pane_grid();
+        self.named_layout = Some(name.to_string());
+        cx.notify();
+    }
+
+    /// Save the active workspace's layout and contents under `name`.
+    pub(super) fn save_named_layout(&mut self, name: &str) {
+        tracing::info!(event = "ui.layouts.named_saved", name = name);
+        let saved = self.active_pane_grid().to_saved();
+        self.layouts.named.insert(name.to_string(), saved);
+        self.named_layout = Some(name.to_string());
+        if let Some(path) = self.layouts_path.clone() {
+            self.save_layouts_file(&path);
+        }
+    }
+
+    /// Handle the `next_layout` binding: cycle named layouts, or the presets
+    /// when none are saved.
+    pub(super) fn cycle_layout(&mut self, cx: &mut Context<Self>) {
+        if let Some(name) = self
+            .layouts
+            .next_named(self.named_layout.as_deref())
+            .map(str::to_string)
+        {
+            self.apply_named_layout(&name, cx);
+            return;
+        }
+        let presets = PaneLayout::presets();
+        let next = presets
+            .iter()
+            .position(|p| p == self.active_pane_grid().layout())
+            .map_or(0, |idx| (idx + 1) % presets.len());
+        self.set_active_layout(presets[next].clone(), cx);
+    }
+
+    /// Fill empty slots of the active grid with kilds that have open terminals.
+    fn fill_active_pane_grid(&mut self) {
+        let displays: Vec<kild_core::SessionSnapshot> = self
+            .state
+            .filtered_displays()
+            .into_iter()
+            .cloned()
+            .collect();
+        let idx = self.active_workspace.min(self.workspaces.len() - 1);
+        self.workspaces[idx].auto_populate(&displays, &self.terminal_tabs);
     }
 
     /// Place a terminal in the active workspace's pane grid. If already present, just focus it.
@@ -64,7 +191,22 @@ impl MainView {
     pub fn on_pane_focus(&mut self, slot_idx: usize, window: &mut Window, cx: &mut Context<Self>) {
         self.active_pane_grid_mut().set_focus(slot_idx);
 
-        let slot_data = if let super::super::pane_grid::PaneSlot::Occupied {
+        // A pane restored from the layouts file whose kild has no terminal open yet.
+        if let PaneSlot::Occupied { session_id, .. } = self.active_pane_grid().slot(slot_idx)
+            && self
+                .terminal_tabs
+                .get(session_id)
+                .is_none_or(|tabs| tabs.is_empty())
+        {
+            let session_id = session_id.clone();
+            if self.active_pane_grid().find_slot(&session_id, 0).is_none() {
+                self.active_pane_grid_mut().set_tab(slot_idx, 0);
+            }
+            self.on_kild_select(&session_id, window, cx);
+            return;
+        }
+
+        let slot_data = if let PaneSlot::Occupied {
             session_id,
             tab_idx,
             ..
@@ -86,6 +228,13 @@ impl MainView {
         cx.notify();
     }
 
+    /// Handle a pane dropped onto another slot: swap the two.
+    pub fn on_pane_swap(&mut self, from: usize, to: usize, cx: &mut Context<Self>) {
+        tracing::info!(event = "ui.pane_grid.swapped", from = from, to = to);
+        self.active_pane_grid_mut().swap(from, to);
+        cx.notify();
+    }
+
     /// Handle maximize/restore toggle on a pane.
     pub fn on_pane_maximize(&mut self, slot_idx: usize, cx: &mut Context<Self>) {
         self.active_pane_grid_mut().toggle_maximize(slot_idx);
@@ -100,9 +249,7 @@ impl MainView {
         if self.active_pane_grid().focused_slot() == slot_idx {
             if let Some(next) = self.active_pane_grid().next_occupied_slot() {
                 self.active_pane_grid_mut().set_focus(next);
-                if let super::super::pane_grid::PaneSlot::Occupied { session_id, .. } =
-                    self.active_pane_grid().slot(next)
-                {
+                if let PaneSlot::Occupied { session_id, .. } = self.active_pane_grid().slot(next) {
                     self.active_terminal_id = Some(session_id.clone());
                 }
             } else {
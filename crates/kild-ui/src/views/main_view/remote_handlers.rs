@@ -0,0 +1,102 @@
+//! Remote daemon host handlers for MainView.
+
+use std::time::Instant;
+
+use gpui::{Context, Window};
+
+use crate::components::Status;
+use crate::state::remote_hosts::{parse_terminal_key, terminal_key};
+
+use super::main_view_def::MainView;
+use super::types::{ActiveView, FocusRegion};
+
+impl MainView {
+    /// Poll the remote hosts that are due. Hosts that answer again after an
+    /// outage reconnect their dropped terminals.
+    pub(super) fn poll_remote_hosts(&mut self, cx: &mut Context<Self>) {
+        let due = self.remote_hosts.take_due(Instant::now());
+        if due.is_empty() {
+            return;
+        }
+
+        cx.spawn(async move |this, cx: &mut gpui::AsyncApp| {
+            let results = cx
+                .background_executor()
+                .spawn(async move {
+                    futures::future::join_all(due.into_iter().map(|endpoint| async move {
+                        let result = crate::daemon_client::list_sessions_async(&endpoint)
+                            .await
+                            .map_err(|e| e.to_string());
+                        (endpoint.host_name().unwrap_or_default().to_string(), result)
+                    }))
+                    .await
+                })
+                .await;
+
+            if let Err(e) = this.update(cx, |view, cx| {
+                let now = Instant::now();
+                for (host, result) in results {
+                    if view.remote_hosts.record(&host, result, now) {
+                        view.reconnect_remote_terminals(&host, cx);
+                    }
+                }
+                cx.notify();
+            }) {
+                tracing::debug!(event = "ui.remote_host.poll_view_dropped", error = ?e);
+            }
+        })
+        .detach();
+    }
+
+    /// Reconnect the dropped terminals of a host that came back.
+    fn reconnect_remote_terminals(&mut self, host: &str, cx: &mut Context<Self>) {
+        let views: Vec<_> = self
+            .terminal_tabs
+            .iter()
+            .filter(|(key, _)| parse_terminal_key(key).is_some_and(|(h, _)| h == host))
+            .flat_map(|(_, tabs)| (0..tabs.len()).filter_map(|i| tabs.get(i)))
+            .map(|entry| entry.view().clone())
+            .collect();
+        for view in views {
+            view.update(cx, |view, cx| view.reconnect_if_disconnected(cx));
+        }
+    }
+
+    /// Handle a click on a remote session in the sidebar: open (or attach)
+    /// its terminal in the Control view.
+    pub(crate) fn on_remote_session_select(
+        &mut self,
+        host: &str,
+        daemon_session_id: &str,
+        window: &mut Window,
+        cx: &mut Context<Self>,
+    ) {
+        let Some(remote) = self.remote_hosts.get(host) else {
+            return;
+        };
+        tracing::debug!(
+            event = "ui.remote_session.selected",
+            host = host,
+            daemon_session_id = daemon_session_id
+        );
+        let endpoint = remote.endpoint().clone();
+        let key = terminal_key(host, daemon_session_id);
+        self.active_view = ActiveView::Control;
+
+        if self.terminal_tabs.get(&key).is_none_or(|t| t.is_empty()) {
+            self.add_daemon_terminal_tab(&key, endpoint, daemon_session_id, cx);
+        }
+        self.place_in_pane_grid(&key, 0, daemon_session_id, Status::Active);
+        self.active_terminal_id = Some(key);
+        self.focus_region = FocusRegion::Terminal;
+        self.focus_active_terminal(window, cx);
+        cx.notify();
+    }
+
+    /// Retry a disconnected host now instead of waiting out its backoff.
+    pub(crate) fn on_remote_host_retry(&mut self, host: &str, cx: &mut Context<Self>) {
+        tracing::info!(event = "ui.remote_host.retry_requested", host = host);
+        self.remote_hosts.retry(host, Instant::now());
+        self.poll_remote_hosts(cx);
+    }
+}
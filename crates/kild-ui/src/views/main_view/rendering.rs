@@ -10,13 +10,13 @@ use gpui_component::button::{Button, ButtonVariants};
 
 use crate::theme;
 use crate::views::{
-    add_project_dialog, confirm_dialog, create_dialog, dashboard_view, detail_view, project_rail,
-    sidebar, status_bar,
+    activity_feed, add_project_dialog, command_palette, confirm_dialog, create_dialog,
+    dashboard_view, detail_view, project_rail, review_panel, sidebar, status_bar,
     terminal_tabs::{RenamingTab, TabBarContext, render_tab_bar},
 };
 
 use super::main_view_def::MainView;
-use super::types::{ActiveView, FocusRegion};
+use super::types::{ActiveView, BroadcastStatus, FocusRegion};
 
 impl MainView {
     #[allow(dead_code)]
@@ -47,7 +47,11 @@ impl MainView {
     }
 
     /// Render the view tab bar: [Control 1] [Control 2] [+] [Dashboard] ...
-    fn render_view_tab_bar(&self, cx: &mut Context<Self>) -> impl IntoElement {
+    fn render_view_tab_bar(
+        &self,
+        broadcast: Option<&BroadcastStatus>,
+        cx: &mut Context<Self>,
+    ) -> impl IntoElement {
         let is_control = self.active_view == ActiveView::Control;
         let is_dashboard = matches!(self.active_view, ActiveView::Dashboard | ActiveView::Detail);
         let workspace_count = self.workspaces.len();
@@ -174,15 +178,55 @@ impl MainView {
         );
 
         // Spacer
-        bar.child(div().flex_1())
+        bar = bar.child(div().flex_1());
+
+        // Broadcast indicator: click to stop broadcasting
+        bar.when_some(broadcast, |bar, status| {
+            bar.child(
+                div()
+                    .id("broadcast-badge")
+                    .flex()
+                    .items_center()
+                    .gap(px(theme::SPACE_1))
+                    .px(px(theme::SPACE_2))
+                    .py(px(2.0))
+                    .rounded(px(theme::RADIUS_SM))
+                    .bg(theme::with_alpha(theme::ember(), 0.15))
+                    .cursor_pointer()
+                    .text_size(px(theme::TEXT_XS))
+                    .font_weight(FontWeight::MEDIUM)
+                    .text_color(theme::ember())
+                    .hover(|d| d.bg(theme::with_alpha(theme::ember(), 0.25)))
+                    .on_mouse_up(
+                        gpui::MouseButton::Left,
+                        cx.listener(|view, _, _, cx| view.set_broadcast(None, cx)),
+                    )
+                    .child(format!(
+                        "BROADCAST · {} {} · {}",
+                        status.targets,
+                        if status.targets == 1 {
+                            "terminal"
+                        } else {
+                            "terminals"
+                        },
+                        status.scope.label()
+                    ))
+                    .child(div().text_color(theme::text_muted()).child("×")),
+            )
+        })
     }
 
     /// Render the main content area based on active view.
-    fn render_main_content(&mut self, cx: &mut Context<Self>) -> impl IntoElement {
+    fn render_main_content(
+        &mut self,
+        broadcast: Option<&BroadcastStatus>,
+        cx: &mut Context<Self>,
+    ) -> impl IntoElement {
         match self.active_view {
             ActiveView::Control => super::super::pane_grid::render_pane_grid(
                 self.active_pane_grid(),
                 &self.terminal_tabs,
+                broadcast.map(|b| b.panes.as_slice()),
                 cx,
             )
             .into_any_element(),
@@ -192,9 +236,14 @@ impl MainView {
                 &self.team_store,
                 cx,
             ),
-            ActiveView::Detail => {
-                detail_view::render_detail_view(&self.state, &self.terminal_tabs, cx)
-            }
+            ActiveView::Detail => match &self.review {
+                Some(review) => review_panel::render_review_panel(
+                    review,
+                    self.review_feedback_input.as_ref(),
+                    cx,
+                ),
+                None => detail_view::render_detail_view(&self.state, &self.terminal_tabs, cx),
+            },
         }
     }
 
@@ -218,6 +267,38 @@ impl MainView {
             return;
         }
 
+        // command_palette binding: open/close the palette from anywhere, terminal included
+        if self
+            .keybindings
+            .navigation
+            .command_palette
+            .matches(&event.keystroke)
+        {
+            self.toggle_palette(window, cx);
+            return;
+        }
+
+        // activity_feed binding: show/hide the activity feed from anywhere
+        if self
+            .keybindings
+            .navigation
+            .activity_feed
+            .matches(&event.keystroke)
+        {
+            self.on_toggle_activity(cx);
+            return;
+        }
+
+        // Palette open: Enter runs the highlighted entry, Escape closes, other keys go to Input
+        if self.state.dialog().is_palette() {
+            match key_str.as_str() {
+                "enter" => self.on_palette_confirm(window, cx),
+                "escape" => self.on_palette_cancel(window, cx),
+                _ => {}
+            }
+            return;
+        }
+
         // focus_escape binding: move focus from terminal to sidebar (terminal stays rendered)
         if self
             .keybindings
@@ -310,6 +391,18 @@ impl MainView {
             return;
         }
 
+        // next_layout binding: cycle the active workspace's layout
+        if self
+            .keybindings
+            .navigation
+            .next_layout
+            .matches(&event.keystroke)
+        {
+            self.active_view = ActiveView::Control;
+            self.cycle_layout(cx);
+            return;
+        }
+
         // prev_workspace / next_workspace bindings: cycle workspaces
         if self
             .keybindings
@@ -383,6 +476,27 @@ impl MainView {
             return;
         }
 
+        // Up/Down in the review panel: move through the changed files
+        if self.active_view == ActiveView::Detail
+            && let Some(review) = self.review.as_mut()
+            && (key_str == "up" || key_str == "down")
+        {
+            if key_str == "up" {
+                review.select_prev();
+            } else {
+                review.select_next();
+            }
+            cx.notify();
+            return;
+        }
+
+        // Escape in the review panel: back to the kild's details
+        if key_str == "escape" && self.active_view == ActiveView::Detail && self.review.is_some() {
+            self.on_review_close(cx);
+            window.focus(&self.focus_handle);
+            return;
+        }
+
         // Escape in Detail view: back to Dashboard
         if key_str == "escape" && self.active_view == ActiveView::Detail {
             self.active_view = ActiveView::Dashboard;
@@ -420,6 +534,8 @@ impl MainView {
                 "tab" => self.on_agent_cycle(cx),
                 _ => {}
             },
+            // Handled above, before terminal propagation
+            DialogState::Palette { .. } => {}
         }
     }
 }
@@ -432,6 +548,9 @@ impl Focusable for MainView {
 
 impl Render for MainView {
     fn render(&mut self, _window: &mut Window, cx: &mut Context<Self>) -> impl IntoElement {
+        self.persist_layout_if_changed();
+        let broadcast = self.sync_broadcast(cx);
+
         div()
             .track_focus(&self.focus_handle)
             .on_key_down(cx.listener(Self::on_key_down))
@@ -511,6 +630,8 @@ impl Render for MainView {
                                         &self.terminal_tabs,
                                         self.active_pane_grid(),
                                         &self.team_store,
+                                        &self.remote_hosts,
+                                        self.active_terminal_id.as_deref(),
                                         cx,
                                     ))
                                     // Main area (flex-1)
@@ -521,10 +642,20 @@ impl Render for MainView {
                                             .flex_col()
                                             .overflow_hidden()
                                             // View tab bar: [Control] [Dashboard]
-                                            .child(self.render_view_tab_bar(cx))
+                                            .child(self.render_view_tab_bar(broadcast.as_ref(), cx))
                                             // View content
-                                            .child(self.render_main_content(cx)),
-                                    ),
+                                            .child(
+                                                self.render_main_content(broadcast.as_ref(), cx),
+                                            ),
+                                    )
+                                    // Activity feed drawer (right)
+                                    .when(self.show_activity, |this| {
+                                        this.child(activity_feed::render_activity_feed(
+                                            &self.state,
+                                            self.activity_filter,
+                                            cx,
+                                        ))
+                                    }),
                             )
                             // Status bar (spans sidebar + main, NOT rail)
                             .child(status_bar::render_status_bar(
@@ -540,8 +671,14 @@ impl Render for MainView {
                 this.child(create_dialog::render_create_dialog(
                     self.state.dialog(),
                     self.state.is_dialog_loading(),
-                    self.branch_input.as_ref(),
-                    self.note_input.as_ref(),
+                    create_dialog::CreateDialogInputs {
+                        branch: self.branch_input.as_ref(),
+                        base: self.base_input.as_ref(),
+                        issue: self.issue_input.as_ref(),
+                        flags: self.flags_input.as_ref(),
+                        prompt: self.prompt_input.as_ref(),
+                        note: self.note_input.as_ref(),
+                    },
                     cx,
                 ))
             })
@@ -560,5 +697,15 @@ impl Render for MainView {
                     cx,
                 ))
             })
+            .when(self.state.dialog().is_palette(), |this| {
+                let (results, selected) = self.palette_matches(cx);
+                this.child(command_palette::render_command_palette(
+                    self.state.dialog(),
+                    self.palette_input.as_ref(),
+                    &results,
+                    selected,
+                    cx,
+                ))
+            })
     }
 }
@@ -0,0 +1,213 @@
+//! Review panel handlers for MainView.
+//!
+//! Loading the diff, discarding files and opening PRs run on the background
+//! executor. Results are dropped if the panel was closed or switched to
+//! another kild in the meantime.
+
+use gpui::{AppContext, Context, Window};
+use gpui_component::input::InputState;
+
+use crate::actions;
+use crate::state::review::{ReviewState, feedback_message};
+
+use super::main_view_def::MainView;
+
+impl MainView {
+    /// Open the review panel for the selected kild and load its diff.
+    pub(crate) fn on_review_open(&mut self, window: &mut Window, cx: &mut Context<Self>) {
+        let Some(kild) = self.state.selected_kild() else {
+            return;
+        };
+        let branch = kild.session.branch.to_string();
+        tracing::info!(event = "ui.review.opened", branch = %branch);
+
+        self.review = Some(ReviewState::new(branch, kild.session.worktree_path.clone()));
+        self.reset_review_feedback(window, cx);
+        self.reload_review(cx);
+    }
+
+    /// Close the review panel, back to the kild's details.
+    pub(crate) fn on_review_close(&mut self, cx: &mut Context<Self>) {
+        tracing::debug!(event = "ui.review.closed");
+        self.review = None;
+        self.review_feedback_input = None;
+        cx.notify();
+    }
+
+    /// Reload the diff and commits of the kild under review.
+    pub(crate) fn reload_review(&mut self, cx: &mut Context<Self>) {
+        let Some(review) = self.review.as_mut() else {
+            return;
+        };
+        review.start_loading();
+        let branch = review.branch.clone();
+        let worktree_path = review.worktree_path.clone();
+        cx.notify();
+
+        cx.spawn(async move |this, cx: &mut gpui::AsyncApp| {
+            let result = cx
+                .background_executor()
+                .spawn(async move { actions::load_review(worktree_path) })
+                .await;
+
+            if let Err(e) = this.update(cx, |view, cx| {
+                let Some(review) = view.review.as_mut().filter(|r| r.branch == branch) else {
+                    return;
+                };
+                match result {
+                    Ok((diff, commits)) => review.set_loaded(diff, commits),
+                    Err(e) => {
+                        tracing::warn!(event = "ui.review.load_failed", branch = %branch, error = %e);
+                        review.set_failed(e);
+                    }
+                }
+                cx.notify();
+            }) {
+                tracing::debug!(event = "ui.review.view_dropped", error = ?e);
+            }
+        })
+        .detach();
+    }
+
+    pub(crate) fn on_review_select(&mut self, idx: usize, cx: &mut Context<Self>) {
+        if let Some(review) = self.review.as_mut() {
+            review.select(idx);
+            cx.notify();
+        }
+    }
+
+    pub(crate) fn on_review_toggle_mode(&mut self, cx: &mut Context<Self>) {
+        if let Some(review) = self.review.as_mut() {
+            review.toggle_mode();
+            cx.notify();
+        }
+    }
+
+    /// Discard the kild's change to `path` (second click confirms), then reload.
+    pub(crate) fn on_review_discard(&mut self, path: &str, cx: &mut Context<Self>) {
+        let Some(review) = self.review.as_mut() else {
+            return;
+        };
+        if review.is_busy() || !review.request_discard(path) {
+            cx.notify();
+            return;
+        }
+        tracing::info!(event = "ui.review.discard_started", branch = %review.branch, file = path);
+        review.set_busy();
+        let branch = review.branch.clone();
+        let worktree_path = review.worktree_path.clone();
+        let file = path.to_string();
+        cx.notify();
+
+        cx.spawn(async move |this, cx: &mut gpui::AsyncApp| {
+            let file_for_action = file.clone();
+            let result = cx
+                .background_executor()
+                .spawn(async move { actions::discard_review_file(worktree_path, file_for_action) })
+                .await;
+
+            if let Err(e) = this.update(cx, |view, cx| {
+                let Some(review) = view.review.as_mut().filter(|r| r.branch == branch) else {
+                    return;
+                };
+                match result {
+                    Ok(()) => {
+                        review.set_notice(format!("Discarded changes to {}", file));
+                        view.reload_review(cx);
+                    }
+                    Err(e) => review.set_failed(e),
+                }
+                cx.notify();
+            }) {
+                tracing::debug!(event = "ui.review.view_dropped", error = ?e);
+            }
+        })
+        .detach();
+    }
+
+    /// Inject the feedback text into the agent, mentioning the file on screen.
+    pub(crate) fn on_review_send_feedback(&mut self, window: &mut Window, cx: &mut Context<Self>) {
+        let text = self
+            .review_feedback_input
+            .as_ref()
+            .map(|i| i.read(cx).value().to_string())
+            .unwrap_or_default();
+        let Some(review) = self.review.as_mut() else {
+            return;
+        };
+        if text.trim().is_empty() {
+            review.set_failed("Type the feedback to send first.".to_string());
+            cx.notify();
+            return;
+        }
+        let message = feedback_message(&text, review.selected_file().map(|f| f.path.as_str()));
+        let branch = review.branch.clone();
+        review.set_busy();
+        self.reset_review_feedback(window, cx);
+        cx.notify();
+
+        cx.spawn(async move |this, cx: &mut gpui::AsyncApp| {
+            let branch_for_action = branch.clone();
+            let result = cx
+                .background_executor()
+                .spawn(async move { actions::inject_text(branch_for_action, message) })
+                .await;
+
+            if let Err(e) = this.update(cx, |view, cx| {
+                let Some(review) = view.review.as_mut().filter(|r| r.branch == branch) else {
+                    return;
+                };
+                match result {
+                    Ok(()) => review.set_notice("Feedback sent to the agent.".to_string()),
+                    Err(e) => review.set_failed(e),
+                }
+                cx.notify();
+            }) {
+                tracing::debug!(event = "ui.review.view_dropped", error = ?e);
+            }
+        })
+        .detach();
+    }
+
+    /// Push the branch and open (or reuse) its PR in the browser.
+    pub(crate) fn on_review_approve(&mut self, cx: &mut Context<Self>) {
+        let Some(review) = self.review.as_mut() else {
+            return;
+        };
+        if review.is_busy() {
+            return;
+        }
+        tracing::info!(event = "ui.review.approve_clicked", branch = %review.branch);
+        review.set_busy();
+        let branch = review.branch.clone();
+        cx.notify();
+
+        cx.spawn(async move |this, cx: &mut gpui::AsyncApp| {
+            let branch_for_action = branch.clone();
+            let result = cx
+                .background_executor()
+                .spawn(async move { actions::approve_and_open_pr(branch_for_action) })
+                .await;
+
+            if let Err(e) = this.update(cx, |view, cx| {
+                let Some(review) = view.review.as_mut().filter(|r| r.branch == branch) else {
+                    return;
+                };
+                match result {
+                    Ok(url) => review.set_notice(format!("PR opened: {}", url)),
+                    Err(e) => review.set_failed(e),
+                }
+                cx.notify();
+            }) {
+                tracing::debug!(event = "ui.review.view_dropped", error = ?e);
+            }
+        })
+        .detach();
+    }
+
+    /// Replace the feedback input with an empty one.
+    fn reset_review_feedback(&mut self, window: &mut Window, cx: &mut Context<Self>) {
+        self.review_feedback_input =
+            Some(cx.new(|cx| InputState::new(window, cx).placeholder("Feedback for the agent...")));
+    }
+}
@@ -14,11 +14,14 @@ impl MainView {
         window: &mut Window,
         cx: &mut Context<Self>,
     ) -> bool {
-        match crate::terminal::state::Terminal::new(Some(worktree), cx) {
+        match crate::terminal::state::Terminal::new(Some(worktree.clone()), cx) {
             Ok(terminal) => {
                 let kb = self.keybindings.clone();
+                let hub = self.broadcast_hub.clone();
                 let view = cx.new(|cx| {
                     crate::terminal::TerminalView::from_terminal(terminal, kb, window, cx)
+                        .with_working_dir(Some(worktree))
+                        .with_broadcast(hub)
                 });
                 let tabs = self
                     .terminal_tabs
@@ -36,6 +39,15 @@ impl MainView {
         }
     }
 
+    /// Worktree of a kild, used to resolve `file:line` links in its terminals.
+    fn kild_worktree(&self, session_id: &str) -> Option<std::path::PathBuf> {
+        self.state
+            .displays()
+            .iter()
+            .find(|d| &*d.session.id == session_id)
+            .map(|d| d.session.worktree_path.clone())
+    }
+
     pub(super) fn focus_active_terminal(&self, window: &mut Window, cx: &gpui::App) {
         if let Some(view) = self.active_terminal_view() {
             let h = view.read(cx).focus_handle(cx).clone();
@@ -72,13 +84,16 @@ impl MainView {
         .detach();
     }
 
+    /// Attach a tab to a daemon session. `tab_key` is the kild session ID,
+    /// or a remote terminal key for sessions on `[[daemon.hosts]]`.
     pub(super) fn add_daemon_terminal_tab(
         &mut self,
-        kild_session_id: &str,
+        tab_key: &str,
+        endpoint: crate::daemon_client::DaemonEndpoint,
         daemon_session_id: &str,
         cx: &mut Context<Self>,
     ) {
-        let kild_id = kild_session_id.to_string();
+        let kild_id = tab_key.to_string();
         let daemon_id = daemon_session_id.to_string();
 
         cx.spawn(async move |this, cx: &mut gpui::AsyncApp| {
@@ -87,7 +102,8 @@ impl MainView {
                 .spawn({
                     let daemon_id = daemon_id.clone();
                     async move {
-                        crate::daemon_client::connect_for_attach(&daemon_id, 24, 80).await
+                        crate::daemon_client::connect_for_attach(&endpoint, &daemon_id, 24, 80)
+                            .await
                     }
                 })
                 .await
@@ -112,21 +128,30 @@ impl MainView {
             if let Err(e) = this.update(cx, |view, cx| {
                 let daemon_id_clone = daemon_id.clone();
                 let kb = view.keybindings.clone();
+                let hub = view.broadcast_hub.clone();
+                let worktree = view.kild_worktree(&kild_id);
+                let terminal_host = conn.endpoint().host_name().map(str::to_string);
                 match crate::terminal::state::Terminal::from_daemon(daemon_id.clone(), conn, cx) {
                     Ok(terminal) => {
                         let entity = cx.new(|cx| {
                             crate::terminal::TerminalView::from_terminal_unfocused(terminal, kb, cx)
+                                .with_working_dir(worktree)
+                                .with_broadcast(hub)
                         });
+                        let backend = match terminal_host {
+                            Some(host) => TerminalBackend::Remote {
+                                host,
+                                daemon_session_id: daemon_id_clone,
+                            },
+                            None => TerminalBackend::Daemon {
+                                daemon_session_id: daemon_id_clone,
+                            },
+                        };
                         let tabs = view
                             .terminal_tabs
                             .entry(kild_id.clone())
                             .or_default();
-                        tabs.push(
-                            entity,
-                            TerminalBackend::Daemon {
-                                daemon_session_id: daemon_id_clone,
-                            },
-                        );
+                        tabs.push(entity, backend);
                         view.active_terminal_id = Some(kild_id);
                         view.focus_region = FocusRegion::Terminal;
                     }
@@ -212,7 +237,13 @@ impl MainView {
                 .spawn({
                     let daemon_id = daemon_id.clone();
                     async move {
-                        crate::daemon_client::connect_for_attach(&daemon_id, 24, 80).await
+                        crate::daemon_client::connect_for_attach(
+                            &crate::daemon_client::DaemonEndpoint::Default,
+                            &daemon_id,
+                            24,
+                            80,
+                        )
+                        .await
                     }
                 })
                 .await
@@ -233,10 +264,14 @@ impl MainView {
                 let daemon_id_clone = daemon_id.clone();
                 let name = teammate_name.clone();
                 let kb = view.keybindings.clone();
+                let hub = view.broadcast_hub.clone();
+                let worktree = view.kild_worktree(&kild_id);
                 match crate::terminal::state::Terminal::from_daemon(daemon_id, conn, cx) {
                     Ok(terminal) => {
                         let entity = cx.new(|cx| {
                             crate::terminal::TerminalView::from_terminal_unfocused(terminal, kb, cx)
+                                .with_working_dir(worktree)
+                                .with_broadcast(hub)
                         });
                         let tabs = view.terminal_tabs.entry(kild_id.clone()).or_default();
                         tabs.push_teammate(entity, name, color, daemon_id_clone);
@@ -324,7 +359,12 @@ impl MainView {
         self.show_add_menu = false;
 
         if is_running && let Some(dsid) = daemon_session_id {
-            self.add_daemon_terminal_tab(session_id, &dsid, cx);
+            self.add_daemon_terminal_tab(
+                session_id,
+                crate::daemon_client::DaemonEndpoint::Default,
+                &dsid,
+                cx,
+            );
         } else {
             // No existing daemon session — create one on the fly
             let worktree_str = worktree.display().to_string();
@@ -346,7 +386,12 @@ impl MainView {
                 match result {
                     Ok(created_dsid) => {
                         if let Err(e) = this.update(cx, |view, cx| {
-                            view.add_daemon_terminal_tab(&kild_id_for_tab, &created_dsid, cx);
+                            view.add_daemon_terminal_tab(
+                                &kild_id_for_tab,
+                                crate::daemon_client::DaemonEndpoint::Default,
+                                &created_dsid,
+                                cx,
+                            );
                         }) {
                             tracing::debug!(event = "ui.on_add_daemon_tab.ok_view_dropped", error = ?e);
                         }
@@ -422,11 +467,22 @@ impl MainView {
     }
 
     pub(super) fn prune_terminal_cache(&mut self) {
+        // Remote tabs live as long as their host stays configured
+        let remote_keys: Vec<String> = self
+            .terminal_tabs
+            .keys()
+            .filter(|key| {
+                crate::state::remote_hosts::parse_terminal_key(key)
+                    .is_some_and(|(host, _)| self.remote_hosts.get(host).is_some())
+            })
+            .cloned()
+            .collect();
         let live_ids: std::collections::HashSet<&str> = self
             .state
             .displays()
             .iter()
             .map(|d| &*d.session.id)
+            .chain(remote_keys.iter().map(String::as_str))
             .collect();
 
         self.terminal_tabs
@@ -19,3 +19,12 @@ pub(crate) enum ActiveView {
     /// Kild detail drill-down (from dashboard card click).
     Detail,
 }
+
+/// Broadcast input as computed for the current frame.
+pub(crate) struct BroadcastStatus {
+    pub scope: crate::terminal::broadcast::BroadcastScope,
+    /// Number of terminals that receive input.
+    pub targets: usize,
+    /// Which pane slots of the active workspace receive input.
+    pub panes: Vec<bool>,
+}
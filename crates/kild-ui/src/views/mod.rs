@@ -4,17 +4,22 @@
 //! - `main_view` - Root view that composes header, sidebar, and dialogs
 //! - `dashboard_view` - Fleet overview with kild cards
 //! - `detail_view` - Kild drill-down from dashboard
+//! - `review_panel` - Diff and commit review for a kild, opened from the detail view
 //! - `status_bar` - Contextual alerts and keyboard shortcut hints
+//! - `activity_feed` - Drawer listing agent, CI and lifecycle activity
 //! - `create_dialog` - Modal dialog for creating new kilds
 //! - `confirm_dialog` - Modal dialog for confirming destructive actions
 //! - `add_project_dialog` - Modal dialog for adding new projects
+//! - `command_palette` - Fuzzy search over kilds and actions
 //! - `sidebar` - Fixed left sidebar for kild navigation
 //! - `project_rail` - Leftmost project switcher column
-//! - `pane_grid` - 2x2 terminal pane grid for Control view
+//! - `pane_grid` - Terminal pane grid (configurable layouts) for Control view
 //! - `terminal_tabs` - Multi-terminal tab management
 //! - `helpers` - Shared view utilities (time formatting, etc.)
 
+pub mod activity_feed;
 pub mod add_project_dialog;
+pub mod command_palette;
 pub mod confirm_dialog;
 pub mod create_dialog;
 pub mod dashboard_view;
@@ -23,6 +28,7 @@ pub mod helpers;
 pub mod main_view;
 pub mod pane_grid;
 pub mod project_rail;
+pub mod review_panel;
 pub mod sidebar;
 pub mod status_bar;
 pub mod terminal_tabs;
@@ -1,21 +1,21 @@
 //! Pane grid for the Control view.
 //!
-//! Provides a 2x2 grid of terminal panes. Each pane slot is either occupied
-//! (showing a terminal from a kild session) or empty (placeholder). Tracks
-//! focus order for LRU replacement when the grid is full.
+//! Lays out terminal panes according to a [`PaneLayout`] (2x2 by default;
+//! see `state::layouts`). Each pane slot is either occupied (showing a
+//! terminal from a kild session) or empty (placeholder). Tracks focus order
+//! for LRU replacement when the grid is full. Panes can be dragged onto each
+//! other by their header to rearrange them.
 
 use gpui::{IntoElement, SharedString, div, prelude::*, px};
 use gpui_component::resizable::{h_resizable, resizable_panel, v_resizable};
 
 use crate::components::Status;
+use crate::state::layouts::{PaneLayout, SavedSlot, SavedWorkspace, SplitNode};
 use crate::theme;
 use crate::views::main_view::MainView;
 use crate::views::terminal_tabs::TerminalTabs;
 use kild_core::ProcessStatus;
 
-/// Maximum number of pane slots in the grid.
-const SLOT_COUNT: usize = 4;
-
 /// A single slot in the pane grid.
 #[derive(Clone, Debug)]
 pub enum PaneSlot {
@@ -28,27 +28,152 @@ pub enum PaneSlot {
     Empty,
 }
 
-/// 2x2 pane grid tracking which terminals are visible and focus order.
+/// Pane grid tracking its layout, which terminals are visible and focus order.
 pub struct PaneGrid {
-    slots: [PaneSlot; SLOT_COUNT],
+    layout: PaneLayout,
+    /// One entry per pane of `layout`, in tree order.
+    slots: Vec<PaneSlot>,
     focused_slot: usize,
     maximized_slot: Option<usize>,
     /// Focus history: most-recently-focused at the end.
     focus_order: Vec<usize>,
+    /// Slots left out of broadcast input. The brain session starts excluded.
+    broadcast_excluded: Vec<bool>,
 }
 
 impl PaneGrid {
     pub fn new() -> Self {
+        Self::with_layout(PaneLayout::default())
+    }
+
+    /// An empty grid with the given layout.
+    pub fn with_layout(layout: PaneLayout) -> Self {
+        let count = layout.slot_count();
         Self {
-            slots: [
-                PaneSlot::Empty,
-                PaneSlot::Empty,
-                PaneSlot::Empty,
-                PaneSlot::Empty,
-            ],
+            layout,
+            slots: vec![PaneSlot::Empty; count],
             focused_slot: 0,
             maximized_slot: None,
             focus_order: Vec::new(),
+            broadcast_excluded: vec![false; count],
+        }
+    }
+
+    /// Rebuild a saved workspace. Slots whose session no longer exists in
+    /// `displays` are left empty.
+    pub fn from_saved(saved: &SavedWorkspace, displays: &[kild_core::SessionSnapshot]) -> Self {
+        let mut grid = Self::with_layout(saved.layout.clone());
+        for (idx, slot) in saved.slots.iter().enumerate().take(grid.slots.len()) {
+            let Some(slot) = slot else { continue };
+            let Some(display) = displays.iter().find(|d| *d.session.id == slot.session_id) else {
+                continue;
+            };
+            if grid.find_slot(&slot.session_id, slot.tab).is_some() {
+                continue;
+            }
+            grid.broadcast_excluded[idx] =
+                &*display.session.branch == kild_core::sessions::fleet::BRAIN_BRANCH;
+            grid.slots[idx] = PaneSlot::Occupied {
+                session_id: slot.session_id.clone(),
+                tab_idx: slot.tab,
+                kild_branch: display.session.branch.to_string(),
+                kild_status: process_status_to_status(display.process_status),
+            };
+        }
+        if let Some(first) = grid
+            .slots
+            .iter()
+            .position(|s| matches!(s, PaneSlot::Occupied { .. }))
+        {
+            grid.set_focus(first);
+        }
+        grid
+    }
+
+    /// Layout and slot contents, for the layouts file.
+    pub fn to_saved(&self) -> SavedWorkspace {
+        SavedWorkspace {
+            layout: self.layout.clone(),
+            slots: self
+                .slots
+                .iter()
+                .map(|slot| match slot {
+                    PaneSlot::Occupied {
+                        session_id,
+                        tab_idx,
+                        ..
+                    } => Some(SavedSlot {
+                        session_id: session_id.clone(),
+                        tab: *tab_idx,
+                    }),
+                    PaneSlot::Empty => None,
+                })
+                .collect(),
+        }
+    }
+
+    pub fn layout(&self) -> &PaneLayout {
+        &self.layout
+    }
+
+    /// Number of pane slots in the current layout.
+    pub fn slot_count(&self) -> usize {
+        self.slots.len()
+    }
+
+    /// Switch to another layout, keeping as many panes as fit.
+    ///
+    /// Panes keep their slot index when it still exists; panes beyond the
+    /// new slot count move into free slots in order, and the rest are dropped.
+    pub fn set_layout(&mut self, layout: PaneLayout) {
+        let count = layout.slot_count();
+        let mut overflow: Vec<(PaneSlot, bool)> = Vec::new();
+        if self.slots.len() > count {
+            overflow = self
+                .slots
+                .drain(count..)
+                .zip(self.broadcast_excluded.drain(count..))
+                .filter(|(slot, _)| matches!(slot, PaneSlot::Occupied { .. }))
+                .collect();
+        }
+        self.slots.resize(count, PaneSlot::Empty);
+        self.broadcast_excluded.resize(count, false);
+        for (slot, excluded) in overflow {
+            let Some(free) = self.slots.iter().position(|s| matches!(s, PaneSlot::Empty)) else {
+                break;
+            };
+            self.slots[free] = slot;
+            self.broadcast_excluded[free] = excluded;
+        }
+
+        self.layout = layout;
+        self.maximized_slot = self
+            .maximized_slot
+            .filter(|&idx| idx < count && matches!(self.slots[idx], PaneSlot::Occupied { .. }));
+        self.focus_order
+            .retain(|&idx| idx < count && matches!(self.slots[idx], PaneSlot::Occupied { .. }));
+        if self.focused_slot >= count || matches!(self.slots[self.focused_slot], PaneSlot::Empty) {
+            self.focused_slot = self.focus_order.last().copied().unwrap_or(0);
+        }
+    }
+
+    /// Exchange the contents of two slots (drag-to-rearrange). Focus and
+    /// maximize follow the panes.
+    pub fn swap(&mut self, a: usize, b: usize) {
+        if a == b || a >= self.slots.len() || b >= self.slots.len() {
+            return;
+        }
+        self.slots.swap(a, b);
+        self.broadcast_excluded.swap(a, b);
+        let remap = |idx: usize| match idx {
+            i if i == a => b,
+            i if i == b => a,
+            i => i,
+        };
+        self.focused_slot = remap(self.focused_slot);
+        self.maximized_slot = self.maximized_slot.map(remap);
+        for idx in &mut self.focus_order {
+            *idx = remap(*idx);
         }
     }
 
@@ -67,6 +192,7 @@ impl PaneGrid {
         }
         let empty = self.slots.iter().position(|s| matches!(s, PaneSlot::Empty));
         if let Some(idx) = empty {
+            self.broadcast_excluded[idx] = branch == kild_core::sessions::fleet::BRAIN_BRANCH;
             self.slots[idx] = PaneSlot::Occupied {
                 session_id,
                 tab_idx,
@@ -80,9 +206,16 @@ impl PaneGrid {
         }
     }
 
+    /// Point an occupied slot at another tab of its kild.
+    pub fn set_tab(&mut self, slot_idx: usize, tab: usize) {
+        if let Some(PaneSlot::Occupied { tab_idx, .. }) = self.slots.get_mut(slot_idx) {
+            *tab_idx = tab;
+        }
+    }
+
     /// Remove the terminal from a slot, making it empty.
     pub fn remove(&mut self, slot_idx: usize) {
-        if slot_idx < SLOT_COUNT {
+        if slot_idx < self.slots.len() {
             self.slots[slot_idx] = PaneSlot::Empty;
             self.focus_order.retain(|&i| i != slot_idx);
             if self.maximized_slot == Some(slot_idx) {
@@ -93,7 +226,7 @@ impl PaneGrid {
 
     /// Set focus to the given slot index. Only focuses occupied slots.
     pub fn set_focus(&mut self, slot_idx: usize) {
-        if slot_idx < SLOT_COUNT && matches!(self.slots[slot_idx], PaneSlot::Occupied { .. }) {
+        if matches!(self.slots.get(slot_idx), Some(PaneSlot::Occupied { .. })) {
             self.focused_slot = slot_idx;
             self.focus_order.retain(|&i| i != slot_idx);
             self.focus_order.push(slot_idx);
@@ -113,12 +246,40 @@ impl PaneGrid {
     pub fn toggle_maximize(&mut self, slot_idx: usize) {
         if self.maximized_slot == Some(slot_idx) {
             self.maximized_slot = None;
-        } else if slot_idx < SLOT_COUNT && matches!(self.slots[slot_idx], PaneSlot::Occupied { .. })
-        {
+        } else if matches!(self.slots.get(slot_idx), Some(PaneSlot::Occupied { .. })) {
             self.maximized_slot = Some(slot_idx);
         }
     }
 
+    /// Whether the slot is left out of broadcast input.
+    pub fn is_broadcast_excluded(&self, slot_idx: usize) -> bool {
+        self.broadcast_excluded
+            .get(slot_idx)
+            .copied()
+            .unwrap_or(true)
+    }
+
+    /// Include or exclude an occupied slot from broadcast input.
+    pub fn toggle_broadcast(&mut self, slot_idx: usize) {
+        if matches!(self.slots.get(slot_idx), Some(PaneSlot::Occupied { .. })) {
+            self.broadcast_excluded[slot_idx] = !self.broadcast_excluded[slot_idx];
+        }
+    }
+
+    /// Occupied slots that receive broadcast input.
+    pub fn broadcast_slots(&self) -> impl Iterator<Item = (usize, &PaneSlot)> {
+        self.slots.iter().enumerate().filter(|(idx, slot)| {
+            matches!(slot, PaneSlot::Occupied { .. }) && !self.broadcast_excluded[*idx]
+        })
+    }
+
+    /// Find a slot showing any tab of the given session.
+    pub fn find_session_slot(&self, session_id: &str) -> Option<usize> {
+        self.slots.iter().position(
+            |s| matches!(s, PaneSlot::Occupied { session_id: sid, .. } if sid == session_id),
+        )
+    }
+
     /// Find a slot containing the given session and tab index.
     pub fn find_slot(&self, session_id: &str, tab_idx: usize) -> Option<usize> {
         self.slots.iter().position(|s| {
@@ -143,16 +304,16 @@ impl PaneGrid {
             .expect("least_recently_focused called with no occupied slots")
     }
 
-    /// Get a reference to a slot. Panics if `idx >= SLOT_COUNT`.
+    /// Get a reference to a slot. Panics if `idx >= slot_count()`.
     pub fn slot(&self, idx: usize) -> &PaneSlot {
         self.slots
             .get(idx)
-            .expect("slot index must be < SLOT_COUNT")
+            .expect("slot index must be < slot_count()")
     }
 
     /// Get all slots as a slice (used in tests).
     #[cfg(test)]
-    pub fn slots(&self) -> &[PaneSlot; SLOT_COUNT] {
+    pub fn slots(&self) -> &[PaneSlot] {
         &self.slots
     }
 
@@ -189,7 +350,7 @@ impl PaneGrid {
     /// Remove any slots referencing sessions not in the live set.
     /// Updates focused_slot if the focused slot was pruned.
     pub fn prune(&mut self, live_ids: &std::collections::HashSet<&str>) {
-        for idx in 0..SLOT_COUNT {
+        for idx in 0..self.slots.len() {
             if let PaneSlot::Occupied { session_id, .. } = &self.slots[idx]
                 && !live_ids.contains(session_id.as_str())
             {
@@ -206,8 +367,9 @@ impl PaneGrid {
 
     /// Return the next occupied slot after the focused one (wrapping).
     pub fn next_occupied_slot(&self) -> Option<usize> {
-        for offset in 1..SLOT_COUNT {
-            let idx = (self.focused_slot + offset) % SLOT_COUNT;
+        let count = self.slots.len();
+        for offset in 1..count {
+            let idx = (self.focused_slot + offset) % count;
             if matches!(self.slots[idx], PaneSlot::Occupied { .. }) {
                 return Some(idx);
             }
@@ -220,59 +382,110 @@ impl PaneGrid {
 // Rendering
 // ---------------------------------------------------------------------------
 
-/// Render the 2x2 pane grid (or maximized single pane).
+/// Payload carried while a pane is dragged by its header.
+#[derive(Clone)]
+pub struct PaneDrag {
+    slot_idx: usize,
+    branch: SharedString,
+}
+
+impl gpui::Render for PaneDrag {
+    fn render(
+        &mut self,
+        _window: &mut gpui::Window,
+        _cx: &mut gpui::Context<Self>,
+    ) -> impl IntoElement {
+        div()
+            .px(px(theme::SPACE_2))
+            .py(px(2.0))
+            .bg(theme::surface())
+            .border_1()
+            .border_color(theme::ice_dim())
+            .rounded(px(theme::RADIUS_SM))
+            .text_size(px(theme::TEXT_XS))
+            .text_color(theme::text_bright())
+            .child(self.branch.clone())
+    }
+}
+
+/// Render the pane grid following its layout (or the maximized single pane).
+///
+/// `broadcast` is set while broadcast input is on and marks the panes that
+/// receive it.
 pub fn render_pane_grid(
     pane_grid: &PaneGrid,
     terminal_tabs: &std::collections::HashMap<String, TerminalTabs>,
+    broadcast: Option<&[bool]>,
     cx: &mut gpui::Context<MainView>,
 ) -> impl IntoElement {
-    if let Some(max_idx) = pane_grid.maximized_slot() {
+    let content = if let Some(max_idx) = pane_grid.maximized_slot() {
         // Maximized: single pane full-size
-        div()
-            .flex_1()
-            .overflow_hidden()
-            .child(render_pane_cell(max_idx, pane_grid, terminal_tabs, cx))
-            .into_any_element()
+        render_pane_cell(max_idx, pane_grid, terminal_tabs, broadcast, cx).into_any_element()
     } else {
-        // 2x2 grid using resizable panels
-        div()
-            .flex_1()
-            .overflow_hidden()
-            .child(
-                v_resizable("pane-grid-v")
-                    .child(
-                        h_resizable("pane-grid-row-0")
-                            .child(resizable_panel().child(render_pane_cell(
-                                0,
-                                pane_grid,
-                                terminal_tabs,
-                                cx,
-                            )))
-                            .child(resizable_panel().child(render_pane_cell(
-                                1,
-                                pane_grid,
-                                terminal_tabs,
-                                cx,
-                            ))),
-                    )
-                    .child(
-                        h_resizable("pane-grid-row-1")
-                            .child(resizable_panel().child(render_pane_cell(
-                                2,
-                                pane_grid,
-                                terminal_tabs,
-                                cx,
-                            )))
-                            .child(resizable_panel().child(render_pane_cell(
-                                3,
-                                pane_grid,
-                                terminal_tabs,
-                                cx,
-                            ))),
-                    ),
-            )
-            .into_any_element()
+        // Resizable splits keyed by layout so sizes reset when it changes
+        let mut next_slot = 0;
+        render_split(
+            &pane_grid.layout().tree(),
+            &format!("pane-split-{}", pane_grid.layout().label()),
+            &mut next_slot,
+            pane_grid,
+            terminal_tabs,
+            broadcast,
+            cx,
+        )
+    };
+    div().flex_1().overflow_hidden().child(content)
+}
+
+/// Render one node of the layout tree. Panes are numbered in tree order via
+/// `next_slot`; `path` makes the resizable group ids unique.
+fn render_split(
+    node: &SplitNode,
+    path: &str,
+    next_slot: &mut usize,
+    pane_grid: &PaneGrid,
+    terminal_tabs: &std::collections::HashMap<String, TerminalTabs>,
+    broadcast: Option<&[bool]>,
+    cx: &mut gpui::Context<MainView>,
+) -> gpui::AnyElement {
+    let (group, children) = match node {
+        SplitNode::Pane => {
+            let slot_idx = *next_slot;
+            *next_slot += 1;
+            return render_pane_cell(slot_idx, pane_grid, terminal_tabs, broadcast, cx)
+                .into_any_element();
+        }
+        SplitNode::Row(children) => (h_resizable(SharedString::from(path.to_string())), children),
+        SplitNode::Column(children) => {
+            (v_resizable(SharedString::from(path.to_string())), children)
+        }
+    };
+    let mut group = group;
+    for (i, child) in children.iter().enumerate() {
+        let child_path = format!("{path}-{i}");
+        group = group.child(resizable_panel().child(render_split(
+            child,
+            &child_path,
+            next_slot,
+            pane_grid,
+            terminal_tabs,
+            broadcast,
+            cx,
+        )));
     }
+    group.into_any_element()
+}
+
+/// Make a pane cell a drop target for panes dragged by their header.
+fn pane_drop_target(
+    cell: gpui::Stateful<gpui::Div>,
+    slot_idx: usize,
+    cx: &mut gpui::Context<MainView>,
+) -> gpui::Stateful<gpui::Div> {
+    cell.drag_over::<PaneDrag>(|style, _, _, _| style.border_color(theme::ice()))
+        .on_drop(cx.listener(move |view, drag: &PaneDrag, _window, cx| {
+            view.on_pane_swap(drag.slot_idx, slot_idx, cx);
+        }))
 }
 
 /// Render a single pane cell (occupied or empty).
@@ -280,9 +493,12 @@ fn render_pane_cell(
     slot_idx: usize,
     pane_grid: &PaneGrid,
     terminal_tabs: &std::collections::HashMap<String, TerminalTabs>,
+    broadcast: Option<&[bool]>,
     cx: &mut gpui::Context<MainView>,
 ) -> impl IntoElement {
     let is_focused = pane_grid.focused_slot() == slot_idx;
+    // None when broadcast is off; otherwise whether this pane receives input.
+    let receives_broadcast = broadcast.map(|panes| panes.get(slot_idx).copied().unwrap_or(false));
     let is_maximized = pane_grid.maximized_slot() == Some(slot_idx);
     let group_name: SharedString = format!("pane-{slot_idx}").into();
 
@@ -304,6 +520,7 @@ fn render_pane_cell(
                 *kild_status,
                 is_focused,
                 is_maximized,
+                receives_broadcast,
                 group_name.clone(),
                 cx,
             );
@@ -316,14 +533,25 @@ fn render_pane_cell(
                 .flex_col()
                 .overflow_hidden()
                 .border_1()
-                .border_color(if is_focused {
-                    theme::ice_dim()
-                } else {
-                    theme::border_subtle()
+                .border_color(match (receives_broadcast, is_focused) {
+                    (Some(true), _) => theme::ember(),
+                    (_, true) => theme::ice_dim(),
+                    _ => theme::border_subtle(),
                 })
                 .child(header)
-                .when_some(terminal_view, |this, view| {
-                    this.child(div().flex_1().overflow_hidden().child(view))
+                .map(|this| match terminal_view {
+                    Some(view) => this.child(div().flex_1().overflow_hidden().child(view)),
+                    // Restored from the layouts file before its terminal was opened
+                    None => this.child(
+                        div()
+                            .flex_1()
+                            .flex()
+                            .items_center()
+                            .justify_center()
+                            .text_size(px(theme::TEXT_SM))
+                            .text_color(theme::text_subtle())
+                            .child("Click to open terminal"),
+                    ),
                 })
                 .on_mouse_down(
                     gpui::MouseButton::Left,
@@ -332,25 +560,36 @@ fn render_pane_cell(
                     }),
                 );
 
-            cell.into_any_element()
+            pane_drop_target(cell, slot_idx, cx).into_any_element()
+        }
+        PaneSlot::Empty => {
+            pane_drop_target(render_empty_slot(slot_idx, cx), slot_idx, cx).into_any_element()
         }
-        PaneSlot::Empty => render_empty_slot(slot_idx, cx).into_any_element(),
     }
 }
 
 /// Render the header bar for an occupied pane.
+#[allow(clippy::too_many_arguments)]
 fn render_pane_header(
     slot_idx: usize,
     branch: &str,
     status: Status,
     is_focused: bool,
     is_maximized: bool,
+    receives_broadcast: Option<bool>,
     group_name: SharedString,
     cx: &mut gpui::Context<MainView>,
 ) -> impl IntoElement {
     let maximize_label = if is_maximized { "Restore" } else { "Maximize" };
+    let drag = PaneDrag {
+        slot_idx,
+        branch: SharedString::from(branch.to_string()),
+    };
 
     div()
+        .id(SharedString::from(format!("pane-header-{slot_idx}")))
+        .on_drag(drag, |drag, _offset, _window, cx| cx.new(|_| drag.clone()))
+        .cursor_grab()
         .flex()
         .items_center()
         .justify_between()
@@ -386,49 +625,85 @@ fn render_pane_header(
                         .child(SharedString::from(branch.to_string())),
                 ),
         )
-        // Right: maximize + close buttons (visible on hover)
+        // Right: broadcast toggle (while broadcasting), then maximize + close
+        // buttons (visible on hover)
         .child(
             div()
                 .flex()
                 .items_center()
                 .gap(px(theme::SPACE_1))
-                .opacity(0.0)
-                .group_hover(group_name.clone(), |s| s.opacity(1.0))
-                .child(
-                    div()
-                        .id(SharedString::from(format!("pane-max-{slot_idx}")))
-                        .cursor_pointer()
-                        .text_size(px(theme::TEXT_XS))
-                        .text_color(theme::text_muted())
-                        .hover(|s| s.text_color(theme::text()))
-                        .on_mouse_down(
-                            gpui::MouseButton::Left,
-                            cx.listener(move |view, _, _window, cx| {
-                                view.on_pane_maximize(slot_idx, cx);
+                .when_some(receives_broadcast, |this, receives| {
+                    this.child(
+                        div()
+                            .id(SharedString::from(format!("pane-broadcast-{slot_idx}")))
+                            .cursor_pointer()
+                            .text_size(px(theme::TEXT_XS))
+                            .text_color(if receives {
+                                theme::ember()
+                            } else {
+                                theme::text_muted()
+                            })
+                            .hover(|s| s.text_color(theme::text()))
+                            .on_mouse_down(
+                                gpui::MouseButton::Left,
+                                cx.listener(move |view, _, _window, cx| {
+                                    view.on_pane_broadcast_toggle(slot_idx, cx);
+                                    cx.stop_propagation();
+                                }),
+                            )
+                            .child(if receives {
+                                "Broadcast on"
+                            } else {
+                                "Broadcast off"
                             }),
-                        )
-                        .child(maximize_label),
-                )
+                    )
+                })
                 .child(
                     div()
-                        .id(SharedString::from(format!("pane-close-{slot_idx}")))
-                        .cursor_pointer()
-                        .text_size(px(theme::TEXT_XS))
-                        .text_color(theme::text_muted())
-                        .hover(|s| s.text_color(theme::ember()))
-                        .on_mouse_down(
-                            gpui::MouseButton::Left,
-                            cx.listener(move |view, _, _window, cx| {
-                                view.on_pane_close(slot_idx, cx);
-                            }),
+                        .flex()
+                        .items_center()
+                        .gap(px(theme::SPACE_1))
+                        .opacity(0.0)
+                        .group_hover(group_name.clone(), |s| s.opacity(1.0))
+                        .child(
+                            div()
+                                .id(SharedString::from(format!("pane-max-{slot_idx}")))
+                                .cursor_pointer()
+                                .text_size(px(theme::TEXT_XS))
+                                .text_color(theme::text_muted())
+                                .hover(|s| s.text_color(theme::text()))
+                                .on_mouse_down(
+                                    gpui::MouseButton::Left,
+                                    cx.listener(move |view, _, _window, cx| {
+                                        view.on_pane_maximize(slot_idx, cx);
+                                    }),
+                                )
+                                .child(maximize_label),
                         )
-                        .child("\u{00D7}"), // × symbol
+                        .child(
+                            div()
+                                .id(SharedString::from(format!("pane-close-{slot_idx}")))
+                                .cursor_pointer()
+                                .text_size(px(theme::TEXT_XS))
+                                .text_color(theme::text_muted())
+                                .hover(|s| s.text_color(theme::ember()))
+                                .on_mouse_down(
+                                    gpui::MouseButton::Left,
+                                    cx.listener(move |view, _, _window, cx| {
+                                        view.on_pane_close(slot_idx, cx);
+                                    }),
+                                )
+                                .child("\u{00D7}"), // × symbol
+                        ),
                 ),
         )
 }
 
 /// Render an empty pane slot with placeholder text.
-fn render_empty_slot(slot_idx: usize, _cx: &mut gpui::Context<MainView>) -> impl IntoElement {
+fn render_empty_slot(
+    slot_idx: usize,
+    _cx: &mut gpui::Context<MainView>,
+) -> gpui::Stateful<gpui::Div> {
     div()
         .id(SharedString::from(format!("pane-empty-{slot_idx}")))
         .flex_1()
@@ -692,6 +967,42 @@ mod tests {
         assert!(matches!(grid.slot(0), PaneSlot::Occupied { .. }));
     }
 
+    #[test]
+    fn test_brain_pane_starts_excluded_from_broadcast() {
+        let mut grid = PaneGrid::new();
+        grid.add_terminal("s1".into(), 0, "auth".into(), Status::Active);
+        grid.add_terminal("s2".into(), 0, "honryu".into(), Status::Active);
+
+        assert!(!grid.is_broadcast_excluded(0));
+        assert!(grid.is_broadcast_excluded(1));
+        let slots: Vec<usize> = grid.broadcast_slots().map(|(idx, _)| idx).collect();
+        assert_eq!(slots, vec![0]);
+
+        // The brain can be opted in; a pane placed in an excluded slot starts included.
+        grid.toggle_broadcast(1);
+        assert_eq!(grid.broadcast_slots().count(), 2);
+        grid.toggle_broadcast(0);
+        grid.remove(0);
+        grid.add_terminal("s3".into(), 0, "api".into(), Status::Active);
+        assert!(!grid.is_broadcast_excluded(0));
+    }
+
+    #[test]
+    fn test_toggle_broadcast_empty_slot_is_noop() {
+        let mut grid = PaneGrid::new();
+        grid.toggle_broadcast(2);
+        assert!(!grid.is_broadcast_excluded(2));
+        assert_eq!(grid.broadcast_slots().count(), 0);
+    }
+
+    #[test]
+    fn test_find_session_slot_matches_any_tab() {
+        let mut grid = PaneGrid::new();
+        grid.add_terminal("s1".into(), 2, "auth".into(), Status::Active);
+        assert_eq!(grid.find_session_slot("s1"), Some(0));
+        assert_eq!(grid.find_session_slot("s2"), None);
+    }
+
     #[test]
     fn test_remove_maximized_slot_clears_maximize() {
         let mut grid = PaneGrid::new();
@@ -702,4 +1013,115 @@ mod tests {
         grid.remove(0);
         assert_eq!(grid.maximized_slot(), None);
     }
+
+    fn make_display(id: &str, branch: &str) -> kild_core::SessionSnapshot {
+        use kild_core::sessions::types::SessionStatus;
+        kild_core::SessionSnapshot {
+            session: kild_core::Session::new(
+                id.into(),
+                "test-project".into(),
+                branch.into(),
+                std::path::PathBuf::from("/tmp/test"),
+                "claude".to_string(),
+                SessionStatus::Active,
+                "2024-01-01T00:00:00Z".to_string(),
+                0,
+                0,
+                0,
+                None,
+                None,
+                None,
+                vec![],
+                None,
+                None,
+                None,
+            ),
+            process_status: ProcessStatus::Running,
+            git_status: kild_core::GitStatus::Unknown,
+            uncommitted_diff: None,
+        }
+    }
+
+    #[test]
+    fn test_with_layout_sizes_slots() {
+        let grid = PaneGrid::with_layout(PaneLayout::Grid { rows: 3, cols: 3 });
+        assert_eq!(grid.slot_count(), 9);
+        assert_eq!(
+            PaneGrid::with_layout(PaneLayout::MainStack { stack: 3 }).slot_count(),
+            4
+        );
+    }
+
+    #[test]
+    fn test_set_layout_shrink_compacts_occupied_slots() {
+        let mut grid = PaneGrid::new();
+        grid.add_terminal("s0".into(), 0, "a".into(), Status::Active);
+        grid.add_terminal("s1".into(), 0, "b".into(), Status::Active);
+        grid.add_terminal("s2".into(), 0, "c".into(), Status::Active);
+        grid.add_terminal("s3".into(), 0, "d".into(), Status::Active);
+        grid.remove(0);
+        grid.toggle_maximize(3);
+
+        grid.set_layout(PaneLayout::Grid { rows: 1, cols: 2 });
+        assert_eq!(grid.slot_count(), 2);
+        // s3 moved into the freed slot 0; s2 no longer fits.
+        assert_eq!(grid.find_slot("s3", 0), Some(0));
+        assert_eq!(grid.find_slot("s1", 0), Some(1));
+        assert_eq!(grid.find_slot("s2", 0), None);
+        assert_eq!(grid.maximized_slot(), None);
+        assert!(grid.focused_slot() < 2);
+    }
+
+    #[test]
+    fn test_set_layout_grow_adds_empty_slots() {
+        let mut grid = PaneGrid::new();
+        grid.add_terminal("s0".into(), 0, "a".into(), Status::Active);
+        grid.set_layout(PaneLayout::Grid { rows: 3, cols: 3 });
+        assert_eq!(grid.slot_count(), 9);
+        assert_eq!(grid.find_slot("s0", 0), Some(0));
+        assert!(
+            grid.slots()[1..]
+                .iter()
+                .all(|s| matches!(s, PaneSlot::Empty))
+        );
+    }
+
+    #[test]
+    fn test_swap_moves_focus_and_broadcast_flags() {
+        let mut grid = PaneGrid::new();
+        grid.add_terminal("s0".into(), 0, "honryu".into(), Status::Active);
+        grid.add_terminal("s1".into(), 0, "b".into(), Status::Active);
+        grid.set_focus(0);
+
+        grid.swap(0, 3);
+        assert_eq!(grid.find_slot("s0", 0), Some(3));
+        assert!(matches!(grid.slot(0), PaneSlot::Empty));
+        assert_eq!(grid.focused_slot(), 3);
+        assert!(grid.is_broadcast_excluded(3));
+        assert!(!grid.is_broadcast_excluded(0));
+
+        grid.swap(3, 99); // out of bounds: no-op
+        assert_eq!(grid.find_slot("s0", 0), Some(3));
+    }
+
+    #[test]
+    fn test_saved_round_trip_skips_missing_sessions() {
+        let mut grid = PaneGrid::with_layout(PaneLayout::MainStack { stack: 2 });
+        grid.add_terminal("s0".into(), 1, "a".into(), Status::Active);
+        grid.add_terminal("gone".into(), 0, "b".into(), Status::Active);
+        grid.swap(0, 2);
+        let saved = grid.to_saved();
+        assert_eq!(saved.slots.len(), 3);
+
+        let displays = vec![make_display("s0", "renamed")];
+        let restored = PaneGrid::from_saved(&saved, &displays);
+        assert_eq!(restored.layout(), &PaneLayout::MainStack { stack: 2 });
+        assert_eq!(restored.find_slot("s0", 1), Some(2));
+        assert_eq!(restored.find_session_slot("gone"), None);
+        assert_eq!(restored.focused_slot(), 2);
+        assert!(matches!(
+            restored.slot(2),
+            PaneSlot::Occupied { kild_branch, .. } if kild_branch == "renamed"
+        ));
+    }
 }
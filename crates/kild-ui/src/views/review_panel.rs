@@ -0,0 +1,510 @@
+//! Review panel: a kild's changes versus its base branch.
+//!
+//! Lists changed files and commits on the left and the selected file's diff
+//! (unified or side by side, syntax colored) on the right, with actions to
+//! approve and open a PR, send feedback to the agent, or discard a file.
+//! The model is `state::review::ReviewState`.
+
+use gpui::{
+    AnyElement, Context, Entity, HighlightStyle, IntoElement, ParentElement, SharedString, Styled,
+    StyledText, div, prelude::*, px,
+};
+
+use gpui_component::Disableable;
+use gpui_component::button::{Button, ButtonVariants};
+use gpui_component::input::{Input, InputState};
+use kild_core::git::types::{DiffLine, DiffLineKind, FileChangeKind, ReviewFile};
+
+use crate::state::review::{DiffMode, ReviewState, split_rows};
+use crate::state::syntax::{Language, TokenKind, highlight};
+use crate::theme;
+use crate::views::main_view::MainView;
+
+/// Width of the file and commit list.
+const LIST_WIDTH: f32 = 260.0;
+
+/// Width of a line number gutter cell.
+const GUTTER_WIDTH: f32 = 40.0;
+
+/// Render the review panel for `review`.
+pub fn render_review_panel(
+    review: &ReviewState,
+    feedback_input: Option<&Entity<InputState>>,
+    cx: &mut Context<MainView>,
+) -> AnyElement {
+    let busy = review.is_busy();
+    let mode_label = match review.mode() {
+        DiffMode::Unified => "Side by side",
+        DiffMode::SideBySide => "Unified",
+    };
+    let subtitle = match (review.base_commit(), review.is_loading()) {
+        (_, true) => "Loading changes...".to_string(),
+        (Some(base), false) => format!(
+            "{} files changed since {} \u{00b7} {} commits",
+            review.files().len(),
+            base,
+            review.commits().len()
+        ),
+        (None, false) => String::new(),
+    };
+
+    div()
+        .flex_1()
+        .flex()
+        .flex_col()
+        .overflow_hidden()
+        // Header
+        .child(
+            div()
+                .flex()
+                .items_center()
+                .gap(px(theme::SPACE_3))
+                .px(px(theme::SPACE_4))
+                .py(px(theme::SPACE_2))
+                .border_b_1()
+                .border_color(theme::border_subtle())
+                .child(
+                    div()
+                        .id("review-back")
+                        .text_size(px(theme::TEXT_XS))
+                        .text_color(theme::text_muted())
+                        .cursor_pointer()
+                        .hover(|d| d.text_color(theme::text_subtle()))
+                        .on_mouse_up(
+                            gpui::MouseButton::Left,
+                            cx.listener(|view, _, _, cx| view.on_review_close(cx)),
+                        )
+                        .child("\u{2190} Details"),
+                )
+                .child(
+                    div()
+                        .flex_1()
+                        .flex()
+                        .flex_col()
+                        .child(
+                            div()
+                                .text_size(px(theme::TEXT_MD))
+                                .font_weight(gpui::FontWeight::SEMIBOLD)
+                                .text_color(theme::text_white())
+                                .child(format!("Review {}", review.branch)),
+                        )
+                        .child(
+                            div()
+                                .text_size(px(theme::TEXT_XS))
+                                .text_color(theme::text_muted())
+                                .child(subtitle),
+                        ),
+                )
+                .child(
+                    Button::new("review-mode")
+                        .label(mode_label)
+                        .ghost()
+                        .on_click(cx.listener(|view, _, _, cx| view.on_review_toggle_mode(cx))),
+                )
+                .child(
+                    Button::new("review-refresh")
+                        .label("Refresh")
+                        .ghost()
+                        .disabled(review.is_loading())
+                        .on_click(cx.listener(|view, _, _, cx| view.reload_review(cx))),
+                )
+                .child(
+                    Button::new("review-approve")
+                        .label("Approve & open PR")
+                        .success()
+                        .disabled(busy)
+                        .on_click(cx.listener(|view, _, _, cx| view.on_review_approve(cx))),
+                ),
+        )
+        // Outcome of the last load or action
+        .when_some(review.error().map(str::to_string), |this, error| {
+            this.child(render_banner(error, theme::ember()))
+        })
+        .when_some(review.notice().map(str::to_string), |this, notice| {
+            this.child(render_banner(notice, theme::aurora()))
+        })
+        // Body: lists + diff
+        .child(
+            div()
+                .flex_1()
+                .flex()
+                .overflow_hidden()
+                .child(render_lists(review, cx))
+                .child(render_file_diff(review, cx)),
+        )
+        // Feedback
+        .child(
+            div()
+                .flex()
+                .items_center()
+                .gap(px(theme::SPACE_2))
+                .px(px(theme::SPACE_4))
+                .py(px(theme::SPACE_2))
+                .border_t_1()
+                .border_color(theme::border_subtle())
+                .child(
+                    div()
+                        .flex_1()
+                        .when_some(feedback_input, |this, input| this.child(Input::new(input))),
+                )
+                .child(
+                    Button::new("review-feedback")
+                        .label("Send feedback")
+                        .primary()
+                        .disabled(busy)
+                        .on_click(cx.listener(|view, _, window, cx| {
+                            view.on_review_send_feedback(window, cx);
+                        })),
+                ),
+        )
+        .into_any_element()
+}
+
+fn render_banner(message: String, color: gpui::Rgba) -> impl IntoElement {
+    div()
+        .px(px(theme::SPACE_4))
+        .py(px(theme::SPACE_1))
+        .bg(theme::with_alpha(color, theme::GLOW_ALPHA))
+        .text_size(px(theme::TEXT_XS))
+        .text_color(color)
+        .child(message)
+}
+
+/// Changed files, then commits.
+fn render_lists(review: &ReviewState, cx: &mut Context<MainView>) -> impl IntoElement {
+    let selected = review.selected_index();
+    let files = review.files().iter().enumerate().map(|(idx, file)| {
+        let (letter, color) = change_badge(file.change.kind);
+        let is_selected = idx == selected;
+        div()
+            .id(SharedString::from(format!("review-file-{}", idx)))
+            .flex()
+            .items_center()
+            .gap(px(theme::SPACE_2))
+            .px(px(theme::SPACE_2))
+            .py(px(theme::SPACE_1))
+            .rounded(px(theme::RADIUS_SM))
+            .cursor_pointer()
+            .when(is_selected, |d| d.bg(theme::elevated()))
+            .hover(|d| d.bg(theme::surface()))
+            .on_mouse_up(
+                gpui::MouseButton::Left,
+                cx.listener(move |view, _, _, cx| view.on_review_select(idx, cx)),
+            )
+            .child(
+                div()
+                    .text_size(px(theme::TEXT_XS))
+                    .font_weight(gpui::FontWeight::SEMIBOLD)
+                    .text_color(color)
+                    .child(letter),
+            )
+            .child(
+                div()
+                    .flex_1()
+                    .overflow_hidden()
+                    .whitespace_nowrap()
+                    .text_ellipsis()
+                    .text_size(px(theme::TEXT_XS))
+                    .text_color(if is_selected {
+                        theme::text_white()
+                    } else {
+                        theme::text()
+                    })
+                    .child(file.path.clone()),
+            )
+            .child(
+                div()
+                    .text_size(px(theme::TEXT_XXS))
+                    .text_color(theme::aurora())
+                    .child(format!("+{}", file.change.insertions)),
+            )
+            .child(
+                div()
+                    .text_size(px(theme::TEXT_XXS))
+                    .text_color(theme::ember())
+                    .child(format!("-{}", file.change.deletions)),
+            )
+    });
+
+    let commits = review.commits().iter().map(|commit| {
+        div()
+            .flex()
+            .gap(px(theme::SPACE_2))
+            .px(px(theme::SPACE_2))
+            .py(px(theme::SPACE_HALF))
+            .text_size(px(theme::TEXT_XS))
+            .child(
+                div()
+                    .font_family(theme::mono_font_family())
+                    .text_color(theme::ice())
+                    .child(commit.sha.clone()),
+            )
+            .child(
+                div()
+                    .flex_1()
+                    .overflow_hidden()
+                    .whitespace_nowrap()
+                    .text_ellipsis()
+                    .text_color(theme::text_subtle())
+                    .child(commit.summary.clone()),
+            )
+    });
+
+    div()
+        .id("review-lists")
+        .w(px(LIST_WIDTH))
+        .flex_shrink_0()
+        .flex()
+        .flex_col()
+        .gap(px(2.0))
+        .p(px(theme::SPACE_2))
+        .border_r_1()
+        .border_color(theme::border_subtle())
+        .overflow_y_scroll()
+        .child(list_title(format!("Files ({})", review.files().len())))
+        .when(review.files().is_empty() && !review.is_loading(), |this| {
+            this.child(empty_note("No changes versus the base branch"))
+        })
+        .children(files)
+        .child(
+            div()
+                .mt(px(theme::SPACE_3))
+                .child(list_title(format!("Commits ({})", review.commits().len()))),
+        )
+        .when(
+            review.commits().is_empty() && !review.is_loading(),
+            |this| this.child(empty_note("No commits")),
+        )
+        .children(commits)
+}
+
+fn list_title(title: String) -> impl IntoElement {
+    div()
+        .px(px(theme::SPACE_2))
+        .py(px(theme::SPACE_1))
+        .text_size(px(theme::TEXT_XS))
+        .font_weight(gpui::FontWeight::SEMIBOLD)
+        .text_color(theme::text_muted())
+        .child(title.to_uppercase())
+}
+
+fn empty_note(text: &'static str) -> impl IntoElement {
+    div()
+        .px(px(theme::SPACE_2))
+        .text_size(px(theme::TEXT_XS))
+        .text_color(theme::text_muted())
+        .child(text)
+}
+
+fn change_badge(kind: FileChangeKind) -> (&'static str, gpui::Rgba) {
+    match kind {
+        FileChangeKind::Added => ("A", theme::aurora()),
+        FileChangeKind::Modified => ("M", theme::copper()),
+        FileChangeKind::Deleted => ("D", theme::ember()),
+    }
+}
+
+/// The selected file's header and diff.
+fn render_file_diff(review: &ReviewState, cx: &mut Context<MainView>) -> AnyElement {
+    let Some(file) = review.selected_file() else {
+        return div().flex_1().into_any_element();
+    };
+    let path = file.path.clone();
+    let discard_pending = review.is_discard_pending(&path);
+    let language = Language::from_path(&file.path);
+
+    let body: Vec<AnyElement> = if file.binary {
+        vec![empty_note("Binary file").into_any_element()]
+    } else {
+        match review.mode() {
+            DiffMode::Unified => render_unified(file, language),
+            DiffMode::SideBySide => render_side_by_side(file, language),
+        }
+    };
+
+    div()
+        .flex_1()
+        .flex()
+        .flex_col()
+        .overflow_hidden()
+        .child(
+            div()
+                .flex()
+                .items_center()
+                .gap(px(theme::SPACE_2))
+                .px(px(theme::SPACE_3))
+                .py(px(theme::SPACE_1))
+                .border_b_1()
+                .border_color(theme::border_subtle())
+                .child(
+                    div()
+                        .flex_1()
+                        .font_family(theme::mono_font_family())
+                        .text_size(px(theme::TEXT_XS))
+                        .text_color(theme::text_bright())
+                        .child(path.clone()),
+                )
+                .child(
+                    Button::new("review-discard")
+                        .label(if discard_pending {
+                            "Click again to discard"
+                        } else {
+                            "Discard file"
+                        })
+                        .danger()
+                        .disabled(review.is_busy())
+                        .on_click(cx.listener(move |view, _, _, cx| {
+                            view.on_review_discard(&path, cx);
+                        })),
+                ),
+        )
+        .child(
+            div()
+                .id("review-diff")
+                .flex_1()
+                .flex()
+                .flex_col()
+                .overflow_scroll()
+                .py(px(theme::SPACE_1))
+                .font_family(theme::mono_font_family())
+                .text_size(px(theme::mono_font_size()))
+                .children(body)
+                .when(file.truncated, |this| {
+                    this.child(
+                        div()
+                            .px(px(theme::SPACE_3))
+                            .py(px(theme::SPACE_2))
+                            .text_size(px(theme::TEXT_XS))
+                            .text_color(theme::copper())
+                            .child("Diff truncated. Open the file in an editor to see the rest."),
+                    )
+                }),
+        )
+        .into_any_element()
+}
+
+fn render_unified(file: &ReviewFile, language: Language) -> Vec<AnyElement> {
+    let mut rows = Vec::new();
+    for hunk in &file.hunks {
+        rows.push(hunk_header(&hunk.header));
+        for line in &hunk.lines {
+            rows.push(
+                div()
+                    .flex()
+                    .bg(line_background(line.kind))
+                    .child(gutter(line.old_lineno))
+                    .child(gutter(line.new_lineno))
+                    .child(sign(line.kind))
+                    .child(code(line, language))
+                    .into_any_element(),
+            );
+        }
+    }
+    rows
+}
+
+fn render_side_by_side(file: &ReviewFile, language: Language) -> Vec<AnyElement> {
+    let mut rows = Vec::new();
+    for hunk in &file.hunks {
+        rows.push(hunk_header(&hunk.header));
+        for row in split_rows(hunk) {
+            rows.push(
+                div()
+                    .flex()
+                    .child(split_cell(row.left, |l| l.old_lineno, language))
+                    .child(div().w(px(1.0)).bg(theme::border_subtle()))
+                    .child(split_cell(row.right, |l| l.new_lineno, language))
+                    .into_any_element(),
+            );
+        }
+    }
+    rows
+}
+
+/// One side of a side-by-side row; context lines are uncolored on both sides.
+fn split_cell(
+    line: Option<&DiffLine>,
+    lineno: fn(&DiffLine) -> Option<u32>,
+    language: Language,
+) -> impl IntoElement {
+    let cell = div().flex_1().flex().overflow_hidden();
+    match line {
+        Some(line) => cell
+            .bg(line_background(line.kind))
+            .child(gutter(lineno(line)))
+            .child(code(line, language)),
+        None => cell.bg(theme::surface()),
+    }
+}
+
+fn hunk_header(header: &str) -> AnyElement {
+    div()
+        .px(px(theme::SPACE_3))
+        .py(px(theme::SPACE_HALF))
+        .mt(px(theme::SPACE_1))
+        .bg(theme::with_alpha(theme::ice(), 0.08))
+        .text_color(theme::ice_dim())
+        .child(header.to_string())
+        .into_any_element()
+}
+
+fn gutter(lineno: Option<u32>) -> impl IntoElement {
+    div()
+        .w(px(GUTTER_WIDTH))
+        .flex_shrink_0()
+        .flex()
+        .justify_end()
+        .pr(px(theme::SPACE_2))
+        .text_color(theme::text_muted())
+        .child(lineno.map(|n| n.to_string()).unwrap_or_default())
+}
+
+fn sign(kind: DiffLineKind) -> impl IntoElement {
+    let (sign, color) = match kind {
+        DiffLineKind::Added => ("+", theme::aurora()),
+        DiffLineKind::Removed => ("-", theme::ember()),
+        DiffLineKind::Context => (" ", theme::text_muted()),
+    };
+    div()
+        .w(px(theme::SPACE_3))
+        .flex_shrink_0()
+        .text_color(color)
+        .child(sign)
+}
+
+fn line_background(kind: DiffLineKind) -> gpui::Rgba {
+    match kind {
+        DiffLineKind::Added => theme::with_alpha(theme::aurora(), 0.12),
+        DiffLineKind::Removed => theme::with_alpha(theme::ember(), 0.12),
+        DiffLineKind::Context => theme::transparent(),
+    }
+}
+
+/// Line content with syntax coloring. Tabs are expanded so columns line up.
+fn code(line: &DiffLine, language: Language) -> impl IntoElement {
+    let text = line.content.replace('\t', "    ");
+    let highlights: Vec<_> = highlight(&text, language)
+        .into_iter()
+        .filter_map(|token| {
+            let color = match token.kind {
+                TokenKind::Plain => return None,
+                TokenKind::Keyword => theme::ice(),
+                TokenKind::String => theme::aurora(),
+                TokenKind::Comment => theme::text_muted(),
+                TokenKind::Number => theme::copper(),
+            };
+            Some((
+                token.range,
+                HighlightStyle {
+                    color: Some(color.into()),
+                    ..Default::default()
+                },
+            ))
+        })
+        .collect();
+    div()
+        .flex_1()
+        .whitespace_nowrap()
+        .text_color(theme::text())
+        .child(StyledText::new(text).with_highlights(highlights))
+}
@@ -1,13 +1,15 @@
 //! Kild navigation sidebar.
 //!
 //! Fixed left sidebar (200px) showing kilds grouped by Active/Stopped status
-//! with nested terminal tab names. Hover actions appear on kild rows.
+//! with nested terminal tab names, followed by one section per remote daemon
+//! host. Hover actions appear on kild rows.
 
 use gpui::{Context, FontWeight, IntoElement, ParentElement, Styled, div, prelude::*, px};
 use std::collections::HashMap;
 
 use crate::components::{Status, StatusIndicator};
 use crate::state::AppState;
+use crate::state::remote_hosts::{HostStatus, RemoteHost, RemoteHosts, terminal_key};
 use crate::theme;
 use crate::views::helpers::format_relative_time;
 use crate::views::main_view::MainView;
@@ -28,6 +30,8 @@ pub fn render_sidebar(
     terminal_tabs: &HashMap<String, TerminalTabs>,
     pane_grid: &super::pane_grid::PaneGrid,
     team_store: &crate::teams::TeamStore,
+    remote_hosts: &RemoteHosts,
+    active_terminal_id: Option<&str>,
     cx: &mut Context<MainView>,
 ) -> impl IntoElement {
     let active_project_name = state
@@ -52,6 +56,18 @@ pub fn render_sidebar(
     let stopped_count = stopped_kilds.len();
     let total_count = active_count + stopped_count;
 
+    let mut host_sections = Vec::new();
+    for (ix, host) in remote_hosts.hosts().iter().enumerate() {
+        host_sections.push(render_host_section(
+            ix,
+            host,
+            terminal_tabs,
+            pane_grid,
+            active_terminal_id,
+            cx,
+        ));
+    }
+
     div()
         .w(px(SIDEBAR_WIDTH))
         .h_full()
@@ -117,6 +133,7 @@ pub fn render_sidebar(
                         );
 
                         let teammate_count = team_store.teammates_for_session(&session_id).len();
+                        let unread = state.activity().unread_count_for(&session_id);
 
                         let sid_for_add = session_id.to_string();
                         active_elements.push(
@@ -130,6 +147,7 @@ pub fn render_sidebar(
                                     is_selected,
                                     &time_meta,
                                     teammate_count,
+                                    unread,
                                     cx.listener(move |view, _, window, cx| {
                                         view.on_kild_select(&session_id_for_click, window, cx);
                                     }),
@@ -180,6 +198,7 @@ pub fn render_sidebar(
                                 _ => Status::Crashed,
                             };
 
+                            let unread = state.activity().unread_count_for(&session_id);
                             let sid_for_add = session_id.to_string();
                             stopped_elements.push(
                                 div()
@@ -192,6 +211,7 @@ pub fn render_sidebar(
                                         is_selected,
                                         &time_meta,
                                         0, // no badge for stopped kilds
+                                        unread,
                                         cx.listener(move |view, _, window, cx| {
                                             view.on_kild_select(&session_id_for_click, window, cx);
                                         }),
@@ -220,7 +240,9 @@ pub fn render_sidebar(
                                 .child("No kilds"),
                         )
                     },
-                ),
+                )
+                // Remote hosts
+                .children(host_sections),
         )
         // Footer: + Create kild
         .child(
@@ -271,7 +293,125 @@ fn render_section_header(title: &str, count: usize, count_color: Rgba) -> impl I
         )
 }
 
+/// Render a remote host: header with its connection state, then its daemon
+/// sessions with their attached terminals.
+fn render_host_section(
+    ix: usize,
+    host: &RemoteHost,
+    terminal_tabs: &HashMap<String, TerminalTabs>,
+    pane_grid: &super::pane_grid::PaneGrid,
+    active_terminal_id: Option<&str>,
+    cx: &mut Context<MainView>,
+) -> gpui::AnyElement {
+    let name = host.name().to_string();
+    let state_label = match host.status() {
+        HostStatus::Connecting => div()
+            .text_color(theme::text_muted())
+            .child("connecting…")
+            .into_any_element(),
+        HostStatus::Connected => div()
+            .text_color(theme::aurora())
+            .child(host.sessions().len().to_string())
+            .into_any_element(),
+        HostStatus::Disconnected(_) => {
+            let name = name.clone();
+            div()
+                .id(("remote-host-retry", ix))
+                .cursor_pointer()
+                .text_color(theme::ember())
+                .hover(|s| s.text_color(theme::text_subtle()))
+                .on_mouse_up(
+                    gpui::MouseButton::Left,
+                    cx.listener(move |view, _, _, cx| view.on_remote_host_retry(&name, cx)),
+                )
+                .child("offline · retry")
+                .into_any_element()
+        }
+    };
+    let error = match host.status() {
+        HostStatus::Disconnected(message) => Some(message.clone()),
+        _ => None,
+    };
+    let offline = error.is_some();
+
+    let mut rows = Vec::new();
+    for session in host.sessions() {
+        let session_id = session.id.to_string();
+        let key = terminal_key(host.name(), &session_id);
+        let status = match (offline, session.status) {
+            (true, _) | (false, kild_protocol::SessionStatus::Stopped) => Status::Stopped,
+            (false, _) => Status::Active,
+        };
+        let time_meta = format_relative_time(&session.created_at);
+        let tab_items = render_terminal_items(
+            &key,
+            terminal_tabs.get(&key),
+            pane_grid,
+            theme::aurora(),
+            cx,
+        );
+        let host_name = name.clone();
+        let sid_for_click = session_id.clone();
+        rows.push(
+            div()
+                .flex()
+                .flex_col()
+                .child(render_kild_row(
+                    gpui::SharedString::from(format!("remote-session-{}-{}", name, session_id)),
+                    &session_id,
+                    status,
+                    active_terminal_id == Some(key.as_str()),
+                    &time_meta,
+                    0,
+                    0,
+                    cx.listener(move |view, _, window, cx| {
+                        view.on_remote_session_select(&host_name, &sid_for_click, window, cx);
+                    }),
+                ))
+                .children(tab_items),
+        );
+    }
+
+    div()
+        .flex()
+        .flex_col()
+        .child(
+            div()
+                .px(px(theme::SPACE_3))
+                .py(px(theme::SPACE_1))
+                .mt(px(theme::SPACE_2))
+                .flex()
+                .items_center()
+                .justify_between()
+                .text_size(px(theme::TEXT_XXS))
+                .child(
+                    div()
+                        .font_weight(FontWeight::SEMIBOLD)
+                        .text_color(theme::text_muted())
+                        .overflow_hidden()
+                        .text_ellipsis()
+                        .child(name.to_uppercase()),
+                )
+                .child(state_label),
+        )
+        .when_some(error, |this, message| {
+            this.child(
+                div()
+                    .px(px(theme::SPACE_3))
+                    .text_size(px(theme::TEXT_XXS))
+                    .text_color(theme::text_muted())
+                    .overflow_hidden()
+                    .text_ellipsis()
+                    .whitespace_nowrap()
+                    .child(message),
+            )
+        })
+        .children(rows)
+        .into_any_element()
+}
+
 /// Render a clean kild row with status dot, branch name, and time meta.
+#[allow(clippy::too_many_arguments)]
 fn render_kild_row(
     id: impl Into<gpui::ElementId>,
     branch: &str,
@@ -279,6 +419,7 @@ fn render_kild_row(
     is_selected: bool,
     time_meta: &str,
     teammate_count: usize,
+    unread: usize,
     on_click: impl Fn(&gpui::MouseUpEvent, &mut gpui::Window, &mut gpui::App) + 'static,
 ) -> impl IntoElement {
     div()
@@ -333,6 +474,19 @@ fn render_kild_row(
                     .child(format!("[{}]", teammate_count)),
             )
         })
+        // Unread activity badge
+        .when(unread > 0, |row| {
+            row.child(
+                div()
+                    .flex_shrink_0()
+                    .px(px(theme::SPACE_1))
+                    .rounded(px(theme::RADIUS_SM))
+                    .bg(theme::with_alpha(theme::copper(), 0.2))
+                    .text_size(px(theme::TEXT_BADGE))
+                    .text_color(theme::copper())
+                    .child(unread.to_string()),
+            )
+        })
 }
 
 /// Collect terminal item elements for a kild's tabs.
@@ -377,6 +531,7 @@ fn render_terminal_item(
             crate::views::terminal_tabs::TerminalBackend::Teammate { color, .. } => {
                 ("team", crate::teams::team_color_to_rgba(color))
             }
+            crate::views::terminal_tabs::TerminalBackend::Remote { .. } => ("remote", dot_color),
         })
         .unwrap_or(("local", dot_color));
     let in_grid = pane_grid.find_slot(session_id, tab_idx).is_some();
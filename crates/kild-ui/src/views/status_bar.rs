@@ -1,7 +1,8 @@
 //! Status bar component — thin footer spanning sidebar + main area.
 //!
-//! Shows contextual alerts on the left (dirty worktrees, operation errors)
-//! and view-aware keyboard shortcut hints on the right.
+//! Shows the activity summary and contextual alerts on the left (kilds
+//! waiting on the user, dirty worktrees, operation errors) and view-aware
+//! keyboard shortcut hints on the right.
 
 use gpui::{
     AnyElement, Context, IntoElement, Keystroke, ParentElement, Styled, div, prelude::*, px,
@@ -39,11 +40,60 @@ pub fn render_status_bar(
         .bg(theme::obsidian())
         .border_t_1()
         .border_color(theme::border_subtle())
-        .child(render_alerts(state, cx))
+        .child(
+            div()
+                .flex()
+                .items_center()
+                .gap(px(theme::SPACE_3))
+                .child(render_activity_summary(state, cx))
+                .child(render_alerts(state, cx)),
+        )
         .child(render_keyboard_hints(active_view, keybindings, cx))
         .into_any_element()
 }
 
+/// Render the activity button (unread count) and the number of kilds
+/// waiting on the user. Clicking toggles the activity feed.
+fn render_activity_summary(state: &AppState, cx: &mut Context<MainView>) -> impl IntoElement {
+    let feed = state.activity();
+    let unread = feed.unread_count();
+    let waiting = feed.waiting_count();
+
+    div()
+        .id("status-bar-activity")
+        .flex()
+        .items_center()
+        .gap(px(theme::SPACE_2))
+        .cursor_pointer()
+        .text_size(px(10.0))
+        .text_color(theme::text_muted())
+        .hover(|d| d.text_color(theme::text_subtle()))
+        .on_click(cx.listener(|view, _, _, cx| view.on_toggle_activity(cx)))
+        .child(if unread > 0 {
+            format!("Activity · {}", unread)
+        } else {
+            "Activity".to_string()
+        })
+        .when(waiting > 0, |d| {
+            d.child(
+                div()
+                    .flex()
+                    .items_center()
+                    .gap(px(theme::SPACE_1))
+                    .text_color(theme::copper())
+                    .child(
+                        div()
+                            .w(px(5.0))
+                            .h(px(5.0))
+                            .rounded_full()
+                            .bg(theme::copper())
+                            .flex_shrink_0(),
+                    )
+                    .child(format!("{} waiting", waiting)),
+            )
+        })
+}
+
 /// Compute and render alerts from session state.
 ///
 /// Shows operation errors (ember dot) and dirty stopped kilds (copper dot).
@@ -181,6 +231,7 @@ fn keyboard_hints_for_view(
             (keybindings.navigation.next_kild.hint_str(), "next"),
             (keybindings.navigation.prev_kild.hint_str(), "prev"),
             (keybindings.navigation.toggle_view.hint_str(), "control"),
+            (keybindings.navigation.command_palette.hint_str(), "palette"),
         ],
         ActiveView::Detail => vec![
             ("escape".to_string(), "back"),
@@ -283,9 +334,10 @@ mod tests {
     #[test]
     fn test_keyboard_hints_dashboard_view() {
         let hints = keyboard_hints_for_view(ActiveView::Dashboard, &default_kb());
-        assert_eq!(hints.len(), 3);
+        assert_eq!(hints.len(), 4);
         assert_eq!(hints[2].0, "cmd-d");
         assert_eq!(hints[2].1, "control");
+        assert_eq!(hints[3].0, "cmd-p");
     }
 
     #[test]
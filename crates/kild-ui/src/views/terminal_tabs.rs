@@ -26,6 +26,11 @@ pub enum TerminalBackend {
         teammate_name: String,
         color: kild_teams::TeamColor,
     },
+    /// Session on a `[[daemon.hosts]]` daemon.
+    Remote {
+        host: String,
+        daemon_session_id: String,
+    },
 }
 
 /// A single terminal tab within a kild's tab bar.
@@ -102,6 +107,7 @@ impl TerminalTabs {
             TerminalBackend::Local => base,
             TerminalBackend::Daemon { .. } => format!("D • {}", base),
             TerminalBackend::Teammate { teammate_name, .. } => teammate_name.clone(),
+            TerminalBackend::Remote { host, .. } => format!("{} • {}", host, base),
         };
         tracing::debug!(
             event = "ui.terminal_tabs.push",
@@ -154,7 +160,8 @@ impl TerminalTabs {
                 daemon_session_id: id,
                 ..
             } => id == daemon_session_id,
-            TerminalBackend::Local => false,
+            // Remote IDs belong to another daemon
+            TerminalBackend::Local | TerminalBackend::Remote { .. } => false,
         })
     }
 
@@ -206,6 +213,21 @@ impl TerminalTabs {
                 );
                 None
             }
+            TerminalBackend::Remote {
+                host,
+                daemon_session_id,
+            } => {
+                // Remote sessions outlive the UI; closing the tab only detaches.
+                tracing::debug!(
+                    event = "ui.terminal_tabs.close",
+                    idx = idx,
+                    backend = "remote",
+                    host = host,
+                    daemon_session_id = daemon_session_id,
+                    remaining = self.tabs.len() - 1
+                );
+                None
+            }
         };
         self.tabs.remove(idx);
         self.active = adjust_active_after_close(self.active, idx, self.tabs.len());
@@ -32,28 +32,63 @@ pub fn inject_command() -> Command {
         .about("Send text to a running daemon worker")
         .long_about(
             "Send text to a running daemon worker. For claude sessions, writes to the \
-             Claude Code inbox (polled every ~1s). For all other agents, writes to PTY \
-             stdin. Only call when the worker is idle (Stop hook fired). Use --inbox to \
-             force the inbox protocol for non-claude agents.",
+             Claude Code inbox (polled every ~1s). For all other agents, types into the \
+             PTY once the agent's input prompt is on screen, then confirms the agent \
+             started working (retrying Enter if not). Exits non-zero when the message \
+             could not be delivered. Use --inbox to force the inbox protocol for \
+             non-claude agents. With --all <TEXT>, the text is sent to every active kild in \
+             the project instead (the brain session is skipped unless --include-brain is \
+             given).",
         )
         .arg(
             Arg::new("branch")
                 .help("Branch name of the target kild")
-                .required(true)
+                .required_unless_present("all")
                 .index(1),
         )
         .arg(
             Arg::new("text")
                 .help("Text to inject (a newline is appended automatically)")
-                .required(true)
+                .required_unless_present("all")
                 .index(2),
         )
+        .arg(
+            Arg::new("all")
+                .long("all")
+                .help("Send TEXT to every active kild in the project")
+                .value_name("TEXT")
+                .conflicts_with_all(["branch", "text"]),
+        )
+        .arg(
+            Arg::new("project")
+                .long("project")
+                .help("Project for --all: name, path or ID (default: the current repository)")
+                .value_name("PROJECT")
+                .conflicts_with("branch"),
+        )
+        .arg(
+            Arg::new("include-brain")
+                .long("include-brain")
+                .help("With --all, also send to the brain session")
+                .action(ArgAction::SetTrue)
+                .conflicts_with("branch"),
+        )
         .arg(
             Arg::new("inbox")
                 .long("inbox")
                 .help("Force Claude Code inbox protocol (default for claude, PTY stdin for others)")
                 .action(ArgAction::SetTrue),
         )
+        .arg(
+            Arg::new("timeout")
+                .long("timeout")
+                .help(
+                    "Seconds to wait for the agent's input prompt before giving up (PTY delivery)",
+                )
+                .value_name("SECS")
+                .value_parser(clap::value_parser!(u64))
+                .default_value("30"),
+        )
 }
 
 pub fn attach_command() -> Command {
@@ -72,3 +107,52 @@ pub fn attach_command() -> Command {
                 .value_name("PANE_ID"),
         )
 }
+
+pub fn approvals_command() -> Command {
+    Command::new("approvals")
+        .about("List and answer agent permission requests held by the daemon")
+        .long_about(
+            "List and answer agent permission requests held by the daemon. Claude Code \
+             PermissionRequest hooks and Codex approval-requested events are queued here \
+             until allowed or denied. Rules in [approvals] config answer matching requests \
+             automatically.",
+        )
+        .arg(
+            Arg::new("json")
+                .long("json")
+                .help("Output pending approvals as JSON")
+                .action(ArgAction::SetTrue),
+        )
+        .subcommand(
+            Command::new("list").about("List pending approvals").arg(
+                Arg::new("json")
+                    .long("json")
+                    .help("Output as JSON")
+                    .action(ArgAction::SetTrue),
+            ),
+        )
+        .subcommand(
+            Command::new("allow").about("Allow a pending request").arg(
+                Arg::new("id")
+                    .help("Approval ID (from `kild approvals`)")
+                    .required(true)
+                    .index(1),
+            ),
+        )
+        .subcommand(
+            Command::new("deny")
+                .about("Deny a pending request")
+                .arg(
+                    Arg::new("id")
+                        .help("Approval ID (from `kild approvals`)")
+                        .required(true)
+                        .index(1),
+                )
+                .arg(
+                    Arg::new("message")
+                        .long("message")
+                        .short('m')
+                        .help("Reason shown to the agent"),
+                ),
+        )
+}
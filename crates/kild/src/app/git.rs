@@ -2,17 +2,21 @@ use clap::{Arg, ArgAction, Command};
 
 pub fn diff_command() -> Command {
     Command::new("diff")
-        .about("Show git diff for a kild's worktree")
+        .about("Show git diff for a kild's worktree, or between two kilds")
+        .long_about(
+            "Show git diff for a kild's worktree. With `<a>..<b>`, diff two kilds' \
+             working trees against each other, uncommitted and untracked files included.",
+        )
         .arg(
             Arg::new("branch")
-                .help("Branch name of the kild")
+                .help("Branch name of the kild, or `<a>..<b>` to compare two kilds")
                 .required(true)
                 .index(1),
         )
         .arg(
             Arg::new("staged")
                 .long("staged")
-                .help("Show only staged changes (git diff --staged)")
+                .help("Show only staged changes (git diff --staged); single kild only")
                 .action(ArgAction::SetTrue),
         )
         .arg(
@@ -23,6 +27,64 @@ pub fn diff_command() -> Command {
         )
 }
 
+pub fn compare_command() -> Command {
+    Command::new("compare")
+        .about("Summarise which files two kilds changed since their common ancestor")
+        .long_about(
+            "Summarise which files two kilds changed since their common ancestor: files \
+             changed by both (and whether they ended up identical) and files only one \
+             of them touched. Uncommitted and untracked files are included.",
+        )
+        .arg(
+            Arg::new("left")
+                .help("Branch name of the first kild")
+                .required(true)
+                .index(1),
+        )
+        .arg(
+            Arg::new("right")
+                .help("Branch name of the second kild")
+                .required(true)
+                .index(2),
+        )
+        .arg(
+            Arg::new("json")
+                .long("json")
+                .help("Output as JSON")
+                .action(ArgAction::SetTrue),
+        )
+}
+
+pub fn cherry_pick_command() -> Command {
+    Command::new("cherry-pick")
+        .about("Apply commits from one kild's branch onto another kild")
+        .long_about(
+            "Apply commits from one kild's branch onto another kild's worktree. Without \
+             commits, picks every non-merge commit the target does not have yet. On \
+             conflict the conflicting files are reported and the whole pick is aborted, \
+             leaving the target unchanged.",
+        )
+        .arg(
+            Arg::new("from")
+                .help("Branch name of the kild to take commits from")
+                .required(true)
+                .index(1),
+        )
+        .arg(
+            Arg::new("to")
+                .help("Branch name of the kild to apply commits to")
+                .required(true)
+                .index(2),
+        )
+        .arg(
+            Arg::new("commits")
+                .help("Commits to pick, resolved in the source kild (e.g. a SHA or HEAD~1)")
+                .value_name("COMMIT")
+                .num_args(1..)
+                .index(3),
+        )
+}
+
 pub fn commits_command() -> Command {
     Command::new("commits")
         .about("Show recent commits in a kild's branch")
@@ -89,3 +151,93 @@ pub fn sync_command() -> Command {
                 .conflicts_with("branch"),
         )
 }
+
+pub fn sparse_command() -> Command {
+    Command::new("sparse")
+        .about("Manage a sparse kild's checked-out directories")
+        .subcommand_required(true)
+        .subcommand(
+            Command::new("add")
+                .about("Check out more directories in a sparse kild")
+                .arg(
+                    Arg::new("branch")
+                        .help("Branch name of the kild")
+                        .required(true)
+                        .index(1),
+                )
+                .arg(
+                    Arg::new("paths")
+                        .help("Directories to add to the checkout")
+                        .value_name("DIR")
+                        .required(true)
+                        .num_args(1..)
+                        .index(2),
+                ),
+        )
+}
+
+pub fn checkpoint_command() -> Command {
+    Command::new("checkpoint")
+        .about("Snapshot a kild's worktree, including uncommitted and untracked files")
+        .long_about(
+            "Snapshot a kild's worktree, including uncommitted and untracked files. \
+             Checkpoints are stored as hidden refs under refs/kild/checkpoints/<branch>/ \
+             and leave the branch, index and working directory untouched. Restore one \
+             with `kild rollback`.",
+        )
+        .args_conflicts_with_subcommands(true)
+        .subcommand_negates_reqs(true)
+        .arg(
+            Arg::new("branch")
+                .help("Branch name of the kild")
+                .required(true)
+                .index(1),
+        )
+        .arg(
+            Arg::new("message")
+                .long("message")
+                .short('m')
+                .help("Describe the checkpoint"),
+        )
+        .subcommand(
+            Command::new("list")
+                .about("List a kild's checkpoints")
+                .arg(
+                    Arg::new("branch")
+                        .help("Branch name of the kild")
+                        .required(true)
+                        .index(1),
+                )
+                .arg(
+                    Arg::new("json")
+                        .long("json")
+                        .help("Output as JSON")
+                        .action(ArgAction::SetTrue),
+                ),
+        )
+}
+
+pub fn rollback_command() -> Command {
+    Command::new("rollback")
+        .about("Restore a kild's worktree to a checkpoint")
+        .long_about(
+            "Restore a kild's worktree to a checkpoint. The current state is saved as a \
+             new checkpoint first, so a rollback can be undone. The branch is reset to the \
+             commit the checkpoint was taken on and the checkpoint's changes are restored \
+             as uncommitted files. Untracked files not in the checkpoint are removed; \
+             ignored files are kept.",
+        )
+        .arg(
+            Arg::new("branch")
+                .help("Branch name of the kild")
+                .required(true)
+                .index(1),
+        )
+        .arg(
+            Arg::new("id")
+                .help("Checkpoint ID (from `kild checkpoint list`)")
+                .required(true)
+                .value_parser(clap::value_parser!(u32))
+                .index(2),
+        )
+}
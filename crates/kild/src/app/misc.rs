@@ -1,4 +1,4 @@
-use clap::{Arg, ArgAction, Command};
+use clap::{Arg, ArgAction, ArgGroup, Command};
 use clap_complete::Shell;
 
 pub fn cleanup_command() -> Command {
@@ -35,13 +35,70 @@ pub fn cleanup_command() -> Command {
                 .help("Clean worktrees in kild directory that have no session")
                 .action(ArgAction::SetTrue),
         )
+        .arg(
+            Arg::new("merged")
+                .long("merged")
+                .help("Clean stopped kilds whose PR is merged upstream")
+                .action(ArgAction::SetTrue),
+        )
+        .arg(
+            Arg::new("max-disk")
+                .long("max-disk")
+                .help("Suggest the largest idle kilds to remove to fit worktrees in SIZE (e.g., 20GB); removes nothing")
+                .value_name("SIZE")
+                .value_parser(|s: &str| {
+                    kild_core::files::operations::parse_file_size(s).map_err(|e| e.to_string())
+                })
+                .conflicts_with_all(["no-pid", "stopped", "older-than", "orphans", "merged", "all", "force"]),
+        )
+        .arg(
+            Arg::new("dry-run")
+                .long("dry-run")
+                .help("Show what would be removed and why, with disk usage, without removing anything")
+                .action(ArgAction::SetTrue),
+        )
+        .arg(
+            Arg::new("json")
+                .long("json")
+                .help("Output the plan in JSON format (with --dry-run or --max-disk)")
+                .action(ArgAction::SetTrue)
+                .requires("plan"),
+        )
+        .group(
+            ArgGroup::new("plan")
+                .args(["dry-run", "max-disk"])
+                .multiple(true),
+        )
         .arg(
             Arg::new("force")
                 .long("force")
                 .short('f')
                 .help("Remove orphaned worktrees even if they have uncommitted changes or active processes")
                 .action(ArgAction::SetTrue)
-                .conflicts_with_all(["no-pid", "stopped", "older-than"]),
+                .conflicts_with_all(["no-pid", "stopped", "older-than", "merged"]),
+        )
+}
+
+pub fn cache_command() -> Command {
+    let json_arg = || {
+        Arg::new("json")
+            .long("json")
+            .help("Output in JSON format")
+            .action(ArgAction::SetTrue)
+    };
+    Command::new("cache")
+        .about("Inspect and prune the shared build caches ([worktree.shared_cache])")
+        .subcommand_required(true)
+        .arg_required_else_help(true)
+        .subcommand(
+            Command::new("status")
+                .about("Show node_modules snapshots and which kilds were cloned from them")
+                .arg(json_arg()),
+        )
+        .subcommand(
+            Command::new("prune")
+                .about("Forget kilds whose lockfile diverged and delete unused snapshots")
+                .arg(json_arg()),
         )
 }
 
@@ -190,6 +247,37 @@ pub fn health_command() -> Command {
                 .value_parser(clap::value_parser!(u64))
                 .default_value("5"),
         )
+        .arg(
+            Arg::new("history")
+                .long("history")
+                .help("Show health trends from recorded snapshots (recorded by --watch)")
+                .action(ArgAction::SetTrue)
+                .conflicts_with("watch"),
+        )
+        .arg(
+            Arg::new("days")
+                .long("days")
+                .help("Number of days of history to include (default: 7)")
+                .value_parser(clap::value_parser!(u64).range(1..))
+                .default_value("7")
+                .requires("history"),
+        )
+        .arg(
+            Arg::new("granularity")
+                .long("granularity")
+                .help("Aggregate history per day or per hour (default: day)")
+                .value_parser(["day", "hour"])
+                .default_value("day")
+                .requires("history"),
+        )
+        .arg(
+            Arg::new("csv")
+                .long("csv")
+                .help("Export raw history snapshots as CSV")
+                .action(ArgAction::SetTrue)
+                .requires("history")
+                .conflicts_with("json"),
+        )
 }
 
 pub fn completions_command() -> Command {
@@ -21,17 +21,25 @@ pub fn build_cli() -> Command {
         .subcommand(session::open_command())
         .subcommand(session::stop_command())
         .subcommand(session::teammates_command())
+        .subcommand(session::export_command())
+        .subcommand(session::import_command())
         .subcommand(misc::code_command())
         .subcommand(misc::focus_command())
         .subcommand(misc::hide_command())
         .subcommand(git::diff_command())
+        .subcommand(git::compare_command())
+        .subcommand(git::cherry_pick_command())
         .subcommand(git::commits_command())
         .subcommand(misc::pr_command())
         .subcommand(query::status_command())
         .subcommand(query::agent_status_command())
         .subcommand(git::rebase_command())
         .subcommand(git::sync_command())
+        .subcommand(git::sparse_command())
+        .subcommand(git::checkpoint_command())
+        .subcommand(git::rollback_command())
         .subcommand(misc::cleanup_command())
+        .subcommand(misc::cache_command())
         .subcommand(misc::stats_command())
         .subcommand(misc::inbox_command())
         .subcommand(misc::prime_command())
@@ -40,6 +48,7 @@ pub fn build_cli() -> Command {
         .subcommand(daemon::daemon_command())
         .subcommand(daemon::attach_command())
         .subcommand(daemon::inject_command())
+        .subcommand(daemon::approvals_command())
         .subcommand(misc::completions_command())
         .subcommand(misc::init_hooks_command())
         .subcommand(project::project_command())
@@ -95,6 +95,22 @@ pub fn create_command() -> Command {
                 .help("Run from the project root instead of creating an isolated worktree (for supervisory sessions like honryu)")
                 .action(ArgAction::SetTrue),
         )
+        .arg(
+            Arg::new("sparse")
+                .long("sparse")
+                .help("Check out only these directories (cone-mode sparse checkout; overrides [git] sparse_paths)")
+                .value_name("DIR")
+                .num_args(1..)
+                .action(ArgAction::Append)
+                .conflicts_with("main"),
+        )
+        .arg(
+            Arg::new("no-sparse")
+                .long("no-sparse")
+                .help("Check out the full tree, ignoring [git] sparse_paths")
+                .action(ArgAction::SetTrue)
+                .conflicts_with("sparse"),
+        )
         .arg(
             Arg::new("initial-prompt")
                 .long("initial-prompt")
@@ -333,3 +349,51 @@ pub fn complete_command() -> Command {
                 .action(ArgAction::SetTrue),
         )
 }
+
+pub fn export_command() -> Command {
+    Command::new("export")
+        .about("Package a kild for another machine")
+        .long_about(
+            "Package a kild for another machine. Writes a tar archive with a git bundle of \
+             the branch's commits since the base branch, a patch of uncommitted and \
+             untracked work, and the session's metadata, fleet inbox and Claude task list. \
+             Recreate it with `kild import`.",
+        )
+        .arg(
+            Arg::new("branch")
+                .help("Branch name of the kild to export")
+                .required(true)
+                .index(1),
+        )
+        .arg(
+            Arg::new("output")
+                .long("output")
+                .short('o')
+                .value_name("FILE")
+                .help("Archive to write (default: <branch>.kild.tar)"),
+        )
+}
+
+pub fn import_command() -> Command {
+    Command::new("import")
+        .about("Recreate an exported kild in the current project")
+        .long_about(
+            "Recreate a kild from a `kild export` archive in the project at the current \
+             directory. The branch, worktree and uncommitted work are restored and the \
+             session gets fresh ports. The repository must already contain the base \
+             branch commit the export was made against. The kild is left stopped; start \
+             it with `kild open`.",
+        )
+        .arg(
+            Arg::new("archive")
+                .help("Archive written by `kild export`")
+                .required(true)
+                .index(1),
+        )
+        .arg(
+            Arg::new("as")
+                .long("as")
+                .value_name("NAME")
+                .help("Import under a different kild name"),
+        )
+}
@@ -126,6 +126,51 @@ fn test_cli_health_watch_mode() {
     assert_eq!(*health_matches.get_one::<u64>("interval").unwrap(), 10);
 }
 
+#[test]
+fn test_cli_health_history_flags() {
+    let app = build_cli();
+    let matches = app.try_get_matches_from(vec![
+        "kild",
+        "health",
+        "--history",
+        "--days",
+        "3",
+        "--granularity",
+        "hour",
+    ]);
+    assert!(matches.is_ok());
+
+    let matches = matches.unwrap();
+    let health_matches = matches.subcommand_matches("health").unwrap();
+    assert!(health_matches.get_flag("history"));
+    assert_eq!(*health_matches.get_one::<u64>("days").unwrap(), 3);
+    assert_eq!(
+        health_matches.get_one::<String>("granularity").unwrap(),
+        "hour"
+    );
+}
+
+#[test]
+fn test_cli_health_history_conflicts_with_watch() {
+    let app = build_cli();
+    let matches = app.try_get_matches_from(vec!["kild", "health", "--history", "--watch"]);
+    assert!(matches.is_err());
+}
+
+#[test]
+fn test_cli_health_days_requires_history() {
+    let app = build_cli();
+    let matches = app.try_get_matches_from(vec!["kild", "health", "--days", "3"]);
+    assert!(matches.is_err());
+}
+
+#[test]
+fn test_cli_health_csv_conflicts_with_json() {
+    let app = build_cli();
+    let matches = app.try_get_matches_from(vec!["kild", "health", "--history", "--csv", "--json"]);
+    assert!(matches.is_err());
+}
+
 #[test]
 fn test_cli_health_default_interval() {
     let app = build_cli();
@@ -1286,6 +1331,208 @@ fn test_cli_create_no_agent_with_no_fetch() {
     assert!(create_matches.get_flag("no-fetch"));
 }
 
+// --- cleanup command tests ---
+
+#[test]
+fn test_cli_cleanup_dry_run_json() {
+    let app = build_cli();
+    let matches = app
+        .try_get_matches_from(vec!["kild", "cleanup", "--merged", "--dry-run", "--json"])
+        .unwrap();
+    let cleanup = matches.subcommand_matches("cleanup").unwrap();
+    assert!(cleanup.get_flag("merged"));
+    assert!(cleanup.get_flag("dry-run"));
+    assert!(cleanup.get_flag("json"));
+}
+
+#[test]
+fn test_cli_cleanup_json_requires_plan() {
+    let app = build_cli();
+    let result = app.try_get_matches_from(vec!["kild", "cleanup", "--json"]);
+    assert!(result.is_err());
+}
+
+#[test]
+fn test_cli_cleanup_max_disk_parses_size() {
+    let app = build_cli();
+    let matches = app
+        .try_get_matches_from(vec!["kild", "cleanup", "--max-disk", "20GB", "--json"])
+        .unwrap();
+    let cleanup = matches.subcommand_matches("cleanup").unwrap();
+    assert_eq!(
+        cleanup.get_one::<u64>("max-disk").copied(),
+        Some(20 * 1024 * 1024 * 1024)
+    );
+
+    let app = build_cli();
+    assert!(
+        app.try_get_matches_from(vec!["kild", "cleanup", "--max-disk", "lots"])
+            .is_err()
+    );
+    let app = build_cli();
+    assert!(
+        app.try_get_matches_from(vec!["kild", "cleanup", "--max-disk", "1GB", "--merged"])
+            .is_err()
+    );
+}
+
+// --- sparse checkout tests ---
+
+#[test]
+fn test_cli_create_sparse_flags() {
+    let app = build_cli();
+    let matches = app
+        .try_get_matches_from(vec![
+            "kild",
+            "create",
+            "billing",
+            "--sparse",
+            "services/billing",
+            "libs/shared",
+        ])
+        .unwrap();
+    let create = matches.subcommand_matches("create").unwrap();
+    let paths: Vec<&String> = create.get_many::<String>("sparse").unwrap().collect();
+    assert_eq!(paths, ["services/billing", "libs/shared"]);
+
+    let app = build_cli();
+    assert!(
+        app.try_get_matches_from(vec!["kild", "create", "b", "--sparse", "x", "--no-sparse"])
+            .is_err()
+    );
+    let app = build_cli();
+    assert!(
+        app.try_get_matches_from(vec!["kild", "create", "b", "--sparse", "x", "--main"])
+            .is_err()
+    );
+}
+
+#[test]
+fn test_cli_sparse_add() {
+    let app = build_cli();
+    let matches = app
+        .try_get_matches_from(vec!["kild", "sparse", "add", "billing", "docs", "libs/ui"])
+        .unwrap();
+    let add = matches
+        .subcommand_matches("sparse")
+        .unwrap()
+        .subcommand_matches("add")
+        .unwrap();
+    assert_eq!(add.get_one::<String>("branch").unwrap(), "billing");
+    assert_eq!(add.get_many::<String>("paths").unwrap().count(), 2);
+
+    let app = build_cli();
+    assert!(
+        app.try_get_matches_from(vec!["kild", "sparse", "add", "billing"])
+            .is_err()
+    );
+}
+
+// --- compare and cherry-pick command tests ---
+
+#[test]
+fn test_cli_compare_and_cherry_pick() {
+    let app = build_cli();
+    let matches = app
+        .try_get_matches_from(vec!["kild", "compare", "auth-a", "auth-b", "--json"])
+        .unwrap();
+    let compare = matches.subcommand_matches("compare").unwrap();
+    assert_eq!(compare.get_one::<String>("left").unwrap(), "auth-a");
+    assert_eq!(compare.get_one::<String>("right").unwrap(), "auth-b");
+    assert!(compare.get_flag("json"));
+
+    let app = build_cli();
+    let matches = app
+        .try_get_matches_from(vec!["kild", "cherry-pick", "auth-a", "auth-b"])
+        .unwrap();
+    let pick = matches.subcommand_matches("cherry-pick").unwrap();
+    assert!(pick.get_many::<String>("commits").is_none());
+
+    let app = build_cli();
+    let matches = app
+        .try_get_matches_from(vec![
+            "kild",
+            "cherry-pick",
+            "auth-a",
+            "auth-b",
+            "abc123",
+            "HEAD~1",
+        ])
+        .unwrap();
+    let pick = matches.subcommand_matches("cherry-pick").unwrap();
+    assert_eq!(pick.get_one::<String>("to").unwrap(), "auth-b");
+    assert_eq!(pick.get_many::<String>("commits").unwrap().count(), 2);
+
+    let app = build_cli();
+    let matches = app
+        .try_get_matches_from(vec!["kild", "diff", "auth-a..auth-b", "--stat"])
+        .unwrap();
+    let diff = matches.subcommand_matches("diff").unwrap();
+    assert_eq!(diff.get_one::<String>("branch").unwrap(), "auth-a..auth-b");
+}
+
+// --- checkpoint command tests ---
+
+#[test]
+fn test_cli_checkpoint_and_rollback() {
+    let app = build_cli();
+    let matches = app
+        .try_get_matches_from(vec!["kild", "checkpoint", "auth", "-m", "before refactor"])
+        .unwrap();
+    let checkpoint = matches.subcommand_matches("checkpoint").unwrap();
+    assert_eq!(checkpoint.get_one::<String>("branch").unwrap(), "auth");
+    assert_eq!(
+        checkpoint.get_one::<String>("message").unwrap(),
+        "before refactor"
+    );
+    assert!(checkpoint.subcommand().is_none());
+
+    let app = build_cli();
+    let matches = app
+        .try_get_matches_from(vec!["kild", "checkpoint", "list", "auth", "--json"])
+        .unwrap();
+    let list = matches
+        .subcommand_matches("checkpoint")
+        .unwrap()
+        .subcommand_matches("list")
+        .unwrap();
+    assert_eq!(list.get_one::<String>("branch").unwrap(), "auth");
+    assert!(list.get_flag("json"));
+
+    let app = build_cli();
+    let matches = app
+        .try_get_matches_from(vec!["kild", "rollback", "auth", "3"])
+        .unwrap();
+    let rollback = matches.subcommand_matches("rollback").unwrap();
+    assert_eq!(*rollback.get_one::<u32>("id").unwrap(), 3);
+
+    let app = build_cli();
+    assert!(
+        app.try_get_matches_from(vec!["kild", "rollback", "auth", "latest"])
+            .is_err()
+    );
+}
+
+// --- cache command tests ---
+
+#[test]
+fn test_cli_cache_subcommands() {
+    let app = build_cli();
+    let matches = app
+        .try_get_matches_from(vec!["kild", "cache", "status", "--json"])
+        .unwrap();
+    let cache = matches.subcommand_matches("cache").unwrap();
+    assert!(cache.subcommand_matches("status").unwrap().get_flag("json"));
+
+    let app = build_cli();
+    assert!(
+        app.try_get_matches_from(vec!["kild", "cache", "prune"])
+            .is_ok()
+    );
+    let app = build_cli();
+    assert!(app.try_get_matches_from(vec!["kild", "cache"]).is_err());
+}
+
 // --- stats command tests ---
 
 #[test]
@@ -1857,6 +2104,23 @@ fn test_cli_inject_with_inbox_flag() {
     assert!(sub.get_flag("inbox"));
 }
 
+#[test]
+fn test_cli_inject_timeout() {
+    let app = build_cli();
+    let matches = app
+        .clone()
+        .try_get_matches_from(vec!["kild", "inject", "my-worker", "msg", "--timeout", "5"])
+        .unwrap();
+    let sub = matches.subcommand_matches("inject").unwrap();
+    assert_eq!(*sub.get_one::<u64>("timeout").unwrap(), 5);
+
+    let matches = app
+        .try_get_matches_from(vec!["kild", "inject", "my-worker", "msg"])
+        .unwrap();
+    let sub = matches.subcommand_matches("inject").unwrap();
+    assert_eq!(*sub.get_one::<u64>("timeout").unwrap(), 30);
+}
+
 #[test]
 fn test_cli_inject_requires_branch_and_text() {
     let app = build_cli();
@@ -1869,6 +2133,83 @@ fn test_cli_inject_requires_branch_and_text() {
     assert!(app.try_get_matches_from(vec!["kild", "inject"]).is_err());
 }
 
+#[test]
+fn test_cli_inject_all_takes_text_only() {
+    let app = build_cli();
+    let matches = app
+        .try_get_matches_from(vec!["kild", "inject", "--all", "run the tests"])
+        .unwrap();
+    let sub = matches.subcommand_matches("inject").unwrap();
+    assert_eq!(sub.get_one::<String>("all").unwrap(), "run the tests");
+    assert!(sub.get_one::<String>("branch").is_none());
+    assert!(sub.get_one::<String>("text").is_none());
+    assert!(sub.get_one::<String>("project").is_none());
+    assert!(!sub.get_flag("include-brain"));
+}
+
+#[test]
+fn test_cli_inject_all_with_project_and_brain() {
+    let app = build_cli();
+    let matches = app
+        .try_get_matches_from(vec![
+            "kild",
+            "inject",
+            "--all",
+            "msg",
+            "--project",
+            "my-app",
+            "--include-brain",
+        ])
+        .unwrap();
+    let sub = matches.subcommand_matches("inject").unwrap();
+    assert_eq!(sub.get_one::<String>("all").unwrap(), "msg");
+    assert_eq!(sub.get_one::<String>("project").unwrap(), "my-app");
+    assert!(sub.get_flag("include-brain"));
+}
+
+#[test]
+fn test_cli_inject_all_rejects_branch() {
+    let app = build_cli();
+    assert!(
+        app.try_get_matches_from(vec!["kild", "inject", "my-worker", "--all", "msg"])
+            .is_err()
+    );
+
+    let app = build_cli();
+    assert!(
+        app.try_get_matches_from(vec!["kild", "inject", "--all"])
+            .is_err()
+    );
+}
+
+#[test]
+fn test_cli_inject_project_requires_all() {
+    let app = build_cli();
+    assert!(
+        app.try_get_matches_from(vec![
+            "kild",
+            "inject",
+            "my-worker",
+            "msg",
+            "--project",
+            "my-app"
+        ])
+        .is_err()
+    );
+
+    let app = build_cli();
+    assert!(
+        app.try_get_matches_from(vec![
+            "kild",
+            "inject",
+            "my-worker",
+            "msg",
+            "--include-brain"
+        ])
+        .is_err()
+    );
+}
+
 // --- create --main flag ---
 
 #[test]
@@ -2001,3 +2342,77 @@ fn test_cli_inbox_requires_branch_or_all() {
     let matches = app.try_get_matches_from(vec!["kild", "inbox"]);
     assert!(matches.is_err());
 }
+
+// --- approvals command tests ---
+
+#[test]
+fn test_cli_approvals_defaults_to_list() {
+    let app = build_cli();
+    let matches = app
+        .try_get_matches_from(vec!["kild", "approvals", "--json"])
+        .unwrap();
+    let sub = matches.subcommand_matches("approvals").unwrap();
+    assert!(sub.subcommand().is_none());
+    assert!(sub.get_flag("json"));
+}
+
+#[test]
+fn test_cli_approvals_allow_requires_id() {
+    let app = build_cli();
+    let matches = app
+        .clone()
+        .try_get_matches_from(vec!["kild", "approvals", "allow", "a3"])
+        .unwrap();
+    let sub = matches.subcommand_matches("approvals").unwrap();
+    let allow = sub.subcommand_matches("allow").unwrap();
+    assert_eq!(allow.get_one::<String>("id").unwrap(), "a3");
+
+    assert!(
+        app.try_get_matches_from(vec!["kild", "approvals", "allow"])
+            .is_err()
+    );
+}
+
+#[test]
+fn test_cli_approvals_deny_with_message() {
+    let app = build_cli();
+    let matches = app
+        .try_get_matches_from(vec![
+            "kild",
+            "approvals",
+            "deny",
+            "a1",
+            "--message",
+            "use the staging db",
+        ])
+        .unwrap();
+    let sub = matches.subcommand_matches("approvals").unwrap();
+    let deny = sub.subcommand_matches("deny").unwrap();
+    assert_eq!(deny.get_one::<String>("id").unwrap(), "a1");
+    assert_eq!(
+        deny.get_one::<String>("message").unwrap(),
+        "use the staging db"
+    );
+}
+
+#[test]
+fn test_cli_export_and_import() {
+    let app = build_cli();
+    let matches = app
+        .try_get_matches_from(vec!["kild", "export", "feature/auth", "-o", "auth.tar"])
+        .unwrap();
+    let export = matches.subcommand_matches("export").unwrap();
+    assert_eq!(export.get_one::<String>("branch").unwrap(), "feature/auth");
+    assert_eq!(export.get_one::<String>("output").unwrap(), "auth.tar");
+
+    let app = build_cli();
+    let matches = app
+        .try_get_matches_from(vec!["kild", "import", "auth.tar", "--as", "auth-2"])
+        .unwrap();
+    let import = matches.subcommand_matches("import").unwrap();
+    assert_eq!(import.get_one::<String>("archive").unwrap(), "auth.tar");
+    assert_eq!(import.get_one::<String>("as").unwrap(), "auth-2");
+
+    let app = build_cli();
+    assert!(app.try_get_matches_from(vec!["kild", "import"]).is_err());
+}
@@ -0,0 +1,138 @@
+use clap::ArgMatches;
+use tracing::{error, info};
+
+use kild_core::daemon::client::{self, DaemonClientError};
+use kild_protocol::{ApprovalDecision, PendingApproval};
+
+use super::helpers;
+
+pub(crate) fn handle_approvals_command(
+    matches: &ArgMatches,
+) -> Result<(), Box<dyn std::error::Error>> {
+    match matches.subcommand() {
+        Some(("list", sub)) => handle_list(sub.get_flag("json")),
+        Some(("allow", sub)) => handle_resolve(sub, ApprovalDecision::Allow),
+        Some(("deny", sub)) => handle_resolve(sub, ApprovalDecision::Deny),
+        _ => handle_list(matches.get_flag("json")),
+    }
+}
+
+fn handle_list(json: bool) -> Result<(), Box<dyn std::error::Error>> {
+    info!(event = "cli.approvals.list_started");
+
+    let approvals = match client::list_approvals() {
+        Ok(approvals) => approvals,
+        Err(DaemonClientError::NotRunning { .. }) if !json => {
+            println!("Daemon is not running — no approvals pending.");
+            return Ok(());
+        }
+        Err(e) => {
+            error!(event = "cli.approvals.list_failed", error = %e);
+            if json {
+                return Err(helpers::print_json_error(&e, "daemon_error"));
+            }
+            eprintln!("{}", crate::color::error(&format!("{}", e)));
+            return Err(e.into());
+        }
+    };
+
+    if json {
+        println!("{}", serde_json::to_string_pretty(&approvals)?);
+    } else {
+        print_approvals(&approvals);
+    }
+
+    info!(
+        event = "cli.approvals.list_completed",
+        count = approvals.len()
+    );
+    Ok(())
+}
+
+fn print_approvals(approvals: &[PendingApproval]) {
+    if approvals.is_empty() {
+        println!("No pending approvals.");
+        return;
+    }
+
+    let id_width = approvals
+        .iter()
+        .map(|a| a.id.len())
+        .max()
+        .unwrap_or(2)
+        .max(2);
+    let branch_width = approvals
+        .iter()
+        .map(|a| a.branch.len())
+        .max()
+        .unwrap_or(6)
+        .max(6);
+    let tool_width = approvals
+        .iter()
+        .map(|a| a.tool.len())
+        .max()
+        .unwrap_or(4)
+        .max(4);
+
+    println!(
+        "{}",
+        crate::color::bold(&format!(
+            "{:<id_width$}  {:<branch_width$}  {:<tool_width$}  REQUEST",
+            "ID", "BRANCH", "TOOL"
+        ))
+    );
+    for approval in approvals {
+        println!(
+            "{:<id_width$}  {}  {:<tool_width$}  {}",
+            approval.id,
+            crate::color::ice(&format!("{:<branch_width$}", approval.branch)),
+            approval.tool,
+            approval.summary,
+        );
+    }
+    println!();
+    println!(
+        "{}",
+        crate::color::hint(
+            "Answer with `kild approvals allow <id>` or `kild approvals deny <id>`."
+        )
+    );
+}
+
+fn handle_resolve(
+    matches: &ArgMatches,
+    decision: ApprovalDecision,
+) -> Result<(), Box<dyn std::error::Error>> {
+    let id = matches
+        .get_one::<String>("id")
+        .ok_or("Approval ID is required")?;
+    let message = matches
+        .try_get_one::<String>("message")
+        .ok()
+        .flatten()
+        .cloned();
+
+    info!(
+        event = "cli.approvals.resolve_started",
+        approval_id = id.as_str(),
+        decision = %decision
+    );
+
+    if let Err(e) = client::resolve_approval(id, decision, message) {
+        eprintln!("{}", crate::color::error(&format!("{}", e)));
+        error!(event = "cli.approvals.resolve_failed", approval_id = id.as_str(), error = %e);
+        return Err(e.into());
+    }
+
+    let verb = match decision {
+        ApprovalDecision::Allow => "Allowed",
+        ApprovalDecision::Deny => "Denied",
+    };
+    println!("{} {}", verb, id);
+    info!(
+        event = "cli.approvals.resolve_completed",
+        approval_id = id.as_str(),
+        decision = %decision
+    );
+    Ok(())
+}
@@ -0,0 +1,120 @@
+use clap::ArgMatches;
+use tracing::{error, info};
+
+use kild_core::cache::{self, LinkState};
+use kild_core::errors::KildError;
+use kild_core::events;
+
+use super::helpers::{format_bytes, print_json_error, shorten_home_path};
+
+pub(crate) fn handle_cache_command(matches: &ArgMatches) -> Result<(), Box<dyn std::error::Error>> {
+    match matches.subcommand() {
+        Some(("prune", sub)) => handle_prune(sub.get_flag("json")),
+        Some(("status", sub)) => handle_status(sub.get_flag("json")),
+        _ => handle_status(false),
+    }
+}
+
+fn fail(e: cache::CacheError, json: bool) -> Box<dyn std::error::Error> {
+    error!(event = "cli.cache_failed", error = %e);
+    events::log_app_error(&e);
+    if json {
+        return print_json_error(&e, e.error_code());
+    }
+    eprintln!("{}", e);
+    e.into()
+}
+
+fn handle_status(json: bool) -> Result<(), Box<dyn std::error::Error>> {
+    info!(event = "cli.cache.status_started");
+
+    let status = cache::cache_status().map_err(|e| fail(e, json))?;
+
+    if json {
+        println!("{}", serde_json::to_string_pretty(&status)?);
+    } else {
+        println!(
+            "Shared cache for {}: {}",
+            status.project,
+            shorten_home_path(&status.cache_dir)
+        );
+        if status.snapshots.is_empty() {
+            println!("  No node_modules snapshots.");
+        }
+        for snapshot in &status.snapshots {
+            let dir = if snapshot.dir.as_os_str().is_empty() {
+                ".".to_string()
+            } else {
+                snapshot.dir.display().to_string()
+            };
+            let used_by = if snapshot.used_by.is_empty() {
+                "unused".to_string()
+            } else {
+                snapshot.used_by.join(", ")
+            };
+            println!(
+                "  {}/node_modules @ {}  {}  ({})",
+                dir,
+                snapshot.lockfile_hash,
+                format_bytes(snapshot.bytes),
+                used_by
+            );
+        }
+
+        let diverged: Vec<_> = status
+            .links
+            .iter()
+            .filter(|l| l.state == LinkState::Diverged)
+            .collect();
+        if !diverged.is_empty() {
+            eprintln!("Kilds whose lockfile no longer matches their node_modules snapshot:");
+            for link in diverged {
+                eprintln!("  - {} ({}/node_modules)", link.branch, link.dir.display());
+            }
+            eprintln!("Reinstall dependencies in those kilds, then run `kild cache prune`.");
+        }
+        println!("  Total: {}", format_bytes(status.total_bytes));
+    }
+
+    info!(
+        event = "cli.cache.status_completed",
+        snapshots = status.snapshots.len(),
+        links = status.links.len()
+    );
+    Ok(())
+}
+
+fn handle_prune(json: bool) -> Result<(), Box<dyn std::error::Error>> {
+    info!(event = "cli.cache.prune_started");
+
+    let report = cache::prune_cache().map_err(|e| fail(e, json))?;
+
+    if json {
+        println!("{}", serde_json::to_string_pretty(&report)?);
+    } else {
+        if report.unlinked.is_empty() && report.removed_snapshots.is_empty() {
+            println!("Nothing to prune.");
+        }
+        for link in &report.unlinked {
+            println!(
+                "Forgot diverged node_modules in {} ({}) — the kild keeps its copy.",
+                link.branch,
+                link.dir.display()
+            );
+        }
+        if !report.removed_snapshots.is_empty() {
+            println!(
+                "Removed {} unused snapshot(s), freed {}.",
+                report.removed_snapshots.len(),
+                format_bytes(report.freed_bytes)
+            );
+        }
+    }
+
+    info!(
+        event = "cli.cache.prune_completed",
+        unlinked = report.unlinked.len(),
+        removed_snapshots = report.removed_snapshots.len()
+    );
+    Ok(())
+}
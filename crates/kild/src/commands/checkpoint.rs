@@ -0,0 +1,156 @@
+use clap::ArgMatches;
+use tracing::{error, info};
+
+use kild_core::Checkpoint;
+use kild_core::events;
+use kild_core::sessions::checkpoints;
+
+use super::helpers;
+use crate::color;
+
+pub(crate) fn handle_checkpoint_command(
+    matches: &ArgMatches,
+) -> Result<(), Box<dyn std::error::Error>> {
+    if let Some(("list", sub)) = matches.subcommand() {
+        return handle_list(sub);
+    }
+
+    let branch = matches
+        .get_one::<String>("branch")
+        .ok_or("Branch argument is required")?;
+    let message = matches.get_one::<String>("message").map(String::as_str);
+
+    info!(event = "cli.checkpoint_started", branch = branch);
+
+    match checkpoints::create_checkpoint(branch, message) {
+        Ok(checkpoint) => {
+            println!(
+                "{} {}",
+                color::aurora("Checkpoint recorded:"),
+                color::ice(&format!("#{}", checkpoint.id))
+            );
+            println!("  {}  {}", color::muted("Branch:"), color::ice(branch));
+            println!("  {} {}", color::muted("Message:"), checkpoint.message);
+            println!(
+                "  {}    {}",
+                color::muted("Undo:"),
+                color::muted(&format!("kild rollback {} {}", branch, checkpoint.id))
+            );
+            info!(
+                event = "cli.checkpoint_completed",
+                branch = branch,
+                id = checkpoint.id
+            );
+            Ok(())
+        }
+        Err(e) => {
+            eprintln!("{}", color::error(&e.to_string()));
+            error!(event = "cli.checkpoint_failed", branch = branch, error = %e);
+            events::log_app_error(&e);
+            Err(e.into())
+        }
+    }
+}
+
+fn handle_list(matches: &ArgMatches) -> Result<(), Box<dyn std::error::Error>> {
+    let branch = matches
+        .get_one::<String>("branch")
+        .ok_or("Branch argument is required")?;
+    let json = matches.get_flag("json");
+
+    info!(event = "cli.checkpoint_list_started", branch = branch);
+
+    let list = match checkpoints::list_checkpoints(branch) {
+        Ok(list) => list,
+        Err(e) => {
+            error!(event = "cli.checkpoint_list_failed", branch = branch, error = %e);
+            events::log_app_error(&e);
+            if json {
+                return Err(helpers::print_json_error(&e, "checkpoint_error"));
+            }
+            eprintln!("{}", color::error(&e.to_string()));
+            return Err(e.into());
+        }
+    };
+
+    if json {
+        println!("{}", serde_json::to_string_pretty(&list)?);
+    } else {
+        print_checkpoints(branch, &list);
+    }
+
+    info!(
+        event = "cli.checkpoint_list_completed",
+        branch = branch,
+        count = list.len()
+    );
+    Ok(())
+}
+
+fn print_checkpoints(branch: &str, checkpoints: &[Checkpoint]) {
+    if checkpoints.is_empty() {
+        println!("No checkpoints for '{}'.", branch);
+        return;
+    }
+
+    for checkpoint in checkpoints.iter().rev() {
+        let created = chrono::DateTime::parse_from_rfc3339(&checkpoint.created_at)
+            .map(|t| {
+                t.with_timezone(&chrono::Local)
+                    .format("%Y-%m-%d %H:%M")
+                    .to_string()
+            })
+            .unwrap_or_else(|_| checkpoint.created_at.clone());
+        println!(
+            "{:>5}  {}  {}  {:<8}  {}",
+            color::ice(&format!("#{}", checkpoint.id)),
+            color::muted(&created),
+            color::muted(&checkpoint.base_commit[..checkpoint.base_commit.len().min(7)]),
+            checkpoint.trigger.as_str(),
+            checkpoint.message
+        );
+    }
+}
+
+pub(crate) fn handle_rollback_command(
+    matches: &ArgMatches,
+) -> Result<(), Box<dyn std::error::Error>> {
+    let branch = matches
+        .get_one::<String>("branch")
+        .ok_or("Branch argument is required")?;
+    let id = *matches
+        .get_one::<u32>("id")
+        .ok_or("ID argument is required")?;
+
+    info!(event = "cli.rollback_started", branch = branch, id = id);
+
+    match checkpoints::rollback(branch, id) {
+        Ok(saved) => {
+            println!(
+                "{} {}",
+                color::aurora("Rolled back to checkpoint"),
+                color::ice(&format!("#{}", id))
+            );
+            println!(
+                "  {} saved as checkpoint #{} (undo: kild rollback {} {})",
+                color::muted("Previous state"),
+                saved.id,
+                branch,
+                saved.id
+            );
+            info!(
+                event = "cli.rollback_completed",
+                branch = branch,
+                id = id,
+                saved_id = saved.id
+            );
+            Ok(())
+        }
+        Err(e) => {
+            eprintln!("{}", color::error(&e.to_string()));
+            error!(event = "cli.rollback_failed", branch = branch, id = id, error = %e);
+            events::log_app_error(&e);
+            Err(e.into())
+        }
+    }
+}
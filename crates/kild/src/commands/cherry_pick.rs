@@ -0,0 +1,127 @@
+use clap::ArgMatches;
+use tracing::{error, info};
+
+use kild_core::events;
+use kild_core::git::GitError;
+
+use super::helpers;
+use crate::color;
+
+pub(crate) fn handle_cherry_pick_command(
+    matches: &ArgMatches,
+) -> Result<(), Box<dyn std::error::Error>> {
+    let from = matches
+        .get_one::<String>("from")
+        .ok_or("Source branch argument is required")?;
+    let to = matches
+        .get_one::<String>("to")
+        .ok_or("Target branch argument is required")?;
+    let revs: Vec<String> = matches
+        .get_many::<String>("commits")
+        .map(|revs| revs.cloned().collect())
+        .unwrap_or_default();
+
+    info!(event = "cli.cherry_pick_started", from = from, to = to, revs = ?revs);
+
+    if from == to {
+        eprintln!("{}", color::error("Source and target kild must differ."));
+        return Err("Source and target kild must differ".into());
+    }
+
+    let source = helpers::require_session(from, "cli.cherry_pick_failed")?;
+    let target = helpers::require_session(to, "cli.cherry_pick_failed")?;
+
+    // git refuses to pick over local changes; say so up front with a clear fix.
+    if kild_core::git::has_uncommitted_changes(&target.worktree_path) != Some(false) {
+        let msg = format!(
+            "'{}' has uncommitted changes. Commit or stash them before cherry-picking.",
+            to
+        );
+        eprintln!("{}", color::error(&msg));
+        error!(
+            event = "cli.cherry_pick_failed",
+            to = to,
+            reason = "uncommitted_changes"
+        );
+        return Err(msg.into());
+    }
+
+    let commits = match kild_core::git::commits_to_pick(
+        &source.worktree_path,
+        &target.worktree_path,
+        &revs,
+    ) {
+        Ok(commits) => commits,
+        Err(e) => return Err(fail(from, to, e)),
+    };
+    if commits.is_empty() {
+        println!(
+            "Nothing to pick: '{}' already has every commit from '{}'.",
+            to, from
+        );
+        info!(
+            event = "cli.cherry_pick_completed",
+            from = from,
+            to = to,
+            count = 0
+        );
+        return Ok(());
+    }
+
+    if let Err(e) = kild_core::git::cli::cherry_pick(&target.worktree_path, &commits) {
+        if let GitError::CherryPickConflict { commit, files } = &e {
+            eprintln!(
+                "{} {} conflicts with '{}':",
+                color::error("Cherry-pick aborted:"),
+                commit,
+                to
+            );
+            for file in files {
+                eprintln!("  {}", file);
+            }
+            eprintln!(
+                "  {}",
+                color::hint(&format!(
+                    "'{}' is unchanged. Pick fewer commits, or resolve by hand in {}",
+                    to,
+                    helpers::shorten_home_path(&target.worktree_path)
+                ))
+            );
+            error!(event = "cli.cherry_pick_failed", from = from, to = to, error = %e);
+            events::log_app_error(&e);
+            return Err(e.into());
+        }
+        return Err(fail(from, to, e));
+    }
+
+    println!(
+        "{} {} {} from {} into {}",
+        color::aurora("Picked"),
+        commits.len(),
+        if commits.len() == 1 {
+            "commit"
+        } else {
+            "commits"
+        },
+        color::ice(from),
+        color::ice(to)
+    );
+    for commit in &commits {
+        println!("  {}", color::muted(&commit[..commit.len().min(7)]));
+    }
+
+    info!(
+        event = "cli.cherry_pick_completed",
+        from = from,
+        to = to,
+        count = commits.len()
+    );
+    Ok(())
+}
+
+fn fail(from: &str, to: &str, e: GitError) -> Box<dyn std::error::Error> {
+    eprintln!("{} {}", color::error("Cherry-pick failed:"), e);
+    error!(event = "cli.cherry_pick_failed", from = from, to = to, error = %e);
+    events::log_app_error(&e);
+    e.into()
+}
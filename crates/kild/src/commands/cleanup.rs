@@ -2,9 +2,10 @@ use clap::ArgMatches;
 use tracing::{error, info};
 
 use kild_core::cleanup;
+use kild_core::errors::KildError;
 use kild_core::events;
 
-use super::helpers::shorten_home_path;
+use super::helpers::{format_bytes, print_json_error, shorten_home_path};
 
 pub(crate) fn handle_cleanup_command(
     sub_matches: &ArgMatches,
@@ -19,10 +20,20 @@ pub(crate) fn handle_cleanup_command(
         cleanup::CleanupStrategy::OlderThan(*days)
     } else if sub_matches.get_flag("orphans") {
         cleanup::CleanupStrategy::Orphans
+    } else if sub_matches.get_flag("merged") {
+        cleanup::CleanupStrategy::Merged
     } else {
         cleanup::CleanupStrategy::All
     };
 
+    let json_output = sub_matches.get_flag("json");
+    if let Some(&max_disk) = sub_matches.get_one::<u64>("max-disk") {
+        return handle_plan(cleanup::plan_max_disk(max_disk), json_output);
+    }
+    if sub_matches.get_flag("dry-run") {
+        return handle_plan(cleanup::plan_cleanup(strategy), json_output);
+    }
+
     let force = sub_matches.get_flag("force");
 
     match cleanup::cleanup_all_with_strategy(strategy, force) {
@@ -96,3 +107,101 @@ pub(crate) fn handle_cleanup_command(
         }
     }
 }
+
+/// Print a cleanup plan (`--dry-run` / `--max-disk`). Nothing is removed.
+fn handle_plan(
+    result: Result<cleanup::CleanupPlan, cleanup::CleanupError>,
+    json_output: bool,
+) -> Result<(), Box<dyn std::error::Error>> {
+    let plan = match result {
+        Ok(plan) => plan,
+        Err(e) => {
+            error!(event = "cli.cleanup_plan_failed", error = %e);
+            events::log_app_error(&e);
+            if json_output {
+                return Err(print_json_error(&e, e.error_code()));
+            }
+            eprintln!("{}", e);
+            return Err(e.into());
+        }
+    };
+
+    info!(
+        event = "cli.cleanup_plan_completed",
+        strategy = %plan.strategy,
+        items = plan.items.len()
+    );
+
+    if json_output {
+        println!("{}", serde_json::to_string_pretty(&plan)?);
+        return Ok(());
+    }
+
+    if let (Some(max), Some(total)) = (plan.max_disk_bytes, plan.total_worktree_bytes) {
+        println!(
+            "Worktrees use {} of {} budget.",
+            format_bytes(total),
+            format_bytes(max)
+        );
+        if plan.items.is_empty() {
+            if total > max {
+                println!("  No idle kilds to suggest — stop some kilds first.");
+            } else {
+                println!("  Within budget, nothing to suggest.");
+            }
+            return Ok(());
+        }
+        println!("Suggested for removal (largest idle first):");
+    } else {
+        println!("Dry run — nothing will be removed.");
+        if plan.items.is_empty() {
+            println!("  No orphaned resources found.");
+            return Ok(());
+        }
+        println!("Would remove ({}):", plan.strategy);
+    }
+
+    for item in &plan.items {
+        let action = match item.action {
+            cleanup::PlanAction::DeleteBranch => "branch",
+            cleanup::PlanAction::RemoveWorktree => "worktree",
+            cleanup::PlanAction::RemoveSession => "kild",
+            cleanup::PlanAction::Suggest => "kild",
+        };
+        println!("  - {} {} — {}", action, item.name, item.reason);
+        if let Some(path) = &item.path {
+            match item.disk {
+                Some(disk) => println!(
+                    "      {}  {} (target {}, node_modules {})",
+                    shorten_home_path(path),
+                    format_bytes(disk.total_bytes),
+                    format_bytes(disk.target_bytes),
+                    format_bytes(disk.node_modules_bytes)
+                ),
+                None => println!("      {}", shorten_home_path(path)),
+            }
+        }
+        let merged = match item.merged {
+            Some(true) => "merged",
+            Some(false) => "not merged",
+            None => "unknown",
+        };
+        println!(
+            "      last commit: {}, PR: {}",
+            item.last_commit.as_deref().unwrap_or("unknown"),
+            merged
+        );
+    }
+
+    println!(
+        "  Total: {} item(s), {} reclaimable",
+        plan.items.len(),
+        format_bytes(plan.reclaimable_bytes)
+    );
+    if plan.max_disk_bytes.is_some() {
+        println!("  Remove with `kild destroy <branch>`.");
+    } else {
+        println!("  Run without --dry-run to apply.");
+    }
+    Ok(())
+}
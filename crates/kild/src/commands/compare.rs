@@ -0,0 +1,165 @@
+use clap::ArgMatches;
+use tracing::{error, info};
+
+use kild_core::events;
+use kild_core::{ComparedFile, FileChange, FileChangeKind, KildComparison};
+
+use super::helpers;
+
+pub(crate) fn handle_compare_command(
+    matches: &ArgMatches,
+) -> Result<(), Box<dyn std::error::Error>> {
+    let left = matches
+        .get_one::<String>("left")
+        .ok_or("Left branch argument is required")?;
+    let right = matches
+        .get_one::<String>("right")
+        .ok_or("Right branch argument is required")?;
+    let json = matches.get_flag("json");
+
+    info!(event = "cli.compare_started", left = left, right = right);
+
+    let left_session = helpers::require_session_json(left, "cli.compare_failed", json)?;
+    let right_session = helpers::require_session_json(right, "cli.compare_failed", json)?;
+
+    let comparison = match kild_core::git::compare_worktrees(
+        &left_session.worktree_path,
+        left,
+        &right_session.worktree_path,
+        right,
+    ) {
+        Ok(comparison) => comparison,
+        Err(e) => {
+            error!(event = "cli.compare_failed", left = left, right = right, error = %e);
+            events::log_app_error(&e);
+            if json {
+                return Err(helpers::print_json_error(&e, "compare_error"));
+            }
+            eprintln!("{} {}", crate::color::error("Compare failed:"), e);
+            return Err(e.into());
+        }
+    };
+
+    if json {
+        println!("{}", serde_json::to_string_pretty(&comparison)?);
+    } else {
+        print_comparison(&comparison);
+    }
+
+    info!(
+        event = "cli.compare_completed",
+        left = left,
+        right = right,
+        files = comparison.files.len()
+    );
+    Ok(())
+}
+
+fn print_comparison(comparison: &KildComparison) {
+    let base = &comparison.base_commit[..comparison.base_commit.len().min(7)];
+    println!(
+        "Changes since common ancestor {}:",
+        crate::color::muted(base)
+    );
+    println!();
+    for side in [&comparison.left, &comparison.right] {
+        println!(
+            "  {}  {} commits, +{} -{} ({} files)",
+            crate::color::ice(&side.branch),
+            side.commits_ahead,
+            side.diff.insertions,
+            side.diff.deletions,
+            side.diff.files_changed
+        );
+    }
+
+    if comparison.files.is_empty() {
+        println!();
+        println!("Neither kild has changed any files.");
+        return;
+    }
+
+    let shared: Vec<&ComparedFile> = comparison.shared().collect();
+    if !shared.is_empty() {
+        println!();
+        println!("Changed by both:");
+        for file in shared {
+            let note = if file.identical {
+                crate::color::muted(" (identical)")
+            } else {
+                String::new()
+            };
+            println!(
+                "  {}  {} {}  {} {}{}",
+                file.path,
+                crate::color::muted(&format!("{}:", comparison.left.branch)),
+                format_change(file.left),
+                crate::color::muted(&format!("{}:", comparison.right.branch)),
+                format_change(file.right),
+                note
+            );
+        }
+    }
+
+    for (branch, files) in [
+        (
+            &comparison.left.branch,
+            comparison.left_only().collect::<Vec<_>>(),
+        ),
+        (
+            &comparison.right.branch,
+            comparison.right_only().collect::<Vec<_>>(),
+        ),
+    ] {
+        if files.is_empty() {
+            continue;
+        }
+        println!();
+        println!("Only {}:", branch);
+        for file in files {
+            println!(
+                "  {}  {}",
+                file.path,
+                format_change(file.left.or(file.right))
+            );
+        }
+    }
+}
+
+fn format_change(change: Option<FileChange>) -> String {
+    let Some(change) = change else {
+        return "-".to_string();
+    };
+    let kind = match change.kind {
+        FileChangeKind::Added => "added ",
+        FileChangeKind::Modified => "",
+        FileChangeKind::Deleted => "deleted ",
+    };
+    format!("{}+{} -{}", kind, change.insertions, change.deletions)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_format_change() {
+        assert_eq!(format_change(None), "-");
+        assert_eq!(
+            format_change(Some(FileChange {
+                kind: FileChangeKind::Added,
+                insertions: 3,
+                deletions: 0,
+            })),
+            "added +3 -0"
+        );
+        assert_eq!(
+            format_change(Some(FileChange {
+                kind: FileChangeKind::Modified,
+                insertions: 1,
+                deletions: 2,
+            })),
+            "+1 -2"
+        );
+    }
+}
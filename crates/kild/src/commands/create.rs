@@ -1,12 +1,14 @@
 use clap::ArgMatches;
 use tracing::{error, info, warn};
 
-use kild_core::CreateSessionRequest;
 use kild_core::events;
 use kild_core::session_ops;
 use kild_core::sessions::fleet;
+use kild_core::{CreateOutcome, CreateSessionRequest};
 
-use super::helpers::{load_config_with_warning, resolve_runtime_mode, shorten_home_path};
+use super::helpers::{
+    format_bytes, load_config_with_warning, resolve_runtime_mode, shorten_home_path,
+};
 use crate::color;
 
 pub(crate) fn handle_create_command(
@@ -47,16 +49,15 @@ pub(crate) fn handle_create_command(
                 .get_one::<String>("agent")
                 .map(|s| s.as_str())
                 .unwrap_or(&config.agent.default);
-            if let Some(yolo) = kild_core::agents::get_yolo_flags(agent_name) {
+            if let Some(flags) =
+                kild_core::agents::with_yolo_flags(agent_name, config.agent.flags.as_deref())
+            {
                 info!(
                     event = "cli.create.yolo_flags_resolved",
                     agent = agent_name,
-                    flags = yolo
+                    flags = flags
                 );
-                config.agent.flags = Some(match config.agent.flags {
-                    Some(existing) => format!("{} {}", yolo, existing),
-                    None => yolo.to_string(),
-                });
+                config.agent.flags = Some(flags);
             } else {
                 warn!(
                     event = "cli.create.yolo_not_supported",
@@ -89,6 +90,14 @@ pub(crate) fn handle_create_command(
     let initial_prompt = matches.get_one::<String>("initial-prompt").cloned();
     let issue = matches.get_one::<u32>("issue").copied();
 
+    let sparse_paths = if matches.get_flag("no-sparse") {
+        Some(Vec::new())
+    } else {
+        matches
+            .get_many::<String>("sparse")
+            .map(|paths| paths.cloned().collect())
+    };
+
     let rows = matches.get_one::<u16>("rows").copied();
     let cols = matches.get_one::<u16>("cols").copied();
 
@@ -99,10 +108,14 @@ pub(crate) fn handle_create_command(
         .with_runtime_mode(runtime_mode)
         .with_main_worktree(use_main)
         .with_initial_prompt(initial_prompt.clone())
-        .with_pty_size(rows, cols);
+        .with_pty_size(rows, cols)
+        .with_sparse_paths(sparse_paths);
 
     match session_ops::create_session(request, &config) {
-        Ok(session) => {
+        Ok(CreateOutcome {
+            session,
+            include_copy,
+        }) => {
             println!("{}", color::aurora("Kild created."));
             println!(
                 "  {}   {}",
@@ -129,6 +142,20 @@ pub(crate) fn handle_create_command(
                 session.port_range_start,
                 session.port_range_end
             );
+            if !session.sparse_paths.is_empty() {
+                println!(
+                    "  {}   {}",
+                    color::muted("Sparse:"),
+                    session.sparse_paths.join(", ")
+                );
+            }
+            if let Some(report) = include_copy.filter(|r| r.copied > 0) {
+                println!(
+                    "  {}    {}",
+                    color::muted("Files:"),
+                    format_include_copy(&report)
+                );
+            }
             let status_str = format!("{:?}", session.status).to_lowercase();
             println!(
                 "  {}   {}",
@@ -231,3 +258,56 @@ pub(crate) fn handle_create_command(
         }
     }
 }
+
+/// One-line summary of include-pattern copying, e.g.
+/// `21 included, 1.9 GB shared (20 hardlinked)`.
+fn format_include_copy(report: &kild_core::files::CopyReport) -> String {
+    let mut line = format!("{} included", report.copied);
+    if report.bytes_saved > 0 {
+        let mut linked = Vec::new();
+        if report.reflinked > 0 {
+            linked.push(format!("{} reflinked", report.reflinked));
+        }
+        if report.hardlinked > 0 {
+            linked.push(format!("{} hardlinked", report.hardlinked));
+        }
+        line.push_str(&format!(
+            ", {} shared ({})",
+            format_bytes(report.bytes_saved),
+            linked.join(", ")
+        ));
+    }
+    if report.failed > 0 {
+        line.push_str(&format!(", {} failed", report.failed));
+    }
+    line
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use kild_core::files::CopyReport;
+
+    #[test]
+    fn test_format_include_copy() {
+        let plain = CopyReport {
+            copied: 3,
+            bytes: 120,
+            ..Default::default()
+        };
+        assert_eq!(format_include_copy(&plain), "3 included");
+
+        let linked = CopyReport {
+            copied: 21,
+            failed: 1,
+            reflinked: 1,
+            hardlinked: 20,
+            bytes: 2 * 1024 * 1024 * 1024,
+            bytes_saved: 2 * 1024 * 1024 * 1024,
+        };
+        assert_eq!(
+            format_include_copy(&linked),
+            "21 included, 2.0 GB shared (1 reflinked, 20 hardlinked), 1 failed"
+        );
+    }
+}
@@ -2,6 +2,7 @@ use clap::ArgMatches;
 use tracing::{error, info};
 
 use kild_core::events;
+use kild_core::git::compare::worktree_tree_id;
 use kild_core::git::get_diff_stats;
 
 use super::helpers;
@@ -14,6 +15,17 @@ pub(crate) fn handle_diff_command(matches: &ArgMatches) -> Result<(), Box<dyn st
     let staged = matches.get_flag("staged");
     let stat = matches.get_flag("stat");
 
+    if let Some((left, right)) = branch.split_once("..") {
+        if staged {
+            eprintln!(
+                "{}",
+                crate::color::error("--staged cannot be used when comparing two kilds.")
+            );
+            return Err("--staged cannot be used when comparing two kilds".into());
+        }
+        return handle_kild_diff(left, right, stat);
+    }
+
     info!(
         event = "cli.diff_started",
         branch = branch,
@@ -55,3 +67,45 @@ pub(crate) fn handle_diff_command(matches: &ArgMatches) -> Result<(), Box<dyn st
 
     Ok(())
 }
+
+/// Diff two kilds' working trees (`kild diff a..b`).
+fn handle_kild_diff(left: &str, right: &str, stat: bool) -> Result<(), Box<dyn std::error::Error>> {
+    info!(
+        event = "cli.diff_kilds_started",
+        left = left,
+        right = right,
+        stat = stat
+    );
+
+    let left_session = helpers::require_session(left, "cli.diff_kilds_failed")?;
+    let right_session = helpers::require_session(right, "cli.diff_kilds_failed")?;
+
+    let result = worktree_tree_id(&left_session.worktree_path).and_then(|left_tree| {
+        let right_tree = worktree_tree_id(&right_session.worktree_path)?;
+        kild_core::git::cli::show_tree_diff(
+            &left_session.worktree_path,
+            &left_tree,
+            &right_tree,
+            (left, right),
+            stat,
+        )
+    });
+    if let Err(e) = result {
+        eprintln!("{} {}", crate::color::error("Diff failed:"), e);
+        error!(
+            event = "cli.diff_kilds_failed",
+            left = left,
+            right = right,
+            error = %e
+        );
+        events::log_app_error(&e);
+        return Err(e.into());
+    }
+
+    info!(
+        event = "cli.diff_kilds_completed",
+        left = left,
+        right = right
+    );
+    Ok(())
+}
@@ -6,7 +6,7 @@ use kild_core::events;
 use kild_core::health;
 
 use super::helpers::{is_valid_branch_name, load_config_with_warning};
-use crate::table::{display_width, pad};
+use crate::table::{display_width, pad, sparkline};
 
 pub(crate) fn handle_health_command(
     matches: &ArgMatches,
@@ -24,7 +24,16 @@ pub(crate) fn handle_health_command(
         interval = interval
     );
 
-    if watch_mode {
+    if matches.get_flag("history") {
+        let days = *matches.get_one::<u64>("days").unwrap_or(&7);
+        let granularity = matches
+            .get_one::<String>("granularity")
+            .map(|g| g.parse::<health::HistoryGranularity>())
+            .transpose()?
+            .unwrap_or(health::HistoryGranularity::Day);
+        let csv_output = matches.get_flag("csv");
+        run_health_history(branch, json_output, csv_output, days, granularity)
+    } else if watch_mode {
         run_health_watch_loop(branch, json_output, interval)
     } else {
         run_health_once(branch, json_output).map(|_| ())
@@ -138,6 +147,191 @@ fn run_health_once(
     }
 }
 
+fn run_health_history(
+    branch: Option<&String>,
+    json_output: bool,
+    csv_output: bool,
+    days: u64,
+    granularity: health::HistoryGranularity,
+) -> Result<(), Box<dyn std::error::Error>> {
+    let snapshots = match health::load_history(days) {
+        Ok(snapshots) => snapshots,
+        Err(e) => {
+            error!(event = "cli.health_history_failed", error = %e);
+            if json_output {
+                return Err(super::helpers::print_json_error(
+                    &e,
+                    "HEALTH_HISTORY_LOAD_FAILED",
+                ));
+            }
+            eprintln!(
+                "{} {}",
+                crate::color::error("Failed to load health history:"),
+                e
+            );
+            return Err(e.into());
+        }
+    };
+
+    if csv_output {
+        print!("{}", health::history::snapshots_to_csv(&snapshots));
+        info!(
+            event = "cli.health_history_completed",
+            snapshots = snapshots.len(),
+            format = "csv"
+        );
+        return Ok(());
+    }
+
+    let mut report = health::build_report(&snapshots, days, granularity);
+    if let Some(branch) = branch {
+        report.timelines.retain(|t| &t.branch == branch);
+    }
+
+    if json_output {
+        println!("{}", serde_json::to_string_pretty(&report)?);
+    } else {
+        print_health_history(&report);
+    }
+
+    info!(
+        event = "cli.health_history_completed",
+        snapshots = report.snapshot_count,
+        buckets = report.buckets.len()
+    );
+    Ok(())
+}
+
+fn print_health_history(report: &health::HistoryReport) {
+    if report.buckets.is_empty() {
+        println!(
+            "No health history recorded in the last {} day(s).",
+            report.days
+        );
+        println!(
+            "{}",
+            crate::color::hint(
+                "Run `kild health --watch` with health.history_enabled = true to record snapshots."
+            )
+        );
+        return;
+    }
+
+    let bucket_label = |b: &health::history::HistoryBucket| match report.granularity {
+        health::HistoryGranularity::Day => b.start.format("%Y-%m-%d").to_string(),
+        health::HistoryGranularity::Hour => b.start.format("%m-%d %H:00").to_string(),
+    };
+
+    let label_w = report
+        .buckets
+        .iter()
+        .map(|b| display_width(&bucket_label(b)))
+        .max()
+        .unwrap_or(0)
+        .max("Period".len());
+
+    println!(
+        "Health History (last {} day(s), {} snapshots)",
+        report.days, report.snapshot_count
+    );
+    println!();
+    println!(
+        "{}  {:>7}  {:>7}  {:>7}  {:>7}  {:>7}  {:>7}  {:>8}",
+        pad("Period", label_w),
+        "Total",
+        "Working",
+        "Idle",
+        "Stuck",
+        "Crashed",
+        "CPU %",
+        "Memory"
+    );
+    for b in &report.buckets {
+        println!(
+            "{}  {:>7.1}  {:>7.1}  {:>7.1}  {:>7.1}  {:>7.1}  {:>7}  {:>8}",
+            pad(&bucket_label(b), label_w),
+            b.avg_total,
+            b.avg_working,
+            b.avg_idle,
+            b.avg_stuck,
+            b.avg_crashed,
+            b.avg_cpu_percent
+                .map(|c| format!("{:.1}", c))
+                .unwrap_or_else(|| "N/A".to_string()),
+            b.avg_memory_mb
+                .map(|m| format!("{}MB", m))
+                .unwrap_or_else(|| "N/A".to_string()),
+        );
+    }
+
+    let max_total = report
+        .buckets
+        .iter()
+        .map(|b| b.avg_total)
+        .fold(0.0_f32, f32::max);
+    let series = |f: fn(&health::history::HistoryBucket) -> f32| -> Vec<f32> {
+        report.buckets.iter().map(f).collect()
+    };
+
+    println!();
+    println!(
+        "Working  {}",
+        crate::color::aurora(&sparkline(&series(|b| b.avg_working), max_total))
+    );
+    println!(
+        "Idle     {}",
+        crate::color::muted(&sparkline(&series(|b| b.avg_idle), max_total))
+    );
+    println!(
+        "Stuck    {}",
+        crate::color::copper(&sparkline(&series(|b| b.avg_stuck), max_total))
+    );
+    println!(
+        "Crashed  {}",
+        crate::color::ember(&sparkline(&series(|b| b.avg_crashed), max_total))
+    );
+
+    if !report.timelines.is_empty() {
+        let branch_w = report
+            .timelines
+            .iter()
+            .map(|t| display_width(&t.branch))
+            .max()
+            .unwrap_or(0);
+
+        println!();
+        println!("Kild activity (█ working, ▄ idle, ▲ stuck, x crashed)");
+        for timeline in &report.timelines {
+            let cells: String = report
+                .buckets
+                .iter()
+                .map(|b| {
+                    match timeline
+                        .entries
+                        .iter()
+                        .find(|e| e.start == b.start)
+                        .map(|e| &e.status)
+                    {
+                        Some(health::HealthStatus::Working) => crate::color::aurora("█"),
+                        Some(health::HealthStatus::Idle) => crate::color::muted("▄"),
+                        Some(health::HealthStatus::Stuck) => crate::color::copper("▲"),
+                        Some(health::HealthStatus::Crashed) => crate::color::ember("x"),
+                        Some(health::HealthStatus::Unknown) => "?".to_string(),
+                        None => " ".to_string(),
+                    }
+                })
+                .collect();
+            println!("{}  {}", pad(&timeline.branch, branch_w), cells);
+        }
+    }
+
+    println!();
+    println!(
+        "Fleet working {:.0}% of the time",
+        report.overall_working_ratio() * 100.0
+    );
+}
+
 fn print_health_table(output: &health::HealthOutput) {
     if output.kilds.is_empty() {
         println!("No active kilds found.");
@@ -317,7 +511,7 @@ fn print_single_kild_health(kild: &health::KildHealth) {
         .unwrap_or("Never")
         .to_string();
 
-    let rows: Vec<(&str, String)> = vec![
+    let mut rows: Vec<(&str, String)> = vec![
         ("Branch:", kild.branch.clone()),
         ("Agent:", kild.agent.clone()),
         ("Activity:", activity),
@@ -328,6 +522,12 @@ fn print_single_kild_health(kild: &health::KildHealth) {
         ("Memory:", mem_str),
         ("Last Active:", last_active),
     ];
+    if let Some(output_at) = &kild.metrics.last_output_at {
+        rows.push(("Last Output:", output_at.clone()));
+    }
+    if let Some(input_at) = &kild.metrics.last_input_at {
+        rows.push(("Last Input:", input_at.clone()));
+    }
 
     // "Last Active:" is the longest label at 12 chars + 1 space
     let label_width = 13;
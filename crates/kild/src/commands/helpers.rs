@@ -204,6 +204,21 @@ pub fn format_count(count: usize) -> String {
     format!("{} {}", count, plural(count))
 }
 
+/// Format a byte count for display, e.g. "512 B" or "1.5 GB".
+pub fn format_bytes(bytes: u64) -> String {
+    const UNITS: [&str; 4] = ["KB", "MB", "GB", "TB"];
+    if bytes < 1024 {
+        return format!("{} B", bytes);
+    }
+    let mut value = bytes as f64 / 1024.0;
+    let mut unit = 0;
+    while value >= 1024.0 && unit < UNITS.len() - 1 {
+        value /= 1024.0;
+        unit += 1;
+    }
+    format!("{:.1} {}", value, UNITS[unit])
+}
+
 /// Replace home directory prefix with `~` for display.
 pub fn shorten_home_path(path: &std::path::Path) -> String {
     if let Ok(home) = std::env::var("HOME") {
@@ -477,6 +492,14 @@ mod tests {
         assert_eq!(format_count(10), "10 kilds");
     }
 
+    #[test]
+    fn test_format_bytes() {
+        assert_eq!(format_bytes(0), "0 B");
+        assert_eq!(format_bytes(1023), "1023 B");
+        assert_eq!(format_bytes(1536), "1.5 KB");
+        assert_eq!(format_bytes(20 * 1024 * 1024 * 1024), "20.0 GB");
+    }
+
     #[test]
     fn test_shorten_home_path_with_home_prefix() {
         if let Ok(home) = std::env::var("HOME") {
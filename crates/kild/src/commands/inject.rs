@@ -1,21 +1,38 @@
 use clap::ArgMatches;
 use tracing::{error, info, warn};
 
+use kild_core::CheckpointTrigger;
 use kild_core::agents::is_claude_agent;
+use kild_core::projects::{generate_project_id, load_projects};
+use kild_core::sessions::delivery::{self, DeliveryOptions, DeliveryState};
 use kild_core::sessions::fleet;
+use kild_protocol::ProjectId;
 
-use super::helpers;
+use super::helpers::{self, FailedOperation, format_partial_failure_error};
+use super::project::resolve_identifier;
 
 pub(crate) fn handle_inject_command(
     matches: &ArgMatches,
 ) -> Result<(), Box<dyn std::error::Error>> {
+    let force_inbox = matches.get_flag("inbox");
+    let prompt_timeout = matches.get_one::<u64>("timeout").copied().unwrap_or(30);
+
+    if let Some(text) = matches.get_one::<String>("all") {
+        return handle_inject_all(
+            text,
+            matches.get_one::<String>("project").map(String::as_str),
+            matches.get_flag("include-brain"),
+            force_inbox,
+            prompt_timeout,
+        );
+    }
+
     let branch = matches
         .get_one::<String>("branch")
         .ok_or("Branch argument is required")?;
     let text = matches
         .get_one::<String>("text")
         .ok_or("Text argument is required")?;
-    let force_inbox = matches.get_flag("inbox");
 
     // Reject empty text — it produces a no-op inbox message or blank PTY input.
     if text.trim().is_empty() {
@@ -47,6 +64,157 @@ pub(crate) fn handle_inject_command(
         return Err(msg.into());
     }
 
+    let via = inject_into(&session, text, force_inbox, prompt_timeout)?;
+
+    println!(
+        "{} {} (via {})",
+        crate::color::muted("Sent to"),
+        crate::color::ice(branch),
+        via
+    );
+    info!(event = "cli.inject_completed", branch = branch, via = via);
+    Ok(())
+}
+
+/// Send `text` to every active kild in a project.
+///
+/// Stopped kilds are skipped; the brain session is skipped unless
+/// `include_brain` is set. Delivery failures are reported per kild and
+/// summarised at the end.
+fn handle_inject_all(
+    text: &str,
+    project: Option<&str>,
+    include_brain: bool,
+    force_inbox: bool,
+    prompt_timeout: u64,
+) -> Result<(), Box<dyn std::error::Error>> {
+    if text.trim().is_empty() {
+        eprintln!("{}", crate::color::error("Inject text cannot be empty."));
+        return Err("Inject text cannot be empty".into());
+    }
+
+    let project_id = resolve_project_id(project)?;
+    info!(
+        event = "cli.inject_all_started",
+        project_id = %project_id,
+        include_brain = include_brain
+    );
+
+    let mut targets = Vec::new();
+    for mut session in kild_core::session_ops::list_sessions()? {
+        if session.project_id != project_id {
+            continue;
+        }
+        if !include_brain && session.branch.as_ref() == fleet::BRAIN_BRANCH {
+            info!(event = "cli.inject.brain_skipped", branch = %session.branch);
+            continue;
+        }
+        kild_core::session_ops::sync_daemon_session_status(&mut session);
+        if session.status == kild_core::SessionStatus::Active {
+            targets.push(session);
+        }
+    }
+
+    if targets.is_empty() {
+        println!("No active kilds to inject into.");
+        info!(event = "cli.inject_all_completed", sent = 0, failed = 0);
+        return Ok(());
+    }
+
+    let mut sent = 0;
+    let mut errors: Vec<FailedOperation> = Vec::new();
+    for session in &targets {
+        match inject_into(session, text, force_inbox, prompt_timeout) {
+            Ok(via) => {
+                println!(
+                    "{} {} (via {})",
+                    crate::color::muted("Sent to"),
+                    crate::color::ice(&session.branch),
+                    via
+                );
+                info!(event = "cli.inject_completed", branch = %session.branch, via = via);
+                sent += 1;
+            }
+            Err(e) => errors.push((session.branch.to_string(), e.to_string())),
+        }
+    }
+
+    info!(
+        event = "cli.inject_all_completed",
+        sent = sent,
+        failed = errors.len()
+    );
+
+    if !errors.is_empty() {
+        eprintln!(
+            "{}",
+            crate::color::error(&format!(
+                "{} failed to receive the message:",
+                helpers::format_count(errors.len())
+            ))
+        );
+        for (branch, err) in &errors {
+            eprintln!("  {}: {}", crate::color::ice(branch), err);
+        }
+        return Err(format_partial_failure_error("inject", errors.len(), targets.len()).into());
+    }
+    Ok(())
+}
+
+/// Project to broadcast into: the named project (name, path or ID), or the
+/// repository the command runs in.
+fn resolve_project_id(project: Option<&str>) -> Result<ProjectId, Box<dyn std::error::Error>> {
+    let Some(identifier) = project else {
+        let detected = kild_core::git::detect_project()?;
+        return Ok(ProjectId::new(detected.id));
+    };
+
+    let data = load_projects();
+    if let Some(ref load_error) = data.load_error {
+        eprintln!("{}", crate::color::error(load_error));
+        return Err(load_error.clone().into());
+    }
+    let path = resolve_identifier(&data, identifier).or_else(|| {
+        data.projects
+            .iter()
+            .find(|p| p.name() == identifier)
+            .map(|p| p.path().to_path_buf())
+    });
+    match path {
+        Some(path) => Ok(generate_project_id(&path)),
+        None => {
+            let msg = format!(
+                "Project not found: {}. See `kild project list` for registered projects.",
+                identifier
+            );
+            eprintln!("{}", crate::color::error(&msg));
+            Err(msg.into())
+        }
+    }
+}
+
+/// Checkpoint, queue and deliver `text` to one active session.
+///
+/// Returns how the text was delivered (`"inbox"` or `"pty"`).
+fn inject_into(
+    session: &kild_core::Session,
+    text: &str,
+    force_inbox: bool,
+    prompt_timeout: u64,
+) -> Result<&'static str, Box<dyn std::error::Error>> {
+    let branch = session.branch.as_ref();
+
+    // Snapshot the worktree before the agent acts on new input ([checkpoints] before_inject).
+    if let Some(checkpoint) =
+        kild_core::sessions::checkpoints::auto_checkpoint(branch, CheckpointTrigger::Inject)
+    {
+        info!(
+            event = "cli.inject.checkpoint_recorded",
+            branch = branch,
+            id = checkpoint.id
+        );
+    }
+
     // 1. Write task to file inbox (universal, all agents).
     match kild_core::sessions::inbox::write_task(&session.project_id, &session.branch, text) {
         Ok(true) => {
@@ -77,26 +245,19 @@ pub(crate) fn handle_inject_command(
             return Err(e.into());
         }
     } else {
-        // 3. Non-Claude: PTY nudge — write the task text directly to PTY stdin.
-        write_to_pty(&session, text)?;
+        // 3. Non-Claude: type into the PTY and confirm the agent picked it up.
+        deliver_to_pty(session, text, prompt_timeout)?;
     }
 
-    let via = if is_claude { "inbox" } else { "pty" };
-
-    println!(
-        "{} {} (via {})",
-        crate::color::muted("Sent to"),
-        crate::color::ice(branch),
-        via
-    );
-    info!(event = "cli.inject_completed", branch = branch, via = via);
-    Ok(())
+    Ok(if is_claude { "inbox" } else { "pty" })
 }
 
-/// Write text to the agent's PTY stdin via the daemon WriteStdin IPC.
-fn write_to_pty(
+/// Type text into the agent's PTY via the daemon, waiting for its input
+/// prompt first and confirming it started working afterwards.
+fn deliver_to_pty(
     session: &kild_core::Session,
     text: &str,
+    prompt_timeout: u64,
 ) -> Result<(), Box<dyn std::error::Error>> {
     let daemon_session_id = session
         .latest_agent()
@@ -109,11 +270,44 @@ fn write_to_pty(
             )
         })?;
 
-    kild_core::daemon::client::write_stdin(daemon_session_id, text.as_bytes())
-        .map_err(|e| format!("PTY write failed (text): {}", e))?;
-
-    std::thread::sleep(std::time::Duration::from_millis(50));
+    let options = DeliveryOptions {
+        prompt_timeout: std::time::Duration::from_secs(prompt_timeout),
+        ..DeliveryOptions::default()
+    };
+    let report = delivery::deliver_to_daemon_session(session, daemon_session_id, text, &options);
 
-    kild_core::daemon::client::write_stdin(daemon_session_id, b"\r")
-        .map_err(|e| format!("PTY write failed (enter): {}", e).into())
+    match report.state {
+        DeliveryState::Delivered => Ok(()),
+        DeliveryState::Unverified => {
+            warn!(
+                event = "cli.inject.delivery_unverified",
+                branch = %session.branch,
+                reason = ?report.reason
+            );
+            eprintln!(
+                "{}",
+                crate::color::warning(&format!(
+                    "Warning: Sent to '{}' but could not confirm delivery ({}).",
+                    session.branch,
+                    report.reason.as_deref().unwrap_or("unknown")
+                ))
+            );
+            Ok(())
+        }
+        _ => {
+            let msg = format!(
+                "Undelivered to '{}': {}. The task is still in the inbox (task.md).",
+                session.branch,
+                report.reason.as_deref().unwrap_or("unknown reason")
+            );
+            eprintln!("{}", crate::color::error(&msg));
+            error!(
+                event = "cli.inject_failed",
+                branch = %session.branch,
+                reason = "undelivered",
+                attempts = report.attempts
+            );
+            Err(msg.into())
+        }
+    }
 }
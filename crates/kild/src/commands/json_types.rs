@@ -76,15 +76,19 @@ impl FleetSummary {
 pub struct ListOutput {
     pub sessions: Vec<EnrichedSession>,
     pub fleet_summary: FleetSummary,
+    pub usage_by_project: Vec<kild_core::ProjectUsage>,
 }
 
 impl ListOutput {
-    /// Construct list output with fleet summary derived from the sessions.
+    /// Construct list output with fleet summary and per-project usage derived from the sessions.
     pub fn new(sessions: Vec<EnrichedSession>, kilds_with_conflicts: &HashSet<&str>) -> Self {
         let fleet_summary = FleetSummary::from_enriched(&sessions, kilds_with_conflicts.len());
+        let usages: Vec<kild_core::KildUsage> =
+            sessions.iter().filter_map(|e| e.usage.clone()).collect();
         Self {
             sessions,
             fleet_summary,
+            usage_by_project: kild_core::usage::aggregate_by_project(&usages),
         }
     }
 }
@@ -119,4 +123,5 @@ pub struct EnrichedSession {
     pub terminal_type: Option<String>,
     pub pr_info: Option<kild_core::PullRequest>,
     pub overlapping_files: Option<Vec<String>>,
+    pub usage: Option<kild_core::KildUsage>,
 }
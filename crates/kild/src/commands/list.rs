@@ -53,9 +53,11 @@ pub(crate) fn handle_list_command(matches: &ArgMatches) -> Result<(), Box<dyn st
             let conflict_count = kilds_with_conflicts.len();
 
             if json_output {
+                let usages = kild_core::usage::collect_usage(&sessions, &config.usage);
                 let enriched: Vec<EnrichedSession> = sessions
                     .into_iter()
-                    .map(|session| {
+                    .zip(usages)
+                    .map(|(session, usage)| {
                         let git_stats = kild_core::git::collect_git_stats(
                             &session.worktree_path,
                             &session.branch,
@@ -114,6 +116,7 @@ pub(crate) fn handle_list_command(matches: &ArgMatches) -> Result<(), Box<dyn st
                             terminal_type,
                             pr_info,
                             overlapping_files,
+                            usage: Some(usage),
                         }
                     })
                     .collect();
@@ -7,11 +7,16 @@ pub mod helpers;
 mod json_types;
 
 mod agent_status;
+mod approvals;
 mod attach;
+mod cache;
 mod cd;
+mod checkpoint;
+mod cherry_pick;
 mod cleanup;
 mod code;
 mod commits;
+mod compare;
 mod complete;
 mod completions;
 mod create;
@@ -31,11 +36,13 @@ mod pr;
 mod prime;
 mod project;
 mod rebase;
+mod sparse;
 mod stats;
 mod status;
 mod stop;
 mod sync;
 mod teammates;
+mod transfer;
 
 pub fn run_command(matches: &ArgMatches) -> Result<(), Box<dyn std::error::Error>> {
     events::log_app_startup();
@@ -53,6 +60,8 @@ pub fn run_command(matches: &ArgMatches) -> Result<(), Box<dyn std::error::Error
         Some(("focus", sub_matches)) => focus::handle_focus_command(sub_matches),
         Some(("hide", sub_matches)) => hide::handle_hide_command(sub_matches),
         Some(("diff", sub_matches)) => diff::handle_diff_command(sub_matches),
+        Some(("compare", sub_matches)) => compare::handle_compare_command(sub_matches),
+        Some(("cherry-pick", sub_matches)) => cherry_pick::handle_cherry_pick_command(sub_matches),
         Some(("commits", sub_matches)) => commits::handle_commits_command(sub_matches),
         Some(("pr", sub_matches)) => pr::handle_pr_command(sub_matches),
         Some(("stats", sub_matches)) => stats::handle_stats_command(sub_matches),
@@ -63,14 +72,21 @@ pub fn run_command(matches: &ArgMatches) -> Result<(), Box<dyn std::error::Error
         }
         Some(("rebase", sub_matches)) => rebase::handle_rebase_command(sub_matches),
         Some(("sync", sub_matches)) => sync::handle_sync_command(sub_matches),
+        Some(("sparse", sub_matches)) => sparse::handle_sparse_command(sub_matches),
+        Some(("checkpoint", sub_matches)) => checkpoint::handle_checkpoint_command(sub_matches),
+        Some(("rollback", sub_matches)) => checkpoint::handle_rollback_command(sub_matches),
         Some(("cleanup", sub_matches)) => cleanup::handle_cleanup_command(sub_matches),
+        Some(("cache", sub_matches)) => cache::handle_cache_command(sub_matches),
         Some(("health", sub_matches)) => health::handle_health_command(sub_matches),
         Some(("daemon", sub_matches)) => daemon::handle_daemon_command(sub_matches),
         Some(("attach", sub_matches)) => attach::handle_attach_command(sub_matches),
         Some(("inbox", sub_matches)) => inbox::handle_inbox_command(sub_matches),
         Some(("prime", sub_matches)) => prime::handle_prime_command(sub_matches),
         Some(("inject", sub_matches)) => inject::handle_inject_command(sub_matches),
+        Some(("approvals", sub_matches)) => approvals::handle_approvals_command(sub_matches),
         Some(("teammates", sub_matches)) => teammates::handle_teammates_command(sub_matches),
+        Some(("export", sub_matches)) => transfer::handle_export_command(sub_matches),
+        Some(("import", sub_matches)) => transfer::handle_import_command(sub_matches),
         Some(("init-hooks", sub_matches)) => init_hooks::handle_init_hooks_command(sub_matches),
         Some(("project", sub_matches)) => project::handle_project_command(sub_matches),
         _ => {
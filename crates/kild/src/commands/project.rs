@@ -329,7 +329,7 @@ fn handle_project_default(matches: &ArgMatches) -> Result<(), Box<dyn std::error
 
 /// Resolve a user-supplied identifier (path string or hex project ID) to a canonical PathBuf.
 /// Returns None if not found in the projects list.
-fn resolve_identifier(data: &ProjectsData, identifier: &str) -> Option<PathBuf> {
+pub(crate) fn resolve_identifier(data: &ProjectsData, identifier: &str) -> Option<PathBuf> {
     // Try as a filesystem path first
     match Path::new(identifier).canonicalize() {
         Ok(canonical)
@@ -0,0 +1,56 @@
+use clap::ArgMatches;
+use tracing::{error, info};
+
+use kild_core::events;
+use kild_core::sessions;
+
+use crate::color;
+
+pub(crate) fn handle_sparse_command(
+    matches: &ArgMatches,
+) -> Result<(), Box<dyn std::error::Error>> {
+    match matches.subcommand() {
+        Some(("add", sub)) => handle_add(sub),
+        _ => Err("Unknown sparse subcommand".into()),
+    }
+}
+
+fn handle_add(matches: &ArgMatches) -> Result<(), Box<dyn std::error::Error>> {
+    let branch = matches
+        .get_one::<String>("branch")
+        .ok_or("Branch argument is required")?;
+    let paths: Vec<String> = matches
+        .get_many::<String>("paths")
+        .map(|paths| paths.cloned().collect())
+        .unwrap_or_default();
+
+    info!(event = "cli.sparse_add_started", branch = branch, paths = ?paths);
+
+    match sessions::add_sparse_paths(branch, &paths) {
+        Ok(session) => {
+            println!("{}", color::aurora("Sparse checkout updated."));
+            println!(
+                "  {}   {}",
+                color::muted("Branch:"),
+                color::ice(&session.branch)
+            );
+            println!(
+                "  {}   {}",
+                color::muted("Sparse:"),
+                session.sparse_paths.join(", ")
+            );
+            info!(
+                event = "cli.sparse_add_completed",
+                branch = branch,
+                sparse_paths = ?session.sparse_paths
+            );
+            Ok(())
+        }
+        Err(e) => {
+            eprintln!("{}", color::error(&e.to_string()));
+            error!(event = "cli.sparse_add_failed", branch = branch, error = %e);
+            events::log_app_error(&e);
+            Err(e.into())
+        }
+    }
+}
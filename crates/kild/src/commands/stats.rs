@@ -4,20 +4,24 @@ use tracing::{error, info};
 
 use kild_core::BranchHealth;
 use kild_core::ConflictStatus;
+use kild_core::KildUsage;
 use kild_core::MergeReadiness;
+use kild_core::UsageConfig;
 use kild_core::session_ops;
+use kild_core::usage::{format_cost, format_tokens};
 
 use super::helpers::{
     self, FailedOperation, format_partial_failure_error, is_valid_branch_name,
     load_config_with_warning,
 };
 
-/// Combined output for JSON: git health + computed readiness.
+/// Combined output for JSON: git health + computed readiness + token usage.
 #[derive(Serialize)]
 struct StatsOutput {
     #[serde(flatten)]
     health: BranchHealth,
     merge_readiness: MergeReadiness,
+    usage: KildUsage,
 }
 
 pub(crate) fn handle_stats_command(matches: &ArgMatches) -> Result<(), Box<dyn std::error::Error>> {
@@ -50,12 +54,13 @@ pub(crate) fn handle_stats_command(matches: &ArgMatches) -> Result<(), Box<dyn s
         None => config.git.base_branch(),
     };
 
-    handle_single_stats(branch, base_branch, json_output)
+    handle_single_stats(branch, base_branch, &config.usage, json_output)
 }
 
 fn handle_single_stats(
     branch: &str,
     base_branch: &str,
+    usage_config: &UsageConfig,
     json_output: bool,
 ) -> Result<(), Box<dyn std::error::Error>> {
     info!(
@@ -103,6 +108,7 @@ fn handle_single_stats(
                 has_unpushed,
                 pr_info.as_ref(),
             );
+            let usage = kild_core::usage::collect_usage_for_session(&session, usage_config);
 
             info!(
                 event = "cli.stats_completed",
@@ -114,10 +120,12 @@ fn handle_single_stats(
                 let output = StatsOutput {
                     health: h,
                     merge_readiness: readiness,
+                    usage,
                 };
                 println!("{}", serde_json::to_string_pretty(&output)?);
             } else {
                 print_single_health(branch, &h, &readiness);
+                print_usage(&usage);
             }
             Ok(())
         }
@@ -165,10 +173,11 @@ fn handle_all_stats(
         return Ok(());
     }
 
-    let mut results: Vec<(BranchHealth, MergeReadiness)> = Vec::new();
+    let usages = kild_core::usage::collect_usage(&sessions, &config.usage);
+    let mut results: Vec<(BranchHealth, MergeReadiness, KildUsage)> = Vec::new();
     let mut errors: Vec<FailedOperation> = Vec::new();
 
-    for session in &sessions {
+    for (session, usage) in sessions.iter().zip(usages) {
         // Skip --main sessions — they have no isolated branch to measure.
         if session.use_main_worktree {
             info!(
@@ -196,7 +205,7 @@ fn handle_all_stats(
                     has_unpushed,
                     pr_info.as_ref(),
                 );
-                results.push((h, readiness));
+                results.push((h, readiness, usage));
             }
             Err(msg) => {
                 errors.push((session.branch.to_string(), msg));
@@ -215,14 +224,17 @@ fn handle_all_stats(
     if json_output {
         let output: Vec<StatsOutput> = results
             .into_iter()
-            .map(|(health, merge_readiness)| StatsOutput {
+            .map(|(health, merge_readiness, usage)| StatsOutput {
                 health,
                 merge_readiness,
+                usage,
             })
             .collect();
         println!("{}", serde_json::to_string_pretty(&output)?);
     } else {
         print_fleet_table(&results);
+        let usages: Vec<KildUsage> = results.into_iter().map(|(_, _, u)| u).collect();
+        print_project_usage(&kild_core::usage::aggregate_by_project(&usages));
     }
 
     if !errors.is_empty() {
@@ -296,11 +308,50 @@ fn print_single_health(branch: &str, h: &BranchHealth, readiness: &MergeReadines
     println!("Readiness:    {}", readiness_detail);
 }
 
-fn print_fleet_table(results: &[(BranchHealth, MergeReadiness)]) {
+fn print_usage(usage: &KildUsage) {
+    let t = &usage.tokens;
+    println!(
+        "Tokens:       {} in, {} out, {} cache read, {} cache write",
+        format_tokens(t.input_tokens),
+        format_tokens(t.output_tokens),
+        format_tokens(t.cache_read_tokens),
+        format_tokens(t.cache_write_tokens),
+    );
+    let unpriced: Vec<&str> = usage.unpriced_models().collect();
+    if unpriced.is_empty() {
+        println!("Cost:         {}", format_cost(usage.cost_usd));
+    } else {
+        println!(
+            "Cost:         {} (no price for: {})",
+            format_cost(usage.cost_usd),
+            unpriced.join(", ")
+        );
+    }
+}
+
+fn print_project_usage(projects: &[kild_core::ProjectUsage]) {
+    if projects.iter().all(|p| p.tokens.is_empty()) {
+        return;
+    }
+    println!();
+    println!("Usage by project:");
+    for p in projects {
+        println!(
+            "  {}  {} kild(s)  {} in / {} out  {}",
+            p.project_id,
+            p.kild_count,
+            format_tokens(p.tokens.input_tokens + p.tokens.cache_read_tokens),
+            format_tokens(p.tokens.output_tokens),
+            format_cost(p.cost_usd),
+        );
+    }
+}
+
+fn print_fleet_table(results: &[(BranchHealth, MergeReadiness, KildUsage)]) {
     // Dynamic column widths
     let branch_w = results
         .iter()
-        .map(|(h, _)| h.branch.len())
+        .map(|(h, _, _)| h.branch.len())
         .max()
         .unwrap_or(6)
         .clamp(6, 30);
@@ -309,33 +360,39 @@ fn print_fleet_table(results: &[(BranchHealth, MergeReadiness)]) {
     let behind_w = 6;
     let conflicts_w = 9;
     let readiness_w = 18;
+    let tokens_w = 7;
+    let cost_w = 8;
 
     // Header
     println!(
-        "┌{}┬{}┬{}┬{}┬{}┬{}┐",
+        "┌{}┬{}┬{}┬{}┬{}┬{}┬{}┬{}┐",
         "─".repeat(branch_w + 2),
         "─".repeat(commits_w + 2),
         "─".repeat(diff_w + 2),
         "─".repeat(behind_w + 2),
         "─".repeat(conflicts_w + 2),
         "─".repeat(readiness_w + 2),
+        "─".repeat(tokens_w + 2),
+        "─".repeat(cost_w + 2),
     );
     println!(
-        "│ {:<branch_w$} │ {:<commits_w$} │ {:<diff_w$} │ {:<behind_w$} │ {:<conflicts_w$} │ {:<readiness_w$} │",
-        "Branch", "Commits", "Diff", "Behind", "Conflicts", "Readiness",
+        "│ {:<branch_w$} │ {:<commits_w$} │ {:<diff_w$} │ {:<behind_w$} │ {:<conflicts_w$} │ {:<readiness_w$} │ {:<tokens_w$} │ {:<cost_w$} │",
+        "Branch", "Commits", "Diff", "Behind", "Conflicts", "Readiness", "Tokens", "Cost",
     );
     println!(
-        "├{}┼{}┼{}┼{}┼{}┼{}┤",
+        "├{}┼{}┼{}┼{}┼{}┼{}┼{}┼{}┤",
         "─".repeat(branch_w + 2),
         "─".repeat(commits_w + 2),
         "─".repeat(diff_w + 2),
         "─".repeat(behind_w + 2),
         "─".repeat(conflicts_w + 2),
         "─".repeat(readiness_w + 2),
+        "─".repeat(tokens_w + 2),
+        "─".repeat(cost_w + 2),
     );
 
     // Rows
-    for (h, readiness) in results {
+    for (h, readiness, usage) in results {
         let diff_str = h.diff_vs_base.as_ref().map_or_else(
             || "-".to_string(),
             |d| format!("+{} -{}", d.insertions, d.deletions),
@@ -347,25 +404,29 @@ fn print_fleet_table(results: &[(BranchHealth, MergeReadiness)]) {
         };
 
         println!(
-            "│ {:<branch_w$} │ {:<commits_w$} │ {:<diff_w$} │ {:<behind_w$} │ {:<conflicts_w$} │ {:<readiness_w$} │",
+            "│ {:<branch_w$} │ {:<commits_w$} │ {:<diff_w$} │ {:<behind_w$} │ {:<conflicts_w$} │ {:<readiness_w$} │ {:<tokens_w$} │ {:<cost_w$} │",
             truncate_str(&h.branch, branch_w),
             h.commit_activity.commits_since_base,
             truncate_str(&diff_str, diff_w),
             h.drift.behind,
             conflicts_str,
             truncate_str(&readiness.to_string(), readiness_w),
+            format_tokens(usage.tokens.total()),
+            format_cost(usage.cost_usd),
         );
     }
 
     // Footer
     println!(
-        "└{}┴{}┴{}┴{}┴{}┴{}┘",
+        "└{}┴{}┴{}┴{}┴{}┴{}┴{}┴{}┘",
         "─".repeat(branch_w + 2),
         "─".repeat(commits_w + 2),
         "─".repeat(diff_w + 2),
         "─".repeat(behind_w + 2),
         "─".repeat(conflicts_w + 2),
         "─".repeat(readiness_w + 2),
+        "─".repeat(tokens_w + 2),
+        "─".repeat(cost_w + 2),
     );
 }
 
@@ -67,6 +67,8 @@ pub(crate) fn handle_status_command(
 
         let overlapping_files = compute_overlapping_files(&session, base_branch);
 
+        let usage = kild_core::usage::collect_usage_for_session(&session, &config.usage);
+
         let agent_count = session.agent_count();
         let enriched = EnrichedSession {
             session,
@@ -80,6 +82,7 @@ pub(crate) fn handle_status_command(
             terminal_type,
             pr_info,
             overlapping_files,
+            usage: Some(usage),
         };
         println!("{}", serde_json::to_string_pretty(&enriched)?);
         info!(
@@ -107,6 +110,9 @@ pub(crate) fn handle_status_command(
         rows.push(("Issue:", format!("#{}", issue)));
     }
     rows.push(("Worktree:", shorten_home_path(&session.worktree_path)));
+    if !session.sparse_paths.is_empty() {
+        rows.push(("Sparse:", session.sparse_paths.join(", ")));
+    }
 
     // Git stats rows
     if let Some(ref stats) = git_stats {
@@ -0,0 +1,175 @@
+use std::path::PathBuf;
+
+use clap::ArgMatches;
+use tracing::{error, info};
+
+use kild_core::events;
+use kild_core::sessions::transfer;
+
+use super::helpers;
+use crate::color;
+
+pub(crate) fn handle_export_command(
+    matches: &ArgMatches,
+) -> Result<(), Box<dyn std::error::Error>> {
+    let branch = matches
+        .get_one::<String>("branch")
+        .ok_or("Branch argument is required")?;
+    let output = matches
+        .get_one::<String>("output")
+        .map(PathBuf::from)
+        .unwrap_or_else(|| default_archive_name(branch));
+
+    info!(event = "cli.export_started", branch = branch);
+
+    match transfer::export_session(branch, &output) {
+        Ok(manifest) => {
+            println!("{} {}", color::aurora("Exported kild:"), color::ice(branch));
+            println!(
+                "  {}  {}",
+                color::muted("Archive:"),
+                helpers::shorten_home_path(&output)
+            );
+            println!(
+                "  {}     {}",
+                color::muted("Head:"),
+                &manifest.head_commit[..manifest.head_commit.len().min(7)]
+            );
+            if let Some(base) = &manifest.base_commit {
+                println!(
+                    "  {}     {} {}",
+                    color::muted("Base:"),
+                    &base[..base.len().min(7)],
+                    color::muted("(must exist in the importing repository)")
+                );
+            }
+            let mut contents = Vec::new();
+            if manifest.has_bundle {
+                contents.push("commits");
+            }
+            if manifest.has_dirty_patch {
+                contents.push("uncommitted work");
+            }
+            if manifest.has_inbox {
+                contents.push("inbox");
+            }
+            if manifest.has_task_list {
+                contents.push("task list");
+            }
+            if !contents.is_empty() {
+                println!("  {} {}", color::muted("Includes:"), contents.join(", "));
+            }
+            println!(
+                "  {}     {}",
+                color::muted("Next:"),
+                color::muted(&format!("kild import {}", output.display()))
+            );
+            info!(
+                event = "cli.export_completed",
+                branch = branch,
+                path = %output.display()
+            );
+            Ok(())
+        }
+        Err(e) => {
+            eprintln!("{}", color::error(&e.to_string()));
+            error!(event = "cli.export_failed", branch = branch, error = %e);
+            events::log_app_error(&e);
+            Err(e.into())
+        }
+    }
+}
+
+pub(crate) fn handle_import_command(
+    matches: &ArgMatches,
+) -> Result<(), Box<dyn std::error::Error>> {
+    let archive = matches
+        .get_one::<String>("archive")
+        .map(PathBuf::from)
+        .ok_or("Archive argument is required")?;
+    let rename = matches.get_one::<String>("as").map(String::as_str);
+
+    info!(event = "cli.import_started", path = %archive.display());
+
+    match transfer::import_session(&archive, rename) {
+        Ok(outcome) => {
+            let session = &outcome.session;
+            println!(
+                "{} {}",
+                color::aurora("Imported kild:"),
+                color::ice(&session.branch)
+            );
+            println!(
+                "  {} {}",
+                color::muted("Worktree:"),
+                helpers::shorten_home_path(&session.worktree_path)
+            );
+            println!(
+                "  {}    {}-{}",
+                color::muted("Ports:"),
+                session.port_range_start,
+                session.port_range_end
+            );
+            if let Some(note) = &session.note {
+                println!("  {}     {}", color::muted("Note:"), note);
+            }
+            if let Some(path) = &outcome.unapplied_patch {
+                println!(
+                    "  {}",
+                    color::warning(&format!(
+                        "Uncommitted work did not apply cleanly; patch saved to {}",
+                        helpers::shorten_home_path(path)
+                    ))
+                );
+            }
+            if outcome.manifest.has_inbox && !outcome.inbox_restored {
+                println!(
+                    "  {}",
+                    color::warning("Inbox not restored (already present)")
+                );
+            }
+            if outcome.manifest.has_task_list && !outcome.task_list_restored {
+                println!(
+                    "  {}",
+                    color::warning("Task list not restored (already present)")
+                );
+            }
+            println!(
+                "  {}     {}",
+                color::muted("Next:"),
+                color::muted(&format!("kild open {}", session.branch))
+            );
+            info!(
+                event = "cli.import_completed",
+                branch = %session.branch,
+                path = %archive.display()
+            );
+            Ok(())
+        }
+        Err(e) => {
+            eprintln!("{}", color::error(&e.to_string()));
+            error!(event = "cli.import_failed", path = %archive.display(), error = %e);
+            events::log_app_error(&e);
+            Err(e.into())
+        }
+    }
+}
+
+/// `feature/auth` exports to `feature-auth.kild.tar`.
+fn default_archive_name(branch: &str) -> PathBuf {
+    PathBuf::from(format!("{}.kild.tar", branch.replace('/', "-")))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_default_archive_name() {
+        assert_eq!(
+            default_archive_name("feature/auth"),
+            PathBuf::from("feature-auth.kild.tar")
+        );
+        assert_eq!(default_archive_name("fix"), PathBuf::from("fix.kild.tar"));
+    }
+}
@@ -358,10 +358,39 @@ pub(crate) fn pad(s: &str, min_width: usize) -> String {
     }
 }
 
+/// Render values as a unicode block sparkline scaled to `max`.
+///
+/// Values are clamped to `0..=max`; a `max` of zero renders the lowest block.
+pub(crate) fn sparkline(values: &[f32], max: f32) -> String {
+    const BLOCKS: [char; 8] = ['▁', '▂', '▃', '▄', '▅', '▆', '▇', '█'];
+    values
+        .iter()
+        .map(|v| {
+            if max <= 0.0 {
+                return BLOCKS[0];
+            }
+            let ratio = (v / max).clamp(0.0, 1.0);
+            BLOCKS[(ratio * (BLOCKS.len() - 1) as f32).round() as usize]
+        })
+        .collect()
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
 
+    #[test]
+    fn test_sparkline_scales_to_max() {
+        assert_eq!(sparkline(&[0.0, 2.0, 4.0], 4.0), "▁▅█");
+    }
+
+    #[test]
+    fn test_sparkline_clamps_and_handles_zero_max() {
+        assert_eq!(sparkline(&[8.0, -1.0], 4.0), "█▁");
+        assert_eq!(sparkline(&[1.0, 2.0], 0.0), "▁▁");
+        assert_eq!(sparkline(&[], 1.0), "");
+    }
+
     #[test]
     fn test_pad_shorter_than_width() {
         assert_eq!(pad("hi", 5), "hi   ");